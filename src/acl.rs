@@ -0,0 +1,395 @@
+use std::fmt;
+use std::result;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use {Error, Result};
+
+/// An IPv4 or IPv6 network prefix. IPv4 prefixes are stored in their
+/// v4-mapped form (`::ffff:0:0/96`) so a single trie covers both families
+/// and v4-mapped client addresses match IPv4 entries.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Prefix {
+    addr: [u8; 16],
+    len: u8,
+}
+
+impl Prefix {
+
+    pub fn new(ip: &IpAddr, len: u8) -> Result<Prefix> {
+        let (addr, len) = match *ip {
+            IpAddr::V4(ref ip) if len <= 32 => (mapped(ip), len + 96),
+            IpAddr::V6(ref ip) if len <= 128 => (octets(ip), len),
+            _ => return Err(Error::BadPrefix),
+        };
+        Ok(Prefix{ addr: mask(addr, len), len: len })
+    }
+
+    pub fn host(ip: &IpAddr) -> Prefix {
+        match *ip {
+            IpAddr::V4(ref ip) => Prefix{ addr: mapped(ip), len: 128 },
+            IpAddr::V6(ref ip) => Prefix{ addr: octets(ip), len: 128 },
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> u8 { self.len }
+
+    #[inline]
+    pub fn is_v4(&self) -> bool {
+        self.len >= 96 && is_mapped(&self.addr)
+    }
+
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        mask(Prefix::host(ip).addr, self.len) == self.addr
+    }
+
+    #[inline]
+    fn bit(&self, i: u8) -> usize {
+        bit(&self.addr, i)
+    }
+}
+
+impl FromStr for Prefix {
+    type Err = Error;
+
+    fn from_str(s: &str) -> result::Result<Prefix, Error> {
+        let (ip, len) = match s.find('/') {
+            Some(i) => (&s[..i], Some(try!(s[i+1..].parse::<u8>().map_err(|_| Error::BadPrefix)))),
+            None => (s, None),
+        };
+        if let Ok(ip) = ip.parse::<Ipv4Addr>() {
+            Prefix::new(&IpAddr::V4(ip), len.unwrap_or(32))
+        } else if let Ok(ip) = ip.parse::<Ipv6Addr>() {
+            Prefix::new(&IpAddr::V6(ip), len.unwrap_or(128))
+        } else {
+            Err(Error::BadPrefix)
+        }
+    }
+}
+
+impl fmt::Display for Prefix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let a = &self.addr;
+        if self.is_v4() {
+            write!(f, "{}/{}", Ipv4Addr::new(a[12], a[13], a[14], a[15]), self.len - 96)
+        } else {
+            let mut s = [0u16; 8];
+            for i in 0..8 {
+                s[i] = (a[i*2] as u16) << 8 | a[i*2+1] as u16;
+            }
+            write!(f, "{}/{}", Ipv6Addr::new(s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]), self.len)
+        }
+    }
+}
+
+impl fmt::Debug for Prefix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+// Index 0 is always the root so it doubles as "no child".
+const NIL: u32 = 0;
+
+#[derive(Clone)]
+struct Node {
+    child: [u32; 2],
+    allow: Option<bool>,
+}
+
+/// A list of allowed and denied prefixes held in a binary trie, packed into
+/// a single vector. Lookups walk at most 128 nodes and the most specific
+/// matching entry decides; addresses matching no entry are denied.
+#[derive(Clone)]
+pub struct Acl {
+    nodes: Vec<Node>,
+}
+
+impl Acl {
+
+    pub fn new() -> Acl {
+        Acl{ nodes: vec![Node{ child: [NIL, NIL], allow: None }] }
+    }
+
+    pub fn any() -> Acl {
+        let mut acl = Acl::new();
+        acl.nodes[0].allow = Some(true);
+        acl
+    }
+
+    pub fn none() -> Acl {
+        let mut acl = Acl::new();
+        acl.nodes[0].allow = Some(false);
+        acl
+    }
+
+    pub fn insert(&mut self, prefix: &Prefix, allow: bool) {
+        let mut n = 0;
+        for i in 0..prefix.len {
+            let b = prefix.bit(i);
+            let next = self.nodes[n].child[b];
+            n = if next != NIL {
+                next as usize
+            } else {
+                self.nodes.push(Node{ child: [NIL, NIL], allow: None });
+                let next = self.nodes.len() - 1;
+                self.nodes[n].child[b] = next as u32;
+                next
+            };
+        }
+        self.nodes[n].allow = Some(allow);
+    }
+
+    /// Returns the decision of the longest matching prefix, if any.
+    pub fn lookup(&self, ip: &IpAddr) -> Option<bool> {
+        let addr = Prefix::host(ip).addr;
+        let mut n = 0;
+        let mut found = self.nodes[0].allow;
+        for i in 0..128 {
+            n = self.nodes[n].child[bit(&addr, i)] as usize;
+            if n == NIL as usize {
+                break
+            }
+            if let Some(allow) = self.nodes[n].allow {
+                found = Some(allow);
+            }
+        }
+        found
+    }
+
+    #[inline]
+    pub fn allows(&self, ip: &IpAddr) -> bool {
+        self.lookup(ip).unwrap_or(false)
+    }
+
+    /// Whether every address is allowed, as by `any` with no exceptions.
+    pub fn is_any(&self) -> bool {
+        self.nodes[0].allow == Some(true) && self.nodes.iter().all(|n| n.allow != Some(false))
+    }
+}
+
+/// Parses a list of entries separated by commas, semicolons or whitespace.
+/// Each entry is a prefix, optionally negated with a leading `!`, or one of
+/// the keywords `any` and `none`.
+impl FromStr for Acl {
+    type Err = Error;
+
+    fn from_str(s: &str) -> result::Result<Acl, Error> {
+        let mut acl = Acl::new();
+        for entry in s.split(|c: char| c == ',' || c == ';' || c.is_whitespace()) {
+            if entry.is_empty() {
+                continue
+            }
+            let (allow, entry) = if entry.starts_with('!') {
+                (false, &entry[1..])
+            } else {
+                (true, entry)
+            };
+            match entry {
+                "any"  => acl.nodes[0].allow = Some(allow),
+                "none" => acl.nodes[0].allow = Some(!allow),
+                _ => acl.insert(&try!(entry.parse::<Prefix>()), allow),
+            }
+        }
+        Ok(acl)
+    }
+}
+
+/// The operations a client can be granted access to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Access {
+    Recursion,
+    Cache,
+    Transfer,
+    Update,
+    /// Sending NOTIFY for secondary zones, besides their primaries.
+    Notify,
+}
+
+/// What to do with a request from a client outside the attached ACL.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Denied {
+    Refuse,
+    Drop,
+}
+
+/// Named ACLs and the operations they are attached to. Recursion and cache
+/// access are open to everyone until an ACL is attached, while zone
+/// transfers, updates and notifications are closed.
+#[derive(Clone)]
+pub struct AccessControl {
+    acls: HashMap<String, Acl>,
+    attached: HashMap<Access, String>,
+    pub denied: Denied,
+}
+
+impl AccessControl {
+
+    pub fn new() -> AccessControl {
+        let mut acls = HashMap::new();
+        acls.insert("any".to_string(), Acl::any());
+        acls.insert("none".to_string(), Acl::none());
+        AccessControl{
+            acls: acls,
+            attached: HashMap::new(),
+            denied: Denied::Refuse,
+        }
+    }
+
+    pub fn define(&mut self, name: &str, acl: &str) -> Result<()> {
+        let acl = try!(acl.parse::<Acl>());
+        self.acls.insert(name.to_string(), acl);
+        Ok(())
+    }
+
+    pub fn attach(&mut self, access: Access, name: &str) -> Result<()> {
+        if !self.acls.contains_key(name) {
+            return Err(Error::UnknownAcl)
+        }
+        self.attached.insert(access, name.to_string());
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Acl> {
+        self.acls.get(name)
+    }
+
+    pub fn allows(&self, access: Access, ip: &IpAddr) -> bool {
+        match self.attached.get(&access).and_then(|name| self.acls.get(name)) {
+            Some(acl) => acl.allows(ip),
+            None => match access {
+                Access::Recursion | Access::Cache => true,
+                Access::Transfer | Access::Update | Access::Notify => false,
+            }
+        }
+    }
+}
+
+impl Default for AccessControl {
+    fn default() -> AccessControl {
+        AccessControl::new()
+    }
+}
+
+#[inline]
+fn bit(addr: &[u8; 16], i: u8) -> usize {
+    ((addr[(i / 8) as usize] >> (7 - i % 8)) & 1) as usize
+}
+
+fn mask(mut addr: [u8; 16], len: u8) -> [u8; 16] {
+    for i in 0..16 {
+        let bits = len as isize - i as isize * 8;
+        if bits <= 0 {
+            addr[i] = 0;
+        } else if bits < 8 {
+            addr[i] &= 0xff << (8 - bits);
+        }
+    }
+    addr
+}
+
+#[inline]
+fn mapped(ip: &Ipv4Addr) -> [u8; 16] {
+    let o = ip.octets();
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, o[0], o[1], o[2], o[3]]
+}
+
+#[inline]
+fn is_mapped(addr: &[u8; 16]) -> bool {
+    addr[..10].iter().all(|&b| b == 0) && addr[10] == 0xff && addr[11] == 0xff
+}
+
+fn octets(ip: &Ipv6Addr) -> [u8; 16] {
+    let mut addr = [0; 16];
+    for (i, s) in ip.segments().iter().enumerate() {
+        addr[i*2] = (s >> 8) as u8;
+        addr[i*2+1] = (s & 0xff) as u8;
+    }
+    addr
+}
+
+
+#[cfg(test)]
+fn ip(s: &str) -> IpAddr {
+    match s.parse::<Ipv4Addr>() {
+        Ok(ip) => IpAddr::V4(ip),
+        Err(_) => IpAddr::V6(s.parse::<Ipv6Addr>().unwrap()),
+    }
+}
+
+#[test]
+fn parse_prefixes() {
+    assert_eq!("10.0.0.0/8".parse::<Prefix>().unwrap().to_string(), "10.0.0.0/8");
+    assert_eq!("10.1.2.3/8".parse::<Prefix>().unwrap().to_string(), "10.0.0.0/8");
+    assert_eq!("192.168.1.1".parse::<Prefix>().unwrap().to_string(), "192.168.1.1/32");
+    assert_eq!("2001:db8::1/32".parse::<Prefix>().unwrap().to_string(), "2001:db8::/32");
+    assert_eq!("::ffff:10.0.0.0/104".parse::<Prefix>().unwrap().to_string(), "10.0.0.0/8");
+
+    assert!("10.0.0.0/33".parse::<Prefix>().is_err());
+    assert!("2001:db8::/129".parse::<Prefix>().is_err());
+    assert!("example.com".parse::<Prefix>().is_err());
+}
+
+#[test]
+fn match_ipv4() {
+    let acl = "10.0.0.0/8, !10.1.0.0/16, 10.1.2.0/24".parse::<Acl>().unwrap();
+    assert!(acl.allows(&ip("10.0.0.1")));
+    assert!(!acl.allows(&ip("10.1.0.1")));
+    assert!(acl.allows(&ip("10.1.2.3")));
+    assert!(!acl.allows(&ip("11.0.0.1")));
+    assert_eq!(acl.lookup(&ip("11.0.0.1")), None);
+
+    let acl = "any; !192.0.2.1".parse::<Acl>().unwrap();
+    assert!(acl.allows(&ip("198.51.100.1")));
+    assert!(!acl.allows(&ip("192.0.2.1")));
+    assert!(!acl.is_any());
+    assert!("any, 192.0.2.1".parse::<Acl>().unwrap().is_any());
+}
+
+#[test]
+fn match_ipv6() {
+    let acl = "2001:db8::/32 !2001:db8:bad::/48 ::1".parse::<Acl>().unwrap();
+    assert!(acl.allows(&ip("2001:db8::53")));
+    assert!(acl.allows(&ip("::1")));
+    assert!(!acl.allows(&ip("2001:db8:bad::53")));
+    assert!(!acl.allows(&ip("2001:db9::53")));
+    assert!(!acl.allows(&ip("::2")));
+}
+
+#[test]
+fn match_ipv4_mapped() {
+    let acl = "192.168.0.0/16".parse::<Acl>().unwrap();
+    assert!(acl.allows(&ip("::ffff:192.168.10.1")));
+    assert!(!acl.allows(&ip("::ffff:10.0.0.1")));
+
+    let acl = "::ffff:172.16.0.0/108".parse::<Acl>().unwrap();
+    assert!(acl.allows(&ip("172.16.1.1")));
+    assert!(!acl.allows(&ip("172.32.1.1")));
+
+    // IPv4 entries must not leak into the rest of the IPv6 space
+    let acl = "0.0.0.0/0".parse::<Acl>().unwrap();
+    assert!(acl.allows(&ip("8.8.8.8")));
+    assert!(!acl.allows(&ip("2001:db8::1")));
+}
+
+#[test]
+fn access_control() {
+    let mut ac = AccessControl::new();
+    assert!(ac.allows(Access::Recursion, &ip("203.0.113.1")));
+    assert!(ac.allows(Access::Cache, &ip("203.0.113.1")));
+    assert!(!ac.allows(Access::Transfer, &ip("127.0.0.1")));
+    assert!(!ac.allows(Access::Update, &ip("127.0.0.1")));
+    assert!(!ac.allows(Access::Notify, &ip("127.0.0.1")));
+
+    ac.define("internal", "10.0.0.0/8 fd00::/8").unwrap();
+    ac.attach(Access::Recursion, "internal").unwrap();
+    ac.attach(Access::Transfer, "any").unwrap();
+    assert!(ac.attach(Access::Update, "missing").is_err());
+
+    assert!(ac.allows(Access::Recursion, &ip("10.9.9.9")));
+    assert!(ac.allows(Access::Recursion, &ip("fd00::1")));
+    assert!(!ac.allows(Access::Recursion, &ip("203.0.113.1")));
+    assert!(ac.allows(Access::Transfer, &ip("203.0.113.1")));
+}
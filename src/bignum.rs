@@ -0,0 +1,279 @@
+use std::cmp::Ordering;
+
+/// A residue modulo some `Modulus`, as little endian 32 bit limbs in
+/// Montgomery form.
+pub type Residue = Vec<u32>;
+
+/// Arithmetic modulo an odd number using Montgomery multiplication, enough
+/// for public key operations. Nothing here runs in constant time.
+#[derive(Clone, Debug)]
+pub struct Modulus {
+    m: Vec<u32>,
+    // -m^-1 mod 2^32
+    inv: u32,
+    // R^2 mod m, for R = 2^(32 * limbs)
+    r2: Vec<u32>,
+}
+
+impl Modulus {
+
+    /// Takes the modulus as big endian bytes. It must be odd and greater
+    /// than one.
+    pub fn new(m: &[u8]) -> Modulus {
+        let m = limbs(m);
+        assert!(m[0] & 1 == 1 && (m.len() > 1 || m[0] > 1));
+
+        // Newton's iteration doubles the correct low bits each round
+        let mut x: u32 = 1;
+        for _ in 0..5 {
+            x = x.wrapping_mul(2u32.wrapping_sub(m[0].wrapping_mul(x)));
+        }
+
+        let mut r2 = vec![0; m.len()];
+        r2[0] = 1;
+        for _ in 0..64 * m.len() {
+            let twice = r2.clone();
+            add_mod(&mut r2, &twice, &m);
+        }
+        Modulus{ inv: x.wrapping_neg(), m: m, r2: r2 }
+    }
+
+    /// The number of bytes the modulus takes.
+    pub fn len(&self) -> usize {
+        let top = self.m[self.m.len() - 1];
+        (self.m.len() - 1) * 4 + (4 - top.leading_zeros() as usize / 8)
+    }
+
+    /// The residue of a big endian number of any size.
+    pub fn reduce(&self, bytes: &[u8]) -> Residue {
+        let mut acc = vec![0; self.m.len()];
+        let mut one = vec![0; self.m.len()];
+        one[0] = 1;
+        for &b in bytes.iter() {
+            for bit in (0..8).rev() {
+                let twice = acc.clone();
+                add_mod(&mut acc, &twice, &self.m);
+                if b >> bit & 1 == 1 {
+                    add_mod(&mut acc, &one, &self.m);
+                }
+            }
+        }
+        self.mul(&acc, &self.r2)
+    }
+
+    /// The residue of a big endian number, or `None` if it is not less
+    /// than the modulus.
+    pub fn residue(&self, bytes: &[u8]) -> Option<Residue> {
+        let mut n = limbs(bytes);
+        if n.len() > self.m.len() {
+            return None
+        }
+        n.resize(self.m.len(), 0);
+        if compare(&n, &self.m) != Ordering::Less {
+            return None
+        }
+        Some(self.mul(&n, &self.r2))
+    }
+
+    /// The big endian bytes of a residue, padded to the modulus length.
+    pub fn bytes(&self, a: &Residue) -> Vec<u8> {
+        let mut one = vec![0; self.m.len()];
+        one[0] = 1;
+        let n = self.mul(a, &one);
+        let mut out = Vec::with_capacity(n.len() * 4);
+        for limb in n.iter().rev() {
+            out.extend(&[(limb >> 24) as u8, (limb >> 16) as u8, (limb >> 8) as u8, *limb as u8]);
+        }
+        let skip = out.len() - self.len();
+        out.split_off(skip)
+    }
+
+    pub fn zero(&self) -> Residue {
+        vec![0; self.m.len()]
+    }
+
+    pub fn one(&self) -> Residue {
+        self.reduce(&[1])
+    }
+
+    pub fn is_zero(&self, a: &Residue) -> bool {
+        a.iter().all(|&l| l == 0)
+    }
+
+    pub fn add(&self, a: &Residue, b: &Residue) -> Residue {
+        let mut r = a.clone();
+        add_mod(&mut r, b, &self.m);
+        r
+    }
+
+    pub fn sub(&self, a: &Residue, b: &Residue) -> Residue {
+        let mut r = a.clone();
+        if sub_in_place(&mut r, b) {
+            add_in_place(&mut r, &self.m);
+        }
+        r
+    }
+
+    pub fn neg(&self, a: &Residue) -> Residue {
+        self.sub(&self.zero(), a)
+    }
+
+    /// Montgomery multiplication, coarsely integrated operand scanning.
+    pub fn mul(&self, a: &Residue, b: &Residue) -> Residue {
+        let n = self.m.len();
+        let mut t = vec![0u32; n + 2];
+        for i in 0..n {
+            let mut c: u64 = 0;
+            for j in 0..n {
+                let s = t[j] as u64 + a[j] as u64 * b[i] as u64 + c;
+                t[j] = s as u32;
+                c = s >> 32;
+            }
+            let s = t[n] as u64 + c;
+            t[n] = s as u32;
+            t[n + 1] = (s >> 32) as u32;
+
+            let q = t[0].wrapping_mul(self.inv) as u64;
+            let mut c = (t[0] as u64 + q * self.m[0] as u64) >> 32;
+            for j in 1..n {
+                let s = t[j] as u64 + q * self.m[j] as u64 + c;
+                t[j - 1] = s as u32;
+                c = s >> 32;
+            }
+            let s = t[n] as u64 + c;
+            t[n - 1] = s as u32;
+            t[n] = t[n + 1] + (s >> 32) as u32;
+        }
+        let high = t[n];
+        t.truncate(n);
+        if high != 0 || compare(&t, &self.m) != Ordering::Less {
+            sub_in_place(&mut t, &self.m);
+        }
+        t
+    }
+
+    pub fn square(&self, a: &Residue) -> Residue {
+        self.mul(a, a)
+    }
+
+    /// Raises `a` to a big endian exponent.
+    pub fn pow(&self, a: &Residue, exp: &[u8]) -> Residue {
+        let mut r = self.one();
+        for &b in exp.iter() {
+            for bit in (0..8).rev() {
+                r = self.square(&r);
+                if b >> bit & 1 == 1 {
+                    r = self.mul(&r, a);
+                }
+            }
+        }
+        r
+    }
+
+    /// The inverse of `a` by Fermat's little theorem, so only for a prime
+    /// modulus; zero has none and stays zero.
+    pub fn inv(&self, a: &Residue) -> Residue {
+        self.pow(a, &self.exponent(2, 0))
+    }
+
+    /// `(m - k) >> shift` as big endian bytes, the form exponents for
+    /// inverses and square roots take.
+    pub fn exponent(&self, k: u32, shift: usize) -> Vec<u8> {
+        let mut m = self.m.clone();
+        sub_in_place(&mut m, &[k]);
+        for _ in 0..shift {
+            let mut carry = 0;
+            for limb in m.iter_mut().rev() {
+                let low = *limb & 1;
+                *limb = *limb >> 1 | carry << 31;
+                carry = low;
+            }
+        }
+        let mut out = vec![];
+        for limb in m.iter().rev() {
+            out.extend(&[(limb >> 24) as u8, (limb >> 16) as u8, (limb >> 8) as u8, *limb as u8]);
+        }
+        out
+    }
+}
+
+// Little endian limbs of a big endian number, without leading zero limbs
+// beyond the first.
+fn limbs(bytes: &[u8]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(bytes.len() / 4 + 1);
+    for chunk in bytes.rchunks(4) {
+        out.push(chunk.iter().fold(0u32, |l, &b| l << 8 | b as u32));
+    }
+    while out.len() > 1 && out[out.len() - 1] == 0 {
+        out.pop();
+    }
+    if out.is_empty() {
+        out.push(0);
+    }
+    out
+}
+
+fn compare(a: &[u32], b: &[u32]) -> Ordering {
+    for i in (0..a.len()).rev() {
+        match a[i].cmp(&b[i]) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    Ordering::Equal
+}
+
+// a += b, returning the carry out of the top limb.
+fn add_in_place(a: &mut [u32], b: &[u32]) -> bool {
+    let mut c = 0u64;
+    for i in 0..a.len() {
+        let s = a[i] as u64 + *b.get(i).unwrap_or(&0) as u64 + c;
+        a[i] = s as u32;
+        c = s >> 32;
+    }
+    c != 0
+}
+
+// a -= b, returning whether it borrowed past the top limb.
+fn sub_in_place(a: &mut [u32], b: &[u32]) -> bool {
+    let mut borrow = 0i64;
+    for i in 0..a.len() {
+        let d = a[i] as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        a[i] = d as u32;
+        borrow = if d < 0 { 1 } else { 0 };
+    }
+    borrow != 0
+}
+
+// a = (a + b) mod m for a, b < m.
+fn add_mod(a: &mut [u32], b: &[u32], m: &[u32]) {
+    if add_in_place(a, b) || compare(a, m) != Ordering::Less {
+        sub_in_place(a, m);
+    }
+}
+
+
+#[cfg(test)] use rustc_serialize::hex::{FromHex, ToHex};
+
+#[test]
+fn modular_arithmetic() {
+    let m = Modulus::new(&[0x01, 0x00, 0x00, 0x00, 0x0f]); // 2^32 + 15
+    assert_eq!(m.len(), 5);
+    let a = m.reduce(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]);
+    let b = m.reduce(&[0xff, 0xff, 0xff, 0xff, 0xff]);
+    // 0x123456789abc * 0xffffffffff mod 2^32 + 15 and so on
+    assert_eq!(m.bytes(&m.mul(&a, &b)).to_hex(), "00a877725e");
+    assert_eq!(m.bytes(&m.add(&a, &b)).to_hex(), "0056777aaf");
+    assert_eq!(m.bytes(&m.sub(&a, &b)).to_hex(), "00567798b1");
+    assert_eq!(m.bytes(&m.neg(&m.one())).to_hex(), "010000000e");
+    assert_eq!(m.residue(&[0x01, 0x00, 0x00, 0x00, 0x0f]), None);
+    assert_eq!(m.residue(&[0x0f]), Some(m.reduce(&[0x0f])));
+
+    // Fermat in a prime field: 2^255 - 19
+    let p = "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed".from_hex().unwrap();
+    let p = Modulus::new(&p);
+    let x = p.reduce(&[0x09]);
+    assert_eq!(p.mul(&x, &p.inv(&x)), p.one());
+    assert_eq!(p.pow(&x, &p.exponent(1, 0)), p.one());
+    assert_eq!(p.exponent(5, 3).to_hex(), "0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd");
+}
@@ -0,0 +1,535 @@
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::net::IpAddr;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use {Result};
+use dns::{Message, RCode, RType, RName, RData, MAX_LABEL_LEN};
+use dns::message::Resource;
+use dns::master::Reader;
+
+const REDIRECT_TTL: u32 = 300;
+
+// Names commonly found in hosts files that must never be blocked.
+const HOSTS_IGNORE: [&'static str; 8] = [
+    "localhost", "localhost.localdomain", "local", "broadcasthost",
+    "ip6-localhost", "ip6-loopback", "ip6-allnodes", "ip6-allrouters",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    /// `/etc/hosts` style lines; each name is blocked exactly and the
+    /// address is ignored.
+    Hosts,
+    /// One name per line blocking the name and everything below it, or
+    /// only the names below it when written as `*.example.com`.
+    Domains,
+    /// A response policy zone in master file format (QNAME triggers only).
+    Rpz,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Policy {
+    /// Use the action given by each RPZ record; other formats answer NXDOMAIN.
+    Given,
+    NxDomain,
+    NoData,
+    Redirect(Vec<IpAddr>),
+    Pass,
+    Drop,
+}
+
+impl Policy {
+
+    /// Synthesises the answer to `req` for a name caught by this policy.
+    pub fn respond(&self, req: &Message) -> Message {
+        match *self {
+            Policy::NxDomain => Message::new_error(req, RCode::NXDOMAIN),
+            Policy::Redirect(ref addrs) => {
+                let mut resp = Message::new_error(req, RCode::NOERROR);
+                if let Some(q) = req.questions.first() {
+                    for addr in addrs.iter() {
+                        let (rtype, data) = match *addr {
+                            IpAddr::V4(ip) if q.rtype == RType::A || q.rtype == RType::ALL => {
+                                let o = ip.octets();
+                                (RType::A, RData::A(o[0], o[1], o[2], o[3]))
+                            }
+                            IpAddr::V6(ip) if q.rtype == RType::AAAA || q.rtype == RType::ALL => {
+                                let s = ip.segments();
+                                (RType::AAAA, RData::AAAA(s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]))
+                            }
+                            _ => continue,
+                        };
+                        resp.answers.push(Resource{
+                            name: q.name.clone(),
+                            rtype: rtype,
+                            class: q.class,
+                            ttl: REDIRECT_TTL,
+                            data: data,
+                        });
+                    }
+                }
+                resp
+            }
+            _ => Message::new_error(req, RCode::NOERROR),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Node {
+    exact: Option<Policy>,
+    subtree: Option<Policy>,
+    children: HashMap<Vec<u8>, Node>,
+}
+
+impl Node {
+
+    fn insert(&mut self, name: &RName, subtree: bool, policy: Policy) {
+        let mut node = self;
+        for label in name.labels().rev() {
+            node = {node}.children.entry(label.to_ascii_lowercase()).or_insert_with(Node::default);
+        }
+        let slot = if subtree { &mut node.subtree } else { &mut node.exact };
+        // RPZ local data may hold several addresses
+        if let Some(Policy::Redirect(ref mut addrs)) = *slot {
+            if let Policy::Redirect(ref more) = policy {
+                addrs.extend(more.iter().cloned());
+                return
+            }
+        }
+        *slot = Some(policy);
+    }
+
+    // Labels are lowercased on the stack, as every query is looked up.
+    fn lookup(&self, name: &RName) -> Option<&Policy> {
+        let mut node = self;
+        let mut found = None;
+        let mut lower = [0; MAX_LABEL_LEN];
+        let len = name.label_count();
+        for (i, label) in name.labels().rev().enumerate() {
+            for (l, c) in lower.iter_mut().zip(label) {
+                *l = c.to_ascii_lowercase();
+            }
+            node = match node.children.get(&lower[..label.len()]) {
+                Some(node) => node,
+                None => return found,
+            };
+            if i + 1 == len {
+                if node.exact.is_some() {
+                    return node.exact.as_ref()
+                }
+            } else if node.subtree.is_some() {
+                found = node.subtree.as_ref();
+            }
+        }
+        found
+    }
+}
+
+/// A named list of blocked names, optionally backed by a file that is
+/// reloaded when it changes.
+#[derive(Clone)]
+pub struct Blocklist {
+    pub name: String,
+    pub format: Format,
+    pub action: Policy,
+    path: Option<PathBuf>,
+    modified: Option<SystemTime>,
+    // the last reload error, reported only when it changes
+    failure: Option<String>,
+    root: Node,
+    len: usize,
+    hits: u64,
+}
+
+impl Blocklist {
+
+    pub fn new(name: &str, format: Format, action: Policy) -> Blocklist {
+        Blocklist{
+            name: name.to_string(),
+            format: format,
+            action: action,
+            path: None,
+            modified: None,
+            failure: None,
+            root: Node::default(),
+            len: 0,
+            hits: 0,
+        }
+    }
+
+    pub fn open<P: AsRef<Path>>(name: &str, format: Format, path: P, action: Policy) -> Result<Blocklist> {
+        let mut list = Blocklist::new(name, format, action);
+        list.path = Some(path.as_ref().to_path_buf());
+        try!(list.reload());
+        Ok(list)
+    }
+
+    /// Number of triggers loaded.
+    #[inline]
+    pub fn len(&self) -> usize { self.len }
+
+    #[inline]
+    pub fn hits(&self) -> u64 { self.hits }
+
+    /// Replaces the contents of the list. On error the previous contents
+    /// are kept.
+    pub fn load(&mut self, input: &str) -> Result<()> {
+        let mut root = Node::default();
+        let len = try!(match self.format {
+            Format::Hosts => load_hosts(&mut root, input),
+            Format::Domains => load_domains(&mut root, input),
+            Format::Rpz => load_rpz(&mut root, input),
+        });
+        self.root = root;
+        self.len = len;
+        Ok(())
+    }
+
+    /// Reloads the backing file if it changed since it was last read. A
+    /// file that fails to load is not read again until it changes, and the
+    /// previous contents are kept meanwhile.
+    pub fn reload(&mut self) -> Result<bool> {
+        let path = match self.path {
+            Some(ref path) => path.clone(),
+            None => return Ok(false),
+        };
+        let modified = try!(fs::metadata(&path).and_then(|m| m.modified()));
+        if self.modified == Some(modified) {
+            return Ok(false)
+        }
+        self.modified = Some(modified);
+        let mut input = String::new();
+        try!(File::open(&path).and_then(|mut f| f.read_to_string(&mut input)));
+        try!(self.load(&input));
+        Ok(true)
+    }
+
+    pub fn lookup(&self, name: &RName) -> Option<&Policy> {
+        let policy = self.root.lookup(name);
+        match self.action {
+            Policy::Given => policy,
+            ref action => policy.map(|_| action),
+        }
+    }
+}
+
+/// Blocklists evaluated in order; the first list with a matching trigger
+/// decides and a `Pass` stops the evaluation.
+#[derive(Clone, Default)]
+pub struct Blocklists {
+    lists: Vec<Blocklist>,
+}
+
+impl Blocklists {
+
+    pub fn new() -> Blocklists {
+        Blocklists{ lists: vec![] }
+    }
+
+    pub fn push(&mut self, list: Blocklist) {
+        self.lists.push(list);
+    }
+
+    pub fn lists(&self) -> &[Blocklist] {
+        &self.lists
+    }
+
+    pub fn check(&mut self, name: &RName) -> Option<Policy> {
+        for list in self.lists.iter_mut() {
+            let policy = list.lookup(name).cloned();
+            if policy.is_some() {
+                list.hits += 1;
+                return policy
+            }
+        }
+        None
+    }
+
+    pub fn reload(&mut self) {
+        for list in self.lists.iter_mut() {
+            match list.reload() {
+                Ok(true) => println!("reloaded blocklist {} with {} names", list.name, list.len()),
+                Ok(false) => {}
+                Err(e) => {
+                    let failure = format!("{:?}", e);
+                    if list.failure.as_ref() != Some(&failure) {
+                        println!("failed to reload blocklist {}: {}", list.name, failure);
+                    }
+                    list.failure = Some(failure);
+                    continue
+                }
+            }
+            list.failure = None;
+        }
+    }
+}
+
+fn parse(name: &str) -> Option<RName> {
+    match RName::from_str(name) {
+        Ok(name) => if name.is_root() { None } else { Some(name) },
+        _ => None,
+    }
+}
+
+fn load_hosts(root: &mut Node, input: &str) -> Result<usize> {
+    let mut len = 0;
+    for line in input.lines() {
+        let line = line.split('#').next().unwrap_or("");
+        for name in line.split_whitespace().skip(1) {
+            if HOSTS_IGNORE.iter().any(|n| n.eq_ignore_ascii_case(name)) {
+                continue
+            }
+            if let Some(name) = parse(name) {
+                root.insert(&name, false, Policy::NxDomain);
+                len += 1;
+            }
+        }
+    }
+    Ok(len)
+}
+
+fn load_domains(root: &mut Node, input: &str) -> Result<usize> {
+    let mut len = 0;
+    for line in input.lines() {
+        let name = match line.split('#').next().and_then(|l| l.split_whitespace().next()) {
+            Some(name) => name,
+            None => continue,
+        };
+        if name.starts_with("*.") {
+            if let Some(name) = parse(&name[2..]) {
+                root.insert(&name, true, Policy::NxDomain);
+                len += 1;
+            }
+        } else if let Some(name) = parse(name) {
+            root.insert(&name, false, Policy::NxDomain);
+            root.insert(&name, true, Policy::NxDomain);
+            len += 1;
+        }
+    }
+    Ok(len)
+}
+
+fn load_rpz(root: &mut Node, input: &str) -> Result<usize> {
+    let mut len = 0;
+    let mut apex: Option<RName> = None;
+    let mut reader = Reader::new(input, RName::from_str(".").unwrap());
+
+    while let Some(entry) = reader.next() {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                println!("rpz line {}: {:?}", reader.line(), e);
+                return Err(e.into())
+            }
+        };
+        if entry.rtype == RType::SOA {
+            apex = Some(entry.name);
+            continue
+        }
+        // triggers are owner names relative to the zone apex
+        let trigger = match apex.as_ref().and_then(|apex| entry.name.strip_suffix(apex)) {
+            Some(trigger) => if trigger.is_root() { continue } else { trigger },
+            None => continue,
+        };
+        // only QNAME triggers are supported
+        if trigger.labels().any(|l| l.starts_with(b"rpz-")) {
+            continue
+        }
+
+        let policy = match entry.rtype {
+            RType::CNAME => match entry.rdata.get(0).map(|s| &s[..]) {
+                Some(".") => Policy::NxDomain,
+                Some("*.") => Policy::NoData,
+                Some("rpz-passthru.") => Policy::Pass,
+                Some("rpz-drop.") => Policy::Drop,
+                _ => continue,
+            },
+            RType::A | RType::AAAA => match entry.rdata.get(0).and_then(|s| s.parse::<IpAddr>().ok()) {
+                Some(addr) => Policy::Redirect(vec![addr]),
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        match trigger.parent() {
+            Some(ref parent) if trigger.is_wildcard() => root.insert(parent, true, policy),
+            _ => root.insert(&trigger, false, policy),
+        }
+        len += 1;
+    }
+    Ok(len)
+}
+
+
+#[cfg(test)] use dns::message::MessageBuilder;
+
+#[cfg(test)]
+fn name(s: &str) -> RName {
+    RName::from_str(s).unwrap()
+}
+
+#[cfg(test)]
+fn query(s: &str, rtype: RType) -> Message {
+    MessageBuilder::query(name(s), rtype).id(7).build()
+}
+
+#[test]
+fn block_hosts() {
+    let mut list = Blocklist::new("hosts", Format::Hosts, Policy::Given);
+    list.load("# ad servers\n\
+               127.0.0.1 localhost\n\
+               0.0.0.0 ads.example.com tracker.example.net # trailing\n\
+               0.0.0.0 Metrics.Example.ORG\n").unwrap();
+
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.lookup(&name("ads.example.com")), Some(&Policy::NxDomain));
+    assert_eq!(list.lookup(&name("ADS.example.com.")), Some(&Policy::NxDomain));
+    assert_eq!(list.lookup(&name("metrics.example.org")), Some(&Policy::NxDomain));
+    assert_eq!(list.lookup(&name("www.ads.example.com")), None);
+    assert_eq!(list.lookup(&name("example.com")), None);
+    assert_eq!(list.lookup(&name("localhost")), None);
+}
+
+#[test]
+fn block_domains() {
+    let mut list = Blocklist::new("domains", Format::Domains, Policy::NoData);
+    list.load("tracking.example\n*.cdn.example\n\n# comment\n").unwrap();
+
+    assert_eq!(list.lookup(&name("tracking.example")), Some(&Policy::NoData));
+    assert_eq!(list.lookup(&name("a.b.tracking.example")), Some(&Policy::NoData));
+    assert_eq!(list.lookup(&name("x.cdn.example")), Some(&Policy::NoData));
+    assert_eq!(list.lookup(&name("cdn.example")), None);
+    assert_eq!(list.lookup(&name("example")), None);
+    assert_eq!(list.lookup(&name("nottracking.example")), None);
+}
+
+#[test]
+fn block_rpz() {
+    let mut list = Blocklist::new("rpz", Format::Rpz, Policy::Given);
+    list.load("$TTL 300\n\
+               $ORIGIN rpz.local.\n\
+               @ SOA localhost. root.localhost. 1 3600 600 86400 300\n\
+               @ NS localhost.\n\
+               bad.example CNAME .\n\
+               *.bad.example CNAME .\n\
+               empty.example CNAME *.\n\
+               good.bad.example CNAME rpz-passthru.\n\
+               silent.example CNAME rpz-drop.\n\
+               walled.example A 192.0.2.1\n\
+               \tAAAA 2001:db8::1\n\
+               32.1.2.0.192.rpz-ip CNAME .\n\
+               outside.example. CNAME .\n").unwrap();
+
+    assert_eq!(list.lookup(&name("bad.example")), Some(&Policy::NxDomain));
+    assert_eq!(list.lookup(&name("www.bad.example")), Some(&Policy::NxDomain));
+    assert_eq!(list.lookup(&name("good.bad.example")), Some(&Policy::Pass));
+    assert_eq!(list.lookup(&name("empty.example")), Some(&Policy::NoData));
+    assert_eq!(list.lookup(&name("silent.example")), Some(&Policy::Drop));
+    assert_eq!(list.lookup(&name("walled.example")),
+               Some(&Policy::Redirect(vec!["192.0.2.1".parse().unwrap(), "2001:db8::1".parse().unwrap()])));
+    assert_eq!(list.lookup(&name("outside.example")), None);
+    assert_eq!(list.lookup(&name("example")), None);
+
+    // a list action overrides the policy given by the zone
+    list.action = Policy::NxDomain;
+    assert_eq!(list.lookup(&name("good.bad.example")), Some(&Policy::NxDomain));
+}
+
+#[test]
+fn respond_with_policy() {
+    let redirect = Policy::Redirect(vec!["192.0.2.1".parse().unwrap(), "2001:db8::1".parse().unwrap()]);
+
+    let resp = redirect.respond(&query("ads.example.com", RType::A));
+    assert_eq!(resp.id, 7);
+    assert!(resp.qr);
+    assert_eq!(resp.rcode, RCode::NOERROR);
+    assert_eq!(resp.answers.len(), 1);
+    assert_eq!(resp.answers[0].data, RData::A(192, 0, 2, 1));
+
+    let resp = redirect.respond(&query("ads.example.com", RType::AAAA));
+    assert_eq!(resp.answers[0].data, RData::AAAA(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+
+    let resp = redirect.respond(&query("ads.example.com", RType::MX));
+    assert_eq!(resp.answers, vec![]);
+
+    let resp = Policy::NxDomain.respond(&query("ads.example.com", RType::A));
+    assert_eq!(resp.rcode, RCode::NXDOMAIN);
+    assert_eq!(resp.answers, vec![]);
+}
+
+#[test]
+fn count_hits_in_order() {
+    let mut allow = Blocklist::new("allow", Format::Domains, Policy::Pass);
+    allow.load("partner.ads.example").unwrap();
+    let mut block = Blocklist::new("block", Format::Domains, Policy::NxDomain);
+    block.load("ads.example").unwrap();
+
+    let mut lists = Blocklists::new();
+    lists.push(allow);
+    lists.push(block);
+
+    assert_eq!(lists.check(&name("partner.ads.example")), Some(Policy::Pass));
+    assert_eq!(lists.check(&name("x.ads.example")), Some(Policy::NxDomain));
+    assert_eq!(lists.check(&name("ads.example")), Some(Policy::NxDomain));
+    assert_eq!(lists.check(&name("example")), None);
+
+    let hits: Vec<u64> = lists.lists().iter().map(|l| l.hits()).collect();
+    assert_eq!(hits, vec![1, 2]);
+}
+
+#[test]
+fn reload_from_file() {
+    use std::env;
+    use std::io::Write;
+
+    let path = env::temp_dir().join("reagent-blocklist-test.txt");
+    File::create(&path).unwrap().write_all(b"one.example\n").unwrap();
+
+    let mut list = Blocklist::open("file", Format::Domains, &path, Policy::NxDomain).unwrap();
+    assert!(list.lookup(&name("one.example")).is_some());
+    assert_eq!(list.reload().unwrap(), false);
+
+    File::create(&path).unwrap().write_all(b"two.example\nthree.example\n").unwrap();
+    list.modified = None; // mtime resolution may hide the rewrite
+    assert_eq!(list.reload().unwrap(), true);
+    assert!(list.lookup(&name("one.example")).is_none());
+    assert!(list.lookup(&name("three.example")).is_some());
+    assert_eq!(list.len(), 2);
+
+    fs::remove_file(&path).unwrap();
+    assert!(list.reload().is_err());
+    assert!(list.lookup(&name("two.example")).is_some());
+}
+
+#[test]
+fn keep_serving_broken_files() {
+    use std::env;
+    use std::io::Write;
+
+    let path = env::temp_dir().join("reagent-blocklist-broken.rpz");
+    File::create(&path).unwrap().write_all(b"$ORIGIN rpz.local.\n@ SOA . . 1 1 1 1 1\nbad CNAME .\n").unwrap();
+    let mut list = Blocklist::open("rpz", Format::Rpz, &path, Policy::Given).unwrap();
+    let mut lists = Blocklists::new();
+    lists.push(list.clone());
+
+    // a broken file fails once and is not read again until it changes
+    File::create(&path).unwrap().write_all(b"bad IN A not-an-address\n").unwrap();
+    list.modified = None;
+    assert!(list.reload().is_err());
+    assert_eq!(list.reload().unwrap(), false);
+    assert_eq!(list.lookup(&name("bad")), Some(&Policy::NxDomain));
+
+    // the same failure is remembered rather than reported every tick
+    fs::remove_file(&path).unwrap();
+    lists.reload();
+    let failure = lists.lists()[0].failure.clone();
+    assert!(failure.is_some());
+    lists.reload();
+    assert_eq!(lists.lists()[0].failure, failure);
+    assert!(lists.check(&name("bad")).is_some());
+}
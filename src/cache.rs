@@ -0,0 +1,288 @@
+use std::cmp;
+use std::collections::HashMap;
+
+use dns::{RCode, RType, RName};
+use dns::view::MessageView;
+
+/// Response cache settings.
+#[derive(Clone, Copy, Debug)]
+pub struct CacheConfig {
+    pub max_entries: usize,
+    /// Upper bound in seconds on how long any response is kept.
+    pub max_ttl: u32,
+}
+
+impl Default for CacheConfig {
+    fn default() -> CacheConfig {
+        CacheConfig{
+            max_entries: 10_000,
+            max_ttl: 86400,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub inserts: u64,
+    /// Entries removed to make room before they expired.
+    pub evictions: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Key {
+    view: usize,
+    qname: RName,
+    qtype: u16,
+    class: u16,
+    // only requests with EDNS get responses with OPT (RFC 6891 §7)
+    edns: bool,
+    dnssec_ok: bool,
+}
+
+struct Entry {
+    msg: Vec<u8>,
+    // offsets of the TTL fields to age on every hit
+    ttls: Vec<usize>,
+    stored: u64,
+    expires: u64,
+}
+
+/// Caches upstream responses as received, keyed by view and question, and
+/// ages their TTLs when served.
+pub struct Cache {
+    config: CacheConfig,
+    entries: HashMap<Key, Entry>,
+    stats: CacheStats,
+}
+
+impl Cache {
+
+    pub fn new(config: CacheConfig) -> Cache {
+        Cache{
+            config: config,
+            entries: HashMap::new(),
+            stats: CacheStats::default(),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize { self.entries.len() }
+
+    #[inline]
+    pub fn stats(&self) -> &CacheStats { &self.stats }
+
+    /// Looks up the answer to `req` in `view`, returning it with the id of
+    /// the request and TTLs reduced by the time it has been cached.
+    pub fn get(&mut self, view: usize, req: &MessageView, now: u64) -> Option<Vec<u8>> {
+        let found = match key(view, req) {
+            Some(key) => match self.entries.get(&key) {
+                Some(entry) if entry.expires > now => {
+                    let mut msg = entry.msg.clone();
+                    let age = (now - entry.stored) as u32;
+                    for &off in entry.ttls.iter() {
+                        let ttl = (msg[off] as u32) << 24 | (msg[off+1] as u32) << 16 |
+                                  (msg[off+2] as u32) << 8 | msg[off+3] as u32;
+                        let ttl = ttl.saturating_sub(age);
+                        msg[off] = (ttl >> 24) as u8;
+                        msg[off+1] = (ttl >> 16) as u8;
+                        msg[off+2] = (ttl >> 8) as u8;
+                        msg[off+3] = ttl as u8;
+                    }
+                    msg[0] = (req.id() >> 8) as u8;
+                    msg[1] = req.id() as u8;
+                    Some(msg)
+                }
+                _ => None,
+            },
+            None => None,
+        };
+        match found {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        found
+    }
+
+    /// Stores a response received for `view`. Only complete NOERROR and
+    /// NXDOMAIN responses holding at least one record are cached, for the
+    /// lowest TTL among their answer and authority records.
+    pub fn insert(&mut self, view: usize, msg: &[u8], now: u64) {
+        if self.config.max_entries == 0 {
+            return
+        }
+        let resp = match MessageView::new(msg) {
+            Ok(resp) => resp,
+            Err(_) => return,
+        };
+        let rcode = resp.rcode();
+        if resp.tc() || (rcode != Ok(RCode::NOERROR) && rcode != Ok(RCode::NXDOMAIN)) {
+            return
+        }
+        let ttl = resp.answers().chain(resp.authority()).map(|r| r.ttl).min();
+        let ttl = match ttl {
+            Some(ttl) if ttl > 0 => cmp::min(ttl, self.config.max_ttl),
+            _ => return,
+        };
+        let key = match key(view, &resp) {
+            Some(key) => key,
+            None => return,
+        };
+        // the TTLs of every record but OPT, whose TTL holds flags
+        let ttls = resp.answers().chain(resp.authority()).chain(resp.additionals())
+            .filter(|r| r.rtype != RType::OPT as u16)
+            .map(|r| r.ttl_offset())
+            .collect();
+
+        if self.entries.len() >= self.config.max_entries && !self.entries.contains_key(&key) {
+            self.prune(now);
+            if self.entries.len() >= self.config.max_entries {
+                self.evict();
+            }
+        }
+        self.entries.insert(key, Entry{
+            msg: msg.to_vec(),
+            ttls: ttls,
+            stored: now,
+            expires: now + ttl as u64,
+        });
+        self.stats.inserts += 1;
+    }
+
+    /// Drops expired entries.
+    pub fn prune(&mut self, now: u64) {
+        self.entries.retain(|_, e| e.expires > now);
+    }
+
+    // Makes room by dropping the entries closest to expiry.
+    fn evict(&mut self) {
+        let mut expires: Vec<u64> = self.entries.values().map(|e| e.expires).collect();
+        expires.sort();
+        let cutoff = expires[self.entries.len() - self.config.max_entries / 2 - 1];
+        let before = self.entries.len();
+        self.entries.retain(|_, e| e.expires > cutoff);
+        self.stats.evictions += (before - self.entries.len()) as u64;
+    }
+}
+
+fn key(view: usize, msg: &MessageView) -> Option<Key> {
+    match msg.questions().next().map(|q| (q, q.name.to_rname())) {
+        Some((q, Ok(qname))) => Some(Key{
+            view: view,
+            qname: qname,
+            qtype: q.rtype,
+            class: q.class,
+            edns: msg.additionals().any(|r| r.rtype == RType::OPT as u16),
+            dnssec_ok: msg.dnssec_ok(),
+        }),
+        _ => None,
+    }
+}
+
+
+#[cfg(test)] use rustc_serialize::hex::FromHex;
+#[cfg(test)] use dns::Message;
+#[cfg(test)] use dns::message::Edns;
+
+#[cfg(test)]
+fn www_reddit_com() -> Vec<u8> {
+    // www.reddit.com IN A answered with a CNAME (TTL 300) and an A record (TTL 299)
+    "f13a81800001000200000000037777770672656464697403636f6d0000010001\
+     c00c000500010000012c001706726564646974036d617006666173746c79036e657400\
+     c02c000100010000012b00049765018c".from_hex().unwrap()
+}
+
+#[cfg(test)]
+fn request(msg: &[u8], id: u16, edns: Option<Edns>) -> Vec<u8> {
+    let mut req = Message::unpack(msg, 0).unwrap();
+    req.id = id;
+    req.qr = false;
+    req.answers.clear();
+    req.edns = edns;
+    let mut buf = vec![0; 512];
+    let len = req.pack(&mut buf, 0).unwrap();
+    buf.truncate(len);
+    buf
+}
+
+#[test]
+fn cache_and_age_responses() {
+    let mut cache = Cache::new(CacheConfig::default());
+    let resp = www_reddit_com();
+    let req = request(&resp, 7, None);
+    let req = MessageView::new(&req).unwrap();
+
+    assert_eq!(cache.get(0, &req, 100), None);
+    cache.insert(0, &resp, 100);
+    assert_eq!(cache.len(), 1);
+
+    // other views have their own answers
+    assert_eq!(cache.get(1, &req, 100), None);
+
+    let hit = cache.get(0, &req, 130).unwrap();
+    let msg = Message::unpack(&hit, 0).unwrap();
+    assert_eq!(msg.id, 7);
+    assert_eq!(msg.answers.iter().map(|r| r.ttl).collect::<Vec<u32>>(), vec![270, 269]);
+
+    // gone once the lowest TTL has passed
+    assert!(cache.get(0, &req, 398).is_some());
+    assert_eq!(cache.get(0, &req, 399), None);
+
+    assert_eq!(cache.stats(), &CacheStats{ hits: 2, misses: 3, inserts: 1, evictions: 0 });
+}
+
+#[test]
+fn match_edns_of_requests() {
+    let mut cache = Cache::new(CacheConfig::default());
+    let plain = www_reddit_com();
+    let mut with_opt = Message::unpack(&plain, 0).unwrap();
+    with_opt.edns = Some(Edns::default());
+    let mut buf = [0; 512];
+    let len = with_opt.pack(&mut buf, 0).unwrap();
+    let with_opt = buf[..len].to_vec();
+
+    let req = request(&plain, 7, None);
+    let req = MessageView::new(&req).unwrap();
+    let edns_req = request(&plain, 7, Some(Edns::default()));
+    let edns_req = MessageView::new(&edns_req).unwrap();
+
+    // an answer with OPT only goes to requests with EDNS, and the other way round
+    cache.insert(0, &with_opt, 100);
+    assert_eq!(cache.get(0, &req, 100), None);
+    assert!(cache.get(0, &edns_req, 100).is_some());
+    cache.insert(0, &plain, 100);
+    assert_eq!(cache.get(0, &req, 100).map(|msg| msg.len()), Some(plain.len()));
+    assert_eq!(cache.get(0, &edns_req, 100).map(|msg| msg.len()), Some(with_opt.len()));
+}
+
+#[test]
+fn skip_uncacheable_responses() {
+    let mut cache = Cache::new(CacheConfig::default());
+    let resp = www_reddit_com();
+
+    let mut truncated = resp.clone();
+    truncated[2] |= 0x02;
+    cache.insert(0, &truncated, 0);
+
+    let mut servfail = resp.clone();
+    servfail[3] = 0x82;
+    cache.insert(0, &servfail, 0);
+
+    cache.insert(0, &resp[..resp.len() - 2], 0);
+    assert_eq!(cache.len(), 0);
+}
+
+#[test]
+fn evict_when_full() {
+    let mut cache = Cache::new(CacheConfig{ max_entries: 4, ..CacheConfig::default() });
+    let resp = www_reddit_com();
+    for view in 0..10 {
+        cache.insert(view, &resp, view as u64);
+    }
+    assert!(cache.len() <= 4);
+    assert!(cache.stats().evictions > 0);
+    // the newest entry survives
+    let req = request(&resp, 1, None);
+    assert!(cache.get(9, &MessageView::new(&req).unwrap(), 10).is_some());
+}
@@ -0,0 +1,406 @@
+/// A hash function fed in pieces.
+pub trait Hash {
+    /// Bytes per input block, the key size of HMAC.
+    fn block_len() -> usize;
+    fn new() -> Self;
+    fn update(&mut self, data: &[u8]);
+    fn finish(self) -> Vec<u8>;
+
+    fn digest(data: &[u8]) -> Vec<u8> where Self: Sized {
+        let mut h = Self::new();
+        h.update(data);
+        h.finish()
+    }
+}
+
+/// HMAC (RFC 2104) of the concatenation of `parts` under `key`.
+pub fn hmac<H: Hash>(key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+    let mut k = if key.len() > H::block_len() { H::digest(key) } else { key.to_vec() };
+    k.resize(H::block_len(), 0);
+
+    let mut inner = H::new();
+    inner.update(&k.iter().map(|b| b ^ 0x36).collect::<Vec<u8>>());
+    for part in parts {
+        inner.update(part);
+    }
+    let mut outer = H::new();
+    outer.update(&k.iter().map(|b| b ^ 0x5c).collect::<Vec<u8>>());
+    outer.update(&inner.finish());
+    outer.finish()
+}
+
+/// Compares two MACs in time independent of where they differ.
+pub fn verify(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).fold(0, |d, (x, y)| d | (x ^ y)) == 0
+}
+
+const K256: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const K512: [u64; 80] = [
+    0x428a2f98d728ae22, 0x7137449123ef65cd, 0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc,
+    0x3956c25bf348b538, 0x59f111f1b605d019, 0x923f82a4af194f9b, 0xab1c5ed5da6d8118,
+    0xd807aa98a3030242, 0x12835b0145706fbe, 0x243185be4ee4b28c, 0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f, 0x80deb1fe3b1696b1, 0x9bdc06a725c71235, 0xc19bf174cf692694,
+    0xe49b69c19ef14ad2, 0xefbe4786384f25e3, 0x0fc19dc68b8cd5b5, 0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275, 0x4a7484aa6ea6e483, 0x5cb0a9dcbd41fbd4, 0x76f988da831153b5,
+    0x983e5152ee66dfab, 0xa831c66d2db43210, 0xb00327c898fb213f, 0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2, 0xd5a79147930aa725, 0x06ca6351e003826f, 0x142929670a0e6e70,
+    0x27b70a8546d22ffc, 0x2e1b21385c26c926, 0x4d2c6dfc5ac42aed, 0x53380d139d95b3df,
+    0x650a73548baf63de, 0x766a0abb3c77b2a8, 0x81c2c92e47edaee6, 0x92722c851482353b,
+    0xa2bfe8a14cf10364, 0xa81a664bbc423001, 0xc24b8b70d0f89791, 0xc76c51a30654be30,
+    0xd192e819d6ef5218, 0xd69906245565a910, 0xf40e35855771202a, 0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8, 0x1e376c085141ab53, 0x2748774cdf8eeb99, 0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63, 0x4ed8aa4ae3418acb, 0x5b9cca4f7763e373, 0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc, 0x78a5636f43172f60, 0x84c87814a1f0ab72, 0x8cc702081a6439ec,
+    0x90befffa23631e28, 0xa4506cebde82bde9, 0xbef9a3f7b2c67915, 0xc67178f2e372532b,
+    0xca273eceea26619c, 0xd186b8c721c0c207, 0xeada7dd6cde0eb1e, 0xf57d4f7fee6ed178,
+    0x06f067aa72176fba, 0x0a637dc5a2c898a6, 0x113f9804bef90dae, 0x1b710b35131c471b,
+    0x28db77f523047d84, 0x32caab7b40c72493, 0x3c9ebe0a15c9bebc, 0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6, 0x597f299cfc657e2a, 0x5fcb6fab3ad6faec, 0x6c44198c4a475817,
+];
+
+/// SHA-1 (FIPS 180-4 §6.1), still needed for NSEC3 hashes and older
+/// DS digests.
+pub struct Sha1 {
+    state: [u32; 5],
+    block: Vec<u8>,
+    len: u64,
+}
+
+impl Sha1 {
+
+    fn compress(&mut self) {
+        let mut w = [0u32; 80];
+        for i in 0..16 {
+            w[i] = (self.block[i * 4] as u32) << 24 | (self.block[i * 4 + 1] as u32) << 16 |
+                   (self.block[i * 4 + 2] as u32) << 8 | self.block[i * 4 + 3] as u32;
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let mut v = self.state;
+        for i in 0..80 {
+            let (f, k) = match i / 20 {
+                0 => ((v[1] & v[2]) | (!v[1] & v[3]), 0x5a827999),
+                1 => (v[1] ^ v[2] ^ v[3], 0x6ed9eba1),
+                2 => ((v[1] & v[2]) | (v[1] & v[3]) | (v[2] & v[3]), 0x8f1bbcdc),
+                _ => (v[1] ^ v[2] ^ v[3], 0xca62c1d6),
+            };
+            let t = v[0].rotate_left(5).wrapping_add(f).wrapping_add(v[4]).wrapping_add(k).wrapping_add(w[i]);
+            v = [t, v[0], v[1].rotate_left(30), v[2], v[3]];
+        }
+        for i in 0..5 {
+            self.state[i] = self.state[i].wrapping_add(v[i]);
+        }
+        self.block.clear();
+    }
+}
+
+impl Hash for Sha1 {
+
+    fn block_len() -> usize { 64 }
+
+    fn new() -> Sha1 {
+        Sha1{
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0],
+            block: Vec::with_capacity(64),
+            len: 0,
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.len += data.len() as u64;
+        for &b in data {
+            self.block.push(b);
+            if self.block.len() == 64 {
+                self.compress();
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let bits = self.len * 8;
+        self.block.push(0x80);
+        if self.block.len() > 56 {
+            self.block.resize(64, 0);
+            self.compress();
+        }
+        self.block.resize(56, 0);
+        for i in 0..8 {
+            self.block.push((bits >> (56 - i * 8)) as u8);
+        }
+        self.compress();
+
+        let mut out = Vec::with_capacity(20);
+        for word in self.state.iter() {
+            out.extend(&[(word >> 24) as u8, (word >> 16) as u8, (word >> 8) as u8, *word as u8]);
+        }
+        out
+    }
+}
+
+/// SHA-256 (FIPS 180-4 §6.2).
+pub struct Sha256 {
+    state: [u32; 8],
+    block: Vec<u8>,
+    len: u64,
+}
+
+impl Sha256 {
+
+    fn compress(&mut self) {
+        let mut w = [0u32; 64];
+        for i in 0..16 {
+            w[i] = (self.block[i * 4] as u32) << 24 | (self.block[i * 4 + 1] as u32) << 16 |
+                   (self.block[i * 4 + 2] as u32) << 8 | self.block[i * 4 + 3] as u32;
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let mut v = self.state;
+        for i in 0..64 {
+            let s1 = v[4].rotate_right(6) ^ v[4].rotate_right(11) ^ v[4].rotate_right(25);
+            let ch = (v[4] & v[5]) ^ (!v[4] & v[6]);
+            let t1 = v[7].wrapping_add(s1).wrapping_add(ch).wrapping_add(K256[i]).wrapping_add(w[i]);
+            let s0 = v[0].rotate_right(2) ^ v[0].rotate_right(13) ^ v[0].rotate_right(22);
+            let maj = (v[0] & v[1]) ^ (v[0] & v[2]) ^ (v[1] & v[2]);
+            let t2 = s0.wrapping_add(maj);
+            v = [t1.wrapping_add(t2), v[0], v[1], v[2], v[3].wrapping_add(t1), v[4], v[5], v[6]];
+        }
+        for i in 0..8 {
+            self.state[i] = self.state[i].wrapping_add(v[i]);
+        }
+        self.block.clear();
+    }
+}
+
+impl Hash for Sha256 {
+
+    fn block_len() -> usize { 64 }
+
+    fn new() -> Sha256 {
+        Sha256{
+            state: [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a,
+                    0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19],
+            block: Vec::with_capacity(64),
+            len: 0,
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.len += data.len() as u64;
+        for &b in data {
+            self.block.push(b);
+            if self.block.len() == 64 {
+                self.compress();
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let bits = self.len * 8;
+        self.block.push(0x80);
+        if self.block.len() > 56 {
+            self.block.resize(64, 0);
+            self.compress();
+        }
+        self.block.resize(56, 0);
+        for i in 0..8 {
+            self.block.push((bits >> (56 - i * 8)) as u8);
+        }
+        self.compress();
+
+        let mut out = Vec::with_capacity(32);
+        for word in self.state.iter() {
+            out.extend(&[(word >> 24) as u8, (word >> 16) as u8, (word >> 8) as u8, *word as u8]);
+        }
+        out
+    }
+}
+
+/// SHA-512 (FIPS 180-4 §6.4).
+pub struct Sha512 {
+    state: [u64; 8],
+    block: Vec<u8>,
+    len: u64,
+}
+
+impl Sha512 {
+
+    fn compress(&mut self) {
+        let mut w = [0u64; 80];
+        for i in 0..16 {
+            w[i] = self.block[i * 8..i * 8 + 8].iter().fold(0, |w, &b| w << 8 | b as u64);
+        }
+        for i in 16..80 {
+            let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+            let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let mut v = self.state;
+        for i in 0..80 {
+            let s1 = v[4].rotate_right(14) ^ v[4].rotate_right(18) ^ v[4].rotate_right(41);
+            let ch = (v[4] & v[5]) ^ (!v[4] & v[6]);
+            let t1 = v[7].wrapping_add(s1).wrapping_add(ch).wrapping_add(K512[i]).wrapping_add(w[i]);
+            let s0 = v[0].rotate_right(28) ^ v[0].rotate_right(34) ^ v[0].rotate_right(39);
+            let maj = (v[0] & v[1]) ^ (v[0] & v[2]) ^ (v[1] & v[2]);
+            let t2 = s0.wrapping_add(maj);
+            v = [t1.wrapping_add(t2), v[0], v[1], v[2], v[3].wrapping_add(t1), v[4], v[5], v[6]];
+        }
+        for i in 0..8 {
+            self.state[i] = self.state[i].wrapping_add(v[i]);
+        }
+        self.block.clear();
+    }
+}
+
+impl Hash for Sha512 {
+
+    fn block_len() -> usize { 128 }
+
+    fn new() -> Sha512 {
+        Sha512{
+            state: [0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+                    0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179],
+            block: Vec::with_capacity(128),
+            len: 0,
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.len += data.len() as u64;
+        for &b in data {
+            self.block.push(b);
+            if self.block.len() == 128 {
+                self.compress();
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let bits = self.len * 8;
+        self.block.push(0x80);
+        if self.block.len() > 112 {
+            self.block.resize(128, 0);
+            self.compress();
+        }
+        // the upper 64 bits of the 128 bit length stay zero
+        self.block.resize(120, 0);
+        for i in 0..8 {
+            self.block.push((bits >> (56 - i * 8)) as u8);
+        }
+        self.compress();
+
+        let mut out = Vec::with_capacity(64);
+        for word in self.state.iter() {
+            for i in 0..8 {
+                out.push((word >> (56 - i * 8)) as u8);
+            }
+        }
+        out
+    }
+}
+
+/// SHA-384 (FIPS 180-4 §6.5), SHA-512 with other initial values cut
+/// to 48 bytes.
+pub struct Sha384(Sha512);
+
+impl Hash for Sha384 {
+
+    fn block_len() -> usize { 128 }
+
+    fn new() -> Sha384 {
+        let mut h = Sha512::new();
+        h.state = [0xcbbb9d5dc1059ed8, 0x629a292a367cd507, 0x9159015a3070dd17, 0x152fecd8f70e5939,
+                   0x67332667ffc00b31, 0x8eb44a8768581511, 0xdb0c2e0d64f98fa7, 0x47b5481dbefa4fa4];
+        Sha384(h)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data)
+    }
+
+    fn finish(self) -> Vec<u8> {
+        let mut out = self.0.finish();
+        out.truncate(48);
+        out
+    }
+}
+
+
+#[cfg(test)] use rustc_serialize::hex::{FromHex, ToHex};
+
+#[test]
+fn sha2_test_vectors() {
+    // FIPS 180-4 examples
+    let long = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+    assert_eq!(Sha256::digest(b"").to_hex(),
+               "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    assert_eq!(Sha256::digest(b"abc").to_hex(),
+               "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    assert_eq!(Sha256::digest(long).to_hex(),
+               "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+    assert_eq!(Sha512::digest(b"abc").to_hex(),
+               "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f");
+    assert_eq!(Sha512::digest(b"").to_hex(),
+               "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce\
+                47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e");
+
+    assert_eq!(Sha384::digest(b"abc").to_hex(),
+               "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed\
+                8086072ba1e7cc2358baeca134c825a7");
+    assert_eq!(Sha1::digest(b"abc").to_hex(), "a9993e364706816aba3e25717850c26c9cd0d89d");
+    assert_eq!(Sha1::digest(long).to_hex(), "84983e441c3bd26ebaae4aa1f95129e5e54670f1");
+
+    // fed in pieces across block boundaries
+    let million = vec![b'a'; 1000000];
+    let mut h = Sha256::new();
+    for chunk in million.chunks(997) {
+        h.update(chunk);
+    }
+    assert_eq!(h.finish().to_hex(), "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0");
+    let mut h = Sha1::new();
+    for chunk in million.chunks(997) {
+        h.update(chunk);
+    }
+    assert_eq!(h.finish().to_hex(), "34aa973cd4c4daa4f61eeb2bdbad27316534016f");
+}
+
+#[test]
+fn hmac_test_vectors() {
+    // RFC 4231 test cases 1, 2 and 6
+    let key = vec![0x0b; 20];
+    assert_eq!(hmac::<Sha256>(&key, &[b"Hi There"]).to_hex(),
+               "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7");
+    assert_eq!(hmac::<Sha512>(&key, &[b"Hi ", b"There"]).to_hex(),
+               "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cde\
+                daa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854");
+    assert_eq!(hmac::<Sha256>(b"Jefe", &[b"what do ya want for nothing?"]).to_hex(),
+               "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+    let key = vec![0xaa; 131];
+    let data = b"Test Using Larger Than Block-Size Key - Hash Key First";
+    assert_eq!(hmac::<Sha256>(&key, &[data]).to_hex(),
+               "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54");
+    assert_eq!(hmac::<Sha512>(&key, &[data]).to_hex(),
+               "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f352\
+                6b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598");
+
+    assert!(verify(&"0102".from_hex().unwrap(), &[1, 2]));
+    assert!(!verify(&[1, 2], &[1, 3]));
+    assert!(!verify(&[1, 2], &[1]));
+}
@@ -0,0 +1,63 @@
+use dns::{Error, Result};
+
+const ALPHABET: &'static [u8] = b"0123456789abcdefghijklmnopqrstuv";
+
+/// Base32 with the extended hex alphabet and without padding (RFC 4648
+/// §7), as NSEC3 hashes are written in owner names and records.
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() * 8 + 4) / 5);
+    let mut n = 0u32;
+    let mut bits = 0;
+    for &b in data.iter() {
+        n = n << 8 | b as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(ALPHABET[(n >> bits) as usize & 0x1f] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(ALPHABET[(n << (5 - bits)) as usize & 0x1f] as char);
+    }
+    out
+}
+
+/// Decodes base32hex in either case. Trailing bits that do not make up
+/// a whole byte must be zero.
+pub fn decode(s: &str) -> Result<Vec<u8>> {
+    let mut out = Vec::with_capacity(s.len() * 5 / 8);
+    let mut n = 0u32;
+    let mut bits = 0;
+    for c in s.bytes() {
+        let v = match c {
+            b'0'...b'9' => c - b'0',
+            b'a'...b'v' => c - b'a' + 10,
+            b'A'...b'V' => c - b'A' + 10,
+            _ => return Err(Error::BadRdata),
+        };
+        n = n << 5 | v as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((n >> bits) as u8);
+        }
+    }
+    if bits >= 5 || n & ((1 << bits) - 1) != 0 {
+        return Err(Error::BadRdata)
+    }
+    Ok(out)
+}
+
+
+#[test]
+fn base32_roundtrip() {
+    // RFC 4648 §10, lowercased and unpadded
+    for &(data, text) in [("", ""), ("f", "co"), ("fo", "cpng"), ("foo", "cpnmu"),
+                          ("foob", "cpnmuog"), ("fooba", "cpnmuoj1"), ("foobar", "cpnmuoj1e8")].iter() {
+        assert_eq!(encode(data.as_bytes()), text);
+        assert_eq!(decode(text).unwrap(), data.as_bytes());
+    }
+    assert_eq!(decode("CPNMUOJ1E8").unwrap(), b"foobar");
+    assert_eq!(decode("cpnmw"), Err(Error::BadRdata));
+    assert_eq!(decode("cp"), Err(Error::BadRdata));
+}
@@ -0,0 +1,67 @@
+use dns::{Error, Result};
+
+const ALPHABET: &'static [u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Base64 with padding (RFC 4648 §4), as keys and signatures are written
+/// in master files.
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - i * 8));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - i * 6)) as usize & 0x3f] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Decodes base64, skipping whitespace as records split over several
+/// lines have it.
+pub fn decode(s: &str) -> Result<Vec<u8>> {
+    let mut out = Vec::with_capacity(s.len() * 3 / 4);
+    let mut n = 0u32;
+    let mut bits = 0;
+    let mut padding = 0;
+    for c in s.bytes().filter(|c| !(*c as char).is_whitespace()) {
+        let v = match c {
+            b'A'...b'Z' => c - b'A',
+            b'a'...b'z' => c - b'a' + 26,
+            b'0'...b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => { padding += 1; continue }
+            _ => return Err(Error::BadRdata),
+        };
+        if padding > 0 {
+            return Err(Error::BadRdata)
+        }
+        n = n << 6 | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((n >> bits) as u8);
+        }
+    }
+    if padding > 2 || (bits + padding * 6) % 8 != 0 && padding > 0 {
+        return Err(Error::BadRdata)
+    }
+    Ok(out)
+}
+
+
+#[test]
+fn base64_roundtrip() {
+    // RFC 4648 §10
+    for &(data, text) in [("", ""), ("f", "Zg=="), ("fo", "Zm8="), ("foo", "Zm9v"),
+                          ("foob", "Zm9vYg=="), ("fooba", "Zm9vYmE="), ("foobar", "Zm9vYmFy")].iter() {
+        assert_eq!(encode(data.as_bytes()), text);
+        assert_eq!(decode(text).unwrap(), data.as_bytes());
+    }
+    assert_eq!(decode("Zm9v\n YmFy").unwrap(), b"foobar");
+    assert_eq!(decode("Zm9v!"), Err(Error::BadRdata));
+    assert_eq!(decode("Zg==Zg"), Err(Error::BadRdata));
+}
@@ -0,0 +1,729 @@
+use std::fmt;
+use std::str::FromStr;
+
+use dns::{Error, Result, RName, RType};
+use dns::{base32, base64};
+use dns::master::Fields;
+
+/// The zone key flag of a DNSKEY (RFC 4034 §2.1.1).
+pub const ZONE_KEY: u16 = 0x0100;
+/// The secure entry point flag, set on key signing keys (RFC 4034 §2.1.1).
+pub const SEP: u16 = 0x0001;
+/// The only protocol value a DNSKEY may carry (RFC 4034 §2.1.2).
+pub const PROTOCOL: u8 = 3;
+
+/// A public key of a zone (RFC 4034 §2), also the data of CDNSKEY.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Dnskey {
+    pub flags: u16,
+    pub protocol: u8,
+    pub algorithm: u8,
+    pub public_key: Vec<u8>,
+}
+
+impl Dnskey {
+
+    pub fn len(&self) -> usize { 4 + self.public_key.len() }
+
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if offset + self.len() > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        put_u16(buf, offset, self.flags);
+        buf[offset + 2] = self.protocol;
+        buf[offset + 3] = self.algorithm;
+        buf[offset + 4..offset + self.len()].copy_from_slice(&self.public_key);
+        Ok(offset + self.len())
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Dnskey> {
+        if rdata.len() < 4 {
+            return Err(Error::BadRdata)
+        }
+        Ok(Dnskey{
+            flags: get_u16(rdata, 0),
+            protocol: rdata[2],
+            algorithm: rdata[3],
+            public_key: rdata[4..].to_vec(),
+        })
+    }
+
+    /// Reads `flags protocol algorithm base64...`.
+    pub fn parse(t: &[String]) -> Result<Dnskey> {
+        let mut t = Fields::new(t);
+        Ok(Dnskey{
+            flags: try!(t.number()),
+            protocol: try!(t.number()),
+            algorithm: try!(t.number()),
+            public_key: try!(t.rest(base64::decode)),
+        })
+    }
+
+    /// The tag by which signatures and DS records refer to this key
+    /// (RFC 4034 Appendix B).
+    pub fn key_tag(&self) -> u16 {
+        let mut wire = vec![0; self.len()];
+        self.pack(&mut wire, 0).unwrap();
+        if self.algorithm == 1 {
+            // RSA/MD5 keys use the low bits of the modulus instead
+            let n = wire.len();
+            return if n >= 7 { get_u16(&wire, n - 3) } else { 0 }
+        }
+        let mut ac: u32 = 0;
+        for (i, &b) in wire.iter().enumerate() {
+            ac += if i & 1 == 0 { (b as u32) << 8 } else { b as u32 };
+        }
+        ac += ac >> 16;
+        ac as u16
+    }
+}
+
+impl fmt::Display for Dnskey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.flags, self.protocol, self.algorithm, base64::encode(&self.public_key))
+    }
+}
+
+/// A digest of a child zone's key held by its parent (RFC 4034 §5), also
+/// the data of CDS.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ds {
+    pub key_tag: u16,
+    pub algorithm: u8,
+    pub digest_type: u8,
+    pub digest: Vec<u8>,
+}
+
+impl Ds {
+
+    pub fn len(&self) -> usize { 4 + self.digest.len() }
+
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if offset + self.len() > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        put_u16(buf, offset, self.key_tag);
+        buf[offset + 2] = self.algorithm;
+        buf[offset + 3] = self.digest_type;
+        buf[offset + 4..offset + self.len()].copy_from_slice(&self.digest);
+        Ok(offset + self.len())
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Ds> {
+        if rdata.len() < 4 {
+            return Err(Error::BadRdata)
+        }
+        Ok(Ds{
+            key_tag: get_u16(rdata, 0),
+            algorithm: rdata[2],
+            digest_type: rdata[3],
+            digest: rdata[4..].to_vec(),
+        })
+    }
+
+    /// Reads `key-tag algorithm digest-type hex...`.
+    pub fn parse(t: &[String]) -> Result<Ds> {
+        let mut t = Fields::new(t);
+        Ok(Ds{
+            key_tag: try!(t.number()),
+            algorithm: try!(t.number()),
+            digest_type: try!(t.number()),
+            digest: try!(t.rest(hex)),
+        })
+    }
+}
+
+impl fmt::Display for Ds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digest: Vec<String> = self.digest.iter().map(|b| format!("{:02X}", b)).collect();
+        write!(f, "{} {} {} {}", self.key_tag, self.algorithm, self.digest_type, digest.concat())
+    }
+}
+
+/// A signature over an RRset (RFC 4034 §3). Times are seconds since the
+/// epoch, compared in serial number arithmetic.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Rrsig {
+    pub type_covered: u16,
+    pub algorithm: u8,
+    pub labels: u8,
+    pub original_ttl: u32,
+    pub expiration: u32,
+    pub inception: u32,
+    pub key_tag: u16,
+    pub signer: RName,
+    pub signature: Vec<u8>,
+}
+
+impl Rrsig {
+
+    pub fn len(&self) -> usize { 18 + self.signer.len() + 1 + self.signature.len() }
+
+    /// Packs the data; the signer's name is never compressed.
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        let off = try!(self.pack_fields(buf, offset));
+        if off + self.signature.len() > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        buf[off..off + self.signature.len()].copy_from_slice(&self.signature);
+        Ok(off + self.signature.len())
+    }
+
+    /// Packs everything but the signature, the prefix of the data a
+    /// signature is computed over (RFC 4034 §3.1.8.1).
+    pub fn pack_fields(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if offset + 18 > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        put_u16(buf, offset, self.type_covered);
+        buf[offset + 2] = self.algorithm;
+        buf[offset + 3] = self.labels;
+        put_u32(buf, offset + 4, self.original_ttl);
+        put_u32(buf, offset + 8, self.expiration);
+        put_u32(buf, offset + 12, self.inception);
+        put_u16(buf, offset + 16, self.key_tag);
+        self.signer.pack(buf, offset + 18)
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Rrsig> {
+        if rdata.len() < 19 {
+            return Err(Error::BadRdata)
+        }
+        let (signer, off) = try!(RName::unpack(rdata, 18));
+        Ok(Rrsig{
+            type_covered: get_u16(rdata, 0),
+            algorithm: rdata[2],
+            labels: rdata[3],
+            original_ttl: get_u32(rdata, 4),
+            expiration: get_u32(rdata, 8),
+            inception: get_u32(rdata, 12),
+            key_tag: get_u16(rdata, 16),
+            signer: signer,
+            signature: rdata[off..].to_vec(),
+        })
+    }
+
+    /// Reads `type algorithm labels ttl expiration inception key-tag
+    /// signer base64...`.
+    pub fn parse(t: &[String], origin: &RName) -> Result<Rrsig> {
+        let mut t = Fields::new(t);
+        Ok(Rrsig{
+            type_covered: try!(t.field(parse_type)),
+            algorithm: try!(t.number()),
+            labels: try!(t.number()),
+            original_ttl: try!(t.number()),
+            expiration: try!(t.field(parse_time)),
+            inception: try!(t.field(parse_time)),
+            key_tag: try!(t.number()),
+            signer: try!(t.name(origin)),
+            signature: try!(t.rest(base64::decode)),
+        })
+    }
+}
+
+impl fmt::Display for Rrsig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {} {} {} {} {} {}", type_name(self.type_covered), self.algorithm, self.labels,
+               self.original_ttl, format_time(self.expiration), format_time(self.inception),
+               self.key_tag, self.signer, base64::encode(&self.signature))
+    }
+}
+
+/// The next owner name in a zone and the types present at this one
+/// (RFC 4034 §4).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Nsec {
+    pub next: RName,
+    pub types: Vec<u16>,
+}
+
+impl Nsec {
+
+    pub fn len(&self) -> usize { self.next.len() + 1 + bitmap(&self.types).len() }
+
+    /// Packs the data; the next name is never compressed.
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        let off = try!(self.next.pack(buf, offset));
+        put_bytes(buf, off, &bitmap(&self.types))
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Nsec> {
+        let (next, off) = try!(RName::unpack(rdata, 0));
+        Ok(Nsec{
+            next: next,
+            types: try!(unpack_bitmap(&rdata[off..])),
+        })
+    }
+
+    /// Reads `next-name type...`.
+    pub fn parse(t: &[String], origin: &RName) -> Result<Nsec> {
+        let mut t = Fields::new(t);
+        Ok(Nsec{
+            next: try!(t.name(origin)),
+            types: try!(parse_types(&mut t)),
+        })
+    }
+}
+
+impl fmt::Display for Nsec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{}", self.next));
+        format_types(f, &self.types)
+    }
+}
+
+/// A link in the chain of hashed owner names (RFC 5155 §3).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Nsec3 {
+    pub algorithm: u8,
+    pub flags: u8,
+    pub iterations: u16,
+    pub salt: Vec<u8>,
+    /// The unencoded hash of the next owner name.
+    pub next: Vec<u8>,
+    pub types: Vec<u16>,
+}
+
+impl Nsec3 {
+
+    pub fn len(&self) -> usize { 6 + self.salt.len() + self.next.len() + bitmap(&self.types).len() }
+
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if self.salt.len() > 255 || self.next.len() > 255 {
+            return Err(Error::BadRdata)
+        }
+        if offset + 5 > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        buf[offset] = self.algorithm;
+        buf[offset + 1] = self.flags;
+        put_u16(buf, offset + 2, self.iterations);
+        buf[offset + 4] = self.salt.len() as u8;
+        let off = try!(put_bytes(buf, offset + 5, &self.salt));
+        let off = try!(put_bytes(buf, off, &[self.next.len() as u8]));
+        let off = try!(put_bytes(buf, off, &self.next));
+        put_bytes(buf, off, &bitmap(&self.types))
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Nsec3> {
+        if rdata.len() < 5 {
+            return Err(Error::BadRdata)
+        }
+        let (salt, off) = try!(string(rdata, 4));
+        let (next, off) = try!(string(rdata, off));
+        if next.is_empty() {
+            return Err(Error::BadRdata)
+        }
+        Ok(Nsec3{
+            algorithm: rdata[0],
+            flags: rdata[1],
+            iterations: get_u16(rdata, 2),
+            salt: salt,
+            next: next,
+            types: try!(unpack_bitmap(&rdata[off..])),
+        })
+    }
+
+    /// Reads `algorithm flags iterations salt next-hash type...`, with
+    /// `-` for an empty salt and the hash in base32hex.
+    pub fn parse(t: &[String]) -> Result<Nsec3> {
+        let mut t = Fields::new(t);
+        Ok(Nsec3{
+            algorithm: try!(t.number()),
+            flags: try!(t.number()),
+            iterations: try!(t.number()),
+            salt: try!(t.field(parse_salt)),
+            next: try!(t.field(|s| {
+                let next = try!(base32::decode(s));
+                if next.is_empty() || next.len() > 255 {
+                    return Err(Error::BadRdata)
+                }
+                Ok(next)
+            })),
+            types: try!(parse_types(&mut t)),
+        })
+    }
+}
+
+impl fmt::Display for Nsec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{} {} {} {} {}", self.algorithm, self.flags, self.iterations,
+                    format_salt(&self.salt), base32::encode(&self.next)));
+        format_types(f, &self.types)
+    }
+}
+
+/// The hashing parameters of a zone's NSEC3 chain (RFC 5155 §4).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Nsec3Param {
+    pub algorithm: u8,
+    pub flags: u8,
+    pub iterations: u16,
+    pub salt: Vec<u8>,
+}
+
+impl Nsec3Param {
+
+    pub fn len(&self) -> usize { 5 + self.salt.len() }
+
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if self.salt.len() > 255 {
+            return Err(Error::BadRdata)
+        }
+        if offset + 5 > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        buf[offset] = self.algorithm;
+        buf[offset + 1] = self.flags;
+        put_u16(buf, offset + 2, self.iterations);
+        buf[offset + 4] = self.salt.len() as u8;
+        put_bytes(buf, offset + 5, &self.salt)
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Nsec3Param> {
+        if rdata.len() < 5 {
+            return Err(Error::BadRdata)
+        }
+        let (salt, off) = try!(string(rdata, 4));
+        if off != rdata.len() {
+            return Err(Error::BadRdata)
+        }
+        Ok(Nsec3Param{
+            algorithm: rdata[0],
+            flags: rdata[1],
+            iterations: get_u16(rdata, 2),
+            salt: salt,
+        })
+    }
+
+    /// Reads `algorithm flags iterations salt`.
+    pub fn parse(t: &[String]) -> Result<Nsec3Param> {
+        let mut t = Fields::new(t);
+        let param = Nsec3Param{
+            algorithm: try!(t.number()),
+            flags: try!(t.number()),
+            iterations: try!(t.number()),
+            salt: try!(t.field(parse_salt)),
+        };
+        try!(t.finish());
+        Ok(param)
+    }
+}
+
+impl fmt::Display for Nsec3Param {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.algorithm, self.flags, self.iterations, format_salt(&self.salt))
+    }
+}
+
+/// The mnemonic of a type, or `TYPEnnn` for those without one
+/// (RFC 3597 §5).
+pub fn type_name(t: u16) -> String {
+    if let Ok(rtype) = RType::unpack(t) {
+        let name = match rtype {
+            RType::ALL => "ANY".to_string(),
+            RType::NSAPPTR => "NSAP-PTR".to_string(),
+            _ => format!("{:?}", rtype),
+        };
+        if RType::from_str(&name) == Ok(rtype) {
+            return name
+        }
+    }
+    format!("TYPE{}", t)
+}
+
+/// Reads a type mnemonic or `TYPEnnn`, also for types `RType` lacks.
+pub fn parse_type(s: &str) -> Result<u16> {
+    if let Ok(rtype) = RType::from_str(s) {
+        return Ok(rtype as u16)
+    }
+    let upper = s.to_uppercase();
+    if upper.starts_with("TYPE") {
+        if let Ok(t) = upper[4..].parse() {
+            return Ok(t)
+        }
+    }
+    Err(Error::BadRType)
+}
+
+/// Encodes a set of types as windowed bitmaps (RFC 4034 §4.1.2).
+pub fn bitmap(types: &[u16]) -> Vec<u8> {
+    let mut types = types.to_vec();
+    types.sort();
+    types.dedup();
+    let mut out = vec![];
+    let mut i = 0;
+    while i < types.len() {
+        let window = types[i] >> 8;
+        let mut bits = [0u8; 32];
+        let mut len = 0;
+        while i < types.len() && types[i] >> 8 == window {
+            let low = (types[i] & 0xff) as usize;
+            bits[low / 8] |= 0x80 >> (low % 8);
+            len = low / 8 + 1;
+            i += 1;
+        }
+        out.push(window as u8);
+        out.push(len as u8);
+        out.extend_from_slice(&bits[..len]);
+    }
+    out
+}
+
+/// Decodes windowed bitmaps, which must come in increasing order.
+pub fn unpack_bitmap(data: &[u8]) -> Result<Vec<u16>> {
+    let mut types = vec![];
+    let mut off = 0;
+    let mut last: Option<u8> = None;
+    while off < data.len() {
+        if off + 2 > data.len() {
+            return Err(Error::BadRdata)
+        }
+        let (window, len) = (data[off], data[off + 1] as usize);
+        if len == 0 || len > 32 || off + 2 + len > data.len() || last.map_or(false, |w| w >= window) {
+            return Err(Error::BadRdata)
+        }
+        for (i, &b) in data[off + 2..off + 2 + len].iter().enumerate() {
+            for bit in 0..8 {
+                if b & (0x80 >> bit) != 0 {
+                    types.push((window as u16) << 8 | (i * 8 + bit) as u16);
+                }
+            }
+        }
+        last = Some(window);
+        off += 2 + len;
+    }
+    Ok(types)
+}
+
+/// Reads a signature time given as `YYYYMMDDHHmmSS` in UTC or as seconds
+/// since the epoch (RFC 4034 §3.2).
+pub fn parse_time(s: &str) -> Result<u32> {
+    if s.len() != 14 {
+        return number(s)
+    }
+    if !s.bytes().all(|c| c >= b'0' && c <= b'9') {
+        return Err(Error::BadRdata)
+    }
+    let field = |from: usize, to: usize| s[from..to].parse::<i64>().unwrap();
+    let (year, month, day) = (field(0, 4), field(4, 6), field(6, 8));
+    let (hour, min, sec) = (field(8, 10), field(10, 12), field(12, 14));
+    if year < 1970 || month < 1 || month > 12 || day < 1 || day > 31 || hour > 23 || min > 59 || sec > 59 {
+        return Err(Error::BadRdata)
+    }
+    let secs = days_from_civil(year, month, day) * 86400 + hour * 3600 + min * 60 + sec;
+    // later times wrap around as serial numbers do
+    Ok(secs as u32)
+}
+
+/// Writes a signature time as `YYYYMMDDHHmmSS` in UTC.
+pub fn format_time(t: u32) -> String {
+    let t = t as i64;
+    let (year, month, day) = civil_from_days(t / 86400);
+    let secs = t % 86400;
+    format!("{:04}{:02}{:02}{:02}{:02}{:02}", year, month, day, secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+// Days between 1970-01-01 and a date of the proleptic Gregorian calendar.
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn civil_from_days(z: i64) -> (i64, i64, i64) {
+    let z = z + 719468;
+    let era = z / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    (if m <= 2 { yoe + era * 400 + 1 } else { yoe + era * 400 }, m, d)
+}
+
+/// Reads hexadecimal digits in either case.
+pub fn hex(s: &str) -> Result<Vec<u8>> {
+    if s.len() % 2 != 0 || !s.is_ascii() {
+        return Err(Error::BadRdata)
+    }
+    let mut out = Vec::with_capacity(s.len() / 2);
+    for i in 0..s.len() / 2 {
+        match u8::from_str_radix(&s[i*2..i*2+2], 16) {
+            Ok(b) => out.push(b),
+            Err(_) => return Err(Error::BadRdata),
+        }
+    }
+    Ok(out)
+}
+
+fn number<T: FromStr>(s: &str) -> Result<T> {
+    s.parse().map_err(|_| Error::BadRdata)
+}
+
+fn parse_types(t: &mut Fields) -> Result<Vec<u16>> {
+    let mut types = vec![];
+    while !t.is_empty() {
+        types.push(try!(t.field(parse_type)));
+    }
+    Ok(types)
+}
+
+fn format_types(f: &mut fmt::Formatter, types: &[u16]) -> fmt::Result {
+    for &t in types.iter() {
+        try!(write!(f, " {}", type_name(t)));
+    }
+    Ok(())
+}
+
+fn parse_salt(s: &str) -> Result<Vec<u8>> {
+    let salt = if s == "-" { vec![] } else { try!(hex(s)) };
+    if salt.len() > 255 {
+        return Err(Error::BadRdata)
+    }
+    Ok(salt)
+}
+
+fn format_salt(salt: &[u8]) -> String {
+    if salt.is_empty() {
+        return "-".to_string()
+    }
+    let hex: Vec<String> = salt.iter().map(|b| format!("{:02x}", b)).collect();
+    hex.concat()
+}
+
+// A length-prefixed run of bytes.
+fn string(data: &[u8], off: usize) -> Result<(Vec<u8>, usize)> {
+    if off >= data.len() || off + 1 + data[off] as usize > data.len() {
+        return Err(Error::BadRdata)
+    }
+    let end = off + 1 + data[off] as usize;
+    Ok((data[off + 1..end].to_vec(), end))
+}
+
+#[inline]
+fn get_u16(buf: &[u8], off: usize) -> u16 {
+    (buf[off] as u16) << 8 | buf[off + 1] as u16
+}
+
+#[inline]
+fn get_u32(buf: &[u8], off: usize) -> u32 {
+    (get_u16(buf, off) as u32) << 16 | get_u16(buf, off + 2) as u32
+}
+
+#[inline]
+fn put_u16(buf: &mut [u8], off: usize, v: u16) {
+    buf[off] = (v >> 8) as u8;
+    buf[off + 1] = v as u8;
+}
+
+#[inline]
+fn put_u32(buf: &mut [u8], off: usize, v: u32) {
+    put_u16(buf, off, (v >> 16) as u16);
+    put_u16(buf, off + 2, v as u16);
+}
+
+fn put_bytes(buf: &mut [u8], off: usize, data: &[u8]) -> Result<usize> {
+    if off + data.len() > buf.len() {
+        return Err(Error::SmallBuf)
+    }
+    buf[off..off + data.len()].copy_from_slice(data);
+    Ok(off + data.len())
+}
+
+
+#[cfg(test)]
+fn tokens(s: &str) -> Vec<String> {
+    s.split_whitespace().map(|t| t.to_string()).collect()
+}
+
+#[test]
+fn key_tags() {
+    // RFC 4034 §5.4
+    let key = Dnskey::parse(&tokens("256 3 5 AQOeiiR0GOMYkDshWoSKz9XzfwJr1AYtsmx3TGkJaNXVbfi/2pHm822aJ5iI9BMzNXxeYCmZDRD99WYwYqUSdjMmmAphXdvxegXd/M5+X7OrzKBaMbCVdFLUUh6DhweJBjEVv5f2wwjM9XzcnOf+EPbtG9DMBmADjFDc2w/rljwvFw==")).unwrap();
+    assert_eq!(key.flags, ZONE_KEY);
+    assert_eq!(key.protocol, PROTOCOL);
+    assert_eq!(key.key_tag(), 60485);
+
+    // the root zone's key signing key of 2017
+    let key = Dnskey::parse(&tokens("257 3 8 AwEAAaz/tAm8yTn4Mfeh5eyI96WSVexTBAvkMgJzkKTOiW1vkIbzxeF3+/4RgWOq7HrxRixHlFlExOLAJr5emLvN7SWXgnLh4+B5xQlNVz8Og8kvArMtNROxVQuCaSnIDdD5LKyWbRd2n9WGe2R8PzgCmr3EgVLrjyBxWezF0jLHwVN8efS3rCj/EWgvIWgb9tarpVUDK/b58Da+sqqls3eNbuv7pr+eoZG+SrDK6nWeL3c6H5Apxz7LjVc1uTIdsIXxuOLYA4/ilBmSVIzuDWfdRUfhHdY6+cn8HFRm+2hM8AnXGXws9555KrUB5qihylGa8subX2Nn6UwNR1AkUTV74bU=")).unwrap();
+    assert_eq!(key.flags, ZONE_KEY | SEP);
+    assert_eq!(key.key_tag(), 20326);
+
+    let mut wire = vec![0; key.len()];
+    assert_eq!(key.pack(&mut wire, 0), Ok(key.len()));
+    assert_eq!(Dnskey::unpack(&wire), Ok(key.clone()));
+    assert_eq!(Dnskey::parse(&tokens(&key.to_string())), Ok(key));
+}
+
+#[test]
+fn signature_fields() {
+    // RFC 4034 §3.3
+    let origin = RName::from_str("example.com.").unwrap();
+    let text = "A 5 3 86400 20030322173103 20030220173103 2642 example.com. \
+                oJB1W6WNGv+ldvQ3WDG0MQkg5IEhjRip8WTrPYGv07h108dUKGMeDPKijVCHX3DDKdfb+v6o \
+                B9wfuh3DTJXUAfI/M0zmO/zz8bW0Rznl8O3tGNazPwQKkRN20XPXV6nwwfoXmJQbsLNrLfkG \
+                J5D6fwFm8nN+6pBzeDQfsS3Ap3o=";
+    let sig = Rrsig::parse(&tokens(text), &origin).unwrap();
+    assert_eq!(sig.type_covered, RType::A as u16);
+    assert_eq!(sig.expiration, 1048354263);
+    assert_eq!(sig.inception, 1045762263);
+    assert_eq!(sig.signer, origin);
+    assert_eq!(sig.signature.len(), 128);
+    assert_eq!(format_time(sig.inception), "20030220173103");
+    assert_eq!(parse_time("1045762263"), Ok(1045762263));
+    assert_eq!(parse_time("20031322173103"), Err(Error::BadRdata));
+
+    let mut wire = vec![0; sig.len()];
+    assert_eq!(sig.pack(&mut wire, 0), Ok(sig.len()));
+    assert_eq!(&wire[..4], &[0, 1, 5, 3]);
+    assert_eq!(Rrsig::unpack(&wire), Ok(sig.clone()));
+    assert_eq!(Rrsig::parse(&tokens(&sig.to_string()), &origin), Ok(sig));
+
+    let ds = Ds::parse(&tokens("60485 5 1 2BB183AF5F22588179A53B0A 98631FAD1A292118")).unwrap();
+    assert_eq!(ds.digest.len(), 20);
+    assert_eq!(ds.to_string(), "60485 5 1 2BB183AF5F22588179A53B0A98631FAD1A292118");
+}
+
+#[test]
+fn type_bitmaps() {
+    // RFC 4034 §4.3
+    let origin = RName::from_str("example.com.").unwrap();
+    let nsec = Nsec::parse(&tokens("host A MX RRSIG NSEC TYPE1234"), &origin).unwrap();
+    assert_eq!(nsec.types, vec![1, 15, 46, 47, 1234]);
+    let mut wire = vec![0; nsec.len()];
+    assert_eq!(nsec.pack(&mut wire, 0), Ok(37 + 18));
+    let mut bitmap = vec![0x00, 0x06, 0x40, 0x01, 0x00, 0x00, 0x00, 0x03,
+                          0x04, 0x1b];
+    bitmap.extend_from_slice(&[0; 26]);
+    bitmap.push(0x20);
+    assert_eq!(&wire[18..], &bitmap[..]);
+    assert_eq!(Nsec::unpack(&wire), Ok(nsec.clone()));
+    assert_eq!(nsec.to_string(), "host.example.com. A MX RRSIG NSEC TYPE1234");
+
+    // windows out of order
+    assert_eq!(unpack_bitmap(&[0x04, 0x01, 0x20, 0x00, 0x01, 0x40]), Err(Error::BadRdata));
+    assert_eq!(unpack_bitmap(&[0x00, 0x00]), Err(Error::BadRdata));
+
+    // RFC 5155 Appendix A
+    let nsec3 = Nsec3::parse(&tokens("1 1 12 aabbccdd 2t7b4g4vsa5smi47k61mv5bv1a22bojr MX DNSKEY NS SOA NSEC3PARAM RRSIG")).unwrap();
+    assert_eq!(nsec3.next.len(), 20);
+    assert_eq!(nsec3.salt, vec![0xaa, 0xbb, 0xcc, 0xdd]);
+    let mut wire = vec![0; nsec3.len()];
+    assert_eq!(nsec3.pack(&mut wire, 0), Ok(nsec3.len()));
+    let back = Nsec3::unpack(&wire).unwrap();
+    assert_eq!(back.types, vec![2, 6, 15, 46, 48, 51]);
+    assert_eq!(back.to_string(), "1 1 12 aabbccdd 2t7b4g4vsa5smi47k61mv5bv1a22bojr NS SOA MX RRSIG DNSKEY NSEC3PARAM");
+
+    let param = Nsec3Param::parse(&tokens("1 0 0 -")).unwrap();
+    assert!(param.salt.is_empty());
+    let mut wire = vec![0; param.len()];
+    param.pack(&mut wire, 0).unwrap();
+    assert_eq!(wire, vec![1, 0, 0, 0, 0]);
+    assert_eq!(Nsec3Param::unpack(&wire), Ok(param.clone()));
+    assert_eq!(param.to_string(), "1 0 0 -");
+}
@@ -0,0 +1,615 @@
+use std::cmp::Ordering;
+
+use dns::{Error, Result};
+use dns::idna_tables::{self, Bidi};
+
+// Parameters of punycode (RFC 3492 §5).
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+/// The prefix of a label holding punycode (RFC 5890 §2.3.1).
+pub const ACE_PREFIX: &'static str = "xn--";
+
+/// Converts a name typed in Unicode to the ASCII form used on the wire.
+///
+/// The name is mapped and normalized to NFC as UTS #46 has it, keeping
+/// the deviations such as `ß` (nontransitional processing). Labels left
+/// with anything but ASCII have to follow IDNA 2008: RFC 5891 §5.4 with the
+/// code points and contexts of RFC 5892 and, in names with right-to-left
+/// labels, the bidi rule of RFC 5893. They are encoded with punycode
+/// behind `xn--`. ASCII labels may hold what other names in DNS do, such as
+/// `_` and `*`.
+pub fn to_ascii(name: &str) -> Result<String> {
+    process(name, false)
+}
+
+/// The Unicode form of an ASCII label, `None` unless it is a valid
+/// `xn--` label.
+pub fn to_unicode(label: &[u8]) -> Option<String> {
+    if label.len() <= ACE_PREFIX.len() || !label[..ACE_PREFIX.len()].eq_ignore_ascii_case(ACE_PREFIX.as_bytes()) {
+        return None
+    }
+    let ascii = match ::std::str::from_utf8(&label[ACE_PREFIX.len()..]) {
+        Ok(ascii) if ascii.is_ascii() => ascii.to_ascii_lowercase(),
+        _ => return None,
+    };
+    match decode(&ascii) {
+        // punycode has a single encoding of each label
+        Some(ref unicode) if !unicode.is_ascii() && check_label(unicode).is_ok() &&
+            encode(unicode).ok() == Some(ascii.clone()) => {
+            Some(unicode.clone())
+        }
+        _ => None,
+    }
+}
+
+// Converts a name to ASCII. Being `strict` holds ASCII labels to the rules
+// of host names too, as UseSTD3ASCIIRules and CheckHyphens of UTS #46 do.
+fn process(name: &str, strict: bool) -> Result<String> {
+    let mapped = try!(map(name, strict));
+    if mapped.is_empty() || mapped == "." {
+        return Ok(mapped)
+    }
+
+    let mut out = String::with_capacity(mapped.len());
+    let mut labels = Vec::new();
+    for (i, label) in mapped.split('.').enumerate() {
+        if i > 0 {
+            out.push('.');
+        }
+        if label.is_empty() {
+            // a trailing dot makes the name absolute, others are left to
+            // fail as names
+            continue
+        } else if label.starts_with(ACE_PREFIX) {
+            labels.push(try!(to_unicode(label.as_bytes()).ok_or(Error::BadIdna)));
+            out.push_str(label);
+        } else if label.is_ascii() {
+            try!(check_ascii(label, strict));
+            labels.push(label.to_string());
+            out.push_str(label);
+        } else {
+            try!(check_label(label));
+            labels.push(label.to_string());
+            out.push_str(ACE_PREFIX);
+            out.push_str(&try!(encode(label)));
+        }
+    }
+
+    // the bidi rule holds for every label of a name with one in a
+    // right-to-left script (RFC 5893 §1.4)
+    if labels.iter().any(|label| label.chars().any(|c| match bidi(c) { Bidi::R | Bidi::AL | Bidi::AN => true, _ => false })) {
+        for label in labels.iter() {
+            try!(check_bidi(label));
+        }
+    }
+    Ok(out)
+}
+
+// Maps a name as UTS #46 §4 steps 1 and 2 do.
+fn map(name: &str, strict: bool) -> Result<String> {
+    let mut mapped = String::with_capacity(name.len());
+    for c in name.chars() {
+        let row = match idna_tables::MAPPING.binary_search_by(|row| row.0.cmp(&(c as u32))) {
+            Ok(i) => idna_tables::MAPPING[i],
+            Err(i) => idna_tables::MAPPING[i - 1],
+        };
+        match row {
+            (_, b'V', _) | (_, b'D', _) => mapped.push(c),
+            (_, b'M', to) => mapped.push_str(to),
+            (_, b'I', _) => {}
+            (_, b'3', "") if !strict => mapped.push(c),
+            (_, b'3', to) if !strict => mapped.push_str(to),
+            _ => return Err(Error::BadIdna),
+        }
+    }
+    if mapped.is_ascii() {
+        return Ok(mapped)
+    }
+    Ok(nfc(&mapped))
+}
+
+// Labels in ASCII pass as they are, unless `strict` asks for letters,
+// digits and hyphens where a host name has them.
+fn check_ascii(label: &str, strict: bool) -> Result<()> {
+    let valid = if strict {
+        label.bytes().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-') && hyphens_valid(label)
+    } else {
+        label.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_' || c == b'*')
+    };
+    if !valid {
+        return Err(Error::BadIdna)
+    }
+    Ok(())
+}
+
+// The rules of RFC 5891 §5.4 for a label in Unicode, but for the bidi rule
+// which depends on the other labels of a name.
+fn check_label(label: &str) -> Result<()> {
+    let chars: Vec<char> = label.chars().collect();
+    if chars.is_empty() || nfc(label) != label || !hyphens_valid(label) || within(idna_tables::MARKS, chars[0]) {
+        return Err(Error::BadIdna)
+    }
+    for i in 0..chars.len() {
+        let valid = within(idna_tables::PVALID, chars[i]) ||
+            within(idna_tables::CONTEXTJ, chars[i]) && contextj(&chars, i) ||
+            within(idna_tables::CONTEXTO, chars[i]) && contexto(&chars, i);
+        if !valid {
+            return Err(Error::BadIdna)
+        }
+    }
+    Ok(())
+}
+
+// No hyphen at either end nor in the third and fourth position, where
+// `xn--` has them (RFC 5891 §4.2.3.1).
+fn hyphens_valid(label: &str) -> bool {
+    !label.starts_with('-') && !label.ends_with('-') && !label.chars().skip(2).take(2).eq("--".chars())
+}
+
+// The rules for zero width joiners (RFC 5892 A.1 and A.2).
+fn contextj(label: &[char], i: usize) -> bool {
+    const VIRAMA: u8 = 9;
+    if i > 0 && combining_class(label[i - 1]) == VIRAMA {
+        return true
+    }
+    if label[i] != '\u{200c}' {
+        return false
+    }
+    // a non-joiner also goes between letters that would join
+    let joining = |c: char| lookup(idna_tables::JOINING_TYPES, c);
+    let before = label[..i].iter().rev().map(|&c| joining(c)).find(|&t| t != Some(b'T'));
+    let after = label[i + 1..].iter().map(|&c| joining(c)).find(|&t| t != Some(b'T'));
+    (before == Some(Some(b'L')) || before == Some(Some(b'D'))) &&
+        (after == Some(Some(b'R')) || after == Some(Some(b'D')))
+}
+
+// The rules for the other code points allowed in context (RFC 5892 A.3 to
+// A.9).
+fn contexto(label: &[char], i: usize) -> bool {
+    let arabic_indic = |c: char| c >= '\u{660}' && c <= '\u{669}';
+    let extended_arabic_indic = |c: char| c >= '\u{6f0}' && c <= '\u{6f9}';
+    match label[i] {
+        '\u{b7}' => i > 0 && label[i - 1] == 'l' && label.get(i + 1) == Some(&'l'),
+        '\u{375}' => label.get(i + 1).map_or(false, |&c| within(idna_tables::GREEK, c)),
+        '\u{5f3}' | '\u{5f4}' => i > 0 && within(idna_tables::HEBREW, label[i - 1]),
+        '\u{30fb}' => label.iter().any(|&c| {
+            within(idna_tables::HIRAGANA, c) || within(idna_tables::KATAKANA, c) || within(idna_tables::HAN, c)
+        }),
+        c if arabic_indic(c) => !label.iter().any(|&c| extended_arabic_indic(c)),
+        c if extended_arabic_indic(c) => !label.iter().any(|&c| arabic_indic(c)),
+        _ => false,
+    }
+}
+
+// The bidi rule (RFC 5893 §2).
+fn check_bidi(label: &str) -> Result<()> {
+    let rtl = match label.chars().next().map(bidi) {
+        Some(Bidi::R) | Some(Bidi::AL) => true,
+        Some(Bidi::L) => false,
+        _ => return Err(Error::BadIdna),
+    };
+    let mut numbers = None;
+    let mut end = None;
+    for class in label.chars().map(bidi) {
+        let valid = match class {
+            Bidi::R | Bidi::AL | Bidi::AN => rtl,
+            Bidi::L => !rtl,
+            Bidi::EN | Bidi::ES | Bidi::CS | Bidi::ET | Bidi::ON | Bidi::BN | Bidi::NSM => true,
+            _ => false,
+        };
+        if !valid {
+            return Err(Error::BadIdna)
+        }
+        if rtl && (class == Bidi::AN || class == Bidi::EN) {
+            // European and Arabic-Indic digits do not mix
+            if numbers.map_or(false, |n| n != class) {
+                return Err(Error::BadIdna)
+            }
+            numbers = Some(class);
+        }
+        if class != Bidi::NSM {
+            end = Some(class);
+        }
+    }
+    let valid_end = match end {
+        Some(Bidi::R) | Some(Bidi::AL) | Some(Bidi::AN) => rtl,
+        Some(Bidi::L) => !rtl,
+        Some(Bidi::EN) => true,
+        _ => false,
+    };
+    if !valid_end {
+        return Err(Error::BadIdna)
+    }
+    Ok(())
+}
+
+fn bidi(c: char) -> Bidi {
+    lookup(idna_tables::BIDI, c).unwrap_or(Bidi::L)
+}
+
+fn combining_class(c: char) -> u8 {
+    lookup(idna_tables::COMBINING_CLASSES, c).unwrap_or(0)
+}
+
+// Normalization form C (UAX #15): the canonical decomposition put in
+// canonical order and composed again.
+fn nfc(s: &str) -> String {
+    let mut chars = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        decompose(c, &mut chars);
+    }
+    for i in 1..chars.len() {
+        let class = combining_class(chars[i]);
+        let mut j = i;
+        while class != 0 && j > 0 && combining_class(chars[j - 1]) > class {
+            chars.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+
+    let mut out: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter = None;
+    let mut last = 0;
+    for c in chars {
+        let class = combining_class(c);
+        if let Some(i) = starter {
+            // nothing in between with a class of 0 or as high
+            if i + 1 == out.len() || last != 0 && last < class {
+                if let Some(composed) = compose(out[i], c) {
+                    out[i] = composed;
+                    continue
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(out.len());
+        }
+        last = class;
+        out.push(c);
+    }
+    out.into_iter().collect()
+}
+
+// Hangul syllables decompose and compose by arithmetic (Unicode §3.12).
+const HANGUL_S: u32 = 0xac00;
+const HANGUL_L: u32 = 0x1100;
+const HANGUL_V: u32 = 0x1161;
+const HANGUL_T: u32 = 0x11a7;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_COUNT: u32 = 11172;
+
+fn decompose(c: char, out: &mut Vec<char>) {
+    let s = (c as u32).wrapping_sub(HANGUL_S);
+    if s < HANGUL_COUNT {
+        let t = s % HANGUL_T_COUNT;
+        out.push(char_from(HANGUL_L + s / (HANGUL_V_COUNT * HANGUL_T_COUNT)));
+        out.push(char_from(HANGUL_V + s % (HANGUL_V_COUNT * HANGUL_T_COUNT) / HANGUL_T_COUNT));
+        if t > 0 {
+            out.push(char_from(HANGUL_T + t));
+        }
+        return
+    }
+    match idna_tables::DECOMPOSITIONS.binary_search_by(|row| row.0.cmp(&(c as u32))) {
+        Ok(i) => out.extend(idna_tables::DECOMPOSITIONS[i].1.chars()),
+        Err(_) => out.push(c),
+    }
+}
+
+fn compose(a: char, b: char) -> Option<char> {
+    let (a, b) = (a as u32, b as u32);
+    let l = a.wrapping_sub(HANGUL_L);
+    let v = b.wrapping_sub(HANGUL_V);
+    let s = a.wrapping_sub(HANGUL_S);
+    let t = b.wrapping_sub(HANGUL_T);
+    if l < 19 && v < HANGUL_V_COUNT {
+        return Some(char_from(HANGUL_S + (l * HANGUL_V_COUNT + v) * HANGUL_T_COUNT))
+    } else if s < HANGUL_COUNT && s % HANGUL_T_COUNT == 0 && t > 0 && t < HANGUL_T_COUNT {
+        return Some(char_from(a + t))
+    }
+    idna_tables::COMPOSITIONS.binary_search_by(|row| (row.0, row.1).cmp(&(a, b)))
+        .ok().map(|i| char_from(idna_tables::COMPOSITIONS[i].2))
+}
+
+fn char_from(c: u32) -> char {
+    ::std::char::from_u32(c).unwrap()
+}
+
+// Whether `c` is in one of the ranges of `table`.
+fn within(table: &[(u32, u32)], c: char) -> bool {
+    table.binary_search_by(|&(first, last)| position(first, last, c)).is_ok()
+}
+
+// The value of the range of `table` holding `c`.
+fn lookup<T: Copy>(table: &[(u32, u32, T)], c: char) -> Option<T> {
+    table.binary_search_by(|&(first, last, _)| position(first, last, c)).ok().map(|i| table[i].2)
+}
+
+fn position(first: u32, last: u32, c: char) -> Ordering {
+    if last < c as u32 {
+        Ordering::Less
+    } else if first > c as u32 {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Encodes a label with punycode (RFC 3492 §6.3), without the prefix.
+pub fn encode(label: &str) -> Result<String> {
+    let input: Vec<u32> = label.chars().map(|c| c as u32).collect();
+    let mut out: String = label.chars().filter(|c| c.is_ascii()).collect();
+    let basic = out.len() as u32;
+    if basic > 0 {
+        out.push('-');
+    }
+
+    let (mut n, mut delta, mut bias) = (INITIAL_N, 0u32, INITIAL_BIAS);
+    let mut h = basic;
+    while (h as usize) < input.len() {
+        let m = input.iter().cloned().filter(|&c| c >= n).min().unwrap();
+        delta = try!((m - n).checked_mul(h + 1).and_then(|d| d.checked_add(delta)).ok_or(Error::BadIdna));
+        n = m;
+        for &c in input.iter() {
+            if c < n {
+                delta = try!(delta.checked_add(1).ok_or(Error::BadIdna));
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break
+                    }
+                    out.push(digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                out.push(digit(q));
+                bias = adapt(delta, h + 1, h == basic);
+                delta = 0;
+                h += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Ok(out)
+}
+
+/// Decodes a punycode label (RFC 3492 §6.2) given without the prefix.
+pub fn decode(label: &str) -> Option<String> {
+    let (basic, rest) = match label.rfind('-') {
+        Some(i) => (&label[..i], &label[i + 1..]),
+        None => ("", label),
+    };
+    if !basic.is_ascii() {
+        return None
+    }
+    let mut out: Vec<char> = basic.chars().collect();
+
+    let (mut n, mut i, mut bias) = (INITIAL_N, 0u32, INITIAL_BIAS);
+    let mut digits = rest.bytes();
+    while digits.len() > 0 {
+        let old = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let d = match digits.next() {
+                Some(c @ b'a'...b'z') => (c - b'a') as u32,
+                Some(c @ b'A'...b'Z') => (c - b'A') as u32,
+                Some(c @ b'0'...b'9') => (c - b'0') as u32 + 26,
+                _ => return None,
+            };
+            i = match d.checked_mul(w).and_then(|d| d.checked_add(i)) {
+                Some(i) => i,
+                None => return None,
+            };
+            let t = threshold(k, bias);
+            if d < t {
+                break
+            }
+            w = match w.checked_mul(BASE - t) {
+                Some(w) => w,
+                None => return None,
+            };
+            k += BASE;
+        }
+        let len = out.len() as u32 + 1;
+        bias = adapt(i - old, len, old == 0);
+        n = match n.checked_add(i / len) {
+            Some(n) => n,
+            None => return None,
+        };
+        i %= len;
+        match ::std::char::from_u32(n) {
+            Some(c) if n >= INITIAL_N => out.insert(i as usize, c),
+            _ => return None,
+        }
+        i += 1;
+    }
+    Some(out.into_iter().collect())
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        TMIN
+    } else if k >= bias + TMAX {
+        TMAX
+    } else {
+        k - bias
+    }
+}
+
+fn adapt(mut delta: u32, points: u32, first: bool) -> u32 {
+    delta /= if first { DAMP } else { 2 };
+    delta += delta / points;
+    let mut k = 0;
+    while delta > ((BASE - TMIN) * TMAX) / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (BASE - TMIN + 1) * delta / (delta + SKEW)
+}
+
+fn digit(d: u32) -> char {
+    (if d < 26 { b'a' + d as u8 } else { b'0' + d as u8 - 26 }) as char
+}
+
+
+#[cfg(test)] use dns::RName;
+
+// Undoes the \\uXXXX and \\x{XXXX} escapes of the UTS #46 test file,
+// with surrogate pairs for the code points past the first plane.
+#[cfg(test)]
+fn unescape(s: &str) -> String {
+    let mut units = vec![];
+    let mut rest = s;
+    while !rest.is_empty() {
+        if rest.starts_with("\\u") && rest.len() >= 6 {
+            units.push(u32::from_str_radix(&rest[2..6], 16).unwrap());
+            rest = &rest[6..];
+        } else if rest.starts_with("\\x{") {
+            let end = rest.find('}').unwrap();
+            units.push(u32::from_str_radix(&rest[3..end], 16).unwrap());
+            rest = &rest[end + 1..];
+        } else {
+            let c = rest.chars().next().unwrap();
+            units.push(c as u32);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    let mut out = String::new();
+    let mut i = 0;
+    while i < units.len() {
+        if units[i] >= 0xd800 && units[i] < 0xdc00 {
+            out.push(char_from(0x10000 + (units[i] - 0xd800) * 0x400 + (units[i + 1] - 0xdc00)));
+            i += 2;
+        } else {
+            out.push(char_from(units[i]));
+            i += 1;
+        }
+    }
+    out
+}
+
+#[test]
+fn conform_to_uts46() {
+    // Whether IDNA 2008 allows all of a mapped name, which UTS #46 does not
+    // ask for: it lets through symbols and punctuation such as U+2615
+    // (marked NV8 and XV8 in its tables).
+    let idna2008 = |mapped: &str| mapped.split('.').all(|label| {
+        let label = match decode(label.trim_left_matches(ACE_PREFIX)) {
+            Some(ref unicode) if label.starts_with(ACE_PREFIX) => unicode.clone(),
+            _ => label.to_string(),
+        };
+        label.chars().all(|c| {
+            c.is_ascii() || within(idna_tables::PVALID, c) ||
+                within(idna_tables::CONTEXTJ, c) || within(idna_tables::CONTEXTO, c)
+        })
+    });
+
+    // the tests of Unicode 13.0.0, as in those of the idna package 3.10,
+    // for toASCII without transitional processing
+    for (n, line) in include_str!("../../tests/IdnaTestV2.txt").lines().enumerate() {
+        let line = line.split('#').next().unwrap();
+        if line.trim().is_empty() {
+            continue
+        }
+        let fields: Vec<String> = line.split(';').map(|f| unescape(f.trim())).collect();
+        let source = &fields[0];
+        let unicode = if fields[1].is_empty() { source } else { &fields[1] };
+        let unicode_status = if fields[2].is_empty() { "[]" } else { &fields[2] };
+        let ascii = if fields[3].is_empty() { unicode } else { &fields[3] };
+        let ascii_status = if fields[4].is_empty() { unicode_status } else { &fields[4] };
+
+        let result = process(source, true).and_then(|name| name.parse::<RName>().map(|_| name));
+        if unicode.is_empty() || unicode == "." {
+            // the root is a name in DNS
+            assert_eq!(result.as_ref().map(|s| &s[..]), Ok(&unicode[..]), "line {}", n + 1);
+        } else if ascii_status != "[]" || !idna2008(ascii) {
+            assert!(result.is_err(), "line {}: {:?}", n + 1, result);
+        } else {
+            assert_eq!(result.as_ref(), Ok(ascii), "line {}", n + 1);
+        }
+    }
+}
+
+#[test]
+fn encode_punycode() {
+    // samples of RFC 3492 §7.1, lowercased
+    assert_eq!(encode("bücher").unwrap(), "bcher-kva");
+    assert_eq!(encode("münchen").unwrap(), "mnchen-3ya");
+    assert_eq!(encode("ليهمابتكلموشعربي؟").unwrap(), "egbpdaj6bu4bxfgehfvwxn");
+    assert_eq!(encode("他们为什么不说中文").unwrap(), "ihqwcrb4cv8a8dqg056pqjye");
+    assert_eq!(encode("3年b組金八先生").unwrap(), "3b-ww4c5e180e575a65lsy2b");
+    assert_eq!(encode("ß").unwrap(), "zca");
+
+    for s in &["bücher", "他们为什么不说中文", "3年b組金八先生", "a-b-ü", "ß"] {
+        assert_eq!(decode(&encode(s).unwrap()).unwrap(), *s);
+    }
+    assert_eq!(decode("mnchen-3YA").unwrap(), "münchen");
+    assert_eq!(decode("MNCHEN-3ya").unwrap(), "MüNCHEN");
+    assert_eq!(decode("abc-!"), None);
+    assert_eq!(decode("99999999999"), None);
+}
+
+#[test]
+fn convert_names_to_ascii() {
+    assert_eq!(to_ascii("bücher.example").unwrap(), "xn--bcher-kva.example");
+    assert_eq!(to_ascii("Bücher.EXAMPLE.").unwrap(), "xn--bcher-kva.example.");
+    assert_eq!(to_ascii("ｂüｃｈｅｒ。example").unwrap(), "xn--bcher-kva.example");
+    assert_eq!(to_ascii("bü\u{ad}cher.example").unwrap(), "xn--bcher-kva.example");
+    assert_eq!(to_ascii("bu\u{308}cher.example").unwrap(), "xn--bcher-kva.example");
+    assert_eq!(to_ascii("\u{2160}.example").unwrap(), "i.example");
+    assert_eq!(to_ascii("faß.de").unwrap(), "xn--fa-hia.de");
+    assert_eq!(to_ascii("_sip._tcp.example").unwrap(), "_sip._tcp.example");
+    assert_eq!(to_ascii("xn--bcher-kva.example").unwrap(), "xn--bcher-kva.example");
+
+    assert_eq!(to_ascii("-bücher.example"), Err(Error::BadIdna));
+    assert_eq!(to_ascii("bü--cher.example"), Err(Error::BadIdna));
+    assert_eq!(to_ascii("\u{301}bücher.example"), Err(Error::BadIdna));
+    assert_eq!(to_ascii("bü cher.example"), Err(Error::BadIdna));
+    assert_eq!(to_ascii("bü_cher.example"), Err(Error::BadIdna));
+    assert_eq!(to_ascii("a b.example"), Err(Error::BadIdna));
+    assert_eq!(to_ascii("xn--abc-.example"), Err(Error::BadIdna));
+    assert_eq!(to_ascii("\u{2615}.example"), Err(Error::BadIdna));
+}
+
+#[test]
+fn check_contexts_and_bidi() {
+    // a zero width non-joiner after a virama, and a stray zero width joiner
+    assert!(to_ascii("\u{915}\u{94d}\u{200c}\u{937}.example").is_ok());
+    assert_eq!(to_ascii("a\u{200d}b.example"), Err(Error::BadIdna));
+
+    assert!(to_ascii("l\u{b7}l.example").is_ok());
+    assert_eq!(to_ascii("\u{b7}a.example"), Err(Error::BadIdna));
+    assert!(to_ascii("\u{30a2}\u{30fb}\u{30a4}.example").is_ok());
+    assert_eq!(to_ascii("a\u{30fb}b.example"), Err(Error::BadIdna));
+
+    assert!(to_ascii("\u{5e9}\u{5dc}\u{5d5}\u{5dd}.example").is_ok());
+    assert!(to_ascii("\u{645}\u{62b}\u{627}\u{644}.example").is_ok());
+    // the rule holds for the left-to-right labels of such a name too
+    assert_eq!(to_ascii("0\u{e0}.\u{5d0}"), Err(Error::BadIdna));
+    assert_eq!(to_ascii("\u{5d0}a.example"), Err(Error::BadIdna));
+}
+
+#[test]
+fn convert_labels_to_unicode() {
+    assert_eq!(to_unicode(b"xn--bcher-kva"), Some("bücher".to_string()));
+    assert_eq!(to_unicode(b"XN--BCHER-KVA"), Some("bücher".to_string()));
+    assert_eq!(to_unicode(b"bcher-kva"), None);
+    assert_eq!(to_unicode(b"xn--"), None);
+    // decodes to plain ASCII
+    assert_eq!(to_unicode(b"xn--abc-"), None);
+    // symbols are not allowed by IDNA 2008
+    assert_eq!(to_unicode(b"xn--ls8h"), None);
+    assert_eq!(to_unicode(b"xn--mgbh0fb"), Some("مثال".to_string()));
+}
@@ -0,0 +1,4467 @@
+// Generated by tools/idna_tables.py from the data of the idna package 3.10
+// and Python 3.13, both of Unicode 15.1.0. Do not edit.
+
+/// The bidirectional class of a code point (UAX #9).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bidi {
+    L, R, AL, EN, ES, ET, AN, CS, NSM, BN, B, S, WS, ON, LRE, LRO, RLE, RLO, PDF, LRI, RLI, FSI, PDI,
+}
+
+/// The UTS #46 status of the code points from the first one up to the
+/// next row: `V` valid, `M` mapped, `D` deviation, `I` ignored, `X`
+/// disallowed and `3` disallowed by STD 3, with what they are mapped to.
+pub static MAPPING: &'static [(u32, u8, &'static str)] = &[
+    (0x0, b'3', ""), (0x1, b'3', ""), (0x2, b'3', ""), (0x3, b'3', ""), (0x4, b'3', ""),
+    (0x5, b'3', ""), (0x6, b'3', ""), (0x7, b'3', ""), (0x8, b'3', ""), (0x9, b'3', ""),
+    (0xa, b'3', ""), (0xb, b'3', ""), (0xc, b'3', ""), (0xd, b'3', ""), (0xe, b'3', ""),
+    (0xf, b'3', ""), (0x10, b'3', ""), (0x11, b'3', ""), (0x12, b'3', ""), (0x13, b'3', ""),
+    (0x14, b'3', ""), (0x15, b'3', ""), (0x16, b'3', ""), (0x17, b'3', ""), (0x18, b'3', ""),
+    (0x19, b'3', ""), (0x1a, b'3', ""), (0x1b, b'3', ""), (0x1c, b'3', ""), (0x1d, b'3', ""),
+    (0x1e, b'3', ""), (0x1f, b'3', ""), (0x20, b'3', ""), (0x21, b'3', ""), (0x22, b'3', ""),
+    (0x23, b'3', ""), (0x24, b'3', ""), (0x25, b'3', ""), (0x26, b'3', ""), (0x27, b'3', ""),
+    (0x28, b'3', ""), (0x29, b'3', ""), (0x2a, b'3', ""), (0x2b, b'3', ""), (0x2c, b'3', ""),
+    (0x2d, b'V', ""), (0x2e, b'V', ""), (0x2f, b'3', ""), (0x30, b'V', ""), (0x31, b'V', ""),
+    (0x32, b'V', ""), (0x33, b'V', ""), (0x34, b'V', ""), (0x35, b'V', ""), (0x36, b'V', ""),
+    (0x37, b'V', ""), (0x38, b'V', ""), (0x39, b'V', ""), (0x3a, b'3', ""), (0x3b, b'3', ""),
+    (0x3c, b'3', ""), (0x3d, b'3', ""), (0x3e, b'3', ""), (0x3f, b'3', ""), (0x40, b'3', ""),
+    (0x41, b'M', "a"), (0x42, b'M', "b"), (0x43, b'M', "c"), (0x44, b'M', "d"), (0x45, b'M', "e"),
+    (0x46, b'M', "f"), (0x47, b'M', "g"), (0x48, b'M', "h"), (0x49, b'M', "i"), (0x4a, b'M', "j"),
+    (0x4b, b'M', "k"), (0x4c, b'M', "l"), (0x4d, b'M', "m"), (0x4e, b'M', "n"), (0x4f, b'M', "o"),
+    (0x50, b'M', "p"), (0x51, b'M', "q"), (0x52, b'M', "r"), (0x53, b'M', "s"), (0x54, b'M', "t"),
+    (0x55, b'M', "u"), (0x56, b'M', "v"), (0x57, b'M', "w"), (0x58, b'M', "x"), (0x59, b'M', "y"),
+    (0x5a, b'M', "z"), (0x5b, b'3', ""), (0x5c, b'3', ""), (0x5d, b'3', ""), (0x5e, b'3', ""),
+    (0x5f, b'3', ""), (0x60, b'3', ""), (0x61, b'V', ""), (0x62, b'V', ""), (0x63, b'V', ""),
+    (0x64, b'V', ""), (0x65, b'V', ""), (0x66, b'V', ""), (0x67, b'V', ""), (0x68, b'V', ""),
+    (0x69, b'V', ""), (0x6a, b'V', ""), (0x6b, b'V', ""), (0x6c, b'V', ""), (0x6d, b'V', ""),
+    (0x6e, b'V', ""), (0x6f, b'V', ""), (0x70, b'V', ""), (0x71, b'V', ""), (0x72, b'V', ""),
+    (0x73, b'V', ""), (0x74, b'V', ""), (0x75, b'V', ""), (0x76, b'V', ""), (0x77, b'V', ""),
+    (0x78, b'V', ""), (0x79, b'V', ""), (0x7a, b'V', ""), (0x7b, b'3', ""), (0x7c, b'3', ""),
+    (0x7d, b'3', ""), (0x7e, b'3', ""), (0x7f, b'3', ""), (0x80, b'X', ""), (0x81, b'X', ""),
+    (0x82, b'X', ""), (0x83, b'X', ""), (0x84, b'X', ""), (0x85, b'X', ""), (0x86, b'X', ""),
+    (0x87, b'X', ""), (0x88, b'X', ""), (0x89, b'X', ""), (0x8a, b'X', ""), (0x8b, b'X', ""),
+    (0x8c, b'X', ""), (0x8d, b'X', ""), (0x8e, b'X', ""), (0x8f, b'X', ""), (0x90, b'X', ""),
+    (0x91, b'X', ""), (0x92, b'X', ""), (0x93, b'X', ""), (0x94, b'X', ""), (0x95, b'X', ""),
+    (0x96, b'X', ""), (0x97, b'X', ""), (0x98, b'X', ""), (0x99, b'X', ""), (0x9a, b'X', ""),
+    (0x9b, b'X', ""), (0x9c, b'X', ""), (0x9d, b'X', ""), (0x9e, b'X', ""), (0x9f, b'X', ""),
+    (0xa0, b'3', " "), (0xa1, b'V', ""), (0xa2, b'V', ""), (0xa3, b'V', ""), (0xa4, b'V', ""),
+    (0xa5, b'V', ""), (0xa6, b'V', ""), (0xa7, b'V', ""), (0xa8, b'3', " \u{308}"),
+    (0xa9, b'V', ""), (0xaa, b'M', "a"), (0xab, b'V', ""), (0xac, b'V', ""), (0xad, b'I', ""),
+    (0xae, b'V', ""), (0xaf, b'3', " \u{304}"), (0xb0, b'V', ""), (0xb1, b'V', ""),
+    (0xb2, b'M', "2"), (0xb3, b'M', "3"), (0xb4, b'3', " \u{301}"), (0xb5, b'M', "\u{3bc}"),
+    (0xb6, b'V', ""), (0xb7, b'V', ""), (0xb8, b'3', " \u{327}"), (0xb9, b'M', "1"),
+    (0xba, b'M', "o"), (0xbb, b'V', ""), (0xbc, b'M', "1\u{2044}4"), (0xbd, b'M', "1\u{2044}2"),
+    (0xbe, b'M', "3\u{2044}4"), (0xbf, b'V', ""), (0xc0, b'M', "\u{e0}"), (0xc1, b'M', "\u{e1}"),
+    (0xc2, b'M', "\u{e2}"), (0xc3, b'M', "\u{e3}"), (0xc4, b'M', "\u{e4}"), (0xc5, b'M', "\u{e5}"),
+    (0xc6, b'M', "\u{e6}"), (0xc7, b'M', "\u{e7}"), (0xc8, b'M', "\u{e8}"), (0xc9, b'M', "\u{e9}"),
+    (0xca, b'M', "\u{ea}"), (0xcb, b'M', "\u{eb}"), (0xcc, b'M', "\u{ec}"), (0xcd, b'M', "\u{ed}"),
+    (0xce, b'M', "\u{ee}"), (0xcf, b'M', "\u{ef}"), (0xd0, b'M', "\u{f0}"), (0xd1, b'M', "\u{f1}"),
+    (0xd2, b'M', "\u{f2}"), (0xd3, b'M', "\u{f3}"), (0xd4, b'M', "\u{f4}"), (0xd5, b'M', "\u{f5}"),
+    (0xd6, b'M', "\u{f6}"), (0xd7, b'V', ""), (0xd8, b'M', "\u{f8}"), (0xd9, b'M', "\u{f9}"),
+    (0xda, b'M', "\u{fa}"), (0xdb, b'M', "\u{fb}"), (0xdc, b'M', "\u{fc}"), (0xdd, b'M', "\u{fd}"),
+    (0xde, b'M', "\u{fe}"), (0xdf, b'D', "ss"), (0xe0, b'V', ""), (0xe1, b'V', ""),
+    (0xe2, b'V', ""), (0xe3, b'V', ""), (0xe4, b'V', ""), (0xe5, b'V', ""), (0xe6, b'V', ""),
+    (0xe7, b'V', ""), (0xe8, b'V', ""), (0xe9, b'V', ""), (0xea, b'V', ""), (0xeb, b'V', ""),
+    (0xec, b'V', ""), (0xed, b'V', ""), (0xee, b'V', ""), (0xef, b'V', ""), (0xf0, b'V', ""),
+    (0xf1, b'V', ""), (0xf2, b'V', ""), (0xf3, b'V', ""), (0xf4, b'V', ""), (0xf5, b'V', ""),
+    (0xf6, b'V', ""), (0xf7, b'V', ""), (0xf8, b'V', ""), (0xf9, b'V', ""), (0xfa, b'V', ""),
+    (0xfb, b'V', ""), (0xfc, b'V', ""), (0xfd, b'V', ""), (0xfe, b'V', ""), (0xff, b'V', ""),
+    (0x100, b'M', "\u{101}"), (0x101, b'V', ""), (0x102, b'M', "\u{103}"), (0x103, b'V', ""),
+    (0x104, b'M', "\u{105}"), (0x105, b'V', ""), (0x106, b'M', "\u{107}"), (0x107, b'V', ""),
+    (0x108, b'M', "\u{109}"), (0x109, b'V', ""), (0x10a, b'M', "\u{10b}"), (0x10b, b'V', ""),
+    (0x10c, b'M', "\u{10d}"), (0x10d, b'V', ""), (0x10e, b'M', "\u{10f}"), (0x10f, b'V', ""),
+    (0x110, b'M', "\u{111}"), (0x111, b'V', ""), (0x112, b'M', "\u{113}"), (0x113, b'V', ""),
+    (0x114, b'M', "\u{115}"), (0x115, b'V', ""), (0x116, b'M', "\u{117}"), (0x117, b'V', ""),
+    (0x118, b'M', "\u{119}"), (0x119, b'V', ""), (0x11a, b'M', "\u{11b}"), (0x11b, b'V', ""),
+    (0x11c, b'M', "\u{11d}"), (0x11d, b'V', ""), (0x11e, b'M', "\u{11f}"), (0x11f, b'V', ""),
+    (0x120, b'M', "\u{121}"), (0x121, b'V', ""), (0x122, b'M', "\u{123}"), (0x123, b'V', ""),
+    (0x124, b'M', "\u{125}"), (0x125, b'V', ""), (0x126, b'M', "\u{127}"), (0x127, b'V', ""),
+    (0x128, b'M', "\u{129}"), (0x129, b'V', ""), (0x12a, b'M', "\u{12b}"), (0x12b, b'V', ""),
+    (0x12c, b'M', "\u{12d}"), (0x12d, b'V', ""), (0x12e, b'M', "\u{12f}"), (0x12f, b'V', ""),
+    (0x130, b'M', "i\u{307}"), (0x131, b'V', ""), (0x132, b'M', "ij"), (0x134, b'M', "\u{135}"),
+    (0x135, b'V', ""), (0x136, b'M', "\u{137}"), (0x137, b'V', ""), (0x139, b'M', "\u{13a}"),
+    (0x13a, b'V', ""), (0x13b, b'M', "\u{13c}"), (0x13c, b'V', ""), (0x13d, b'M', "\u{13e}"),
+    (0x13e, b'V', ""), (0x13f, b'M', "l\u{b7}"), (0x141, b'M', "\u{142}"), (0x142, b'V', ""),
+    (0x143, b'M', "\u{144}"), (0x144, b'V', ""), (0x145, b'M', "\u{146}"), (0x146, b'V', ""),
+    (0x147, b'M', "\u{148}"), (0x148, b'V', ""), (0x149, b'M', "\u{2bc}n"),
+    (0x14a, b'M', "\u{14b}"), (0x14b, b'V', ""), (0x14c, b'M', "\u{14d}"), (0x14d, b'V', ""),
+    (0x14e, b'M', "\u{14f}"), (0x14f, b'V', ""), (0x150, b'M', "\u{151}"), (0x151, b'V', ""),
+    (0x152, b'M', "\u{153}"), (0x153, b'V', ""), (0x154, b'M', "\u{155}"), (0x155, b'V', ""),
+    (0x156, b'M', "\u{157}"), (0x157, b'V', ""), (0x158, b'M', "\u{159}"), (0x159, b'V', ""),
+    (0x15a, b'M', "\u{15b}"), (0x15b, b'V', ""), (0x15c, b'M', "\u{15d}"), (0x15d, b'V', ""),
+    (0x15e, b'M', "\u{15f}"), (0x15f, b'V', ""), (0x160, b'M', "\u{161}"), (0x161, b'V', ""),
+    (0x162, b'M', "\u{163}"), (0x163, b'V', ""), (0x164, b'M', "\u{165}"), (0x165, b'V', ""),
+    (0x166, b'M', "\u{167}"), (0x167, b'V', ""), (0x168, b'M', "\u{169}"), (0x169, b'V', ""),
+    (0x16a, b'M', "\u{16b}"), (0x16b, b'V', ""), (0x16c, b'M', "\u{16d}"), (0x16d, b'V', ""),
+    (0x16e, b'M', "\u{16f}"), (0x16f, b'V', ""), (0x170, b'M', "\u{171}"), (0x171, b'V', ""),
+    (0x172, b'M', "\u{173}"), (0x173, b'V', ""), (0x174, b'M', "\u{175}"), (0x175, b'V', ""),
+    (0x176, b'M', "\u{177}"), (0x177, b'V', ""), (0x178, b'M', "\u{ff}"), (0x179, b'M', "\u{17a}"),
+    (0x17a, b'V', ""), (0x17b, b'M', "\u{17c}"), (0x17c, b'V', ""), (0x17d, b'M', "\u{17e}"),
+    (0x17e, b'V', ""), (0x17f, b'M', "s"), (0x180, b'V', ""), (0x181, b'M', "\u{253}"),
+    (0x182, b'M', "\u{183}"), (0x183, b'V', ""), (0x184, b'M', "\u{185}"), (0x185, b'V', ""),
+    (0x186, b'M', "\u{254}"), (0x187, b'M', "\u{188}"), (0x188, b'V', ""), (0x189, b'M', "\u{256}"),
+    (0x18a, b'M', "\u{257}"), (0x18b, b'M', "\u{18c}"), (0x18c, b'V', ""), (0x18e, b'M', "\u{1dd}"),
+    (0x18f, b'M', "\u{259}"), (0x190, b'M', "\u{25b}"), (0x191, b'M', "\u{192}"), (0x192, b'V', ""),
+    (0x193, b'M', "\u{260}"), (0x194, b'M', "\u{263}"), (0x195, b'V', ""), (0x196, b'M', "\u{269}"),
+    (0x197, b'M', "\u{268}"), (0x198, b'M', "\u{199}"), (0x199, b'V', ""), (0x19c, b'M', "\u{26f}"),
+    (0x19d, b'M', "\u{272}"), (0x19e, b'V', ""), (0x19f, b'M', "\u{275}"), (0x1a0, b'M', "\u{1a1}"),
+    (0x1a1, b'V', ""), (0x1a2, b'M', "\u{1a3}"), (0x1a3, b'V', ""), (0x1a4, b'M', "\u{1a5}"),
+    (0x1a5, b'V', ""), (0x1a6, b'M', "\u{280}"), (0x1a7, b'M', "\u{1a8}"), (0x1a8, b'V', ""),
+    (0x1a9, b'M', "\u{283}"), (0x1aa, b'V', ""), (0x1ac, b'M', "\u{1ad}"), (0x1ad, b'V', ""),
+    (0x1ae, b'M', "\u{288}"), (0x1af, b'M', "\u{1b0}"), (0x1b0, b'V', ""), (0x1b1, b'M', "\u{28a}"),
+    (0x1b2, b'M', "\u{28b}"), (0x1b3, b'M', "\u{1b4}"), (0x1b4, b'V', ""), (0x1b5, b'M', "\u{1b6}"),
+    (0x1b6, b'V', ""), (0x1b7, b'M', "\u{292}"), (0x1b8, b'M', "\u{1b9}"), (0x1b9, b'V', ""),
+    (0x1bc, b'M', "\u{1bd}"), (0x1bd, b'V', ""), (0x1c4, b'M', "d\u{17e}"), (0x1c7, b'M', "lj"),
+    (0x1ca, b'M', "nj"), (0x1cd, b'M', "\u{1ce}"), (0x1ce, b'V', ""), (0x1cf, b'M', "\u{1d0}"),
+    (0x1d0, b'V', ""), (0x1d1, b'M', "\u{1d2}"), (0x1d2, b'V', ""), (0x1d3, b'M', "\u{1d4}"),
+    (0x1d4, b'V', ""), (0x1d5, b'M', "\u{1d6}"), (0x1d6, b'V', ""), (0x1d7, b'M', "\u{1d8}"),
+    (0x1d8, b'V', ""), (0x1d9, b'M', "\u{1da}"), (0x1da, b'V', ""), (0x1db, b'M', "\u{1dc}"),
+    (0x1dc, b'V', ""), (0x1de, b'M', "\u{1df}"), (0x1df, b'V', ""), (0x1e0, b'M', "\u{1e1}"),
+    (0x1e1, b'V', ""), (0x1e2, b'M', "\u{1e3}"), (0x1e3, b'V', ""), (0x1e4, b'M', "\u{1e5}"),
+    (0x1e5, b'V', ""), (0x1e6, b'M', "\u{1e7}"), (0x1e7, b'V', ""), (0x1e8, b'M', "\u{1e9}"),
+    (0x1e9, b'V', ""), (0x1ea, b'M', "\u{1eb}"), (0x1eb, b'V', ""), (0x1ec, b'M', "\u{1ed}"),
+    (0x1ed, b'V', ""), (0x1ee, b'M', "\u{1ef}"), (0x1ef, b'V', ""), (0x1f1, b'M', "dz"),
+    (0x1f4, b'M', "\u{1f5}"), (0x1f5, b'V', ""), (0x1f6, b'M', "\u{195}"), (0x1f7, b'M', "\u{1bf}"),
+    (0x1f8, b'M', "\u{1f9}"), (0x1f9, b'V', ""), (0x1fa, b'M', "\u{1fb}"), (0x1fb, b'V', ""),
+    (0x1fc, b'M', "\u{1fd}"), (0x1fd, b'V', ""), (0x1fe, b'M', "\u{1ff}"), (0x1ff, b'V', ""),
+    (0x200, b'M', "\u{201}"), (0x201, b'V', ""), (0x202, b'M', "\u{203}"), (0x203, b'V', ""),
+    (0x204, b'M', "\u{205}"), (0x205, b'V', ""), (0x206, b'M', "\u{207}"), (0x207, b'V', ""),
+    (0x208, b'M', "\u{209}"), (0x209, b'V', ""), (0x20a, b'M', "\u{20b}"), (0x20b, b'V', ""),
+    (0x20c, b'M', "\u{20d}"), (0x20d, b'V', ""), (0x20e, b'M', "\u{20f}"), (0x20f, b'V', ""),
+    (0x210, b'M', "\u{211}"), (0x211, b'V', ""), (0x212, b'M', "\u{213}"), (0x213, b'V', ""),
+    (0x214, b'M', "\u{215}"), (0x215, b'V', ""), (0x216, b'M', "\u{217}"), (0x217, b'V', ""),
+    (0x218, b'M', "\u{219}"), (0x219, b'V', ""), (0x21a, b'M', "\u{21b}"), (0x21b, b'V', ""),
+    (0x21c, b'M', "\u{21d}"), (0x21d, b'V', ""), (0x21e, b'M', "\u{21f}"), (0x21f, b'V', ""),
+    (0x220, b'M', "\u{19e}"), (0x221, b'V', ""), (0x222, b'M', "\u{223}"), (0x223, b'V', ""),
+    (0x224, b'M', "\u{225}"), (0x225, b'V', ""), (0x226, b'M', "\u{227}"), (0x227, b'V', ""),
+    (0x228, b'M', "\u{229}"), (0x229, b'V', ""), (0x22a, b'M', "\u{22b}"), (0x22b, b'V', ""),
+    (0x22c, b'M', "\u{22d}"), (0x22d, b'V', ""), (0x22e, b'M', "\u{22f}"), (0x22f, b'V', ""),
+    (0x230, b'M', "\u{231}"), (0x231, b'V', ""), (0x232, b'M', "\u{233}"), (0x233, b'V', ""),
+    (0x23a, b'M', "\u{2c65}"), (0x23b, b'M', "\u{23c}"), (0x23c, b'V', ""),
+    (0x23d, b'M', "\u{19a}"), (0x23e, b'M', "\u{2c66}"), (0x23f, b'V', ""),
+    (0x241, b'M', "\u{242}"), (0x242, b'V', ""), (0x243, b'M', "\u{180}"), (0x244, b'M', "\u{289}"),
+    (0x245, b'M', "\u{28c}"), (0x246, b'M', "\u{247}"), (0x247, b'V', ""), (0x248, b'M', "\u{249}"),
+    (0x249, b'V', ""), (0x24a, b'M', "\u{24b}"), (0x24b, b'V', ""), (0x24c, b'M', "\u{24d}"),
+    (0x24d, b'V', ""), (0x24e, b'M', "\u{24f}"), (0x24f, b'V', ""), (0x2b0, b'M', "h"),
+    (0x2b1, b'M', "\u{266}"), (0x2b2, b'M', "j"), (0x2b3, b'M', "r"), (0x2b4, b'M', "\u{279}"),
+    (0x2b5, b'M', "\u{27b}"), (0x2b6, b'M', "\u{281}"), (0x2b7, b'M', "w"), (0x2b8, b'M', "y"),
+    (0x2b9, b'V', ""), (0x2d8, b'3', " \u{306}"), (0x2d9, b'3', " \u{307}"),
+    (0x2da, b'3', " \u{30a}"), (0x2db, b'3', " \u{328}"), (0x2dc, b'3', " \u{303}"),
+    (0x2dd, b'3', " \u{30b}"), (0x2de, b'V', ""), (0x2e0, b'M', "\u{263}"), (0x2e1, b'M', "l"),
+    (0x2e2, b'M', "s"), (0x2e3, b'M', "x"), (0x2e4, b'M', "\u{295}"), (0x2e5, b'V', ""),
+    (0x340, b'M', "\u{300}"), (0x341, b'M', "\u{301}"), (0x342, b'V', ""), (0x343, b'M', "\u{313}"),
+    (0x344, b'M', "\u{308}\u{301}"), (0x345, b'M', "\u{3b9}"), (0x346, b'V', ""), (0x34f, b'I', ""),
+    (0x350, b'V', ""), (0x370, b'M', "\u{371}"), (0x371, b'V', ""), (0x372, b'M', "\u{373}"),
+    (0x373, b'V', ""), (0x374, b'M', "\u{2b9}"), (0x375, b'V', ""), (0x376, b'M', "\u{377}"),
+    (0x377, b'V', ""), (0x378, b'X', ""), (0x37a, b'3', " \u{3b9}"), (0x37b, b'V', ""),
+    (0x37e, b'3', ";"), (0x37f, b'M', "\u{3f3}"), (0x380, b'X', ""), (0x384, b'3', " \u{301}"),
+    (0x385, b'3', " \u{308}\u{301}"), (0x386, b'M', "\u{3ac}"), (0x387, b'M', "\u{b7}"),
+    (0x388, b'M', "\u{3ad}"), (0x389, b'M', "\u{3ae}"), (0x38a, b'M', "\u{3af}"), (0x38b, b'X', ""),
+    (0x38c, b'M', "\u{3cc}"), (0x38d, b'X', ""), (0x38e, b'M', "\u{3cd}"), (0x38f, b'M', "\u{3ce}"),
+    (0x390, b'V', ""), (0x391, b'M', "\u{3b1}"), (0x392, b'M', "\u{3b2}"), (0x393, b'M', "\u{3b3}"),
+    (0x394, b'M', "\u{3b4}"), (0x395, b'M', "\u{3b5}"), (0x396, b'M', "\u{3b6}"),
+    (0x397, b'M', "\u{3b7}"), (0x398, b'M', "\u{3b8}"), (0x399, b'M', "\u{3b9}"),
+    (0x39a, b'M', "\u{3ba}"), (0x39b, b'M', "\u{3bb}"), (0x39c, b'M', "\u{3bc}"),
+    (0x39d, b'M', "\u{3bd}"), (0x39e, b'M', "\u{3be}"), (0x39f, b'M', "\u{3bf}"),
+    (0x3a0, b'M', "\u{3c0}"), (0x3a1, b'M', "\u{3c1}"), (0x3a2, b'X', ""), (0x3a3, b'M', "\u{3c3}"),
+    (0x3a4, b'M', "\u{3c4}"), (0x3a5, b'M', "\u{3c5}"), (0x3a6, b'M', "\u{3c6}"),
+    (0x3a7, b'M', "\u{3c7}"), (0x3a8, b'M', "\u{3c8}"), (0x3a9, b'M', "\u{3c9}"),
+    (0x3aa, b'M', "\u{3ca}"), (0x3ab, b'M', "\u{3cb}"), (0x3ac, b'V', ""), (0x3c2, b'D', "\u{3c3}"),
+    (0x3c3, b'V', ""), (0x3cf, b'M', "\u{3d7}"), (0x3d0, b'M', "\u{3b2}"), (0x3d1, b'M', "\u{3b8}"),
+    (0x3d2, b'M', "\u{3c5}"), (0x3d3, b'M', "\u{3cd}"), (0x3d4, b'M', "\u{3cb}"),
+    (0x3d5, b'M', "\u{3c6}"), (0x3d6, b'M', "\u{3c0}"), (0x3d7, b'V', ""), (0x3d8, b'M', "\u{3d9}"),
+    (0x3d9, b'V', ""), (0x3da, b'M', "\u{3db}"), (0x3db, b'V', ""), (0x3dc, b'M', "\u{3dd}"),
+    (0x3dd, b'V', ""), (0x3de, b'M', "\u{3df}"), (0x3df, b'V', ""), (0x3e0, b'M', "\u{3e1}"),
+    (0x3e1, b'V', ""), (0x3e2, b'M', "\u{3e3}"), (0x3e3, b'V', ""), (0x3e4, b'M', "\u{3e5}"),
+    (0x3e5, b'V', ""), (0x3e6, b'M', "\u{3e7}"), (0x3e7, b'V', ""), (0x3e8, b'M', "\u{3e9}"),
+    (0x3e9, b'V', ""), (0x3ea, b'M', "\u{3eb}"), (0x3eb, b'V', ""), (0x3ec, b'M', "\u{3ed}"),
+    (0x3ed, b'V', ""), (0x3ee, b'M', "\u{3ef}"), (0x3ef, b'V', ""), (0x3f0, b'M', "\u{3ba}"),
+    (0x3f1, b'M', "\u{3c1}"), (0x3f2, b'M', "\u{3c3}"), (0x3f3, b'V', ""), (0x3f4, b'M', "\u{3b8}"),
+    (0x3f5, b'M', "\u{3b5}"), (0x3f6, b'V', ""), (0x3f7, b'M', "\u{3f8}"), (0x3f8, b'V', ""),
+    (0x3f9, b'M', "\u{3c3}"), (0x3fa, b'M', "\u{3fb}"), (0x3fb, b'V', ""), (0x3fd, b'M', "\u{37b}"),
+    (0x3fe, b'M', "\u{37c}"), (0x3ff, b'M', "\u{37d}"), (0x400, b'M', "\u{450}"),
+    (0x401, b'M', "\u{451}"), (0x402, b'M', "\u{452}"), (0x403, b'M', "\u{453}"),
+    (0x404, b'M', "\u{454}"), (0x405, b'M', "\u{455}"), (0x406, b'M', "\u{456}"),
+    (0x407, b'M', "\u{457}"), (0x408, b'M', "\u{458}"), (0x409, b'M', "\u{459}"),
+    (0x40a, b'M', "\u{45a}"), (0x40b, b'M', "\u{45b}"), (0x40c, b'M', "\u{45c}"),
+    (0x40d, b'M', "\u{45d}"), (0x40e, b'M', "\u{45e}"), (0x40f, b'M', "\u{45f}"),
+    (0x410, b'M', "\u{430}"), (0x411, b'M', "\u{431}"), (0x412, b'M', "\u{432}"),
+    (0x413, b'M', "\u{433}"), (0x414, b'M', "\u{434}"), (0x415, b'M', "\u{435}"),
+    (0x416, b'M', "\u{436}"), (0x417, b'M', "\u{437}"), (0x418, b'M', "\u{438}"),
+    (0x419, b'M', "\u{439}"), (0x41a, b'M', "\u{43a}"), (0x41b, b'M', "\u{43b}"),
+    (0x41c, b'M', "\u{43c}"), (0x41d, b'M', "\u{43d}"), (0x41e, b'M', "\u{43e}"),
+    (0x41f, b'M', "\u{43f}"), (0x420, b'M', "\u{440}"), (0x421, b'M', "\u{441}"),
+    (0x422, b'M', "\u{442}"), (0x423, b'M', "\u{443}"), (0x424, b'M', "\u{444}"),
+    (0x425, b'M', "\u{445}"), (0x426, b'M', "\u{446}"), (0x427, b'M', "\u{447}"),
+    (0x428, b'M', "\u{448}"), (0x429, b'M', "\u{449}"), (0x42a, b'M', "\u{44a}"),
+    (0x42b, b'M', "\u{44b}"), (0x42c, b'M', "\u{44c}"), (0x42d, b'M', "\u{44d}"),
+    (0x42e, b'M', "\u{44e}"), (0x42f, b'M', "\u{44f}"), (0x430, b'V', ""), (0x460, b'M', "\u{461}"),
+    (0x461, b'V', ""), (0x462, b'M', "\u{463}"), (0x463, b'V', ""), (0x464, b'M', "\u{465}"),
+    (0x465, b'V', ""), (0x466, b'M', "\u{467}"), (0x467, b'V', ""), (0x468, b'M', "\u{469}"),
+    (0x469, b'V', ""), (0x46a, b'M', "\u{46b}"), (0x46b, b'V', ""), (0x46c, b'M', "\u{46d}"),
+    (0x46d, b'V', ""), (0x46e, b'M', "\u{46f}"), (0x46f, b'V', ""), (0x470, b'M', "\u{471}"),
+    (0x471, b'V', ""), (0x472, b'M', "\u{473}"), (0x473, b'V', ""), (0x474, b'M', "\u{475}"),
+    (0x475, b'V', ""), (0x476, b'M', "\u{477}"), (0x477, b'V', ""), (0x478, b'M', "\u{479}"),
+    (0x479, b'V', ""), (0x47a, b'M', "\u{47b}"), (0x47b, b'V', ""), (0x47c, b'M', "\u{47d}"),
+    (0x47d, b'V', ""), (0x47e, b'M', "\u{47f}"), (0x47f, b'V', ""), (0x480, b'M', "\u{481}"),
+    (0x481, b'V', ""), (0x48a, b'M', "\u{48b}"), (0x48b, b'V', ""), (0x48c, b'M', "\u{48d}"),
+    (0x48d, b'V', ""), (0x48e, b'M', "\u{48f}"), (0x48f, b'V', ""), (0x490, b'M', "\u{491}"),
+    (0x491, b'V', ""), (0x492, b'M', "\u{493}"), (0x493, b'V', ""), (0x494, b'M', "\u{495}"),
+    (0x495, b'V', ""), (0x496, b'M', "\u{497}"), (0x497, b'V', ""), (0x498, b'M', "\u{499}"),
+    (0x499, b'V', ""), (0x49a, b'M', "\u{49b}"), (0x49b, b'V', ""), (0x49c, b'M', "\u{49d}"),
+    (0x49d, b'V', ""), (0x49e, b'M', "\u{49f}"), (0x49f, b'V', ""), (0x4a0, b'M', "\u{4a1}"),
+    (0x4a1, b'V', ""), (0x4a2, b'M', "\u{4a3}"), (0x4a3, b'V', ""), (0x4a4, b'M', "\u{4a5}"),
+    (0x4a5, b'V', ""), (0x4a6, b'M', "\u{4a7}"), (0x4a7, b'V', ""), (0x4a8, b'M', "\u{4a9}"),
+    (0x4a9, b'V', ""), (0x4aa, b'M', "\u{4ab}"), (0x4ab, b'V', ""), (0x4ac, b'M', "\u{4ad}"),
+    (0x4ad, b'V', ""), (0x4ae, b'M', "\u{4af}"), (0x4af, b'V', ""), (0x4b0, b'M', "\u{4b1}"),
+    (0x4b1, b'V', ""), (0x4b2, b'M', "\u{4b3}"), (0x4b3, b'V', ""), (0x4b4, b'M', "\u{4b5}"),
+    (0x4b5, b'V', ""), (0x4b6, b'M', "\u{4b7}"), (0x4b7, b'V', ""), (0x4b8, b'M', "\u{4b9}"),
+    (0x4b9, b'V', ""), (0x4ba, b'M', "\u{4bb}"), (0x4bb, b'V', ""), (0x4bc, b'M', "\u{4bd}"),
+    (0x4bd, b'V', ""), (0x4be, b'M', "\u{4bf}"), (0x4bf, b'V', ""), (0x4c0, b'X', ""),
+    (0x4c1, b'M', "\u{4c2}"), (0x4c2, b'V', ""), (0x4c3, b'M', "\u{4c4}"), (0x4c4, b'V', ""),
+    (0x4c5, b'M', "\u{4c6}"), (0x4c6, b'V', ""), (0x4c7, b'M', "\u{4c8}"), (0x4c8, b'V', ""),
+    (0x4c9, b'M', "\u{4ca}"), (0x4ca, b'V', ""), (0x4cb, b'M', "\u{4cc}"), (0x4cc, b'V', ""),
+    (0x4cd, b'M', "\u{4ce}"), (0x4ce, b'V', ""), (0x4d0, b'M', "\u{4d1}"), (0x4d1, b'V', ""),
+    (0x4d2, b'M', "\u{4d3}"), (0x4d3, b'V', ""), (0x4d4, b'M', "\u{4d5}"), (0x4d5, b'V', ""),
+    (0x4d6, b'M', "\u{4d7}"), (0x4d7, b'V', ""), (0x4d8, b'M', "\u{4d9}"), (0x4d9, b'V', ""),
+    (0x4da, b'M', "\u{4db}"), (0x4db, b'V', ""), (0x4dc, b'M', "\u{4dd}"), (0x4dd, b'V', ""),
+    (0x4de, b'M', "\u{4df}"), (0x4df, b'V', ""), (0x4e0, b'M', "\u{4e1}"), (0x4e1, b'V', ""),
+    (0x4e2, b'M', "\u{4e3}"), (0x4e3, b'V', ""), (0x4e4, b'M', "\u{4e5}"), (0x4e5, b'V', ""),
+    (0x4e6, b'M', "\u{4e7}"), (0x4e7, b'V', ""), (0x4e8, b'M', "\u{4e9}"), (0x4e9, b'V', ""),
+    (0x4ea, b'M', "\u{4eb}"), (0x4eb, b'V', ""), (0x4ec, b'M', "\u{4ed}"), (0x4ed, b'V', ""),
+    (0x4ee, b'M', "\u{4ef}"), (0x4ef, b'V', ""), (0x4f0, b'M', "\u{4f1}"), (0x4f1, b'V', ""),
+    (0x4f2, b'M', "\u{4f3}"), (0x4f3, b'V', ""), (0x4f4, b'M', "\u{4f5}"), (0x4f5, b'V', ""),
+    (0x4f6, b'M', "\u{4f7}"), (0x4f7, b'V', ""), (0x4f8, b'M', "\u{4f9}"), (0x4f9, b'V', ""),
+    (0x4fa, b'M', "\u{4fb}"), (0x4fb, b'V', ""), (0x4fc, b'M', "\u{4fd}"), (0x4fd, b'V', ""),
+    (0x4fe, b'M', "\u{4ff}"), (0x4ff, b'V', ""), (0x500, b'M', "\u{501}"), (0x501, b'V', ""),
+    (0x502, b'M', "\u{503}"), (0x503, b'V', ""), (0x504, b'M', "\u{505}"), (0x505, b'V', ""),
+    (0x506, b'M', "\u{507}"), (0x507, b'V', ""), (0x508, b'M', "\u{509}"), (0x509, b'V', ""),
+    (0x50a, b'M', "\u{50b}"), (0x50b, b'V', ""), (0x50c, b'M', "\u{50d}"), (0x50d, b'V', ""),
+    (0x50e, b'M', "\u{50f}"), (0x50f, b'V', ""), (0x510, b'M', "\u{511}"), (0x511, b'V', ""),
+    (0x512, b'M', "\u{513}"), (0x513, b'V', ""), (0x514, b'M', "\u{515}"), (0x515, b'V', ""),
+    (0x516, b'M', "\u{517}"), (0x517, b'V', ""), (0x518, b'M', "\u{519}"), (0x519, b'V', ""),
+    (0x51a, b'M', "\u{51b}"), (0x51b, b'V', ""), (0x51c, b'M', "\u{51d}"), (0x51d, b'V', ""),
+    (0x51e, b'M', "\u{51f}"), (0x51f, b'V', ""), (0x520, b'M', "\u{521}"), (0x521, b'V', ""),
+    (0x522, b'M', "\u{523}"), (0x523, b'V', ""), (0x524, b'M', "\u{525}"), (0x525, b'V', ""),
+    (0x526, b'M', "\u{527}"), (0x527, b'V', ""), (0x528, b'M', "\u{529}"), (0x529, b'V', ""),
+    (0x52a, b'M', "\u{52b}"), (0x52b, b'V', ""), (0x52c, b'M', "\u{52d}"), (0x52d, b'V', ""),
+    (0x52e, b'M', "\u{52f}"), (0x52f, b'V', ""), (0x530, b'X', ""), (0x531, b'M', "\u{561}"),
+    (0x532, b'M', "\u{562}"), (0x533, b'M', "\u{563}"), (0x534, b'M', "\u{564}"),
+    (0x535, b'M', "\u{565}"), (0x536, b'M', "\u{566}"), (0x537, b'M', "\u{567}"),
+    (0x538, b'M', "\u{568}"), (0x539, b'M', "\u{569}"), (0x53a, b'M', "\u{56a}"),
+    (0x53b, b'M', "\u{56b}"), (0x53c, b'M', "\u{56c}"), (0x53d, b'M', "\u{56d}"),
+    (0x53e, b'M', "\u{56e}"), (0x53f, b'M', "\u{56f}"), (0x540, b'M', "\u{570}"),
+    (0x541, b'M', "\u{571}"), (0x542, b'M', "\u{572}"), (0x543, b'M', "\u{573}"),
+    (0x544, b'M', "\u{574}"), (0x545, b'M', "\u{575}"), (0x546, b'M', "\u{576}"),
+    (0x547, b'M', "\u{577}"), (0x548, b'M', "\u{578}"), (0x549, b'M', "\u{579}"),
+    (0x54a, b'M', "\u{57a}"), (0x54b, b'M', "\u{57b}"), (0x54c, b'M', "\u{57c}"),
+    (0x54d, b'M', "\u{57d}"), (0x54e, b'M', "\u{57e}"), (0x54f, b'M', "\u{57f}"),
+    (0x550, b'M', "\u{580}"), (0x551, b'M', "\u{581}"), (0x552, b'M', "\u{582}"),
+    (0x553, b'M', "\u{583}"), (0x554, b'M', "\u{584}"), (0x555, b'M', "\u{585}"),
+    (0x556, b'M', "\u{586}"), (0x557, b'X', ""), (0x559, b'V', ""), (0x587, b'M', "\u{565}\u{582}"),
+    (0x588, b'V', ""), (0x58b, b'X', ""), (0x58d, b'V', ""), (0x590, b'X', ""), (0x591, b'V', ""),
+    (0x5c8, b'X', ""), (0x5d0, b'V', ""), (0x5eb, b'X', ""), (0x5ef, b'V', ""), (0x5f5, b'X', ""),
+    (0x606, b'V', ""), (0x61c, b'X', ""), (0x61d, b'V', ""), (0x675, b'M', "\u{627}\u{674}"),
+    (0x676, b'M', "\u{648}\u{674}"), (0x677, b'M', "\u{6c7}\u{674}"),
+    (0x678, b'M', "\u{64a}\u{674}"), (0x679, b'V', ""), (0x6dd, b'X', ""), (0x6de, b'V', ""),
+    (0x70e, b'X', ""), (0x710, b'V', ""), (0x74b, b'X', ""), (0x74d, b'V', ""), (0x7b2, b'X', ""),
+    (0x7c0, b'V', ""), (0x7fb, b'X', ""), (0x7fd, b'V', ""), (0x82e, b'X', ""), (0x830, b'V', ""),
+    (0x83f, b'X', ""), (0x840, b'V', ""), (0x85c, b'X', ""), (0x85e, b'V', ""), (0x85f, b'X', ""),
+    (0x860, b'V', ""), (0x86b, b'X', ""), (0x870, b'V', ""), (0x88f, b'X', ""), (0x898, b'V', ""),
+    (0x8e2, b'X', ""), (0x8e3, b'V', ""), (0x958, b'M', "\u{915}\u{93c}"),
+    (0x959, b'M', "\u{916}\u{93c}"), (0x95a, b'M', "\u{917}\u{93c}"),
+    (0x95b, b'M', "\u{91c}\u{93c}"), (0x95c, b'M', "\u{921}\u{93c}"),
+    (0x95d, b'M', "\u{922}\u{93c}"), (0x95e, b'M', "\u{92b}\u{93c}"),
+    (0x95f, b'M', "\u{92f}\u{93c}"), (0x960, b'V', ""), (0x984, b'X', ""), (0x985, b'V', ""),
+    (0x98d, b'X', ""), (0x98f, b'V', ""), (0x991, b'X', ""), (0x993, b'V', ""), (0x9a9, b'X', ""),
+    (0x9aa, b'V', ""), (0x9b1, b'X', ""), (0x9b2, b'V', ""), (0x9b3, b'X', ""), (0x9b6, b'V', ""),
+    (0x9ba, b'X', ""), (0x9bc, b'V', ""), (0x9c5, b'X', ""), (0x9c7, b'V', ""), (0x9c9, b'X', ""),
+    (0x9cb, b'V', ""), (0x9cf, b'X', ""), (0x9d7, b'V', ""), (0x9d8, b'X', ""),
+    (0x9dc, b'M', "\u{9a1}\u{9bc}"), (0x9dd, b'M', "\u{9a2}\u{9bc}"), (0x9de, b'X', ""),
+    (0x9df, b'M', "\u{9af}\u{9bc}"), (0x9e0, b'V', ""), (0x9e4, b'X', ""), (0x9e6, b'V', ""),
+    (0x9ff, b'X', ""), (0xa01, b'V', ""), (0xa04, b'X', ""), (0xa05, b'V', ""), (0xa0b, b'X', ""),
+    (0xa0f, b'V', ""), (0xa11, b'X', ""), (0xa13, b'V', ""), (0xa29, b'X', ""), (0xa2a, b'V', ""),
+    (0xa31, b'X', ""), (0xa32, b'V', ""), (0xa33, b'M', "\u{a32}\u{a3c}"), (0xa34, b'X', ""),
+    (0xa35, b'V', ""), (0xa36, b'M', "\u{a38}\u{a3c}"), (0xa37, b'X', ""), (0xa38, b'V', ""),
+    (0xa3a, b'X', ""), (0xa3c, b'V', ""), (0xa3d, b'X', ""), (0xa3e, b'V', ""), (0xa43, b'X', ""),
+    (0xa47, b'V', ""), (0xa49, b'X', ""), (0xa4b, b'V', ""), (0xa4e, b'X', ""), (0xa51, b'V', ""),
+    (0xa52, b'X', ""), (0xa59, b'M', "\u{a16}\u{a3c}"), (0xa5a, b'M', "\u{a17}\u{a3c}"),
+    (0xa5b, b'M', "\u{a1c}\u{a3c}"), (0xa5c, b'V', ""), (0xa5d, b'X', ""),
+    (0xa5e, b'M', "\u{a2b}\u{a3c}"), (0xa5f, b'X', ""), (0xa66, b'V', ""), (0xa77, b'X', ""),
+    (0xa81, b'V', ""), (0xa84, b'X', ""), (0xa85, b'V', ""), (0xa8e, b'X', ""), (0xa8f, b'V', ""),
+    (0xa92, b'X', ""), (0xa93, b'V', ""), (0xaa9, b'X', ""), (0xaaa, b'V', ""), (0xab1, b'X', ""),
+    (0xab2, b'V', ""), (0xab4, b'X', ""), (0xab5, b'V', ""), (0xaba, b'X', ""), (0xabc, b'V', ""),
+    (0xac6, b'X', ""), (0xac7, b'V', ""), (0xaca, b'X', ""), (0xacb, b'V', ""), (0xace, b'X', ""),
+    (0xad0, b'V', ""), (0xad1, b'X', ""), (0xae0, b'V', ""), (0xae4, b'X', ""), (0xae6, b'V', ""),
+    (0xaf2, b'X', ""), (0xaf9, b'V', ""), (0xb00, b'X', ""), (0xb01, b'V', ""), (0xb04, b'X', ""),
+    (0xb05, b'V', ""), (0xb0d, b'X', ""), (0xb0f, b'V', ""), (0xb11, b'X', ""), (0xb13, b'V', ""),
+    (0xb29, b'X', ""), (0xb2a, b'V', ""), (0xb31, b'X', ""), (0xb32, b'V', ""), (0xb34, b'X', ""),
+    (0xb35, b'V', ""), (0xb3a, b'X', ""), (0xb3c, b'V', ""), (0xb45, b'X', ""), (0xb47, b'V', ""),
+    (0xb49, b'X', ""), (0xb4b, b'V', ""), (0xb4e, b'X', ""), (0xb55, b'V', ""), (0xb58, b'X', ""),
+    (0xb5c, b'M', "\u{b21}\u{b3c}"), (0xb5d, b'M', "\u{b22}\u{b3c}"), (0xb5e, b'X', ""),
+    (0xb5f, b'V', ""), (0xb64, b'X', ""), (0xb66, b'V', ""), (0xb78, b'X', ""), (0xb82, b'V', ""),
+    (0xb84, b'X', ""), (0xb85, b'V', ""), (0xb8b, b'X', ""), (0xb8e, b'V', ""), (0xb91, b'X', ""),
+    (0xb92, b'V', ""), (0xb96, b'X', ""), (0xb99, b'V', ""), (0xb9b, b'X', ""), (0xb9c, b'V', ""),
+    (0xb9d, b'X', ""), (0xb9e, b'V', ""), (0xba0, b'X', ""), (0xba3, b'V', ""), (0xba5, b'X', ""),
+    (0xba8, b'V', ""), (0xbab, b'X', ""), (0xbae, b'V', ""), (0xbba, b'X', ""), (0xbbe, b'V', ""),
+    (0xbc3, b'X', ""), (0xbc6, b'V', ""), (0xbc9, b'X', ""), (0xbca, b'V', ""), (0xbce, b'X', ""),
+    (0xbd0, b'V', ""), (0xbd1, b'X', ""), (0xbd7, b'V', ""), (0xbd8, b'X', ""), (0xbe6, b'V', ""),
+    (0xbfb, b'X', ""), (0xc00, b'V', ""), (0xc0d, b'X', ""), (0xc0e, b'V', ""), (0xc11, b'X', ""),
+    (0xc12, b'V', ""), (0xc29, b'X', ""), (0xc2a, b'V', ""), (0xc3a, b'X', ""), (0xc3c, b'V', ""),
+    (0xc45, b'X', ""), (0xc46, b'V', ""), (0xc49, b'X', ""), (0xc4a, b'V', ""), (0xc4e, b'X', ""),
+    (0xc55, b'V', ""), (0xc57, b'X', ""), (0xc58, b'V', ""), (0xc5b, b'X', ""), (0xc5d, b'V', ""),
+    (0xc5e, b'X', ""), (0xc60, b'V', ""), (0xc64, b'X', ""), (0xc66, b'V', ""), (0xc70, b'X', ""),
+    (0xc77, b'V', ""), (0xc8d, b'X', ""), (0xc8e, b'V', ""), (0xc91, b'X', ""), (0xc92, b'V', ""),
+    (0xca9, b'X', ""), (0xcaa, b'V', ""), (0xcb4, b'X', ""), (0xcb5, b'V', ""), (0xcba, b'X', ""),
+    (0xcbc, b'V', ""), (0xcc5, b'X', ""), (0xcc6, b'V', ""), (0xcc9, b'X', ""), (0xcca, b'V', ""),
+    (0xcce, b'X', ""), (0xcd5, b'V', ""), (0xcd7, b'X', ""), (0xcdd, b'V', ""), (0xcdf, b'X', ""),
+    (0xce0, b'V', ""), (0xce4, b'X', ""), (0xce6, b'V', ""), (0xcf0, b'X', ""), (0xcf1, b'V', ""),
+    (0xcf4, b'X', ""), (0xd00, b'V', ""), (0xd0d, b'X', ""), (0xd0e, b'V', ""), (0xd11, b'X', ""),
+    (0xd12, b'V', ""), (0xd45, b'X', ""), (0xd46, b'V', ""), (0xd49, b'X', ""), (0xd4a, b'V', ""),
+    (0xd50, b'X', ""), (0xd54, b'V', ""), (0xd64, b'X', ""), (0xd66, b'V', ""), (0xd80, b'X', ""),
+    (0xd81, b'V', ""), (0xd84, b'X', ""), (0xd85, b'V', ""), (0xd97, b'X', ""), (0xd9a, b'V', ""),
+    (0xdb2, b'X', ""), (0xdb3, b'V', ""), (0xdbc, b'X', ""), (0xdbd, b'V', ""), (0xdbe, b'X', ""),
+    (0xdc0, b'V', ""), (0xdc7, b'X', ""), (0xdca, b'V', ""), (0xdcb, b'X', ""), (0xdcf, b'V', ""),
+    (0xdd5, b'X', ""), (0xdd6, b'V', ""), (0xdd7, b'X', ""), (0xdd8, b'V', ""), (0xde0, b'X', ""),
+    (0xde6, b'V', ""), (0xdf0, b'X', ""), (0xdf2, b'V', ""), (0xdf5, b'X', ""), (0xe01, b'V', ""),
+    (0xe33, b'M', "\u{e4d}\u{e32}"), (0xe34, b'V', ""), (0xe3b, b'X', ""), (0xe3f, b'V', ""),
+    (0xe5c, b'X', ""), (0xe81, b'V', ""), (0xe83, b'X', ""), (0xe84, b'V', ""), (0xe85, b'X', ""),
+    (0xe86, b'V', ""), (0xe8b, b'X', ""), (0xe8c, b'V', ""), (0xea4, b'X', ""), (0xea5, b'V', ""),
+    (0xea6, b'X', ""), (0xea7, b'V', ""), (0xeb3, b'M', "\u{ecd}\u{eb2}"), (0xeb4, b'V', ""),
+    (0xebe, b'X', ""), (0xec0, b'V', ""), (0xec5, b'X', ""), (0xec6, b'V', ""), (0xec7, b'X', ""),
+    (0xec8, b'V', ""), (0xecf, b'X', ""), (0xed0, b'V', ""), (0xeda, b'X', ""),
+    (0xedc, b'M', "\u{eab}\u{e99}"), (0xedd, b'M', "\u{eab}\u{ea1}"), (0xede, b'V', ""),
+    (0xee0, b'X', ""), (0xf00, b'V', ""), (0xf0c, b'M', "\u{f0b}"), (0xf0d, b'V', ""),
+    (0xf43, b'M', "\u{f42}\u{fb7}"), (0xf44, b'V', ""), (0xf48, b'X', ""), (0xf49, b'V', ""),
+    (0xf4d, b'M', "\u{f4c}\u{fb7}"), (0xf4e, b'V', ""), (0xf52, b'M', "\u{f51}\u{fb7}"),
+    (0xf53, b'V', ""), (0xf57, b'M', "\u{f56}\u{fb7}"), (0xf58, b'V', ""),
+    (0xf5c, b'M', "\u{f5b}\u{fb7}"), (0xf5d, b'V', ""), (0xf69, b'M', "\u{f40}\u{fb5}"),
+    (0xf6a, b'V', ""), (0xf6d, b'X', ""), (0xf71, b'V', ""), (0xf73, b'M', "\u{f71}\u{f72}"),
+    (0xf74, b'V', ""), (0xf75, b'M', "\u{f71}\u{f74}"), (0xf76, b'M', "\u{fb2}\u{f80}"),
+    (0xf77, b'M', "\u{fb2}\u{f71}\u{f80}"), (0xf78, b'M', "\u{fb3}\u{f80}"),
+    (0xf79, b'M', "\u{fb3}\u{f71}\u{f80}"), (0xf7a, b'V', ""), (0xf81, b'M', "\u{f71}\u{f80}"),
+    (0xf82, b'V', ""), (0xf93, b'M', "\u{f92}\u{fb7}"), (0xf94, b'V', ""), (0xf98, b'X', ""),
+    (0xf99, b'V', ""), (0xf9d, b'M', "\u{f9c}\u{fb7}"), (0xf9e, b'V', ""),
+    (0xfa2, b'M', "\u{fa1}\u{fb7}"), (0xfa3, b'V', ""), (0xfa7, b'M', "\u{fa6}\u{fb7}"),
+    (0xfa8, b'V', ""), (0xfac, b'M', "\u{fab}\u{fb7}"), (0xfad, b'V', ""),
+    (0xfb9, b'M', "\u{f90}\u{fb5}"), (0xfba, b'V', ""), (0xfbd, b'X', ""), (0xfbe, b'V', ""),
+    (0xfcd, b'X', ""), (0xfce, b'V', ""), (0xfdb, b'X', ""), (0x1000, b'V', ""), (0x10a0, b'X', ""),
+    (0x10c7, b'M', "\u{2d27}"), (0x10c8, b'X', ""), (0x10cd, b'M', "\u{2d2d}"), (0x10ce, b'X', ""),
+    (0x10d0, b'V', ""), (0x10fc, b'M', "\u{10dc}"), (0x10fd, b'V', ""), (0x115f, b'X', ""),
+    (0x1161, b'V', ""), (0x1249, b'X', ""), (0x124a, b'V', ""), (0x124e, b'X', ""),
+    (0x1250, b'V', ""), (0x1257, b'X', ""), (0x1258, b'V', ""), (0x1259, b'X', ""),
+    (0x125a, b'V', ""), (0x125e, b'X', ""), (0x1260, b'V', ""), (0x1289, b'X', ""),
+    (0x128a, b'V', ""), (0x128e, b'X', ""), (0x1290, b'V', ""), (0x12b1, b'X', ""),
+    (0x12b2, b'V', ""), (0x12b6, b'X', ""), (0x12b8, b'V', ""), (0x12bf, b'X', ""),
+    (0x12c0, b'V', ""), (0x12c1, b'X', ""), (0x12c2, b'V', ""), (0x12c6, b'X', ""),
+    (0x12c8, b'V', ""), (0x12d7, b'X', ""), (0x12d8, b'V', ""), (0x1311, b'X', ""),
+    (0x1312, b'V', ""), (0x1316, b'X', ""), (0x1318, b'V', ""), (0x135b, b'X', ""),
+    (0x135d, b'V', ""), (0x137d, b'X', ""), (0x1380, b'V', ""), (0x139a, b'X', ""),
+    (0x13a0, b'V', ""), (0x13f6, b'X', ""), (0x13f8, b'M', "\u{13f0}"), (0x13f9, b'M', "\u{13f1}"),
+    (0x13fa, b'M', "\u{13f2}"), (0x13fb, b'M', "\u{13f3}"), (0x13fc, b'M', "\u{13f4}"),
+    (0x13fd, b'M', "\u{13f5}"), (0x13fe, b'X', ""), (0x1400, b'V', ""), (0x1680, b'X', ""),
+    (0x1681, b'V', ""), (0x169d, b'X', ""), (0x16a0, b'V', ""), (0x16f9, b'X', ""),
+    (0x1700, b'V', ""), (0x1716, b'X', ""), (0x171f, b'V', ""), (0x1737, b'X', ""),
+    (0x1740, b'V', ""), (0x1754, b'X', ""), (0x1760, b'V', ""), (0x176d, b'X', ""),
+    (0x176e, b'V', ""), (0x1771, b'X', ""), (0x1772, b'V', ""), (0x1774, b'X', ""),
+    (0x1780, b'V', ""), (0x17b4, b'X', ""), (0x17b6, b'V', ""), (0x17de, b'X', ""),
+    (0x17e0, b'V', ""), (0x17ea, b'X', ""), (0x17f0, b'V', ""), (0x17fa, b'X', ""),
+    (0x1800, b'V', ""), (0x1806, b'X', ""), (0x1807, b'V', ""), (0x180b, b'I', ""),
+    (0x180e, b'X', ""), (0x180f, b'I', ""), (0x1810, b'V', ""), (0x181a, b'X', ""),
+    (0x1820, b'V', ""), (0x1879, b'X', ""), (0x1880, b'V', ""), (0x18ab, b'X', ""),
+    (0x18b0, b'V', ""), (0x18f6, b'X', ""), (0x1900, b'V', ""), (0x191f, b'X', ""),
+    (0x1920, b'V', ""), (0x192c, b'X', ""), (0x1930, b'V', ""), (0x193c, b'X', ""),
+    (0x1940, b'V', ""), (0x1941, b'X', ""), (0x1944, b'V', ""), (0x196e, b'X', ""),
+    (0x1970, b'V', ""), (0x1975, b'X', ""), (0x1980, b'V', ""), (0x19ac, b'X', ""),
+    (0x19b0, b'V', ""), (0x19ca, b'X', ""), (0x19d0, b'V', ""), (0x19db, b'X', ""),
+    (0x19de, b'V', ""), (0x1a1c, b'X', ""), (0x1a1e, b'V', ""), (0x1a5f, b'X', ""),
+    (0x1a60, b'V', ""), (0x1a7d, b'X', ""), (0x1a7f, b'V', ""), (0x1a8a, b'X', ""),
+    (0x1a90, b'V', ""), (0x1a9a, b'X', ""), (0x1aa0, b'V', ""), (0x1aae, b'X', ""),
+    (0x1ab0, b'V', ""), (0x1acf, b'X', ""), (0x1b00, b'V', ""), (0x1b4d, b'X', ""),
+    (0x1b50, b'V', ""), (0x1b7f, b'X', ""), (0x1b80, b'V', ""), (0x1bf4, b'X', ""),
+    (0x1bfc, b'V', ""), (0x1c38, b'X', ""), (0x1c3b, b'V', ""), (0x1c4a, b'X', ""),
+    (0x1c4d, b'V', ""), (0x1c80, b'M', "\u{432}"), (0x1c81, b'M', "\u{434}"),
+    (0x1c82, b'M', "\u{43e}"), (0x1c83, b'M', "\u{441}"), (0x1c84, b'M', "\u{442}"),
+    (0x1c86, b'M', "\u{44a}"), (0x1c87, b'M', "\u{463}"), (0x1c88, b'M', "\u{a64b}"),
+    (0x1c89, b'X', ""), (0x1c90, b'M', "\u{10d0}"), (0x1c91, b'M', "\u{10d1}"),
+    (0x1c92, b'M', "\u{10d2}"), (0x1c93, b'M', "\u{10d3}"), (0x1c94, b'M', "\u{10d4}"),
+    (0x1c95, b'M', "\u{10d5}"), (0x1c96, b'M', "\u{10d6}"), (0x1c97, b'M', "\u{10d7}"),
+    (0x1c98, b'M', "\u{10d8}"), (0x1c99, b'M', "\u{10d9}"), (0x1c9a, b'M', "\u{10da}"),
+    (0x1c9b, b'M', "\u{10db}"), (0x1c9c, b'M', "\u{10dc}"), (0x1c9d, b'M', "\u{10dd}"),
+    (0x1c9e, b'M', "\u{10de}"), (0x1c9f, b'M', "\u{10df}"), (0x1ca0, b'M', "\u{10e0}"),
+    (0x1ca1, b'M', "\u{10e1}"), (0x1ca2, b'M', "\u{10e2}"), (0x1ca3, b'M', "\u{10e3}"),
+    (0x1ca4, b'M', "\u{10e4}"), (0x1ca5, b'M', "\u{10e5}"), (0x1ca6, b'M', "\u{10e6}"),
+    (0x1ca7, b'M', "\u{10e7}"), (0x1ca8, b'M', "\u{10e8}"), (0x1ca9, b'M', "\u{10e9}"),
+    (0x1caa, b'M', "\u{10ea}"), (0x1cab, b'M', "\u{10eb}"), (0x1cac, b'M', "\u{10ec}"),
+    (0x1cad, b'M', "\u{10ed}"), (0x1cae, b'M', "\u{10ee}"), (0x1caf, b'M', "\u{10ef}"),
+    (0x1cb0, b'M', "\u{10f0}"), (0x1cb1, b'M', "\u{10f1}"), (0x1cb2, b'M', "\u{10f2}"),
+    (0x1cb3, b'M', "\u{10f3}"), (0x1cb4, b'M', "\u{10f4}"), (0x1cb5, b'M', "\u{10f5}"),
+    (0x1cb6, b'M', "\u{10f6}"), (0x1cb7, b'M', "\u{10f7}"), (0x1cb8, b'M', "\u{10f8}"),
+    (0x1cb9, b'M', "\u{10f9}"), (0x1cba, b'M', "\u{10fa}"), (0x1cbb, b'X', ""),
+    (0x1cbd, b'M', "\u{10fd}"), (0x1cbe, b'M', "\u{10fe}"), (0x1cbf, b'M', "\u{10ff}"),
+    (0x1cc0, b'V', ""), (0x1cc8, b'X', ""), (0x1cd0, b'V', ""), (0x1cfb, b'X', ""),
+    (0x1d00, b'V', ""), (0x1d2c, b'M', "a"), (0x1d2d, b'M', "\u{e6}"), (0x1d2e, b'M', "b"),
+    (0x1d2f, b'V', ""), (0x1d30, b'M', "d"), (0x1d31, b'M', "e"), (0x1d32, b'M', "\u{1dd}"),
+    (0x1d33, b'M', "g"), (0x1d34, b'M', "h"), (0x1d35, b'M', "i"), (0x1d36, b'M', "j"),
+    (0x1d37, b'M', "k"), (0x1d38, b'M', "l"), (0x1d39, b'M', "m"), (0x1d3a, b'M', "n"),
+    (0x1d3b, b'V', ""), (0x1d3c, b'M', "o"), (0x1d3d, b'M', "\u{223}"), (0x1d3e, b'M', "p"),
+    (0x1d3f, b'M', "r"), (0x1d40, b'M', "t"), (0x1d41, b'M', "u"), (0x1d42, b'M', "w"),
+    (0x1d43, b'M', "a"), (0x1d44, b'M', "\u{250}"), (0x1d45, b'M', "\u{251}"),
+    (0x1d46, b'M', "\u{1d02}"), (0x1d47, b'M', "b"), (0x1d48, b'M', "d"), (0x1d49, b'M', "e"),
+    (0x1d4a, b'M', "\u{259}"), (0x1d4b, b'M', "\u{25b}"), (0x1d4c, b'M', "\u{25c}"),
+    (0x1d4d, b'M', "g"), (0x1d4e, b'V', ""), (0x1d4f, b'M', "k"), (0x1d50, b'M', "m"),
+    (0x1d51, b'M', "\u{14b}"), (0x1d52, b'M', "o"), (0x1d53, b'M', "\u{254}"),
+    (0x1d54, b'M', "\u{1d16}"), (0x1d55, b'M', "\u{1d17}"), (0x1d56, b'M', "p"),
+    (0x1d57, b'M', "t"), (0x1d58, b'M', "u"), (0x1d59, b'M', "\u{1d1d}"), (0x1d5a, b'M', "\u{26f}"),
+    (0x1d5b, b'M', "v"), (0x1d5c, b'M', "\u{1d25}"), (0x1d5d, b'M', "\u{3b2}"),
+    (0x1d5e, b'M', "\u{3b3}"), (0x1d5f, b'M', "\u{3b4}"), (0x1d60, b'M', "\u{3c6}"),
+    (0x1d61, b'M', "\u{3c7}"), (0x1d62, b'M', "i"), (0x1d63, b'M', "r"), (0x1d64, b'M', "u"),
+    (0x1d65, b'M', "v"), (0x1d66, b'M', "\u{3b2}"), (0x1d67, b'M', "\u{3b3}"),
+    (0x1d68, b'M', "\u{3c1}"), (0x1d69, b'M', "\u{3c6}"), (0x1d6a, b'M', "\u{3c7}"),
+    (0x1d6b, b'V', ""), (0x1d78, b'M', "\u{43d}"), (0x1d79, b'V', ""), (0x1d9b, b'M', "\u{252}"),
+    (0x1d9c, b'M', "c"), (0x1d9d, b'M', "\u{255}"), (0x1d9e, b'M', "\u{f0}"),
+    (0x1d9f, b'M', "\u{25c}"), (0x1da0, b'M', "f"), (0x1da1, b'M', "\u{25f}"),
+    (0x1da2, b'M', "\u{261}"), (0x1da3, b'M', "\u{265}"), (0x1da4, b'M', "\u{268}"),
+    (0x1da5, b'M', "\u{269}"), (0x1da6, b'M', "\u{26a}"), (0x1da7, b'M', "\u{1d7b}"),
+    (0x1da8, b'M', "\u{29d}"), (0x1da9, b'M', "\u{26d}"), (0x1daa, b'M', "\u{1d85}"),
+    (0x1dab, b'M', "\u{29f}"), (0x1dac, b'M', "\u{271}"), (0x1dad, b'M', "\u{270}"),
+    (0x1dae, b'M', "\u{272}"), (0x1daf, b'M', "\u{273}"), (0x1db0, b'M', "\u{274}"),
+    (0x1db1, b'M', "\u{275}"), (0x1db2, b'M', "\u{278}"), (0x1db3, b'M', "\u{282}"),
+    (0x1db4, b'M', "\u{283}"), (0x1db5, b'M', "\u{1ab}"), (0x1db6, b'M', "\u{289}"),
+    (0x1db7, b'M', "\u{28a}"), (0x1db8, b'M', "\u{1d1c}"), (0x1db9, b'M', "\u{28b}"),
+    (0x1dba, b'M', "\u{28c}"), (0x1dbb, b'M', "z"), (0x1dbc, b'M', "\u{290}"),
+    (0x1dbd, b'M', "\u{291}"), (0x1dbe, b'M', "\u{292}"), (0x1dbf, b'M', "\u{3b8}"),
+    (0x1dc0, b'V', ""), (0x1e00, b'M', "\u{1e01}"), (0x1e01, b'V', ""), (0x1e02, b'M', "\u{1e03}"),
+    (0x1e03, b'V', ""), (0x1e04, b'M', "\u{1e05}"), (0x1e05, b'V', ""), (0x1e06, b'M', "\u{1e07}"),
+    (0x1e07, b'V', ""), (0x1e08, b'M', "\u{1e09}"), (0x1e09, b'V', ""), (0x1e0a, b'M', "\u{1e0b}"),
+    (0x1e0b, b'V', ""), (0x1e0c, b'M', "\u{1e0d}"), (0x1e0d, b'V', ""), (0x1e0e, b'M', "\u{1e0f}"),
+    (0x1e0f, b'V', ""), (0x1e10, b'M', "\u{1e11}"), (0x1e11, b'V', ""), (0x1e12, b'M', "\u{1e13}"),
+    (0x1e13, b'V', ""), (0x1e14, b'M', "\u{1e15}"), (0x1e15, b'V', ""), (0x1e16, b'M', "\u{1e17}"),
+    (0x1e17, b'V', ""), (0x1e18, b'M', "\u{1e19}"), (0x1e19, b'V', ""), (0x1e1a, b'M', "\u{1e1b}"),
+    (0x1e1b, b'V', ""), (0x1e1c, b'M', "\u{1e1d}"), (0x1e1d, b'V', ""), (0x1e1e, b'M', "\u{1e1f}"),
+    (0x1e1f, b'V', ""), (0x1e20, b'M', "\u{1e21}"), (0x1e21, b'V', ""), (0x1e22, b'M', "\u{1e23}"),
+    (0x1e23, b'V', ""), (0x1e24, b'M', "\u{1e25}"), (0x1e25, b'V', ""), (0x1e26, b'M', "\u{1e27}"),
+    (0x1e27, b'V', ""), (0x1e28, b'M', "\u{1e29}"), (0x1e29, b'V', ""), (0x1e2a, b'M', "\u{1e2b}"),
+    (0x1e2b, b'V', ""), (0x1e2c, b'M', "\u{1e2d}"), (0x1e2d, b'V', ""), (0x1e2e, b'M', "\u{1e2f}"),
+    (0x1e2f, b'V', ""), (0x1e30, b'M', "\u{1e31}"), (0x1e31, b'V', ""), (0x1e32, b'M', "\u{1e33}"),
+    (0x1e33, b'V', ""), (0x1e34, b'M', "\u{1e35}"), (0x1e35, b'V', ""), (0x1e36, b'M', "\u{1e37}"),
+    (0x1e37, b'V', ""), (0x1e38, b'M', "\u{1e39}"), (0x1e39, b'V', ""), (0x1e3a, b'M', "\u{1e3b}"),
+    (0x1e3b, b'V', ""), (0x1e3c, b'M', "\u{1e3d}"), (0x1e3d, b'V', ""), (0x1e3e, b'M', "\u{1e3f}"),
+    (0x1e3f, b'V', ""), (0x1e40, b'M', "\u{1e41}"), (0x1e41, b'V', ""), (0x1e42, b'M', "\u{1e43}"),
+    (0x1e43, b'V', ""), (0x1e44, b'M', "\u{1e45}"), (0x1e45, b'V', ""), (0x1e46, b'M', "\u{1e47}"),
+    (0x1e47, b'V', ""), (0x1e48, b'M', "\u{1e49}"), (0x1e49, b'V', ""), (0x1e4a, b'M', "\u{1e4b}"),
+    (0x1e4b, b'V', ""), (0x1e4c, b'M', "\u{1e4d}"), (0x1e4d, b'V', ""), (0x1e4e, b'M', "\u{1e4f}"),
+    (0x1e4f, b'V', ""), (0x1e50, b'M', "\u{1e51}"), (0x1e51, b'V', ""), (0x1e52, b'M', "\u{1e53}"),
+    (0x1e53, b'V', ""), (0x1e54, b'M', "\u{1e55}"), (0x1e55, b'V', ""), (0x1e56, b'M', "\u{1e57}"),
+    (0x1e57, b'V', ""), (0x1e58, b'M', "\u{1e59}"), (0x1e59, b'V', ""), (0x1e5a, b'M', "\u{1e5b}"),
+    (0x1e5b, b'V', ""), (0x1e5c, b'M', "\u{1e5d}"), (0x1e5d, b'V', ""), (0x1e5e, b'M', "\u{1e5f}"),
+    (0x1e5f, b'V', ""), (0x1e60, b'M', "\u{1e61}"), (0x1e61, b'V', ""), (0x1e62, b'M', "\u{1e63}"),
+    (0x1e63, b'V', ""), (0x1e64, b'M', "\u{1e65}"), (0x1e65, b'V', ""), (0x1e66, b'M', "\u{1e67}"),
+    (0x1e67, b'V', ""), (0x1e68, b'M', "\u{1e69}"), (0x1e69, b'V', ""), (0x1e6a, b'M', "\u{1e6b}"),
+    (0x1e6b, b'V', ""), (0x1e6c, b'M', "\u{1e6d}"), (0x1e6d, b'V', ""), (0x1e6e, b'M', "\u{1e6f}"),
+    (0x1e6f, b'V', ""), (0x1e70, b'M', "\u{1e71}"), (0x1e71, b'V', ""), (0x1e72, b'M', "\u{1e73}"),
+    (0x1e73, b'V', ""), (0x1e74, b'M', "\u{1e75}"), (0x1e75, b'V', ""), (0x1e76, b'M', "\u{1e77}"),
+    (0x1e77, b'V', ""), (0x1e78, b'M', "\u{1e79}"), (0x1e79, b'V', ""), (0x1e7a, b'M', "\u{1e7b}"),
+    (0x1e7b, b'V', ""), (0x1e7c, b'M', "\u{1e7d}"), (0x1e7d, b'V', ""), (0x1e7e, b'M', "\u{1e7f}"),
+    (0x1e7f, b'V', ""), (0x1e80, b'M', "\u{1e81}"), (0x1e81, b'V', ""), (0x1e82, b'M', "\u{1e83}"),
+    (0x1e83, b'V', ""), (0x1e84, b'M', "\u{1e85}"), (0x1e85, b'V', ""), (0x1e86, b'M', "\u{1e87}"),
+    (0x1e87, b'V', ""), (0x1e88, b'M', "\u{1e89}"), (0x1e89, b'V', ""), (0x1e8a, b'M', "\u{1e8b}"),
+    (0x1e8b, b'V', ""), (0x1e8c, b'M', "\u{1e8d}"), (0x1e8d, b'V', ""), (0x1e8e, b'M', "\u{1e8f}"),
+    (0x1e8f, b'V', ""), (0x1e90, b'M', "\u{1e91}"), (0x1e91, b'V', ""), (0x1e92, b'M', "\u{1e93}"),
+    (0x1e93, b'V', ""), (0x1e94, b'M', "\u{1e95}"), (0x1e95, b'V', ""), (0x1e9a, b'M', "a\u{2be}"),
+    (0x1e9b, b'M', "\u{1e61}"), (0x1e9c, b'V', ""), (0x1e9e, b'M', "\u{df}"), (0x1e9f, b'V', ""),
+    (0x1ea0, b'M', "\u{1ea1}"), (0x1ea1, b'V', ""), (0x1ea2, b'M', "\u{1ea3}"), (0x1ea3, b'V', ""),
+    (0x1ea4, b'M', "\u{1ea5}"), (0x1ea5, b'V', ""), (0x1ea6, b'M', "\u{1ea7}"), (0x1ea7, b'V', ""),
+    (0x1ea8, b'M', "\u{1ea9}"), (0x1ea9, b'V', ""), (0x1eaa, b'M', "\u{1eab}"), (0x1eab, b'V', ""),
+    (0x1eac, b'M', "\u{1ead}"), (0x1ead, b'V', ""), (0x1eae, b'M', "\u{1eaf}"), (0x1eaf, b'V', ""),
+    (0x1eb0, b'M', "\u{1eb1}"), (0x1eb1, b'V', ""), (0x1eb2, b'M', "\u{1eb3}"), (0x1eb3, b'V', ""),
+    (0x1eb4, b'M', "\u{1eb5}"), (0x1eb5, b'V', ""), (0x1eb6, b'M', "\u{1eb7}"), (0x1eb7, b'V', ""),
+    (0x1eb8, b'M', "\u{1eb9}"), (0x1eb9, b'V', ""), (0x1eba, b'M', "\u{1ebb}"), (0x1ebb, b'V', ""),
+    (0x1ebc, b'M', "\u{1ebd}"), (0x1ebd, b'V', ""), (0x1ebe, b'M', "\u{1ebf}"), (0x1ebf, b'V', ""),
+    (0x1ec0, b'M', "\u{1ec1}"), (0x1ec1, b'V', ""), (0x1ec2, b'M', "\u{1ec3}"), (0x1ec3, b'V', ""),
+    (0x1ec4, b'M', "\u{1ec5}"), (0x1ec5, b'V', ""), (0x1ec6, b'M', "\u{1ec7}"), (0x1ec7, b'V', ""),
+    (0x1ec8, b'M', "\u{1ec9}"), (0x1ec9, b'V', ""), (0x1eca, b'M', "\u{1ecb}"), (0x1ecb, b'V', ""),
+    (0x1ecc, b'M', "\u{1ecd}"), (0x1ecd, b'V', ""), (0x1ece, b'M', "\u{1ecf}"), (0x1ecf, b'V', ""),
+    (0x1ed0, b'M', "\u{1ed1}"), (0x1ed1, b'V', ""), (0x1ed2, b'M', "\u{1ed3}"), (0x1ed3, b'V', ""),
+    (0x1ed4, b'M', "\u{1ed5}"), (0x1ed5, b'V', ""), (0x1ed6, b'M', "\u{1ed7}"), (0x1ed7, b'V', ""),
+    (0x1ed8, b'M', "\u{1ed9}"), (0x1ed9, b'V', ""), (0x1eda, b'M', "\u{1edb}"), (0x1edb, b'V', ""),
+    (0x1edc, b'M', "\u{1edd}"), (0x1edd, b'V', ""), (0x1ede, b'M', "\u{1edf}"), (0x1edf, b'V', ""),
+    (0x1ee0, b'M', "\u{1ee1}"), (0x1ee1, b'V', ""), (0x1ee2, b'M', "\u{1ee3}"), (0x1ee3, b'V', ""),
+    (0x1ee4, b'M', "\u{1ee5}"), (0x1ee5, b'V', ""), (0x1ee6, b'M', "\u{1ee7}"), (0x1ee7, b'V', ""),
+    (0x1ee8, b'M', "\u{1ee9}"), (0x1ee9, b'V', ""), (0x1eea, b'M', "\u{1eeb}"), (0x1eeb, b'V', ""),
+    (0x1eec, b'M', "\u{1eed}"), (0x1eed, b'V', ""), (0x1eee, b'M', "\u{1eef}"), (0x1eef, b'V', ""),
+    (0x1ef0, b'M', "\u{1ef1}"), (0x1ef1, b'V', ""), (0x1ef2, b'M', "\u{1ef3}"), (0x1ef3, b'V', ""),
+    (0x1ef4, b'M', "\u{1ef5}"), (0x1ef5, b'V', ""), (0x1ef6, b'M', "\u{1ef7}"), (0x1ef7, b'V', ""),
+    (0x1ef8, b'M', "\u{1ef9}"), (0x1ef9, b'V', ""), (0x1efa, b'M', "\u{1efb}"), (0x1efb, b'V', ""),
+    (0x1efc, b'M', "\u{1efd}"), (0x1efd, b'V', ""), (0x1efe, b'M', "\u{1eff}"), (0x1eff, b'V', ""),
+    (0x1f08, b'M', "\u{1f00}"), (0x1f09, b'M', "\u{1f01}"), (0x1f0a, b'M', "\u{1f02}"),
+    (0x1f0b, b'M', "\u{1f03}"), (0x1f0c, b'M', "\u{1f04}"), (0x1f0d, b'M', "\u{1f05}"),
+    (0x1f0e, b'M', "\u{1f06}"), (0x1f0f, b'M', "\u{1f07}"), (0x1f10, b'V', ""), (0x1f16, b'X', ""),
+    (0x1f18, b'M', "\u{1f10}"), (0x1f19, b'M', "\u{1f11}"), (0x1f1a, b'M', "\u{1f12}"),
+    (0x1f1b, b'M', "\u{1f13}"), (0x1f1c, b'M', "\u{1f14}"), (0x1f1d, b'M', "\u{1f15}"),
+    (0x1f1e, b'X', ""), (0x1f20, b'V', ""), (0x1f28, b'M', "\u{1f20}"), (0x1f29, b'M', "\u{1f21}"),
+    (0x1f2a, b'M', "\u{1f22}"), (0x1f2b, b'M', "\u{1f23}"), (0x1f2c, b'M', "\u{1f24}"),
+    (0x1f2d, b'M', "\u{1f25}"), (0x1f2e, b'M', "\u{1f26}"), (0x1f2f, b'M', "\u{1f27}"),
+    (0x1f30, b'V', ""), (0x1f38, b'M', "\u{1f30}"), (0x1f39, b'M', "\u{1f31}"),
+    (0x1f3a, b'M', "\u{1f32}"), (0x1f3b, b'M', "\u{1f33}"), (0x1f3c, b'M', "\u{1f34}"),
+    (0x1f3d, b'M', "\u{1f35}"), (0x1f3e, b'M', "\u{1f36}"), (0x1f3f, b'M', "\u{1f37}"),
+    (0x1f40, b'V', ""), (0x1f46, b'X', ""), (0x1f48, b'M', "\u{1f40}"), (0x1f49, b'M', "\u{1f41}"),
+    (0x1f4a, b'M', "\u{1f42}"), (0x1f4b, b'M', "\u{1f43}"), (0x1f4c, b'M', "\u{1f44}"),
+    (0x1f4d, b'M', "\u{1f45}"), (0x1f4e, b'X', ""), (0x1f50, b'V', ""), (0x1f58, b'X', ""),
+    (0x1f59, b'M', "\u{1f51}"), (0x1f5a, b'X', ""), (0x1f5b, b'M', "\u{1f53}"), (0x1f5c, b'X', ""),
+    (0x1f5d, b'M', "\u{1f55}"), (0x1f5e, b'X', ""), (0x1f5f, b'M', "\u{1f57}"), (0x1f60, b'V', ""),
+    (0x1f68, b'M', "\u{1f60}"), (0x1f69, b'M', "\u{1f61}"), (0x1f6a, b'M', "\u{1f62}"),
+    (0x1f6b, b'M', "\u{1f63}"), (0x1f6c, b'M', "\u{1f64}"), (0x1f6d, b'M', "\u{1f65}"),
+    (0x1f6e, b'M', "\u{1f66}"), (0x1f6f, b'M', "\u{1f67}"), (0x1f70, b'V', ""),
+    (0x1f71, b'M', "\u{3ac}"), (0x1f72, b'V', ""), (0x1f73, b'M', "\u{3ad}"), (0x1f74, b'V', ""),
+    (0x1f75, b'M', "\u{3ae}"), (0x1f76, b'V', ""), (0x1f77, b'M', "\u{3af}"), (0x1f78, b'V', ""),
+    (0x1f79, b'M', "\u{3cc}"), (0x1f7a, b'V', ""), (0x1f7b, b'M', "\u{3cd}"), (0x1f7c, b'V', ""),
+    (0x1f7d, b'M', "\u{3ce}"), (0x1f7e, b'X', ""), (0x1f80, b'M', "\u{1f00}\u{3b9}"),
+    (0x1f81, b'M', "\u{1f01}\u{3b9}"), (0x1f82, b'M', "\u{1f02}\u{3b9}"),
+    (0x1f83, b'M', "\u{1f03}\u{3b9}"), (0x1f84, b'M', "\u{1f04}\u{3b9}"),
+    (0x1f85, b'M', "\u{1f05}\u{3b9}"), (0x1f86, b'M', "\u{1f06}\u{3b9}"),
+    (0x1f87, b'M', "\u{1f07}\u{3b9}"), (0x1f88, b'M', "\u{1f00}\u{3b9}"),
+    (0x1f89, b'M', "\u{1f01}\u{3b9}"), (0x1f8a, b'M', "\u{1f02}\u{3b9}"),
+    (0x1f8b, b'M', "\u{1f03}\u{3b9}"), (0x1f8c, b'M', "\u{1f04}\u{3b9}"),
+    (0x1f8d, b'M', "\u{1f05}\u{3b9}"), (0x1f8e, b'M', "\u{1f06}\u{3b9}"),
+    (0x1f8f, b'M', "\u{1f07}\u{3b9}"), (0x1f90, b'M', "\u{1f20}\u{3b9}"),
+    (0x1f91, b'M', "\u{1f21}\u{3b9}"), (0x1f92, b'M', "\u{1f22}\u{3b9}"),
+    (0x1f93, b'M', "\u{1f23}\u{3b9}"), (0x1f94, b'M', "\u{1f24}\u{3b9}"),
+    (0x1f95, b'M', "\u{1f25}\u{3b9}"), (0x1f96, b'M', "\u{1f26}\u{3b9}"),
+    (0x1f97, b'M', "\u{1f27}\u{3b9}"), (0x1f98, b'M', "\u{1f20}\u{3b9}"),
+    (0x1f99, b'M', "\u{1f21}\u{3b9}"), (0x1f9a, b'M', "\u{1f22}\u{3b9}"),
+    (0x1f9b, b'M', "\u{1f23}\u{3b9}"), (0x1f9c, b'M', "\u{1f24}\u{3b9}"),
+    (0x1f9d, b'M', "\u{1f25}\u{3b9}"), (0x1f9e, b'M', "\u{1f26}\u{3b9}"),
+    (0x1f9f, b'M', "\u{1f27}\u{3b9}"), (0x1fa0, b'M', "\u{1f60}\u{3b9}"),
+    (0x1fa1, b'M', "\u{1f61}\u{3b9}"), (0x1fa2, b'M', "\u{1f62}\u{3b9}"),
+    (0x1fa3, b'M', "\u{1f63}\u{3b9}"), (0x1fa4, b'M', "\u{1f64}\u{3b9}"),
+    (0x1fa5, b'M', "\u{1f65}\u{3b9}"), (0x1fa6, b'M', "\u{1f66}\u{3b9}"),
+    (0x1fa7, b'M', "\u{1f67}\u{3b9}"), (0x1fa8, b'M', "\u{1f60}\u{3b9}"),
+    (0x1fa9, b'M', "\u{1f61}\u{3b9}"), (0x1faa, b'M', "\u{1f62}\u{3b9}"),
+    (0x1fab, b'M', "\u{1f63}\u{3b9}"), (0x1fac, b'M', "\u{1f64}\u{3b9}"),
+    (0x1fad, b'M', "\u{1f65}\u{3b9}"), (0x1fae, b'M', "\u{1f66}\u{3b9}"),
+    (0x1faf, b'M', "\u{1f67}\u{3b9}"), (0x1fb0, b'V', ""), (0x1fb2, b'M', "\u{1f70}\u{3b9}"),
+    (0x1fb3, b'M', "\u{3b1}\u{3b9}"), (0x1fb4, b'M', "\u{3ac}\u{3b9}"), (0x1fb5, b'X', ""),
+    (0x1fb6, b'V', ""), (0x1fb7, b'M', "\u{1fb6}\u{3b9}"), (0x1fb8, b'M', "\u{1fb0}"),
+    (0x1fb9, b'M', "\u{1fb1}"), (0x1fba, b'M', "\u{1f70}"), (0x1fbb, b'M', "\u{3ac}"),
+    (0x1fbc, b'M', "\u{3b1}\u{3b9}"), (0x1fbd, b'3', " \u{313}"), (0x1fbe, b'M', "\u{3b9}"),
+    (0x1fbf, b'3', " \u{313}"), (0x1fc0, b'3', " \u{342}"), (0x1fc1, b'3', " \u{308}\u{342}"),
+    (0x1fc2, b'M', "\u{1f74}\u{3b9}"), (0x1fc3, b'M', "\u{3b7}\u{3b9}"),
+    (0x1fc4, b'M', "\u{3ae}\u{3b9}"), (0x1fc5, b'X', ""), (0x1fc6, b'V', ""),
+    (0x1fc7, b'M', "\u{1fc6}\u{3b9}"), (0x1fc8, b'M', "\u{1f72}"), (0x1fc9, b'M', "\u{3ad}"),
+    (0x1fca, b'M', "\u{1f74}"), (0x1fcb, b'M', "\u{3ae}"), (0x1fcc, b'M', "\u{3b7}\u{3b9}"),
+    (0x1fcd, b'3', " \u{313}\u{300}"), (0x1fce, b'3', " \u{313}\u{301}"),
+    (0x1fcf, b'3', " \u{313}\u{342}"), (0x1fd0, b'V', ""), (0x1fd3, b'M', "\u{390}"),
+    (0x1fd4, b'X', ""), (0x1fd6, b'V', ""), (0x1fd8, b'M', "\u{1fd0}"), (0x1fd9, b'M', "\u{1fd1}"),
+    (0x1fda, b'M', "\u{1f76}"), (0x1fdb, b'M', "\u{3af}"), (0x1fdc, b'X', ""),
+    (0x1fdd, b'3', " \u{314}\u{300}"), (0x1fde, b'3', " \u{314}\u{301}"),
+    (0x1fdf, b'3', " \u{314}\u{342}"), (0x1fe0, b'V', ""), (0x1fe3, b'M', "\u{3b0}"),
+    (0x1fe4, b'V', ""), (0x1fe8, b'M', "\u{1fe0}"), (0x1fe9, b'M', "\u{1fe1}"),
+    (0x1fea, b'M', "\u{1f7a}"), (0x1feb, b'M', "\u{3cd}"), (0x1fec, b'M', "\u{1fe5}"),
+    (0x1fed, b'3', " \u{308}\u{300}"), (0x1fee, b'3', " \u{308}\u{301}"), (0x1fef, b'3', "`"),
+    (0x1ff0, b'X', ""), (0x1ff2, b'M', "\u{1f7c}\u{3b9}"), (0x1ff3, b'M', "\u{3c9}\u{3b9}"),
+    (0x1ff4, b'M', "\u{3ce}\u{3b9}"), (0x1ff5, b'X', ""), (0x1ff6, b'V', ""),
+    (0x1ff7, b'M', "\u{1ff6}\u{3b9}"), (0x1ff8, b'M', "\u{1f78}"), (0x1ff9, b'M', "\u{3cc}"),
+    (0x1ffa, b'M', "\u{1f7c}"), (0x1ffb, b'M', "\u{3ce}"), (0x1ffc, b'M', "\u{3c9}\u{3b9}"),
+    (0x1ffd, b'3', " \u{301}"), (0x1ffe, b'3', " \u{314}"), (0x1fff, b'X', ""), (0x2000, b'3', " "),
+    (0x200b, b'I', ""), (0x200c, b'D', ""), (0x200e, b'X', ""), (0x2010, b'V', ""),
+    (0x2011, b'M', "\u{2010}"), (0x2012, b'V', ""), (0x2017, b'3', " \u{333}"), (0x2018, b'V', ""),
+    (0x2024, b'X', ""), (0x2027, b'V', ""), (0x2028, b'X', ""), (0x202f, b'3', " "),
+    (0x2030, b'V', ""), (0x2033, b'M', "\u{2032}\u{2032}"),
+    (0x2034, b'M', "\u{2032}\u{2032}\u{2032}"), (0x2035, b'V', ""),
+    (0x2036, b'M', "\u{2035}\u{2035}"), (0x2037, b'M', "\u{2035}\u{2035}\u{2035}"),
+    (0x2038, b'V', ""), (0x203c, b'3', "!!"), (0x203d, b'V', ""), (0x203e, b'3', " \u{305}"),
+    (0x203f, b'V', ""), (0x2047, b'3', "??"), (0x2048, b'3', "?!"), (0x2049, b'3', "!?"),
+    (0x204a, b'V', ""), (0x2057, b'M', "\u{2032}\u{2032}\u{2032}\u{2032}"), (0x2058, b'V', ""),
+    (0x205f, b'3', " "), (0x2060, b'I', ""), (0x2061, b'X', ""), (0x2064, b'I', ""),
+    (0x2065, b'X', ""), (0x2070, b'M', "0"), (0x2071, b'M', "i"), (0x2072, b'X', ""),
+    (0x2074, b'M', "4"), (0x2075, b'M', "5"), (0x2076, b'M', "6"), (0x2077, b'M', "7"),
+    (0x2078, b'M', "8"), (0x2079, b'M', "9"), (0x207a, b'3', "+"), (0x207b, b'M', "\u{2212}"),
+    (0x207c, b'3', "="), (0x207d, b'3', "("), (0x207e, b'3', ")"), (0x207f, b'M', "n"),
+    (0x2080, b'M', "0"), (0x2081, b'M', "1"), (0x2082, b'M', "2"), (0x2083, b'M', "3"),
+    (0x2084, b'M', "4"), (0x2085, b'M', "5"), (0x2086, b'M', "6"), (0x2087, b'M', "7"),
+    (0x2088, b'M', "8"), (0x2089, b'M', "9"), (0x208a, b'3', "+"), (0x208b, b'M', "\u{2212}"),
+    (0x208c, b'3', "="), (0x208d, b'3', "("), (0x208e, b'3', ")"), (0x208f, b'X', ""),
+    (0x2090, b'M', "a"), (0x2091, b'M', "e"), (0x2092, b'M', "o"), (0x2093, b'M', "x"),
+    (0x2094, b'M', "\u{259}"), (0x2095, b'M', "h"), (0x2096, b'M', "k"), (0x2097, b'M', "l"),
+    (0x2098, b'M', "m"), (0x2099, b'M', "n"), (0x209a, b'M', "p"), (0x209b, b'M', "s"),
+    (0x209c, b'M', "t"), (0x209d, b'X', ""), (0x20a0, b'V', ""), (0x20a8, b'M', "rs"),
+    (0x20a9, b'V', ""), (0x20c1, b'X', ""), (0x20d0, b'V', ""), (0x20f1, b'X', ""),
+    (0x2100, b'3', "a/c"), (0x2101, b'3', "a/s"), (0x2102, b'M', "c"), (0x2103, b'M', "\u{b0}c"),
+    (0x2104, b'V', ""), (0x2105, b'3', "c/o"), (0x2106, b'3', "c/u"), (0x2107, b'M', "\u{25b}"),
+    (0x2108, b'V', ""), (0x2109, b'M', "\u{b0}f"), (0x210a, b'M', "g"), (0x210b, b'M', "h"),
+    (0x210f, b'M', "\u{127}"), (0x2110, b'M', "i"), (0x2112, b'M', "l"), (0x2114, b'V', ""),
+    (0x2115, b'M', "n"), (0x2116, b'M', "no"), (0x2117, b'V', ""), (0x2119, b'M', "p"),
+    (0x211a, b'M', "q"), (0x211b, b'M', "r"), (0x211e, b'V', ""), (0x2120, b'M', "sm"),
+    (0x2121, b'M', "tel"), (0x2122, b'M', "tm"), (0x2123, b'V', ""), (0x2124, b'M', "z"),
+    (0x2125, b'V', ""), (0x2126, b'M', "\u{3c9}"), (0x2127, b'V', ""), (0x2128, b'M', "z"),
+    (0x2129, b'V', ""), (0x212a, b'M', "k"), (0x212b, b'M', "\u{e5}"), (0x212c, b'M', "b"),
+    (0x212d, b'M', "c"), (0x212e, b'V', ""), (0x212f, b'M', "e"), (0x2131, b'M', "f"),
+    (0x2132, b'X', ""), (0x2133, b'M', "m"), (0x2134, b'M', "o"), (0x2135, b'M', "\u{5d0}"),
+    (0x2136, b'M', "\u{5d1}"), (0x2137, b'M', "\u{5d2}"), (0x2138, b'M', "\u{5d3}"),
+    (0x2139, b'M', "i"), (0x213a, b'V', ""), (0x213b, b'M', "fax"), (0x213c, b'M', "\u{3c0}"),
+    (0x213d, b'M', "\u{3b3}"), (0x213f, b'M', "\u{3c0}"), (0x2140, b'M', "\u{2211}"),
+    (0x2141, b'V', ""), (0x2145, b'M', "d"), (0x2147, b'M', "e"), (0x2148, b'M', "i"),
+    (0x2149, b'M', "j"), (0x214a, b'V', ""), (0x2150, b'M', "1\u{2044}7"),
+    (0x2151, b'M', "1\u{2044}9"), (0x2152, b'M', "1\u{2044}10"), (0x2153, b'M', "1\u{2044}3"),
+    (0x2154, b'M', "2\u{2044}3"), (0x2155, b'M', "1\u{2044}5"), (0x2156, b'M', "2\u{2044}5"),
+    (0x2157, b'M', "3\u{2044}5"), (0x2158, b'M', "4\u{2044}5"), (0x2159, b'M', "1\u{2044}6"),
+    (0x215a, b'M', "5\u{2044}6"), (0x215b, b'M', "1\u{2044}8"), (0x215c, b'M', "3\u{2044}8"),
+    (0x215d, b'M', "5\u{2044}8"), (0x215e, b'M', "7\u{2044}8"), (0x215f, b'M', "1\u{2044}"),
+    (0x2160, b'M', "i"), (0x2161, b'M', "ii"), (0x2162, b'M', "iii"), (0x2163, b'M', "iv"),
+    (0x2164, b'M', "v"), (0x2165, b'M', "vi"), (0x2166, b'M', "vii"), (0x2167, b'M', "viii"),
+    (0x2168, b'M', "ix"), (0x2169, b'M', "x"), (0x216a, b'M', "xi"), (0x216b, b'M', "xii"),
+    (0x216c, b'M', "l"), (0x216d, b'M', "c"), (0x216e, b'M', "d"), (0x216f, b'M', "m"),
+    (0x2170, b'M', "i"), (0x2171, b'M', "ii"), (0x2172, b'M', "iii"), (0x2173, b'M', "iv"),
+    (0x2174, b'M', "v"), (0x2175, b'M', "vi"), (0x2176, b'M', "vii"), (0x2177, b'M', "viii"),
+    (0x2178, b'M', "ix"), (0x2179, b'M', "x"), (0x217a, b'M', "xi"), (0x217b, b'M', "xii"),
+    (0x217c, b'M', "l"), (0x217d, b'M', "c"), (0x217e, b'M', "d"), (0x217f, b'M', "m"),
+    (0x2180, b'V', ""), (0x2183, b'X', ""), (0x2184, b'V', ""), (0x2189, b'M', "0\u{2044}3"),
+    (0x218a, b'V', ""), (0x218c, b'X', ""), (0x2190, b'V', ""), (0x222c, b'M', "\u{222b}\u{222b}"),
+    (0x222d, b'M', "\u{222b}\u{222b}\u{222b}"), (0x222e, b'V', ""),
+    (0x222f, b'M', "\u{222e}\u{222e}"), (0x2230, b'M', "\u{222e}\u{222e}\u{222e}"),
+    (0x2231, b'V', ""), (0x2329, b'M', "\u{3008}"), (0x232a, b'M', "\u{3009}"), (0x232b, b'V', ""),
+    (0x2427, b'X', ""), (0x2440, b'V', ""), (0x244b, b'X', ""), (0x2460, b'M', "1"),
+    (0x2461, b'M', "2"), (0x2462, b'M', "3"), (0x2463, b'M', "4"), (0x2464, b'M', "5"),
+    (0x2465, b'M', "6"), (0x2466, b'M', "7"), (0x2467, b'M', "8"), (0x2468, b'M', "9"),
+    (0x2469, b'M', "10"), (0x246a, b'M', "11"), (0x246b, b'M', "12"), (0x246c, b'M', "13"),
+    (0x246d, b'M', "14"), (0x246e, b'M', "15"), (0x246f, b'M', "16"), (0x2470, b'M', "17"),
+    (0x2471, b'M', "18"), (0x2472, b'M', "19"), (0x2473, b'M', "20"), (0x2474, b'3', "(1)"),
+    (0x2475, b'3', "(2)"), (0x2476, b'3', "(3)"), (0x2477, b'3', "(4)"), (0x2478, b'3', "(5)"),
+    (0x2479, b'3', "(6)"), (0x247a, b'3', "(7)"), (0x247b, b'3', "(8)"), (0x247c, b'3', "(9)"),
+    (0x247d, b'3', "(10)"), (0x247e, b'3', "(11)"), (0x247f, b'3', "(12)"), (0x2480, b'3', "(13)"),
+    (0x2481, b'3', "(14)"), (0x2482, b'3', "(15)"), (0x2483, b'3', "(16)"), (0x2484, b'3', "(17)"),
+    (0x2485, b'3', "(18)"), (0x2486, b'3', "(19)"), (0x2487, b'3', "(20)"), (0x2488, b'X', ""),
+    (0x249c, b'3', "(a)"), (0x249d, b'3', "(b)"), (0x249e, b'3', "(c)"), (0x249f, b'3', "(d)"),
+    (0x24a0, b'3', "(e)"), (0x24a1, b'3', "(f)"), (0x24a2, b'3', "(g)"), (0x24a3, b'3', "(h)"),
+    (0x24a4, b'3', "(i)"), (0x24a5, b'3', "(j)"), (0x24a6, b'3', "(k)"), (0x24a7, b'3', "(l)"),
+    (0x24a8, b'3', "(m)"), (0x24a9, b'3', "(n)"), (0x24aa, b'3', "(o)"), (0x24ab, b'3', "(p)"),
+    (0x24ac, b'3', "(q)"), (0x24ad, b'3', "(r)"), (0x24ae, b'3', "(s)"), (0x24af, b'3', "(t)"),
+    (0x24b0, b'3', "(u)"), (0x24b1, b'3', "(v)"), (0x24b2, b'3', "(w)"), (0x24b3, b'3', "(x)"),
+    (0x24b4, b'3', "(y)"), (0x24b5, b'3', "(z)"), (0x24b6, b'M', "a"), (0x24b7, b'M', "b"),
+    (0x24b8, b'M', "c"), (0x24b9, b'M', "d"), (0x24ba, b'M', "e"), (0x24bb, b'M', "f"),
+    (0x24bc, b'M', "g"), (0x24bd, b'M', "h"), (0x24be, b'M', "i"), (0x24bf, b'M', "j"),
+    (0x24c0, b'M', "k"), (0x24c1, b'M', "l"), (0x24c2, b'M', "m"), (0x24c3, b'M', "n"),
+    (0x24c4, b'M', "o"), (0x24c5, b'M', "p"), (0x24c6, b'M', "q"), (0x24c7, b'M', "r"),
+    (0x24c8, b'M', "s"), (0x24c9, b'M', "t"), (0x24ca, b'M', "u"), (0x24cb, b'M', "v"),
+    (0x24cc, b'M', "w"), (0x24cd, b'M', "x"), (0x24ce, b'M', "y"), (0x24cf, b'M', "z"),
+    (0x24d0, b'M', "a"), (0x24d1, b'M', "b"), (0x24d2, b'M', "c"), (0x24d3, b'M', "d"),
+    (0x24d4, b'M', "e"), (0x24d5, b'M', "f"), (0x24d6, b'M', "g"), (0x24d7, b'M', "h"),
+    (0x24d8, b'M', "i"), (0x24d9, b'M', "j"), (0x24da, b'M', "k"), (0x24db, b'M', "l"),
+    (0x24dc, b'M', "m"), (0x24dd, b'M', "n"), (0x24de, b'M', "o"), (0x24df, b'M', "p"),
+    (0x24e0, b'M', "q"), (0x24e1, b'M', "r"), (0x24e2, b'M', "s"), (0x24e3, b'M', "t"),
+    (0x24e4, b'M', "u"), (0x24e5, b'M', "v"), (0x24e6, b'M', "w"), (0x24e7, b'M', "x"),
+    (0x24e8, b'M', "y"), (0x24e9, b'M', "z"), (0x24ea, b'M', "0"), (0x24eb, b'V', ""),
+    (0x2a0c, b'M', "\u{222b}\u{222b}\u{222b}\u{222b}"), (0x2a0d, b'V', ""), (0x2a74, b'3', "::="),
+    (0x2a75, b'3', "=="), (0x2a76, b'3', "==="), (0x2a77, b'V', ""),
+    (0x2adc, b'M', "\u{2add}\u{338}"), (0x2add, b'V', ""), (0x2b74, b'X', ""), (0x2b76, b'V', ""),
+    (0x2b96, b'X', ""), (0x2b97, b'V', ""), (0x2c00, b'M', "\u{2c30}"), (0x2c01, b'M', "\u{2c31}"),
+    (0x2c02, b'M', "\u{2c32}"), (0x2c03, b'M', "\u{2c33}"), (0x2c04, b'M', "\u{2c34}"),
+    (0x2c05, b'M', "\u{2c35}"), (0x2c06, b'M', "\u{2c36}"), (0x2c07, b'M', "\u{2c37}"),
+    (0x2c08, b'M', "\u{2c38}"), (0x2c09, b'M', "\u{2c39}"), (0x2c0a, b'M', "\u{2c3a}"),
+    (0x2c0b, b'M', "\u{2c3b}"), (0x2c0c, b'M', "\u{2c3c}"), (0x2c0d, b'M', "\u{2c3d}"),
+    (0x2c0e, b'M', "\u{2c3e}"), (0x2c0f, b'M', "\u{2c3f}"), (0x2c10, b'M', "\u{2c40}"),
+    (0x2c11, b'M', "\u{2c41}"), (0x2c12, b'M', "\u{2c42}"), (0x2c13, b'M', "\u{2c43}"),
+    (0x2c14, b'M', "\u{2c44}"), (0x2c15, b'M', "\u{2c45}"), (0x2c16, b'M', "\u{2c46}"),
+    (0x2c17, b'M', "\u{2c47}"), (0x2c18, b'M', "\u{2c48}"), (0x2c19, b'M', "\u{2c49}"),
+    (0x2c1a, b'M', "\u{2c4a}"), (0x2c1b, b'M', "\u{2c4b}"), (0x2c1c, b'M', "\u{2c4c}"),
+    (0x2c1d, b'M', "\u{2c4d}"), (0x2c1e, b'M', "\u{2c4e}"), (0x2c1f, b'M', "\u{2c4f}"),
+    (0x2c20, b'M', "\u{2c50}"), (0x2c21, b'M', "\u{2c51}"), (0x2c22, b'M', "\u{2c52}"),
+    (0x2c23, b'M', "\u{2c53}"), (0x2c24, b'M', "\u{2c54}"), (0x2c25, b'M', "\u{2c55}"),
+    (0x2c26, b'M', "\u{2c56}"), (0x2c27, b'M', "\u{2c57}"), (0x2c28, b'M', "\u{2c58}"),
+    (0x2c29, b'M', "\u{2c59}"), (0x2c2a, b'M', "\u{2c5a}"), (0x2c2b, b'M', "\u{2c5b}"),
+    (0x2c2c, b'M', "\u{2c5c}"), (0x2c2d, b'M', "\u{2c5d}"), (0x2c2e, b'M', "\u{2c5e}"),
+    (0x2c2f, b'M', "\u{2c5f}"), (0x2c30, b'V', ""), (0x2c60, b'M', "\u{2c61}"), (0x2c61, b'V', ""),
+    (0x2c62, b'M', "\u{26b}"), (0x2c63, b'M', "\u{1d7d}"), (0x2c64, b'M', "\u{27d}"),
+    (0x2c65, b'V', ""), (0x2c67, b'M', "\u{2c68}"), (0x2c68, b'V', ""), (0x2c69, b'M', "\u{2c6a}"),
+    (0x2c6a, b'V', ""), (0x2c6b, b'M', "\u{2c6c}"), (0x2c6c, b'V', ""), (0x2c6d, b'M', "\u{251}"),
+    (0x2c6e, b'M', "\u{271}"), (0x2c6f, b'M', "\u{250}"), (0x2c70, b'M', "\u{252}"),
+    (0x2c71, b'V', ""), (0x2c72, b'M', "\u{2c73}"), (0x2c73, b'V', ""), (0x2c75, b'M', "\u{2c76}"),
+    (0x2c76, b'V', ""), (0x2c7c, b'M', "j"), (0x2c7d, b'M', "v"), (0x2c7e, b'M', "\u{23f}"),
+    (0x2c7f, b'M', "\u{240}"), (0x2c80, b'M', "\u{2c81}"), (0x2c81, b'V', ""),
+    (0x2c82, b'M', "\u{2c83}"), (0x2c83, b'V', ""), (0x2c84, b'M', "\u{2c85}"), (0x2c85, b'V', ""),
+    (0x2c86, b'M', "\u{2c87}"), (0x2c87, b'V', ""), (0x2c88, b'M', "\u{2c89}"), (0x2c89, b'V', ""),
+    (0x2c8a, b'M', "\u{2c8b}"), (0x2c8b, b'V', ""), (0x2c8c, b'M', "\u{2c8d}"), (0x2c8d, b'V', ""),
+    (0x2c8e, b'M', "\u{2c8f}"), (0x2c8f, b'V', ""), (0x2c90, b'M', "\u{2c91}"), (0x2c91, b'V', ""),
+    (0x2c92, b'M', "\u{2c93}"), (0x2c93, b'V', ""), (0x2c94, b'M', "\u{2c95}"), (0x2c95, b'V', ""),
+    (0x2c96, b'M', "\u{2c97}"), (0x2c97, b'V', ""), (0x2c98, b'M', "\u{2c99}"), (0x2c99, b'V', ""),
+    (0x2c9a, b'M', "\u{2c9b}"), (0x2c9b, b'V', ""), (0x2c9c, b'M', "\u{2c9d}"), (0x2c9d, b'V', ""),
+    (0x2c9e, b'M', "\u{2c9f}"), (0x2c9f, b'V', ""), (0x2ca0, b'M', "\u{2ca1}"), (0x2ca1, b'V', ""),
+    (0x2ca2, b'M', "\u{2ca3}"), (0x2ca3, b'V', ""), (0x2ca4, b'M', "\u{2ca5}"), (0x2ca5, b'V', ""),
+    (0x2ca6, b'M', "\u{2ca7}"), (0x2ca7, b'V', ""), (0x2ca8, b'M', "\u{2ca9}"), (0x2ca9, b'V', ""),
+    (0x2caa, b'M', "\u{2cab}"), (0x2cab, b'V', ""), (0x2cac, b'M', "\u{2cad}"), (0x2cad, b'V', ""),
+    (0x2cae, b'M', "\u{2caf}"), (0x2caf, b'V', ""), (0x2cb0, b'M', "\u{2cb1}"), (0x2cb1, b'V', ""),
+    (0x2cb2, b'M', "\u{2cb3}"), (0x2cb3, b'V', ""), (0x2cb4, b'M', "\u{2cb5}"), (0x2cb5, b'V', ""),
+    (0x2cb6, b'M', "\u{2cb7}"), (0x2cb7, b'V', ""), (0x2cb8, b'M', "\u{2cb9}"), (0x2cb9, b'V', ""),
+    (0x2cba, b'M', "\u{2cbb}"), (0x2cbb, b'V', ""), (0x2cbc, b'M', "\u{2cbd}"), (0x2cbd, b'V', ""),
+    (0x2cbe, b'M', "\u{2cbf}"), (0x2cbf, b'V', ""), (0x2cc0, b'M', "\u{2cc1}"), (0x2cc1, b'V', ""),
+    (0x2cc2, b'M', "\u{2cc3}"), (0x2cc3, b'V', ""), (0x2cc4, b'M', "\u{2cc5}"), (0x2cc5, b'V', ""),
+    (0x2cc6, b'M', "\u{2cc7}"), (0x2cc7, b'V', ""), (0x2cc8, b'M', "\u{2cc9}"), (0x2cc9, b'V', ""),
+    (0x2cca, b'M', "\u{2ccb}"), (0x2ccb, b'V', ""), (0x2ccc, b'M', "\u{2ccd}"), (0x2ccd, b'V', ""),
+    (0x2cce, b'M', "\u{2ccf}"), (0x2ccf, b'V', ""), (0x2cd0, b'M', "\u{2cd1}"), (0x2cd1, b'V', ""),
+    (0x2cd2, b'M', "\u{2cd3}"), (0x2cd3, b'V', ""), (0x2cd4, b'M', "\u{2cd5}"), (0x2cd5, b'V', ""),
+    (0x2cd6, b'M', "\u{2cd7}"), (0x2cd7, b'V', ""), (0x2cd8, b'M', "\u{2cd9}"), (0x2cd9, b'V', ""),
+    (0x2cda, b'M', "\u{2cdb}"), (0x2cdb, b'V', ""), (0x2cdc, b'M', "\u{2cdd}"), (0x2cdd, b'V', ""),
+    (0x2cde, b'M', "\u{2cdf}"), (0x2cdf, b'V', ""), (0x2ce0, b'M', "\u{2ce1}"), (0x2ce1, b'V', ""),
+    (0x2ce2, b'M', "\u{2ce3}"), (0x2ce3, b'V', ""), (0x2ceb, b'M', "\u{2cec}"), (0x2cec, b'V', ""),
+    (0x2ced, b'M', "\u{2cee}"), (0x2cee, b'V', ""), (0x2cf2, b'M', "\u{2cf3}"), (0x2cf3, b'V', ""),
+    (0x2cf4, b'X', ""), (0x2cf9, b'V', ""), (0x2d26, b'X', ""), (0x2d27, b'V', ""),
+    (0x2d28, b'X', ""), (0x2d2d, b'V', ""), (0x2d2e, b'X', ""), (0x2d30, b'V', ""),
+    (0x2d68, b'X', ""), (0x2d6f, b'M', "\u{2d61}"), (0x2d70, b'V', ""), (0x2d71, b'X', ""),
+    (0x2d7f, b'V', ""), (0x2d97, b'X', ""), (0x2da0, b'V', ""), (0x2da7, b'X', ""),
+    (0x2da8, b'V', ""), (0x2daf, b'X', ""), (0x2db0, b'V', ""), (0x2db7, b'X', ""),
+    (0x2db8, b'V', ""), (0x2dbf, b'X', ""), (0x2dc0, b'V', ""), (0x2dc7, b'X', ""),
+    (0x2dc8, b'V', ""), (0x2dcf, b'X', ""), (0x2dd0, b'V', ""), (0x2dd7, b'X', ""),
+    (0x2dd8, b'V', ""), (0x2ddf, b'X', ""), (0x2de0, b'V', ""), (0x2e5e, b'X', ""),
+    (0x2e80, b'V', ""), (0x2e9a, b'X', ""), (0x2e9b, b'V', ""), (0x2e9f, b'M', "\u{6bcd}"),
+    (0x2ea0, b'V', ""), (0x2ef3, b'M', "\u{9f9f}"), (0x2ef4, b'X', ""), (0x2f00, b'M', "\u{4e00}"),
+    (0x2f01, b'M', "\u{4e28}"), (0x2f02, b'M', "\u{4e36}"), (0x2f03, b'M', "\u{4e3f}"),
+    (0x2f04, b'M', "\u{4e59}"), (0x2f05, b'M', "\u{4e85}"), (0x2f06, b'M', "\u{4e8c}"),
+    (0x2f07, b'M', "\u{4ea0}"), (0x2f08, b'M', "\u{4eba}"), (0x2f09, b'M', "\u{513f}"),
+    (0x2f0a, b'M', "\u{5165}"), (0x2f0b, b'M', "\u{516b}"), (0x2f0c, b'M', "\u{5182}"),
+    (0x2f0d, b'M', "\u{5196}"), (0x2f0e, b'M', "\u{51ab}"), (0x2f0f, b'M', "\u{51e0}"),
+    (0x2f10, b'M', "\u{51f5}"), (0x2f11, b'M', "\u{5200}"), (0x2f12, b'M', "\u{529b}"),
+    (0x2f13, b'M', "\u{52f9}"), (0x2f14, b'M', "\u{5315}"), (0x2f15, b'M', "\u{531a}"),
+    (0x2f16, b'M', "\u{5338}"), (0x2f17, b'M', "\u{5341}"), (0x2f18, b'M', "\u{535c}"),
+    (0x2f19, b'M', "\u{5369}"), (0x2f1a, b'M', "\u{5382}"), (0x2f1b, b'M', "\u{53b6}"),
+    (0x2f1c, b'M', "\u{53c8}"), (0x2f1d, b'M', "\u{53e3}"), (0x2f1e, b'M', "\u{56d7}"),
+    (0x2f1f, b'M', "\u{571f}"), (0x2f20, b'M', "\u{58eb}"), (0x2f21, b'M', "\u{5902}"),
+    (0x2f22, b'M', "\u{590a}"), (0x2f23, b'M', "\u{5915}"), (0x2f24, b'M', "\u{5927}"),
+    (0x2f25, b'M', "\u{5973}"), (0x2f26, b'M', "\u{5b50}"), (0x2f27, b'M', "\u{5b80}"),
+    (0x2f28, b'M', "\u{5bf8}"), (0x2f29, b'M', "\u{5c0f}"), (0x2f2a, b'M', "\u{5c22}"),
+    (0x2f2b, b'M', "\u{5c38}"), (0x2f2c, b'M', "\u{5c6e}"), (0x2f2d, b'M', "\u{5c71}"),
+    (0x2f2e, b'M', "\u{5ddb}"), (0x2f2f, b'M', "\u{5de5}"), (0x2f30, b'M', "\u{5df1}"),
+    (0x2f31, b'M', "\u{5dfe}"), (0x2f32, b'M', "\u{5e72}"), (0x2f33, b'M', "\u{5e7a}"),
+    (0x2f34, b'M', "\u{5e7f}"), (0x2f35, b'M', "\u{5ef4}"), (0x2f36, b'M', "\u{5efe}"),
+    (0x2f37, b'M', "\u{5f0b}"), (0x2f38, b'M', "\u{5f13}"), (0x2f39, b'M', "\u{5f50}"),
+    (0x2f3a, b'M', "\u{5f61}"), (0x2f3b, b'M', "\u{5f73}"), (0x2f3c, b'M', "\u{5fc3}"),
+    (0x2f3d, b'M', "\u{6208}"), (0x2f3e, b'M', "\u{6236}"), (0x2f3f, b'M', "\u{624b}"),
+    (0x2f40, b'M', "\u{652f}"), (0x2f41, b'M', "\u{6534}"), (0x2f42, b'M', "\u{6587}"),
+    (0x2f43, b'M', "\u{6597}"), (0x2f44, b'M', "\u{65a4}"), (0x2f45, b'M', "\u{65b9}"),
+    (0x2f46, b'M', "\u{65e0}"), (0x2f47, b'M', "\u{65e5}"), (0x2f48, b'M', "\u{66f0}"),
+    (0x2f49, b'M', "\u{6708}"), (0x2f4a, b'M', "\u{6728}"), (0x2f4b, b'M', "\u{6b20}"),
+    (0x2f4c, b'M', "\u{6b62}"), (0x2f4d, b'M', "\u{6b79}"), (0x2f4e, b'M', "\u{6bb3}"),
+    (0x2f4f, b'M', "\u{6bcb}"), (0x2f50, b'M', "\u{6bd4}"), (0x2f51, b'M', "\u{6bdb}"),
+    (0x2f52, b'M', "\u{6c0f}"), (0x2f53, b'M', "\u{6c14}"), (0x2f54, b'M', "\u{6c34}"),
+    (0x2f55, b'M', "\u{706b}"), (0x2f56, b'M', "\u{722a}"), (0x2f57, b'M', "\u{7236}"),
+    (0x2f58, b'M', "\u{723b}"), (0x2f59, b'M', "\u{723f}"), (0x2f5a, b'M', "\u{7247}"),
+    (0x2f5b, b'M', "\u{7259}"), (0x2f5c, b'M', "\u{725b}"), (0x2f5d, b'M', "\u{72ac}"),
+    (0x2f5e, b'M', "\u{7384}"), (0x2f5f, b'M', "\u{7389}"), (0x2f60, b'M', "\u{74dc}"),
+    (0x2f61, b'M', "\u{74e6}"), (0x2f62, b'M', "\u{7518}"), (0x2f63, b'M', "\u{751f}"),
+    (0x2f64, b'M', "\u{7528}"), (0x2f65, b'M', "\u{7530}"), (0x2f66, b'M', "\u{758b}"),
+    (0x2f67, b'M', "\u{7592}"), (0x2f68, b'M', "\u{7676}"), (0x2f69, b'M', "\u{767d}"),
+    (0x2f6a, b'M', "\u{76ae}"), (0x2f6b, b'M', "\u{76bf}"), (0x2f6c, b'M', "\u{76ee}"),
+    (0x2f6d, b'M', "\u{77db}"), (0x2f6e, b'M', "\u{77e2}"), (0x2f6f, b'M', "\u{77f3}"),
+    (0x2f70, b'M', "\u{793a}"), (0x2f71, b'M', "\u{79b8}"), (0x2f72, b'M', "\u{79be}"),
+    (0x2f73, b'M', "\u{7a74}"), (0x2f74, b'M', "\u{7acb}"), (0x2f75, b'M', "\u{7af9}"),
+    (0x2f76, b'M', "\u{7c73}"), (0x2f77, b'M', "\u{7cf8}"), (0x2f78, b'M', "\u{7f36}"),
+    (0x2f79, b'M', "\u{7f51}"), (0x2f7a, b'M', "\u{7f8a}"), (0x2f7b, b'M', "\u{7fbd}"),
+    (0x2f7c, b'M', "\u{8001}"), (0x2f7d, b'M', "\u{800c}"), (0x2f7e, b'M', "\u{8012}"),
+    (0x2f7f, b'M', "\u{8033}"), (0x2f80, b'M', "\u{807f}"), (0x2f81, b'M', "\u{8089}"),
+    (0x2f82, b'M', "\u{81e3}"), (0x2f83, b'M', "\u{81ea}"), (0x2f84, b'M', "\u{81f3}"),
+    (0x2f85, b'M', "\u{81fc}"), (0x2f86, b'M', "\u{820c}"), (0x2f87, b'M', "\u{821b}"),
+    (0x2f88, b'M', "\u{821f}"), (0x2f89, b'M', "\u{826e}"), (0x2f8a, b'M', "\u{8272}"),
+    (0x2f8b, b'M', "\u{8278}"), (0x2f8c, b'M', "\u{864d}"), (0x2f8d, b'M', "\u{866b}"),
+    (0x2f8e, b'M', "\u{8840}"), (0x2f8f, b'M', "\u{884c}"), (0x2f90, b'M', "\u{8863}"),
+    (0x2f91, b'M', "\u{897e}"), (0x2f92, b'M', "\u{898b}"), (0x2f93, b'M', "\u{89d2}"),
+    (0x2f94, b'M', "\u{8a00}"), (0x2f95, b'M', "\u{8c37}"), (0x2f96, b'M', "\u{8c46}"),
+    (0x2f97, b'M', "\u{8c55}"), (0x2f98, b'M', "\u{8c78}"), (0x2f99, b'M', "\u{8c9d}"),
+    (0x2f9a, b'M', "\u{8d64}"), (0x2f9b, b'M', "\u{8d70}"), (0x2f9c, b'M', "\u{8db3}"),
+    (0x2f9d, b'M', "\u{8eab}"), (0x2f9e, b'M', "\u{8eca}"), (0x2f9f, b'M', "\u{8f9b}"),
+    (0x2fa0, b'M', "\u{8fb0}"), (0x2fa1, b'M', "\u{8fb5}"), (0x2fa2, b'M', "\u{9091}"),
+    (0x2fa3, b'M', "\u{9149}"), (0x2fa4, b'M', "\u{91c6}"), (0x2fa5, b'M', "\u{91cc}"),
+    (0x2fa6, b'M', "\u{91d1}"), (0x2fa7, b'M', "\u{9577}"), (0x2fa8, b'M', "\u{9580}"),
+    (0x2fa9, b'M', "\u{961c}"), (0x2faa, b'M', "\u{96b6}"), (0x2fab, b'M', "\u{96b9}"),
+    (0x2fac, b'M', "\u{96e8}"), (0x2fad, b'M', "\u{9751}"), (0x2fae, b'M', "\u{975e}"),
+    (0x2faf, b'M', "\u{9762}"), (0x2fb0, b'M', "\u{9769}"), (0x2fb1, b'M', "\u{97cb}"),
+    (0x2fb2, b'M', "\u{97ed}"), (0x2fb3, b'M', "\u{97f3}"), (0x2fb4, b'M', "\u{9801}"),
+    (0x2fb5, b'M', "\u{98a8}"), (0x2fb6, b'M', "\u{98db}"), (0x2fb7, b'M', "\u{98df}"),
+    (0x2fb8, b'M', "\u{9996}"), (0x2fb9, b'M', "\u{9999}"), (0x2fba, b'M', "\u{99ac}"),
+    (0x2fbb, b'M', "\u{9aa8}"), (0x2fbc, b'M', "\u{9ad8}"), (0x2fbd, b'M', "\u{9adf}"),
+    (0x2fbe, b'M', "\u{9b25}"), (0x2fbf, b'M', "\u{9b2f}"), (0x2fc0, b'M', "\u{9b32}"),
+    (0x2fc1, b'M', "\u{9b3c}"), (0x2fc2, b'M', "\u{9b5a}"), (0x2fc3, b'M', "\u{9ce5}"),
+    (0x2fc4, b'M', "\u{9e75}"), (0x2fc5, b'M', "\u{9e7f}"), (0x2fc6, b'M', "\u{9ea5}"),
+    (0x2fc7, b'M', "\u{9ebb}"), (0x2fc8, b'M', "\u{9ec3}"), (0x2fc9, b'M', "\u{9ecd}"),
+    (0x2fca, b'M', "\u{9ed1}"), (0x2fcb, b'M', "\u{9ef9}"), (0x2fcc, b'M', "\u{9efd}"),
+    (0x2fcd, b'M', "\u{9f0e}"), (0x2fce, b'M', "\u{9f13}"), (0x2fcf, b'M', "\u{9f20}"),
+    (0x2fd0, b'M', "\u{9f3b}"), (0x2fd1, b'M', "\u{9f4a}"), (0x2fd2, b'M', "\u{9f52}"),
+    (0x2fd3, b'M', "\u{9f8d}"), (0x2fd4, b'M', "\u{9f9c}"), (0x2fd5, b'M', "\u{9fa0}"),
+    (0x2fd6, b'X', ""), (0x3000, b'3', " "), (0x3001, b'V', ""), (0x3002, b'M', "."),
+    (0x3003, b'V', ""), (0x3036, b'M', "\u{3012}"), (0x3037, b'V', ""), (0x3038, b'M', "\u{5341}"),
+    (0x3039, b'M', "\u{5344}"), (0x303a, b'M', "\u{5345}"), (0x303b, b'V', ""), (0x3040, b'X', ""),
+    (0x3041, b'V', ""), (0x3097, b'X', ""), (0x3099, b'V', ""), (0x309b, b'3', " \u{3099}"),
+    (0x309c, b'3', " \u{309a}"), (0x309d, b'V', ""), (0x309f, b'M', "\u{3088}\u{308a}"),
+    (0x30a0, b'V', ""), (0x30ff, b'M', "\u{30b3}\u{30c8}"), (0x3100, b'X', ""), (0x3105, b'V', ""),
+    (0x3130, b'X', ""), (0x3131, b'M', "\u{1100}"), (0x3132, b'M', "\u{1101}"),
+    (0x3133, b'M', "\u{11aa}"), (0x3134, b'M', "\u{1102}"), (0x3135, b'M', "\u{11ac}"),
+    (0x3136, b'M', "\u{11ad}"), (0x3137, b'M', "\u{1103}"), (0x3138, b'M', "\u{1104}"),
+    (0x3139, b'M', "\u{1105}"), (0x313a, b'M', "\u{11b0}"), (0x313b, b'M', "\u{11b1}"),
+    (0x313c, b'M', "\u{11b2}"), (0x313d, b'M', "\u{11b3}"), (0x313e, b'M', "\u{11b4}"),
+    (0x313f, b'M', "\u{11b5}"), (0x3140, b'M', "\u{111a}"), (0x3141, b'M', "\u{1106}"),
+    (0x3142, b'M', "\u{1107}"), (0x3143, b'M', "\u{1108}"), (0x3144, b'M', "\u{1121}"),
+    (0x3145, b'M', "\u{1109}"), (0x3146, b'M', "\u{110a}"), (0x3147, b'M', "\u{110b}"),
+    (0x3148, b'M', "\u{110c}"), (0x3149, b'M', "\u{110d}"), (0x314a, b'M', "\u{110e}"),
+    (0x314b, b'M', "\u{110f}"), (0x314c, b'M', "\u{1110}"), (0x314d, b'M', "\u{1111}"),
+    (0x314e, b'M', "\u{1112}"), (0x314f, b'M', "\u{1161}"), (0x3150, b'M', "\u{1162}"),
+    (0x3151, b'M', "\u{1163}"), (0x3152, b'M', "\u{1164}"), (0x3153, b'M', "\u{1165}"),
+    (0x3154, b'M', "\u{1166}"), (0x3155, b'M', "\u{1167}"), (0x3156, b'M', "\u{1168}"),
+    (0x3157, b'M', "\u{1169}"), (0x3158, b'M', "\u{116a}"), (0x3159, b'M', "\u{116b}"),
+    (0x315a, b'M', "\u{116c}"), (0x315b, b'M', "\u{116d}"), (0x315c, b'M', "\u{116e}"),
+    (0x315d, b'M', "\u{116f}"), (0x315e, b'M', "\u{1170}"), (0x315f, b'M', "\u{1171}"),
+    (0x3160, b'M', "\u{1172}"), (0x3161, b'M', "\u{1173}"), (0x3162, b'M', "\u{1174}"),
+    (0x3163, b'M', "\u{1175}"), (0x3164, b'X', ""), (0x3165, b'M', "\u{1114}"),
+    (0x3166, b'M', "\u{1115}"), (0x3167, b'M', "\u{11c7}"), (0x3168, b'M', "\u{11c8}"),
+    (0x3169, b'M', "\u{11cc}"), (0x316a, b'M', "\u{11ce}"), (0x316b, b'M', "\u{11d3}"),
+    (0x316c, b'M', "\u{11d7}"), (0x316d, b'M', "\u{11d9}"), (0x316e, b'M', "\u{111c}"),
+    (0x316f, b'M', "\u{11dd}"), (0x3170, b'M', "\u{11df}"), (0x3171, b'M', "\u{111d}"),
+    (0x3172, b'M', "\u{111e}"), (0x3173, b'M', "\u{1120}"), (0x3174, b'M', "\u{1122}"),
+    (0x3175, b'M', "\u{1123}"), (0x3176, b'M', "\u{1127}"), (0x3177, b'M', "\u{1129}"),
+    (0x3178, b'M', "\u{112b}"), (0x3179, b'M', "\u{112c}"), (0x317a, b'M', "\u{112d}"),
+    (0x317b, b'M', "\u{112e}"), (0x317c, b'M', "\u{112f}"), (0x317d, b'M', "\u{1132}"),
+    (0x317e, b'M', "\u{1136}"), (0x317f, b'M', "\u{1140}"), (0x3180, b'M', "\u{1147}"),
+    (0x3181, b'M', "\u{114c}"), (0x3182, b'M', "\u{11f1}"), (0x3183, b'M', "\u{11f2}"),
+    (0x3184, b'M', "\u{1157}"), (0x3185, b'M', "\u{1158}"), (0x3186, b'M', "\u{1159}"),
+    (0x3187, b'M', "\u{1184}"), (0x3188, b'M', "\u{1185}"), (0x3189, b'M', "\u{1188}"),
+    (0x318a, b'M', "\u{1191}"), (0x318b, b'M', "\u{1192}"), (0x318c, b'M', "\u{1194}"),
+    (0x318d, b'M', "\u{119e}"), (0x318e, b'M', "\u{11a1}"), (0x318f, b'X', ""), (0x3190, b'V', ""),
+    (0x3192, b'M', "\u{4e00}"), (0x3193, b'M', "\u{4e8c}"), (0x3194, b'M', "\u{4e09}"),
+    (0x3195, b'M', "\u{56db}"), (0x3196, b'M', "\u{4e0a}"), (0x3197, b'M', "\u{4e2d}"),
+    (0x3198, b'M', "\u{4e0b}"), (0x3199, b'M', "\u{7532}"), (0x319a, b'M', "\u{4e59}"),
+    (0x319b, b'M', "\u{4e19}"), (0x319c, b'M', "\u{4e01}"), (0x319d, b'M', "\u{5929}"),
+    (0x319e, b'M', "\u{5730}"), (0x319f, b'M', "\u{4eba}"), (0x31a0, b'V', ""), (0x31e4, b'X', ""),
+    (0x31f0, b'V', ""), (0x3200, b'3', "(\u{1100})"), (0x3201, b'3', "(\u{1102})"),
+    (0x3202, b'3', "(\u{1103})"), (0x3203, b'3', "(\u{1105})"), (0x3204, b'3', "(\u{1106})"),
+    (0x3205, b'3', "(\u{1107})"), (0x3206, b'3', "(\u{1109})"), (0x3207, b'3', "(\u{110b})"),
+    (0x3208, b'3', "(\u{110c})"), (0x3209, b'3', "(\u{110e})"), (0x320a, b'3', "(\u{110f})"),
+    (0x320b, b'3', "(\u{1110})"), (0x320c, b'3', "(\u{1111})"), (0x320d, b'3', "(\u{1112})"),
+    (0x320e, b'3', "(\u{ac00})"), (0x320f, b'3', "(\u{b098})"), (0x3210, b'3', "(\u{b2e4})"),
+    (0x3211, b'3', "(\u{b77c})"), (0x3212, b'3', "(\u{b9c8})"), (0x3213, b'3', "(\u{bc14})"),
+    (0x3214, b'3', "(\u{c0ac})"), (0x3215, b'3', "(\u{c544})"), (0x3216, b'3', "(\u{c790})"),
+    (0x3217, b'3', "(\u{cc28})"), (0x3218, b'3', "(\u{ce74})"), (0x3219, b'3', "(\u{d0c0})"),
+    (0x321a, b'3', "(\u{d30c})"), (0x321b, b'3', "(\u{d558})"), (0x321c, b'3', "(\u{c8fc})"),
+    (0x321d, b'3', "(\u{c624}\u{c804})"), (0x321e, b'3', "(\u{c624}\u{d6c4})"), (0x321f, b'X', ""),
+    (0x3220, b'3', "(\u{4e00})"), (0x3221, b'3', "(\u{4e8c})"), (0x3222, b'3', "(\u{4e09})"),
+    (0x3223, b'3', "(\u{56db})"), (0x3224, b'3', "(\u{4e94})"), (0x3225, b'3', "(\u{516d})"),
+    (0x3226, b'3', "(\u{4e03})"), (0x3227, b'3', "(\u{516b})"), (0x3228, b'3', "(\u{4e5d})"),
+    (0x3229, b'3', "(\u{5341})"), (0x322a, b'3', "(\u{6708})"), (0x322b, b'3', "(\u{706b})"),
+    (0x322c, b'3', "(\u{6c34})"), (0x322d, b'3', "(\u{6728})"), (0x322e, b'3', "(\u{91d1})"),
+    (0x322f, b'3', "(\u{571f})"), (0x3230, b'3', "(\u{65e5})"), (0x3231, b'3', "(\u{682a})"),
+    (0x3232, b'3', "(\u{6709})"), (0x3233, b'3', "(\u{793e})"), (0x3234, b'3', "(\u{540d})"),
+    (0x3235, b'3', "(\u{7279})"), (0x3236, b'3', "(\u{8ca1})"), (0x3237, b'3', "(\u{795d})"),
+    (0x3238, b'3', "(\u{52b4})"), (0x3239, b'3', "(\u{4ee3})"), (0x323a, b'3', "(\u{547c})"),
+    (0x323b, b'3', "(\u{5b66})"), (0x323c, b'3', "(\u{76e3})"), (0x323d, b'3', "(\u{4f01})"),
+    (0x323e, b'3', "(\u{8cc7})"), (0x323f, b'3', "(\u{5354})"), (0x3240, b'3', "(\u{796d})"),
+    (0x3241, b'3', "(\u{4f11})"), (0x3242, b'3', "(\u{81ea})"), (0x3243, b'3', "(\u{81f3})"),
+    (0x3244, b'M', "\u{554f}"), (0x3245, b'M', "\u{5e7c}"), (0x3246, b'M', "\u{6587}"),
+    (0x3247, b'M', "\u{7b8f}"), (0x3248, b'V', ""), (0x3250, b'M', "pte"), (0x3251, b'M', "21"),
+    (0x3252, b'M', "22"), (0x3253, b'M', "23"), (0x3254, b'M', "24"), (0x3255, b'M', "25"),
+    (0x3256, b'M', "26"), (0x3257, b'M', "27"), (0x3258, b'M', "28"), (0x3259, b'M', "29"),
+    (0x325a, b'M', "30"), (0x325b, b'M', "31"), (0x325c, b'M', "32"), (0x325d, b'M', "33"),
+    (0x325e, b'M', "34"), (0x325f, b'M', "35"), (0x3260, b'M', "\u{1100}"),
+    (0x3261, b'M', "\u{1102}"), (0x3262, b'M', "\u{1103}"), (0x3263, b'M', "\u{1105}"),
+    (0x3264, b'M', "\u{1106}"), (0x3265, b'M', "\u{1107}"), (0x3266, b'M', "\u{1109}"),
+    (0x3267, b'M', "\u{110b}"), (0x3268, b'M', "\u{110c}"), (0x3269, b'M', "\u{110e}"),
+    (0x326a, b'M', "\u{110f}"), (0x326b, b'M', "\u{1110}"), (0x326c, b'M', "\u{1111}"),
+    (0x326d, b'M', "\u{1112}"), (0x326e, b'M', "\u{ac00}"), (0x326f, b'M', "\u{b098}"),
+    (0x3270, b'M', "\u{b2e4}"), (0x3271, b'M', "\u{b77c}"), (0x3272, b'M', "\u{b9c8}"),
+    (0x3273, b'M', "\u{bc14}"), (0x3274, b'M', "\u{c0ac}"), (0x3275, b'M', "\u{c544}"),
+    (0x3276, b'M', "\u{c790}"), (0x3277, b'M', "\u{cc28}"), (0x3278, b'M', "\u{ce74}"),
+    (0x3279, b'M', "\u{d0c0}"), (0x327a, b'M', "\u{d30c}"), (0x327b, b'M', "\u{d558}"),
+    (0x327c, b'M', "\u{cc38}\u{ace0}"), (0x327d, b'M', "\u{c8fc}\u{c758}"),
+    (0x327e, b'M', "\u{c6b0}"), (0x327f, b'V', ""), (0x3280, b'M', "\u{4e00}"),
+    (0x3281, b'M', "\u{4e8c}"), (0x3282, b'M', "\u{4e09}"), (0x3283, b'M', "\u{56db}"),
+    (0x3284, b'M', "\u{4e94}"), (0x3285, b'M', "\u{516d}"), (0x3286, b'M', "\u{4e03}"),
+    (0x3287, b'M', "\u{516b}"), (0x3288, b'M', "\u{4e5d}"), (0x3289, b'M', "\u{5341}"),
+    (0x328a, b'M', "\u{6708}"), (0x328b, b'M', "\u{706b}"), (0x328c, b'M', "\u{6c34}"),
+    (0x328d, b'M', "\u{6728}"), (0x328e, b'M', "\u{91d1}"), (0x328f, b'M', "\u{571f}"),
+    (0x3290, b'M', "\u{65e5}"), (0x3291, b'M', "\u{682a}"), (0x3292, b'M', "\u{6709}"),
+    (0x3293, b'M', "\u{793e}"), (0x3294, b'M', "\u{540d}"), (0x3295, b'M', "\u{7279}"),
+    (0x3296, b'M', "\u{8ca1}"), (0x3297, b'M', "\u{795d}"), (0x3298, b'M', "\u{52b4}"),
+    (0x3299, b'M', "\u{79d8}"), (0x329a, b'M', "\u{7537}"), (0x329b, b'M', "\u{5973}"),
+    (0x329c, b'M', "\u{9069}"), (0x329d, b'M', "\u{512a}"), (0x329e, b'M', "\u{5370}"),
+    (0x329f, b'M', "\u{6ce8}"), (0x32a0, b'M', "\u{9805}"), (0x32a1, b'M', "\u{4f11}"),
+    (0x32a2, b'M', "\u{5199}"), (0x32a3, b'M', "\u{6b63}"), (0x32a4, b'M', "\u{4e0a}"),
+    (0x32a5, b'M', "\u{4e2d}"), (0x32a6, b'M', "\u{4e0b}"), (0x32a7, b'M', "\u{5de6}"),
+    (0x32a8, b'M', "\u{53f3}"), (0x32a9, b'M', "\u{533b}"), (0x32aa, b'M', "\u{5b97}"),
+    (0x32ab, b'M', "\u{5b66}"), (0x32ac, b'M', "\u{76e3}"), (0x32ad, b'M', "\u{4f01}"),
+    (0x32ae, b'M', "\u{8cc7}"), (0x32af, b'M', "\u{5354}"), (0x32b0, b'M', "\u{591c}"),
+    (0x32b1, b'M', "36"), (0x32b2, b'M', "37"), (0x32b3, b'M', "38"), (0x32b4, b'M', "39"),
+    (0x32b5, b'M', "40"), (0x32b6, b'M', "41"), (0x32b7, b'M', "42"), (0x32b8, b'M', "43"),
+    (0x32b9, b'M', "44"), (0x32ba, b'M', "45"), (0x32bb, b'M', "46"), (0x32bc, b'M', "47"),
+    (0x32bd, b'M', "48"), (0x32be, b'M', "49"), (0x32bf, b'M', "50"), (0x32c0, b'M', "1\u{6708}"),
+    (0x32c1, b'M', "2\u{6708}"), (0x32c2, b'M', "3\u{6708}"), (0x32c3, b'M', "4\u{6708}"),
+    (0x32c4, b'M', "5\u{6708}"), (0x32c5, b'M', "6\u{6708}"), (0x32c6, b'M', "7\u{6708}"),
+    (0x32c7, b'M', "8\u{6708}"), (0x32c8, b'M', "9\u{6708}"), (0x32c9, b'M', "10\u{6708}"),
+    (0x32ca, b'M', "11\u{6708}"), (0x32cb, b'M', "12\u{6708}"), (0x32cc, b'M', "hg"),
+    (0x32cd, b'M', "erg"), (0x32ce, b'M', "ev"), (0x32cf, b'M', "ltd"), (0x32d0, b'M', "\u{30a2}"),
+    (0x32d1, b'M', "\u{30a4}"), (0x32d2, b'M', "\u{30a6}"), (0x32d3, b'M', "\u{30a8}"),
+    (0x32d4, b'M', "\u{30aa}"), (0x32d5, b'M', "\u{30ab}"), (0x32d6, b'M', "\u{30ad}"),
+    (0x32d7, b'M', "\u{30af}"), (0x32d8, b'M', "\u{30b1}"), (0x32d9, b'M', "\u{30b3}"),
+    (0x32da, b'M', "\u{30b5}"), (0x32db, b'M', "\u{30b7}"), (0x32dc, b'M', "\u{30b9}"),
+    (0x32dd, b'M', "\u{30bb}"), (0x32de, b'M', "\u{30bd}"), (0x32df, b'M', "\u{30bf}"),
+    (0x32e0, b'M', "\u{30c1}"), (0x32e1, b'M', "\u{30c4}"), (0x32e2, b'M', "\u{30c6}"),
+    (0x32e3, b'M', "\u{30c8}"), (0x32e4, b'M', "\u{30ca}"), (0x32e5, b'M', "\u{30cb}"),
+    (0x32e6, b'M', "\u{30cc}"), (0x32e7, b'M', "\u{30cd}"), (0x32e8, b'M', "\u{30ce}"),
+    (0x32e9, b'M', "\u{30cf}"), (0x32ea, b'M', "\u{30d2}"), (0x32eb, b'M', "\u{30d5}"),
+    (0x32ec, b'M', "\u{30d8}"), (0x32ed, b'M', "\u{30db}"), (0x32ee, b'M', "\u{30de}"),
+    (0x32ef, b'M', "\u{30df}"), (0x32f0, b'M', "\u{30e0}"), (0x32f1, b'M', "\u{30e1}"),
+    (0x32f2, b'M', "\u{30e2}"), (0x32f3, b'M', "\u{30e4}"), (0x32f4, b'M', "\u{30e6}"),
+    (0x32f5, b'M', "\u{30e8}"), (0x32f6, b'M', "\u{30e9}"), (0x32f7, b'M', "\u{30ea}"),
+    (0x32f8, b'M', "\u{30eb}"), (0x32f9, b'M', "\u{30ec}"), (0x32fa, b'M', "\u{30ed}"),
+    (0x32fb, b'M', "\u{30ef}"), (0x32fc, b'M', "\u{30f0}"), (0x32fd, b'M', "\u{30f1}"),
+    (0x32fe, b'M', "\u{30f2}"), (0x32ff, b'M', "\u{4ee4}\u{548c}"),
+    (0x3300, b'M', "\u{30a2}\u{30d1}\u{30fc}\u{30c8}"),
+    (0x3301, b'M', "\u{30a2}\u{30eb}\u{30d5}\u{30a1}"),
+    (0x3302, b'M', "\u{30a2}\u{30f3}\u{30da}\u{30a2}"), (0x3303, b'M', "\u{30a2}\u{30fc}\u{30eb}"),
+    (0x3304, b'M', "\u{30a4}\u{30cb}\u{30f3}\u{30b0}"), (0x3305, b'M', "\u{30a4}\u{30f3}\u{30c1}"),
+    (0x3306, b'M', "\u{30a6}\u{30a9}\u{30f3}"),
+    (0x3307, b'M', "\u{30a8}\u{30b9}\u{30af}\u{30fc}\u{30c9}"),
+    (0x3308, b'M', "\u{30a8}\u{30fc}\u{30ab}\u{30fc}"), (0x3309, b'M', "\u{30aa}\u{30f3}\u{30b9}"),
+    (0x330a, b'M', "\u{30aa}\u{30fc}\u{30e0}"), (0x330b, b'M', "\u{30ab}\u{30a4}\u{30ea}"),
+    (0x330c, b'M', "\u{30ab}\u{30e9}\u{30c3}\u{30c8}"),
+    (0x330d, b'M', "\u{30ab}\u{30ed}\u{30ea}\u{30fc}"), (0x330e, b'M', "\u{30ac}\u{30ed}\u{30f3}"),
+    (0x330f, b'M', "\u{30ac}\u{30f3}\u{30de}"), (0x3310, b'M', "\u{30ae}\u{30ac}"),
+    (0x3311, b'M', "\u{30ae}\u{30cb}\u{30fc}"), (0x3312, b'M', "\u{30ad}\u{30e5}\u{30ea}\u{30fc}"),
+    (0x3313, b'M', "\u{30ae}\u{30eb}\u{30c0}\u{30fc}"), (0x3314, b'M', "\u{30ad}\u{30ed}"),
+    (0x3315, b'M', "\u{30ad}\u{30ed}\u{30b0}\u{30e9}\u{30e0}"),
+    (0x3316, b'M', "\u{30ad}\u{30ed}\u{30e1}\u{30fc}\u{30c8}\u{30eb}"),
+    (0x3317, b'M', "\u{30ad}\u{30ed}\u{30ef}\u{30c3}\u{30c8}"),
+    (0x3318, b'M', "\u{30b0}\u{30e9}\u{30e0}"),
+    (0x3319, b'M', "\u{30b0}\u{30e9}\u{30e0}\u{30c8}\u{30f3}"),
+    (0x331a, b'M', "\u{30af}\u{30eb}\u{30bc}\u{30a4}\u{30ed}"),
+    (0x331b, b'M', "\u{30af}\u{30ed}\u{30fc}\u{30cd}"), (0x331c, b'M', "\u{30b1}\u{30fc}\u{30b9}"),
+    (0x331d, b'M', "\u{30b3}\u{30eb}\u{30ca}"), (0x331e, b'M', "\u{30b3}\u{30fc}\u{30dd}"),
+    (0x331f, b'M', "\u{30b5}\u{30a4}\u{30af}\u{30eb}"),
+    (0x3320, b'M', "\u{30b5}\u{30f3}\u{30c1}\u{30fc}\u{30e0}"),
+    (0x3321, b'M', "\u{30b7}\u{30ea}\u{30f3}\u{30b0}"), (0x3322, b'M', "\u{30bb}\u{30f3}\u{30c1}"),
+    (0x3323, b'M', "\u{30bb}\u{30f3}\u{30c8}"), (0x3324, b'M', "\u{30c0}\u{30fc}\u{30b9}"),
+    (0x3325, b'M', "\u{30c7}\u{30b7}"), (0x3326, b'M', "\u{30c9}\u{30eb}"),
+    (0x3327, b'M', "\u{30c8}\u{30f3}"), (0x3328, b'M', "\u{30ca}\u{30ce}"),
+    (0x3329, b'M', "\u{30ce}\u{30c3}\u{30c8}"), (0x332a, b'M', "\u{30cf}\u{30a4}\u{30c4}"),
+    (0x332b, b'M', "\u{30d1}\u{30fc}\u{30bb}\u{30f3}\u{30c8}"),
+    (0x332c, b'M', "\u{30d1}\u{30fc}\u{30c4}"), (0x332d, b'M', "\u{30d0}\u{30fc}\u{30ec}\u{30eb}"),
+    (0x332e, b'M', "\u{30d4}\u{30a2}\u{30b9}\u{30c8}\u{30eb}"),
+    (0x332f, b'M', "\u{30d4}\u{30af}\u{30eb}"), (0x3330, b'M', "\u{30d4}\u{30b3}"),
+    (0x3331, b'M', "\u{30d3}\u{30eb}"), (0x3332, b'M', "\u{30d5}\u{30a1}\u{30e9}\u{30c3}\u{30c9}"),
+    (0x3333, b'M', "\u{30d5}\u{30a3}\u{30fc}\u{30c8}"),
+    (0x3334, b'M', "\u{30d6}\u{30c3}\u{30b7}\u{30a7}\u{30eb}"),
+    (0x3335, b'M', "\u{30d5}\u{30e9}\u{30f3}"),
+    (0x3336, b'M', "\u{30d8}\u{30af}\u{30bf}\u{30fc}\u{30eb}"), (0x3337, b'M', "\u{30da}\u{30bd}"),
+    (0x3338, b'M', "\u{30da}\u{30cb}\u{30d2}"), (0x3339, b'M', "\u{30d8}\u{30eb}\u{30c4}"),
+    (0x333a, b'M', "\u{30da}\u{30f3}\u{30b9}"), (0x333b, b'M', "\u{30da}\u{30fc}\u{30b8}"),
+    (0x333c, b'M', "\u{30d9}\u{30fc}\u{30bf}"), (0x333d, b'M', "\u{30dd}\u{30a4}\u{30f3}\u{30c8}"),
+    (0x333e, b'M', "\u{30dc}\u{30eb}\u{30c8}"), (0x333f, b'M', "\u{30db}\u{30f3}"),
+    (0x3340, b'M', "\u{30dd}\u{30f3}\u{30c9}"), (0x3341, b'M', "\u{30db}\u{30fc}\u{30eb}"),
+    (0x3342, b'M', "\u{30db}\u{30fc}\u{30f3}"), (0x3343, b'M', "\u{30de}\u{30a4}\u{30af}\u{30ed}"),
+    (0x3344, b'M', "\u{30de}\u{30a4}\u{30eb}"), (0x3345, b'M', "\u{30de}\u{30c3}\u{30cf}"),
+    (0x3346, b'M', "\u{30de}\u{30eb}\u{30af}"),
+    (0x3347, b'M', "\u{30de}\u{30f3}\u{30b7}\u{30e7}\u{30f3}"),
+    (0x3348, b'M', "\u{30df}\u{30af}\u{30ed}\u{30f3}"), (0x3349, b'M', "\u{30df}\u{30ea}"),
+    (0x334a, b'M', "\u{30df}\u{30ea}\u{30d0}\u{30fc}\u{30eb}"), (0x334b, b'M', "\u{30e1}\u{30ac}"),
+    (0x334c, b'M', "\u{30e1}\u{30ac}\u{30c8}\u{30f3}"),
+    (0x334d, b'M', "\u{30e1}\u{30fc}\u{30c8}\u{30eb}"), (0x334e, b'M', "\u{30e4}\u{30fc}\u{30c9}"),
+    (0x334f, b'M', "\u{30e4}\u{30fc}\u{30eb}"), (0x3350, b'M', "\u{30e6}\u{30a2}\u{30f3}"),
+    (0x3351, b'M', "\u{30ea}\u{30c3}\u{30c8}\u{30eb}"), (0x3352, b'M', "\u{30ea}\u{30e9}"),
+    (0x3353, b'M', "\u{30eb}\u{30d4}\u{30fc}"), (0x3354, b'M', "\u{30eb}\u{30fc}\u{30d6}\u{30eb}"),
+    (0x3355, b'M', "\u{30ec}\u{30e0}"), (0x3356, b'M', "\u{30ec}\u{30f3}\u{30c8}\u{30b2}\u{30f3}"),
+    (0x3357, b'M', "\u{30ef}\u{30c3}\u{30c8}"), (0x3358, b'M', "0\u{70b9}"),
+    (0x3359, b'M', "1\u{70b9}"), (0x335a, b'M', "2\u{70b9}"), (0x335b, b'M', "3\u{70b9}"),
+    (0x335c, b'M', "4\u{70b9}"), (0x335d, b'M', "5\u{70b9}"), (0x335e, b'M', "6\u{70b9}"),
+    (0x335f, b'M', "7\u{70b9}"), (0x3360, b'M', "8\u{70b9}"), (0x3361, b'M', "9\u{70b9}"),
+    (0x3362, b'M', "10\u{70b9}"), (0x3363, b'M', "11\u{70b9}"), (0x3364, b'M', "12\u{70b9}"),
+    (0x3365, b'M', "13\u{70b9}"), (0x3366, b'M', "14\u{70b9}"), (0x3367, b'M', "15\u{70b9}"),
+    (0x3368, b'M', "16\u{70b9}"), (0x3369, b'M', "17\u{70b9}"), (0x336a, b'M', "18\u{70b9}"),
+    (0x336b, b'M', "19\u{70b9}"), (0x336c, b'M', "20\u{70b9}"), (0x336d, b'M', "21\u{70b9}"),
+    (0x336e, b'M', "22\u{70b9}"), (0x336f, b'M', "23\u{70b9}"), (0x3370, b'M', "24\u{70b9}"),
+    (0x3371, b'M', "hpa"), (0x3372, b'M', "da"), (0x3373, b'M', "au"), (0x3374, b'M', "bar"),
+    (0x3375, b'M', "ov"), (0x3376, b'M', "pc"), (0x3377, b'M', "dm"), (0x3378, b'M', "dm2"),
+    (0x3379, b'M', "dm3"), (0x337a, b'M', "iu"), (0x337b, b'M', "\u{5e73}\u{6210}"),
+    (0x337c, b'M', "\u{662d}\u{548c}"), (0x337d, b'M', "\u{5927}\u{6b63}"),
+    (0x337e, b'M', "\u{660e}\u{6cbb}"), (0x337f, b'M', "\u{682a}\u{5f0f}\u{4f1a}\u{793e}"),
+    (0x3380, b'M', "pa"), (0x3381, b'M', "na"), (0x3382, b'M', "\u{3bc}a"), (0x3383, b'M', "ma"),
+    (0x3384, b'M', "ka"), (0x3385, b'M', "kb"), (0x3386, b'M', "mb"), (0x3387, b'M', "gb"),
+    (0x3388, b'M', "cal"), (0x3389, b'M', "kcal"), (0x338a, b'M', "pf"), (0x338b, b'M', "nf"),
+    (0x338c, b'M', "\u{3bc}f"), (0x338d, b'M', "\u{3bc}g"), (0x338e, b'M', "mg"),
+    (0x338f, b'M', "kg"), (0x3390, b'M', "hz"), (0x3391, b'M', "khz"), (0x3392, b'M', "mhz"),
+    (0x3393, b'M', "ghz"), (0x3394, b'M', "thz"), (0x3395, b'M', "\u{3bc}l"), (0x3396, b'M', "ml"),
+    (0x3397, b'M', "dl"), (0x3398, b'M', "kl"), (0x3399, b'M', "fm"), (0x339a, b'M', "nm"),
+    (0x339b, b'M', "\u{3bc}m"), (0x339c, b'M', "mm"), (0x339d, b'M', "cm"), (0x339e, b'M', "km"),
+    (0x339f, b'M', "mm2"), (0x33a0, b'M', "cm2"), (0x33a1, b'M', "m2"), (0x33a2, b'M', "km2"),
+    (0x33a3, b'M', "mm3"), (0x33a4, b'M', "cm3"), (0x33a5, b'M', "m3"), (0x33a6, b'M', "km3"),
+    (0x33a7, b'M', "m\u{2215}s"), (0x33a8, b'M', "m\u{2215}s2"), (0x33a9, b'M', "pa"),
+    (0x33aa, b'M', "kpa"), (0x33ab, b'M', "mpa"), (0x33ac, b'M', "gpa"), (0x33ad, b'M', "rad"),
+    (0x33ae, b'M', "rad\u{2215}s"), (0x33af, b'M', "rad\u{2215}s2"), (0x33b0, b'M', "ps"),
+    (0x33b1, b'M', "ns"), (0x33b2, b'M', "\u{3bc}s"), (0x33b3, b'M', "ms"), (0x33b4, b'M', "pv"),
+    (0x33b5, b'M', "nv"), (0x33b6, b'M', "\u{3bc}v"), (0x33b7, b'M', "mv"), (0x33b8, b'M', "kv"),
+    (0x33b9, b'M', "mv"), (0x33ba, b'M', "pw"), (0x33bb, b'M', "nw"), (0x33bc, b'M', "\u{3bc}w"),
+    (0x33bd, b'M', "mw"), (0x33be, b'M', "kw"), (0x33bf, b'M', "mw"), (0x33c0, b'M', "k\u{3c9}"),
+    (0x33c1, b'M', "m\u{3c9}"), (0x33c2, b'X', ""), (0x33c3, b'M', "bq"), (0x33c4, b'M', "cc"),
+    (0x33c5, b'M', "cd"), (0x33c6, b'M', "c\u{2215}kg"), (0x33c7, b'X', ""), (0x33c8, b'M', "db"),
+    (0x33c9, b'M', "gy"), (0x33ca, b'M', "ha"), (0x33cb, b'M', "hp"), (0x33cc, b'M', "in"),
+    (0x33cd, b'M', "kk"), (0x33ce, b'M', "km"), (0x33cf, b'M', "kt"), (0x33d0, b'M', "lm"),
+    (0x33d1, b'M', "ln"), (0x33d2, b'M', "log"), (0x33d3, b'M', "lx"), (0x33d4, b'M', "mb"),
+    (0x33d5, b'M', "mil"), (0x33d6, b'M', "mol"), (0x33d7, b'M', "ph"), (0x33d8, b'X', ""),
+    (0x33d9, b'M', "ppm"), (0x33da, b'M', "pr"), (0x33db, b'M', "sr"), (0x33dc, b'M', "sv"),
+    (0x33dd, b'M', "wb"), (0x33de, b'M', "v\u{2215}m"), (0x33df, b'M', "a\u{2215}m"),
+    (0x33e0, b'M', "1\u{65e5}"), (0x33e1, b'M', "2\u{65e5}"), (0x33e2, b'M', "3\u{65e5}"),
+    (0x33e3, b'M', "4\u{65e5}"), (0x33e4, b'M', "5\u{65e5}"), (0x33e5, b'M', "6\u{65e5}"),
+    (0x33e6, b'M', "7\u{65e5}"), (0x33e7, b'M', "8\u{65e5}"), (0x33e8, b'M', "9\u{65e5}"),
+    (0x33e9, b'M', "10\u{65e5}"), (0x33ea, b'M', "11\u{65e5}"), (0x33eb, b'M', "12\u{65e5}"),
+    (0x33ec, b'M', "13\u{65e5}"), (0x33ed, b'M', "14\u{65e5}"), (0x33ee, b'M', "15\u{65e5}"),
+    (0x33ef, b'M', "16\u{65e5}"), (0x33f0, b'M', "17\u{65e5}"), (0x33f1, b'M', "18\u{65e5}"),
+    (0x33f2, b'M', "19\u{65e5}"), (0x33f3, b'M', "20\u{65e5}"), (0x33f4, b'M', "21\u{65e5}"),
+    (0x33f5, b'M', "22\u{65e5}"), (0x33f6, b'M', "23\u{65e5}"), (0x33f7, b'M', "24\u{65e5}"),
+    (0x33f8, b'M', "25\u{65e5}"), (0x33f9, b'M', "26\u{65e5}"), (0x33fa, b'M', "27\u{65e5}"),
+    (0x33fb, b'M', "28\u{65e5}"), (0x33fc, b'M', "29\u{65e5}"), (0x33fd, b'M', "30\u{65e5}"),
+    (0x33fe, b'M', "31\u{65e5}"), (0x33ff, b'M', "gal"), (0x3400, b'V', ""), (0xa48d, b'X', ""),
+    (0xa490, b'V', ""), (0xa4c7, b'X', ""), (0xa4d0, b'V', ""), (0xa62c, b'X', ""),
+    (0xa640, b'M', "\u{a641}"), (0xa641, b'V', ""), (0xa642, b'M', "\u{a643}"), (0xa643, b'V', ""),
+    (0xa644, b'M', "\u{a645}"), (0xa645, b'V', ""), (0xa646, b'M', "\u{a647}"), (0xa647, b'V', ""),
+    (0xa648, b'M', "\u{a649}"), (0xa649, b'V', ""), (0xa64a, b'M', "\u{a64b}"), (0xa64b, b'V', ""),
+    (0xa64c, b'M', "\u{a64d}"), (0xa64d, b'V', ""), (0xa64e, b'M', "\u{a64f}"), (0xa64f, b'V', ""),
+    (0xa650, b'M', "\u{a651}"), (0xa651, b'V', ""), (0xa652, b'M', "\u{a653}"), (0xa653, b'V', ""),
+    (0xa654, b'M', "\u{a655}"), (0xa655, b'V', ""), (0xa656, b'M', "\u{a657}"), (0xa657, b'V', ""),
+    (0xa658, b'M', "\u{a659}"), (0xa659, b'V', ""), (0xa65a, b'M', "\u{a65b}"), (0xa65b, b'V', ""),
+    (0xa65c, b'M', "\u{a65d}"), (0xa65d, b'V', ""), (0xa65e, b'M', "\u{a65f}"), (0xa65f, b'V', ""),
+    (0xa660, b'M', "\u{a661}"), (0xa661, b'V', ""), (0xa662, b'M', "\u{a663}"), (0xa663, b'V', ""),
+    (0xa664, b'M', "\u{a665}"), (0xa665, b'V', ""), (0xa666, b'M', "\u{a667}"), (0xa667, b'V', ""),
+    (0xa668, b'M', "\u{a669}"), (0xa669, b'V', ""), (0xa66a, b'M', "\u{a66b}"), (0xa66b, b'V', ""),
+    (0xa66c, b'M', "\u{a66d}"), (0xa66d, b'V', ""), (0xa680, b'M', "\u{a681}"), (0xa681, b'V', ""),
+    (0xa682, b'M', "\u{a683}"), (0xa683, b'V', ""), (0xa684, b'M', "\u{a685}"), (0xa685, b'V', ""),
+    (0xa686, b'M', "\u{a687}"), (0xa687, b'V', ""), (0xa688, b'M', "\u{a689}"), (0xa689, b'V', ""),
+    (0xa68a, b'M', "\u{a68b}"), (0xa68b, b'V', ""), (0xa68c, b'M', "\u{a68d}"), (0xa68d, b'V', ""),
+    (0xa68e, b'M', "\u{a68f}"), (0xa68f, b'V', ""), (0xa690, b'M', "\u{a691}"), (0xa691, b'V', ""),
+    (0xa692, b'M', "\u{a693}"), (0xa693, b'V', ""), (0xa694, b'M', "\u{a695}"), (0xa695, b'V', ""),
+    (0xa696, b'M', "\u{a697}"), (0xa697, b'V', ""), (0xa698, b'M', "\u{a699}"), (0xa699, b'V', ""),
+    (0xa69a, b'M', "\u{a69b}"), (0xa69b, b'V', ""), (0xa69c, b'M', "\u{44a}"),
+    (0xa69d, b'M', "\u{44c}"), (0xa69e, b'V', ""), (0xa6f8, b'X', ""), (0xa700, b'V', ""),
+    (0xa722, b'M', "\u{a723}"), (0xa723, b'V', ""), (0xa724, b'M', "\u{a725}"), (0xa725, b'V', ""),
+    (0xa726, b'M', "\u{a727}"), (0xa727, b'V', ""), (0xa728, b'M', "\u{a729}"), (0xa729, b'V', ""),
+    (0xa72a, b'M', "\u{a72b}"), (0xa72b, b'V', ""), (0xa72c, b'M', "\u{a72d}"), (0xa72d, b'V', ""),
+    (0xa72e, b'M', "\u{a72f}"), (0xa72f, b'V', ""), (0xa732, b'M', "\u{a733}"), (0xa733, b'V', ""),
+    (0xa734, b'M', "\u{a735}"), (0xa735, b'V', ""), (0xa736, b'M', "\u{a737}"), (0xa737, b'V', ""),
+    (0xa738, b'M', "\u{a739}"), (0xa739, b'V', ""), (0xa73a, b'M', "\u{a73b}"), (0xa73b, b'V', ""),
+    (0xa73c, b'M', "\u{a73d}"), (0xa73d, b'V', ""), (0xa73e, b'M', "\u{a73f}"), (0xa73f, b'V', ""),
+    (0xa740, b'M', "\u{a741}"), (0xa741, b'V', ""), (0xa742, b'M', "\u{a743}"), (0xa743, b'V', ""),
+    (0xa744, b'M', "\u{a745}"), (0xa745, b'V', ""), (0xa746, b'M', "\u{a747}"), (0xa747, b'V', ""),
+    (0xa748, b'M', "\u{a749}"), (0xa749, b'V', ""), (0xa74a, b'M', "\u{a74b}"), (0xa74b, b'V', ""),
+    (0xa74c, b'M', "\u{a74d}"), (0xa74d, b'V', ""), (0xa74e, b'M', "\u{a74f}"), (0xa74f, b'V', ""),
+    (0xa750, b'M', "\u{a751}"), (0xa751, b'V', ""), (0xa752, b'M', "\u{a753}"), (0xa753, b'V', ""),
+    (0xa754, b'M', "\u{a755}"), (0xa755, b'V', ""), (0xa756, b'M', "\u{a757}"), (0xa757, b'V', ""),
+    (0xa758, b'M', "\u{a759}"), (0xa759, b'V', ""), (0xa75a, b'M', "\u{a75b}"), (0xa75b, b'V', ""),
+    (0xa75c, b'M', "\u{a75d}"), (0xa75d, b'V', ""), (0xa75e, b'M', "\u{a75f}"), (0xa75f, b'V', ""),
+    (0xa760, b'M', "\u{a761}"), (0xa761, b'V', ""), (0xa762, b'M', "\u{a763}"), (0xa763, b'V', ""),
+    (0xa764, b'M', "\u{a765}"), (0xa765, b'V', ""), (0xa766, b'M', "\u{a767}"), (0xa767, b'V', ""),
+    (0xa768, b'M', "\u{a769}"), (0xa769, b'V', ""), (0xa76a, b'M', "\u{a76b}"), (0xa76b, b'V', ""),
+    (0xa76c, b'M', "\u{a76d}"), (0xa76d, b'V', ""), (0xa76e, b'M', "\u{a76f}"), (0xa76f, b'V', ""),
+    (0xa770, b'M', "\u{a76f}"), (0xa771, b'V', ""), (0xa779, b'M', "\u{a77a}"), (0xa77a, b'V', ""),
+    (0xa77b, b'M', "\u{a77c}"), (0xa77c, b'V', ""), (0xa77d, b'M', "\u{1d79}"),
+    (0xa77e, b'M', "\u{a77f}"), (0xa77f, b'V', ""), (0xa780, b'M', "\u{a781}"), (0xa781, b'V', ""),
+    (0xa782, b'M', "\u{a783}"), (0xa783, b'V', ""), (0xa784, b'M', "\u{a785}"), (0xa785, b'V', ""),
+    (0xa786, b'M', "\u{a787}"), (0xa787, b'V', ""), (0xa78b, b'M', "\u{a78c}"), (0xa78c, b'V', ""),
+    (0xa78d, b'M', "\u{265}"), (0xa78e, b'V', ""), (0xa790, b'M', "\u{a791}"), (0xa791, b'V', ""),
+    (0xa792, b'M', "\u{a793}"), (0xa793, b'V', ""), (0xa796, b'M', "\u{a797}"), (0xa797, b'V', ""),
+    (0xa798, b'M', "\u{a799}"), (0xa799, b'V', ""), (0xa79a, b'M', "\u{a79b}"), (0xa79b, b'V', ""),
+    (0xa79c, b'M', "\u{a79d}"), (0xa79d, b'V', ""), (0xa79e, b'M', "\u{a79f}"), (0xa79f, b'V', ""),
+    (0xa7a0, b'M', "\u{a7a1}"), (0xa7a1, b'V', ""), (0xa7a2, b'M', "\u{a7a3}"), (0xa7a3, b'V', ""),
+    (0xa7a4, b'M', "\u{a7a5}"), (0xa7a5, b'V', ""), (0xa7a6, b'M', "\u{a7a7}"), (0xa7a7, b'V', ""),
+    (0xa7a8, b'M', "\u{a7a9}"), (0xa7a9, b'V', ""), (0xa7aa, b'M', "\u{266}"),
+    (0xa7ab, b'M', "\u{25c}"), (0xa7ac, b'M', "\u{261}"), (0xa7ad, b'M', "\u{26c}"),
+    (0xa7ae, b'M', "\u{26a}"), (0xa7af, b'V', ""), (0xa7b0, b'M', "\u{29e}"),
+    (0xa7b1, b'M', "\u{287}"), (0xa7b2, b'M', "\u{29d}"), (0xa7b3, b'M', "\u{ab53}"),
+    (0xa7b4, b'M', "\u{a7b5}"), (0xa7b5, b'V', ""), (0xa7b6, b'M', "\u{a7b7}"), (0xa7b7, b'V', ""),
+    (0xa7b8, b'M', "\u{a7b9}"), (0xa7b9, b'V', ""), (0xa7ba, b'M', "\u{a7bb}"), (0xa7bb, b'V', ""),
+    (0xa7bc, b'M', "\u{a7bd}"), (0xa7bd, b'V', ""), (0xa7be, b'M', "\u{a7bf}"), (0xa7bf, b'V', ""),
+    (0xa7c0, b'M', "\u{a7c1}"), (0xa7c1, b'V', ""), (0xa7c2, b'M', "\u{a7c3}"), (0xa7c3, b'V', ""),
+    (0xa7c4, b'M', "\u{a794}"), (0xa7c5, b'M', "\u{282}"), (0xa7c6, b'M', "\u{1d8e}"),
+    (0xa7c7, b'M', "\u{a7c8}"), (0xa7c8, b'V', ""), (0xa7c9, b'M', "\u{a7ca}"), (0xa7ca, b'V', ""),
+    (0xa7cb, b'X', ""), (0xa7d0, b'M', "\u{a7d1}"), (0xa7d1, b'V', ""), (0xa7d2, b'X', ""),
+    (0xa7d3, b'V', ""), (0xa7d4, b'X', ""), (0xa7d5, b'V', ""), (0xa7d6, b'M', "\u{a7d7}"),
+    (0xa7d7, b'V', ""), (0xa7d8, b'M', "\u{a7d9}"), (0xa7d9, b'V', ""), (0xa7da, b'X', ""),
+    (0xa7f2, b'M', "c"), (0xa7f3, b'M', "f"), (0xa7f4, b'M', "q"), (0xa7f5, b'M', "\u{a7f6}"),
+    (0xa7f6, b'V', ""), (0xa7f8, b'M', "\u{127}"), (0xa7f9, b'M', "\u{153}"), (0xa7fa, b'V', ""),
+    (0xa82d, b'X', ""), (0xa830, b'V', ""), (0xa83a, b'X', ""), (0xa840, b'V', ""),
+    (0xa878, b'X', ""), (0xa880, b'V', ""), (0xa8c6, b'X', ""), (0xa8ce, b'V', ""),
+    (0xa8da, b'X', ""), (0xa8e0, b'V', ""), (0xa954, b'X', ""), (0xa95f, b'V', ""),
+    (0xa97d, b'X', ""), (0xa980, b'V', ""), (0xa9ce, b'X', ""), (0xa9cf, b'V', ""),
+    (0xa9da, b'X', ""), (0xa9de, b'V', ""), (0xa9ff, b'X', ""), (0xaa00, b'V', ""),
+    (0xaa37, b'X', ""), (0xaa40, b'V', ""), (0xaa4e, b'X', ""), (0xaa50, b'V', ""),
+    (0xaa5a, b'X', ""), (0xaa5c, b'V', ""), (0xaac3, b'X', ""), (0xaadb, b'V', ""),
+    (0xaaf7, b'X', ""), (0xab01, b'V', ""), (0xab07, b'X', ""), (0xab09, b'V', ""),
+    (0xab0f, b'X', ""), (0xab11, b'V', ""), (0xab17, b'X', ""), (0xab20, b'V', ""),
+    (0xab27, b'X', ""), (0xab28, b'V', ""), (0xab2f, b'X', ""), (0xab30, b'V', ""),
+    (0xab5c, b'M', "\u{a727}"), (0xab5d, b'M', "\u{ab37}"), (0xab5e, b'M', "\u{26b}"),
+    (0xab5f, b'M', "\u{ab52}"), (0xab60, b'V', ""), (0xab69, b'M', "\u{28d}"), (0xab6a, b'V', ""),
+    (0xab6c, b'X', ""), (0xab70, b'M', "\u{13a0}"), (0xab71, b'M', "\u{13a1}"),
+    (0xab72, b'M', "\u{13a2}"), (0xab73, b'M', "\u{13a3}"), (0xab74, b'M', "\u{13a4}"),
+    (0xab75, b'M', "\u{13a5}"), (0xab76, b'M', "\u{13a6}"), (0xab77, b'M', "\u{13a7}"),
+    (0xab78, b'M', "\u{13a8}"), (0xab79, b'M', "\u{13a9}"), (0xab7a, b'M', "\u{13aa}"),
+    (0xab7b, b'M', "\u{13ab}"), (0xab7c, b'M', "\u{13ac}"), (0xab7d, b'M', "\u{13ad}"),
+    (0xab7e, b'M', "\u{13ae}"), (0xab7f, b'M', "\u{13af}"), (0xab80, b'M', "\u{13b0}"),
+    (0xab81, b'M', "\u{13b1}"), (0xab82, b'M', "\u{13b2}"), (0xab83, b'M', "\u{13b3}"),
+    (0xab84, b'M', "\u{13b4}"), (0xab85, b'M', "\u{13b5}"), (0xab86, b'M', "\u{13b6}"),
+    (0xab87, b'M', "\u{13b7}"), (0xab88, b'M', "\u{13b8}"), (0xab89, b'M', "\u{13b9}"),
+    (0xab8a, b'M', "\u{13ba}"), (0xab8b, b'M', "\u{13bb}"), (0xab8c, b'M', "\u{13bc}"),
+    (0xab8d, b'M', "\u{13bd}"), (0xab8e, b'M', "\u{13be}"), (0xab8f, b'M', "\u{13bf}"),
+    (0xab90, b'M', "\u{13c0}"), (0xab91, b'M', "\u{13c1}"), (0xab92, b'M', "\u{13c2}"),
+    (0xab93, b'M', "\u{13c3}"), (0xab94, b'M', "\u{13c4}"), (0xab95, b'M', "\u{13c5}"),
+    (0xab96, b'M', "\u{13c6}"), (0xab97, b'M', "\u{13c7}"), (0xab98, b'M', "\u{13c8}"),
+    (0xab99, b'M', "\u{13c9}"), (0xab9a, b'M', "\u{13ca}"), (0xab9b, b'M', "\u{13cb}"),
+    (0xab9c, b'M', "\u{13cc}"), (0xab9d, b'M', "\u{13cd}"), (0xab9e, b'M', "\u{13ce}"),
+    (0xab9f, b'M', "\u{13cf}"), (0xaba0, b'M', "\u{13d0}"), (0xaba1, b'M', "\u{13d1}"),
+    (0xaba2, b'M', "\u{13d2}"), (0xaba3, b'M', "\u{13d3}"), (0xaba4, b'M', "\u{13d4}"),
+    (0xaba5, b'M', "\u{13d5}"), (0xaba6, b'M', "\u{13d6}"), (0xaba7, b'M', "\u{13d7}"),
+    (0xaba8, b'M', "\u{13d8}"), (0xaba9, b'M', "\u{13d9}"), (0xabaa, b'M', "\u{13da}"),
+    (0xabab, b'M', "\u{13db}"), (0xabac, b'M', "\u{13dc}"), (0xabad, b'M', "\u{13dd}"),
+    (0xabae, b'M', "\u{13de}"), (0xabaf, b'M', "\u{13df}"), (0xabb0, b'M', "\u{13e0}"),
+    (0xabb1, b'M', "\u{13e1}"), (0xabb2, b'M', "\u{13e2}"), (0xabb3, b'M', "\u{13e3}"),
+    (0xabb4, b'M', "\u{13e4}"), (0xabb5, b'M', "\u{13e5}"), (0xabb6, b'M', "\u{13e6}"),
+    (0xabb7, b'M', "\u{13e7}"), (0xabb8, b'M', "\u{13e8}"), (0xabb9, b'M', "\u{13e9}"),
+    (0xabba, b'M', "\u{13ea}"), (0xabbb, b'M', "\u{13eb}"), (0xabbc, b'M', "\u{13ec}"),
+    (0xabbd, b'M', "\u{13ed}"), (0xabbe, b'M', "\u{13ee}"), (0xabbf, b'M', "\u{13ef}"),
+    (0xabc0, b'V', ""), (0xabee, b'X', ""), (0xabf0, b'V', ""), (0xabfa, b'X', ""),
+    (0xac00, b'V', ""), (0xd7a4, b'X', ""), (0xd7b0, b'V', ""), (0xd7c7, b'X', ""),
+    (0xd7cb, b'V', ""), (0xd7fc, b'X', ""), (0xf900, b'M', "\u{8c48}"), (0xf901, b'M', "\u{66f4}"),
+    (0xf902, b'M', "\u{8eca}"), (0xf903, b'M', "\u{8cc8}"), (0xf904, b'M', "\u{6ed1}"),
+    (0xf905, b'M', "\u{4e32}"), (0xf906, b'M', "\u{53e5}"), (0xf907, b'M', "\u{9f9c}"),
+    (0xf909, b'M', "\u{5951}"), (0xf90a, b'M', "\u{91d1}"), (0xf90b, b'M', "\u{5587}"),
+    (0xf90c, b'M', "\u{5948}"), (0xf90d, b'M', "\u{61f6}"), (0xf90e, b'M', "\u{7669}"),
+    (0xf90f, b'M', "\u{7f85}"), (0xf910, b'M', "\u{863f}"), (0xf911, b'M', "\u{87ba}"),
+    (0xf912, b'M', "\u{88f8}"), (0xf913, b'M', "\u{908f}"), (0xf914, b'M', "\u{6a02}"),
+    (0xf915, b'M', "\u{6d1b}"), (0xf916, b'M', "\u{70d9}"), (0xf917, b'M', "\u{73de}"),
+    (0xf918, b'M', "\u{843d}"), (0xf919, b'M', "\u{916a}"), (0xf91a, b'M', "\u{99f1}"),
+    (0xf91b, b'M', "\u{4e82}"), (0xf91c, b'M', "\u{5375}"), (0xf91d, b'M', "\u{6b04}"),
+    (0xf91e, b'M', "\u{721b}"), (0xf91f, b'M', "\u{862d}"), (0xf920, b'M', "\u{9e1e}"),
+    (0xf921, b'M', "\u{5d50}"), (0xf922, b'M', "\u{6feb}"), (0xf923, b'M', "\u{85cd}"),
+    (0xf924, b'M', "\u{8964}"), (0xf925, b'M', "\u{62c9}"), (0xf926, b'M', "\u{81d8}"),
+    (0xf927, b'M', "\u{881f}"), (0xf928, b'M', "\u{5eca}"), (0xf929, b'M', "\u{6717}"),
+    (0xf92a, b'M', "\u{6d6a}"), (0xf92b, b'M', "\u{72fc}"), (0xf92c, b'M', "\u{90ce}"),
+    (0xf92d, b'M', "\u{4f86}"), (0xf92e, b'M', "\u{51b7}"), (0xf92f, b'M', "\u{52de}"),
+    (0xf930, b'M', "\u{64c4}"), (0xf931, b'M', "\u{6ad3}"), (0xf932, b'M', "\u{7210}"),
+    (0xf933, b'M', "\u{76e7}"), (0xf934, b'M', "\u{8001}"), (0xf935, b'M', "\u{8606}"),
+    (0xf936, b'M', "\u{865c}"), (0xf937, b'M', "\u{8def}"), (0xf938, b'M', "\u{9732}"),
+    (0xf939, b'M', "\u{9b6f}"), (0xf93a, b'M', "\u{9dfa}"), (0xf93b, b'M', "\u{788c}"),
+    (0xf93c, b'M', "\u{797f}"), (0xf93d, b'M', "\u{7da0}"), (0xf93e, b'M', "\u{83c9}"),
+    (0xf93f, b'M', "\u{9304}"), (0xf940, b'M', "\u{9e7f}"), (0xf941, b'M', "\u{8ad6}"),
+    (0xf942, b'M', "\u{58df}"), (0xf943, b'M', "\u{5f04}"), (0xf944, b'M', "\u{7c60}"),
+    (0xf945, b'M', "\u{807e}"), (0xf946, b'M', "\u{7262}"), (0xf947, b'M', "\u{78ca}"),
+    (0xf948, b'M', "\u{8cc2}"), (0xf949, b'M', "\u{96f7}"), (0xf94a, b'M', "\u{58d8}"),
+    (0xf94b, b'M', "\u{5c62}"), (0xf94c, b'M', "\u{6a13}"), (0xf94d, b'M', "\u{6dda}"),
+    (0xf94e, b'M', "\u{6f0f}"), (0xf94f, b'M', "\u{7d2f}"), (0xf950, b'M', "\u{7e37}"),
+    (0xf951, b'M', "\u{964b}"), (0xf952, b'M', "\u{52d2}"), (0xf953, b'M', "\u{808b}"),
+    (0xf954, b'M', "\u{51dc}"), (0xf955, b'M', "\u{51cc}"), (0xf956, b'M', "\u{7a1c}"),
+    (0xf957, b'M', "\u{7dbe}"), (0xf958, b'M', "\u{83f1}"), (0xf959, b'M', "\u{9675}"),
+    (0xf95a, b'M', "\u{8b80}"), (0xf95b, b'M', "\u{62cf}"), (0xf95c, b'M', "\u{6a02}"),
+    (0xf95d, b'M', "\u{8afe}"), (0xf95e, b'M', "\u{4e39}"), (0xf95f, b'M', "\u{5be7}"),
+    (0xf960, b'M', "\u{6012}"), (0xf961, b'M', "\u{7387}"), (0xf962, b'M', "\u{7570}"),
+    (0xf963, b'M', "\u{5317}"), (0xf964, b'M', "\u{78fb}"), (0xf965, b'M', "\u{4fbf}"),
+    (0xf966, b'M', "\u{5fa9}"), (0xf967, b'M', "\u{4e0d}"), (0xf968, b'M', "\u{6ccc}"),
+    (0xf969, b'M', "\u{6578}"), (0xf96a, b'M', "\u{7d22}"), (0xf96b, b'M', "\u{53c3}"),
+    (0xf96c, b'M', "\u{585e}"), (0xf96d, b'M', "\u{7701}"), (0xf96e, b'M', "\u{8449}"),
+    (0xf96f, b'M', "\u{8aaa}"), (0xf970, b'M', "\u{6bba}"), (0xf971, b'M', "\u{8fb0}"),
+    (0xf972, b'M', "\u{6c88}"), (0xf973, b'M', "\u{62fe}"), (0xf974, b'M', "\u{82e5}"),
+    (0xf975, b'M', "\u{63a0}"), (0xf976, b'M', "\u{7565}"), (0xf977, b'M', "\u{4eae}"),
+    (0xf978, b'M', "\u{5169}"), (0xf979, b'M', "\u{51c9}"), (0xf97a, b'M', "\u{6881}"),
+    (0xf97b, b'M', "\u{7ce7}"), (0xf97c, b'M', "\u{826f}"), (0xf97d, b'M', "\u{8ad2}"),
+    (0xf97e, b'M', "\u{91cf}"), (0xf97f, b'M', "\u{52f5}"), (0xf980, b'M', "\u{5442}"),
+    (0xf981, b'M', "\u{5973}"), (0xf982, b'M', "\u{5eec}"), (0xf983, b'M', "\u{65c5}"),
+    (0xf984, b'M', "\u{6ffe}"), (0xf985, b'M', "\u{792a}"), (0xf986, b'M', "\u{95ad}"),
+    (0xf987, b'M', "\u{9a6a}"), (0xf988, b'M', "\u{9e97}"), (0xf989, b'M', "\u{9ece}"),
+    (0xf98a, b'M', "\u{529b}"), (0xf98b, b'M', "\u{66c6}"), (0xf98c, b'M', "\u{6b77}"),
+    (0xf98d, b'M', "\u{8f62}"), (0xf98e, b'M', "\u{5e74}"), (0xf98f, b'M', "\u{6190}"),
+    (0xf990, b'M', "\u{6200}"), (0xf991, b'M', "\u{649a}"), (0xf992, b'M', "\u{6f23}"),
+    (0xf993, b'M', "\u{7149}"), (0xf994, b'M', "\u{7489}"), (0xf995, b'M', "\u{79ca}"),
+    (0xf996, b'M', "\u{7df4}"), (0xf997, b'M', "\u{806f}"), (0xf998, b'M', "\u{8f26}"),
+    (0xf999, b'M', "\u{84ee}"), (0xf99a, b'M', "\u{9023}"), (0xf99b, b'M', "\u{934a}"),
+    (0xf99c, b'M', "\u{5217}"), (0xf99d, b'M', "\u{52a3}"), (0xf99e, b'M', "\u{54bd}"),
+    (0xf99f, b'M', "\u{70c8}"), (0xf9a0, b'M', "\u{88c2}"), (0xf9a1, b'M', "\u{8aaa}"),
+    (0xf9a2, b'M', "\u{5ec9}"), (0xf9a3, b'M', "\u{5ff5}"), (0xf9a4, b'M', "\u{637b}"),
+    (0xf9a5, b'M', "\u{6bae}"), (0xf9a6, b'M', "\u{7c3e}"), (0xf9a7, b'M', "\u{7375}"),
+    (0xf9a8, b'M', "\u{4ee4}"), (0xf9a9, b'M', "\u{56f9}"), (0xf9aa, b'M', "\u{5be7}"),
+    (0xf9ab, b'M', "\u{5dba}"), (0xf9ac, b'M', "\u{601c}"), (0xf9ad, b'M', "\u{73b2}"),
+    (0xf9ae, b'M', "\u{7469}"), (0xf9af, b'M', "\u{7f9a}"), (0xf9b0, b'M', "\u{8046}"),
+    (0xf9b1, b'M', "\u{9234}"), (0xf9b2, b'M', "\u{96f6}"), (0xf9b3, b'M', "\u{9748}"),
+    (0xf9b4, b'M', "\u{9818}"), (0xf9b5, b'M', "\u{4f8b}"), (0xf9b6, b'M', "\u{79ae}"),
+    (0xf9b7, b'M', "\u{91b4}"), (0xf9b8, b'M', "\u{96b8}"), (0xf9b9, b'M', "\u{60e1}"),
+    (0xf9ba, b'M', "\u{4e86}"), (0xf9bb, b'M', "\u{50da}"), (0xf9bc, b'M', "\u{5bee}"),
+    (0xf9bd, b'M', "\u{5c3f}"), (0xf9be, b'M', "\u{6599}"), (0xf9bf, b'M', "\u{6a02}"),
+    (0xf9c0, b'M', "\u{71ce}"), (0xf9c1, b'M', "\u{7642}"), (0xf9c2, b'M', "\u{84fc}"),
+    (0xf9c3, b'M', "\u{907c}"), (0xf9c4, b'M', "\u{9f8d}"), (0xf9c5, b'M', "\u{6688}"),
+    (0xf9c6, b'M', "\u{962e}"), (0xf9c7, b'M', "\u{5289}"), (0xf9c8, b'M', "\u{677b}"),
+    (0xf9c9, b'M', "\u{67f3}"), (0xf9ca, b'M', "\u{6d41}"), (0xf9cb, b'M', "\u{6e9c}"),
+    (0xf9cc, b'M', "\u{7409}"), (0xf9cd, b'M', "\u{7559}"), (0xf9ce, b'M', "\u{786b}"),
+    (0xf9cf, b'M', "\u{7d10}"), (0xf9d0, b'M', "\u{985e}"), (0xf9d1, b'M', "\u{516d}"),
+    (0xf9d2, b'M', "\u{622e}"), (0xf9d3, b'M', "\u{9678}"), (0xf9d4, b'M', "\u{502b}"),
+    (0xf9d5, b'M', "\u{5d19}"), (0xf9d6, b'M', "\u{6dea}"), (0xf9d7, b'M', "\u{8f2a}"),
+    (0xf9d8, b'M', "\u{5f8b}"), (0xf9d9, b'M', "\u{6144}"), (0xf9da, b'M', "\u{6817}"),
+    (0xf9db, b'M', "\u{7387}"), (0xf9dc, b'M', "\u{9686}"), (0xf9dd, b'M', "\u{5229}"),
+    (0xf9de, b'M', "\u{540f}"), (0xf9df, b'M', "\u{5c65}"), (0xf9e0, b'M', "\u{6613}"),
+    (0xf9e1, b'M', "\u{674e}"), (0xf9e2, b'M', "\u{68a8}"), (0xf9e3, b'M', "\u{6ce5}"),
+    (0xf9e4, b'M', "\u{7406}"), (0xf9e5, b'M', "\u{75e2}"), (0xf9e6, b'M', "\u{7f79}"),
+    (0xf9e7, b'M', "\u{88cf}"), (0xf9e8, b'M', "\u{88e1}"), (0xf9e9, b'M', "\u{91cc}"),
+    (0xf9ea, b'M', "\u{96e2}"), (0xf9eb, b'M', "\u{533f}"), (0xf9ec, b'M', "\u{6eba}"),
+    (0xf9ed, b'M', "\u{541d}"), (0xf9ee, b'M', "\u{71d0}"), (0xf9ef, b'M', "\u{7498}"),
+    (0xf9f0, b'M', "\u{85fa}"), (0xf9f1, b'M', "\u{96a3}"), (0xf9f2, b'M', "\u{9c57}"),
+    (0xf9f3, b'M', "\u{9e9f}"), (0xf9f4, b'M', "\u{6797}"), (0xf9f5, b'M', "\u{6dcb}"),
+    (0xf9f6, b'M', "\u{81e8}"), (0xf9f7, b'M', "\u{7acb}"), (0xf9f8, b'M', "\u{7b20}"),
+    (0xf9f9, b'M', "\u{7c92}"), (0xf9fa, b'M', "\u{72c0}"), (0xf9fb, b'M', "\u{7099}"),
+    (0xf9fc, b'M', "\u{8b58}"), (0xf9fd, b'M', "\u{4ec0}"), (0xf9fe, b'M', "\u{8336}"),
+    (0xf9ff, b'M', "\u{523a}"), (0xfa00, b'M', "\u{5207}"), (0xfa01, b'M', "\u{5ea6}"),
+    (0xfa02, b'M', "\u{62d3}"), (0xfa03, b'M', "\u{7cd6}"), (0xfa04, b'M', "\u{5b85}"),
+    (0xfa05, b'M', "\u{6d1e}"), (0xfa06, b'M', "\u{66b4}"), (0xfa07, b'M', "\u{8f3b}"),
+    (0xfa08, b'M', "\u{884c}"), (0xfa09, b'M', "\u{964d}"), (0xfa0a, b'M', "\u{898b}"),
+    (0xfa0b, b'M', "\u{5ed3}"), (0xfa0c, b'M', "\u{5140}"), (0xfa0d, b'M', "\u{55c0}"),
+    (0xfa0e, b'V', ""), (0xfa10, b'M', "\u{585a}"), (0xfa11, b'V', ""), (0xfa12, b'M', "\u{6674}"),
+    (0xfa13, b'V', ""), (0xfa15, b'M', "\u{51de}"), (0xfa16, b'M', "\u{732a}"),
+    (0xfa17, b'M', "\u{76ca}"), (0xfa18, b'M', "\u{793c}"), (0xfa19, b'M', "\u{795e}"),
+    (0xfa1a, b'M', "\u{7965}"), (0xfa1b, b'M', "\u{798f}"), (0xfa1c, b'M', "\u{9756}"),
+    (0xfa1d, b'M', "\u{7cbe}"), (0xfa1e, b'M', "\u{7fbd}"), (0xfa1f, b'V', ""),
+    (0xfa20, b'M', "\u{8612}"), (0xfa21, b'V', ""), (0xfa22, b'M', "\u{8af8}"), (0xfa23, b'V', ""),
+    (0xfa25, b'M', "\u{9038}"), (0xfa26, b'M', "\u{90fd}"), (0xfa27, b'V', ""),
+    (0xfa2a, b'M', "\u{98ef}"), (0xfa2b, b'M', "\u{98fc}"), (0xfa2c, b'M', "\u{9928}"),
+    (0xfa2d, b'M', "\u{9db4}"), (0xfa2e, b'M', "\u{90de}"), (0xfa2f, b'M', "\u{96b7}"),
+    (0xfa30, b'M', "\u{4fae}"), (0xfa31, b'M', "\u{50e7}"), (0xfa32, b'M', "\u{514d}"),
+    (0xfa33, b'M', "\u{52c9}"), (0xfa34, b'M', "\u{52e4}"), (0xfa35, b'M', "\u{5351}"),
+    (0xfa36, b'M', "\u{559d}"), (0xfa37, b'M', "\u{5606}"), (0xfa38, b'M', "\u{5668}"),
+    (0xfa39, b'M', "\u{5840}"), (0xfa3a, b'M', "\u{58a8}"), (0xfa3b, b'M', "\u{5c64}"),
+    (0xfa3c, b'M', "\u{5c6e}"), (0xfa3d, b'M', "\u{6094}"), (0xfa3e, b'M', "\u{6168}"),
+    (0xfa3f, b'M', "\u{618e}"), (0xfa40, b'M', "\u{61f2}"), (0xfa41, b'M', "\u{654f}"),
+    (0xfa42, b'M', "\u{65e2}"), (0xfa43, b'M', "\u{6691}"), (0xfa44, b'M', "\u{6885}"),
+    (0xfa45, b'M', "\u{6d77}"), (0xfa46, b'M', "\u{6e1a}"), (0xfa47, b'M', "\u{6f22}"),
+    (0xfa48, b'M', "\u{716e}"), (0xfa49, b'M', "\u{722b}"), (0xfa4a, b'M', "\u{7422}"),
+    (0xfa4b, b'M', "\u{7891}"), (0xfa4c, b'M', "\u{793e}"), (0xfa4d, b'M', "\u{7949}"),
+    (0xfa4e, b'M', "\u{7948}"), (0xfa4f, b'M', "\u{7950}"), (0xfa50, b'M', "\u{7956}"),
+    (0xfa51, b'M', "\u{795d}"), (0xfa52, b'M', "\u{798d}"), (0xfa53, b'M', "\u{798e}"),
+    (0xfa54, b'M', "\u{7a40}"), (0xfa55, b'M', "\u{7a81}"), (0xfa56, b'M', "\u{7bc0}"),
+    (0xfa57, b'M', "\u{7df4}"), (0xfa58, b'M', "\u{7e09}"), (0xfa59, b'M', "\u{7e41}"),
+    (0xfa5a, b'M', "\u{7f72}"), (0xfa5b, b'M', "\u{8005}"), (0xfa5c, b'M', "\u{81ed}"),
+    (0xfa5d, b'M', "\u{8279}"), (0xfa5f, b'M', "\u{8457}"), (0xfa60, b'M', "\u{8910}"),
+    (0xfa61, b'M', "\u{8996}"), (0xfa62, b'M', "\u{8b01}"), (0xfa63, b'M', "\u{8b39}"),
+    (0xfa64, b'M', "\u{8cd3}"), (0xfa65, b'M', "\u{8d08}"), (0xfa66, b'M', "\u{8fb6}"),
+    (0xfa67, b'M', "\u{9038}"), (0xfa68, b'M', "\u{96e3}"), (0xfa69, b'M', "\u{97ff}"),
+    (0xfa6a, b'M', "\u{983b}"), (0xfa6b, b'M', "\u{6075}"), (0xfa6c, b'M', "\u{242ee}"),
+    (0xfa6d, b'M', "\u{8218}"), (0xfa6e, b'X', ""), (0xfa70, b'M', "\u{4e26}"),
+    (0xfa71, b'M', "\u{51b5}"), (0xfa72, b'M', "\u{5168}"), (0xfa73, b'M', "\u{4f80}"),
+    (0xfa74, b'M', "\u{5145}"), (0xfa75, b'M', "\u{5180}"), (0xfa76, b'M', "\u{52c7}"),
+    (0xfa77, b'M', "\u{52fa}"), (0xfa78, b'M', "\u{559d}"), (0xfa79, b'M', "\u{5555}"),
+    (0xfa7a, b'M', "\u{5599}"), (0xfa7b, b'M', "\u{55e2}"), (0xfa7c, b'M', "\u{585a}"),
+    (0xfa7d, b'M', "\u{58b3}"), (0xfa7e, b'M', "\u{5944}"), (0xfa7f, b'M', "\u{5954}"),
+    (0xfa80, b'M', "\u{5a62}"), (0xfa81, b'M', "\u{5b28}"), (0xfa82, b'M', "\u{5ed2}"),
+    (0xfa83, b'M', "\u{5ed9}"), (0xfa84, b'M', "\u{5f69}"), (0xfa85, b'M', "\u{5fad}"),
+    (0xfa86, b'M', "\u{60d8}"), (0xfa87, b'M', "\u{614e}"), (0xfa88, b'M', "\u{6108}"),
+    (0xfa89, b'M', "\u{618e}"), (0xfa8a, b'M', "\u{6160}"), (0xfa8b, b'M', "\u{61f2}"),
+    (0xfa8c, b'M', "\u{6234}"), (0xfa8d, b'M', "\u{63c4}"), (0xfa8e, b'M', "\u{641c}"),
+    (0xfa8f, b'M', "\u{6452}"), (0xfa90, b'M', "\u{6556}"), (0xfa91, b'M', "\u{6674}"),
+    (0xfa92, b'M', "\u{6717}"), (0xfa93, b'M', "\u{671b}"), (0xfa94, b'M', "\u{6756}"),
+    (0xfa95, b'M', "\u{6b79}"), (0xfa96, b'M', "\u{6bba}"), (0xfa97, b'M', "\u{6d41}"),
+    (0xfa98, b'M', "\u{6edb}"), (0xfa99, b'M', "\u{6ecb}"), (0xfa9a, b'M', "\u{6f22}"),
+    (0xfa9b, b'M', "\u{701e}"), (0xfa9c, b'M', "\u{716e}"), (0xfa9d, b'M', "\u{77a7}"),
+    (0xfa9e, b'M', "\u{7235}"), (0xfa9f, b'M', "\u{72af}"), (0xfaa0, b'M', "\u{732a}"),
+    (0xfaa1, b'M', "\u{7471}"), (0xfaa2, b'M', "\u{7506}"), (0xfaa3, b'M', "\u{753b}"),
+    (0xfaa4, b'M', "\u{761d}"), (0xfaa5, b'M', "\u{761f}"), (0xfaa6, b'M', "\u{76ca}"),
+    (0xfaa7, b'M', "\u{76db}"), (0xfaa8, b'M', "\u{76f4}"), (0xfaa9, b'M', "\u{774a}"),
+    (0xfaaa, b'M', "\u{7740}"), (0xfaab, b'M', "\u{78cc}"), (0xfaac, b'M', "\u{7ab1}"),
+    (0xfaad, b'M', "\u{7bc0}"), (0xfaae, b'M', "\u{7c7b}"), (0xfaaf, b'M', "\u{7d5b}"),
+    (0xfab0, b'M', "\u{7df4}"), (0xfab1, b'M', "\u{7f3e}"), (0xfab2, b'M', "\u{8005}"),
+    (0xfab3, b'M', "\u{8352}"), (0xfab4, b'M', "\u{83ef}"), (0xfab5, b'M', "\u{8779}"),
+    (0xfab6, b'M', "\u{8941}"), (0xfab7, b'M', "\u{8986}"), (0xfab8, b'M', "\u{8996}"),
+    (0xfab9, b'M', "\u{8abf}"), (0xfaba, b'M', "\u{8af8}"), (0xfabb, b'M', "\u{8acb}"),
+    (0xfabc, b'M', "\u{8b01}"), (0xfabd, b'M', "\u{8afe}"), (0xfabe, b'M', "\u{8aed}"),
+    (0xfabf, b'M', "\u{8b39}"), (0xfac0, b'M', "\u{8b8a}"), (0xfac1, b'M', "\u{8d08}"),
+    (0xfac2, b'M', "\u{8f38}"), (0xfac3, b'M', "\u{9072}"), (0xfac4, b'M', "\u{9199}"),
+    (0xfac5, b'M', "\u{9276}"), (0xfac6, b'M', "\u{967c}"), (0xfac7, b'M', "\u{96e3}"),
+    (0xfac8, b'M', "\u{9756}"), (0xfac9, b'M', "\u{97db}"), (0xfaca, b'M', "\u{97ff}"),
+    (0xfacb, b'M', "\u{980b}"), (0xfacc, b'M', "\u{983b}"), (0xfacd, b'M', "\u{9b12}"),
+    (0xface, b'M', "\u{9f9c}"), (0xfacf, b'M', "\u{2284a}"), (0xfad0, b'M', "\u{22844}"),
+    (0xfad1, b'M', "\u{233d5}"), (0xfad2, b'M', "\u{3b9d}"), (0xfad3, b'M', "\u{4018}"),
+    (0xfad4, b'M', "\u{4039}"), (0xfad5, b'M', "\u{25249}"), (0xfad6, b'M', "\u{25cd0}"),
+    (0xfad7, b'M', "\u{27ed3}"), (0xfad8, b'M', "\u{9f43}"), (0xfad9, b'M', "\u{9f8e}"),
+    (0xfada, b'X', ""), (0xfb00, b'M', "ff"), (0xfb01, b'M', "fi"), (0xfb02, b'M', "fl"),
+    (0xfb03, b'M', "ffi"), (0xfb04, b'M', "ffl"), (0xfb05, b'M', "st"), (0xfb07, b'X', ""),
+    (0xfb13, b'M', "\u{574}\u{576}"), (0xfb14, b'M', "\u{574}\u{565}"),
+    (0xfb15, b'M', "\u{574}\u{56b}"), (0xfb16, b'M', "\u{57e}\u{576}"),
+    (0xfb17, b'M', "\u{574}\u{56d}"), (0xfb18, b'X', ""), (0xfb1d, b'M', "\u{5d9}\u{5b4}"),
+    (0xfb1e, b'V', ""), (0xfb1f, b'M', "\u{5f2}\u{5b7}"), (0xfb20, b'M', "\u{5e2}"),
+    (0xfb21, b'M', "\u{5d0}"), (0xfb22, b'M', "\u{5d3}"), (0xfb23, b'M', "\u{5d4}"),
+    (0xfb24, b'M', "\u{5db}"), (0xfb25, b'M', "\u{5dc}"), (0xfb26, b'M', "\u{5dd}"),
+    (0xfb27, b'M', "\u{5e8}"), (0xfb28, b'M', "\u{5ea}"), (0xfb29, b'3', "+"),
+    (0xfb2a, b'M', "\u{5e9}\u{5c1}"), (0xfb2b, b'M', "\u{5e9}\u{5c2}"),
+    (0xfb2c, b'M', "\u{5e9}\u{5bc}\u{5c1}"), (0xfb2d, b'M', "\u{5e9}\u{5bc}\u{5c2}"),
+    (0xfb2e, b'M', "\u{5d0}\u{5b7}"), (0xfb2f, b'M', "\u{5d0}\u{5b8}"),
+    (0xfb30, b'M', "\u{5d0}\u{5bc}"), (0xfb31, b'M', "\u{5d1}\u{5bc}"),
+    (0xfb32, b'M', "\u{5d2}\u{5bc}"), (0xfb33, b'M', "\u{5d3}\u{5bc}"),
+    (0xfb34, b'M', "\u{5d4}\u{5bc}"), (0xfb35, b'M', "\u{5d5}\u{5bc}"),
+    (0xfb36, b'M', "\u{5d6}\u{5bc}"), (0xfb37, b'X', ""), (0xfb38, b'M', "\u{5d8}\u{5bc}"),
+    (0xfb39, b'M', "\u{5d9}\u{5bc}"), (0xfb3a, b'M', "\u{5da}\u{5bc}"),
+    (0xfb3b, b'M', "\u{5db}\u{5bc}"), (0xfb3c, b'M', "\u{5dc}\u{5bc}"), (0xfb3d, b'X', ""),
+    (0xfb3e, b'M', "\u{5de}\u{5bc}"), (0xfb3f, b'X', ""), (0xfb40, b'M', "\u{5e0}\u{5bc}"),
+    (0xfb41, b'M', "\u{5e1}\u{5bc}"), (0xfb42, b'X', ""), (0xfb43, b'M', "\u{5e3}\u{5bc}"),
+    (0xfb44, b'M', "\u{5e4}\u{5bc}"), (0xfb45, b'X', ""), (0xfb46, b'M', "\u{5e6}\u{5bc}"),
+    (0xfb47, b'M', "\u{5e7}\u{5bc}"), (0xfb48, b'M', "\u{5e8}\u{5bc}"),
+    (0xfb49, b'M', "\u{5e9}\u{5bc}"), (0xfb4a, b'M', "\u{5ea}\u{5bc}"),
+    (0xfb4b, b'M', "\u{5d5}\u{5b9}"), (0xfb4c, b'M', "\u{5d1}\u{5bf}"),
+    (0xfb4d, b'M', "\u{5db}\u{5bf}"), (0xfb4e, b'M', "\u{5e4}\u{5bf}"),
+    (0xfb4f, b'M', "\u{5d0}\u{5dc}"), (0xfb50, b'M', "\u{671}"), (0xfb52, b'M', "\u{67b}"),
+    (0xfb56, b'M', "\u{67e}"), (0xfb5a, b'M', "\u{680}"), (0xfb5e, b'M', "\u{67a}"),
+    (0xfb62, b'M', "\u{67f}"), (0xfb66, b'M', "\u{679}"), (0xfb6a, b'M', "\u{6a4}"),
+    (0xfb6e, b'M', "\u{6a6}"), (0xfb72, b'M', "\u{684}"), (0xfb76, b'M', "\u{683}"),
+    (0xfb7a, b'M', "\u{686}"), (0xfb7e, b'M', "\u{687}"), (0xfb82, b'M', "\u{68d}"),
+    (0xfb84, b'M', "\u{68c}"), (0xfb86, b'M', "\u{68e}"), (0xfb88, b'M', "\u{688}"),
+    (0xfb8a, b'M', "\u{698}"), (0xfb8c, b'M', "\u{691}"), (0xfb8e, b'M', "\u{6a9}"),
+    (0xfb92, b'M', "\u{6af}"), (0xfb96, b'M', "\u{6b3}"), (0xfb9a, b'M', "\u{6b1}"),
+    (0xfb9e, b'M', "\u{6ba}"), (0xfba0, b'M', "\u{6bb}"), (0xfba4, b'M', "\u{6c0}"),
+    (0xfba6, b'M', "\u{6c1}"), (0xfbaa, b'M', "\u{6be}"), (0xfbae, b'M', "\u{6d2}"),
+    (0xfbb0, b'M', "\u{6d3}"), (0xfbb2, b'V', ""), (0xfbc3, b'X', ""), (0xfbd3, b'M', "\u{6ad}"),
+    (0xfbd7, b'M', "\u{6c7}"), (0xfbd9, b'M', "\u{6c6}"), (0xfbdb, b'M', "\u{6c8}"),
+    (0xfbdd, b'M', "\u{6c7}\u{674}"), (0xfbde, b'M', "\u{6cb}"), (0xfbe0, b'M', "\u{6c5}"),
+    (0xfbe2, b'M', "\u{6c9}"), (0xfbe4, b'M', "\u{6d0}"), (0xfbe8, b'M', "\u{649}"),
+    (0xfbea, b'M', "\u{626}\u{627}"), (0xfbec, b'M', "\u{626}\u{6d5}"),
+    (0xfbee, b'M', "\u{626}\u{648}"), (0xfbf0, b'M', "\u{626}\u{6c7}"),
+    (0xfbf2, b'M', "\u{626}\u{6c6}"), (0xfbf4, b'M', "\u{626}\u{6c8}"),
+    (0xfbf6, b'M', "\u{626}\u{6d0}"), (0xfbf9, b'M', "\u{626}\u{649}"), (0xfbfc, b'M', "\u{6cc}"),
+    (0xfc00, b'M', "\u{626}\u{62c}"), (0xfc01, b'M', "\u{626}\u{62d}"),
+    (0xfc02, b'M', "\u{626}\u{645}"), (0xfc03, b'M', "\u{626}\u{649}"),
+    (0xfc04, b'M', "\u{626}\u{64a}"), (0xfc05, b'M', "\u{628}\u{62c}"),
+    (0xfc06, b'M', "\u{628}\u{62d}"), (0xfc07, b'M', "\u{628}\u{62e}"),
+    (0xfc08, b'M', "\u{628}\u{645}"), (0xfc09, b'M', "\u{628}\u{649}"),
+    (0xfc0a, b'M', "\u{628}\u{64a}"), (0xfc0b, b'M', "\u{62a}\u{62c}"),
+    (0xfc0c, b'M', "\u{62a}\u{62d}"), (0xfc0d, b'M', "\u{62a}\u{62e}"),
+    (0xfc0e, b'M', "\u{62a}\u{645}"), (0xfc0f, b'M', "\u{62a}\u{649}"),
+    (0xfc10, b'M', "\u{62a}\u{64a}"), (0xfc11, b'M', "\u{62b}\u{62c}"),
+    (0xfc12, b'M', "\u{62b}\u{645}"), (0xfc13, b'M', "\u{62b}\u{649}"),
+    (0xfc14, b'M', "\u{62b}\u{64a}"), (0xfc15, b'M', "\u{62c}\u{62d}"),
+    (0xfc16, b'M', "\u{62c}\u{645}"), (0xfc17, b'M', "\u{62d}\u{62c}"),
+    (0xfc18, b'M', "\u{62d}\u{645}"), (0xfc19, b'M', "\u{62e}\u{62c}"),
+    (0xfc1a, b'M', "\u{62e}\u{62d}"), (0xfc1b, b'M', "\u{62e}\u{645}"),
+    (0xfc1c, b'M', "\u{633}\u{62c}"), (0xfc1d, b'M', "\u{633}\u{62d}"),
+    (0xfc1e, b'M', "\u{633}\u{62e}"), (0xfc1f, b'M', "\u{633}\u{645}"),
+    (0xfc20, b'M', "\u{635}\u{62d}"), (0xfc21, b'M', "\u{635}\u{645}"),
+    (0xfc22, b'M', "\u{636}\u{62c}"), (0xfc23, b'M', "\u{636}\u{62d}"),
+    (0xfc24, b'M', "\u{636}\u{62e}"), (0xfc25, b'M', "\u{636}\u{645}"),
+    (0xfc26, b'M', "\u{637}\u{62d}"), (0xfc27, b'M', "\u{637}\u{645}"),
+    (0xfc28, b'M', "\u{638}\u{645}"), (0xfc29, b'M', "\u{639}\u{62c}"),
+    (0xfc2a, b'M', "\u{639}\u{645}"), (0xfc2b, b'M', "\u{63a}\u{62c}"),
+    (0xfc2c, b'M', "\u{63a}\u{645}"), (0xfc2d, b'M', "\u{641}\u{62c}"),
+    (0xfc2e, b'M', "\u{641}\u{62d}"), (0xfc2f, b'M', "\u{641}\u{62e}"),
+    (0xfc30, b'M', "\u{641}\u{645}"), (0xfc31, b'M', "\u{641}\u{649}"),
+    (0xfc32, b'M', "\u{641}\u{64a}"), (0xfc33, b'M', "\u{642}\u{62d}"),
+    (0xfc34, b'M', "\u{642}\u{645}"), (0xfc35, b'M', "\u{642}\u{649}"),
+    (0xfc36, b'M', "\u{642}\u{64a}"), (0xfc37, b'M', "\u{643}\u{627}"),
+    (0xfc38, b'M', "\u{643}\u{62c}"), (0xfc39, b'M', "\u{643}\u{62d}"),
+    (0xfc3a, b'M', "\u{643}\u{62e}"), (0xfc3b, b'M', "\u{643}\u{644}"),
+    (0xfc3c, b'M', "\u{643}\u{645}"), (0xfc3d, b'M', "\u{643}\u{649}"),
+    (0xfc3e, b'M', "\u{643}\u{64a}"), (0xfc3f, b'M', "\u{644}\u{62c}"),
+    (0xfc40, b'M', "\u{644}\u{62d}"), (0xfc41, b'M', "\u{644}\u{62e}"),
+    (0xfc42, b'M', "\u{644}\u{645}"), (0xfc43, b'M', "\u{644}\u{649}"),
+    (0xfc44, b'M', "\u{644}\u{64a}"), (0xfc45, b'M', "\u{645}\u{62c}"),
+    (0xfc46, b'M', "\u{645}\u{62d}"), (0xfc47, b'M', "\u{645}\u{62e}"),
+    (0xfc48, b'M', "\u{645}\u{645}"), (0xfc49, b'M', "\u{645}\u{649}"),
+    (0xfc4a, b'M', "\u{645}\u{64a}"), (0xfc4b, b'M', "\u{646}\u{62c}"),
+    (0xfc4c, b'M', "\u{646}\u{62d}"), (0xfc4d, b'M', "\u{646}\u{62e}"),
+    (0xfc4e, b'M', "\u{646}\u{645}"), (0xfc4f, b'M', "\u{646}\u{649}"),
+    (0xfc50, b'M', "\u{646}\u{64a}"), (0xfc51, b'M', "\u{647}\u{62c}"),
+    (0xfc52, b'M', "\u{647}\u{645}"), (0xfc53, b'M', "\u{647}\u{649}"),
+    (0xfc54, b'M', "\u{647}\u{64a}"), (0xfc55, b'M', "\u{64a}\u{62c}"),
+    (0xfc56, b'M', "\u{64a}\u{62d}"), (0xfc57, b'M', "\u{64a}\u{62e}"),
+    (0xfc58, b'M', "\u{64a}\u{645}"), (0xfc59, b'M', "\u{64a}\u{649}"),
+    (0xfc5a, b'M', "\u{64a}\u{64a}"), (0xfc5b, b'M', "\u{630}\u{670}"),
+    (0xfc5c, b'M', "\u{631}\u{670}"), (0xfc5d, b'M', "\u{649}\u{670}"),
+    (0xfc5e, b'3', " \u{64c}\u{651}"), (0xfc5f, b'3', " \u{64d}\u{651}"),
+    (0xfc60, b'3', " \u{64e}\u{651}"), (0xfc61, b'3', " \u{64f}\u{651}"),
+    (0xfc62, b'3', " \u{650}\u{651}"), (0xfc63, b'3', " \u{651}\u{670}"),
+    (0xfc64, b'M', "\u{626}\u{631}"), (0xfc65, b'M', "\u{626}\u{632}"),
+    (0xfc66, b'M', "\u{626}\u{645}"), (0xfc67, b'M', "\u{626}\u{646}"),
+    (0xfc68, b'M', "\u{626}\u{649}"), (0xfc69, b'M', "\u{626}\u{64a}"),
+    (0xfc6a, b'M', "\u{628}\u{631}"), (0xfc6b, b'M', "\u{628}\u{632}"),
+    (0xfc6c, b'M', "\u{628}\u{645}"), (0xfc6d, b'M', "\u{628}\u{646}"),
+    (0xfc6e, b'M', "\u{628}\u{649}"), (0xfc6f, b'M', "\u{628}\u{64a}"),
+    (0xfc70, b'M', "\u{62a}\u{631}"), (0xfc71, b'M', "\u{62a}\u{632}"),
+    (0xfc72, b'M', "\u{62a}\u{645}"), (0xfc73, b'M', "\u{62a}\u{646}"),
+    (0xfc74, b'M', "\u{62a}\u{649}"), (0xfc75, b'M', "\u{62a}\u{64a}"),
+    (0xfc76, b'M', "\u{62b}\u{631}"), (0xfc77, b'M', "\u{62b}\u{632}"),
+    (0xfc78, b'M', "\u{62b}\u{645}"), (0xfc79, b'M', "\u{62b}\u{646}"),
+    (0xfc7a, b'M', "\u{62b}\u{649}"), (0xfc7b, b'M', "\u{62b}\u{64a}"),
+    (0xfc7c, b'M', "\u{641}\u{649}"), (0xfc7d, b'M', "\u{641}\u{64a}"),
+    (0xfc7e, b'M', "\u{642}\u{649}"), (0xfc7f, b'M', "\u{642}\u{64a}"),
+    (0xfc80, b'M', "\u{643}\u{627}"), (0xfc81, b'M', "\u{643}\u{644}"),
+    (0xfc82, b'M', "\u{643}\u{645}"), (0xfc83, b'M', "\u{643}\u{649}"),
+    (0xfc84, b'M', "\u{643}\u{64a}"), (0xfc85, b'M', "\u{644}\u{645}"),
+    (0xfc86, b'M', "\u{644}\u{649}"), (0xfc87, b'M', "\u{644}\u{64a}"),
+    (0xfc88, b'M', "\u{645}\u{627}"), (0xfc89, b'M', "\u{645}\u{645}"),
+    (0xfc8a, b'M', "\u{646}\u{631}"), (0xfc8b, b'M', "\u{646}\u{632}"),
+    (0xfc8c, b'M', "\u{646}\u{645}"), (0xfc8d, b'M', "\u{646}\u{646}"),
+    (0xfc8e, b'M', "\u{646}\u{649}"), (0xfc8f, b'M', "\u{646}\u{64a}"),
+    (0xfc90, b'M', "\u{649}\u{670}"), (0xfc91, b'M', "\u{64a}\u{631}"),
+    (0xfc92, b'M', "\u{64a}\u{632}"), (0xfc93, b'M', "\u{64a}\u{645}"),
+    (0xfc94, b'M', "\u{64a}\u{646}"), (0xfc95, b'M', "\u{64a}\u{649}"),
+    (0xfc96, b'M', "\u{64a}\u{64a}"), (0xfc97, b'M', "\u{626}\u{62c}"),
+    (0xfc98, b'M', "\u{626}\u{62d}"), (0xfc99, b'M', "\u{626}\u{62e}"),
+    (0xfc9a, b'M', "\u{626}\u{645}"), (0xfc9b, b'M', "\u{626}\u{647}"),
+    (0xfc9c, b'M', "\u{628}\u{62c}"), (0xfc9d, b'M', "\u{628}\u{62d}"),
+    (0xfc9e, b'M', "\u{628}\u{62e}"), (0xfc9f, b'M', "\u{628}\u{645}"),
+    (0xfca0, b'M', "\u{628}\u{647}"), (0xfca1, b'M', "\u{62a}\u{62c}"),
+    (0xfca2, b'M', "\u{62a}\u{62d}"), (0xfca3, b'M', "\u{62a}\u{62e}"),
+    (0xfca4, b'M', "\u{62a}\u{645}"), (0xfca5, b'M', "\u{62a}\u{647}"),
+    (0xfca6, b'M', "\u{62b}\u{645}"), (0xfca7, b'M', "\u{62c}\u{62d}"),
+    (0xfca8, b'M', "\u{62c}\u{645}"), (0xfca9, b'M', "\u{62d}\u{62c}"),
+    (0xfcaa, b'M', "\u{62d}\u{645}"), (0xfcab, b'M', "\u{62e}\u{62c}"),
+    (0xfcac, b'M', "\u{62e}\u{645}"), (0xfcad, b'M', "\u{633}\u{62c}"),
+    (0xfcae, b'M', "\u{633}\u{62d}"), (0xfcaf, b'M', "\u{633}\u{62e}"),
+    (0xfcb0, b'M', "\u{633}\u{645}"), (0xfcb1, b'M', "\u{635}\u{62d}"),
+    (0xfcb2, b'M', "\u{635}\u{62e}"), (0xfcb3, b'M', "\u{635}\u{645}"),
+    (0xfcb4, b'M', "\u{636}\u{62c}"), (0xfcb5, b'M', "\u{636}\u{62d}"),
+    (0xfcb6, b'M', "\u{636}\u{62e}"), (0xfcb7, b'M', "\u{636}\u{645}"),
+    (0xfcb8, b'M', "\u{637}\u{62d}"), (0xfcb9, b'M', "\u{638}\u{645}"),
+    (0xfcba, b'M', "\u{639}\u{62c}"), (0xfcbb, b'M', "\u{639}\u{645}"),
+    (0xfcbc, b'M', "\u{63a}\u{62c}"), (0xfcbd, b'M', "\u{63a}\u{645}"),
+    (0xfcbe, b'M', "\u{641}\u{62c}"), (0xfcbf, b'M', "\u{641}\u{62d}"),
+    (0xfcc0, b'M', "\u{641}\u{62e}"), (0xfcc1, b'M', "\u{641}\u{645}"),
+    (0xfcc2, b'M', "\u{642}\u{62d}"), (0xfcc3, b'M', "\u{642}\u{645}"),
+    (0xfcc4, b'M', "\u{643}\u{62c}"), (0xfcc5, b'M', "\u{643}\u{62d}"),
+    (0xfcc6, b'M', "\u{643}\u{62e}"), (0xfcc7, b'M', "\u{643}\u{644}"),
+    (0xfcc8, b'M', "\u{643}\u{645}"), (0xfcc9, b'M', "\u{644}\u{62c}"),
+    (0xfcca, b'M', "\u{644}\u{62d}"), (0xfccb, b'M', "\u{644}\u{62e}"),
+    (0xfccc, b'M', "\u{644}\u{645}"), (0xfccd, b'M', "\u{644}\u{647}"),
+    (0xfcce, b'M', "\u{645}\u{62c}"), (0xfccf, b'M', "\u{645}\u{62d}"),
+    (0xfcd0, b'M', "\u{645}\u{62e}"), (0xfcd1, b'M', "\u{645}\u{645}"),
+    (0xfcd2, b'M', "\u{646}\u{62c}"), (0xfcd3, b'M', "\u{646}\u{62d}"),
+    (0xfcd4, b'M', "\u{646}\u{62e}"), (0xfcd5, b'M', "\u{646}\u{645}"),
+    (0xfcd6, b'M', "\u{646}\u{647}"), (0xfcd7, b'M', "\u{647}\u{62c}"),
+    (0xfcd8, b'M', "\u{647}\u{645}"), (0xfcd9, b'M', "\u{647}\u{670}"),
+    (0xfcda, b'M', "\u{64a}\u{62c}"), (0xfcdb, b'M', "\u{64a}\u{62d}"),
+    (0xfcdc, b'M', "\u{64a}\u{62e}"), (0xfcdd, b'M', "\u{64a}\u{645}"),
+    (0xfcde, b'M', "\u{64a}\u{647}"), (0xfcdf, b'M', "\u{626}\u{645}"),
+    (0xfce0, b'M', "\u{626}\u{647}"), (0xfce1, b'M', "\u{628}\u{645}"),
+    (0xfce2, b'M', "\u{628}\u{647}"), (0xfce3, b'M', "\u{62a}\u{645}"),
+    (0xfce4, b'M', "\u{62a}\u{647}"), (0xfce5, b'M', "\u{62b}\u{645}"),
+    (0xfce6, b'M', "\u{62b}\u{647}"), (0xfce7, b'M', "\u{633}\u{645}"),
+    (0xfce8, b'M', "\u{633}\u{647}"), (0xfce9, b'M', "\u{634}\u{645}"),
+    (0xfcea, b'M', "\u{634}\u{647}"), (0xfceb, b'M', "\u{643}\u{644}"),
+    (0xfcec, b'M', "\u{643}\u{645}"), (0xfced, b'M', "\u{644}\u{645}"),
+    (0xfcee, b'M', "\u{646}\u{645}"), (0xfcef, b'M', "\u{646}\u{647}"),
+    (0xfcf0, b'M', "\u{64a}\u{645}"), (0xfcf1, b'M', "\u{64a}\u{647}"),
+    (0xfcf2, b'M', "\u{640}\u{64e}\u{651}"), (0xfcf3, b'M', "\u{640}\u{64f}\u{651}"),
+    (0xfcf4, b'M', "\u{640}\u{650}\u{651}"), (0xfcf5, b'M', "\u{637}\u{649}"),
+    (0xfcf6, b'M', "\u{637}\u{64a}"), (0xfcf7, b'M', "\u{639}\u{649}"),
+    (0xfcf8, b'M', "\u{639}\u{64a}"), (0xfcf9, b'M', "\u{63a}\u{649}"),
+    (0xfcfa, b'M', "\u{63a}\u{64a}"), (0xfcfb, b'M', "\u{633}\u{649}"),
+    (0xfcfc, b'M', "\u{633}\u{64a}"), (0xfcfd, b'M', "\u{634}\u{649}"),
+    (0xfcfe, b'M', "\u{634}\u{64a}"), (0xfcff, b'M', "\u{62d}\u{649}"),
+    (0xfd00, b'M', "\u{62d}\u{64a}"), (0xfd01, b'M', "\u{62c}\u{649}"),
+    (0xfd02, b'M', "\u{62c}\u{64a}"), (0xfd03, b'M', "\u{62e}\u{649}"),
+    (0xfd04, b'M', "\u{62e}\u{64a}"), (0xfd05, b'M', "\u{635}\u{649}"),
+    (0xfd06, b'M', "\u{635}\u{64a}"), (0xfd07, b'M', "\u{636}\u{649}"),
+    (0xfd08, b'M', "\u{636}\u{64a}"), (0xfd09, b'M', "\u{634}\u{62c}"),
+    (0xfd0a, b'M', "\u{634}\u{62d}"), (0xfd0b, b'M', "\u{634}\u{62e}"),
+    (0xfd0c, b'M', "\u{634}\u{645}"), (0xfd0d, b'M', "\u{634}\u{631}"),
+    (0xfd0e, b'M', "\u{633}\u{631}"), (0xfd0f, b'M', "\u{635}\u{631}"),
+    (0xfd10, b'M', "\u{636}\u{631}"), (0xfd11, b'M', "\u{637}\u{649}"),
+    (0xfd12, b'M', "\u{637}\u{64a}"), (0xfd13, b'M', "\u{639}\u{649}"),
+    (0xfd14, b'M', "\u{639}\u{64a}"), (0xfd15, b'M', "\u{63a}\u{649}"),
+    (0xfd16, b'M', "\u{63a}\u{64a}"), (0xfd17, b'M', "\u{633}\u{649}"),
+    (0xfd18, b'M', "\u{633}\u{64a}"), (0xfd19, b'M', "\u{634}\u{649}"),
+    (0xfd1a, b'M', "\u{634}\u{64a}"), (0xfd1b, b'M', "\u{62d}\u{649}"),
+    (0xfd1c, b'M', "\u{62d}\u{64a}"), (0xfd1d, b'M', "\u{62c}\u{649}"),
+    (0xfd1e, b'M', "\u{62c}\u{64a}"), (0xfd1f, b'M', "\u{62e}\u{649}"),
+    (0xfd20, b'M', "\u{62e}\u{64a}"), (0xfd21, b'M', "\u{635}\u{649}"),
+    (0xfd22, b'M', "\u{635}\u{64a}"), (0xfd23, b'M', "\u{636}\u{649}"),
+    (0xfd24, b'M', "\u{636}\u{64a}"), (0xfd25, b'M', "\u{634}\u{62c}"),
+    (0xfd26, b'M', "\u{634}\u{62d}"), (0xfd27, b'M', "\u{634}\u{62e}"),
+    (0xfd28, b'M', "\u{634}\u{645}"), (0xfd29, b'M', "\u{634}\u{631}"),
+    (0xfd2a, b'M', "\u{633}\u{631}"), (0xfd2b, b'M', "\u{635}\u{631}"),
+    (0xfd2c, b'M', "\u{636}\u{631}"), (0xfd2d, b'M', "\u{634}\u{62c}"),
+    (0xfd2e, b'M', "\u{634}\u{62d}"), (0xfd2f, b'M', "\u{634}\u{62e}"),
+    (0xfd30, b'M', "\u{634}\u{645}"), (0xfd31, b'M', "\u{633}\u{647}"),
+    (0xfd32, b'M', "\u{634}\u{647}"), (0xfd33, b'M', "\u{637}\u{645}"),
+    (0xfd34, b'M', "\u{633}\u{62c}"), (0xfd35, b'M', "\u{633}\u{62d}"),
+    (0xfd36, b'M', "\u{633}\u{62e}"), (0xfd37, b'M', "\u{634}\u{62c}"),
+    (0xfd38, b'M', "\u{634}\u{62d}"), (0xfd39, b'M', "\u{634}\u{62e}"),
+    (0xfd3a, b'M', "\u{637}\u{645}"), (0xfd3b, b'M', "\u{638}\u{645}"),
+    (0xfd3c, b'M', "\u{627}\u{64b}"), (0xfd3e, b'V', ""), (0xfd50, b'M', "\u{62a}\u{62c}\u{645}"),
+    (0xfd51, b'M', "\u{62a}\u{62d}\u{62c}"), (0xfd53, b'M', "\u{62a}\u{62d}\u{645}"),
+    (0xfd54, b'M', "\u{62a}\u{62e}\u{645}"), (0xfd55, b'M', "\u{62a}\u{645}\u{62c}"),
+    (0xfd56, b'M', "\u{62a}\u{645}\u{62d}"), (0xfd57, b'M', "\u{62a}\u{645}\u{62e}"),
+    (0xfd58, b'M', "\u{62c}\u{645}\u{62d}"), (0xfd5a, b'M', "\u{62d}\u{645}\u{64a}"),
+    (0xfd5b, b'M', "\u{62d}\u{645}\u{649}"), (0xfd5c, b'M', "\u{633}\u{62d}\u{62c}"),
+    (0xfd5d, b'M', "\u{633}\u{62c}\u{62d}"), (0xfd5e, b'M', "\u{633}\u{62c}\u{649}"),
+    (0xfd5f, b'M', "\u{633}\u{645}\u{62d}"), (0xfd61, b'M', "\u{633}\u{645}\u{62c}"),
+    (0xfd62, b'M', "\u{633}\u{645}\u{645}"), (0xfd64, b'M', "\u{635}\u{62d}\u{62d}"),
+    (0xfd66, b'M', "\u{635}\u{645}\u{645}"), (0xfd67, b'M', "\u{634}\u{62d}\u{645}"),
+    (0xfd69, b'M', "\u{634}\u{62c}\u{64a}"), (0xfd6a, b'M', "\u{634}\u{645}\u{62e}"),
+    (0xfd6c, b'M', "\u{634}\u{645}\u{645}"), (0xfd6e, b'M', "\u{636}\u{62d}\u{649}"),
+    (0xfd6f, b'M', "\u{636}\u{62e}\u{645}"), (0xfd71, b'M', "\u{637}\u{645}\u{62d}"),
+    (0xfd73, b'M', "\u{637}\u{645}\u{645}"), (0xfd74, b'M', "\u{637}\u{645}\u{64a}"),
+    (0xfd75, b'M', "\u{639}\u{62c}\u{645}"), (0xfd76, b'M', "\u{639}\u{645}\u{645}"),
+    (0xfd78, b'M', "\u{639}\u{645}\u{649}"), (0xfd79, b'M', "\u{63a}\u{645}\u{645}"),
+    (0xfd7a, b'M', "\u{63a}\u{645}\u{64a}"), (0xfd7b, b'M', "\u{63a}\u{645}\u{649}"),
+    (0xfd7c, b'M', "\u{641}\u{62e}\u{645}"), (0xfd7e, b'M', "\u{642}\u{645}\u{62d}"),
+    (0xfd7f, b'M', "\u{642}\u{645}\u{645}"), (0xfd80, b'M', "\u{644}\u{62d}\u{645}"),
+    (0xfd81, b'M', "\u{644}\u{62d}\u{64a}"), (0xfd82, b'M', "\u{644}\u{62d}\u{649}"),
+    (0xfd83, b'M', "\u{644}\u{62c}\u{62c}"), (0xfd85, b'M', "\u{644}\u{62e}\u{645}"),
+    (0xfd87, b'M', "\u{644}\u{645}\u{62d}"), (0xfd89, b'M', "\u{645}\u{62d}\u{62c}"),
+    (0xfd8a, b'M', "\u{645}\u{62d}\u{645}"), (0xfd8b, b'M', "\u{645}\u{62d}\u{64a}"),
+    (0xfd8c, b'M', "\u{645}\u{62c}\u{62d}"), (0xfd8d, b'M', "\u{645}\u{62c}\u{645}"),
+    (0xfd8e, b'M', "\u{645}\u{62e}\u{62c}"), (0xfd8f, b'M', "\u{645}\u{62e}\u{645}"),
+    (0xfd90, b'X', ""), (0xfd92, b'M', "\u{645}\u{62c}\u{62e}"),
+    (0xfd93, b'M', "\u{647}\u{645}\u{62c}"), (0xfd94, b'M', "\u{647}\u{645}\u{645}"),
+    (0xfd95, b'M', "\u{646}\u{62d}\u{645}"), (0xfd96, b'M', "\u{646}\u{62d}\u{649}"),
+    (0xfd97, b'M', "\u{646}\u{62c}\u{645}"), (0xfd99, b'M', "\u{646}\u{62c}\u{649}"),
+    (0xfd9a, b'M', "\u{646}\u{645}\u{64a}"), (0xfd9b, b'M', "\u{646}\u{645}\u{649}"),
+    (0xfd9c, b'M', "\u{64a}\u{645}\u{645}"), (0xfd9e, b'M', "\u{628}\u{62e}\u{64a}"),
+    (0xfd9f, b'M', "\u{62a}\u{62c}\u{64a}"), (0xfda0, b'M', "\u{62a}\u{62c}\u{649}"),
+    (0xfda1, b'M', "\u{62a}\u{62e}\u{64a}"), (0xfda2, b'M', "\u{62a}\u{62e}\u{649}"),
+    (0xfda3, b'M', "\u{62a}\u{645}\u{64a}"), (0xfda4, b'M', "\u{62a}\u{645}\u{649}"),
+    (0xfda5, b'M', "\u{62c}\u{645}\u{64a}"), (0xfda6, b'M', "\u{62c}\u{62d}\u{649}"),
+    (0xfda7, b'M', "\u{62c}\u{645}\u{649}"), (0xfda8, b'M', "\u{633}\u{62e}\u{649}"),
+    (0xfda9, b'M', "\u{635}\u{62d}\u{64a}"), (0xfdaa, b'M', "\u{634}\u{62d}\u{64a}"),
+    (0xfdab, b'M', "\u{636}\u{62d}\u{64a}"), (0xfdac, b'M', "\u{644}\u{62c}\u{64a}"),
+    (0xfdad, b'M', "\u{644}\u{645}\u{64a}"), (0xfdae, b'M', "\u{64a}\u{62d}\u{64a}"),
+    (0xfdaf, b'M', "\u{64a}\u{62c}\u{64a}"), (0xfdb0, b'M', "\u{64a}\u{645}\u{64a}"),
+    (0xfdb1, b'M', "\u{645}\u{645}\u{64a}"), (0xfdb2, b'M', "\u{642}\u{645}\u{64a}"),
+    (0xfdb3, b'M', "\u{646}\u{62d}\u{64a}"), (0xfdb4, b'M', "\u{642}\u{645}\u{62d}"),
+    (0xfdb5, b'M', "\u{644}\u{62d}\u{645}"), (0xfdb6, b'M', "\u{639}\u{645}\u{64a}"),
+    (0xfdb7, b'M', "\u{643}\u{645}\u{64a}"), (0xfdb8, b'M', "\u{646}\u{62c}\u{62d}"),
+    (0xfdb9, b'M', "\u{645}\u{62e}\u{64a}"), (0xfdba, b'M', "\u{644}\u{62c}\u{645}"),
+    (0xfdbb, b'M', "\u{643}\u{645}\u{645}"), (0xfdbc, b'M', "\u{644}\u{62c}\u{645}"),
+    (0xfdbd, b'M', "\u{646}\u{62c}\u{62d}"), (0xfdbe, b'M', "\u{62c}\u{62d}\u{64a}"),
+    (0xfdbf, b'M', "\u{62d}\u{62c}\u{64a}"), (0xfdc0, b'M', "\u{645}\u{62c}\u{64a}"),
+    (0xfdc1, b'M', "\u{641}\u{645}\u{64a}"), (0xfdc2, b'M', "\u{628}\u{62d}\u{64a}"),
+    (0xfdc3, b'M', "\u{643}\u{645}\u{645}"), (0xfdc4, b'M', "\u{639}\u{62c}\u{645}"),
+    (0xfdc5, b'M', "\u{635}\u{645}\u{645}"), (0xfdc6, b'M', "\u{633}\u{62e}\u{64a}"),
+    (0xfdc7, b'M', "\u{646}\u{62c}\u{64a}"), (0xfdc8, b'X', ""), (0xfdcf, b'V', ""),
+    (0xfdd0, b'X', ""), (0xfdf0, b'M', "\u{635}\u{644}\u{6d2}"),
+    (0xfdf1, b'M', "\u{642}\u{644}\u{6d2}"), (0xfdf2, b'M', "\u{627}\u{644}\u{644}\u{647}"),
+    (0xfdf3, b'M', "\u{627}\u{643}\u{628}\u{631}"), (0xfdf4, b'M', "\u{645}\u{62d}\u{645}\u{62f}"),
+    (0xfdf5, b'M', "\u{635}\u{644}\u{639}\u{645}"), (0xfdf6, b'M', "\u{631}\u{633}\u{648}\u{644}"),
+    (0xfdf7, b'M', "\u{639}\u{644}\u{64a}\u{647}"), (0xfdf8, b'M', "\u{648}\u{633}\u{644}\u{645}"),
+    (0xfdf9, b'M', "\u{635}\u{644}\u{649}"),
+    (0xfdfa, b'3', "\u{635}\u{644}\u{649} \u{627}\u{644}\u{644}\u{647} \u{639}\u{644}\u{64a}\u{647} \u{648}\u{633}\u{644}\u{645}"),
+    (0xfdfb, b'3', "\u{62c}\u{644} \u{62c}\u{644}\u{627}\u{644}\u{647}"),
+    (0xfdfc, b'M', "\u{631}\u{6cc}\u{627}\u{644}"), (0xfdfd, b'V', ""), (0xfe00, b'I', ""),
+    (0xfe10, b'3', ","), (0xfe11, b'M', "\u{3001}"), (0xfe12, b'X', ""), (0xfe13, b'3', ":"),
+    (0xfe14, b'3', ";"), (0xfe15, b'3', "!"), (0xfe16, b'3', "?"), (0xfe17, b'M', "\u{3016}"),
+    (0xfe18, b'M', "\u{3017}"), (0xfe19, b'X', ""), (0xfe20, b'V', ""), (0xfe30, b'X', ""),
+    (0xfe31, b'M', "\u{2014}"), (0xfe32, b'M', "\u{2013}"), (0xfe33, b'3', "_"),
+    (0xfe35, b'3', "("), (0xfe36, b'3', ")"), (0xfe37, b'3', "{"), (0xfe38, b'3', "}"),
+    (0xfe39, b'M', "\u{3014}"), (0xfe3a, b'M', "\u{3015}"), (0xfe3b, b'M', "\u{3010}"),
+    (0xfe3c, b'M', "\u{3011}"), (0xfe3d, b'M', "\u{300a}"), (0xfe3e, b'M', "\u{300b}"),
+    (0xfe3f, b'M', "\u{3008}"), (0xfe40, b'M', "\u{3009}"), (0xfe41, b'M', "\u{300c}"),
+    (0xfe42, b'M', "\u{300d}"), (0xfe43, b'M', "\u{300e}"), (0xfe44, b'M', "\u{300f}"),
+    (0xfe45, b'V', ""), (0xfe47, b'3', "["), (0xfe48, b'3', "]"), (0xfe49, b'3', " \u{305}"),
+    (0xfe4d, b'3', "_"), (0xfe50, b'3', ","), (0xfe51, b'M', "\u{3001}"), (0xfe52, b'X', ""),
+    (0xfe54, b'3', ";"), (0xfe55, b'3', ":"), (0xfe56, b'3', "?"), (0xfe57, b'3', "!"),
+    (0xfe58, b'M', "\u{2014}"), (0xfe59, b'3', "("), (0xfe5a, b'3', ")"), (0xfe5b, b'3', "{"),
+    (0xfe5c, b'3', "}"), (0xfe5d, b'M', "\u{3014}"), (0xfe5e, b'M', "\u{3015}"),
+    (0xfe5f, b'3', "#"), (0xfe60, b'3', "&"), (0xfe61, b'3', "*"), (0xfe62, b'3', "+"),
+    (0xfe63, b'M', "-"), (0xfe64, b'3', "<"), (0xfe65, b'3', ">"), (0xfe66, b'3', "="),
+    (0xfe67, b'X', ""), (0xfe68, b'3', "\\"), (0xfe69, b'3', "$"), (0xfe6a, b'3', "%"),
+    (0xfe6b, b'3', "@"), (0xfe6c, b'X', ""), (0xfe70, b'3', " \u{64b}"),
+    (0xfe71, b'M', "\u{640}\u{64b}"), (0xfe72, b'3', " \u{64c}"), (0xfe73, b'V', ""),
+    (0xfe74, b'3', " \u{64d}"), (0xfe75, b'X', ""), (0xfe76, b'3', " \u{64e}"),
+    (0xfe77, b'M', "\u{640}\u{64e}"), (0xfe78, b'3', " \u{64f}"), (0xfe79, b'M', "\u{640}\u{64f}"),
+    (0xfe7a, b'3', " \u{650}"), (0xfe7b, b'M', "\u{640}\u{650}"), (0xfe7c, b'3', " \u{651}"),
+    (0xfe7d, b'M', "\u{640}\u{651}"), (0xfe7e, b'3', " \u{652}"), (0xfe7f, b'M', "\u{640}\u{652}"),
+    (0xfe80, b'M', "\u{621}"), (0xfe81, b'M', "\u{622}"), (0xfe83, b'M', "\u{623}"),
+    (0xfe85, b'M', "\u{624}"), (0xfe87, b'M', "\u{625}"), (0xfe89, b'M', "\u{626}"),
+    (0xfe8d, b'M', "\u{627}"), (0xfe8f, b'M', "\u{628}"), (0xfe93, b'M', "\u{629}"),
+    (0xfe95, b'M', "\u{62a}"), (0xfe99, b'M', "\u{62b}"), (0xfe9d, b'M', "\u{62c}"),
+    (0xfea1, b'M', "\u{62d}"), (0xfea5, b'M', "\u{62e}"), (0xfea9, b'M', "\u{62f}"),
+    (0xfeab, b'M', "\u{630}"), (0xfead, b'M', "\u{631}"), (0xfeaf, b'M', "\u{632}"),
+    (0xfeb1, b'M', "\u{633}"), (0xfeb5, b'M', "\u{634}"), (0xfeb9, b'M', "\u{635}"),
+    (0xfebd, b'M', "\u{636}"), (0xfec1, b'M', "\u{637}"), (0xfec5, b'M', "\u{638}"),
+    (0xfec9, b'M', "\u{639}"), (0xfecd, b'M', "\u{63a}"), (0xfed1, b'M', "\u{641}"),
+    (0xfed5, b'M', "\u{642}"), (0xfed9, b'M', "\u{643}"), (0xfedd, b'M', "\u{644}"),
+    (0xfee1, b'M', "\u{645}"), (0xfee5, b'M', "\u{646}"), (0xfee9, b'M', "\u{647}"),
+    (0xfeed, b'M', "\u{648}"), (0xfeef, b'M', "\u{649}"), (0xfef1, b'M', "\u{64a}"),
+    (0xfef5, b'M', "\u{644}\u{622}"), (0xfef7, b'M', "\u{644}\u{623}"),
+    (0xfef9, b'M', "\u{644}\u{625}"), (0xfefb, b'M', "\u{644}\u{627}"), (0xfefd, b'X', ""),
+    (0xfeff, b'I', ""), (0xff00, b'X', ""), (0xff01, b'3', "!"), (0xff02, b'3', "\""),
+    (0xff03, b'3', "#"), (0xff04, b'3', "$"), (0xff05, b'3', "%"), (0xff06, b'3', "&"),
+    (0xff07, b'3', "'"), (0xff08, b'3', "("), (0xff09, b'3', ")"), (0xff0a, b'3', "*"),
+    (0xff0b, b'3', "+"), (0xff0c, b'3', ","), (0xff0d, b'M', "-"), (0xff0e, b'M', "."),
+    (0xff0f, b'3', "/"), (0xff10, b'M', "0"), (0xff11, b'M', "1"), (0xff12, b'M', "2"),
+    (0xff13, b'M', "3"), (0xff14, b'M', "4"), (0xff15, b'M', "5"), (0xff16, b'M', "6"),
+    (0xff17, b'M', "7"), (0xff18, b'M', "8"), (0xff19, b'M', "9"), (0xff1a, b'3', ":"),
+    (0xff1b, b'3', ";"), (0xff1c, b'3', "<"), (0xff1d, b'3', "="), (0xff1e, b'3', ">"),
+    (0xff1f, b'3', "?"), (0xff20, b'3', "@"), (0xff21, b'M', "a"), (0xff22, b'M', "b"),
+    (0xff23, b'M', "c"), (0xff24, b'M', "d"), (0xff25, b'M', "e"), (0xff26, b'M', "f"),
+    (0xff27, b'M', "g"), (0xff28, b'M', "h"), (0xff29, b'M', "i"), (0xff2a, b'M', "j"),
+    (0xff2b, b'M', "k"), (0xff2c, b'M', "l"), (0xff2d, b'M', "m"), (0xff2e, b'M', "n"),
+    (0xff2f, b'M', "o"), (0xff30, b'M', "p"), (0xff31, b'M', "q"), (0xff32, b'M', "r"),
+    (0xff33, b'M', "s"), (0xff34, b'M', "t"), (0xff35, b'M', "u"), (0xff36, b'M', "v"),
+    (0xff37, b'M', "w"), (0xff38, b'M', "x"), (0xff39, b'M', "y"), (0xff3a, b'M', "z"),
+    (0xff3b, b'3', "["), (0xff3c, b'3', "\\"), (0xff3d, b'3', "]"), (0xff3e, b'3', "^"),
+    (0xff3f, b'3', "_"), (0xff40, b'3', "`"), (0xff41, b'M', "a"), (0xff42, b'M', "b"),
+    (0xff43, b'M', "c"), (0xff44, b'M', "d"), (0xff45, b'M', "e"), (0xff46, b'M', "f"),
+    (0xff47, b'M', "g"), (0xff48, b'M', "h"), (0xff49, b'M', "i"), (0xff4a, b'M', "j"),
+    (0xff4b, b'M', "k"), (0xff4c, b'M', "l"), (0xff4d, b'M', "m"), (0xff4e, b'M', "n"),
+    (0xff4f, b'M', "o"), (0xff50, b'M', "p"), (0xff51, b'M', "q"), (0xff52, b'M', "r"),
+    (0xff53, b'M', "s"), (0xff54, b'M', "t"), (0xff55, b'M', "u"), (0xff56, b'M', "v"),
+    (0xff57, b'M', "w"), (0xff58, b'M', "x"), (0xff59, b'M', "y"), (0xff5a, b'M', "z"),
+    (0xff5b, b'3', "{"), (0xff5c, b'3', "|"), (0xff5d, b'3', "}"), (0xff5e, b'3', "~"),
+    (0xff5f, b'M', "\u{2985}"), (0xff60, b'M', "\u{2986}"), (0xff61, b'M', "."),
+    (0xff62, b'M', "\u{300c}"), (0xff63, b'M', "\u{300d}"), (0xff64, b'M', "\u{3001}"),
+    (0xff65, b'M', "\u{30fb}"), (0xff66, b'M', "\u{30f2}"), (0xff67, b'M', "\u{30a1}"),
+    (0xff68, b'M', "\u{30a3}"), (0xff69, b'M', "\u{30a5}"), (0xff6a, b'M', "\u{30a7}"),
+    (0xff6b, b'M', "\u{30a9}"), (0xff6c, b'M', "\u{30e3}"), (0xff6d, b'M', "\u{30e5}"),
+    (0xff6e, b'M', "\u{30e7}"), (0xff6f, b'M', "\u{30c3}"), (0xff70, b'M', "\u{30fc}"),
+    (0xff71, b'M', "\u{30a2}"), (0xff72, b'M', "\u{30a4}"), (0xff73, b'M', "\u{30a6}"),
+    (0xff74, b'M', "\u{30a8}"), (0xff75, b'M', "\u{30aa}"), (0xff76, b'M', "\u{30ab}"),
+    (0xff77, b'M', "\u{30ad}"), (0xff78, b'M', "\u{30af}"), (0xff79, b'M', "\u{30b1}"),
+    (0xff7a, b'M', "\u{30b3}"), (0xff7b, b'M', "\u{30b5}"), (0xff7c, b'M', "\u{30b7}"),
+    (0xff7d, b'M', "\u{30b9}"), (0xff7e, b'M', "\u{30bb}"), (0xff7f, b'M', "\u{30bd}"),
+    (0xff80, b'M', "\u{30bf}"), (0xff81, b'M', "\u{30c1}"), (0xff82, b'M', "\u{30c4}"),
+    (0xff83, b'M', "\u{30c6}"), (0xff84, b'M', "\u{30c8}"), (0xff85, b'M', "\u{30ca}"),
+    (0xff86, b'M', "\u{30cb}"), (0xff87, b'M', "\u{30cc}"), (0xff88, b'M', "\u{30cd}"),
+    (0xff89, b'M', "\u{30ce}"), (0xff8a, b'M', "\u{30cf}"), (0xff8b, b'M', "\u{30d2}"),
+    (0xff8c, b'M', "\u{30d5}"), (0xff8d, b'M', "\u{30d8}"), (0xff8e, b'M', "\u{30db}"),
+    (0xff8f, b'M', "\u{30de}"), (0xff90, b'M', "\u{30df}"), (0xff91, b'M', "\u{30e0}"),
+    (0xff92, b'M', "\u{30e1}"), (0xff93, b'M', "\u{30e2}"), (0xff94, b'M', "\u{30e4}"),
+    (0xff95, b'M', "\u{30e6}"), (0xff96, b'M', "\u{30e8}"), (0xff97, b'M', "\u{30e9}"),
+    (0xff98, b'M', "\u{30ea}"), (0xff99, b'M', "\u{30eb}"), (0xff9a, b'M', "\u{30ec}"),
+    (0xff9b, b'M', "\u{30ed}"), (0xff9c, b'M', "\u{30ef}"), (0xff9d, b'M', "\u{30f3}"),
+    (0xff9e, b'M', "\u{3099}"), (0xff9f, b'M', "\u{309a}"), (0xffa0, b'X', ""),
+    (0xffa1, b'M', "\u{1100}"), (0xffa2, b'M', "\u{1101}"), (0xffa3, b'M', "\u{11aa}"),
+    (0xffa4, b'M', "\u{1102}"), (0xffa5, b'M', "\u{11ac}"), (0xffa6, b'M', "\u{11ad}"),
+    (0xffa7, b'M', "\u{1103}"), (0xffa8, b'M', "\u{1104}"), (0xffa9, b'M', "\u{1105}"),
+    (0xffaa, b'M', "\u{11b0}"), (0xffab, b'M', "\u{11b1}"), (0xffac, b'M', "\u{11b2}"),
+    (0xffad, b'M', "\u{11b3}"), (0xffae, b'M', "\u{11b4}"), (0xffaf, b'M', "\u{11b5}"),
+    (0xffb0, b'M', "\u{111a}"), (0xffb1, b'M', "\u{1106}"), (0xffb2, b'M', "\u{1107}"),
+    (0xffb3, b'M', "\u{1108}"), (0xffb4, b'M', "\u{1121}"), (0xffb5, b'M', "\u{1109}"),
+    (0xffb6, b'M', "\u{110a}"), (0xffb7, b'M', "\u{110b}"), (0xffb8, b'M', "\u{110c}"),
+    (0xffb9, b'M', "\u{110d}"), (0xffba, b'M', "\u{110e}"), (0xffbb, b'M', "\u{110f}"),
+    (0xffbc, b'M', "\u{1110}"), (0xffbd, b'M', "\u{1111}"), (0xffbe, b'M', "\u{1112}"),
+    (0xffbf, b'X', ""), (0xffc2, b'M', "\u{1161}"), (0xffc3, b'M', "\u{1162}"),
+    (0xffc4, b'M', "\u{1163}"), (0xffc5, b'M', "\u{1164}"), (0xffc6, b'M', "\u{1165}"),
+    (0xffc7, b'M', "\u{1166}"), (0xffc8, b'X', ""), (0xffca, b'M', "\u{1167}"),
+    (0xffcb, b'M', "\u{1168}"), (0xffcc, b'M', "\u{1169}"), (0xffcd, b'M', "\u{116a}"),
+    (0xffce, b'M', "\u{116b}"), (0xffcf, b'M', "\u{116c}"), (0xffd0, b'X', ""),
+    (0xffd2, b'M', "\u{116d}"), (0xffd3, b'M', "\u{116e}"), (0xffd4, b'M', "\u{116f}"),
+    (0xffd5, b'M', "\u{1170}"), (0xffd6, b'M', "\u{1171}"), (0xffd7, b'M', "\u{1172}"),
+    (0xffd8, b'X', ""), (0xffda, b'M', "\u{1173}"), (0xffdb, b'M', "\u{1174}"),
+    (0xffdc, b'M', "\u{1175}"), (0xffdd, b'X', ""), (0xffe0, b'M', "\u{a2}"),
+    (0xffe1, b'M', "\u{a3}"), (0xffe2, b'M', "\u{ac}"), (0xffe3, b'3', " \u{304}"),
+    (0xffe4, b'M', "\u{a6}"), (0xffe5, b'M', "\u{a5}"), (0xffe6, b'M', "\u{20a9}"),
+    (0xffe7, b'X', ""), (0xffe8, b'M', "\u{2502}"), (0xffe9, b'M', "\u{2190}"),
+    (0xffea, b'M', "\u{2191}"), (0xffeb, b'M', "\u{2192}"), (0xffec, b'M', "\u{2193}"),
+    (0xffed, b'M', "\u{25a0}"), (0xffee, b'M', "\u{25cb}"), (0xffef, b'X', ""), (0x10000, b'V', ""),
+    (0x1000c, b'X', ""), (0x1000d, b'V', ""), (0x10027, b'X', ""), (0x10028, b'V', ""),
+    (0x1003b, b'X', ""), (0x1003c, b'V', ""), (0x1003e, b'X', ""), (0x1003f, b'V', ""),
+    (0x1004e, b'X', ""), (0x10050, b'V', ""), (0x1005e, b'X', ""), (0x10080, b'V', ""),
+    (0x100fb, b'X', ""), (0x10100, b'V', ""), (0x10103, b'X', ""), (0x10107, b'V', ""),
+    (0x10134, b'X', ""), (0x10137, b'V', ""), (0x1018f, b'X', ""), (0x10190, b'V', ""),
+    (0x1019d, b'X', ""), (0x101a0, b'V', ""), (0x101a1, b'X', ""), (0x101d0, b'V', ""),
+    (0x101fe, b'X', ""), (0x10280, b'V', ""), (0x1029d, b'X', ""), (0x102a0, b'V', ""),
+    (0x102d1, b'X', ""), (0x102e0, b'V', ""), (0x102fc, b'X', ""), (0x10300, b'V', ""),
+    (0x10324, b'X', ""), (0x1032d, b'V', ""), (0x1034b, b'X', ""), (0x10350, b'V', ""),
+    (0x1037b, b'X', ""), (0x10380, b'V', ""), (0x1039e, b'X', ""), (0x1039f, b'V', ""),
+    (0x103c4, b'X', ""), (0x103c8, b'V', ""), (0x103d6, b'X', ""), (0x10400, b'M', "\u{10428}"),
+    (0x10401, b'M', "\u{10429}"), (0x10402, b'M', "\u{1042a}"), (0x10403, b'M', "\u{1042b}"),
+    (0x10404, b'M', "\u{1042c}"), (0x10405, b'M', "\u{1042d}"), (0x10406, b'M', "\u{1042e}"),
+    (0x10407, b'M', "\u{1042f}"), (0x10408, b'M', "\u{10430}"), (0x10409, b'M', "\u{10431}"),
+    (0x1040a, b'M', "\u{10432}"), (0x1040b, b'M', "\u{10433}"), (0x1040c, b'M', "\u{10434}"),
+    (0x1040d, b'M', "\u{10435}"), (0x1040e, b'M', "\u{10436}"), (0x1040f, b'M', "\u{10437}"),
+    (0x10410, b'M', "\u{10438}"), (0x10411, b'M', "\u{10439}"), (0x10412, b'M', "\u{1043a}"),
+    (0x10413, b'M', "\u{1043b}"), (0x10414, b'M', "\u{1043c}"), (0x10415, b'M', "\u{1043d}"),
+    (0x10416, b'M', "\u{1043e}"), (0x10417, b'M', "\u{1043f}"), (0x10418, b'M', "\u{10440}"),
+    (0x10419, b'M', "\u{10441}"), (0x1041a, b'M', "\u{10442}"), (0x1041b, b'M', "\u{10443}"),
+    (0x1041c, b'M', "\u{10444}"), (0x1041d, b'M', "\u{10445}"), (0x1041e, b'M', "\u{10446}"),
+    (0x1041f, b'M', "\u{10447}"), (0x10420, b'M', "\u{10448}"), (0x10421, b'M', "\u{10449}"),
+    (0x10422, b'M', "\u{1044a}"), (0x10423, b'M', "\u{1044b}"), (0x10424, b'M', "\u{1044c}"),
+    (0x10425, b'M', "\u{1044d}"), (0x10426, b'M', "\u{1044e}"), (0x10427, b'M', "\u{1044f}"),
+    (0x10428, b'V', ""), (0x1049e, b'X', ""), (0x104a0, b'V', ""), (0x104aa, b'X', ""),
+    (0x104b0, b'M', "\u{104d8}"), (0x104b1, b'M', "\u{104d9}"), (0x104b2, b'M', "\u{104da}"),
+    (0x104b3, b'M', "\u{104db}"), (0x104b4, b'M', "\u{104dc}"), (0x104b5, b'M', "\u{104dd}"),
+    (0x104b6, b'M', "\u{104de}"), (0x104b7, b'M', "\u{104df}"), (0x104b8, b'M', "\u{104e0}"),
+    (0x104b9, b'M', "\u{104e1}"), (0x104ba, b'M', "\u{104e2}"), (0x104bb, b'M', "\u{104e3}"),
+    (0x104bc, b'M', "\u{104e4}"), (0x104bd, b'M', "\u{104e5}"), (0x104be, b'M', "\u{104e6}"),
+    (0x104bf, b'M', "\u{104e7}"), (0x104c0, b'M', "\u{104e8}"), (0x104c1, b'M', "\u{104e9}"),
+    (0x104c2, b'M', "\u{104ea}"), (0x104c3, b'M', "\u{104eb}"), (0x104c4, b'M', "\u{104ec}"),
+    (0x104c5, b'M', "\u{104ed}"), (0x104c6, b'M', "\u{104ee}"), (0x104c7, b'M', "\u{104ef}"),
+    (0x104c8, b'M', "\u{104f0}"), (0x104c9, b'M', "\u{104f1}"), (0x104ca, b'M', "\u{104f2}"),
+    (0x104cb, b'M', "\u{104f3}"), (0x104cc, b'M', "\u{104f4}"), (0x104cd, b'M', "\u{104f5}"),
+    (0x104ce, b'M', "\u{104f6}"), (0x104cf, b'M', "\u{104f7}"), (0x104d0, b'M', "\u{104f8}"),
+    (0x104d1, b'M', "\u{104f9}"), (0x104d2, b'M', "\u{104fa}"), (0x104d3, b'M', "\u{104fb}"),
+    (0x104d4, b'X', ""), (0x104d8, b'V', ""), (0x104fc, b'X', ""), (0x10500, b'V', ""),
+    (0x10528, b'X', ""), (0x10530, b'V', ""), (0x10564, b'X', ""), (0x1056f, b'V', ""),
+    (0x10570, b'M', "\u{10597}"), (0x10571, b'M', "\u{10598}"), (0x10572, b'M', "\u{10599}"),
+    (0x10573, b'M', "\u{1059a}"), (0x10574, b'M', "\u{1059b}"), (0x10575, b'M', "\u{1059c}"),
+    (0x10576, b'M', "\u{1059d}"), (0x10577, b'M', "\u{1059e}"), (0x10578, b'M', "\u{1059f}"),
+    (0x10579, b'M', "\u{105a0}"), (0x1057a, b'M', "\u{105a1}"), (0x1057b, b'X', ""),
+    (0x1057c, b'M', "\u{105a3}"), (0x1057d, b'M', "\u{105a4}"), (0x1057e, b'M', "\u{105a5}"),
+    (0x1057f, b'M', "\u{105a6}"), (0x10580, b'M', "\u{105a7}"), (0x10581, b'M', "\u{105a8}"),
+    (0x10582, b'M', "\u{105a9}"), (0x10583, b'M', "\u{105aa}"), (0x10584, b'M', "\u{105ab}"),
+    (0x10585, b'M', "\u{105ac}"), (0x10586, b'M', "\u{105ad}"), (0x10587, b'M', "\u{105ae}"),
+    (0x10588, b'M', "\u{105af}"), (0x10589, b'M', "\u{105b0}"), (0x1058a, b'M', "\u{105b1}"),
+    (0x1058b, b'X', ""), (0x1058c, b'M', "\u{105b3}"), (0x1058d, b'M', "\u{105b4}"),
+    (0x1058e, b'M', "\u{105b5}"), (0x1058f, b'M', "\u{105b6}"), (0x10590, b'M', "\u{105b7}"),
+    (0x10591, b'M', "\u{105b8}"), (0x10592, b'M', "\u{105b9}"), (0x10593, b'X', ""),
+    (0x10594, b'M', "\u{105bb}"), (0x10595, b'M', "\u{105bc}"), (0x10596, b'X', ""),
+    (0x10597, b'V', ""), (0x105a2, b'X', ""), (0x105a3, b'V', ""), (0x105b2, b'X', ""),
+    (0x105b3, b'V', ""), (0x105ba, b'X', ""), (0x105bb, b'V', ""), (0x105bd, b'X', ""),
+    (0x10600, b'V', ""), (0x10737, b'X', ""), (0x10740, b'V', ""), (0x10756, b'X', ""),
+    (0x10760, b'V', ""), (0x10768, b'X', ""), (0x10780, b'V', ""), (0x10781, b'M', "\u{2d0}"),
+    (0x10782, b'M', "\u{2d1}"), (0x10783, b'M', "\u{e6}"), (0x10784, b'M', "\u{299}"),
+    (0x10785, b'M', "\u{253}"), (0x10786, b'X', ""), (0x10787, b'M', "\u{2a3}"),
+    (0x10788, b'M', "\u{ab66}"), (0x10789, b'M', "\u{2a5}"), (0x1078a, b'M', "\u{2a4}"),
+    (0x1078b, b'M', "\u{256}"), (0x1078c, b'M', "\u{257}"), (0x1078d, b'M', "\u{1d91}"),
+    (0x1078e, b'M', "\u{258}"), (0x1078f, b'M', "\u{25e}"), (0x10790, b'M', "\u{2a9}"),
+    (0x10791, b'M', "\u{264}"), (0x10792, b'M', "\u{262}"), (0x10793, b'M', "\u{260}"),
+    (0x10794, b'M', "\u{29b}"), (0x10795, b'M', "\u{127}"), (0x10796, b'M', "\u{29c}"),
+    (0x10797, b'M', "\u{267}"), (0x10798, b'M', "\u{284}"), (0x10799, b'M', "\u{2aa}"),
+    (0x1079a, b'M', "\u{2ab}"), (0x1079b, b'M', "\u{26c}"), (0x1079c, b'M', "\u{1df04}"),
+    (0x1079d, b'M', "\u{a78e}"), (0x1079e, b'M', "\u{26e}"), (0x1079f, b'M', "\u{1df05}"),
+    (0x107a0, b'M', "\u{28e}"), (0x107a1, b'M', "\u{1df06}"), (0x107a2, b'M', "\u{f8}"),
+    (0x107a3, b'M', "\u{276}"), (0x107a4, b'M', "\u{277}"), (0x107a5, b'M', "q"),
+    (0x107a6, b'M', "\u{27a}"), (0x107a7, b'M', "\u{1df08}"), (0x107a8, b'M', "\u{27d}"),
+    (0x107a9, b'M', "\u{27e}"), (0x107aa, b'M', "\u{280}"), (0x107ab, b'M', "\u{2a8}"),
+    (0x107ac, b'M', "\u{2a6}"), (0x107ad, b'M', "\u{ab67}"), (0x107ae, b'M', "\u{2a7}"),
+    (0x107af, b'M', "\u{288}"), (0x107b0, b'M', "\u{2c71}"), (0x107b1, b'X', ""),
+    (0x107b2, b'M', "\u{28f}"), (0x107b3, b'M', "\u{2a1}"), (0x107b4, b'M', "\u{2a2}"),
+    (0x107b5, b'M', "\u{298}"), (0x107b6, b'M', "\u{1c0}"), (0x107b7, b'M', "\u{1c1}"),
+    (0x107b8, b'M', "\u{1c2}"), (0x107b9, b'M', "\u{1df0a}"), (0x107ba, b'M', "\u{1df1e}"),
+    (0x107bb, b'X', ""), (0x10800, b'V', ""), (0x10806, b'X', ""), (0x10808, b'V', ""),
+    (0x10809, b'X', ""), (0x1080a, b'V', ""), (0x10836, b'X', ""), (0x10837, b'V', ""),
+    (0x10839, b'X', ""), (0x1083c, b'V', ""), (0x1083d, b'X', ""), (0x1083f, b'V', ""),
+    (0x10856, b'X', ""), (0x10857, b'V', ""), (0x1089f, b'X', ""), (0x108a7, b'V', ""),
+    (0x108b0, b'X', ""), (0x108e0, b'V', ""), (0x108f3, b'X', ""), (0x108f4, b'V', ""),
+    (0x108f6, b'X', ""), (0x108fb, b'V', ""), (0x1091c, b'X', ""), (0x1091f, b'V', ""),
+    (0x1093a, b'X', ""), (0x1093f, b'V', ""), (0x10940, b'X', ""), (0x10980, b'V', ""),
+    (0x109b8, b'X', ""), (0x109bc, b'V', ""), (0x109d0, b'X', ""), (0x109d2, b'V', ""),
+    (0x10a04, b'X', ""), (0x10a05, b'V', ""), (0x10a07, b'X', ""), (0x10a0c, b'V', ""),
+    (0x10a14, b'X', ""), (0x10a15, b'V', ""), (0x10a18, b'X', ""), (0x10a19, b'V', ""),
+    (0x10a36, b'X', ""), (0x10a38, b'V', ""), (0x10a3b, b'X', ""), (0x10a3f, b'V', ""),
+    (0x10a49, b'X', ""), (0x10a50, b'V', ""), (0x10a59, b'X', ""), (0x10a60, b'V', ""),
+    (0x10aa0, b'X', ""), (0x10ac0, b'V', ""), (0x10ae7, b'X', ""), (0x10aeb, b'V', ""),
+    (0x10af7, b'X', ""), (0x10b00, b'V', ""), (0x10b36, b'X', ""), (0x10b39, b'V', ""),
+    (0x10b56, b'X', ""), (0x10b58, b'V', ""), (0x10b73, b'X', ""), (0x10b78, b'V', ""),
+    (0x10b92, b'X', ""), (0x10b99, b'V', ""), (0x10b9d, b'X', ""), (0x10ba9, b'V', ""),
+    (0x10bb0, b'X', ""), (0x10c00, b'V', ""), (0x10c49, b'X', ""), (0x10c80, b'M', "\u{10cc0}"),
+    (0x10c81, b'M', "\u{10cc1}"), (0x10c82, b'M', "\u{10cc2}"), (0x10c83, b'M', "\u{10cc3}"),
+    (0x10c84, b'M', "\u{10cc4}"), (0x10c85, b'M', "\u{10cc5}"), (0x10c86, b'M', "\u{10cc6}"),
+    (0x10c87, b'M', "\u{10cc7}"), (0x10c88, b'M', "\u{10cc8}"), (0x10c89, b'M', "\u{10cc9}"),
+    (0x10c8a, b'M', "\u{10cca}"), (0x10c8b, b'M', "\u{10ccb}"), (0x10c8c, b'M', "\u{10ccc}"),
+    (0x10c8d, b'M', "\u{10ccd}"), (0x10c8e, b'M', "\u{10cce}"), (0x10c8f, b'M', "\u{10ccf}"),
+    (0x10c90, b'M', "\u{10cd0}"), (0x10c91, b'M', "\u{10cd1}"), (0x10c92, b'M', "\u{10cd2}"),
+    (0x10c93, b'M', "\u{10cd3}"), (0x10c94, b'M', "\u{10cd4}"), (0x10c95, b'M', "\u{10cd5}"),
+    (0x10c96, b'M', "\u{10cd6}"), (0x10c97, b'M', "\u{10cd7}"), (0x10c98, b'M', "\u{10cd8}"),
+    (0x10c99, b'M', "\u{10cd9}"), (0x10c9a, b'M', "\u{10cda}"), (0x10c9b, b'M', "\u{10cdb}"),
+    (0x10c9c, b'M', "\u{10cdc}"), (0x10c9d, b'M', "\u{10cdd}"), (0x10c9e, b'M', "\u{10cde}"),
+    (0x10c9f, b'M', "\u{10cdf}"), (0x10ca0, b'M', "\u{10ce0}"), (0x10ca1, b'M', "\u{10ce1}"),
+    (0x10ca2, b'M', "\u{10ce2}"), (0x10ca3, b'M', "\u{10ce3}"), (0x10ca4, b'M', "\u{10ce4}"),
+    (0x10ca5, b'M', "\u{10ce5}"), (0x10ca6, b'M', "\u{10ce6}"), (0x10ca7, b'M', "\u{10ce7}"),
+    (0x10ca8, b'M', "\u{10ce8}"), (0x10ca9, b'M', "\u{10ce9}"), (0x10caa, b'M', "\u{10cea}"),
+    (0x10cab, b'M', "\u{10ceb}"), (0x10cac, b'M', "\u{10cec}"), (0x10cad, b'M', "\u{10ced}"),
+    (0x10cae, b'M', "\u{10cee}"), (0x10caf, b'M', "\u{10cef}"), (0x10cb0, b'M', "\u{10cf0}"),
+    (0x10cb1, b'M', "\u{10cf1}"), (0x10cb2, b'M', "\u{10cf2}"), (0x10cb3, b'X', ""),
+    (0x10cc0, b'V', ""), (0x10cf3, b'X', ""), (0x10cfa, b'V', ""), (0x10d28, b'X', ""),
+    (0x10d30, b'V', ""), (0x10d3a, b'X', ""), (0x10e60, b'V', ""), (0x10e7f, b'X', ""),
+    (0x10e80, b'V', ""), (0x10eaa, b'X', ""), (0x10eab, b'V', ""), (0x10eae, b'X', ""),
+    (0x10eb0, b'V', ""), (0x10eb2, b'X', ""), (0x10efd, b'V', ""), (0x10f28, b'X', ""),
+    (0x10f30, b'V', ""), (0x10f5a, b'X', ""), (0x10f70, b'V', ""), (0x10f8a, b'X', ""),
+    (0x10fb0, b'V', ""), (0x10fcc, b'X', ""), (0x10fe0, b'V', ""), (0x10ff7, b'X', ""),
+    (0x11000, b'V', ""), (0x1104e, b'X', ""), (0x11052, b'V', ""), (0x11076, b'X', ""),
+    (0x1107f, b'V', ""), (0x110bd, b'X', ""), (0x110be, b'V', ""), (0x110c3, b'X', ""),
+    (0x110d0, b'V', ""), (0x110e9, b'X', ""), (0x110f0, b'V', ""), (0x110fa, b'X', ""),
+    (0x11100, b'V', ""), (0x11135, b'X', ""), (0x11136, b'V', ""), (0x11148, b'X', ""),
+    (0x11150, b'V', ""), (0x11177, b'X', ""), (0x11180, b'V', ""), (0x111e0, b'X', ""),
+    (0x111e1, b'V', ""), (0x111f5, b'X', ""), (0x11200, b'V', ""), (0x11212, b'X', ""),
+    (0x11213, b'V', ""), (0x11242, b'X', ""), (0x11280, b'V', ""), (0x11287, b'X', ""),
+    (0x11288, b'V', ""), (0x11289, b'X', ""), (0x1128a, b'V', ""), (0x1128e, b'X', ""),
+    (0x1128f, b'V', ""), (0x1129e, b'X', ""), (0x1129f, b'V', ""), (0x112aa, b'X', ""),
+    (0x112b0, b'V', ""), (0x112eb, b'X', ""), (0x112f0, b'V', ""), (0x112fa, b'X', ""),
+    (0x11300, b'V', ""), (0x11304, b'X', ""), (0x11305, b'V', ""), (0x1130d, b'X', ""),
+    (0x1130f, b'V', ""), (0x11311, b'X', ""), (0x11313, b'V', ""), (0x11329, b'X', ""),
+    (0x1132a, b'V', ""), (0x11331, b'X', ""), (0x11332, b'V', ""), (0x11334, b'X', ""),
+    (0x11335, b'V', ""), (0x1133a, b'X', ""), (0x1133b, b'V', ""), (0x11345, b'X', ""),
+    (0x11347, b'V', ""), (0x11349, b'X', ""), (0x1134b, b'V', ""), (0x1134e, b'X', ""),
+    (0x11350, b'V', ""), (0x11351, b'X', ""), (0x11357, b'V', ""), (0x11358, b'X', ""),
+    (0x1135d, b'V', ""), (0x11364, b'X', ""), (0x11366, b'V', ""), (0x1136d, b'X', ""),
+    (0x11370, b'V', ""), (0x11375, b'X', ""), (0x11400, b'V', ""), (0x1145c, b'X', ""),
+    (0x1145d, b'V', ""), (0x11462, b'X', ""), (0x11480, b'V', ""), (0x114c8, b'X', ""),
+    (0x114d0, b'V', ""), (0x114da, b'X', ""), (0x11580, b'V', ""), (0x115b6, b'X', ""),
+    (0x115b8, b'V', ""), (0x115de, b'X', ""), (0x11600, b'V', ""), (0x11645, b'X', ""),
+    (0x11650, b'V', ""), (0x1165a, b'X', ""), (0x11660, b'V', ""), (0x1166d, b'X', ""),
+    (0x11680, b'V', ""), (0x116ba, b'X', ""), (0x116c0, b'V', ""), (0x116ca, b'X', ""),
+    (0x11700, b'V', ""), (0x1171b, b'X', ""), (0x1171d, b'V', ""), (0x1172c, b'X', ""),
+    (0x11730, b'V', ""), (0x11747, b'X', ""), (0x11800, b'V', ""), (0x1183c, b'X', ""),
+    (0x118a0, b'M', "\u{118c0}"), (0x118a1, b'M', "\u{118c1}"), (0x118a2, b'M', "\u{118c2}"),
+    (0x118a3, b'M', "\u{118c3}"), (0x118a4, b'M', "\u{118c4}"), (0x118a5, b'M', "\u{118c5}"),
+    (0x118a6, b'M', "\u{118c6}"), (0x118a7, b'M', "\u{118c7}"), (0x118a8, b'M', "\u{118c8}"),
+    (0x118a9, b'M', "\u{118c9}"), (0x118aa, b'M', "\u{118ca}"), (0x118ab, b'M', "\u{118cb}"),
+    (0x118ac, b'M', "\u{118cc}"), (0x118ad, b'M', "\u{118cd}"), (0x118ae, b'M', "\u{118ce}"),
+    (0x118af, b'M', "\u{118cf}"), (0x118b0, b'M', "\u{118d0}"), (0x118b1, b'M', "\u{118d1}"),
+    (0x118b2, b'M', "\u{118d2}"), (0x118b3, b'M', "\u{118d3}"), (0x118b4, b'M', "\u{118d4}"),
+    (0x118b5, b'M', "\u{118d5}"), (0x118b6, b'M', "\u{118d6}"), (0x118b7, b'M', "\u{118d7}"),
+    (0x118b8, b'M', "\u{118d8}"), (0x118b9, b'M', "\u{118d9}"), (0x118ba, b'M', "\u{118da}"),
+    (0x118bb, b'M', "\u{118db}"), (0x118bc, b'M', "\u{118dc}"), (0x118bd, b'M', "\u{118dd}"),
+    (0x118be, b'M', "\u{118de}"), (0x118bf, b'M', "\u{118df}"), (0x118c0, b'V', ""),
+    (0x118f3, b'X', ""), (0x118ff, b'V', ""), (0x11907, b'X', ""), (0x11909, b'V', ""),
+    (0x1190a, b'X', ""), (0x1190c, b'V', ""), (0x11914, b'X', ""), (0x11915, b'V', ""),
+    (0x11917, b'X', ""), (0x11918, b'V', ""), (0x11936, b'X', ""), (0x11937, b'V', ""),
+    (0x11939, b'X', ""), (0x1193b, b'V', ""), (0x11947, b'X', ""), (0x11950, b'V', ""),
+    (0x1195a, b'X', ""), (0x119a0, b'V', ""), (0x119a8, b'X', ""), (0x119aa, b'V', ""),
+    (0x119d8, b'X', ""), (0x119da, b'V', ""), (0x119e5, b'X', ""), (0x11a00, b'V', ""),
+    (0x11a48, b'X', ""), (0x11a50, b'V', ""), (0x11aa3, b'X', ""), (0x11ab0, b'V', ""),
+    (0x11af9, b'X', ""), (0x11b00, b'V', ""), (0x11b0a, b'X', ""), (0x11c00, b'V', ""),
+    (0x11c09, b'X', ""), (0x11c0a, b'V', ""), (0x11c37, b'X', ""), (0x11c38, b'V', ""),
+    (0x11c46, b'X', ""), (0x11c50, b'V', ""), (0x11c6d, b'X', ""), (0x11c70, b'V', ""),
+    (0x11c90, b'X', ""), (0x11c92, b'V', ""), (0x11ca8, b'X', ""), (0x11ca9, b'V', ""),
+    (0x11cb7, b'X', ""), (0x11d00, b'V', ""), (0x11d07, b'X', ""), (0x11d08, b'V', ""),
+    (0x11d0a, b'X', ""), (0x11d0b, b'V', ""), (0x11d37, b'X', ""), (0x11d3a, b'V', ""),
+    (0x11d3b, b'X', ""), (0x11d3c, b'V', ""), (0x11d3e, b'X', ""), (0x11d3f, b'V', ""),
+    (0x11d48, b'X', ""), (0x11d50, b'V', ""), (0x11d5a, b'X', ""), (0x11d60, b'V', ""),
+    (0x11d66, b'X', ""), (0x11d67, b'V', ""), (0x11d69, b'X', ""), (0x11d6a, b'V', ""),
+    (0x11d8f, b'X', ""), (0x11d90, b'V', ""), (0x11d92, b'X', ""), (0x11d93, b'V', ""),
+    (0x11d99, b'X', ""), (0x11da0, b'V', ""), (0x11daa, b'X', ""), (0x11ee0, b'V', ""),
+    (0x11ef9, b'X', ""), (0x11f00, b'V', ""), (0x11f11, b'X', ""), (0x11f12, b'V', ""),
+    (0x11f3b, b'X', ""), (0x11f3e, b'V', ""), (0x11f5a, b'X', ""), (0x11fb0, b'V', ""),
+    (0x11fb1, b'X', ""), (0x11fc0, b'V', ""), (0x11ff2, b'X', ""), (0x11fff, b'V', ""),
+    (0x1239a, b'X', ""), (0x12400, b'V', ""), (0x1246f, b'X', ""), (0x12470, b'V', ""),
+    (0x12475, b'X', ""), (0x12480, b'V', ""), (0x12544, b'X', ""), (0x12f90, b'V', ""),
+    (0x12ff3, b'X', ""), (0x13000, b'V', ""), (0x13430, b'X', ""), (0x13440, b'V', ""),
+    (0x13456, b'X', ""), (0x14400, b'V', ""), (0x14647, b'X', ""), (0x16800, b'V', ""),
+    (0x16a39, b'X', ""), (0x16a40, b'V', ""), (0x16a5f, b'X', ""), (0x16a60, b'V', ""),
+    (0x16a6a, b'X', ""), (0x16a6e, b'V', ""), (0x16abf, b'X', ""), (0x16ac0, b'V', ""),
+    (0x16aca, b'X', ""), (0x16ad0, b'V', ""), (0x16aee, b'X', ""), (0x16af0, b'V', ""),
+    (0x16af6, b'X', ""), (0x16b00, b'V', ""), (0x16b46, b'X', ""), (0x16b50, b'V', ""),
+    (0x16b5a, b'X', ""), (0x16b5b, b'V', ""), (0x16b62, b'X', ""), (0x16b63, b'V', ""),
+    (0x16b78, b'X', ""), (0x16b7d, b'V', ""), (0x16b90, b'X', ""), (0x16e40, b'M', "\u{16e60}"),
+    (0x16e41, b'M', "\u{16e61}"), (0x16e42, b'M', "\u{16e62}"), (0x16e43, b'M', "\u{16e63}"),
+    (0x16e44, b'M', "\u{16e64}"), (0x16e45, b'M', "\u{16e65}"), (0x16e46, b'M', "\u{16e66}"),
+    (0x16e47, b'M', "\u{16e67}"), (0x16e48, b'M', "\u{16e68}"), (0x16e49, b'M', "\u{16e69}"),
+    (0x16e4a, b'M', "\u{16e6a}"), (0x16e4b, b'M', "\u{16e6b}"), (0x16e4c, b'M', "\u{16e6c}"),
+    (0x16e4d, b'M', "\u{16e6d}"), (0x16e4e, b'M', "\u{16e6e}"), (0x16e4f, b'M', "\u{16e6f}"),
+    (0x16e50, b'M', "\u{16e70}"), (0x16e51, b'M', "\u{16e71}"), (0x16e52, b'M', "\u{16e72}"),
+    (0x16e53, b'M', "\u{16e73}"), (0x16e54, b'M', "\u{16e74}"), (0x16e55, b'M', "\u{16e75}"),
+    (0x16e56, b'M', "\u{16e76}"), (0x16e57, b'M', "\u{16e77}"), (0x16e58, b'M', "\u{16e78}"),
+    (0x16e59, b'M', "\u{16e79}"), (0x16e5a, b'M', "\u{16e7a}"), (0x16e5b, b'M', "\u{16e7b}"),
+    (0x16e5c, b'M', "\u{16e7c}"), (0x16e5d, b'M', "\u{16e7d}"), (0x16e5e, b'M', "\u{16e7e}"),
+    (0x16e5f, b'M', "\u{16e7f}"), (0x16e60, b'V', ""), (0x16e9b, b'X', ""), (0x16f00, b'V', ""),
+    (0x16f4b, b'X', ""), (0x16f4f, b'V', ""), (0x16f88, b'X', ""), (0x16f8f, b'V', ""),
+    (0x16fa0, b'X', ""), (0x16fe0, b'V', ""), (0x16fe5, b'X', ""), (0x16ff0, b'V', ""),
+    (0x16ff2, b'X', ""), (0x17000, b'V', ""), (0x187f8, b'X', ""), (0x18800, b'V', ""),
+    (0x18cd6, b'X', ""), (0x18d00, b'V', ""), (0x18d09, b'X', ""), (0x1aff0, b'V', ""),
+    (0x1aff4, b'X', ""), (0x1aff5, b'V', ""), (0x1affc, b'X', ""), (0x1affd, b'V', ""),
+    (0x1afff, b'X', ""), (0x1b000, b'V', ""), (0x1b123, b'X', ""), (0x1b132, b'V', ""),
+    (0x1b133, b'X', ""), (0x1b150, b'V', ""), (0x1b153, b'X', ""), (0x1b155, b'V', ""),
+    (0x1b156, b'X', ""), (0x1b164, b'V', ""), (0x1b168, b'X', ""), (0x1b170, b'V', ""),
+    (0x1b2fc, b'X', ""), (0x1bc00, b'V', ""), (0x1bc6b, b'X', ""), (0x1bc70, b'V', ""),
+    (0x1bc7d, b'X', ""), (0x1bc80, b'V', ""), (0x1bc89, b'X', ""), (0x1bc90, b'V', ""),
+    (0x1bc9a, b'X', ""), (0x1bc9c, b'V', ""), (0x1bca0, b'I', ""), (0x1bca4, b'X', ""),
+    (0x1cf00, b'V', ""), (0x1cf2e, b'X', ""), (0x1cf30, b'V', ""), (0x1cf47, b'X', ""),
+    (0x1cf50, b'V', ""), (0x1cfc4, b'X', ""), (0x1d000, b'V', ""), (0x1d0f6, b'X', ""),
+    (0x1d100, b'V', ""), (0x1d127, b'X', ""), (0x1d129, b'V', ""),
+    (0x1d15e, b'M', "\u{1d157}\u{1d165}"), (0x1d15f, b'M', "\u{1d158}\u{1d165}"),
+    (0x1d160, b'M', "\u{1d158}\u{1d165}\u{1d16e}"), (0x1d161, b'M', "\u{1d158}\u{1d165}\u{1d16f}"),
+    (0x1d162, b'M', "\u{1d158}\u{1d165}\u{1d170}"), (0x1d163, b'M', "\u{1d158}\u{1d165}\u{1d171}"),
+    (0x1d164, b'M', "\u{1d158}\u{1d165}\u{1d172}"), (0x1d165, b'V', ""), (0x1d173, b'X', ""),
+    (0x1d17b, b'V', ""), (0x1d1bb, b'M', "\u{1d1b9}\u{1d165}"),
+    (0x1d1bc, b'M', "\u{1d1ba}\u{1d165}"), (0x1d1bd, b'M', "\u{1d1b9}\u{1d165}\u{1d16e}"),
+    (0x1d1be, b'M', "\u{1d1ba}\u{1d165}\u{1d16e}"), (0x1d1bf, b'M', "\u{1d1b9}\u{1d165}\u{1d16f}"),
+    (0x1d1c0, b'M', "\u{1d1ba}\u{1d165}\u{1d16f}"), (0x1d1c1, b'V', ""), (0x1d1eb, b'X', ""),
+    (0x1d200, b'V', ""), (0x1d246, b'X', ""), (0x1d2c0, b'V', ""), (0x1d2d4, b'X', ""),
+    (0x1d2e0, b'V', ""), (0x1d2f4, b'X', ""), (0x1d300, b'V', ""), (0x1d357, b'X', ""),
+    (0x1d360, b'V', ""), (0x1d379, b'X', ""), (0x1d400, b'M', "a"), (0x1d401, b'M', "b"),
+    (0x1d402, b'M', "c"), (0x1d403, b'M', "d"), (0x1d404, b'M', "e"), (0x1d405, b'M', "f"),
+    (0x1d406, b'M', "g"), (0x1d407, b'M', "h"), (0x1d408, b'M', "i"), (0x1d409, b'M', "j"),
+    (0x1d40a, b'M', "k"), (0x1d40b, b'M', "l"), (0x1d40c, b'M', "m"), (0x1d40d, b'M', "n"),
+    (0x1d40e, b'M', "o"), (0x1d40f, b'M', "p"), (0x1d410, b'M', "q"), (0x1d411, b'M', "r"),
+    (0x1d412, b'M', "s"), (0x1d413, b'M', "t"), (0x1d414, b'M', "u"), (0x1d415, b'M', "v"),
+    (0x1d416, b'M', "w"), (0x1d417, b'M', "x"), (0x1d418, b'M', "y"), (0x1d419, b'M', "z"),
+    (0x1d41a, b'M', "a"), (0x1d41b, b'M', "b"), (0x1d41c, b'M', "c"), (0x1d41d, b'M', "d"),
+    (0x1d41e, b'M', "e"), (0x1d41f, b'M', "f"), (0x1d420, b'M', "g"), (0x1d421, b'M', "h"),
+    (0x1d422, b'M', "i"), (0x1d423, b'M', "j"), (0x1d424, b'M', "k"), (0x1d425, b'M', "l"),
+    (0x1d426, b'M', "m"), (0x1d427, b'M', "n"), (0x1d428, b'M', "o"), (0x1d429, b'M', "p"),
+    (0x1d42a, b'M', "q"), (0x1d42b, b'M', "r"), (0x1d42c, b'M', "s"), (0x1d42d, b'M', "t"),
+    (0x1d42e, b'M', "u"), (0x1d42f, b'M', "v"), (0x1d430, b'M', "w"), (0x1d431, b'M', "x"),
+    (0x1d432, b'M', "y"), (0x1d433, b'M', "z"), (0x1d434, b'M', "a"), (0x1d435, b'M', "b"),
+    (0x1d436, b'M', "c"), (0x1d437, b'M', "d"), (0x1d438, b'M', "e"), (0x1d439, b'M', "f"),
+    (0x1d43a, b'M', "g"), (0x1d43b, b'M', "h"), (0x1d43c, b'M', "i"), (0x1d43d, b'M', "j"),
+    (0x1d43e, b'M', "k"), (0x1d43f, b'M', "l"), (0x1d440, b'M', "m"), (0x1d441, b'M', "n"),
+    (0x1d442, b'M', "o"), (0x1d443, b'M', "p"), (0x1d444, b'M', "q"), (0x1d445, b'M', "r"),
+    (0x1d446, b'M', "s"), (0x1d447, b'M', "t"), (0x1d448, b'M', "u"), (0x1d449, b'M', "v"),
+    (0x1d44a, b'M', "w"), (0x1d44b, b'M', "x"), (0x1d44c, b'M', "y"), (0x1d44d, b'M', "z"),
+    (0x1d44e, b'M', "a"), (0x1d44f, b'M', "b"), (0x1d450, b'M', "c"), (0x1d451, b'M', "d"),
+    (0x1d452, b'M', "e"), (0x1d453, b'M', "f"), (0x1d454, b'M', "g"), (0x1d455, b'X', ""),
+    (0x1d456, b'M', "i"), (0x1d457, b'M', "j"), (0x1d458, b'M', "k"), (0x1d459, b'M', "l"),
+    (0x1d45a, b'M', "m"), (0x1d45b, b'M', "n"), (0x1d45c, b'M', "o"), (0x1d45d, b'M', "p"),
+    (0x1d45e, b'M', "q"), (0x1d45f, b'M', "r"), (0x1d460, b'M', "s"), (0x1d461, b'M', "t"),
+    (0x1d462, b'M', "u"), (0x1d463, b'M', "v"), (0x1d464, b'M', "w"), (0x1d465, b'M', "x"),
+    (0x1d466, b'M', "y"), (0x1d467, b'M', "z"), (0x1d468, b'M', "a"), (0x1d469, b'M', "b"),
+    (0x1d46a, b'M', "c"), (0x1d46b, b'M', "d"), (0x1d46c, b'M', "e"), (0x1d46d, b'M', "f"),
+    (0x1d46e, b'M', "g"), (0x1d46f, b'M', "h"), (0x1d470, b'M', "i"), (0x1d471, b'M', "j"),
+    (0x1d472, b'M', "k"), (0x1d473, b'M', "l"), (0x1d474, b'M', "m"), (0x1d475, b'M', "n"),
+    (0x1d476, b'M', "o"), (0x1d477, b'M', "p"), (0x1d478, b'M', "q"), (0x1d479, b'M', "r"),
+    (0x1d47a, b'M', "s"), (0x1d47b, b'M', "t"), (0x1d47c, b'M', "u"), (0x1d47d, b'M', "v"),
+    (0x1d47e, b'M', "w"), (0x1d47f, b'M', "x"), (0x1d480, b'M', "y"), (0x1d481, b'M', "z"),
+    (0x1d482, b'M', "a"), (0x1d483, b'M', "b"), (0x1d484, b'M', "c"), (0x1d485, b'M', "d"),
+    (0x1d486, b'M', "e"), (0x1d487, b'M', "f"), (0x1d488, b'M', "g"), (0x1d489, b'M', "h"),
+    (0x1d48a, b'M', "i"), (0x1d48b, b'M', "j"), (0x1d48c, b'M', "k"), (0x1d48d, b'M', "l"),
+    (0x1d48e, b'M', "m"), (0x1d48f, b'M', "n"), (0x1d490, b'M', "o"), (0x1d491, b'M', "p"),
+    (0x1d492, b'M', "q"), (0x1d493, b'M', "r"), (0x1d494, b'M', "s"), (0x1d495, b'M', "t"),
+    (0x1d496, b'M', "u"), (0x1d497, b'M', "v"), (0x1d498, b'M', "w"), (0x1d499, b'M', "x"),
+    (0x1d49a, b'M', "y"), (0x1d49b, b'M', "z"), (0x1d49c, b'M', "a"), (0x1d49d, b'X', ""),
+    (0x1d49e, b'M', "c"), (0x1d49f, b'M', "d"), (0x1d4a0, b'X', ""), (0x1d4a2, b'M', "g"),
+    (0x1d4a3, b'X', ""), (0x1d4a5, b'M', "j"), (0x1d4a6, b'M', "k"), (0x1d4a7, b'X', ""),
+    (0x1d4a9, b'M', "n"), (0x1d4aa, b'M', "o"), (0x1d4ab, b'M', "p"), (0x1d4ac, b'M', "q"),
+    (0x1d4ad, b'X', ""), (0x1d4ae, b'M', "s"), (0x1d4af, b'M', "t"), (0x1d4b0, b'M', "u"),
+    (0x1d4b1, b'M', "v"), (0x1d4b2, b'M', "w"), (0x1d4b3, b'M', "x"), (0x1d4b4, b'M', "y"),
+    (0x1d4b5, b'M', "z"), (0x1d4b6, b'M', "a"), (0x1d4b7, b'M', "b"), (0x1d4b8, b'M', "c"),
+    (0x1d4b9, b'M', "d"), (0x1d4ba, b'X', ""), (0x1d4bb, b'M', "f"), (0x1d4bc, b'X', ""),
+    (0x1d4bd, b'M', "h"), (0x1d4be, b'M', "i"), (0x1d4bf, b'M', "j"), (0x1d4c0, b'M', "k"),
+    (0x1d4c1, b'M', "l"), (0x1d4c2, b'M', "m"), (0x1d4c3, b'M', "n"), (0x1d4c4, b'X', ""),
+    (0x1d4c5, b'M', "p"), (0x1d4c6, b'M', "q"), (0x1d4c7, b'M', "r"), (0x1d4c8, b'M', "s"),
+    (0x1d4c9, b'M', "t"), (0x1d4ca, b'M', "u"), (0x1d4cb, b'M', "v"), (0x1d4cc, b'M', "w"),
+    (0x1d4cd, b'M', "x"), (0x1d4ce, b'M', "y"), (0x1d4cf, b'M', "z"), (0x1d4d0, b'M', "a"),
+    (0x1d4d1, b'M', "b"), (0x1d4d2, b'M', "c"), (0x1d4d3, b'M', "d"), (0x1d4d4, b'M', "e"),
+    (0x1d4d5, b'M', "f"), (0x1d4d6, b'M', "g"), (0x1d4d7, b'M', "h"), (0x1d4d8, b'M', "i"),
+    (0x1d4d9, b'M', "j"), (0x1d4da, b'M', "k"), (0x1d4db, b'M', "l"), (0x1d4dc, b'M', "m"),
+    (0x1d4dd, b'M', "n"), (0x1d4de, b'M', "o"), (0x1d4df, b'M', "p"), (0x1d4e0, b'M', "q"),
+    (0x1d4e1, b'M', "r"), (0x1d4e2, b'M', "s"), (0x1d4e3, b'M', "t"), (0x1d4e4, b'M', "u"),
+    (0x1d4e5, b'M', "v"), (0x1d4e6, b'M', "w"), (0x1d4e7, b'M', "x"), (0x1d4e8, b'M', "y"),
+    (0x1d4e9, b'M', "z"), (0x1d4ea, b'M', "a"), (0x1d4eb, b'M', "b"), (0x1d4ec, b'M', "c"),
+    (0x1d4ed, b'M', "d"), (0x1d4ee, b'M', "e"), (0x1d4ef, b'M', "f"), (0x1d4f0, b'M', "g"),
+    (0x1d4f1, b'M', "h"), (0x1d4f2, b'M', "i"), (0x1d4f3, b'M', "j"), (0x1d4f4, b'M', "k"),
+    (0x1d4f5, b'M', "l"), (0x1d4f6, b'M', "m"), (0x1d4f7, b'M', "n"), (0x1d4f8, b'M', "o"),
+    (0x1d4f9, b'M', "p"), (0x1d4fa, b'M', "q"), (0x1d4fb, b'M', "r"), (0x1d4fc, b'M', "s"),
+    (0x1d4fd, b'M', "t"), (0x1d4fe, b'M', "u"), (0x1d4ff, b'M', "v"), (0x1d500, b'M', "w"),
+    (0x1d501, b'M', "x"), (0x1d502, b'M', "y"), (0x1d503, b'M', "z"), (0x1d504, b'M', "a"),
+    (0x1d505, b'M', "b"), (0x1d506, b'X', ""), (0x1d507, b'M', "d"), (0x1d508, b'M', "e"),
+    (0x1d509, b'M', "f"), (0x1d50a, b'M', "g"), (0x1d50b, b'X', ""), (0x1d50d, b'M', "j"),
+    (0x1d50e, b'M', "k"), (0x1d50f, b'M', "l"), (0x1d510, b'M', "m"), (0x1d511, b'M', "n"),
+    (0x1d512, b'M', "o"), (0x1d513, b'M', "p"), (0x1d514, b'M', "q"), (0x1d515, b'X', ""),
+    (0x1d516, b'M', "s"), (0x1d517, b'M', "t"), (0x1d518, b'M', "u"), (0x1d519, b'M', "v"),
+    (0x1d51a, b'M', "w"), (0x1d51b, b'M', "x"), (0x1d51c, b'M', "y"), (0x1d51d, b'X', ""),
+    (0x1d51e, b'M', "a"), (0x1d51f, b'M', "b"), (0x1d520, b'M', "c"), (0x1d521, b'M', "d"),
+    (0x1d522, b'M', "e"), (0x1d523, b'M', "f"), (0x1d524, b'M', "g"), (0x1d525, b'M', "h"),
+    (0x1d526, b'M', "i"), (0x1d527, b'M', "j"), (0x1d528, b'M', "k"), (0x1d529, b'M', "l"),
+    (0x1d52a, b'M', "m"), (0x1d52b, b'M', "n"), (0x1d52c, b'M', "o"), (0x1d52d, b'M', "p"),
+    (0x1d52e, b'M', "q"), (0x1d52f, b'M', "r"), (0x1d530, b'M', "s"), (0x1d531, b'M', "t"),
+    (0x1d532, b'M', "u"), (0x1d533, b'M', "v"), (0x1d534, b'M', "w"), (0x1d535, b'M', "x"),
+    (0x1d536, b'M', "y"), (0x1d537, b'M', "z"), (0x1d538, b'M', "a"), (0x1d539, b'M', "b"),
+    (0x1d53a, b'X', ""), (0x1d53b, b'M', "d"), (0x1d53c, b'M', "e"), (0x1d53d, b'M', "f"),
+    (0x1d53e, b'M', "g"), (0x1d53f, b'X', ""), (0x1d540, b'M', "i"), (0x1d541, b'M', "j"),
+    (0x1d542, b'M', "k"), (0x1d543, b'M', "l"), (0x1d544, b'M', "m"), (0x1d545, b'X', ""),
+    (0x1d546, b'M', "o"), (0x1d547, b'X', ""), (0x1d54a, b'M', "s"), (0x1d54b, b'M', "t"),
+    (0x1d54c, b'M', "u"), (0x1d54d, b'M', "v"), (0x1d54e, b'M', "w"), (0x1d54f, b'M', "x"),
+    (0x1d550, b'M', "y"), (0x1d551, b'X', ""), (0x1d552, b'M', "a"), (0x1d553, b'M', "b"),
+    (0x1d554, b'M', "c"), (0x1d555, b'M', "d"), (0x1d556, b'M', "e"), (0x1d557, b'M', "f"),
+    (0x1d558, b'M', "g"), (0x1d559, b'M', "h"), (0x1d55a, b'M', "i"), (0x1d55b, b'M', "j"),
+    (0x1d55c, b'M', "k"), (0x1d55d, b'M', "l"), (0x1d55e, b'M', "m"), (0x1d55f, b'M', "n"),
+    (0x1d560, b'M', "o"), (0x1d561, b'M', "p"), (0x1d562, b'M', "q"), (0x1d563, b'M', "r"),
+    (0x1d564, b'M', "s"), (0x1d565, b'M', "t"), (0x1d566, b'M', "u"), (0x1d567, b'M', "v"),
+    (0x1d568, b'M', "w"), (0x1d569, b'M', "x"), (0x1d56a, b'M', "y"), (0x1d56b, b'M', "z"),
+    (0x1d56c, b'M', "a"), (0x1d56d, b'M', "b"), (0x1d56e, b'M', "c"), (0x1d56f, b'M', "d"),
+    (0x1d570, b'M', "e"), (0x1d571, b'M', "f"), (0x1d572, b'M', "g"), (0x1d573, b'M', "h"),
+    (0x1d574, b'M', "i"), (0x1d575, b'M', "j"), (0x1d576, b'M', "k"), (0x1d577, b'M', "l"),
+    (0x1d578, b'M', "m"), (0x1d579, b'M', "n"), (0x1d57a, b'M', "o"), (0x1d57b, b'M', "p"),
+    (0x1d57c, b'M', "q"), (0x1d57d, b'M', "r"), (0x1d57e, b'M', "s"), (0x1d57f, b'M', "t"),
+    (0x1d580, b'M', "u"), (0x1d581, b'M', "v"), (0x1d582, b'M', "w"), (0x1d583, b'M', "x"),
+    (0x1d584, b'M', "y"), (0x1d585, b'M', "z"), (0x1d586, b'M', "a"), (0x1d587, b'M', "b"),
+    (0x1d588, b'M', "c"), (0x1d589, b'M', "d"), (0x1d58a, b'M', "e"), (0x1d58b, b'M', "f"),
+    (0x1d58c, b'M', "g"), (0x1d58d, b'M', "h"), (0x1d58e, b'M', "i"), (0x1d58f, b'M', "j"),
+    (0x1d590, b'M', "k"), (0x1d591, b'M', "l"), (0x1d592, b'M', "m"), (0x1d593, b'M', "n"),
+    (0x1d594, b'M', "o"), (0x1d595, b'M', "p"), (0x1d596, b'M', "q"), (0x1d597, b'M', "r"),
+    (0x1d598, b'M', "s"), (0x1d599, b'M', "t"), (0x1d59a, b'M', "u"), (0x1d59b, b'M', "v"),
+    (0x1d59c, b'M', "w"), (0x1d59d, b'M', "x"), (0x1d59e, b'M', "y"), (0x1d59f, b'M', "z"),
+    (0x1d5a0, b'M', "a"), (0x1d5a1, b'M', "b"), (0x1d5a2, b'M', "c"), (0x1d5a3, b'M', "d"),
+    (0x1d5a4, b'M', "e"), (0x1d5a5, b'M', "f"), (0x1d5a6, b'M', "g"), (0x1d5a7, b'M', "h"),
+    (0x1d5a8, b'M', "i"), (0x1d5a9, b'M', "j"), (0x1d5aa, b'M', "k"), (0x1d5ab, b'M', "l"),
+    (0x1d5ac, b'M', "m"), (0x1d5ad, b'M', "n"), (0x1d5ae, b'M', "o"), (0x1d5af, b'M', "p"),
+    (0x1d5b0, b'M', "q"), (0x1d5b1, b'M', "r"), (0x1d5b2, b'M', "s"), (0x1d5b3, b'M', "t"),
+    (0x1d5b4, b'M', "u"), (0x1d5b5, b'M', "v"), (0x1d5b6, b'M', "w"), (0x1d5b7, b'M', "x"),
+    (0x1d5b8, b'M', "y"), (0x1d5b9, b'M', "z"), (0x1d5ba, b'M', "a"), (0x1d5bb, b'M', "b"),
+    (0x1d5bc, b'M', "c"), (0x1d5bd, b'M', "d"), (0x1d5be, b'M', "e"), (0x1d5bf, b'M', "f"),
+    (0x1d5c0, b'M', "g"), (0x1d5c1, b'M', "h"), (0x1d5c2, b'M', "i"), (0x1d5c3, b'M', "j"),
+    (0x1d5c4, b'M', "k"), (0x1d5c5, b'M', "l"), (0x1d5c6, b'M', "m"), (0x1d5c7, b'M', "n"),
+    (0x1d5c8, b'M', "o"), (0x1d5c9, b'M', "p"), (0x1d5ca, b'M', "q"), (0x1d5cb, b'M', "r"),
+    (0x1d5cc, b'M', "s"), (0x1d5cd, b'M', "t"), (0x1d5ce, b'M', "u"), (0x1d5cf, b'M', "v"),
+    (0x1d5d0, b'M', "w"), (0x1d5d1, b'M', "x"), (0x1d5d2, b'M', "y"), (0x1d5d3, b'M', "z"),
+    (0x1d5d4, b'M', "a"), (0x1d5d5, b'M', "b"), (0x1d5d6, b'M', "c"), (0x1d5d7, b'M', "d"),
+    (0x1d5d8, b'M', "e"), (0x1d5d9, b'M', "f"), (0x1d5da, b'M', "g"), (0x1d5db, b'M', "h"),
+    (0x1d5dc, b'M', "i"), (0x1d5dd, b'M', "j"), (0x1d5de, b'M', "k"), (0x1d5df, b'M', "l"),
+    (0x1d5e0, b'M', "m"), (0x1d5e1, b'M', "n"), (0x1d5e2, b'M', "o"), (0x1d5e3, b'M', "p"),
+    (0x1d5e4, b'M', "q"), (0x1d5e5, b'M', "r"), (0x1d5e6, b'M', "s"), (0x1d5e7, b'M', "t"),
+    (0x1d5e8, b'M', "u"), (0x1d5e9, b'M', "v"), (0x1d5ea, b'M', "w"), (0x1d5eb, b'M', "x"),
+    (0x1d5ec, b'M', "y"), (0x1d5ed, b'M', "z"), (0x1d5ee, b'M', "a"), (0x1d5ef, b'M', "b"),
+    (0x1d5f0, b'M', "c"), (0x1d5f1, b'M', "d"), (0x1d5f2, b'M', "e"), (0x1d5f3, b'M', "f"),
+    (0x1d5f4, b'M', "g"), (0x1d5f5, b'M', "h"), (0x1d5f6, b'M', "i"), (0x1d5f7, b'M', "j"),
+    (0x1d5f8, b'M', "k"), (0x1d5f9, b'M', "l"), (0x1d5fa, b'M', "m"), (0x1d5fb, b'M', "n"),
+    (0x1d5fc, b'M', "o"), (0x1d5fd, b'M', "p"), (0x1d5fe, b'M', "q"), (0x1d5ff, b'M', "r"),
+    (0x1d600, b'M', "s"), (0x1d601, b'M', "t"), (0x1d602, b'M', "u"), (0x1d603, b'M', "v"),
+    (0x1d604, b'M', "w"), (0x1d605, b'M', "x"), (0x1d606, b'M', "y"), (0x1d607, b'M', "z"),
+    (0x1d608, b'M', "a"), (0x1d609, b'M', "b"), (0x1d60a, b'M', "c"), (0x1d60b, b'M', "d"),
+    (0x1d60c, b'M', "e"), (0x1d60d, b'M', "f"), (0x1d60e, b'M', "g"), (0x1d60f, b'M', "h"),
+    (0x1d610, b'M', "i"), (0x1d611, b'M', "j"), (0x1d612, b'M', "k"), (0x1d613, b'M', "l"),
+    (0x1d614, b'M', "m"), (0x1d615, b'M', "n"), (0x1d616, b'M', "o"), (0x1d617, b'M', "p"),
+    (0x1d618, b'M', "q"), (0x1d619, b'M', "r"), (0x1d61a, b'M', "s"), (0x1d61b, b'M', "t"),
+    (0x1d61c, b'M', "u"), (0x1d61d, b'M', "v"), (0x1d61e, b'M', "w"), (0x1d61f, b'M', "x"),
+    (0x1d620, b'M', "y"), (0x1d621, b'M', "z"), (0x1d622, b'M', "a"), (0x1d623, b'M', "b"),
+    (0x1d624, b'M', "c"), (0x1d625, b'M', "d"), (0x1d626, b'M', "e"), (0x1d627, b'M', "f"),
+    (0x1d628, b'M', "g"), (0x1d629, b'M', "h"), (0x1d62a, b'M', "i"), (0x1d62b, b'M', "j"),
+    (0x1d62c, b'M', "k"), (0x1d62d, b'M', "l"), (0x1d62e, b'M', "m"), (0x1d62f, b'M', "n"),
+    (0x1d630, b'M', "o"), (0x1d631, b'M', "p"), (0x1d632, b'M', "q"), (0x1d633, b'M', "r"),
+    (0x1d634, b'M', "s"), (0x1d635, b'M', "t"), (0x1d636, b'M', "u"), (0x1d637, b'M', "v"),
+    (0x1d638, b'M', "w"), (0x1d639, b'M', "x"), (0x1d63a, b'M', "y"), (0x1d63b, b'M', "z"),
+    (0x1d63c, b'M', "a"), (0x1d63d, b'M', "b"), (0x1d63e, b'M', "c"), (0x1d63f, b'M', "d"),
+    (0x1d640, b'M', "e"), (0x1d641, b'M', "f"), (0x1d642, b'M', "g"), (0x1d643, b'M', "h"),
+    (0x1d644, b'M', "i"), (0x1d645, b'M', "j"), (0x1d646, b'M', "k"), (0x1d647, b'M', "l"),
+    (0x1d648, b'M', "m"), (0x1d649, b'M', "n"), (0x1d64a, b'M', "o"), (0x1d64b, b'M', "p"),
+    (0x1d64c, b'M', "q"), (0x1d64d, b'M', "r"), (0x1d64e, b'M', "s"), (0x1d64f, b'M', "t"),
+    (0x1d650, b'M', "u"), (0x1d651, b'M', "v"), (0x1d652, b'M', "w"), (0x1d653, b'M', "x"),
+    (0x1d654, b'M', "y"), (0x1d655, b'M', "z"), (0x1d656, b'M', "a"), (0x1d657, b'M', "b"),
+    (0x1d658, b'M', "c"), (0x1d659, b'M', "d"), (0x1d65a, b'M', "e"), (0x1d65b, b'M', "f"),
+    (0x1d65c, b'M', "g"), (0x1d65d, b'M', "h"), (0x1d65e, b'M', "i"), (0x1d65f, b'M', "j"),
+    (0x1d660, b'M', "k"), (0x1d661, b'M', "l"), (0x1d662, b'M', "m"), (0x1d663, b'M', "n"),
+    (0x1d664, b'M', "o"), (0x1d665, b'M', "p"), (0x1d666, b'M', "q"), (0x1d667, b'M', "r"),
+    (0x1d668, b'M', "s"), (0x1d669, b'M', "t"), (0x1d66a, b'M', "u"), (0x1d66b, b'M', "v"),
+    (0x1d66c, b'M', "w"), (0x1d66d, b'M', "x"), (0x1d66e, b'M', "y"), (0x1d66f, b'M', "z"),
+    (0x1d670, b'M', "a"), (0x1d671, b'M', "b"), (0x1d672, b'M', "c"), (0x1d673, b'M', "d"),
+    (0x1d674, b'M', "e"), (0x1d675, b'M', "f"), (0x1d676, b'M', "g"), (0x1d677, b'M', "h"),
+    (0x1d678, b'M', "i"), (0x1d679, b'M', "j"), (0x1d67a, b'M', "k"), (0x1d67b, b'M', "l"),
+    (0x1d67c, b'M', "m"), (0x1d67d, b'M', "n"), (0x1d67e, b'M', "o"), (0x1d67f, b'M', "p"),
+    (0x1d680, b'M', "q"), (0x1d681, b'M', "r"), (0x1d682, b'M', "s"), (0x1d683, b'M', "t"),
+    (0x1d684, b'M', "u"), (0x1d685, b'M', "v"), (0x1d686, b'M', "w"), (0x1d687, b'M', "x"),
+    (0x1d688, b'M', "y"), (0x1d689, b'M', "z"), (0x1d68a, b'M', "a"), (0x1d68b, b'M', "b"),
+    (0x1d68c, b'M', "c"), (0x1d68d, b'M', "d"), (0x1d68e, b'M', "e"), (0x1d68f, b'M', "f"),
+    (0x1d690, b'M', "g"), (0x1d691, b'M', "h"), (0x1d692, b'M', "i"), (0x1d693, b'M', "j"),
+    (0x1d694, b'M', "k"), (0x1d695, b'M', "l"), (0x1d696, b'M', "m"), (0x1d697, b'M', "n"),
+    (0x1d698, b'M', "o"), (0x1d699, b'M', "p"), (0x1d69a, b'M', "q"), (0x1d69b, b'M', "r"),
+    (0x1d69c, b'M', "s"), (0x1d69d, b'M', "t"), (0x1d69e, b'M', "u"), (0x1d69f, b'M', "v"),
+    (0x1d6a0, b'M', "w"), (0x1d6a1, b'M', "x"), (0x1d6a2, b'M', "y"), (0x1d6a3, b'M', "z"),
+    (0x1d6a4, b'M', "\u{131}"), (0x1d6a5, b'M', "\u{237}"), (0x1d6a6, b'X', ""),
+    (0x1d6a8, b'M', "\u{3b1}"), (0x1d6a9, b'M', "\u{3b2}"), (0x1d6aa, b'M', "\u{3b3}"),
+    (0x1d6ab, b'M', "\u{3b4}"), (0x1d6ac, b'M', "\u{3b5}"), (0x1d6ad, b'M', "\u{3b6}"),
+    (0x1d6ae, b'M', "\u{3b7}"), (0x1d6af, b'M', "\u{3b8}"), (0x1d6b0, b'M', "\u{3b9}"),
+    (0x1d6b1, b'M', "\u{3ba}"), (0x1d6b2, b'M', "\u{3bb}"), (0x1d6b3, b'M', "\u{3bc}"),
+    (0x1d6b4, b'M', "\u{3bd}"), (0x1d6b5, b'M', "\u{3be}"), (0x1d6b6, b'M', "\u{3bf}"),
+    (0x1d6b7, b'M', "\u{3c0}"), (0x1d6b8, b'M', "\u{3c1}"), (0x1d6b9, b'M', "\u{3b8}"),
+    (0x1d6ba, b'M', "\u{3c3}"), (0x1d6bb, b'M', "\u{3c4}"), (0x1d6bc, b'M', "\u{3c5}"),
+    (0x1d6bd, b'M', "\u{3c6}"), (0x1d6be, b'M', "\u{3c7}"), (0x1d6bf, b'M', "\u{3c8}"),
+    (0x1d6c0, b'M', "\u{3c9}"), (0x1d6c1, b'M', "\u{2207}"), (0x1d6c2, b'M', "\u{3b1}"),
+    (0x1d6c3, b'M', "\u{3b2}"), (0x1d6c4, b'M', "\u{3b3}"), (0x1d6c5, b'M', "\u{3b4}"),
+    (0x1d6c6, b'M', "\u{3b5}"), (0x1d6c7, b'M', "\u{3b6}"), (0x1d6c8, b'M', "\u{3b7}"),
+    (0x1d6c9, b'M', "\u{3b8}"), (0x1d6ca, b'M', "\u{3b9}"), (0x1d6cb, b'M', "\u{3ba}"),
+    (0x1d6cc, b'M', "\u{3bb}"), (0x1d6cd, b'M', "\u{3bc}"), (0x1d6ce, b'M', "\u{3bd}"),
+    (0x1d6cf, b'M', "\u{3be}"), (0x1d6d0, b'M', "\u{3bf}"), (0x1d6d1, b'M', "\u{3c0}"),
+    (0x1d6d2, b'M', "\u{3c1}"), (0x1d6d3, b'M', "\u{3c3}"), (0x1d6d5, b'M', "\u{3c4}"),
+    (0x1d6d6, b'M', "\u{3c5}"), (0x1d6d7, b'M', "\u{3c6}"), (0x1d6d8, b'M', "\u{3c7}"),
+    (0x1d6d9, b'M', "\u{3c8}"), (0x1d6da, b'M', "\u{3c9}"), (0x1d6db, b'M', "\u{2202}"),
+    (0x1d6dc, b'M', "\u{3b5}"), (0x1d6dd, b'M', "\u{3b8}"), (0x1d6de, b'M', "\u{3ba}"),
+    (0x1d6df, b'M', "\u{3c6}"), (0x1d6e0, b'M', "\u{3c1}"), (0x1d6e1, b'M', "\u{3c0}"),
+    (0x1d6e2, b'M', "\u{3b1}"), (0x1d6e3, b'M', "\u{3b2}"), (0x1d6e4, b'M', "\u{3b3}"),
+    (0x1d6e5, b'M', "\u{3b4}"), (0x1d6e6, b'M', "\u{3b5}"), (0x1d6e7, b'M', "\u{3b6}"),
+    (0x1d6e8, b'M', "\u{3b7}"), (0x1d6e9, b'M', "\u{3b8}"), (0x1d6ea, b'M', "\u{3b9}"),
+    (0x1d6eb, b'M', "\u{3ba}"), (0x1d6ec, b'M', "\u{3bb}"), (0x1d6ed, b'M', "\u{3bc}"),
+    (0x1d6ee, b'M', "\u{3bd}"), (0x1d6ef, b'M', "\u{3be}"), (0x1d6f0, b'M', "\u{3bf}"),
+    (0x1d6f1, b'M', "\u{3c0}"), (0x1d6f2, b'M', "\u{3c1}"), (0x1d6f3, b'M', "\u{3b8}"),
+    (0x1d6f4, b'M', "\u{3c3}"), (0x1d6f5, b'M', "\u{3c4}"), (0x1d6f6, b'M', "\u{3c5}"),
+    (0x1d6f7, b'M', "\u{3c6}"), (0x1d6f8, b'M', "\u{3c7}"), (0x1d6f9, b'M', "\u{3c8}"),
+    (0x1d6fa, b'M', "\u{3c9}"), (0x1d6fb, b'M', "\u{2207}"), (0x1d6fc, b'M', "\u{3b1}"),
+    (0x1d6fd, b'M', "\u{3b2}"), (0x1d6fe, b'M', "\u{3b3}"), (0x1d6ff, b'M', "\u{3b4}"),
+    (0x1d700, b'M', "\u{3b5}"), (0x1d701, b'M', "\u{3b6}"), (0x1d702, b'M', "\u{3b7}"),
+    (0x1d703, b'M', "\u{3b8}"), (0x1d704, b'M', "\u{3b9}"), (0x1d705, b'M', "\u{3ba}"),
+    (0x1d706, b'M', "\u{3bb}"), (0x1d707, b'M', "\u{3bc}"), (0x1d708, b'M', "\u{3bd}"),
+    (0x1d709, b'M', "\u{3be}"), (0x1d70a, b'M', "\u{3bf}"), (0x1d70b, b'M', "\u{3c0}"),
+    (0x1d70c, b'M', "\u{3c1}"), (0x1d70d, b'M', "\u{3c3}"), (0x1d70f, b'M', "\u{3c4}"),
+    (0x1d710, b'M', "\u{3c5}"), (0x1d711, b'M', "\u{3c6}"), (0x1d712, b'M', "\u{3c7}"),
+    (0x1d713, b'M', "\u{3c8}"), (0x1d714, b'M', "\u{3c9}"), (0x1d715, b'M', "\u{2202}"),
+    (0x1d716, b'M', "\u{3b5}"), (0x1d717, b'M', "\u{3b8}"), (0x1d718, b'M', "\u{3ba}"),
+    (0x1d719, b'M', "\u{3c6}"), (0x1d71a, b'M', "\u{3c1}"), (0x1d71b, b'M', "\u{3c0}"),
+    (0x1d71c, b'M', "\u{3b1}"), (0x1d71d, b'M', "\u{3b2}"), (0x1d71e, b'M', "\u{3b3}"),
+    (0x1d71f, b'M', "\u{3b4}"), (0x1d720, b'M', "\u{3b5}"), (0x1d721, b'M', "\u{3b6}"),
+    (0x1d722, b'M', "\u{3b7}"), (0x1d723, b'M', "\u{3b8}"), (0x1d724, b'M', "\u{3b9}"),
+    (0x1d725, b'M', "\u{3ba}"), (0x1d726, b'M', "\u{3bb}"), (0x1d727, b'M', "\u{3bc}"),
+    (0x1d728, b'M', "\u{3bd}"), (0x1d729, b'M', "\u{3be}"), (0x1d72a, b'M', "\u{3bf}"),
+    (0x1d72b, b'M', "\u{3c0}"), (0x1d72c, b'M', "\u{3c1}"), (0x1d72d, b'M', "\u{3b8}"),
+    (0x1d72e, b'M', "\u{3c3}"), (0x1d72f, b'M', "\u{3c4}"), (0x1d730, b'M', "\u{3c5}"),
+    (0x1d731, b'M', "\u{3c6}"), (0x1d732, b'M', "\u{3c7}"), (0x1d733, b'M', "\u{3c8}"),
+    (0x1d734, b'M', "\u{3c9}"), (0x1d735, b'M', "\u{2207}"), (0x1d736, b'M', "\u{3b1}"),
+    (0x1d737, b'M', "\u{3b2}"), (0x1d738, b'M', "\u{3b3}"), (0x1d739, b'M', "\u{3b4}"),
+    (0x1d73a, b'M', "\u{3b5}"), (0x1d73b, b'M', "\u{3b6}"), (0x1d73c, b'M', "\u{3b7}"),
+    (0x1d73d, b'M', "\u{3b8}"), (0x1d73e, b'M', "\u{3b9}"), (0x1d73f, b'M', "\u{3ba}"),
+    (0x1d740, b'M', "\u{3bb}"), (0x1d741, b'M', "\u{3bc}"), (0x1d742, b'M', "\u{3bd}"),
+    (0x1d743, b'M', "\u{3be}"), (0x1d744, b'M', "\u{3bf}"), (0x1d745, b'M', "\u{3c0}"),
+    (0x1d746, b'M', "\u{3c1}"), (0x1d747, b'M', "\u{3c3}"), (0x1d749, b'M', "\u{3c4}"),
+    (0x1d74a, b'M', "\u{3c5}"), (0x1d74b, b'M', "\u{3c6}"), (0x1d74c, b'M', "\u{3c7}"),
+    (0x1d74d, b'M', "\u{3c8}"), (0x1d74e, b'M', "\u{3c9}"), (0x1d74f, b'M', "\u{2202}"),
+    (0x1d750, b'M', "\u{3b5}"), (0x1d751, b'M', "\u{3b8}"), (0x1d752, b'M', "\u{3ba}"),
+    (0x1d753, b'M', "\u{3c6}"), (0x1d754, b'M', "\u{3c1}"), (0x1d755, b'M', "\u{3c0}"),
+    (0x1d756, b'M', "\u{3b1}"), (0x1d757, b'M', "\u{3b2}"), (0x1d758, b'M', "\u{3b3}"),
+    (0x1d759, b'M', "\u{3b4}"), (0x1d75a, b'M', "\u{3b5}"), (0x1d75b, b'M', "\u{3b6}"),
+    (0x1d75c, b'M', "\u{3b7}"), (0x1d75d, b'M', "\u{3b8}"), (0x1d75e, b'M', "\u{3b9}"),
+    (0x1d75f, b'M', "\u{3ba}"), (0x1d760, b'M', "\u{3bb}"), (0x1d761, b'M', "\u{3bc}"),
+    (0x1d762, b'M', "\u{3bd}"), (0x1d763, b'M', "\u{3be}"), (0x1d764, b'M', "\u{3bf}"),
+    (0x1d765, b'M', "\u{3c0}"), (0x1d766, b'M', "\u{3c1}"), (0x1d767, b'M', "\u{3b8}"),
+    (0x1d768, b'M', "\u{3c3}"), (0x1d769, b'M', "\u{3c4}"), (0x1d76a, b'M', "\u{3c5}"),
+    (0x1d76b, b'M', "\u{3c6}"), (0x1d76c, b'M', "\u{3c7}"), (0x1d76d, b'M', "\u{3c8}"),
+    (0x1d76e, b'M', "\u{3c9}"), (0x1d76f, b'M', "\u{2207}"), (0x1d770, b'M', "\u{3b1}"),
+    (0x1d771, b'M', "\u{3b2}"), (0x1d772, b'M', "\u{3b3}"), (0x1d773, b'M', "\u{3b4}"),
+    (0x1d774, b'M', "\u{3b5}"), (0x1d775, b'M', "\u{3b6}"), (0x1d776, b'M', "\u{3b7}"),
+    (0x1d777, b'M', "\u{3b8}"), (0x1d778, b'M', "\u{3b9}"), (0x1d779, b'M', "\u{3ba}"),
+    (0x1d77a, b'M', "\u{3bb}"), (0x1d77b, b'M', "\u{3bc}"), (0x1d77c, b'M', "\u{3bd}"),
+    (0x1d77d, b'M', "\u{3be}"), (0x1d77e, b'M', "\u{3bf}"), (0x1d77f, b'M', "\u{3c0}"),
+    (0x1d780, b'M', "\u{3c1}"), (0x1d781, b'M', "\u{3c3}"), (0x1d783, b'M', "\u{3c4}"),
+    (0x1d784, b'M', "\u{3c5}"), (0x1d785, b'M', "\u{3c6}"), (0x1d786, b'M', "\u{3c7}"),
+    (0x1d787, b'M', "\u{3c8}"), (0x1d788, b'M', "\u{3c9}"), (0x1d789, b'M', "\u{2202}"),
+    (0x1d78a, b'M', "\u{3b5}"), (0x1d78b, b'M', "\u{3b8}"), (0x1d78c, b'M', "\u{3ba}"),
+    (0x1d78d, b'M', "\u{3c6}"), (0x1d78e, b'M', "\u{3c1}"), (0x1d78f, b'M', "\u{3c0}"),
+    (0x1d790, b'M', "\u{3b1}"), (0x1d791, b'M', "\u{3b2}"), (0x1d792, b'M', "\u{3b3}"),
+    (0x1d793, b'M', "\u{3b4}"), (0x1d794, b'M', "\u{3b5}"), (0x1d795, b'M', "\u{3b6}"),
+    (0x1d796, b'M', "\u{3b7}"), (0x1d797, b'M', "\u{3b8}"), (0x1d798, b'M', "\u{3b9}"),
+    (0x1d799, b'M', "\u{3ba}"), (0x1d79a, b'M', "\u{3bb}"), (0x1d79b, b'M', "\u{3bc}"),
+    (0x1d79c, b'M', "\u{3bd}"), (0x1d79d, b'M', "\u{3be}"), (0x1d79e, b'M', "\u{3bf}"),
+    (0x1d79f, b'M', "\u{3c0}"), (0x1d7a0, b'M', "\u{3c1}"), (0x1d7a1, b'M', "\u{3b8}"),
+    (0x1d7a2, b'M', "\u{3c3}"), (0x1d7a3, b'M', "\u{3c4}"), (0x1d7a4, b'M', "\u{3c5}"),
+    (0x1d7a5, b'M', "\u{3c6}"), (0x1d7a6, b'M', "\u{3c7}"), (0x1d7a7, b'M', "\u{3c8}"),
+    (0x1d7a8, b'M', "\u{3c9}"), (0x1d7a9, b'M', "\u{2207}"), (0x1d7aa, b'M', "\u{3b1}"),
+    (0x1d7ab, b'M', "\u{3b2}"), (0x1d7ac, b'M', "\u{3b3}"), (0x1d7ad, b'M', "\u{3b4}"),
+    (0x1d7ae, b'M', "\u{3b5}"), (0x1d7af, b'M', "\u{3b6}"), (0x1d7b0, b'M', "\u{3b7}"),
+    (0x1d7b1, b'M', "\u{3b8}"), (0x1d7b2, b'M', "\u{3b9}"), (0x1d7b3, b'M', "\u{3ba}"),
+    (0x1d7b4, b'M', "\u{3bb}"), (0x1d7b5, b'M', "\u{3bc}"), (0x1d7b6, b'M', "\u{3bd}"),
+    (0x1d7b7, b'M', "\u{3be}"), (0x1d7b8, b'M', "\u{3bf}"), (0x1d7b9, b'M', "\u{3c0}"),
+    (0x1d7ba, b'M', "\u{3c1}"), (0x1d7bb, b'M', "\u{3c3}"), (0x1d7bd, b'M', "\u{3c4}"),
+    (0x1d7be, b'M', "\u{3c5}"), (0x1d7bf, b'M', "\u{3c6}"), (0x1d7c0, b'M', "\u{3c7}"),
+    (0x1d7c1, b'M', "\u{3c8}"), (0x1d7c2, b'M', "\u{3c9}"), (0x1d7c3, b'M', "\u{2202}"),
+    (0x1d7c4, b'M', "\u{3b5}"), (0x1d7c5, b'M', "\u{3b8}"), (0x1d7c6, b'M', "\u{3ba}"),
+    (0x1d7c7, b'M', "\u{3c6}"), (0x1d7c8, b'M', "\u{3c1}"), (0x1d7c9, b'M', "\u{3c0}"),
+    (0x1d7ca, b'M', "\u{3dd}"), (0x1d7cc, b'X', ""), (0x1d7ce, b'M', "0"), (0x1d7cf, b'M', "1"),
+    (0x1d7d0, b'M', "2"), (0x1d7d1, b'M', "3"), (0x1d7d2, b'M', "4"), (0x1d7d3, b'M', "5"),
+    (0x1d7d4, b'M', "6"), (0x1d7d5, b'M', "7"), (0x1d7d6, b'M', "8"), (0x1d7d7, b'M', "9"),
+    (0x1d7d8, b'M', "0"), (0x1d7d9, b'M', "1"), (0x1d7da, b'M', "2"), (0x1d7db, b'M', "3"),
+    (0x1d7dc, b'M', "4"), (0x1d7dd, b'M', "5"), (0x1d7de, b'M', "6"), (0x1d7df, b'M', "7"),
+    (0x1d7e0, b'M', "8"), (0x1d7e1, b'M', "9"), (0x1d7e2, b'M', "0"), (0x1d7e3, b'M', "1"),
+    (0x1d7e4, b'M', "2"), (0x1d7e5, b'M', "3"), (0x1d7e6, b'M', "4"), (0x1d7e7, b'M', "5"),
+    (0x1d7e8, b'M', "6"), (0x1d7e9, b'M', "7"), (0x1d7ea, b'M', "8"), (0x1d7eb, b'M', "9"),
+    (0x1d7ec, b'M', "0"), (0x1d7ed, b'M', "1"), (0x1d7ee, b'M', "2"), (0x1d7ef, b'M', "3"),
+    (0x1d7f0, b'M', "4"), (0x1d7f1, b'M', "5"), (0x1d7f2, b'M', "6"), (0x1d7f3, b'M', "7"),
+    (0x1d7f4, b'M', "8"), (0x1d7f5, b'M', "9"), (0x1d7f6, b'M', "0"), (0x1d7f7, b'M', "1"),
+    (0x1d7f8, b'M', "2"), (0x1d7f9, b'M', "3"), (0x1d7fa, b'M', "4"), (0x1d7fb, b'M', "5"),
+    (0x1d7fc, b'M', "6"), (0x1d7fd, b'M', "7"), (0x1d7fe, b'M', "8"), (0x1d7ff, b'M', "9"),
+    (0x1d800, b'V', ""), (0x1da8c, b'X', ""), (0x1da9b, b'V', ""), (0x1daa0, b'X', ""),
+    (0x1daa1, b'V', ""), (0x1dab0, b'X', ""), (0x1df00, b'V', ""), (0x1df1f, b'X', ""),
+    (0x1df25, b'V', ""), (0x1df2b, b'X', ""), (0x1e000, b'V', ""), (0x1e007, b'X', ""),
+    (0x1e008, b'V', ""), (0x1e019, b'X', ""), (0x1e01b, b'V', ""), (0x1e022, b'X', ""),
+    (0x1e023, b'V', ""), (0x1e025, b'X', ""), (0x1e026, b'V', ""), (0x1e02b, b'X', ""),
+    (0x1e030, b'M', "\u{430}"), (0x1e031, b'M', "\u{431}"), (0x1e032, b'M', "\u{432}"),
+    (0x1e033, b'M', "\u{433}"), (0x1e034, b'M', "\u{434}"), (0x1e035, b'M', "\u{435}"),
+    (0x1e036, b'M', "\u{436}"), (0x1e037, b'M', "\u{437}"), (0x1e038, b'M', "\u{438}"),
+    (0x1e039, b'M', "\u{43a}"), (0x1e03a, b'M', "\u{43b}"), (0x1e03b, b'M', "\u{43c}"),
+    (0x1e03c, b'M', "\u{43e}"), (0x1e03d, b'M', "\u{43f}"), (0x1e03e, b'M', "\u{440}"),
+    (0x1e03f, b'M', "\u{441}"), (0x1e040, b'M', "\u{442}"), (0x1e041, b'M', "\u{443}"),
+    (0x1e042, b'M', "\u{444}"), (0x1e043, b'M', "\u{445}"), (0x1e044, b'M', "\u{446}"),
+    (0x1e045, b'M', "\u{447}"), (0x1e046, b'M', "\u{448}"), (0x1e047, b'M', "\u{44b}"),
+    (0x1e048, b'M', "\u{44d}"), (0x1e049, b'M', "\u{44e}"), (0x1e04a, b'M', "\u{a689}"),
+    (0x1e04b, b'M', "\u{4d9}"), (0x1e04c, b'M', "\u{456}"), (0x1e04d, b'M', "\u{458}"),
+    (0x1e04e, b'M', "\u{4e9}"), (0x1e04f, b'M', "\u{4af}"), (0x1e050, b'M', "\u{4cf}"),
+    (0x1e051, b'M', "\u{430}"), (0x1e052, b'M', "\u{431}"), (0x1e053, b'M', "\u{432}"),
+    (0x1e054, b'M', "\u{433}"), (0x1e055, b'M', "\u{434}"), (0x1e056, b'M', "\u{435}"),
+    (0x1e057, b'M', "\u{436}"), (0x1e058, b'M', "\u{437}"), (0x1e059, b'M', "\u{438}"),
+    (0x1e05a, b'M', "\u{43a}"), (0x1e05b, b'M', "\u{43b}"), (0x1e05c, b'M', "\u{43e}"),
+    (0x1e05d, b'M', "\u{43f}"), (0x1e05e, b'M', "\u{441}"), (0x1e05f, b'M', "\u{443}"),
+    (0x1e060, b'M', "\u{444}"), (0x1e061, b'M', "\u{445}"), (0x1e062, b'M', "\u{446}"),
+    (0x1e063, b'M', "\u{447}"), (0x1e064, b'M', "\u{448}"), (0x1e065, b'M', "\u{44a}"),
+    (0x1e066, b'M', "\u{44b}"), (0x1e067, b'M', "\u{491}"), (0x1e068, b'M', "\u{456}"),
+    (0x1e069, b'M', "\u{455}"), (0x1e06a, b'M', "\u{45f}"), (0x1e06b, b'M', "\u{4ab}"),
+    (0x1e06c, b'M', "\u{a651}"), (0x1e06d, b'M', "\u{4b1}"), (0x1e06e, b'X', ""),
+    (0x1e08f, b'V', ""), (0x1e090, b'X', ""), (0x1e100, b'V', ""), (0x1e12d, b'X', ""),
+    (0x1e130, b'V', ""), (0x1e13e, b'X', ""), (0x1e140, b'V', ""), (0x1e14a, b'X', ""),
+    (0x1e14e, b'V', ""), (0x1e150, b'X', ""), (0x1e290, b'V', ""), (0x1e2af, b'X', ""),
+    (0x1e2c0, b'V', ""), (0x1e2fa, b'X', ""), (0x1e2ff, b'V', ""), (0x1e300, b'X', ""),
+    (0x1e4d0, b'V', ""), (0x1e4fa, b'X', ""), (0x1e7e0, b'V', ""), (0x1e7e7, b'X', ""),
+    (0x1e7e8, b'V', ""), (0x1e7ec, b'X', ""), (0x1e7ed, b'V', ""), (0x1e7ef, b'X', ""),
+    (0x1e7f0, b'V', ""), (0x1e7ff, b'X', ""), (0x1e800, b'V', ""), (0x1e8c5, b'X', ""),
+    (0x1e8c7, b'V', ""), (0x1e8d7, b'X', ""), (0x1e900, b'M', "\u{1e922}"),
+    (0x1e901, b'M', "\u{1e923}"), (0x1e902, b'M', "\u{1e924}"), (0x1e903, b'M', "\u{1e925}"),
+    (0x1e904, b'M', "\u{1e926}"), (0x1e905, b'M', "\u{1e927}"), (0x1e906, b'M', "\u{1e928}"),
+    (0x1e907, b'M', "\u{1e929}"), (0x1e908, b'M', "\u{1e92a}"), (0x1e909, b'M', "\u{1e92b}"),
+    (0x1e90a, b'M', "\u{1e92c}"), (0x1e90b, b'M', "\u{1e92d}"), (0x1e90c, b'M', "\u{1e92e}"),
+    (0x1e90d, b'M', "\u{1e92f}"), (0x1e90e, b'M', "\u{1e930}"), (0x1e90f, b'M', "\u{1e931}"),
+    (0x1e910, b'M', "\u{1e932}"), (0x1e911, b'M', "\u{1e933}"), (0x1e912, b'M', "\u{1e934}"),
+    (0x1e913, b'M', "\u{1e935}"), (0x1e914, b'M', "\u{1e936}"), (0x1e915, b'M', "\u{1e937}"),
+    (0x1e916, b'M', "\u{1e938}"), (0x1e917, b'M', "\u{1e939}"), (0x1e918, b'M', "\u{1e93a}"),
+    (0x1e919, b'M', "\u{1e93b}"), (0x1e91a, b'M', "\u{1e93c}"), (0x1e91b, b'M', "\u{1e93d}"),
+    (0x1e91c, b'M', "\u{1e93e}"), (0x1e91d, b'M', "\u{1e93f}"), (0x1e91e, b'M', "\u{1e940}"),
+    (0x1e91f, b'M', "\u{1e941}"), (0x1e920, b'M', "\u{1e942}"), (0x1e921, b'M', "\u{1e943}"),
+    (0x1e922, b'V', ""), (0x1e94c, b'X', ""), (0x1e950, b'V', ""), (0x1e95a, b'X', ""),
+    (0x1e95e, b'V', ""), (0x1e960, b'X', ""), (0x1ec71, b'V', ""), (0x1ecb5, b'X', ""),
+    (0x1ed01, b'V', ""), (0x1ed3e, b'X', ""), (0x1ee00, b'M', "\u{627}"),
+    (0x1ee01, b'M', "\u{628}"), (0x1ee02, b'M', "\u{62c}"), (0x1ee03, b'M', "\u{62f}"),
+    (0x1ee04, b'X', ""), (0x1ee05, b'M', "\u{648}"), (0x1ee06, b'M', "\u{632}"),
+    (0x1ee07, b'M', "\u{62d}"), (0x1ee08, b'M', "\u{637}"), (0x1ee09, b'M', "\u{64a}"),
+    (0x1ee0a, b'M', "\u{643}"), (0x1ee0b, b'M', "\u{644}"), (0x1ee0c, b'M', "\u{645}"),
+    (0x1ee0d, b'M', "\u{646}"), (0x1ee0e, b'M', "\u{633}"), (0x1ee0f, b'M', "\u{639}"),
+    (0x1ee10, b'M', "\u{641}"), (0x1ee11, b'M', "\u{635}"), (0x1ee12, b'M', "\u{642}"),
+    (0x1ee13, b'M', "\u{631}"), (0x1ee14, b'M', "\u{634}"), (0x1ee15, b'M', "\u{62a}"),
+    (0x1ee16, b'M', "\u{62b}"), (0x1ee17, b'M', "\u{62e}"), (0x1ee18, b'M', "\u{630}"),
+    (0x1ee19, b'M', "\u{636}"), (0x1ee1a, b'M', "\u{638}"), (0x1ee1b, b'M', "\u{63a}"),
+    (0x1ee1c, b'M', "\u{66e}"), (0x1ee1d, b'M', "\u{6ba}"), (0x1ee1e, b'M', "\u{6a1}"),
+    (0x1ee1f, b'M', "\u{66f}"), (0x1ee20, b'X', ""), (0x1ee21, b'M', "\u{628}"),
+    (0x1ee22, b'M', "\u{62c}"), (0x1ee23, b'X', ""), (0x1ee24, b'M', "\u{647}"),
+    (0x1ee25, b'X', ""), (0x1ee27, b'M', "\u{62d}"), (0x1ee28, b'X', ""),
+    (0x1ee29, b'M', "\u{64a}"), (0x1ee2a, b'M', "\u{643}"), (0x1ee2b, b'M', "\u{644}"),
+    (0x1ee2c, b'M', "\u{645}"), (0x1ee2d, b'M', "\u{646}"), (0x1ee2e, b'M', "\u{633}"),
+    (0x1ee2f, b'M', "\u{639}"), (0x1ee30, b'M', "\u{641}"), (0x1ee31, b'M', "\u{635}"),
+    (0x1ee32, b'M', "\u{642}"), (0x1ee33, b'X', ""), (0x1ee34, b'M', "\u{634}"),
+    (0x1ee35, b'M', "\u{62a}"), (0x1ee36, b'M', "\u{62b}"), (0x1ee37, b'M', "\u{62e}"),
+    (0x1ee38, b'X', ""), (0x1ee39, b'M', "\u{636}"), (0x1ee3a, b'X', ""),
+    (0x1ee3b, b'M', "\u{63a}"), (0x1ee3c, b'X', ""), (0x1ee42, b'M', "\u{62c}"),
+    (0x1ee43, b'X', ""), (0x1ee47, b'M', "\u{62d}"), (0x1ee48, b'X', ""),
+    (0x1ee49, b'M', "\u{64a}"), (0x1ee4a, b'X', ""), (0x1ee4b, b'M', "\u{644}"),
+    (0x1ee4c, b'X', ""), (0x1ee4d, b'M', "\u{646}"), (0x1ee4e, b'M', "\u{633}"),
+    (0x1ee4f, b'M', "\u{639}"), (0x1ee50, b'X', ""), (0x1ee51, b'M', "\u{635}"),
+    (0x1ee52, b'M', "\u{642}"), (0x1ee53, b'X', ""), (0x1ee54, b'M', "\u{634}"),
+    (0x1ee55, b'X', ""), (0x1ee57, b'M', "\u{62e}"), (0x1ee58, b'X', ""),
+    (0x1ee59, b'M', "\u{636}"), (0x1ee5a, b'X', ""), (0x1ee5b, b'M', "\u{63a}"),
+    (0x1ee5c, b'X', ""), (0x1ee5d, b'M', "\u{6ba}"), (0x1ee5e, b'X', ""),
+    (0x1ee5f, b'M', "\u{66f}"), (0x1ee60, b'X', ""), (0x1ee61, b'M', "\u{628}"),
+    (0x1ee62, b'M', "\u{62c}"), (0x1ee63, b'X', ""), (0x1ee64, b'M', "\u{647}"),
+    (0x1ee65, b'X', ""), (0x1ee67, b'M', "\u{62d}"), (0x1ee68, b'M', "\u{637}"),
+    (0x1ee69, b'M', "\u{64a}"), (0x1ee6a, b'M', "\u{643}"), (0x1ee6b, b'X', ""),
+    (0x1ee6c, b'M', "\u{645}"), (0x1ee6d, b'M', "\u{646}"), (0x1ee6e, b'M', "\u{633}"),
+    (0x1ee6f, b'M', "\u{639}"), (0x1ee70, b'M', "\u{641}"), (0x1ee71, b'M', "\u{635}"),
+    (0x1ee72, b'M', "\u{642}"), (0x1ee73, b'X', ""), (0x1ee74, b'M', "\u{634}"),
+    (0x1ee75, b'M', "\u{62a}"), (0x1ee76, b'M', "\u{62b}"), (0x1ee77, b'M', "\u{62e}"),
+    (0x1ee78, b'X', ""), (0x1ee79, b'M', "\u{636}"), (0x1ee7a, b'M', "\u{638}"),
+    (0x1ee7b, b'M', "\u{63a}"), (0x1ee7c, b'M', "\u{66e}"), (0x1ee7d, b'X', ""),
+    (0x1ee7e, b'M', "\u{6a1}"), (0x1ee7f, b'X', ""), (0x1ee80, b'M', "\u{627}"),
+    (0x1ee81, b'M', "\u{628}"), (0x1ee82, b'M', "\u{62c}"), (0x1ee83, b'M', "\u{62f}"),
+    (0x1ee84, b'M', "\u{647}"), (0x1ee85, b'M', "\u{648}"), (0x1ee86, b'M', "\u{632}"),
+    (0x1ee87, b'M', "\u{62d}"), (0x1ee88, b'M', "\u{637}"), (0x1ee89, b'M', "\u{64a}"),
+    (0x1ee8a, b'X', ""), (0x1ee8b, b'M', "\u{644}"), (0x1ee8c, b'M', "\u{645}"),
+    (0x1ee8d, b'M', "\u{646}"), (0x1ee8e, b'M', "\u{633}"), (0x1ee8f, b'M', "\u{639}"),
+    (0x1ee90, b'M', "\u{641}"), (0x1ee91, b'M', "\u{635}"), (0x1ee92, b'M', "\u{642}"),
+    (0x1ee93, b'M', "\u{631}"), (0x1ee94, b'M', "\u{634}"), (0x1ee95, b'M', "\u{62a}"),
+    (0x1ee96, b'M', "\u{62b}"), (0x1ee97, b'M', "\u{62e}"), (0x1ee98, b'M', "\u{630}"),
+    (0x1ee99, b'M', "\u{636}"), (0x1ee9a, b'M', "\u{638}"), (0x1ee9b, b'M', "\u{63a}"),
+    (0x1ee9c, b'X', ""), (0x1eea1, b'M', "\u{628}"), (0x1eea2, b'M', "\u{62c}"),
+    (0x1eea3, b'M', "\u{62f}"), (0x1eea4, b'X', ""), (0x1eea5, b'M', "\u{648}"),
+    (0x1eea6, b'M', "\u{632}"), (0x1eea7, b'M', "\u{62d}"), (0x1eea8, b'M', "\u{637}"),
+    (0x1eea9, b'M', "\u{64a}"), (0x1eeaa, b'X', ""), (0x1eeab, b'M', "\u{644}"),
+    (0x1eeac, b'M', "\u{645}"), (0x1eead, b'M', "\u{646}"), (0x1eeae, b'M', "\u{633}"),
+    (0x1eeaf, b'M', "\u{639}"), (0x1eeb0, b'M', "\u{641}"), (0x1eeb1, b'M', "\u{635}"),
+    (0x1eeb2, b'M', "\u{642}"), (0x1eeb3, b'M', "\u{631}"), (0x1eeb4, b'M', "\u{634}"),
+    (0x1eeb5, b'M', "\u{62a}"), (0x1eeb6, b'M', "\u{62b}"), (0x1eeb7, b'M', "\u{62e}"),
+    (0x1eeb8, b'M', "\u{630}"), (0x1eeb9, b'M', "\u{636}"), (0x1eeba, b'M', "\u{638}"),
+    (0x1eebb, b'M', "\u{63a}"), (0x1eebc, b'X', ""), (0x1eef0, b'V', ""), (0x1eef2, b'X', ""),
+    (0x1f000, b'V', ""), (0x1f02c, b'X', ""), (0x1f030, b'V', ""), (0x1f094, b'X', ""),
+    (0x1f0a0, b'V', ""), (0x1f0af, b'X', ""), (0x1f0b1, b'V', ""), (0x1f0c0, b'X', ""),
+    (0x1f0c1, b'V', ""), (0x1f0d0, b'X', ""), (0x1f0d1, b'V', ""), (0x1f0f6, b'X', ""),
+    (0x1f101, b'3', "0,"), (0x1f102, b'3', "1,"), (0x1f103, b'3', "2,"), (0x1f104, b'3', "3,"),
+    (0x1f105, b'3', "4,"), (0x1f106, b'3', "5,"), (0x1f107, b'3', "6,"), (0x1f108, b'3', "7,"),
+    (0x1f109, b'3', "8,"), (0x1f10a, b'3', "9,"), (0x1f10b, b'V', ""), (0x1f110, b'3', "(a)"),
+    (0x1f111, b'3', "(b)"), (0x1f112, b'3', "(c)"), (0x1f113, b'3', "(d)"), (0x1f114, b'3', "(e)"),
+    (0x1f115, b'3', "(f)"), (0x1f116, b'3', "(g)"), (0x1f117, b'3', "(h)"), (0x1f118, b'3', "(i)"),
+    (0x1f119, b'3', "(j)"), (0x1f11a, b'3', "(k)"), (0x1f11b, b'3', "(l)"), (0x1f11c, b'3', "(m)"),
+    (0x1f11d, b'3', "(n)"), (0x1f11e, b'3', "(o)"), (0x1f11f, b'3', "(p)"), (0x1f120, b'3', "(q)"),
+    (0x1f121, b'3', "(r)"), (0x1f122, b'3', "(s)"), (0x1f123, b'3', "(t)"), (0x1f124, b'3', "(u)"),
+    (0x1f125, b'3', "(v)"), (0x1f126, b'3', "(w)"), (0x1f127, b'3', "(x)"), (0x1f128, b'3', "(y)"),
+    (0x1f129, b'3', "(z)"), (0x1f12a, b'M', "\u{3014}s\u{3015}"), (0x1f12b, b'M', "c"),
+    (0x1f12c, b'M', "r"), (0x1f12d, b'M', "cd"), (0x1f12e, b'M', "wz"), (0x1f12f, b'V', ""),
+    (0x1f130, b'M', "a"), (0x1f131, b'M', "b"), (0x1f132, b'M', "c"), (0x1f133, b'M', "d"),
+    (0x1f134, b'M', "e"), (0x1f135, b'M', "f"), (0x1f136, b'M', "g"), (0x1f137, b'M', "h"),
+    (0x1f138, b'M', "i"), (0x1f139, b'M', "j"), (0x1f13a, b'M', "k"), (0x1f13b, b'M', "l"),
+    (0x1f13c, b'M', "m"), (0x1f13d, b'M', "n"), (0x1f13e, b'M', "o"), (0x1f13f, b'M', "p"),
+    (0x1f140, b'M', "q"), (0x1f141, b'M', "r"), (0x1f142, b'M', "s"), (0x1f143, b'M', "t"),
+    (0x1f144, b'M', "u"), (0x1f145, b'M', "v"), (0x1f146, b'M', "w"), (0x1f147, b'M', "x"),
+    (0x1f148, b'M', "y"), (0x1f149, b'M', "z"), (0x1f14a, b'M', "hv"), (0x1f14b, b'M', "mv"),
+    (0x1f14c, b'M', "sd"), (0x1f14d, b'M', "ss"), (0x1f14e, b'M', "ppv"), (0x1f14f, b'M', "wc"),
+    (0x1f150, b'V', ""), (0x1f16a, b'M', "mc"), (0x1f16b, b'M', "md"), (0x1f16c, b'M', "mr"),
+    (0x1f16d, b'V', ""), (0x1f190, b'M', "dj"), (0x1f191, b'V', ""), (0x1f1ae, b'X', ""),
+    (0x1f1e6, b'V', ""), (0x1f200, b'M', "\u{307b}\u{304b}"), (0x1f201, b'M', "\u{30b3}\u{30b3}"),
+    (0x1f202, b'M', "\u{30b5}"), (0x1f203, b'X', ""), (0x1f210, b'M', "\u{624b}"),
+    (0x1f211, b'M', "\u{5b57}"), (0x1f212, b'M', "\u{53cc}"), (0x1f213, b'M', "\u{30c7}"),
+    (0x1f214, b'M', "\u{4e8c}"), (0x1f215, b'M', "\u{591a}"), (0x1f216, b'M', "\u{89e3}"),
+    (0x1f217, b'M', "\u{5929}"), (0x1f218, b'M', "\u{4ea4}"), (0x1f219, b'M', "\u{6620}"),
+    (0x1f21a, b'M', "\u{7121}"), (0x1f21b, b'M', "\u{6599}"), (0x1f21c, b'M', "\u{524d}"),
+    (0x1f21d, b'M', "\u{5f8c}"), (0x1f21e, b'M', "\u{518d}"), (0x1f21f, b'M', "\u{65b0}"),
+    (0x1f220, b'M', "\u{521d}"), (0x1f221, b'M', "\u{7d42}"), (0x1f222, b'M', "\u{751f}"),
+    (0x1f223, b'M', "\u{8ca9}"), (0x1f224, b'M', "\u{58f0}"), (0x1f225, b'M', "\u{5439}"),
+    (0x1f226, b'M', "\u{6f14}"), (0x1f227, b'M', "\u{6295}"), (0x1f228, b'M', "\u{6355}"),
+    (0x1f229, b'M', "\u{4e00}"), (0x1f22a, b'M', "\u{4e09}"), (0x1f22b, b'M', "\u{904a}"),
+    (0x1f22c, b'M', "\u{5de6}"), (0x1f22d, b'M', "\u{4e2d}"), (0x1f22e, b'M', "\u{53f3}"),
+    (0x1f22f, b'M', "\u{6307}"), (0x1f230, b'M', "\u{8d70}"), (0x1f231, b'M', "\u{6253}"),
+    (0x1f232, b'M', "\u{7981}"), (0x1f233, b'M', "\u{7a7a}"), (0x1f234, b'M', "\u{5408}"),
+    (0x1f235, b'M', "\u{6e80}"), (0x1f236, b'M', "\u{6709}"), (0x1f237, b'M', "\u{6708}"),
+    (0x1f238, b'M', "\u{7533}"), (0x1f239, b'M', "\u{5272}"), (0x1f23a, b'M', "\u{55b6}"),
+    (0x1f23b, b'M', "\u{914d}"), (0x1f23c, b'X', ""), (0x1f240, b'M', "\u{3014}\u{672c}\u{3015}"),
+    (0x1f241, b'M', "\u{3014}\u{4e09}\u{3015}"), (0x1f242, b'M', "\u{3014}\u{4e8c}\u{3015}"),
+    (0x1f243, b'M', "\u{3014}\u{5b89}\u{3015}"), (0x1f244, b'M', "\u{3014}\u{70b9}\u{3015}"),
+    (0x1f245, b'M', "\u{3014}\u{6253}\u{3015}"), (0x1f246, b'M', "\u{3014}\u{76d7}\u{3015}"),
+    (0x1f247, b'M', "\u{3014}\u{52dd}\u{3015}"), (0x1f248, b'M', "\u{3014}\u{6557}\u{3015}"),
+    (0x1f249, b'X', ""), (0x1f250, b'M', "\u{5f97}"), (0x1f251, b'M', "\u{53ef}"),
+    (0x1f252, b'X', ""), (0x1f260, b'V', ""), (0x1f266, b'X', ""), (0x1f300, b'V', ""),
+    (0x1f6d8, b'X', ""), (0x1f6dc, b'V', ""), (0x1f6ed, b'X', ""), (0x1f6f0, b'V', ""),
+    (0x1f6fd, b'X', ""), (0x1f700, b'V', ""), (0x1f777, b'X', ""), (0x1f77b, b'V', ""),
+    (0x1f7da, b'X', ""), (0x1f7e0, b'V', ""), (0x1f7ec, b'X', ""), (0x1f7f0, b'V', ""),
+    (0x1f7f1, b'X', ""), (0x1f800, b'V', ""), (0x1f80c, b'X', ""), (0x1f810, b'V', ""),
+    (0x1f848, b'X', ""), (0x1f850, b'V', ""), (0x1f85a, b'X', ""), (0x1f860, b'V', ""),
+    (0x1f888, b'X', ""), (0x1f890, b'V', ""), (0x1f8ae, b'X', ""), (0x1f8b0, b'V', ""),
+    (0x1f8b2, b'X', ""), (0x1f900, b'V', ""), (0x1fa54, b'X', ""), (0x1fa60, b'V', ""),
+    (0x1fa6e, b'X', ""), (0x1fa70, b'V', ""), (0x1fa7d, b'X', ""), (0x1fa80, b'V', ""),
+    (0x1fa89, b'X', ""), (0x1fa90, b'V', ""), (0x1fabe, b'X', ""), (0x1fabf, b'V', ""),
+    (0x1fac6, b'X', ""), (0x1face, b'V', ""), (0x1fadc, b'X', ""), (0x1fae0, b'V', ""),
+    (0x1fae9, b'X', ""), (0x1faf0, b'V', ""), (0x1faf9, b'X', ""), (0x1fb00, b'V', ""),
+    (0x1fb93, b'X', ""), (0x1fb94, b'V', ""), (0x1fbcb, b'X', ""), (0x1fbf0, b'M', "0"),
+    (0x1fbf1, b'M', "1"), (0x1fbf2, b'M', "2"), (0x1fbf3, b'M', "3"), (0x1fbf4, b'M', "4"),
+    (0x1fbf5, b'M', "5"), (0x1fbf6, b'M', "6"), (0x1fbf7, b'M', "7"), (0x1fbf8, b'M', "8"),
+    (0x1fbf9, b'M', "9"), (0x1fbfa, b'X', ""), (0x20000, b'V', ""), (0x2a6e0, b'X', ""),
+    (0x2a700, b'V', ""), (0x2b73a, b'X', ""), (0x2b740, b'V', ""), (0x2b81e, b'X', ""),
+    (0x2b820, b'V', ""), (0x2cea2, b'X', ""), (0x2ceb0, b'V', ""), (0x2ebe1, b'X', ""),
+    (0x2ebf0, b'V', ""), (0x2ee5e, b'X', ""), (0x2f800, b'M', "\u{4e3d}"),
+    (0x2f801, b'M', "\u{4e38}"), (0x2f802, b'M', "\u{4e41}"), (0x2f803, b'M', "\u{20122}"),
+    (0x2f804, b'M', "\u{4f60}"), (0x2f805, b'M', "\u{4fae}"), (0x2f806, b'M', "\u{4fbb}"),
+    (0x2f807, b'M', "\u{5002}"), (0x2f808, b'M', "\u{507a}"), (0x2f809, b'M', "\u{5099}"),
+    (0x2f80a, b'M', "\u{50e7}"), (0x2f80b, b'M', "\u{50cf}"), (0x2f80c, b'M', "\u{349e}"),
+    (0x2f80d, b'M', "\u{2063a}"), (0x2f80e, b'M', "\u{514d}"), (0x2f80f, b'M', "\u{5154}"),
+    (0x2f810, b'M', "\u{5164}"), (0x2f811, b'M', "\u{5177}"), (0x2f812, b'M', "\u{2051c}"),
+    (0x2f813, b'M', "\u{34b9}"), (0x2f814, b'M', "\u{5167}"), (0x2f815, b'M', "\u{518d}"),
+    (0x2f816, b'M', "\u{2054b}"), (0x2f817, b'M', "\u{5197}"), (0x2f818, b'M', "\u{51a4}"),
+    (0x2f819, b'M', "\u{4ecc}"), (0x2f81a, b'M', "\u{51ac}"), (0x2f81b, b'M', "\u{51b5}"),
+    (0x2f81c, b'M', "\u{291df}"), (0x2f81d, b'M', "\u{51f5}"), (0x2f81e, b'M', "\u{5203}"),
+    (0x2f81f, b'M', "\u{34df}"), (0x2f820, b'M', "\u{523b}"), (0x2f821, b'M', "\u{5246}"),
+    (0x2f822, b'M', "\u{5272}"), (0x2f823, b'M', "\u{5277}"), (0x2f824, b'M', "\u{3515}"),
+    (0x2f825, b'M', "\u{52c7}"), (0x2f826, b'M', "\u{52c9}"), (0x2f827, b'M', "\u{52e4}"),
+    (0x2f828, b'M', "\u{52fa}"), (0x2f829, b'M', "\u{5305}"), (0x2f82a, b'M', "\u{5306}"),
+    (0x2f82b, b'M', "\u{5317}"), (0x2f82c, b'M', "\u{5349}"), (0x2f82d, b'M', "\u{5351}"),
+    (0x2f82e, b'M', "\u{535a}"), (0x2f82f, b'M', "\u{5373}"), (0x2f830, b'M', "\u{537d}"),
+    (0x2f831, b'M', "\u{537f}"), (0x2f834, b'M', "\u{20a2c}"), (0x2f835, b'M', "\u{7070}"),
+    (0x2f836, b'M', "\u{53ca}"), (0x2f837, b'M', "\u{53df}"), (0x2f838, b'M', "\u{20b63}"),
+    (0x2f839, b'M', "\u{53eb}"), (0x2f83a, b'M', "\u{53f1}"), (0x2f83b, b'M', "\u{5406}"),
+    (0x2f83c, b'M', "\u{549e}"), (0x2f83d, b'M', "\u{5438}"), (0x2f83e, b'M', "\u{5448}"),
+    (0x2f83f, b'M', "\u{5468}"), (0x2f840, b'M', "\u{54a2}"), (0x2f841, b'M', "\u{54f6}"),
+    (0x2f842, b'M', "\u{5510}"), (0x2f843, b'M', "\u{5553}"), (0x2f844, b'M', "\u{5563}"),
+    (0x2f845, b'M', "\u{5584}"), (0x2f847, b'M', "\u{5599}"), (0x2f848, b'M', "\u{55ab}"),
+    (0x2f849, b'M', "\u{55b3}"), (0x2f84a, b'M', "\u{55c2}"), (0x2f84b, b'M', "\u{5716}"),
+    (0x2f84c, b'M', "\u{5606}"), (0x2f84d, b'M', "\u{5717}"), (0x2f84e, b'M', "\u{5651}"),
+    (0x2f84f, b'M', "\u{5674}"), (0x2f850, b'M', "\u{5207}"), (0x2f851, b'M', "\u{58ee}"),
+    (0x2f852, b'M', "\u{57ce}"), (0x2f853, b'M', "\u{57f4}"), (0x2f854, b'M', "\u{580d}"),
+    (0x2f855, b'M', "\u{578b}"), (0x2f856, b'M', "\u{5832}"), (0x2f857, b'M', "\u{5831}"),
+    (0x2f858, b'M', "\u{58ac}"), (0x2f859, b'M', "\u{214e4}"), (0x2f85a, b'M', "\u{58f2}"),
+    (0x2f85b, b'M', "\u{58f7}"), (0x2f85c, b'M', "\u{5906}"), (0x2f85d, b'M', "\u{591a}"),
+    (0x2f85e, b'M', "\u{5922}"), (0x2f85f, b'M', "\u{5962}"), (0x2f860, b'M', "\u{216a8}"),
+    (0x2f861, b'M', "\u{216ea}"), (0x2f862, b'M', "\u{59ec}"), (0x2f863, b'M', "\u{5a1b}"),
+    (0x2f864, b'M', "\u{5a27}"), (0x2f865, b'M', "\u{59d8}"), (0x2f866, b'M', "\u{5a66}"),
+    (0x2f867, b'M', "\u{36ee}"), (0x2f868, b'X', ""), (0x2f869, b'M', "\u{5b08}"),
+    (0x2f86a, b'M', "\u{5b3e}"), (0x2f86c, b'M', "\u{219c8}"), (0x2f86d, b'M', "\u{5bc3}"),
+    (0x2f86e, b'M', "\u{5bd8}"), (0x2f86f, b'M', "\u{5be7}"), (0x2f870, b'M', "\u{5bf3}"),
+    (0x2f871, b'M', "\u{21b18}"), (0x2f872, b'M', "\u{5bff}"), (0x2f873, b'M', "\u{5c06}"),
+    (0x2f874, b'X', ""), (0x2f875, b'M', "\u{5c22}"), (0x2f876, b'M', "\u{3781}"),
+    (0x2f877, b'M', "\u{5c60}"), (0x2f878, b'M', "\u{5c6e}"), (0x2f879, b'M', "\u{5cc0}"),
+    (0x2f87a, b'M', "\u{5c8d}"), (0x2f87b, b'M', "\u{21de4}"), (0x2f87c, b'M', "\u{5d43}"),
+    (0x2f87d, b'M', "\u{21de6}"), (0x2f87e, b'M', "\u{5d6e}"), (0x2f87f, b'M', "\u{5d6b}"),
+    (0x2f880, b'M', "\u{5d7c}"), (0x2f881, b'M', "\u{5de1}"), (0x2f882, b'M', "\u{5de2}"),
+    (0x2f883, b'M', "\u{382f}"), (0x2f884, b'M', "\u{5dfd}"), (0x2f885, b'M', "\u{5e28}"),
+    (0x2f886, b'M', "\u{5e3d}"), (0x2f887, b'M', "\u{5e69}"), (0x2f888, b'M', "\u{3862}"),
+    (0x2f889, b'M', "\u{22183}"), (0x2f88a, b'M', "\u{387c}"), (0x2f88b, b'M', "\u{5eb0}"),
+    (0x2f88c, b'M', "\u{5eb3}"), (0x2f88d, b'M', "\u{5eb6}"), (0x2f88e, b'M', "\u{5eca}"),
+    (0x2f88f, b'M', "\u{2a392}"), (0x2f890, b'M', "\u{5efe}"), (0x2f891, b'M', "\u{22331}"),
+    (0x2f893, b'M', "\u{8201}"), (0x2f894, b'M', "\u{5f22}"), (0x2f896, b'M', "\u{38c7}"),
+    (0x2f897, b'M', "\u{232b8}"), (0x2f898, b'M', "\u{261da}"), (0x2f899, b'M', "\u{5f62}"),
+    (0x2f89a, b'M', "\u{5f6b}"), (0x2f89b, b'M', "\u{38e3}"), (0x2f89c, b'M', "\u{5f9a}"),
+    (0x2f89d, b'M', "\u{5fcd}"), (0x2f89e, b'M', "\u{5fd7}"), (0x2f89f, b'M', "\u{5ff9}"),
+    (0x2f8a0, b'M', "\u{6081}"), (0x2f8a1, b'M', "\u{393a}"), (0x2f8a2, b'M', "\u{391c}"),
+    (0x2f8a3, b'M', "\u{6094}"), (0x2f8a4, b'M', "\u{226d4}"), (0x2f8a5, b'M', "\u{60c7}"),
+    (0x2f8a6, b'M', "\u{6148}"), (0x2f8a7, b'M', "\u{614c}"), (0x2f8a8, b'M', "\u{614e}"),
+    (0x2f8a9, b'M', "\u{614c}"), (0x2f8aa, b'M', "\u{617a}"), (0x2f8ab, b'M', "\u{618e}"),
+    (0x2f8ac, b'M', "\u{61b2}"), (0x2f8ad, b'M', "\u{61a4}"), (0x2f8ae, b'M', "\u{61af}"),
+    (0x2f8af, b'M', "\u{61de}"), (0x2f8b0, b'M', "\u{61f2}"), (0x2f8b1, b'M', "\u{61f6}"),
+    (0x2f8b2, b'M', "\u{6210}"), (0x2f8b3, b'M', "\u{621b}"), (0x2f8b4, b'M', "\u{625d}"),
+    (0x2f8b5, b'M', "\u{62b1}"), (0x2f8b6, b'M', "\u{62d4}"), (0x2f8b7, b'M', "\u{6350}"),
+    (0x2f8b8, b'M', "\u{22b0c}"), (0x2f8b9, b'M', "\u{633d}"), (0x2f8ba, b'M', "\u{62fc}"),
+    (0x2f8bb, b'M', "\u{6368}"), (0x2f8bc, b'M', "\u{6383}"), (0x2f8bd, b'M', "\u{63e4}"),
+    (0x2f8be, b'M', "\u{22bf1}"), (0x2f8bf, b'M', "\u{6422}"), (0x2f8c0, b'M', "\u{63c5}"),
+    (0x2f8c1, b'M', "\u{63a9}"), (0x2f8c2, b'M', "\u{3a2e}"), (0x2f8c3, b'M', "\u{6469}"),
+    (0x2f8c4, b'M', "\u{647e}"), (0x2f8c5, b'M', "\u{649d}"), (0x2f8c6, b'M', "\u{6477}"),
+    (0x2f8c7, b'M', "\u{3a6c}"), (0x2f8c8, b'M', "\u{654f}"), (0x2f8c9, b'M', "\u{656c}"),
+    (0x2f8ca, b'M', "\u{2300a}"), (0x2f8cb, b'M', "\u{65e3}"), (0x2f8cc, b'M', "\u{66f8}"),
+    (0x2f8cd, b'M', "\u{6649}"), (0x2f8ce, b'M', "\u{3b19}"), (0x2f8cf, b'M', "\u{6691}"),
+    (0x2f8d0, b'M', "\u{3b08}"), (0x2f8d1, b'M', "\u{3ae4}"), (0x2f8d2, b'M', "\u{5192}"),
+    (0x2f8d3, b'M', "\u{5195}"), (0x2f8d4, b'M', "\u{6700}"), (0x2f8d5, b'M', "\u{669c}"),
+    (0x2f8d6, b'M', "\u{80ad}"), (0x2f8d7, b'M', "\u{43d9}"), (0x2f8d8, b'M', "\u{6717}"),
+    (0x2f8d9, b'M', "\u{671b}"), (0x2f8da, b'M', "\u{6721}"), (0x2f8db, b'M', "\u{675e}"),
+    (0x2f8dc, b'M', "\u{6753}"), (0x2f8dd, b'M', "\u{233c3}"), (0x2f8de, b'M', "\u{3b49}"),
+    (0x2f8df, b'M', "\u{67fa}"), (0x2f8e0, b'M', "\u{6785}"), (0x2f8e1, b'M', "\u{6852}"),
+    (0x2f8e2, b'M', "\u{6885}"), (0x2f8e3, b'M', "\u{2346d}"), (0x2f8e4, b'M', "\u{688e}"),
+    (0x2f8e5, b'M', "\u{681f}"), (0x2f8e6, b'M', "\u{6914}"), (0x2f8e7, b'M', "\u{3b9d}"),
+    (0x2f8e8, b'M', "\u{6942}"), (0x2f8e9, b'M', "\u{69a3}"), (0x2f8ea, b'M', "\u{69ea}"),
+    (0x2f8eb, b'M', "\u{6aa8}"), (0x2f8ec, b'M', "\u{236a3}"), (0x2f8ed, b'M', "\u{6adb}"),
+    (0x2f8ee, b'M', "\u{3c18}"), (0x2f8ef, b'M', "\u{6b21}"), (0x2f8f0, b'M', "\u{238a7}"),
+    (0x2f8f1, b'M', "\u{6b54}"), (0x2f8f2, b'M', "\u{3c4e}"), (0x2f8f3, b'M', "\u{6b72}"),
+    (0x2f8f4, b'M', "\u{6b9f}"), (0x2f8f5, b'M', "\u{6bba}"), (0x2f8f6, b'M', "\u{6bbb}"),
+    (0x2f8f7, b'M', "\u{23a8d}"), (0x2f8f8, b'M', "\u{21d0b}"), (0x2f8f9, b'M', "\u{23afa}"),
+    (0x2f8fa, b'M', "\u{6c4e}"), (0x2f8fb, b'M', "\u{23cbc}"), (0x2f8fc, b'M', "\u{6cbf}"),
+    (0x2f8fd, b'M', "\u{6ccd}"), (0x2f8fe, b'M', "\u{6c67}"), (0x2f8ff, b'M', "\u{6d16}"),
+    (0x2f900, b'M', "\u{6d3e}"), (0x2f901, b'M', "\u{6d77}"), (0x2f902, b'M', "\u{6d41}"),
+    (0x2f903, b'M', "\u{6d69}"), (0x2f904, b'M', "\u{6d78}"), (0x2f905, b'M', "\u{6d85}"),
+    (0x2f906, b'M', "\u{23d1e}"), (0x2f907, b'M', "\u{6d34}"), (0x2f908, b'M', "\u{6e2f}"),
+    (0x2f909, b'M', "\u{6e6e}"), (0x2f90a, b'M', "\u{3d33}"), (0x2f90b, b'M', "\u{6ecb}"),
+    (0x2f90c, b'M', "\u{6ec7}"), (0x2f90d, b'M', "\u{23ed1}"), (0x2f90e, b'M', "\u{6df9}"),
+    (0x2f90f, b'M', "\u{6f6e}"), (0x2f910, b'M', "\u{23f5e}"), (0x2f911, b'M', "\u{23f8e}"),
+    (0x2f912, b'M', "\u{6fc6}"), (0x2f913, b'M', "\u{7039}"), (0x2f914, b'M', "\u{701e}"),
+    (0x2f915, b'M', "\u{701b}"), (0x2f916, b'M', "\u{3d96}"), (0x2f917, b'M', "\u{704a}"),
+    (0x2f918, b'M', "\u{707d}"), (0x2f919, b'M', "\u{7077}"), (0x2f91a, b'M', "\u{70ad}"),
+    (0x2f91b, b'M', "\u{20525}"), (0x2f91c, b'M', "\u{7145}"), (0x2f91d, b'M', "\u{24263}"),
+    (0x2f91e, b'M', "\u{719c}"), (0x2f91f, b'X', ""), (0x2f920, b'M', "\u{7228}"),
+    (0x2f921, b'M', "\u{7235}"), (0x2f922, b'M', "\u{7250}"), (0x2f923, b'M', "\u{24608}"),
+    (0x2f924, b'M', "\u{7280}"), (0x2f925, b'M', "\u{7295}"), (0x2f926, b'M', "\u{24735}"),
+    (0x2f927, b'M', "\u{24814}"), (0x2f928, b'M', "\u{737a}"), (0x2f929, b'M', "\u{738b}"),
+    (0x2f92a, b'M', "\u{3eac}"), (0x2f92b, b'M', "\u{73a5}"), (0x2f92c, b'M', "\u{3eb8}"),
+    (0x2f92e, b'M', "\u{7447}"), (0x2f92f, b'M', "\u{745c}"), (0x2f930, b'M', "\u{7471}"),
+    (0x2f931, b'M', "\u{7485}"), (0x2f932, b'M', "\u{74ca}"), (0x2f933, b'M', "\u{3f1b}"),
+    (0x2f934, b'M', "\u{7524}"), (0x2f935, b'M', "\u{24c36}"), (0x2f936, b'M', "\u{753e}"),
+    (0x2f937, b'M', "\u{24c92}"), (0x2f938, b'M', "\u{7570}"), (0x2f939, b'M', "\u{2219f}"),
+    (0x2f93a, b'M', "\u{7610}"), (0x2f93b, b'M', "\u{24fa1}"), (0x2f93c, b'M', "\u{24fb8}"),
+    (0x2f93d, b'M', "\u{25044}"), (0x2f93e, b'M', "\u{3ffc}"), (0x2f93f, b'M', "\u{4008}"),
+    (0x2f940, b'M', "\u{76f4}"), (0x2f941, b'M', "\u{250f3}"), (0x2f942, b'M', "\u{250f2}"),
+    (0x2f943, b'M', "\u{25119}"), (0x2f944, b'M', "\u{25133}"), (0x2f945, b'M', "\u{771e}"),
+    (0x2f946, b'M', "\u{771f}"), (0x2f948, b'M', "\u{774a}"), (0x2f949, b'M', "\u{4039}"),
+    (0x2f94a, b'M', "\u{778b}"), (0x2f94b, b'M', "\u{4046}"), (0x2f94c, b'M', "\u{4096}"),
+    (0x2f94d, b'M', "\u{2541d}"), (0x2f94e, b'M', "\u{784e}"), (0x2f94f, b'M', "\u{788c}"),
+    (0x2f950, b'M', "\u{78cc}"), (0x2f951, b'M', "\u{40e3}"), (0x2f952, b'M', "\u{25626}"),
+    (0x2f953, b'M', "\u{7956}"), (0x2f954, b'M', "\u{2569a}"), (0x2f955, b'M', "\u{256c5}"),
+    (0x2f956, b'M', "\u{798f}"), (0x2f957, b'M', "\u{79eb}"), (0x2f958, b'M', "\u{412f}"),
+    (0x2f959, b'M', "\u{7a40}"), (0x2f95a, b'M', "\u{7a4a}"), (0x2f95b, b'M', "\u{7a4f}"),
+    (0x2f95c, b'M', "\u{2597c}"), (0x2f95d, b'M', "\u{25aa7}"), (0x2f95f, b'X', ""),
+    (0x2f960, b'M', "\u{4202}"), (0x2f961, b'M', "\u{25bab}"), (0x2f962, b'M', "\u{7bc6}"),
+    (0x2f963, b'M', "\u{7bc9}"), (0x2f964, b'M', "\u{4227}"), (0x2f965, b'M', "\u{25c80}"),
+    (0x2f966, b'M', "\u{7cd2}"), (0x2f967, b'M', "\u{42a0}"), (0x2f968, b'M', "\u{7ce8}"),
+    (0x2f969, b'M', "\u{7ce3}"), (0x2f96a, b'M', "\u{7d00}"), (0x2f96b, b'M', "\u{25f86}"),
+    (0x2f96c, b'M', "\u{7d63}"), (0x2f96d, b'M', "\u{4301}"), (0x2f96e, b'M', "\u{7dc7}"),
+    (0x2f96f, b'M', "\u{7e02}"), (0x2f970, b'M', "\u{7e45}"), (0x2f971, b'M', "\u{4334}"),
+    (0x2f972, b'M', "\u{26228}"), (0x2f973, b'M', "\u{26247}"), (0x2f974, b'M', "\u{4359}"),
+    (0x2f975, b'M', "\u{262d9}"), (0x2f976, b'M', "\u{7f7a}"), (0x2f977, b'M', "\u{2633e}"),
+    (0x2f978, b'M', "\u{7f95}"), (0x2f979, b'M', "\u{7ffa}"), (0x2f97a, b'M', "\u{8005}"),
+    (0x2f97b, b'M', "\u{264da}"), (0x2f97c, b'M', "\u{26523}"), (0x2f97d, b'M', "\u{8060}"),
+    (0x2f97e, b'M', "\u{265a8}"), (0x2f97f, b'M', "\u{8070}"), (0x2f980, b'M', "\u{2335f}"),
+    (0x2f981, b'M', "\u{43d5}"), (0x2f982, b'M', "\u{80b2}"), (0x2f983, b'M', "\u{8103}"),
+    (0x2f984, b'M', "\u{440b}"), (0x2f985, b'M', "\u{813e}"), (0x2f986, b'M', "\u{5ab5}"),
+    (0x2f987, b'M', "\u{267a7}"), (0x2f988, b'M', "\u{267b5}"), (0x2f989, b'M', "\u{23393}"),
+    (0x2f98a, b'M', "\u{2339c}"), (0x2f98b, b'M', "\u{8201}"), (0x2f98c, b'M', "\u{8204}"),
+    (0x2f98d, b'M', "\u{8f9e}"), (0x2f98e, b'M', "\u{446b}"), (0x2f98f, b'M', "\u{8291}"),
+    (0x2f990, b'M', "\u{828b}"), (0x2f991, b'M', "\u{829d}"), (0x2f992, b'M', "\u{52b3}"),
+    (0x2f993, b'M', "\u{82b1}"), (0x2f994, b'M', "\u{82b3}"), (0x2f995, b'M', "\u{82bd}"),
+    (0x2f996, b'M', "\u{82e6}"), (0x2f997, b'M', "\u{26b3c}"), (0x2f998, b'M', "\u{82e5}"),
+    (0x2f999, b'M', "\u{831d}"), (0x2f99a, b'M', "\u{8363}"), (0x2f99b, b'M', "\u{83ad}"),
+    (0x2f99c, b'M', "\u{8323}"), (0x2f99d, b'M', "\u{83bd}"), (0x2f99e, b'M', "\u{83e7}"),
+    (0x2f99f, b'M', "\u{8457}"), (0x2f9a0, b'M', "\u{8353}"), (0x2f9a1, b'M', "\u{83ca}"),
+    (0x2f9a2, b'M', "\u{83cc}"), (0x2f9a3, b'M', "\u{83dc}"), (0x2f9a4, b'M', "\u{26c36}"),
+    (0x2f9a5, b'M', "\u{26d6b}"), (0x2f9a6, b'M', "\u{26cd5}"), (0x2f9a7, b'M', "\u{452b}"),
+    (0x2f9a8, b'M', "\u{84f1}"), (0x2f9a9, b'M', "\u{84f3}"), (0x2f9aa, b'M', "\u{8516}"),
+    (0x2f9ab, b'M', "\u{273ca}"), (0x2f9ac, b'M', "\u{8564}"), (0x2f9ad, b'M', "\u{26f2c}"),
+    (0x2f9ae, b'M', "\u{455d}"), (0x2f9af, b'M', "\u{4561}"), (0x2f9b0, b'M', "\u{26fb1}"),
+    (0x2f9b1, b'M', "\u{270d2}"), (0x2f9b2, b'M', "\u{456b}"), (0x2f9b3, b'M', "\u{8650}"),
+    (0x2f9b4, b'M', "\u{865c}"), (0x2f9b5, b'M', "\u{8667}"), (0x2f9b6, b'M', "\u{8669}"),
+    (0x2f9b7, b'M', "\u{86a9}"), (0x2f9b8, b'M', "\u{8688}"), (0x2f9b9, b'M', "\u{870e}"),
+    (0x2f9ba, b'M', "\u{86e2}"), (0x2f9bb, b'M', "\u{8779}"), (0x2f9bc, b'M', "\u{8728}"),
+    (0x2f9bd, b'M', "\u{876b}"), (0x2f9be, b'M', "\u{8786}"), (0x2f9bf, b'X', ""),
+    (0x2f9c0, b'M', "\u{87e1}"), (0x2f9c1, b'M', "\u{8801}"), (0x2f9c2, b'M', "\u{45f9}"),
+    (0x2f9c3, b'M', "\u{8860}"), (0x2f9c4, b'M', "\u{8863}"), (0x2f9c5, b'M', "\u{27667}"),
+    (0x2f9c6, b'M', "\u{88d7}"), (0x2f9c7, b'M', "\u{88de}"), (0x2f9c8, b'M', "\u{4635}"),
+    (0x2f9c9, b'M', "\u{88fa}"), (0x2f9ca, b'M', "\u{34bb}"), (0x2f9cb, b'M', "\u{278ae}"),
+    (0x2f9cc, b'M', "\u{27966}"), (0x2f9cd, b'M', "\u{46be}"), (0x2f9ce, b'M', "\u{46c7}"),
+    (0x2f9cf, b'M', "\u{8aa0}"), (0x2f9d0, b'M', "\u{8aed}"), (0x2f9d1, b'M', "\u{8b8a}"),
+    (0x2f9d2, b'M', "\u{8c55}"), (0x2f9d3, b'M', "\u{27ca8}"), (0x2f9d4, b'M', "\u{8cab}"),
+    (0x2f9d5, b'M', "\u{8cc1}"), (0x2f9d6, b'M', "\u{8d1b}"), (0x2f9d7, b'M', "\u{8d77}"),
+    (0x2f9d8, b'M', "\u{27f2f}"), (0x2f9d9, b'M', "\u{20804}"), (0x2f9da, b'M', "\u{8dcb}"),
+    (0x2f9db, b'M', "\u{8dbc}"), (0x2f9dc, b'M', "\u{8df0}"), (0x2f9dd, b'M', "\u{208de}"),
+    (0x2f9de, b'M', "\u{8ed4}"), (0x2f9df, b'M', "\u{8f38}"), (0x2f9e0, b'M', "\u{285d2}"),
+    (0x2f9e1, b'M', "\u{285ed}"), (0x2f9e2, b'M', "\u{9094}"), (0x2f9e3, b'M', "\u{90f1}"),
+    (0x2f9e4, b'M', "\u{9111}"), (0x2f9e5, b'M', "\u{2872e}"), (0x2f9e6, b'M', "\u{911b}"),
+    (0x2f9e7, b'M', "\u{9238}"), (0x2f9e8, b'M', "\u{92d7}"), (0x2f9e9, b'M', "\u{92d8}"),
+    (0x2f9ea, b'M', "\u{927c}"), (0x2f9eb, b'M', "\u{93f9}"), (0x2f9ec, b'M', "\u{9415}"),
+    (0x2f9ed, b'M', "\u{28bfa}"), (0x2f9ee, b'M', "\u{958b}"), (0x2f9ef, b'M', "\u{4995}"),
+    (0x2f9f0, b'M', "\u{95b7}"), (0x2f9f1, b'M', "\u{28d77}"), (0x2f9f2, b'M', "\u{49e6}"),
+    (0x2f9f3, b'M', "\u{96c3}"), (0x2f9f4, b'M', "\u{5db2}"), (0x2f9f5, b'M', "\u{9723}"),
+    (0x2f9f6, b'M', "\u{29145}"), (0x2f9f7, b'M', "\u{2921a}"), (0x2f9f8, b'M', "\u{4a6e}"),
+    (0x2f9f9, b'M', "\u{4a76}"), (0x2f9fa, b'M', "\u{97e0}"), (0x2f9fb, b'M', "\u{2940a}"),
+    (0x2f9fc, b'M', "\u{4ab2}"), (0x2f9fd, b'M', "\u{29496}"), (0x2f9fe, b'M', "\u{980b}"),
+    (0x2fa00, b'M', "\u{9829}"), (0x2fa01, b'M', "\u{295b6}"), (0x2fa02, b'M', "\u{98e2}"),
+    (0x2fa03, b'M', "\u{4b33}"), (0x2fa04, b'M', "\u{9929}"), (0x2fa05, b'M', "\u{99a7}"),
+    (0x2fa06, b'M', "\u{99c2}"), (0x2fa07, b'M', "\u{99fe}"), (0x2fa08, b'M', "\u{4bce}"),
+    (0x2fa09, b'M', "\u{29b30}"), (0x2fa0a, b'M', "\u{9b12}"), (0x2fa0b, b'M', "\u{9c40}"),
+    (0x2fa0c, b'M', "\u{9cfd}"), (0x2fa0d, b'M', "\u{4cce}"), (0x2fa0e, b'M', "\u{4ced}"),
+    (0x2fa0f, b'M', "\u{9d67}"), (0x2fa10, b'M', "\u{2a0ce}"), (0x2fa11, b'M', "\u{4cf8}"),
+    (0x2fa12, b'M', "\u{2a105}"), (0x2fa13, b'M', "\u{2a20e}"), (0x2fa14, b'M', "\u{2a291}"),
+    (0x2fa15, b'M', "\u{9ebb}"), (0x2fa16, b'M', "\u{4d56}"), (0x2fa17, b'M', "\u{9ef9}"),
+    (0x2fa18, b'M', "\u{9efe}"), (0x2fa19, b'M', "\u{9f05}"), (0x2fa1a, b'M', "\u{9f0f}"),
+    (0x2fa1b, b'M', "\u{9f16}"), (0x2fa1c, b'M', "\u{9f3b}"), (0x2fa1d, b'M', "\u{2a600}"),
+    (0x2fa1e, b'X', ""), (0x30000, b'V', ""), (0x3134b, b'X', ""), (0x31350, b'V', ""),
+    (0x323b0, b'X', ""), (0xe0100, b'I', ""), (0xe01f0, b'X', ""),
+];
+
+/// The code points in PVALID under IDNA 2008 (RFC 5892 §2).
+pub static PVALID: &'static [(u32, u32)] = &[
+    (0x2d, 0x2d), (0x30, 0x39), (0x61, 0x7a), (0xdf, 0xf6), (0xf8, 0xff), (0x101, 0x101),
+    (0x103, 0x103), (0x105, 0x105), (0x107, 0x107), (0x109, 0x109), (0x10b, 0x10b), (0x10d, 0x10d),
+    (0x10f, 0x10f), (0x111, 0x111), (0x113, 0x113), (0x115, 0x115), (0x117, 0x117), (0x119, 0x119),
+    (0x11b, 0x11b), (0x11d, 0x11d), (0x11f, 0x11f), (0x121, 0x121), (0x123, 0x123), (0x125, 0x125),
+    (0x127, 0x127), (0x129, 0x129), (0x12b, 0x12b), (0x12d, 0x12d), (0x12f, 0x12f), (0x131, 0x131),
+    (0x135, 0x135), (0x137, 0x138), (0x13a, 0x13a), (0x13c, 0x13c), (0x13e, 0x13e), (0x142, 0x142),
+    (0x144, 0x144), (0x146, 0x146), (0x148, 0x148), (0x14b, 0x14b), (0x14d, 0x14d), (0x14f, 0x14f),
+    (0x151, 0x151), (0x153, 0x153), (0x155, 0x155), (0x157, 0x157), (0x159, 0x159), (0x15b, 0x15b),
+    (0x15d, 0x15d), (0x15f, 0x15f), (0x161, 0x161), (0x163, 0x163), (0x165, 0x165), (0x167, 0x167),
+    (0x169, 0x169), (0x16b, 0x16b), (0x16d, 0x16d), (0x16f, 0x16f), (0x171, 0x171), (0x173, 0x173),
+    (0x175, 0x175), (0x177, 0x177), (0x17a, 0x17a), (0x17c, 0x17c), (0x17e, 0x17e), (0x180, 0x180),
+    (0x183, 0x183), (0x185, 0x185), (0x188, 0x188), (0x18c, 0x18d), (0x192, 0x192), (0x195, 0x195),
+    (0x199, 0x19b), (0x19e, 0x19e), (0x1a1, 0x1a1), (0x1a3, 0x1a3), (0x1a5, 0x1a5), (0x1a8, 0x1a8),
+    (0x1aa, 0x1ab), (0x1ad, 0x1ad), (0x1b0, 0x1b0), (0x1b4, 0x1b4), (0x1b6, 0x1b6), (0x1b9, 0x1bb),
+    (0x1bd, 0x1c3), (0x1ce, 0x1ce), (0x1d0, 0x1d0), (0x1d2, 0x1d2), (0x1d4, 0x1d4), (0x1d6, 0x1d6),
+    (0x1d8, 0x1d8), (0x1da, 0x1da), (0x1dc, 0x1dd), (0x1df, 0x1df), (0x1e1, 0x1e1), (0x1e3, 0x1e3),
+    (0x1e5, 0x1e5), (0x1e7, 0x1e7), (0x1e9, 0x1e9), (0x1eb, 0x1eb), (0x1ed, 0x1ed), (0x1ef, 0x1f0),
+    (0x1f5, 0x1f5), (0x1f9, 0x1f9), (0x1fb, 0x1fb), (0x1fd, 0x1fd), (0x1ff, 0x1ff), (0x201, 0x201),
+    (0x203, 0x203), (0x205, 0x205), (0x207, 0x207), (0x209, 0x209), (0x20b, 0x20b), (0x20d, 0x20d),
+    (0x20f, 0x20f), (0x211, 0x211), (0x213, 0x213), (0x215, 0x215), (0x217, 0x217), (0x219, 0x219),
+    (0x21b, 0x21b), (0x21d, 0x21d), (0x21f, 0x21f), (0x221, 0x221), (0x223, 0x223), (0x225, 0x225),
+    (0x227, 0x227), (0x229, 0x229), (0x22b, 0x22b), (0x22d, 0x22d), (0x22f, 0x22f), (0x231, 0x231),
+    (0x233, 0x239), (0x23c, 0x23c), (0x23f, 0x240), (0x242, 0x242), (0x247, 0x247), (0x249, 0x249),
+    (0x24b, 0x24b), (0x24d, 0x24d), (0x24f, 0x2af), (0x2b9, 0x2c1), (0x2c6, 0x2d1), (0x2ec, 0x2ec),
+    (0x2ee, 0x2ee), (0x300, 0x33f), (0x342, 0x342), (0x346, 0x34e), (0x350, 0x36f), (0x371, 0x371),
+    (0x373, 0x373), (0x377, 0x377), (0x37b, 0x37d), (0x390, 0x390), (0x3ac, 0x3ce), (0x3d7, 0x3d7),
+    (0x3d9, 0x3d9), (0x3db, 0x3db), (0x3dd, 0x3dd), (0x3df, 0x3df), (0x3e1, 0x3e1), (0x3e3, 0x3e3),
+    (0x3e5, 0x3e5), (0x3e7, 0x3e7), (0x3e9, 0x3e9), (0x3eb, 0x3eb), (0x3ed, 0x3ed), (0x3ef, 0x3ef),
+    (0x3f3, 0x3f3), (0x3f8, 0x3f8), (0x3fb, 0x3fc), (0x430, 0x45f), (0x461, 0x461), (0x463, 0x463),
+    (0x465, 0x465), (0x467, 0x467), (0x469, 0x469), (0x46b, 0x46b), (0x46d, 0x46d), (0x46f, 0x46f),
+    (0x471, 0x471), (0x473, 0x473), (0x475, 0x475), (0x477, 0x477), (0x479, 0x479), (0x47b, 0x47b),
+    (0x47d, 0x47d), (0x47f, 0x47f), (0x481, 0x481), (0x483, 0x487), (0x48b, 0x48b), (0x48d, 0x48d),
+    (0x48f, 0x48f), (0x491, 0x491), (0x493, 0x493), (0x495, 0x495), (0x497, 0x497), (0x499, 0x499),
+    (0x49b, 0x49b), (0x49d, 0x49d), (0x49f, 0x49f), (0x4a1, 0x4a1), (0x4a3, 0x4a3), (0x4a5, 0x4a5),
+    (0x4a7, 0x4a7), (0x4a9, 0x4a9), (0x4ab, 0x4ab), (0x4ad, 0x4ad), (0x4af, 0x4af), (0x4b1, 0x4b1),
+    (0x4b3, 0x4b3), (0x4b5, 0x4b5), (0x4b7, 0x4b7), (0x4b9, 0x4b9), (0x4bb, 0x4bb), (0x4bd, 0x4bd),
+    (0x4bf, 0x4bf), (0x4c2, 0x4c2), (0x4c4, 0x4c4), (0x4c6, 0x4c6), (0x4c8, 0x4c8), (0x4ca, 0x4ca),
+    (0x4cc, 0x4cc), (0x4ce, 0x4cf), (0x4d1, 0x4d1), (0x4d3, 0x4d3), (0x4d5, 0x4d5), (0x4d7, 0x4d7),
+    (0x4d9, 0x4d9), (0x4db, 0x4db), (0x4dd, 0x4dd), (0x4df, 0x4df), (0x4e1, 0x4e1), (0x4e3, 0x4e3),
+    (0x4e5, 0x4e5), (0x4e7, 0x4e7), (0x4e9, 0x4e9), (0x4eb, 0x4eb), (0x4ed, 0x4ed), (0x4ef, 0x4ef),
+    (0x4f1, 0x4f1), (0x4f3, 0x4f3), (0x4f5, 0x4f5), (0x4f7, 0x4f7), (0x4f9, 0x4f9), (0x4fb, 0x4fb),
+    (0x4fd, 0x4fd), (0x4ff, 0x4ff), (0x501, 0x501), (0x503, 0x503), (0x505, 0x505), (0x507, 0x507),
+    (0x509, 0x509), (0x50b, 0x50b), (0x50d, 0x50d), (0x50f, 0x50f), (0x511, 0x511), (0x513, 0x513),
+    (0x515, 0x515), (0x517, 0x517), (0x519, 0x519), (0x51b, 0x51b), (0x51d, 0x51d), (0x51f, 0x51f),
+    (0x521, 0x521), (0x523, 0x523), (0x525, 0x525), (0x527, 0x527), (0x529, 0x529), (0x52b, 0x52b),
+    (0x52d, 0x52d), (0x52f, 0x52f), (0x559, 0x559), (0x560, 0x586), (0x588, 0x588), (0x591, 0x5bd),
+    (0x5bf, 0x5bf), (0x5c1, 0x5c2), (0x5c4, 0x5c5), (0x5c7, 0x5c7), (0x5d0, 0x5ea), (0x5ef, 0x5f2),
+    (0x610, 0x61a), (0x620, 0x63f), (0x641, 0x65f), (0x66e, 0x674), (0x679, 0x6d3), (0x6d5, 0x6dc),
+    (0x6df, 0x6e8), (0x6ea, 0x6ef), (0x6fa, 0x6ff), (0x710, 0x74a), (0x74d, 0x7b1), (0x7c0, 0x7f5),
+    (0x7fd, 0x7fd), (0x800, 0x82d), (0x840, 0x85b), (0x860, 0x86a), (0x870, 0x887), (0x889, 0x88e),
+    (0x898, 0x8e1), (0x8e3, 0x957), (0x960, 0x963), (0x966, 0x96f), (0x971, 0x983), (0x985, 0x98c),
+    (0x98f, 0x990), (0x993, 0x9a8), (0x9aa, 0x9b0), (0x9b2, 0x9b2), (0x9b6, 0x9b9), (0x9bc, 0x9c4),
+    (0x9c7, 0x9c8), (0x9cb, 0x9ce), (0x9d7, 0x9d7), (0x9e0, 0x9e3), (0x9e6, 0x9f1), (0x9fc, 0x9fc),
+    (0x9fe, 0x9fe), (0xa01, 0xa03), (0xa05, 0xa0a), (0xa0f, 0xa10), (0xa13, 0xa28), (0xa2a, 0xa30),
+    (0xa32, 0xa32), (0xa35, 0xa35), (0xa38, 0xa39), (0xa3c, 0xa3c), (0xa3e, 0xa42), (0xa47, 0xa48),
+    (0xa4b, 0xa4d), (0xa51, 0xa51), (0xa5c, 0xa5c), (0xa66, 0xa75), (0xa81, 0xa83), (0xa85, 0xa8d),
+    (0xa8f, 0xa91), (0xa93, 0xaa8), (0xaaa, 0xab0), (0xab2, 0xab3), (0xab5, 0xab9), (0xabc, 0xac5),
+    (0xac7, 0xac9), (0xacb, 0xacd), (0xad0, 0xad0), (0xae0, 0xae3), (0xae6, 0xaef), (0xaf9, 0xaff),
+    (0xb01, 0xb03), (0xb05, 0xb0c), (0xb0f, 0xb10), (0xb13, 0xb28), (0xb2a, 0xb30), (0xb32, 0xb33),
+    (0xb35, 0xb39), (0xb3c, 0xb44), (0xb47, 0xb48), (0xb4b, 0xb4d), (0xb55, 0xb57), (0xb5f, 0xb63),
+    (0xb66, 0xb6f), (0xb71, 0xb71), (0xb82, 0xb83), (0xb85, 0xb8a), (0xb8e, 0xb90), (0xb92, 0xb95),
+    (0xb99, 0xb9a), (0xb9c, 0xb9c), (0xb9e, 0xb9f), (0xba3, 0xba4), (0xba8, 0xbaa), (0xbae, 0xbb9),
+    (0xbbe, 0xbc2), (0xbc6, 0xbc8), (0xbca, 0xbcd), (0xbd0, 0xbd0), (0xbd7, 0xbd7), (0xbe6, 0xbef),
+    (0xc00, 0xc0c), (0xc0e, 0xc10), (0xc12, 0xc28), (0xc2a, 0xc39), (0xc3c, 0xc44), (0xc46, 0xc48),
+    (0xc4a, 0xc4d), (0xc55, 0xc56), (0xc58, 0xc5a), (0xc5d, 0xc5d), (0xc60, 0xc63), (0xc66, 0xc6f),
+    (0xc80, 0xc83), (0xc85, 0xc8c), (0xc8e, 0xc90), (0xc92, 0xca8), (0xcaa, 0xcb3), (0xcb5, 0xcb9),
+    (0xcbc, 0xcc4), (0xcc6, 0xcc8), (0xcca, 0xccd), (0xcd5, 0xcd6), (0xcdd, 0xcde), (0xce0, 0xce3),
+    (0xce6, 0xcef), (0xcf1, 0xcf3), (0xd00, 0xd0c), (0xd0e, 0xd10), (0xd12, 0xd44), (0xd46, 0xd48),
+    (0xd4a, 0xd4e), (0xd54, 0xd57), (0xd5f, 0xd63), (0xd66, 0xd6f), (0xd7a, 0xd7f), (0xd81, 0xd83),
+    (0xd85, 0xd96), (0xd9a, 0xdb1), (0xdb3, 0xdbb), (0xdbd, 0xdbd), (0xdc0, 0xdc6), (0xdca, 0xdca),
+    (0xdcf, 0xdd4), (0xdd6, 0xdd6), (0xdd8, 0xddf), (0xde6, 0xdef), (0xdf2, 0xdf3), (0xe01, 0xe32),
+    (0xe34, 0xe3a), (0xe40, 0xe4e), (0xe50, 0xe59), (0xe81, 0xe82), (0xe84, 0xe84), (0xe86, 0xe8a),
+    (0xe8c, 0xea3), (0xea5, 0xea5), (0xea7, 0xeb2), (0xeb4, 0xebd), (0xec0, 0xec4), (0xec6, 0xec6),
+    (0xec8, 0xece), (0xed0, 0xed9), (0xede, 0xedf), (0xf00, 0xf00), (0xf0b, 0xf0b), (0xf18, 0xf19),
+    (0xf20, 0xf29), (0xf35, 0xf35), (0xf37, 0xf37), (0xf39, 0xf39), (0xf3e, 0xf42), (0xf44, 0xf47),
+    (0xf49, 0xf4c), (0xf4e, 0xf51), (0xf53, 0xf56), (0xf58, 0xf5b), (0xf5d, 0xf68), (0xf6a, 0xf6c),
+    (0xf71, 0xf72), (0xf74, 0xf74), (0xf7a, 0xf80), (0xf82, 0xf84), (0xf86, 0xf92), (0xf94, 0xf97),
+    (0xf99, 0xf9c), (0xf9e, 0xfa1), (0xfa3, 0xfa6), (0xfa8, 0xfab), (0xfad, 0xfb8), (0xfba, 0xfbc),
+    (0xfc6, 0xfc6), (0x1000, 0x1049), (0x1050, 0x109d), (0x10d0, 0x10fa), (0x10fd, 0x10ff),
+    (0x1200, 0x1248), (0x124a, 0x124d), (0x1250, 0x1256), (0x1258, 0x1258), (0x125a, 0x125d),
+    (0x1260, 0x1288), (0x128a, 0x128d), (0x1290, 0x12b0), (0x12b2, 0x12b5), (0x12b8, 0x12be),
+    (0x12c0, 0x12c0), (0x12c2, 0x12c5), (0x12c8, 0x12d6), (0x12d8, 0x1310), (0x1312, 0x1315),
+    (0x1318, 0x135a), (0x135d, 0x135f), (0x1380, 0x138f), (0x13a0, 0x13f5), (0x1401, 0x166c),
+    (0x166f, 0x167f), (0x1681, 0x169a), (0x16a0, 0x16ea), (0x16f1, 0x16f8), (0x1700, 0x1715),
+    (0x171f, 0x1734), (0x1740, 0x1753), (0x1760, 0x176c), (0x176e, 0x1770), (0x1772, 0x1773),
+    (0x1780, 0x17b3), (0x17b6, 0x17d3), (0x17d7, 0x17d7), (0x17dc, 0x17dd), (0x17e0, 0x17e9),
+    (0x1810, 0x1819), (0x1820, 0x1878), (0x1880, 0x18aa), (0x18b0, 0x18f5), (0x1900, 0x191e),
+    (0x1920, 0x192b), (0x1930, 0x193b), (0x1946, 0x196d), (0x1970, 0x1974), (0x1980, 0x19ab),
+    (0x19b0, 0x19c9), (0x19d0, 0x19d9), (0x1a00, 0x1a1b), (0x1a20, 0x1a5e), (0x1a60, 0x1a7c),
+    (0x1a7f, 0x1a89), (0x1a90, 0x1a99), (0x1aa7, 0x1aa7), (0x1ab0, 0x1abd), (0x1abf, 0x1ace),
+    (0x1b00, 0x1b4c), (0x1b50, 0x1b59), (0x1b6b, 0x1b73), (0x1b80, 0x1bf3), (0x1c00, 0x1c37),
+    (0x1c40, 0x1c49), (0x1c4d, 0x1c7d), (0x1cd0, 0x1cd2), (0x1cd4, 0x1cfa), (0x1d00, 0x1d2b),
+    (0x1d2f, 0x1d2f), (0x1d3b, 0x1d3b), (0x1d4e, 0x1d4e), (0x1d6b, 0x1d77), (0x1d79, 0x1d9a),
+    (0x1dc0, 0x1dff), (0x1e01, 0x1e01), (0x1e03, 0x1e03), (0x1e05, 0x1e05), (0x1e07, 0x1e07),
+    (0x1e09, 0x1e09), (0x1e0b, 0x1e0b), (0x1e0d, 0x1e0d), (0x1e0f, 0x1e0f), (0x1e11, 0x1e11),
+    (0x1e13, 0x1e13), (0x1e15, 0x1e15), (0x1e17, 0x1e17), (0x1e19, 0x1e19), (0x1e1b, 0x1e1b),
+    (0x1e1d, 0x1e1d), (0x1e1f, 0x1e1f), (0x1e21, 0x1e21), (0x1e23, 0x1e23), (0x1e25, 0x1e25),
+    (0x1e27, 0x1e27), (0x1e29, 0x1e29), (0x1e2b, 0x1e2b), (0x1e2d, 0x1e2d), (0x1e2f, 0x1e2f),
+    (0x1e31, 0x1e31), (0x1e33, 0x1e33), (0x1e35, 0x1e35), (0x1e37, 0x1e37), (0x1e39, 0x1e39),
+    (0x1e3b, 0x1e3b), (0x1e3d, 0x1e3d), (0x1e3f, 0x1e3f), (0x1e41, 0x1e41), (0x1e43, 0x1e43),
+    (0x1e45, 0x1e45), (0x1e47, 0x1e47), (0x1e49, 0x1e49), (0x1e4b, 0x1e4b), (0x1e4d, 0x1e4d),
+    (0x1e4f, 0x1e4f), (0x1e51, 0x1e51), (0x1e53, 0x1e53), (0x1e55, 0x1e55), (0x1e57, 0x1e57),
+    (0x1e59, 0x1e59), (0x1e5b, 0x1e5b), (0x1e5d, 0x1e5d), (0x1e5f, 0x1e5f), (0x1e61, 0x1e61),
+    (0x1e63, 0x1e63), (0x1e65, 0x1e65), (0x1e67, 0x1e67), (0x1e69, 0x1e69), (0x1e6b, 0x1e6b),
+    (0x1e6d, 0x1e6d), (0x1e6f, 0x1e6f), (0x1e71, 0x1e71), (0x1e73, 0x1e73), (0x1e75, 0x1e75),
+    (0x1e77, 0x1e77), (0x1e79, 0x1e79), (0x1e7b, 0x1e7b), (0x1e7d, 0x1e7d), (0x1e7f, 0x1e7f),
+    (0x1e81, 0x1e81), (0x1e83, 0x1e83), (0x1e85, 0x1e85), (0x1e87, 0x1e87), (0x1e89, 0x1e89),
+    (0x1e8b, 0x1e8b), (0x1e8d, 0x1e8d), (0x1e8f, 0x1e8f), (0x1e91, 0x1e91), (0x1e93, 0x1e93),
+    (0x1e95, 0x1e99), (0x1e9c, 0x1e9d), (0x1e9f, 0x1e9f), (0x1ea1, 0x1ea1), (0x1ea3, 0x1ea3),
+    (0x1ea5, 0x1ea5), (0x1ea7, 0x1ea7), (0x1ea9, 0x1ea9), (0x1eab, 0x1eab), (0x1ead, 0x1ead),
+    (0x1eaf, 0x1eaf), (0x1eb1, 0x1eb1), (0x1eb3, 0x1eb3), (0x1eb5, 0x1eb5), (0x1eb7, 0x1eb7),
+    (0x1eb9, 0x1eb9), (0x1ebb, 0x1ebb), (0x1ebd, 0x1ebd), (0x1ebf, 0x1ebf), (0x1ec1, 0x1ec1),
+    (0x1ec3, 0x1ec3), (0x1ec5, 0x1ec5), (0x1ec7, 0x1ec7), (0x1ec9, 0x1ec9), (0x1ecb, 0x1ecb),
+    (0x1ecd, 0x1ecd), (0x1ecf, 0x1ecf), (0x1ed1, 0x1ed1), (0x1ed3, 0x1ed3), (0x1ed5, 0x1ed5),
+    (0x1ed7, 0x1ed7), (0x1ed9, 0x1ed9), (0x1edb, 0x1edb), (0x1edd, 0x1edd), (0x1edf, 0x1edf),
+    (0x1ee1, 0x1ee1), (0x1ee3, 0x1ee3), (0x1ee5, 0x1ee5), (0x1ee7, 0x1ee7), (0x1ee9, 0x1ee9),
+    (0x1eeb, 0x1eeb), (0x1eed, 0x1eed), (0x1eef, 0x1eef), (0x1ef1, 0x1ef1), (0x1ef3, 0x1ef3),
+    (0x1ef5, 0x1ef5), (0x1ef7, 0x1ef7), (0x1ef9, 0x1ef9), (0x1efb, 0x1efb), (0x1efd, 0x1efd),
+    (0x1eff, 0x1f07), (0x1f10, 0x1f15), (0x1f20, 0x1f27), (0x1f30, 0x1f37), (0x1f40, 0x1f45),
+    (0x1f50, 0x1f57), (0x1f60, 0x1f67), (0x1f70, 0x1f70), (0x1f72, 0x1f72), (0x1f74, 0x1f74),
+    (0x1f76, 0x1f76), (0x1f78, 0x1f78), (0x1f7a, 0x1f7a), (0x1f7c, 0x1f7c), (0x1fb0, 0x1fb1),
+    (0x1fb6, 0x1fb6), (0x1fc6, 0x1fc6), (0x1fd0, 0x1fd2), (0x1fd6, 0x1fd7), (0x1fe0, 0x1fe2),
+    (0x1fe4, 0x1fe7), (0x1ff6, 0x1ff6), (0x214e, 0x214e), (0x2184, 0x2184), (0x2c30, 0x2c5f),
+    (0x2c61, 0x2c61), (0x2c65, 0x2c66), (0x2c68, 0x2c68), (0x2c6a, 0x2c6a), (0x2c6c, 0x2c6c),
+    (0x2c71, 0x2c71), (0x2c73, 0x2c74), (0x2c76, 0x2c7b), (0x2c81, 0x2c81), (0x2c83, 0x2c83),
+    (0x2c85, 0x2c85), (0x2c87, 0x2c87), (0x2c89, 0x2c89), (0x2c8b, 0x2c8b), (0x2c8d, 0x2c8d),
+    (0x2c8f, 0x2c8f), (0x2c91, 0x2c91), (0x2c93, 0x2c93), (0x2c95, 0x2c95), (0x2c97, 0x2c97),
+    (0x2c99, 0x2c99), (0x2c9b, 0x2c9b), (0x2c9d, 0x2c9d), (0x2c9f, 0x2c9f), (0x2ca1, 0x2ca1),
+    (0x2ca3, 0x2ca3), (0x2ca5, 0x2ca5), (0x2ca7, 0x2ca7), (0x2ca9, 0x2ca9), (0x2cab, 0x2cab),
+    (0x2cad, 0x2cad), (0x2caf, 0x2caf), (0x2cb1, 0x2cb1), (0x2cb3, 0x2cb3), (0x2cb5, 0x2cb5),
+    (0x2cb7, 0x2cb7), (0x2cb9, 0x2cb9), (0x2cbb, 0x2cbb), (0x2cbd, 0x2cbd), (0x2cbf, 0x2cbf),
+    (0x2cc1, 0x2cc1), (0x2cc3, 0x2cc3), (0x2cc5, 0x2cc5), (0x2cc7, 0x2cc7), (0x2cc9, 0x2cc9),
+    (0x2ccb, 0x2ccb), (0x2ccd, 0x2ccd), (0x2ccf, 0x2ccf), (0x2cd1, 0x2cd1), (0x2cd3, 0x2cd3),
+    (0x2cd5, 0x2cd5), (0x2cd7, 0x2cd7), (0x2cd9, 0x2cd9), (0x2cdb, 0x2cdb), (0x2cdd, 0x2cdd),
+    (0x2cdf, 0x2cdf), (0x2ce1, 0x2ce1), (0x2ce3, 0x2ce4), (0x2cec, 0x2cec), (0x2cee, 0x2cf1),
+    (0x2cf3, 0x2cf3), (0x2d00, 0x2d25), (0x2d27, 0x2d27), (0x2d2d, 0x2d2d), (0x2d30, 0x2d67),
+    (0x2d7f, 0x2d96), (0x2da0, 0x2da6), (0x2da8, 0x2dae), (0x2db0, 0x2db6), (0x2db8, 0x2dbe),
+    (0x2dc0, 0x2dc6), (0x2dc8, 0x2dce), (0x2dd0, 0x2dd6), (0x2dd8, 0x2dde), (0x2de0, 0x2dff),
+    (0x2e2f, 0x2e2f), (0x3005, 0x3007), (0x302a, 0x302d), (0x303c, 0x303c), (0x3041, 0x3096),
+    (0x3099, 0x309a), (0x309d, 0x309e), (0x30a1, 0x30fa), (0x30fc, 0x30fe), (0x3105, 0x312f),
+    (0x31a0, 0x31bf), (0x31f0, 0x31ff), (0x3400, 0x4dbf), (0x4e00, 0xa48c), (0xa4d0, 0xa4fd),
+    (0xa500, 0xa60c), (0xa610, 0xa62b), (0xa641, 0xa641), (0xa643, 0xa643), (0xa645, 0xa645),
+    (0xa647, 0xa647), (0xa649, 0xa649), (0xa64b, 0xa64b), (0xa64d, 0xa64d), (0xa64f, 0xa64f),
+    (0xa651, 0xa651), (0xa653, 0xa653), (0xa655, 0xa655), (0xa657, 0xa657), (0xa659, 0xa659),
+    (0xa65b, 0xa65b), (0xa65d, 0xa65d), (0xa65f, 0xa65f), (0xa661, 0xa661), (0xa663, 0xa663),
+    (0xa665, 0xa665), (0xa667, 0xa667), (0xa669, 0xa669), (0xa66b, 0xa66b), (0xa66d, 0xa66f),
+    (0xa674, 0xa67d), (0xa67f, 0xa67f), (0xa681, 0xa681), (0xa683, 0xa683), (0xa685, 0xa685),
+    (0xa687, 0xa687), (0xa689, 0xa689), (0xa68b, 0xa68b), (0xa68d, 0xa68d), (0xa68f, 0xa68f),
+    (0xa691, 0xa691), (0xa693, 0xa693), (0xa695, 0xa695), (0xa697, 0xa697), (0xa699, 0xa699),
+    (0xa69b, 0xa69b), (0xa69e, 0xa6e5), (0xa6f0, 0xa6f1), (0xa717, 0xa71f), (0xa723, 0xa723),
+    (0xa725, 0xa725), (0xa727, 0xa727), (0xa729, 0xa729), (0xa72b, 0xa72b), (0xa72d, 0xa72d),
+    (0xa72f, 0xa731), (0xa733, 0xa733), (0xa735, 0xa735), (0xa737, 0xa737), (0xa739, 0xa739),
+    (0xa73b, 0xa73b), (0xa73d, 0xa73d), (0xa73f, 0xa73f), (0xa741, 0xa741), (0xa743, 0xa743),
+    (0xa745, 0xa745), (0xa747, 0xa747), (0xa749, 0xa749), (0xa74b, 0xa74b), (0xa74d, 0xa74d),
+    (0xa74f, 0xa74f), (0xa751, 0xa751), (0xa753, 0xa753), (0xa755, 0xa755), (0xa757, 0xa757),
+    (0xa759, 0xa759), (0xa75b, 0xa75b), (0xa75d, 0xa75d), (0xa75f, 0xa75f), (0xa761, 0xa761),
+    (0xa763, 0xa763), (0xa765, 0xa765), (0xa767, 0xa767), (0xa769, 0xa769), (0xa76b, 0xa76b),
+    (0xa76d, 0xa76d), (0xa76f, 0xa76f), (0xa771, 0xa778), (0xa77a, 0xa77a), (0xa77c, 0xa77c),
+    (0xa77f, 0xa77f), (0xa781, 0xa781), (0xa783, 0xa783), (0xa785, 0xa785), (0xa787, 0xa788),
+    (0xa78c, 0xa78c), (0xa78e, 0xa78f), (0xa791, 0xa791), (0xa793, 0xa795), (0xa797, 0xa797),
+    (0xa799, 0xa799), (0xa79b, 0xa79b), (0xa79d, 0xa79d), (0xa79f, 0xa79f), (0xa7a1, 0xa7a1),
+    (0xa7a3, 0xa7a3), (0xa7a5, 0xa7a5), (0xa7a7, 0xa7a7), (0xa7a9, 0xa7a9), (0xa7af, 0xa7af),
+    (0xa7b5, 0xa7b5), (0xa7b7, 0xa7b7), (0xa7b9, 0xa7b9), (0xa7bb, 0xa7bb), (0xa7bd, 0xa7bd),
+    (0xa7bf, 0xa7bf), (0xa7c1, 0xa7c1), (0xa7c3, 0xa7c3), (0xa7c8, 0xa7c8), (0xa7ca, 0xa7ca),
+    (0xa7d1, 0xa7d1), (0xa7d3, 0xa7d3), (0xa7d5, 0xa7d5), (0xa7d7, 0xa7d7), (0xa7d9, 0xa7d9),
+    (0xa7f6, 0xa7f7), (0xa7fa, 0xa827), (0xa82c, 0xa82c), (0xa840, 0xa873), (0xa880, 0xa8c5),
+    (0xa8d0, 0xa8d9), (0xa8e0, 0xa8f7), (0xa8fb, 0xa8fb), (0xa8fd, 0xa92d), (0xa930, 0xa953),
+    (0xa980, 0xa9c0), (0xa9cf, 0xa9d9), (0xa9e0, 0xa9fe), (0xaa00, 0xaa36), (0xaa40, 0xaa4d),
+    (0xaa50, 0xaa59), (0xaa60, 0xaa76), (0xaa7a, 0xaac2), (0xaadb, 0xaadd), (0xaae0, 0xaaef),
+    (0xaaf2, 0xaaf6), (0xab01, 0xab06), (0xab09, 0xab0e), (0xab11, 0xab16), (0xab20, 0xab26),
+    (0xab28, 0xab2e), (0xab30, 0xab5a), (0xab60, 0xab68), (0xabc0, 0xabea), (0xabec, 0xabed),
+    (0xabf0, 0xabf9), (0xac00, 0xd7a3), (0xfa0e, 0xfa0f), (0xfa11, 0xfa11), (0xfa13, 0xfa14),
+    (0xfa1f, 0xfa1f), (0xfa21, 0xfa21), (0xfa23, 0xfa24), (0xfa27, 0xfa29), (0xfb1e, 0xfb1e),
+    (0xfe20, 0xfe2f), (0xfe73, 0xfe73), (0x10000, 0x1000b), (0x1000d, 0x10026), (0x10028, 0x1003a),
+    (0x1003c, 0x1003d), (0x1003f, 0x1004d), (0x10050, 0x1005d), (0x10080, 0x100fa),
+    (0x101fd, 0x101fd), (0x10280, 0x1029c), (0x102a0, 0x102d0), (0x102e0, 0x102e0),
+    (0x10300, 0x1031f), (0x1032d, 0x10340), (0x10342, 0x10349), (0x10350, 0x1037a),
+    (0x10380, 0x1039d), (0x103a0, 0x103c3), (0x103c8, 0x103cf), (0x10428, 0x1049d),
+    (0x104a0, 0x104a9), (0x104d8, 0x104fb), (0x10500, 0x10527), (0x10530, 0x10563),
+    (0x10597, 0x105a1), (0x105a3, 0x105b1), (0x105b3, 0x105b9), (0x105bb, 0x105bc),
+    (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10780),
+    (0x10800, 0x10805), (0x10808, 0x10808), (0x1080a, 0x10835), (0x10837, 0x10838),
+    (0x1083c, 0x1083c), (0x1083f, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089e),
+    (0x108e0, 0x108f2), (0x108f4, 0x108f5), (0x10900, 0x10915), (0x10920, 0x10939),
+    (0x10980, 0x109b7), (0x109be, 0x109bf), (0x10a00, 0x10a03), (0x10a05, 0x10a06),
+    (0x10a0c, 0x10a13), (0x10a15, 0x10a17), (0x10a19, 0x10a35), (0x10a38, 0x10a3a),
+    (0x10a3f, 0x10a3f), (0x10a60, 0x10a7c), (0x10a80, 0x10a9c), (0x10ac0, 0x10ac7),
+    (0x10ac9, 0x10ae6), (0x10b00, 0x10b35), (0x10b40, 0x10b55), (0x10b60, 0x10b72),
+    (0x10b80, 0x10b91), (0x10c00, 0x10c48), (0x10cc0, 0x10cf2), (0x10d00, 0x10d27),
+    (0x10d30, 0x10d39), (0x10e80, 0x10ea9), (0x10eab, 0x10eac), (0x10eb0, 0x10eb1),
+    (0x10efd, 0x10f1c), (0x10f27, 0x10f27), (0x10f30, 0x10f50), (0x10f70, 0x10f85),
+    (0x10fb0, 0x10fc4), (0x10fe0, 0x10ff6), (0x11000, 0x11046), (0x11066, 0x11075),
+    (0x1107f, 0x110ba), (0x110c2, 0x110c2), (0x110d0, 0x110e8), (0x110f0, 0x110f9),
+    (0x11100, 0x11134), (0x11136, 0x1113f), (0x11144, 0x11147), (0x11150, 0x11173),
+    (0x11176, 0x11176), (0x11180, 0x111c4), (0x111c9, 0x111cc), (0x111ce, 0x111da),
+    (0x111dc, 0x111dc), (0x11200, 0x11211), (0x11213, 0x11237), (0x1123e, 0x11241),
+    (0x11280, 0x11286), (0x11288, 0x11288), (0x1128a, 0x1128d), (0x1128f, 0x1129d),
+    (0x1129f, 0x112a8), (0x112b0, 0x112ea), (0x112f0, 0x112f9), (0x11300, 0x11303),
+    (0x11305, 0x1130c), (0x1130f, 0x11310), (0x11313, 0x11328), (0x1132a, 0x11330),
+    (0x11332, 0x11333), (0x11335, 0x11339), (0x1133b, 0x11344), (0x11347, 0x11348),
+    (0x1134b, 0x1134d), (0x11350, 0x11350), (0x11357, 0x11357), (0x1135d, 0x11363),
+    (0x11366, 0x1136c), (0x11370, 0x11374), (0x11400, 0x1144a), (0x11450, 0x11459),
+    (0x1145e, 0x11461), (0x11480, 0x114c5), (0x114c7, 0x114c7), (0x114d0, 0x114d9),
+    (0x11580, 0x115b5), (0x115b8, 0x115c0), (0x115d8, 0x115dd), (0x11600, 0x11640),
+    (0x11644, 0x11644), (0x11650, 0x11659), (0x11680, 0x116b8), (0x116c0, 0x116c9),
+    (0x11700, 0x1171a), (0x1171d, 0x1172b), (0x11730, 0x11739), (0x11740, 0x11746),
+    (0x11800, 0x1183a), (0x118c0, 0x118e9), (0x118ff, 0x11906), (0x11909, 0x11909),
+    (0x1190c, 0x11913), (0x11915, 0x11916), (0x11918, 0x11935), (0x11937, 0x11938),
+    (0x1193b, 0x11943), (0x11950, 0x11959), (0x119a0, 0x119a7), (0x119aa, 0x119d7),
+    (0x119da, 0x119e1), (0x119e3, 0x119e4), (0x11a00, 0x11a3e), (0x11a47, 0x11a47),
+    (0x11a50, 0x11a99), (0x11a9d, 0x11a9d), (0x11ab0, 0x11af8), (0x11c00, 0x11c08),
+    (0x11c0a, 0x11c36), (0x11c38, 0x11c40), (0x11c50, 0x11c59), (0x11c72, 0x11c8f),
+    (0x11c92, 0x11ca7), (0x11ca9, 0x11cb6), (0x11d00, 0x11d06), (0x11d08, 0x11d09),
+    (0x11d0b, 0x11d36), (0x11d3a, 0x11d3a), (0x11d3c, 0x11d3d), (0x11d3f, 0x11d47),
+    (0x11d50, 0x11d59), (0x11d60, 0x11d65), (0x11d67, 0x11d68), (0x11d6a, 0x11d8e),
+    (0x11d90, 0x11d91), (0x11d93, 0x11d98), (0x11da0, 0x11da9), (0x11ee0, 0x11ef6),
+    (0x11f00, 0x11f10), (0x11f12, 0x11f3a), (0x11f3e, 0x11f42), (0x11f50, 0x11f59),
+    (0x11fb0, 0x11fb0), (0x12000, 0x12399), (0x12480, 0x12543), (0x12f90, 0x12ff0),
+    (0x13000, 0x1342f), (0x13440, 0x13455), (0x14400, 0x14646), (0x16800, 0x16a38),
+    (0x16a40, 0x16a5e), (0x16a60, 0x16a69), (0x16a70, 0x16abe), (0x16ac0, 0x16ac9),
+    (0x16ad0, 0x16aed), (0x16af0, 0x16af4), (0x16b00, 0x16b36), (0x16b40, 0x16b43),
+    (0x16b50, 0x16b59), (0x16b63, 0x16b77), (0x16b7d, 0x16b8f), (0x16e60, 0x16e7f),
+    (0x16f00, 0x16f4a), (0x16f4f, 0x16f87), (0x16f8f, 0x16f9f), (0x16fe0, 0x16fe1),
+    (0x16fe3, 0x16fe4), (0x16ff0, 0x16ff1), (0x17000, 0x187f7), (0x18800, 0x18cd5),
+    (0x18d00, 0x18d08), (0x1aff0, 0x1aff3), (0x1aff5, 0x1affb), (0x1affd, 0x1affe),
+    (0x1b000, 0x1b122), (0x1b132, 0x1b132), (0x1b150, 0x1b152), (0x1b155, 0x1b155),
+    (0x1b164, 0x1b167), (0x1b170, 0x1b2fb), (0x1bc00, 0x1bc6a), (0x1bc70, 0x1bc7c),
+    (0x1bc80, 0x1bc88), (0x1bc90, 0x1bc99), (0x1bc9d, 0x1bc9e), (0x1cf00, 0x1cf2d),
+    (0x1cf30, 0x1cf46), (0x1da00, 0x1da36), (0x1da3b, 0x1da6c), (0x1da75, 0x1da75),
+    (0x1da84, 0x1da84), (0x1da9b, 0x1da9f), (0x1daa1, 0x1daaf), (0x1df00, 0x1df1e),
+    (0x1df25, 0x1df2a), (0x1e000, 0x1e006), (0x1e008, 0x1e018), (0x1e01b, 0x1e021),
+    (0x1e023, 0x1e024), (0x1e026, 0x1e02a), (0x1e08f, 0x1e08f), (0x1e100, 0x1e12c),
+    (0x1e130, 0x1e13d), (0x1e140, 0x1e149), (0x1e14e, 0x1e14e), (0x1e290, 0x1e2ae),
+    (0x1e2c0, 0x1e2f9), (0x1e4d0, 0x1e4f9), (0x1e7e0, 0x1e7e6), (0x1e7e8, 0x1e7eb),
+    (0x1e7ed, 0x1e7ee), (0x1e7f0, 0x1e7fe), (0x1e800, 0x1e8c4), (0x1e8d0, 0x1e8d6),
+    (0x1e922, 0x1e94b), (0x1e950, 0x1e959), (0x20000, 0x2a6df), (0x2a700, 0x2b739),
+    (0x2b740, 0x2b81d), (0x2b820, 0x2cea1), (0x2ceb0, 0x2ebe0), (0x2ebf0, 0x2ee5d),
+    (0x30000, 0x3134a), (0x31350, 0x323af),
+];
+
+/// The code points in CONTEXTJ under IDNA 2008 (RFC 5892 §2).
+pub static CONTEXTJ: &'static [(u32, u32)] = &[
+    (0x200c, 0x200d),
+];
+
+/// The code points in CONTEXTO under IDNA 2008 (RFC 5892 §2).
+pub static CONTEXTO: &'static [(u32, u32)] = &[
+    (0xb7, 0xb7), (0x375, 0x375), (0x5f3, 0x5f4), (0x660, 0x669), (0x6f0, 0x6f9), (0x30fb, 0x30fb),
+];
+
+/// The joining types of the code points that have one, for CONTEXTJ.
+pub static JOINING_TYPES: &'static [(u32, u32, u8)] = &[
+    (0xad, 0xad, b'T'), (0x300, 0x36f, b'T'), (0x483, 0x489, b'T'), (0x591, 0x5bd, b'T'),
+    (0x5bf, 0x5bf, b'T'), (0x5c1, 0x5c2, b'T'), (0x5c4, 0x5c5, b'T'), (0x5c7, 0x5c7, b'T'),
+    (0x610, 0x61a, b'T'), (0x61c, 0x61c, b'T'), (0x620, 0x620, b'D'), (0x622, 0x625, b'R'),
+    (0x626, 0x626, b'D'), (0x627, 0x627, b'R'), (0x628, 0x628, b'D'), (0x629, 0x629, b'R'),
+    (0x62a, 0x62e, b'D'), (0x62f, 0x632, b'R'), (0x633, 0x63f, b'D'), (0x640, 0x640, b'C'),
+    (0x641, 0x647, b'D'), (0x648, 0x648, b'R'), (0x649, 0x64a, b'D'), (0x64b, 0x65f, b'T'),
+    (0x66e, 0x66f, b'D'), (0x670, 0x670, b'T'), (0x671, 0x673, b'R'), (0x675, 0x677, b'R'),
+    (0x678, 0x687, b'D'), (0x688, 0x699, b'R'), (0x69a, 0x6bf, b'D'), (0x6c0, 0x6c0, b'R'),
+    (0x6c1, 0x6c2, b'D'), (0x6c3, 0x6cb, b'R'), (0x6cc, 0x6cc, b'D'), (0x6cd, 0x6cd, b'R'),
+    (0x6ce, 0x6ce, b'D'), (0x6cf, 0x6cf, b'R'), (0x6d0, 0x6d1, b'D'), (0x6d2, 0x6d3, b'R'),
+    (0x6d5, 0x6d5, b'R'), (0x6d6, 0x6dc, b'T'), (0x6df, 0x6e4, b'T'), (0x6e7, 0x6e8, b'T'),
+    (0x6ea, 0x6ed, b'T'), (0x6ee, 0x6ef, b'R'), (0x6fa, 0x6fc, b'D'), (0x6ff, 0x6ff, b'D'),
+    (0x70f, 0x70f, b'T'), (0x710, 0x710, b'R'), (0x711, 0x711, b'T'), (0x712, 0x714, b'D'),
+    (0x715, 0x719, b'R'), (0x71a, 0x71d, b'D'), (0x71e, 0x71e, b'R'), (0x71f, 0x727, b'D'),
+    (0x728, 0x728, b'R'), (0x729, 0x729, b'D'), (0x72a, 0x72a, b'R'), (0x72b, 0x72b, b'D'),
+    (0x72c, 0x72c, b'R'), (0x72d, 0x72e, b'D'), (0x72f, 0x72f, b'R'), (0x730, 0x74a, b'T'),
+    (0x74d, 0x74d, b'R'), (0x74e, 0x758, b'D'), (0x759, 0x75b, b'R'), (0x75c, 0x76a, b'D'),
+    (0x76b, 0x76c, b'R'), (0x76d, 0x770, b'D'), (0x771, 0x771, b'R'), (0x772, 0x772, b'D'),
+    (0x773, 0x774, b'R'), (0x775, 0x777, b'D'), (0x778, 0x779, b'R'), (0x77a, 0x77f, b'D'),
+    (0x7a6, 0x7b0, b'T'), (0x7ca, 0x7ea, b'D'), (0x7eb, 0x7f3, b'T'), (0x7fa, 0x7fa, b'C'),
+    (0x7fd, 0x7fd, b'T'), (0x816, 0x819, b'T'), (0x81b, 0x823, b'T'), (0x825, 0x827, b'T'),
+    (0x829, 0x82d, b'T'), (0x840, 0x840, b'R'), (0x841, 0x845, b'D'), (0x846, 0x847, b'R'),
+    (0x848, 0x848, b'D'), (0x849, 0x849, b'R'), (0x84a, 0x853, b'D'), (0x854, 0x854, b'R'),
+    (0x855, 0x855, b'D'), (0x856, 0x858, b'R'), (0x859, 0x85b, b'T'), (0x860, 0x860, b'D'),
+    (0x862, 0x865, b'D'), (0x867, 0x867, b'R'), (0x868, 0x868, b'D'), (0x869, 0x86a, b'R'),
+    (0x870, 0x882, b'R'), (0x883, 0x885, b'C'), (0x886, 0x886, b'D'), (0x889, 0x88d, b'D'),
+    (0x88e, 0x88e, b'R'), (0x898, 0x89f, b'T'), (0x8a0, 0x8a9, b'D'), (0x8aa, 0x8ac, b'R'),
+    (0x8ae, 0x8ae, b'R'), (0x8af, 0x8b0, b'D'), (0x8b1, 0x8b2, b'R'), (0x8b3, 0x8b8, b'D'),
+    (0x8b9, 0x8b9, b'R'), (0x8ba, 0x8c8, b'D'), (0x8ca, 0x8e1, b'T'), (0x8e3, 0x902, b'T'),
+    (0x93a, 0x93a, b'T'), (0x93c, 0x93c, b'T'), (0x941, 0x948, b'T'), (0x94d, 0x94d, b'T'),
+    (0x951, 0x957, b'T'), (0x962, 0x963, b'T'), (0x981, 0x981, b'T'), (0x9bc, 0x9bc, b'T'),
+    (0x9c1, 0x9c4, b'T'), (0x9cd, 0x9cd, b'T'), (0x9e2, 0x9e3, b'T'), (0x9fe, 0x9fe, b'T'),
+    (0xa01, 0xa02, b'T'), (0xa3c, 0xa3c, b'T'), (0xa41, 0xa42, b'T'), (0xa47, 0xa48, b'T'),
+    (0xa4b, 0xa4d, b'T'), (0xa51, 0xa51, b'T'), (0xa70, 0xa71, b'T'), (0xa75, 0xa75, b'T'),
+    (0xa81, 0xa82, b'T'), (0xabc, 0xabc, b'T'), (0xac1, 0xac5, b'T'), (0xac7, 0xac8, b'T'),
+    (0xacd, 0xacd, b'T'), (0xae2, 0xae3, b'T'), (0xafa, 0xaff, b'T'), (0xb01, 0xb01, b'T'),
+    (0xb3c, 0xb3c, b'T'), (0xb3f, 0xb3f, b'T'), (0xb41, 0xb44, b'T'), (0xb4d, 0xb4d, b'T'),
+    (0xb55, 0xb56, b'T'), (0xb62, 0xb63, b'T'), (0xb82, 0xb82, b'T'), (0xbc0, 0xbc0, b'T'),
+    (0xbcd, 0xbcd, b'T'), (0xc00, 0xc00, b'T'), (0xc04, 0xc04, b'T'), (0xc3c, 0xc3c, b'T'),
+    (0xc3e, 0xc40, b'T'), (0xc46, 0xc48, b'T'), (0xc4a, 0xc4d, b'T'), (0xc55, 0xc56, b'T'),
+    (0xc62, 0xc63, b'T'), (0xc81, 0xc81, b'T'), (0xcbc, 0xcbc, b'T'), (0xcbf, 0xcbf, b'T'),
+    (0xcc6, 0xcc6, b'T'), (0xccc, 0xccd, b'T'), (0xce2, 0xce3, b'T'), (0xd00, 0xd01, b'T'),
+    (0xd3b, 0xd3c, b'T'), (0xd41, 0xd44, b'T'), (0xd4d, 0xd4d, b'T'), (0xd62, 0xd63, b'T'),
+    (0xd81, 0xd81, b'T'), (0xdca, 0xdca, b'T'), (0xdd2, 0xdd4, b'T'), (0xdd6, 0xdd6, b'T'),
+    (0xe31, 0xe31, b'T'), (0xe34, 0xe3a, b'T'), (0xe47, 0xe4e, b'T'), (0xeb1, 0xeb1, b'T'),
+    (0xeb4, 0xebc, b'T'), (0xec8, 0xece, b'T'), (0xf18, 0xf19, b'T'), (0xf35, 0xf35, b'T'),
+    (0xf37, 0xf37, b'T'), (0xf39, 0xf39, b'T'), (0xf71, 0xf7e, b'T'), (0xf80, 0xf84, b'T'),
+    (0xf86, 0xf87, b'T'), (0xf8d, 0xf97, b'T'), (0xf99, 0xfbc, b'T'), (0xfc6, 0xfc6, b'T'),
+    (0x102d, 0x1030, b'T'), (0x1032, 0x1037, b'T'), (0x1039, 0x103a, b'T'), (0x103d, 0x103e, b'T'),
+    (0x1058, 0x1059, b'T'), (0x105e, 0x1060, b'T'), (0x1071, 0x1074, b'T'), (0x1082, 0x1082, b'T'),
+    (0x1085, 0x1086, b'T'), (0x108d, 0x108d, b'T'), (0x109d, 0x109d, b'T'), (0x135d, 0x135f, b'T'),
+    (0x1712, 0x1714, b'T'), (0x1732, 0x1733, b'T'), (0x1752, 0x1753, b'T'), (0x1772, 0x1773, b'T'),
+    (0x17b4, 0x17b5, b'T'), (0x17b7, 0x17bd, b'T'), (0x17c6, 0x17c6, b'T'), (0x17c9, 0x17d3, b'T'),
+    (0x17dd, 0x17dd, b'T'), (0x1807, 0x1807, b'D'), (0x180a, 0x180a, b'C'), (0x180b, 0x180d, b'T'),
+    (0x180f, 0x180f, b'T'), (0x1820, 0x1878, b'D'), (0x1885, 0x1886, b'T'), (0x1887, 0x18a8, b'D'),
+    (0x18a9, 0x18a9, b'T'), (0x18aa, 0x18aa, b'D'), (0x1920, 0x1922, b'T'), (0x1927, 0x1928, b'T'),
+    (0x1932, 0x1932, b'T'), (0x1939, 0x193b, b'T'), (0x1a17, 0x1a18, b'T'), (0x1a1b, 0x1a1b, b'T'),
+    (0x1a56, 0x1a56, b'T'), (0x1a58, 0x1a5e, b'T'), (0x1a60, 0x1a60, b'T'), (0x1a62, 0x1a62, b'T'),
+    (0x1a65, 0x1a6c, b'T'), (0x1a73, 0x1a7c, b'T'), (0x1a7f, 0x1a7f, b'T'), (0x1ab0, 0x1ace, b'T'),
+    (0x1b00, 0x1b03, b'T'), (0x1b34, 0x1b34, b'T'), (0x1b36, 0x1b3a, b'T'), (0x1b3c, 0x1b3c, b'T'),
+    (0x1b42, 0x1b42, b'T'), (0x1b6b, 0x1b73, b'T'), (0x1b80, 0x1b81, b'T'), (0x1ba2, 0x1ba5, b'T'),
+    (0x1ba8, 0x1ba9, b'T'), (0x1bab, 0x1bad, b'T'), (0x1be6, 0x1be6, b'T'), (0x1be8, 0x1be9, b'T'),
+    (0x1bed, 0x1bed, b'T'), (0x1bef, 0x1bf1, b'T'), (0x1c2c, 0x1c33, b'T'), (0x1c36, 0x1c37, b'T'),
+    (0x1cd0, 0x1cd2, b'T'), (0x1cd4, 0x1ce0, b'T'), (0x1ce2, 0x1ce8, b'T'), (0x1ced, 0x1ced, b'T'),
+    (0x1cf4, 0x1cf4, b'T'), (0x1cf8, 0x1cf9, b'T'), (0x1dc0, 0x1dff, b'T'), (0x200b, 0x200b, b'T'),
+    (0x200d, 0x200d, b'C'), (0x200e, 0x200f, b'T'), (0x202a, 0x202e, b'T'), (0x2060, 0x2064, b'T'),
+    (0x206a, 0x206f, b'T'), (0x20d0, 0x20f0, b'T'), (0x2cef, 0x2cf1, b'T'), (0x2d7f, 0x2d7f, b'T'),
+    (0x2de0, 0x2dff, b'T'), (0x302a, 0x302d, b'T'), (0x3099, 0x309a, b'T'), (0xa66f, 0xa672, b'T'),
+    (0xa674, 0xa67d, b'T'), (0xa69e, 0xa69f, b'T'), (0xa6f0, 0xa6f1, b'T'), (0xa802, 0xa802, b'T'),
+    (0xa806, 0xa806, b'T'), (0xa80b, 0xa80b, b'T'), (0xa825, 0xa826, b'T'), (0xa82c, 0xa82c, b'T'),
+    (0xa840, 0xa871, b'D'), (0xa872, 0xa872, b'L'), (0xa8c4, 0xa8c5, b'T'), (0xa8e0, 0xa8f1, b'T'),
+    (0xa8ff, 0xa8ff, b'T'), (0xa926, 0xa92d, b'T'), (0xa947, 0xa951, b'T'), (0xa980, 0xa982, b'T'),
+    (0xa9b3, 0xa9b3, b'T'), (0xa9b6, 0xa9b9, b'T'), (0xa9bc, 0xa9bd, b'T'), (0xa9e5, 0xa9e5, b'T'),
+    (0xaa29, 0xaa2e, b'T'), (0xaa31, 0xaa32, b'T'), (0xaa35, 0xaa36, b'T'), (0xaa43, 0xaa43, b'T'),
+    (0xaa4c, 0xaa4c, b'T'), (0xaa7c, 0xaa7c, b'T'), (0xaab0, 0xaab0, b'T'), (0xaab2, 0xaab4, b'T'),
+    (0xaab7, 0xaab8, b'T'), (0xaabe, 0xaabf, b'T'), (0xaac1, 0xaac1, b'T'), (0xaaec, 0xaaed, b'T'),
+    (0xaaf6, 0xaaf6, b'T'), (0xabe5, 0xabe5, b'T'), (0xabe8, 0xabe8, b'T'), (0xabed, 0xabed, b'T'),
+    (0xfb1e, 0xfb1e, b'T'), (0xfe00, 0xfe0f, b'T'), (0xfe20, 0xfe2f, b'T'), (0xfeff, 0xfeff, b'T'),
+    (0xfff9, 0xfffb, b'T'), (0x101fd, 0x101fd, b'T'), (0x102e0, 0x102e0, b'T'),
+    (0x10376, 0x1037a, b'T'), (0x10a01, 0x10a03, b'T'), (0x10a05, 0x10a06, b'T'),
+    (0x10a0c, 0x10a0f, b'T'), (0x10a38, 0x10a3a, b'T'), (0x10a3f, 0x10a3f, b'T'),
+    (0x10ac0, 0x10ac4, b'D'), (0x10ac5, 0x10ac5, b'R'), (0x10ac7, 0x10ac7, b'R'),
+    (0x10ac9, 0x10aca, b'R'), (0x10acd, 0x10acd, b'L'), (0x10ace, 0x10ad2, b'R'),
+    (0x10ad3, 0x10ad6, b'D'), (0x10ad7, 0x10ad7, b'L'), (0x10ad8, 0x10adc, b'D'),
+    (0x10add, 0x10add, b'R'), (0x10ade, 0x10ae0, b'D'), (0x10ae1, 0x10ae1, b'R'),
+    (0x10ae4, 0x10ae4, b'R'), (0x10ae5, 0x10ae6, b'T'), (0x10aeb, 0x10aee, b'D'),
+    (0x10aef, 0x10aef, b'R'), (0x10b80, 0x10b80, b'D'), (0x10b81, 0x10b81, b'R'),
+    (0x10b82, 0x10b82, b'D'), (0x10b83, 0x10b85, b'R'), (0x10b86, 0x10b88, b'D'),
+    (0x10b89, 0x10b89, b'R'), (0x10b8a, 0x10b8b, b'D'), (0x10b8c, 0x10b8c, b'R'),
+    (0x10b8d, 0x10b8d, b'D'), (0x10b8e, 0x10b8f, b'R'), (0x10b90, 0x10b90, b'D'),
+    (0x10b91, 0x10b91, b'R'), (0x10ba9, 0x10bac, b'R'), (0x10bad, 0x10bae, b'D'),
+    (0x10d00, 0x10d00, b'L'), (0x10d01, 0x10d21, b'D'), (0x10d22, 0x10d22, b'R'),
+    (0x10d23, 0x10d23, b'D'), (0x10d24, 0x10d27, b'T'), (0x10eab, 0x10eac, b'T'),
+    (0x10efd, 0x10eff, b'T'), (0x10f30, 0x10f32, b'D'), (0x10f33, 0x10f33, b'R'),
+    (0x10f34, 0x10f44, b'D'), (0x10f46, 0x10f50, b'T'), (0x10f51, 0x10f53, b'D'),
+    (0x10f54, 0x10f54, b'R'), (0x10f70, 0x10f73, b'D'), (0x10f74, 0x10f75, b'R'),
+    (0x10f76, 0x10f81, b'D'), (0x10f82, 0x10f85, b'T'), (0x10fb0, 0x10fb0, b'D'),
+    (0x10fb2, 0x10fb3, b'D'), (0x10fb4, 0x10fb6, b'R'), (0x10fb8, 0x10fb8, b'D'),
+    (0x10fb9, 0x10fba, b'R'), (0x10fbb, 0x10fbc, b'D'), (0x10fbd, 0x10fbd, b'R'),
+    (0x10fbe, 0x10fbf, b'D'), (0x10fc1, 0x10fc1, b'D'), (0x10fc2, 0x10fc3, b'R'),
+    (0x10fc4, 0x10fc4, b'D'), (0x10fc9, 0x10fc9, b'R'), (0x10fca, 0x10fca, b'D'),
+    (0x10fcb, 0x10fcb, b'L'), (0x11001, 0x11001, b'T'), (0x11038, 0x11046, b'T'),
+    (0x11070, 0x11070, b'T'), (0x11073, 0x11074, b'T'), (0x1107f, 0x11081, b'T'),
+    (0x110b3, 0x110b6, b'T'), (0x110b9, 0x110ba, b'T'), (0x110c2, 0x110c2, b'T'),
+    (0x11100, 0x11102, b'T'), (0x11127, 0x1112b, b'T'), (0x1112d, 0x11134, b'T'),
+    (0x11173, 0x11173, b'T'), (0x11180, 0x11181, b'T'), (0x111b6, 0x111be, b'T'),
+    (0x111c9, 0x111cc, b'T'), (0x111cf, 0x111cf, b'T'), (0x1122f, 0x11231, b'T'),
+    (0x11234, 0x11234, b'T'), (0x11236, 0x11237, b'T'), (0x1123e, 0x1123e, b'T'),
+    (0x11241, 0x11241, b'T'), (0x112df, 0x112df, b'T'), (0x112e3, 0x112ea, b'T'),
+    (0x11300, 0x11301, b'T'), (0x1133b, 0x1133c, b'T'), (0x11340, 0x11340, b'T'),
+    (0x11366, 0x1136c, b'T'), (0x11370, 0x11374, b'T'), (0x11438, 0x1143f, b'T'),
+    (0x11442, 0x11444, b'T'), (0x11446, 0x11446, b'T'), (0x1145e, 0x1145e, b'T'),
+    (0x114b3, 0x114b8, b'T'), (0x114ba, 0x114ba, b'T'), (0x114bf, 0x114c0, b'T'),
+    (0x114c2, 0x114c3, b'T'), (0x115b2, 0x115b5, b'T'), (0x115bc, 0x115bd, b'T'),
+    (0x115bf, 0x115c0, b'T'), (0x115dc, 0x115dd, b'T'), (0x11633, 0x1163a, b'T'),
+    (0x1163d, 0x1163d, b'T'), (0x1163f, 0x11640, b'T'), (0x116ab, 0x116ab, b'T'),
+    (0x116ad, 0x116ad, b'T'), (0x116b0, 0x116b5, b'T'), (0x116b7, 0x116b7, b'T'),
+    (0x1171d, 0x1171f, b'T'), (0x11722, 0x11725, b'T'), (0x11727, 0x1172b, b'T'),
+    (0x1182f, 0x11837, b'T'), (0x11839, 0x1183a, b'T'), (0x1193b, 0x1193c, b'T'),
+    (0x1193e, 0x1193e, b'T'), (0x11943, 0x11943, b'T'), (0x119d4, 0x119d7, b'T'),
+    (0x119da, 0x119db, b'T'), (0x119e0, 0x119e0, b'T'), (0x11a01, 0x11a0a, b'T'),
+    (0x11a33, 0x11a38, b'T'), (0x11a3b, 0x11a3e, b'T'), (0x11a47, 0x11a47, b'T'),
+    (0x11a51, 0x11a56, b'T'), (0x11a59, 0x11a5b, b'T'), (0x11a8a, 0x11a96, b'T'),
+    (0x11a98, 0x11a99, b'T'), (0x11c30, 0x11c36, b'T'), (0x11c38, 0x11c3d, b'T'),
+    (0x11c3f, 0x11c3f, b'T'), (0x11c92, 0x11ca7, b'T'), (0x11caa, 0x11cb0, b'T'),
+    (0x11cb2, 0x11cb3, b'T'), (0x11cb5, 0x11cb6, b'T'), (0x11d31, 0x11d36, b'T'),
+    (0x11d3a, 0x11d3a, b'T'), (0x11d3c, 0x11d3d, b'T'), (0x11d3f, 0x11d45, b'T'),
+    (0x11d47, 0x11d47, b'T'), (0x11d90, 0x11d91, b'T'), (0x11d95, 0x11d95, b'T'),
+    (0x11d97, 0x11d97, b'T'), (0x11ef3, 0x11ef4, b'T'), (0x11f00, 0x11f01, b'T'),
+    (0x11f36, 0x11f3a, b'T'), (0x11f40, 0x11f40, b'T'), (0x11f42, 0x11f42, b'T'),
+    (0x13430, 0x13440, b'T'), (0x13447, 0x13455, b'T'), (0x16af0, 0x16af4, b'T'),
+    (0x16b30, 0x16b36, b'T'), (0x16f4f, 0x16f4f, b'T'), (0x16f8f, 0x16f92, b'T'),
+    (0x16fe4, 0x16fe4, b'T'), (0x1bc9d, 0x1bc9e, b'T'), (0x1bca0, 0x1bca3, b'T'),
+    (0x1cf00, 0x1cf2d, b'T'), (0x1cf30, 0x1cf46, b'T'), (0x1d167, 0x1d169, b'T'),
+    (0x1d173, 0x1d182, b'T'), (0x1d185, 0x1d18b, b'T'), (0x1d1aa, 0x1d1ad, b'T'),
+    (0x1d242, 0x1d244, b'T'), (0x1da00, 0x1da36, b'T'), (0x1da3b, 0x1da6c, b'T'),
+    (0x1da75, 0x1da75, b'T'), (0x1da84, 0x1da84, b'T'), (0x1da9b, 0x1da9f, b'T'),
+    (0x1daa1, 0x1daaf, b'T'), (0x1e000, 0x1e006, b'T'), (0x1e008, 0x1e018, b'T'),
+    (0x1e01b, 0x1e021, b'T'), (0x1e023, 0x1e024, b'T'), (0x1e026, 0x1e02a, b'T'),
+    (0x1e08f, 0x1e08f, b'T'), (0x1e130, 0x1e136, b'T'), (0x1e2ae, 0x1e2ae, b'T'),
+    (0x1e2ec, 0x1e2ef, b'T'), (0x1e4ec, 0x1e4ef, b'T'), (0x1e8d0, 0x1e8d6, b'T'),
+    (0x1e900, 0x1e943, b'D'), (0x1e944, 0x1e94b, b'T'), (0xe0001, 0xe0001, b'T'),
+    (0xe0020, 0xe007f, b'T'), (0xe0100, 0xe01ef, b'T'),
+];
+
+/// The code points of the Greek script, for CONTEXTO.
+pub static GREEK: &'static [(u32, u32)] = &[
+    (0x370, 0x373), (0x375, 0x377), (0x37a, 0x37d), (0x37f, 0x37f), (0x384, 0x384), (0x386, 0x386),
+    (0x388, 0x38a), (0x38c, 0x38c), (0x38e, 0x3a1), (0x3a3, 0x3e1), (0x3f0, 0x3ff),
+    (0x1d26, 0x1d2a), (0x1d5d, 0x1d61), (0x1d66, 0x1d6a), (0x1dbf, 0x1dbf), (0x1f00, 0x1f15),
+    (0x1f18, 0x1f1d), (0x1f20, 0x1f45), (0x1f48, 0x1f4d), (0x1f50, 0x1f57), (0x1f59, 0x1f59),
+    (0x1f5b, 0x1f5b), (0x1f5d, 0x1f5d), (0x1f5f, 0x1f7d), (0x1f80, 0x1fb4), (0x1fb6, 0x1fc4),
+    (0x1fc6, 0x1fd3), (0x1fd6, 0x1fdb), (0x1fdd, 0x1fef), (0x1ff2, 0x1ff4), (0x1ff6, 0x1ffe),
+    (0x2126, 0x2126), (0xab65, 0xab65), (0x10140, 0x1018e), (0x101a0, 0x101a0), (0x1d200, 0x1d245),
+];
+
+/// The code points of the Hebrew script, for CONTEXTO.
+pub static HEBREW: &'static [(u32, u32)] = &[
+    (0x591, 0x5c7), (0x5d0, 0x5ea), (0x5ef, 0x5f4), (0xfb1d, 0xfb36), (0xfb38, 0xfb3c),
+    (0xfb3e, 0xfb3e), (0xfb40, 0xfb41), (0xfb43, 0xfb44), (0xfb46, 0xfb4f),
+];
+
+/// The code points of the Hiragana script, for CONTEXTO.
+pub static HIRAGANA: &'static [(u32, u32)] = &[
+    (0x3041, 0x3096), (0x309d, 0x309f), (0x1b001, 0x1b11f), (0x1b132, 0x1b132), (0x1b150, 0x1b152),
+    (0x1f200, 0x1f200),
+];
+
+/// The code points of the Katakana script, for CONTEXTO.
+pub static KATAKANA: &'static [(u32, u32)] = &[
+    (0x30a1, 0x30fa), (0x30fd, 0x30ff), (0x31f0, 0x31ff), (0x32d0, 0x32fe), (0x3300, 0x3357),
+    (0xff66, 0xff6f), (0xff71, 0xff9d), (0x1aff0, 0x1aff3), (0x1aff5, 0x1affb), (0x1affd, 0x1affe),
+    (0x1b000, 0x1b000), (0x1b120, 0x1b122), (0x1b155, 0x1b155), (0x1b164, 0x1b167),
+];
+
+/// The code points of the Han script, for CONTEXTO.
+pub static HAN: &'static [(u32, u32)] = &[
+    (0x2e80, 0x2e99), (0x2e9b, 0x2ef3), (0x2f00, 0x2fd5), (0x3005, 0x3005), (0x3007, 0x3007),
+    (0x3021, 0x3029), (0x3038, 0x303b), (0x3400, 0x4dbf), (0x4e00, 0x9fff), (0xf900, 0xfa6d),
+    (0xfa70, 0xfad9), (0x16fe2, 0x16fe3), (0x16ff0, 0x16ff1), (0x20000, 0x2a6df),
+    (0x2a700, 0x2b739), (0x2b740, 0x2b81d), (0x2b820, 0x2cea1), (0x2ceb0, 0x2ebe0),
+    (0x2ebf0, 0x2ee5d), (0x2f800, 0x2fa1d), (0x30000, 0x3134a), (0x31350, 0x323af),
+];
+
+/// The combining marks, general category M, no label may start with.
+pub static MARKS: &'static [(u32, u32)] = &[
+    (0x300, 0x36f), (0x483, 0x489), (0x591, 0x5bd), (0x5bf, 0x5bf), (0x5c1, 0x5c2), (0x5c4, 0x5c5),
+    (0x5c7, 0x5c7), (0x610, 0x61a), (0x64b, 0x65f), (0x670, 0x670), (0x6d6, 0x6dc), (0x6df, 0x6e4),
+    (0x6e7, 0x6e8), (0x6ea, 0x6ed), (0x711, 0x711), (0x730, 0x74a), (0x7a6, 0x7b0), (0x7eb, 0x7f3),
+    (0x7fd, 0x7fd), (0x816, 0x819), (0x81b, 0x823), (0x825, 0x827), (0x829, 0x82d), (0x859, 0x85b),
+    (0x898, 0x89f), (0x8ca, 0x8e1), (0x8e3, 0x903), (0x93a, 0x93c), (0x93e, 0x94f), (0x951, 0x957),
+    (0x962, 0x963), (0x981, 0x983), (0x9bc, 0x9bc), (0x9be, 0x9c4), (0x9c7, 0x9c8), (0x9cb, 0x9cd),
+    (0x9d7, 0x9d7), (0x9e2, 0x9e3), (0x9fe, 0x9fe), (0xa01, 0xa03), (0xa3c, 0xa3c), (0xa3e, 0xa42),
+    (0xa47, 0xa48), (0xa4b, 0xa4d), (0xa51, 0xa51), (0xa70, 0xa71), (0xa75, 0xa75), (0xa81, 0xa83),
+    (0xabc, 0xabc), (0xabe, 0xac5), (0xac7, 0xac9), (0xacb, 0xacd), (0xae2, 0xae3), (0xafa, 0xaff),
+    (0xb01, 0xb03), (0xb3c, 0xb3c), (0xb3e, 0xb44), (0xb47, 0xb48), (0xb4b, 0xb4d), (0xb55, 0xb57),
+    (0xb62, 0xb63), (0xb82, 0xb82), (0xbbe, 0xbc2), (0xbc6, 0xbc8), (0xbca, 0xbcd), (0xbd7, 0xbd7),
+    (0xc00, 0xc04), (0xc3c, 0xc3c), (0xc3e, 0xc44), (0xc46, 0xc48), (0xc4a, 0xc4d), (0xc55, 0xc56),
+    (0xc62, 0xc63), (0xc81, 0xc83), (0xcbc, 0xcbc), (0xcbe, 0xcc4), (0xcc6, 0xcc8), (0xcca, 0xccd),
+    (0xcd5, 0xcd6), (0xce2, 0xce3), (0xcf3, 0xcf3), (0xd00, 0xd03), (0xd3b, 0xd3c), (0xd3e, 0xd44),
+    (0xd46, 0xd48), (0xd4a, 0xd4d), (0xd57, 0xd57), (0xd62, 0xd63), (0xd81, 0xd83), (0xdca, 0xdca),
+    (0xdcf, 0xdd4), (0xdd6, 0xdd6), (0xdd8, 0xddf), (0xdf2, 0xdf3), (0xe31, 0xe31), (0xe34, 0xe3a),
+    (0xe47, 0xe4e), (0xeb1, 0xeb1), (0xeb4, 0xebc), (0xec8, 0xece), (0xf18, 0xf19), (0xf35, 0xf35),
+    (0xf37, 0xf37), (0xf39, 0xf39), (0xf3e, 0xf3f), (0xf71, 0xf84), (0xf86, 0xf87), (0xf8d, 0xf97),
+    (0xf99, 0xfbc), (0xfc6, 0xfc6), (0x102b, 0x103e), (0x1056, 0x1059), (0x105e, 0x1060),
+    (0x1062, 0x1064), (0x1067, 0x106d), (0x1071, 0x1074), (0x1082, 0x108d), (0x108f, 0x108f),
+    (0x109a, 0x109d), (0x135d, 0x135f), (0x1712, 0x1715), (0x1732, 0x1734), (0x1752, 0x1753),
+    (0x1772, 0x1773), (0x17b4, 0x17d3), (0x17dd, 0x17dd), (0x180b, 0x180d), (0x180f, 0x180f),
+    (0x1885, 0x1886), (0x18a9, 0x18a9), (0x1920, 0x192b), (0x1930, 0x193b), (0x1a17, 0x1a1b),
+    (0x1a55, 0x1a5e), (0x1a60, 0x1a7c), (0x1a7f, 0x1a7f), (0x1ab0, 0x1ace), (0x1b00, 0x1b04),
+    (0x1b34, 0x1b44), (0x1b6b, 0x1b73), (0x1b80, 0x1b82), (0x1ba1, 0x1bad), (0x1be6, 0x1bf3),
+    (0x1c24, 0x1c37), (0x1cd0, 0x1cd2), (0x1cd4, 0x1ce8), (0x1ced, 0x1ced), (0x1cf4, 0x1cf4),
+    (0x1cf7, 0x1cf9), (0x1dc0, 0x1dff), (0x20d0, 0x20f0), (0x2cef, 0x2cf1), (0x2d7f, 0x2d7f),
+    (0x2de0, 0x2dff), (0x302a, 0x302f), (0x3099, 0x309a), (0xa66f, 0xa672), (0xa674, 0xa67d),
+    (0xa69e, 0xa69f), (0xa6f0, 0xa6f1), (0xa802, 0xa802), (0xa806, 0xa806), (0xa80b, 0xa80b),
+    (0xa823, 0xa827), (0xa82c, 0xa82c), (0xa880, 0xa881), (0xa8b4, 0xa8c5), (0xa8e0, 0xa8f1),
+    (0xa8ff, 0xa8ff), (0xa926, 0xa92d), (0xa947, 0xa953), (0xa980, 0xa983), (0xa9b3, 0xa9c0),
+    (0xa9e5, 0xa9e5), (0xaa29, 0xaa36), (0xaa43, 0xaa43), (0xaa4c, 0xaa4d), (0xaa7b, 0xaa7d),
+    (0xaab0, 0xaab0), (0xaab2, 0xaab4), (0xaab7, 0xaab8), (0xaabe, 0xaabf), (0xaac1, 0xaac1),
+    (0xaaeb, 0xaaef), (0xaaf5, 0xaaf6), (0xabe3, 0xabea), (0xabec, 0xabed), (0xfb1e, 0xfb1e),
+    (0xfe00, 0xfe0f), (0xfe20, 0xfe2f), (0x101fd, 0x101fd), (0x102e0, 0x102e0), (0x10376, 0x1037a),
+    (0x10a01, 0x10a03), (0x10a05, 0x10a06), (0x10a0c, 0x10a0f), (0x10a38, 0x10a3a),
+    (0x10a3f, 0x10a3f), (0x10ae5, 0x10ae6), (0x10d24, 0x10d27), (0x10eab, 0x10eac),
+    (0x10efd, 0x10eff), (0x10f46, 0x10f50), (0x10f82, 0x10f85), (0x11000, 0x11002),
+    (0x11038, 0x11046), (0x11070, 0x11070), (0x11073, 0x11074), (0x1107f, 0x11082),
+    (0x110b0, 0x110ba), (0x110c2, 0x110c2), (0x11100, 0x11102), (0x11127, 0x11134),
+    (0x11145, 0x11146), (0x11173, 0x11173), (0x11180, 0x11182), (0x111b3, 0x111c0),
+    (0x111c9, 0x111cc), (0x111ce, 0x111cf), (0x1122c, 0x11237), (0x1123e, 0x1123e),
+    (0x11241, 0x11241), (0x112df, 0x112ea), (0x11300, 0x11303), (0x1133b, 0x1133c),
+    (0x1133e, 0x11344), (0x11347, 0x11348), (0x1134b, 0x1134d), (0x11357, 0x11357),
+    (0x11362, 0x11363), (0x11366, 0x1136c), (0x11370, 0x11374), (0x11435, 0x11446),
+    (0x1145e, 0x1145e), (0x114b0, 0x114c3), (0x115af, 0x115b5), (0x115b8, 0x115c0),
+    (0x115dc, 0x115dd), (0x11630, 0x11640), (0x116ab, 0x116b7), (0x1171d, 0x1172b),
+    (0x1182c, 0x1183a), (0x11930, 0x11935), (0x11937, 0x11938), (0x1193b, 0x1193e),
+    (0x11940, 0x11940), (0x11942, 0x11943), (0x119d1, 0x119d7), (0x119da, 0x119e0),
+    (0x119e4, 0x119e4), (0x11a01, 0x11a0a), (0x11a33, 0x11a39), (0x11a3b, 0x11a3e),
+    (0x11a47, 0x11a47), (0x11a51, 0x11a5b), (0x11a8a, 0x11a99), (0x11c2f, 0x11c36),
+    (0x11c38, 0x11c3f), (0x11c92, 0x11ca7), (0x11ca9, 0x11cb6), (0x11d31, 0x11d36),
+    (0x11d3a, 0x11d3a), (0x11d3c, 0x11d3d), (0x11d3f, 0x11d45), (0x11d47, 0x11d47),
+    (0x11d8a, 0x11d8e), (0x11d90, 0x11d91), (0x11d93, 0x11d97), (0x11ef3, 0x11ef6),
+    (0x11f00, 0x11f01), (0x11f03, 0x11f03), (0x11f34, 0x11f3a), (0x11f3e, 0x11f42),
+    (0x13440, 0x13440), (0x13447, 0x13455), (0x16af0, 0x16af4), (0x16b30, 0x16b36),
+    (0x16f4f, 0x16f4f), (0x16f51, 0x16f87), (0x16f8f, 0x16f92), (0x16fe4, 0x16fe4),
+    (0x16ff0, 0x16ff1), (0x1bc9d, 0x1bc9e), (0x1cf00, 0x1cf2d), (0x1cf30, 0x1cf46),
+    (0x1d165, 0x1d169), (0x1d16d, 0x1d172), (0x1d17b, 0x1d182), (0x1d185, 0x1d18b),
+    (0x1d1aa, 0x1d1ad), (0x1d242, 0x1d244), (0x1da00, 0x1da36), (0x1da3b, 0x1da6c),
+    (0x1da75, 0x1da75), (0x1da84, 0x1da84), (0x1da9b, 0x1da9f), (0x1daa1, 0x1daaf),
+    (0x1e000, 0x1e006), (0x1e008, 0x1e018), (0x1e01b, 0x1e021), (0x1e023, 0x1e024),
+    (0x1e026, 0x1e02a), (0x1e08f, 0x1e08f), (0x1e130, 0x1e136), (0x1e2ae, 0x1e2ae),
+    (0x1e2ec, 0x1e2ef), (0x1e4ec, 0x1e4ef), (0x1e8d0, 0x1e8d6), (0x1e944, 0x1e94a),
+    (0xe0100, 0xe01ef),
+];
+
+/// The bidirectional classes of the assigned code points.
+pub static BIDI: &'static [(u32, u32, Bidi)] = &[
+    (0x0, 0x8, Bidi::BN), (0x9, 0x9, Bidi::S), (0xa, 0xa, Bidi::B), (0xb, 0xb, Bidi::S),
+    (0xc, 0xc, Bidi::WS), (0xd, 0xd, Bidi::B), (0xe, 0x1b, Bidi::BN), (0x1c, 0x1e, Bidi::B),
+    (0x1f, 0x1f, Bidi::S), (0x20, 0x20, Bidi::WS), (0x21, 0x22, Bidi::ON), (0x23, 0x25, Bidi::ET),
+    (0x26, 0x2a, Bidi::ON), (0x2b, 0x2b, Bidi::ES), (0x2c, 0x2c, Bidi::CS), (0x2d, 0x2d, Bidi::ES),
+    (0x2e, 0x2f, Bidi::CS), (0x30, 0x39, Bidi::EN), (0x3a, 0x3a, Bidi::CS), (0x3b, 0x40, Bidi::ON),
+    (0x41, 0x5a, Bidi::L), (0x5b, 0x60, Bidi::ON), (0x61, 0x7a, Bidi::L), (0x7b, 0x7e, Bidi::ON),
+    (0x7f, 0x84, Bidi::BN), (0x85, 0x85, Bidi::B), (0x86, 0x9f, Bidi::BN), (0xa0, 0xa0, Bidi::CS),
+    (0xa1, 0xa1, Bidi::ON), (0xa2, 0xa5, Bidi::ET), (0xa6, 0xa9, Bidi::ON), (0xaa, 0xaa, Bidi::L),
+    (0xab, 0xac, Bidi::ON), (0xad, 0xad, Bidi::BN), (0xae, 0xaf, Bidi::ON), (0xb0, 0xb1, Bidi::ET),
+    (0xb2, 0xb3, Bidi::EN), (0xb4, 0xb4, Bidi::ON), (0xb5, 0xb5, Bidi::L), (0xb6, 0xb8, Bidi::ON),
+    (0xb9, 0xb9, Bidi::EN), (0xba, 0xba, Bidi::L), (0xbb, 0xbf, Bidi::ON), (0xc0, 0xd6, Bidi::L),
+    (0xd7, 0xd7, Bidi::ON), (0xd8, 0xf6, Bidi::L), (0xf7, 0xf7, Bidi::ON), (0xf8, 0x2b8, Bidi::L),
+    (0x2b9, 0x2ba, Bidi::ON), (0x2bb, 0x2c1, Bidi::L), (0x2c2, 0x2cf, Bidi::ON),
+    (0x2d0, 0x2d1, Bidi::L), (0x2d2, 0x2df, Bidi::ON), (0x2e0, 0x2e4, Bidi::L),
+    (0x2e5, 0x2ed, Bidi::ON), (0x2ee, 0x2ee, Bidi::L), (0x2ef, 0x2ff, Bidi::ON),
+    (0x300, 0x36f, Bidi::NSM), (0x370, 0x373, Bidi::L), (0x374, 0x375, Bidi::ON),
+    (0x376, 0x377, Bidi::L), (0x37a, 0x37d, Bidi::L), (0x37e, 0x37e, Bidi::ON),
+    (0x37f, 0x37f, Bidi::L), (0x384, 0x385, Bidi::ON), (0x386, 0x386, Bidi::L),
+    (0x387, 0x387, Bidi::ON), (0x388, 0x38a, Bidi::L), (0x38c, 0x38c, Bidi::L),
+    (0x38e, 0x3a1, Bidi::L), (0x3a3, 0x3f5, Bidi::L), (0x3f6, 0x3f6, Bidi::ON),
+    (0x3f7, 0x482, Bidi::L), (0x483, 0x489, Bidi::NSM), (0x48a, 0x52f, Bidi::L),
+    (0x531, 0x556, Bidi::L), (0x559, 0x589, Bidi::L), (0x58a, 0x58a, Bidi::ON),
+    (0x58d, 0x58e, Bidi::ON), (0x58f, 0x58f, Bidi::ET), (0x591, 0x5bd, Bidi::NSM),
+    (0x5be, 0x5be, Bidi::R), (0x5bf, 0x5bf, Bidi::NSM), (0x5c0, 0x5c0, Bidi::R),
+    (0x5c1, 0x5c2, Bidi::NSM), (0x5c3, 0x5c3, Bidi::R), (0x5c4, 0x5c5, Bidi::NSM),
+    (0x5c6, 0x5c6, Bidi::R), (0x5c7, 0x5c7, Bidi::NSM), (0x5d0, 0x5ea, Bidi::R),
+    (0x5ef, 0x5f4, Bidi::R), (0x600, 0x605, Bidi::AN), (0x606, 0x607, Bidi::ON),
+    (0x608, 0x608, Bidi::AL), (0x609, 0x60a, Bidi::ET), (0x60b, 0x60b, Bidi::AL),
+    (0x60c, 0x60c, Bidi::CS), (0x60d, 0x60d, Bidi::AL), (0x60e, 0x60f, Bidi::ON),
+    (0x610, 0x61a, Bidi::NSM), (0x61b, 0x64a, Bidi::AL), (0x64b, 0x65f, Bidi::NSM),
+    (0x660, 0x669, Bidi::AN), (0x66a, 0x66a, Bidi::ET), (0x66b, 0x66c, Bidi::AN),
+    (0x66d, 0x66f, Bidi::AL), (0x670, 0x670, Bidi::NSM), (0x671, 0x6d5, Bidi::AL),
+    (0x6d6, 0x6dc, Bidi::NSM), (0x6dd, 0x6dd, Bidi::AN), (0x6de, 0x6de, Bidi::ON),
+    (0x6df, 0x6e4, Bidi::NSM), (0x6e5, 0x6e6, Bidi::AL), (0x6e7, 0x6e8, Bidi::NSM),
+    (0x6e9, 0x6e9, Bidi::ON), (0x6ea, 0x6ed, Bidi::NSM), (0x6ee, 0x6ef, Bidi::AL),
+    (0x6f0, 0x6f9, Bidi::EN), (0x6fa, 0x70d, Bidi::AL), (0x70f, 0x710, Bidi::AL),
+    (0x711, 0x711, Bidi::NSM), (0x712, 0x72f, Bidi::AL), (0x730, 0x74a, Bidi::NSM),
+    (0x74d, 0x7a5, Bidi::AL), (0x7a6, 0x7b0, Bidi::NSM), (0x7b1, 0x7b1, Bidi::AL),
+    (0x7c0, 0x7ea, Bidi::R), (0x7eb, 0x7f3, Bidi::NSM), (0x7f4, 0x7f5, Bidi::R),
+    (0x7f6, 0x7f9, Bidi::ON), (0x7fa, 0x7fa, Bidi::R), (0x7fd, 0x7fd, Bidi::NSM),
+    (0x7fe, 0x815, Bidi::R), (0x816, 0x819, Bidi::NSM), (0x81a, 0x81a, Bidi::R),
+    (0x81b, 0x823, Bidi::NSM), (0x824, 0x824, Bidi::R), (0x825, 0x827, Bidi::NSM),
+    (0x828, 0x828, Bidi::R), (0x829, 0x82d, Bidi::NSM), (0x830, 0x83e, Bidi::R),
+    (0x840, 0x858, Bidi::R), (0x859, 0x85b, Bidi::NSM), (0x85e, 0x85e, Bidi::R),
+    (0x860, 0x86a, Bidi::AL), (0x870, 0x88e, Bidi::AL), (0x890, 0x891, Bidi::AN),
+    (0x898, 0x89f, Bidi::NSM), (0x8a0, 0x8c9, Bidi::AL), (0x8ca, 0x8e1, Bidi::NSM),
+    (0x8e2, 0x8e2, Bidi::AN), (0x8e3, 0x902, Bidi::NSM), (0x903, 0x939, Bidi::L),
+    (0x93a, 0x93a, Bidi::NSM), (0x93b, 0x93b, Bidi::L), (0x93c, 0x93c, Bidi::NSM),
+    (0x93d, 0x940, Bidi::L), (0x941, 0x948, Bidi::NSM), (0x949, 0x94c, Bidi::L),
+    (0x94d, 0x94d, Bidi::NSM), (0x94e, 0x950, Bidi::L), (0x951, 0x957, Bidi::NSM),
+    (0x958, 0x961, Bidi::L), (0x962, 0x963, Bidi::NSM), (0x964, 0x980, Bidi::L),
+    (0x981, 0x981, Bidi::NSM), (0x982, 0x983, Bidi::L), (0x985, 0x98c, Bidi::L),
+    (0x98f, 0x990, Bidi::L), (0x993, 0x9a8, Bidi::L), (0x9aa, 0x9b0, Bidi::L),
+    (0x9b2, 0x9b2, Bidi::L), (0x9b6, 0x9b9, Bidi::L), (0x9bc, 0x9bc, Bidi::NSM),
+    (0x9bd, 0x9c0, Bidi::L), (0x9c1, 0x9c4, Bidi::NSM), (0x9c7, 0x9c8, Bidi::L),
+    (0x9cb, 0x9cc, Bidi::L), (0x9cd, 0x9cd, Bidi::NSM), (0x9ce, 0x9ce, Bidi::L),
+    (0x9d7, 0x9d7, Bidi::L), (0x9dc, 0x9dd, Bidi::L), (0x9df, 0x9e1, Bidi::L),
+    (0x9e2, 0x9e3, Bidi::NSM), (0x9e6, 0x9f1, Bidi::L), (0x9f2, 0x9f3, Bidi::ET),
+    (0x9f4, 0x9fa, Bidi::L), (0x9fb, 0x9fb, Bidi::ET), (0x9fc, 0x9fd, Bidi::L),
+    (0x9fe, 0x9fe, Bidi::NSM), (0xa01, 0xa02, Bidi::NSM), (0xa03, 0xa03, Bidi::L),
+    (0xa05, 0xa0a, Bidi::L), (0xa0f, 0xa10, Bidi::L), (0xa13, 0xa28, Bidi::L),
+    (0xa2a, 0xa30, Bidi::L), (0xa32, 0xa33, Bidi::L), (0xa35, 0xa36, Bidi::L),
+    (0xa38, 0xa39, Bidi::L), (0xa3c, 0xa3c, Bidi::NSM), (0xa3e, 0xa40, Bidi::L),
+    (0xa41, 0xa42, Bidi::NSM), (0xa47, 0xa48, Bidi::NSM), (0xa4b, 0xa4d, Bidi::NSM),
+    (0xa51, 0xa51, Bidi::NSM), (0xa59, 0xa5c, Bidi::L), (0xa5e, 0xa5e, Bidi::L),
+    (0xa66, 0xa6f, Bidi::L), (0xa70, 0xa71, Bidi::NSM), (0xa72, 0xa74, Bidi::L),
+    (0xa75, 0xa75, Bidi::NSM), (0xa76, 0xa76, Bidi::L), (0xa81, 0xa82, Bidi::NSM),
+    (0xa83, 0xa83, Bidi::L), (0xa85, 0xa8d, Bidi::L), (0xa8f, 0xa91, Bidi::L),
+    (0xa93, 0xaa8, Bidi::L), (0xaaa, 0xab0, Bidi::L), (0xab2, 0xab3, Bidi::L),
+    (0xab5, 0xab9, Bidi::L), (0xabc, 0xabc, Bidi::NSM), (0xabd, 0xac0, Bidi::L),
+    (0xac1, 0xac5, Bidi::NSM), (0xac7, 0xac8, Bidi::NSM), (0xac9, 0xac9, Bidi::L),
+    (0xacb, 0xacc, Bidi::L), (0xacd, 0xacd, Bidi::NSM), (0xad0, 0xad0, Bidi::L),
+    (0xae0, 0xae1, Bidi::L), (0xae2, 0xae3, Bidi::NSM), (0xae6, 0xaf0, Bidi::L),
+    (0xaf1, 0xaf1, Bidi::ET), (0xaf9, 0xaf9, Bidi::L), (0xafa, 0xaff, Bidi::NSM),
+    (0xb01, 0xb01, Bidi::NSM), (0xb02, 0xb03, Bidi::L), (0xb05, 0xb0c, Bidi::L),
+    (0xb0f, 0xb10, Bidi::L), (0xb13, 0xb28, Bidi::L), (0xb2a, 0xb30, Bidi::L),
+    (0xb32, 0xb33, Bidi::L), (0xb35, 0xb39, Bidi::L), (0xb3c, 0xb3c, Bidi::NSM),
+    (0xb3d, 0xb3e, Bidi::L), (0xb3f, 0xb3f, Bidi::NSM), (0xb40, 0xb40, Bidi::L),
+    (0xb41, 0xb44, Bidi::NSM), (0xb47, 0xb48, Bidi::L), (0xb4b, 0xb4c, Bidi::L),
+    (0xb4d, 0xb4d, Bidi::NSM), (0xb55, 0xb56, Bidi::NSM), (0xb57, 0xb57, Bidi::L),
+    (0xb5c, 0xb5d, Bidi::L), (0xb5f, 0xb61, Bidi::L), (0xb62, 0xb63, Bidi::NSM),
+    (0xb66, 0xb77, Bidi::L), (0xb82, 0xb82, Bidi::NSM), (0xb83, 0xb83, Bidi::L),
+    (0xb85, 0xb8a, Bidi::L), (0xb8e, 0xb90, Bidi::L), (0xb92, 0xb95, Bidi::L),
+    (0xb99, 0xb9a, Bidi::L), (0xb9c, 0xb9c, Bidi::L), (0xb9e, 0xb9f, Bidi::L),
+    (0xba3, 0xba4, Bidi::L), (0xba8, 0xbaa, Bidi::L), (0xbae, 0xbb9, Bidi::L),
+    (0xbbe, 0xbbf, Bidi::L), (0xbc0, 0xbc0, Bidi::NSM), (0xbc1, 0xbc2, Bidi::L),
+    (0xbc6, 0xbc8, Bidi::L), (0xbca, 0xbcc, Bidi::L), (0xbcd, 0xbcd, Bidi::NSM),
+    (0xbd0, 0xbd0, Bidi::L), (0xbd7, 0xbd7, Bidi::L), (0xbe6, 0xbf2, Bidi::L),
+    (0xbf3, 0xbf8, Bidi::ON), (0xbf9, 0xbf9, Bidi::ET), (0xbfa, 0xbfa, Bidi::ON),
+    (0xc00, 0xc00, Bidi::NSM), (0xc01, 0xc03, Bidi::L), (0xc04, 0xc04, Bidi::NSM),
+    (0xc05, 0xc0c, Bidi::L), (0xc0e, 0xc10, Bidi::L), (0xc12, 0xc28, Bidi::L),
+    (0xc2a, 0xc39, Bidi::L), (0xc3c, 0xc3c, Bidi::NSM), (0xc3d, 0xc3d, Bidi::L),
+    (0xc3e, 0xc40, Bidi::NSM), (0xc41, 0xc44, Bidi::L), (0xc46, 0xc48, Bidi::NSM),
+    (0xc4a, 0xc4d, Bidi::NSM), (0xc55, 0xc56, Bidi::NSM), (0xc58, 0xc5a, Bidi::L),
+    (0xc5d, 0xc5d, Bidi::L), (0xc60, 0xc61, Bidi::L), (0xc62, 0xc63, Bidi::NSM),
+    (0xc66, 0xc6f, Bidi::L), (0xc77, 0xc77, Bidi::L), (0xc78, 0xc7e, Bidi::ON),
+    (0xc7f, 0xc80, Bidi::L), (0xc81, 0xc81, Bidi::NSM), (0xc82, 0xc8c, Bidi::L),
+    (0xc8e, 0xc90, Bidi::L), (0xc92, 0xca8, Bidi::L), (0xcaa, 0xcb3, Bidi::L),
+    (0xcb5, 0xcb9, Bidi::L), (0xcbc, 0xcbc, Bidi::NSM), (0xcbd, 0xcc4, Bidi::L),
+    (0xcc6, 0xcc8, Bidi::L), (0xcca, 0xccb, Bidi::L), (0xccc, 0xccd, Bidi::NSM),
+    (0xcd5, 0xcd6, Bidi::L), (0xcdd, 0xcde, Bidi::L), (0xce0, 0xce1, Bidi::L),
+    (0xce2, 0xce3, Bidi::NSM), (0xce6, 0xcef, Bidi::L), (0xcf1, 0xcf3, Bidi::L),
+    (0xd00, 0xd01, Bidi::NSM), (0xd02, 0xd0c, Bidi::L), (0xd0e, 0xd10, Bidi::L),
+    (0xd12, 0xd3a, Bidi::L), (0xd3b, 0xd3c, Bidi::NSM), (0xd3d, 0xd40, Bidi::L),
+    (0xd41, 0xd44, Bidi::NSM), (0xd46, 0xd48, Bidi::L), (0xd4a, 0xd4c, Bidi::L),
+    (0xd4d, 0xd4d, Bidi::NSM), (0xd4e, 0xd4f, Bidi::L), (0xd54, 0xd61, Bidi::L),
+    (0xd62, 0xd63, Bidi::NSM), (0xd66, 0xd7f, Bidi::L), (0xd81, 0xd81, Bidi::NSM),
+    (0xd82, 0xd83, Bidi::L), (0xd85, 0xd96, Bidi::L), (0xd9a, 0xdb1, Bidi::L),
+    (0xdb3, 0xdbb, Bidi::L), (0xdbd, 0xdbd, Bidi::L), (0xdc0, 0xdc6, Bidi::L),
+    (0xdca, 0xdca, Bidi::NSM), (0xdcf, 0xdd1, Bidi::L), (0xdd2, 0xdd4, Bidi::NSM),
+    (0xdd6, 0xdd6, Bidi::NSM), (0xdd8, 0xddf, Bidi::L), (0xde6, 0xdef, Bidi::L),
+    (0xdf2, 0xdf4, Bidi::L), (0xe01, 0xe30, Bidi::L), (0xe31, 0xe31, Bidi::NSM),
+    (0xe32, 0xe33, Bidi::L), (0xe34, 0xe3a, Bidi::NSM), (0xe3f, 0xe3f, Bidi::ET),
+    (0xe40, 0xe46, Bidi::L), (0xe47, 0xe4e, Bidi::NSM), (0xe4f, 0xe5b, Bidi::L),
+    (0xe81, 0xe82, Bidi::L), (0xe84, 0xe84, Bidi::L), (0xe86, 0xe8a, Bidi::L),
+    (0xe8c, 0xea3, Bidi::L), (0xea5, 0xea5, Bidi::L), (0xea7, 0xeb0, Bidi::L),
+    (0xeb1, 0xeb1, Bidi::NSM), (0xeb2, 0xeb3, Bidi::L), (0xeb4, 0xebc, Bidi::NSM),
+    (0xebd, 0xebd, Bidi::L), (0xec0, 0xec4, Bidi::L), (0xec6, 0xec6, Bidi::L),
+    (0xec8, 0xece, Bidi::NSM), (0xed0, 0xed9, Bidi::L), (0xedc, 0xedf, Bidi::L),
+    (0xf00, 0xf17, Bidi::L), (0xf18, 0xf19, Bidi::NSM), (0xf1a, 0xf34, Bidi::L),
+    (0xf35, 0xf35, Bidi::NSM), (0xf36, 0xf36, Bidi::L), (0xf37, 0xf37, Bidi::NSM),
+    (0xf38, 0xf38, Bidi::L), (0xf39, 0xf39, Bidi::NSM), (0xf3a, 0xf3d, Bidi::ON),
+    (0xf3e, 0xf47, Bidi::L), (0xf49, 0xf6c, Bidi::L), (0xf71, 0xf7e, Bidi::NSM),
+    (0xf7f, 0xf7f, Bidi::L), (0xf80, 0xf84, Bidi::NSM), (0xf85, 0xf85, Bidi::L),
+    (0xf86, 0xf87, Bidi::NSM), (0xf88, 0xf8c, Bidi::L), (0xf8d, 0xf97, Bidi::NSM),
+    (0xf99, 0xfbc, Bidi::NSM), (0xfbe, 0xfc5, Bidi::L), (0xfc6, 0xfc6, Bidi::NSM),
+    (0xfc7, 0xfcc, Bidi::L), (0xfce, 0xfda, Bidi::L), (0x1000, 0x102c, Bidi::L),
+    (0x102d, 0x1030, Bidi::NSM), (0x1031, 0x1031, Bidi::L), (0x1032, 0x1037, Bidi::NSM),
+    (0x1038, 0x1038, Bidi::L), (0x1039, 0x103a, Bidi::NSM), (0x103b, 0x103c, Bidi::L),
+    (0x103d, 0x103e, Bidi::NSM), (0x103f, 0x1057, Bidi::L), (0x1058, 0x1059, Bidi::NSM),
+    (0x105a, 0x105d, Bidi::L), (0x105e, 0x1060, Bidi::NSM), (0x1061, 0x1070, Bidi::L),
+    (0x1071, 0x1074, Bidi::NSM), (0x1075, 0x1081, Bidi::L), (0x1082, 0x1082, Bidi::NSM),
+    (0x1083, 0x1084, Bidi::L), (0x1085, 0x1086, Bidi::NSM), (0x1087, 0x108c, Bidi::L),
+    (0x108d, 0x108d, Bidi::NSM), (0x108e, 0x109c, Bidi::L), (0x109d, 0x109d, Bidi::NSM),
+    (0x109e, 0x10c5, Bidi::L), (0x10c7, 0x10c7, Bidi::L), (0x10cd, 0x10cd, Bidi::L),
+    (0x10d0, 0x1248, Bidi::L), (0x124a, 0x124d, Bidi::L), (0x1250, 0x1256, Bidi::L),
+    (0x1258, 0x1258, Bidi::L), (0x125a, 0x125d, Bidi::L), (0x1260, 0x1288, Bidi::L),
+    (0x128a, 0x128d, Bidi::L), (0x1290, 0x12b0, Bidi::L), (0x12b2, 0x12b5, Bidi::L),
+    (0x12b8, 0x12be, Bidi::L), (0x12c0, 0x12c0, Bidi::L), (0x12c2, 0x12c5, Bidi::L),
+    (0x12c8, 0x12d6, Bidi::L), (0x12d8, 0x1310, Bidi::L), (0x1312, 0x1315, Bidi::L),
+    (0x1318, 0x135a, Bidi::L), (0x135d, 0x135f, Bidi::NSM), (0x1360, 0x137c, Bidi::L),
+    (0x1380, 0x138f, Bidi::L), (0x1390, 0x1399, Bidi::ON), (0x13a0, 0x13f5, Bidi::L),
+    (0x13f8, 0x13fd, Bidi::L), (0x1400, 0x1400, Bidi::ON), (0x1401, 0x167f, Bidi::L),
+    (0x1680, 0x1680, Bidi::WS), (0x1681, 0x169a, Bidi::L), (0x169b, 0x169c, Bidi::ON),
+    (0x16a0, 0x16f8, Bidi::L), (0x1700, 0x1711, Bidi::L), (0x1712, 0x1714, Bidi::NSM),
+    (0x1715, 0x1715, Bidi::L), (0x171f, 0x1731, Bidi::L), (0x1732, 0x1733, Bidi::NSM),
+    (0x1734, 0x1736, Bidi::L), (0x1740, 0x1751, Bidi::L), (0x1752, 0x1753, Bidi::NSM),
+    (0x1760, 0x176c, Bidi::L), (0x176e, 0x1770, Bidi::L), (0x1772, 0x1773, Bidi::NSM),
+    (0x1780, 0x17b3, Bidi::L), (0x17b4, 0x17b5, Bidi::NSM), (0x17b6, 0x17b6, Bidi::L),
+    (0x17b7, 0x17bd, Bidi::NSM), (0x17be, 0x17c5, Bidi::L), (0x17c6, 0x17c6, Bidi::NSM),
+    (0x17c7, 0x17c8, Bidi::L), (0x17c9, 0x17d3, Bidi::NSM), (0x17d4, 0x17da, Bidi::L),
+    (0x17db, 0x17db, Bidi::ET), (0x17dc, 0x17dc, Bidi::L), (0x17dd, 0x17dd, Bidi::NSM),
+    (0x17e0, 0x17e9, Bidi::L), (0x17f0, 0x17f9, Bidi::ON), (0x1800, 0x180a, Bidi::ON),
+    (0x180b, 0x180d, Bidi::NSM), (0x180e, 0x180e, Bidi::BN), (0x180f, 0x180f, Bidi::NSM),
+    (0x1810, 0x1819, Bidi::L), (0x1820, 0x1878, Bidi::L), (0x1880, 0x1884, Bidi::L),
+    (0x1885, 0x1886, Bidi::NSM), (0x1887, 0x18a8, Bidi::L), (0x18a9, 0x18a9, Bidi::NSM),
+    (0x18aa, 0x18aa, Bidi::L), (0x18b0, 0x18f5, Bidi::L), (0x1900, 0x191e, Bidi::L),
+    (0x1920, 0x1922, Bidi::NSM), (0x1923, 0x1926, Bidi::L), (0x1927, 0x1928, Bidi::NSM),
+    (0x1929, 0x192b, Bidi::L), (0x1930, 0x1931, Bidi::L), (0x1932, 0x1932, Bidi::NSM),
+    (0x1933, 0x1938, Bidi::L), (0x1939, 0x193b, Bidi::NSM), (0x1940, 0x1940, Bidi::ON),
+    (0x1944, 0x1945, Bidi::ON), (0x1946, 0x196d, Bidi::L), (0x1970, 0x1974, Bidi::L),
+    (0x1980, 0x19ab, Bidi::L), (0x19b0, 0x19c9, Bidi::L), (0x19d0, 0x19da, Bidi::L),
+    (0x19de, 0x19ff, Bidi::ON), (0x1a00, 0x1a16, Bidi::L), (0x1a17, 0x1a18, Bidi::NSM),
+    (0x1a19, 0x1a1a, Bidi::L), (0x1a1b, 0x1a1b, Bidi::NSM), (0x1a1e, 0x1a55, Bidi::L),
+    (0x1a56, 0x1a56, Bidi::NSM), (0x1a57, 0x1a57, Bidi::L), (0x1a58, 0x1a5e, Bidi::NSM),
+    (0x1a60, 0x1a60, Bidi::NSM), (0x1a61, 0x1a61, Bidi::L), (0x1a62, 0x1a62, Bidi::NSM),
+    (0x1a63, 0x1a64, Bidi::L), (0x1a65, 0x1a6c, Bidi::NSM), (0x1a6d, 0x1a72, Bidi::L),
+    (0x1a73, 0x1a7c, Bidi::NSM), (0x1a7f, 0x1a7f, Bidi::NSM), (0x1a80, 0x1a89, Bidi::L),
+    (0x1a90, 0x1a99, Bidi::L), (0x1aa0, 0x1aad, Bidi::L), (0x1ab0, 0x1ace, Bidi::NSM),
+    (0x1b00, 0x1b03, Bidi::NSM), (0x1b04, 0x1b33, Bidi::L), (0x1b34, 0x1b34, Bidi::NSM),
+    (0x1b35, 0x1b35, Bidi::L), (0x1b36, 0x1b3a, Bidi::NSM), (0x1b3b, 0x1b3b, Bidi::L),
+    (0x1b3c, 0x1b3c, Bidi::NSM), (0x1b3d, 0x1b41, Bidi::L), (0x1b42, 0x1b42, Bidi::NSM),
+    (0x1b43, 0x1b4c, Bidi::L), (0x1b50, 0x1b6a, Bidi::L), (0x1b6b, 0x1b73, Bidi::NSM),
+    (0x1b74, 0x1b7e, Bidi::L), (0x1b80, 0x1b81, Bidi::NSM), (0x1b82, 0x1ba1, Bidi::L),
+    (0x1ba2, 0x1ba5, Bidi::NSM), (0x1ba6, 0x1ba7, Bidi::L), (0x1ba8, 0x1ba9, Bidi::NSM),
+    (0x1baa, 0x1baa, Bidi::L), (0x1bab, 0x1bad, Bidi::NSM), (0x1bae, 0x1be5, Bidi::L),
+    (0x1be6, 0x1be6, Bidi::NSM), (0x1be7, 0x1be7, Bidi::L), (0x1be8, 0x1be9, Bidi::NSM),
+    (0x1bea, 0x1bec, Bidi::L), (0x1bed, 0x1bed, Bidi::NSM), (0x1bee, 0x1bee, Bidi::L),
+    (0x1bef, 0x1bf1, Bidi::NSM), (0x1bf2, 0x1bf3, Bidi::L), (0x1bfc, 0x1c2b, Bidi::L),
+    (0x1c2c, 0x1c33, Bidi::NSM), (0x1c34, 0x1c35, Bidi::L), (0x1c36, 0x1c37, Bidi::NSM),
+    (0x1c3b, 0x1c49, Bidi::L), (0x1c4d, 0x1c88, Bidi::L), (0x1c90, 0x1cba, Bidi::L),
+    (0x1cbd, 0x1cc7, Bidi::L), (0x1cd0, 0x1cd2, Bidi::NSM), (0x1cd3, 0x1cd3, Bidi::L),
+    (0x1cd4, 0x1ce0, Bidi::NSM), (0x1ce1, 0x1ce1, Bidi::L), (0x1ce2, 0x1ce8, Bidi::NSM),
+    (0x1ce9, 0x1cec, Bidi::L), (0x1ced, 0x1ced, Bidi::NSM), (0x1cee, 0x1cf3, Bidi::L),
+    (0x1cf4, 0x1cf4, Bidi::NSM), (0x1cf5, 0x1cf7, Bidi::L), (0x1cf8, 0x1cf9, Bidi::NSM),
+    (0x1cfa, 0x1cfa, Bidi::L), (0x1d00, 0x1dbf, Bidi::L), (0x1dc0, 0x1dff, Bidi::NSM),
+    (0x1e00, 0x1f15, Bidi::L), (0x1f18, 0x1f1d, Bidi::L), (0x1f20, 0x1f45, Bidi::L),
+    (0x1f48, 0x1f4d, Bidi::L), (0x1f50, 0x1f57, Bidi::L), (0x1f59, 0x1f59, Bidi::L),
+    (0x1f5b, 0x1f5b, Bidi::L), (0x1f5d, 0x1f5d, Bidi::L), (0x1f5f, 0x1f7d, Bidi::L),
+    (0x1f80, 0x1fb4, Bidi::L), (0x1fb6, 0x1fbc, Bidi::L), (0x1fbd, 0x1fbd, Bidi::ON),
+    (0x1fbe, 0x1fbe, Bidi::L), (0x1fbf, 0x1fc1, Bidi::ON), (0x1fc2, 0x1fc4, Bidi::L),
+    (0x1fc6, 0x1fcc, Bidi::L), (0x1fcd, 0x1fcf, Bidi::ON), (0x1fd0, 0x1fd3, Bidi::L),
+    (0x1fd6, 0x1fdb, Bidi::L), (0x1fdd, 0x1fdf, Bidi::ON), (0x1fe0, 0x1fec, Bidi::L),
+    (0x1fed, 0x1fef, Bidi::ON), (0x1ff2, 0x1ff4, Bidi::L), (0x1ff6, 0x1ffc, Bidi::L),
+    (0x1ffd, 0x1ffe, Bidi::ON), (0x2000, 0x200a, Bidi::WS), (0x200b, 0x200d, Bidi::BN),
+    (0x200e, 0x200e, Bidi::L), (0x200f, 0x200f, Bidi::R), (0x2010, 0x2027, Bidi::ON),
+    (0x2028, 0x2028, Bidi::WS), (0x2029, 0x2029, Bidi::B), (0x202a, 0x202a, Bidi::LRE),
+    (0x202b, 0x202b, Bidi::RLE), (0x202c, 0x202c, Bidi::PDF), (0x202d, 0x202d, Bidi::LRO),
+    (0x202e, 0x202e, Bidi::RLO), (0x202f, 0x202f, Bidi::CS), (0x2030, 0x2034, Bidi::ET),
+    (0x2035, 0x2043, Bidi::ON), (0x2044, 0x2044, Bidi::CS), (0x2045, 0x205e, Bidi::ON),
+    (0x205f, 0x205f, Bidi::WS), (0x2060, 0x2064, Bidi::BN), (0x2066, 0x2066, Bidi::LRI),
+    (0x2067, 0x2067, Bidi::RLI), (0x2068, 0x2068, Bidi::FSI), (0x2069, 0x2069, Bidi::PDI),
+    (0x206a, 0x206f, Bidi::BN), (0x2070, 0x2070, Bidi::EN), (0x2071, 0x2071, Bidi::L),
+    (0x2074, 0x2079, Bidi::EN), (0x207a, 0x207b, Bidi::ES), (0x207c, 0x207e, Bidi::ON),
+    (0x207f, 0x207f, Bidi::L), (0x2080, 0x2089, Bidi::EN), (0x208a, 0x208b, Bidi::ES),
+    (0x208c, 0x208e, Bidi::ON), (0x2090, 0x209c, Bidi::L), (0x20a0, 0x20c0, Bidi::ET),
+    (0x20d0, 0x20f0, Bidi::NSM), (0x2100, 0x2101, Bidi::ON), (0x2102, 0x2102, Bidi::L),
+    (0x2103, 0x2106, Bidi::ON), (0x2107, 0x2107, Bidi::L), (0x2108, 0x2109, Bidi::ON),
+    (0x210a, 0x2113, Bidi::L), (0x2114, 0x2114, Bidi::ON), (0x2115, 0x2115, Bidi::L),
+    (0x2116, 0x2118, Bidi::ON), (0x2119, 0x211d, Bidi::L), (0x211e, 0x2123, Bidi::ON),
+    (0x2124, 0x2124, Bidi::L), (0x2125, 0x2125, Bidi::ON), (0x2126, 0x2126, Bidi::L),
+    (0x2127, 0x2127, Bidi::ON), (0x2128, 0x2128, Bidi::L), (0x2129, 0x2129, Bidi::ON),
+    (0x212a, 0x212d, Bidi::L), (0x212e, 0x212e, Bidi::ET), (0x212f, 0x2139, Bidi::L),
+    (0x213a, 0x213b, Bidi::ON), (0x213c, 0x213f, Bidi::L), (0x2140, 0x2144, Bidi::ON),
+    (0x2145, 0x2149, Bidi::L), (0x214a, 0x214d, Bidi::ON), (0x214e, 0x214f, Bidi::L),
+    (0x2150, 0x215f, Bidi::ON), (0x2160, 0x2188, Bidi::L), (0x2189, 0x218b, Bidi::ON),
+    (0x2190, 0x2211, Bidi::ON), (0x2212, 0x2212, Bidi::ES), (0x2213, 0x2213, Bidi::ET),
+    (0x2214, 0x2335, Bidi::ON), (0x2336, 0x237a, Bidi::L), (0x237b, 0x2394, Bidi::ON),
+    (0x2395, 0x2395, Bidi::L), (0x2396, 0x2426, Bidi::ON), (0x2440, 0x244a, Bidi::ON),
+    (0x2460, 0x2487, Bidi::ON), (0x2488, 0x249b, Bidi::EN), (0x249c, 0x24e9, Bidi::L),
+    (0x24ea, 0x26ab, Bidi::ON), (0x26ac, 0x26ac, Bidi::L), (0x26ad, 0x27ff, Bidi::ON),
+    (0x2800, 0x28ff, Bidi::L), (0x2900, 0x2b73, Bidi::ON), (0x2b76, 0x2b95, Bidi::ON),
+    (0x2b97, 0x2bff, Bidi::ON), (0x2c00, 0x2ce4, Bidi::L), (0x2ce5, 0x2cea, Bidi::ON),
+    (0x2ceb, 0x2cee, Bidi::L), (0x2cef, 0x2cf1, Bidi::NSM), (0x2cf2, 0x2cf3, Bidi::L),
+    (0x2cf9, 0x2cff, Bidi::ON), (0x2d00, 0x2d25, Bidi::L), (0x2d27, 0x2d27, Bidi::L),
+    (0x2d2d, 0x2d2d, Bidi::L), (0x2d30, 0x2d67, Bidi::L), (0x2d6f, 0x2d70, Bidi::L),
+    (0x2d7f, 0x2d7f, Bidi::NSM), (0x2d80, 0x2d96, Bidi::L), (0x2da0, 0x2da6, Bidi::L),
+    (0x2da8, 0x2dae, Bidi::L), (0x2db0, 0x2db6, Bidi::L), (0x2db8, 0x2dbe, Bidi::L),
+    (0x2dc0, 0x2dc6, Bidi::L), (0x2dc8, 0x2dce, Bidi::L), (0x2dd0, 0x2dd6, Bidi::L),
+    (0x2dd8, 0x2dde, Bidi::L), (0x2de0, 0x2dff, Bidi::NSM), (0x2e00, 0x2e5d, Bidi::ON),
+    (0x2e80, 0x2e99, Bidi::ON), (0x2e9b, 0x2ef3, Bidi::ON), (0x2f00, 0x2fd5, Bidi::ON),
+    (0x2ff0, 0x2fff, Bidi::ON), (0x3000, 0x3000, Bidi::WS), (0x3001, 0x3004, Bidi::ON),
+    (0x3005, 0x3007, Bidi::L), (0x3008, 0x3020, Bidi::ON), (0x3021, 0x3029, Bidi::L),
+    (0x302a, 0x302d, Bidi::NSM), (0x302e, 0x302f, Bidi::L), (0x3030, 0x3030, Bidi::ON),
+    (0x3031, 0x3035, Bidi::L), (0x3036, 0x3037, Bidi::ON), (0x3038, 0x303c, Bidi::L),
+    (0x303d, 0x303f, Bidi::ON), (0x3041, 0x3096, Bidi::L), (0x3099, 0x309a, Bidi::NSM),
+    (0x309b, 0x309c, Bidi::ON), (0x309d, 0x309f, Bidi::L), (0x30a0, 0x30a0, Bidi::ON),
+    (0x30a1, 0x30fa, Bidi::L), (0x30fb, 0x30fb, Bidi::ON), (0x30fc, 0x30ff, Bidi::L),
+    (0x3105, 0x312f, Bidi::L), (0x3131, 0x318e, Bidi::L), (0x3190, 0x31bf, Bidi::L),
+    (0x31c0, 0x31e3, Bidi::ON), (0x31ef, 0x31ef, Bidi::ON), (0x31f0, 0x321c, Bidi::L),
+    (0x321d, 0x321e, Bidi::ON), (0x3220, 0x324f, Bidi::L), (0x3250, 0x325f, Bidi::ON),
+    (0x3260, 0x327b, Bidi::L), (0x327c, 0x327e, Bidi::ON), (0x327f, 0x32b0, Bidi::L),
+    (0x32b1, 0x32bf, Bidi::ON), (0x32c0, 0x32cb, Bidi::L), (0x32cc, 0x32cf, Bidi::ON),
+    (0x32d0, 0x3376, Bidi::L), (0x3377, 0x337a, Bidi::ON), (0x337b, 0x33dd, Bidi::L),
+    (0x33de, 0x33df, Bidi::ON), (0x33e0, 0x33fe, Bidi::L), (0x33ff, 0x33ff, Bidi::ON),
+    (0x3400, 0x4dbf, Bidi::L), (0x4dc0, 0x4dff, Bidi::ON), (0x4e00, 0xa48c, Bidi::L),
+    (0xa490, 0xa4c6, Bidi::ON), (0xa4d0, 0xa60c, Bidi::L), (0xa60d, 0xa60f, Bidi::ON),
+    (0xa610, 0xa62b, Bidi::L), (0xa640, 0xa66e, Bidi::L), (0xa66f, 0xa672, Bidi::NSM),
+    (0xa673, 0xa673, Bidi::ON), (0xa674, 0xa67d, Bidi::NSM), (0xa67e, 0xa67f, Bidi::ON),
+    (0xa680, 0xa69d, Bidi::L), (0xa69e, 0xa69f, Bidi::NSM), (0xa6a0, 0xa6ef, Bidi::L),
+    (0xa6f0, 0xa6f1, Bidi::NSM), (0xa6f2, 0xa6f7, Bidi::L), (0xa700, 0xa721, Bidi::ON),
+    (0xa722, 0xa787, Bidi::L), (0xa788, 0xa788, Bidi::ON), (0xa789, 0xa7ca, Bidi::L),
+    (0xa7d0, 0xa7d1, Bidi::L), (0xa7d3, 0xa7d3, Bidi::L), (0xa7d5, 0xa7d9, Bidi::L),
+    (0xa7f2, 0xa801, Bidi::L), (0xa802, 0xa802, Bidi::NSM), (0xa803, 0xa805, Bidi::L),
+    (0xa806, 0xa806, Bidi::NSM), (0xa807, 0xa80a, Bidi::L), (0xa80b, 0xa80b, Bidi::NSM),
+    (0xa80c, 0xa824, Bidi::L), (0xa825, 0xa826, Bidi::NSM), (0xa827, 0xa827, Bidi::L),
+    (0xa828, 0xa82b, Bidi::ON), (0xa82c, 0xa82c, Bidi::NSM), (0xa830, 0xa837, Bidi::L),
+    (0xa838, 0xa839, Bidi::ET), (0xa840, 0xa873, Bidi::L), (0xa874, 0xa877, Bidi::ON),
+    (0xa880, 0xa8c3, Bidi::L), (0xa8c4, 0xa8c5, Bidi::NSM), (0xa8ce, 0xa8d9, Bidi::L),
+    (0xa8e0, 0xa8f1, Bidi::NSM), (0xa8f2, 0xa8fe, Bidi::L), (0xa8ff, 0xa8ff, Bidi::NSM),
+    (0xa900, 0xa925, Bidi::L), (0xa926, 0xa92d, Bidi::NSM), (0xa92e, 0xa946, Bidi::L),
+    (0xa947, 0xa951, Bidi::NSM), (0xa952, 0xa953, Bidi::L), (0xa95f, 0xa97c, Bidi::L),
+    (0xa980, 0xa982, Bidi::NSM), (0xa983, 0xa9b2, Bidi::L), (0xa9b3, 0xa9b3, Bidi::NSM),
+    (0xa9b4, 0xa9b5, Bidi::L), (0xa9b6, 0xa9b9, Bidi::NSM), (0xa9ba, 0xa9bb, Bidi::L),
+    (0xa9bc, 0xa9bd, Bidi::NSM), (0xa9be, 0xa9cd, Bidi::L), (0xa9cf, 0xa9d9, Bidi::L),
+    (0xa9de, 0xa9e4, Bidi::L), (0xa9e5, 0xa9e5, Bidi::NSM), (0xa9e6, 0xa9fe, Bidi::L),
+    (0xaa00, 0xaa28, Bidi::L), (0xaa29, 0xaa2e, Bidi::NSM), (0xaa2f, 0xaa30, Bidi::L),
+    (0xaa31, 0xaa32, Bidi::NSM), (0xaa33, 0xaa34, Bidi::L), (0xaa35, 0xaa36, Bidi::NSM),
+    (0xaa40, 0xaa42, Bidi::L), (0xaa43, 0xaa43, Bidi::NSM), (0xaa44, 0xaa4b, Bidi::L),
+    (0xaa4c, 0xaa4c, Bidi::NSM), (0xaa4d, 0xaa4d, Bidi::L), (0xaa50, 0xaa59, Bidi::L),
+    (0xaa5c, 0xaa7b, Bidi::L), (0xaa7c, 0xaa7c, Bidi::NSM), (0xaa7d, 0xaaaf, Bidi::L),
+    (0xaab0, 0xaab0, Bidi::NSM), (0xaab1, 0xaab1, Bidi::L), (0xaab2, 0xaab4, Bidi::NSM),
+    (0xaab5, 0xaab6, Bidi::L), (0xaab7, 0xaab8, Bidi::NSM), (0xaab9, 0xaabd, Bidi::L),
+    (0xaabe, 0xaabf, Bidi::NSM), (0xaac0, 0xaac0, Bidi::L), (0xaac1, 0xaac1, Bidi::NSM),
+    (0xaac2, 0xaac2, Bidi::L), (0xaadb, 0xaaeb, Bidi::L), (0xaaec, 0xaaed, Bidi::NSM),
+    (0xaaee, 0xaaf5, Bidi::L), (0xaaf6, 0xaaf6, Bidi::NSM), (0xab01, 0xab06, Bidi::L),
+    (0xab09, 0xab0e, Bidi::L), (0xab11, 0xab16, Bidi::L), (0xab20, 0xab26, Bidi::L),
+    (0xab28, 0xab2e, Bidi::L), (0xab30, 0xab69, Bidi::L), (0xab6a, 0xab6b, Bidi::ON),
+    (0xab70, 0xabe4, Bidi::L), (0xabe5, 0xabe5, Bidi::NSM), (0xabe6, 0xabe7, Bidi::L),
+    (0xabe8, 0xabe8, Bidi::NSM), (0xabe9, 0xabec, Bidi::L), (0xabed, 0xabed, Bidi::NSM),
+    (0xabf0, 0xabf9, Bidi::L), (0xac00, 0xd7a3, Bidi::L), (0xd7b0, 0xd7c6, Bidi::L),
+    (0xd7cb, 0xd7fb, Bidi::L), (0xd800, 0xfa6d, Bidi::L), (0xfa70, 0xfad9, Bidi::L),
+    (0xfb00, 0xfb06, Bidi::L), (0xfb13, 0xfb17, Bidi::L), (0xfb1d, 0xfb1d, Bidi::R),
+    (0xfb1e, 0xfb1e, Bidi::NSM), (0xfb1f, 0xfb28, Bidi::R), (0xfb29, 0xfb29, Bidi::ES),
+    (0xfb2a, 0xfb36, Bidi::R), (0xfb38, 0xfb3c, Bidi::R), (0xfb3e, 0xfb3e, Bidi::R),
+    (0xfb40, 0xfb41, Bidi::R), (0xfb43, 0xfb44, Bidi::R), (0xfb46, 0xfb4f, Bidi::R),
+    (0xfb50, 0xfbc2, Bidi::AL), (0xfbd3, 0xfd3d, Bidi::AL), (0xfd3e, 0xfd4f, Bidi::ON),
+    (0xfd50, 0xfd8f, Bidi::AL), (0xfd92, 0xfdc7, Bidi::AL), (0xfdcf, 0xfdcf, Bidi::ON),
+    (0xfdf0, 0xfdfc, Bidi::AL), (0xfdfd, 0xfdff, Bidi::ON), (0xfe00, 0xfe0f, Bidi::NSM),
+    (0xfe10, 0xfe19, Bidi::ON), (0xfe20, 0xfe2f, Bidi::NSM), (0xfe30, 0xfe4f, Bidi::ON),
+    (0xfe50, 0xfe50, Bidi::CS), (0xfe51, 0xfe51, Bidi::ON), (0xfe52, 0xfe52, Bidi::CS),
+    (0xfe54, 0xfe54, Bidi::ON), (0xfe55, 0xfe55, Bidi::CS), (0xfe56, 0xfe5e, Bidi::ON),
+    (0xfe5f, 0xfe5f, Bidi::ET), (0xfe60, 0xfe61, Bidi::ON), (0xfe62, 0xfe63, Bidi::ES),
+    (0xfe64, 0xfe66, Bidi::ON), (0xfe68, 0xfe68, Bidi::ON), (0xfe69, 0xfe6a, Bidi::ET),
+    (0xfe6b, 0xfe6b, Bidi::ON), (0xfe70, 0xfe74, Bidi::AL), (0xfe76, 0xfefc, Bidi::AL),
+    (0xfeff, 0xfeff, Bidi::BN), (0xff01, 0xff02, Bidi::ON), (0xff03, 0xff05, Bidi::ET),
+    (0xff06, 0xff0a, Bidi::ON), (0xff0b, 0xff0b, Bidi::ES), (0xff0c, 0xff0c, Bidi::CS),
+    (0xff0d, 0xff0d, Bidi::ES), (0xff0e, 0xff0f, Bidi::CS), (0xff10, 0xff19, Bidi::EN),
+    (0xff1a, 0xff1a, Bidi::CS), (0xff1b, 0xff20, Bidi::ON), (0xff21, 0xff3a, Bidi::L),
+    (0xff3b, 0xff40, Bidi::ON), (0xff41, 0xff5a, Bidi::L), (0xff5b, 0xff65, Bidi::ON),
+    (0xff66, 0xffbe, Bidi::L), (0xffc2, 0xffc7, Bidi::L), (0xffca, 0xffcf, Bidi::L),
+    (0xffd2, 0xffd7, Bidi::L), (0xffda, 0xffdc, Bidi::L), (0xffe0, 0xffe1, Bidi::ET),
+    (0xffe2, 0xffe4, Bidi::ON), (0xffe5, 0xffe6, Bidi::ET), (0xffe8, 0xffee, Bidi::ON),
+    (0xfff9, 0xfffd, Bidi::ON), (0x10000, 0x1000b, Bidi::L), (0x1000d, 0x10026, Bidi::L),
+    (0x10028, 0x1003a, Bidi::L), (0x1003c, 0x1003d, Bidi::L), (0x1003f, 0x1004d, Bidi::L),
+    (0x10050, 0x1005d, Bidi::L), (0x10080, 0x100fa, Bidi::L), (0x10100, 0x10100, Bidi::L),
+    (0x10101, 0x10101, Bidi::ON), (0x10102, 0x10102, Bidi::L), (0x10107, 0x10133, Bidi::L),
+    (0x10137, 0x1013f, Bidi::L), (0x10140, 0x1018c, Bidi::ON), (0x1018d, 0x1018e, Bidi::L),
+    (0x10190, 0x1019c, Bidi::ON), (0x101a0, 0x101a0, Bidi::ON), (0x101d0, 0x101fc, Bidi::L),
+    (0x101fd, 0x101fd, Bidi::NSM), (0x10280, 0x1029c, Bidi::L), (0x102a0, 0x102d0, Bidi::L),
+    (0x102e0, 0x102e0, Bidi::NSM), (0x102e1, 0x102fb, Bidi::EN), (0x10300, 0x10323, Bidi::L),
+    (0x1032d, 0x1034a, Bidi::L), (0x10350, 0x10375, Bidi::L), (0x10376, 0x1037a, Bidi::NSM),
+    (0x10380, 0x1039d, Bidi::L), (0x1039f, 0x103c3, Bidi::L), (0x103c8, 0x103d5, Bidi::L),
+    (0x10400, 0x1049d, Bidi::L), (0x104a0, 0x104a9, Bidi::L), (0x104b0, 0x104d3, Bidi::L),
+    (0x104d8, 0x104fb, Bidi::L), (0x10500, 0x10527, Bidi::L), (0x10530, 0x10563, Bidi::L),
+    (0x1056f, 0x1057a, Bidi::L), (0x1057c, 0x1058a, Bidi::L), (0x1058c, 0x10592, Bidi::L),
+    (0x10594, 0x10595, Bidi::L), (0x10597, 0x105a1, Bidi::L), (0x105a3, 0x105b1, Bidi::L),
+    (0x105b3, 0x105b9, Bidi::L), (0x105bb, 0x105bc, Bidi::L), (0x10600, 0x10736, Bidi::L),
+    (0x10740, 0x10755, Bidi::L), (0x10760, 0x10767, Bidi::L), (0x10780, 0x10785, Bidi::L),
+    (0x10787, 0x107b0, Bidi::L), (0x107b2, 0x107ba, Bidi::L), (0x10800, 0x10805, Bidi::R),
+    (0x10808, 0x10808, Bidi::R), (0x1080a, 0x10835, Bidi::R), (0x10837, 0x10838, Bidi::R),
+    (0x1083c, 0x1083c, Bidi::R), (0x1083f, 0x10855, Bidi::R), (0x10857, 0x1089e, Bidi::R),
+    (0x108a7, 0x108af, Bidi::R), (0x108e0, 0x108f2, Bidi::R), (0x108f4, 0x108f5, Bidi::R),
+    (0x108fb, 0x1091b, Bidi::R), (0x1091f, 0x1091f, Bidi::ON), (0x10920, 0x10939, Bidi::R),
+    (0x1093f, 0x1093f, Bidi::R), (0x10980, 0x109b7, Bidi::R), (0x109bc, 0x109cf, Bidi::R),
+    (0x109d2, 0x10a00, Bidi::R), (0x10a01, 0x10a03, Bidi::NSM), (0x10a05, 0x10a06, Bidi::NSM),
+    (0x10a0c, 0x10a0f, Bidi::NSM), (0x10a10, 0x10a13, Bidi::R), (0x10a15, 0x10a17, Bidi::R),
+    (0x10a19, 0x10a35, Bidi::R), (0x10a38, 0x10a3a, Bidi::NSM), (0x10a3f, 0x10a3f, Bidi::NSM),
+    (0x10a40, 0x10a48, Bidi::R), (0x10a50, 0x10a58, Bidi::R), (0x10a60, 0x10a9f, Bidi::R),
+    (0x10ac0, 0x10ae4, Bidi::R), (0x10ae5, 0x10ae6, Bidi::NSM), (0x10aeb, 0x10af6, Bidi::R),
+    (0x10b00, 0x10b35, Bidi::R), (0x10b39, 0x10b3f, Bidi::ON), (0x10b40, 0x10b55, Bidi::R),
+    (0x10b58, 0x10b72, Bidi::R), (0x10b78, 0x10b91, Bidi::R), (0x10b99, 0x10b9c, Bidi::R),
+    (0x10ba9, 0x10baf, Bidi::R), (0x10c00, 0x10c48, Bidi::R), (0x10c80, 0x10cb2, Bidi::R),
+    (0x10cc0, 0x10cf2, Bidi::R), (0x10cfa, 0x10cff, Bidi::R), (0x10d00, 0x10d23, Bidi::AL),
+    (0x10d24, 0x10d27, Bidi::NSM), (0x10d30, 0x10d39, Bidi::AN), (0x10e60, 0x10e7e, Bidi::AN),
+    (0x10e80, 0x10ea9, Bidi::R), (0x10eab, 0x10eac, Bidi::NSM), (0x10ead, 0x10ead, Bidi::R),
+    (0x10eb0, 0x10eb1, Bidi::R), (0x10efd, 0x10eff, Bidi::NSM), (0x10f00, 0x10f27, Bidi::R),
+    (0x10f30, 0x10f45, Bidi::AL), (0x10f46, 0x10f50, Bidi::NSM), (0x10f51, 0x10f59, Bidi::AL),
+    (0x10f70, 0x10f81, Bidi::R), (0x10f82, 0x10f85, Bidi::NSM), (0x10f86, 0x10f89, Bidi::R),
+    (0x10fb0, 0x10fcb, Bidi::R), (0x10fe0, 0x10ff6, Bidi::R), (0x11000, 0x11000, Bidi::L),
+    (0x11001, 0x11001, Bidi::NSM), (0x11002, 0x11037, Bidi::L), (0x11038, 0x11046, Bidi::NSM),
+    (0x11047, 0x1104d, Bidi::L), (0x11052, 0x11065, Bidi::ON), (0x11066, 0x1106f, Bidi::L),
+    (0x11070, 0x11070, Bidi::NSM), (0x11071, 0x11072, Bidi::L), (0x11073, 0x11074, Bidi::NSM),
+    (0x11075, 0x11075, Bidi::L), (0x1107f, 0x11081, Bidi::NSM), (0x11082, 0x110b2, Bidi::L),
+    (0x110b3, 0x110b6, Bidi::NSM), (0x110b7, 0x110b8, Bidi::L), (0x110b9, 0x110ba, Bidi::NSM),
+    (0x110bb, 0x110c1, Bidi::L), (0x110c2, 0x110c2, Bidi::NSM), (0x110cd, 0x110cd, Bidi::L),
+    (0x110d0, 0x110e8, Bidi::L), (0x110f0, 0x110f9, Bidi::L), (0x11100, 0x11102, Bidi::NSM),
+    (0x11103, 0x11126, Bidi::L), (0x11127, 0x1112b, Bidi::NSM), (0x1112c, 0x1112c, Bidi::L),
+    (0x1112d, 0x11134, Bidi::NSM), (0x11136, 0x11147, Bidi::L), (0x11150, 0x11172, Bidi::L),
+    (0x11173, 0x11173, Bidi::NSM), (0x11174, 0x11176, Bidi::L), (0x11180, 0x11181, Bidi::NSM),
+    (0x11182, 0x111b5, Bidi::L), (0x111b6, 0x111be, Bidi::NSM), (0x111bf, 0x111c8, Bidi::L),
+    (0x111c9, 0x111cc, Bidi::NSM), (0x111cd, 0x111ce, Bidi::L), (0x111cf, 0x111cf, Bidi::NSM),
+    (0x111d0, 0x111df, Bidi::L), (0x111e1, 0x111f4, Bidi::L), (0x11200, 0x11211, Bidi::L),
+    (0x11213, 0x1122e, Bidi::L), (0x1122f, 0x11231, Bidi::NSM), (0x11232, 0x11233, Bidi::L),
+    (0x11234, 0x11234, Bidi::NSM), (0x11235, 0x11235, Bidi::L), (0x11236, 0x11237, Bidi::NSM),
+    (0x11238, 0x1123d, Bidi::L), (0x1123e, 0x1123e, Bidi::NSM), (0x1123f, 0x11240, Bidi::L),
+    (0x11241, 0x11241, Bidi::NSM), (0x11280, 0x11286, Bidi::L), (0x11288, 0x11288, Bidi::L),
+    (0x1128a, 0x1128d, Bidi::L), (0x1128f, 0x1129d, Bidi::L), (0x1129f, 0x112a9, Bidi::L),
+    (0x112b0, 0x112de, Bidi::L), (0x112df, 0x112df, Bidi::NSM), (0x112e0, 0x112e2, Bidi::L),
+    (0x112e3, 0x112ea, Bidi::NSM), (0x112f0, 0x112f9, Bidi::L), (0x11300, 0x11301, Bidi::NSM),
+    (0x11302, 0x11303, Bidi::L), (0x11305, 0x1130c, Bidi::L), (0x1130f, 0x11310, Bidi::L),
+    (0x11313, 0x11328, Bidi::L), (0x1132a, 0x11330, Bidi::L), (0x11332, 0x11333, Bidi::L),
+    (0x11335, 0x11339, Bidi::L), (0x1133b, 0x1133c, Bidi::NSM), (0x1133d, 0x1133f, Bidi::L),
+    (0x11340, 0x11340, Bidi::NSM), (0x11341, 0x11344, Bidi::L), (0x11347, 0x11348, Bidi::L),
+    (0x1134b, 0x1134d, Bidi::L), (0x11350, 0x11350, Bidi::L), (0x11357, 0x11357, Bidi::L),
+    (0x1135d, 0x11363, Bidi::L), (0x11366, 0x1136c, Bidi::NSM), (0x11370, 0x11374, Bidi::NSM),
+    (0x11400, 0x11437, Bidi::L), (0x11438, 0x1143f, Bidi::NSM), (0x11440, 0x11441, Bidi::L),
+    (0x11442, 0x11444, Bidi::NSM), (0x11445, 0x11445, Bidi::L), (0x11446, 0x11446, Bidi::NSM),
+    (0x11447, 0x1145b, Bidi::L), (0x1145d, 0x1145d, Bidi::L), (0x1145e, 0x1145e, Bidi::NSM),
+    (0x1145f, 0x11461, Bidi::L), (0x11480, 0x114b2, Bidi::L), (0x114b3, 0x114b8, Bidi::NSM),
+    (0x114b9, 0x114b9, Bidi::L), (0x114ba, 0x114ba, Bidi::NSM), (0x114bb, 0x114be, Bidi::L),
+    (0x114bf, 0x114c0, Bidi::NSM), (0x114c1, 0x114c1, Bidi::L), (0x114c2, 0x114c3, Bidi::NSM),
+    (0x114c4, 0x114c7, Bidi::L), (0x114d0, 0x114d9, Bidi::L), (0x11580, 0x115b1, Bidi::L),
+    (0x115b2, 0x115b5, Bidi::NSM), (0x115b8, 0x115bb, Bidi::L), (0x115bc, 0x115bd, Bidi::NSM),
+    (0x115be, 0x115be, Bidi::L), (0x115bf, 0x115c0, Bidi::NSM), (0x115c1, 0x115db, Bidi::L),
+    (0x115dc, 0x115dd, Bidi::NSM), (0x11600, 0x11632, Bidi::L), (0x11633, 0x1163a, Bidi::NSM),
+    (0x1163b, 0x1163c, Bidi::L), (0x1163d, 0x1163d, Bidi::NSM), (0x1163e, 0x1163e, Bidi::L),
+    (0x1163f, 0x11640, Bidi::NSM), (0x11641, 0x11644, Bidi::L), (0x11650, 0x11659, Bidi::L),
+    (0x11660, 0x1166c, Bidi::ON), (0x11680, 0x116aa, Bidi::L), (0x116ab, 0x116ab, Bidi::NSM),
+    (0x116ac, 0x116ac, Bidi::L), (0x116ad, 0x116ad, Bidi::NSM), (0x116ae, 0x116af, Bidi::L),
+    (0x116b0, 0x116b5, Bidi::NSM), (0x116b6, 0x116b6, Bidi::L), (0x116b7, 0x116b7, Bidi::NSM),
+    (0x116b8, 0x116b9, Bidi::L), (0x116c0, 0x116c9, Bidi::L), (0x11700, 0x1171a, Bidi::L),
+    (0x1171d, 0x1171f, Bidi::NSM), (0x11720, 0x11721, Bidi::L), (0x11722, 0x11725, Bidi::NSM),
+    (0x11726, 0x11726, Bidi::L), (0x11727, 0x1172b, Bidi::NSM), (0x11730, 0x11746, Bidi::L),
+    (0x11800, 0x1182e, Bidi::L), (0x1182f, 0x11837, Bidi::NSM), (0x11838, 0x11838, Bidi::L),
+    (0x11839, 0x1183a, Bidi::NSM), (0x1183b, 0x1183b, Bidi::L), (0x118a0, 0x118f2, Bidi::L),
+    (0x118ff, 0x11906, Bidi::L), (0x11909, 0x11909, Bidi::L), (0x1190c, 0x11913, Bidi::L),
+    (0x11915, 0x11916, Bidi::L), (0x11918, 0x11935, Bidi::L), (0x11937, 0x11938, Bidi::L),
+    (0x1193b, 0x1193c, Bidi::NSM), (0x1193d, 0x1193d, Bidi::L), (0x1193e, 0x1193e, Bidi::NSM),
+    (0x1193f, 0x11942, Bidi::L), (0x11943, 0x11943, Bidi::NSM), (0x11944, 0x11946, Bidi::L),
+    (0x11950, 0x11959, Bidi::L), (0x119a0, 0x119a7, Bidi::L), (0x119aa, 0x119d3, Bidi::L),
+    (0x119d4, 0x119d7, Bidi::NSM), (0x119da, 0x119db, Bidi::NSM), (0x119dc, 0x119df, Bidi::L),
+    (0x119e0, 0x119e0, Bidi::NSM), (0x119e1, 0x119e4, Bidi::L), (0x11a00, 0x11a00, Bidi::L),
+    (0x11a01, 0x11a06, Bidi::NSM), (0x11a07, 0x11a08, Bidi::L), (0x11a09, 0x11a0a, Bidi::NSM),
+    (0x11a0b, 0x11a32, Bidi::L), (0x11a33, 0x11a38, Bidi::NSM), (0x11a39, 0x11a3a, Bidi::L),
+    (0x11a3b, 0x11a3e, Bidi::NSM), (0x11a3f, 0x11a46, Bidi::L), (0x11a47, 0x11a47, Bidi::NSM),
+    (0x11a50, 0x11a50, Bidi::L), (0x11a51, 0x11a56, Bidi::NSM), (0x11a57, 0x11a58, Bidi::L),
+    (0x11a59, 0x11a5b, Bidi::NSM), (0x11a5c, 0x11a89, Bidi::L), (0x11a8a, 0x11a96, Bidi::NSM),
+    (0x11a97, 0x11a97, Bidi::L), (0x11a98, 0x11a99, Bidi::NSM), (0x11a9a, 0x11aa2, Bidi::L),
+    (0x11ab0, 0x11af8, Bidi::L), (0x11b00, 0x11b09, Bidi::L), (0x11c00, 0x11c08, Bidi::L),
+    (0x11c0a, 0x11c2f, Bidi::L), (0x11c30, 0x11c36, Bidi::NSM), (0x11c38, 0x11c3d, Bidi::NSM),
+    (0x11c3e, 0x11c45, Bidi::L), (0x11c50, 0x11c6c, Bidi::L), (0x11c70, 0x11c8f, Bidi::L),
+    (0x11c92, 0x11ca7, Bidi::NSM), (0x11ca9, 0x11ca9, Bidi::L), (0x11caa, 0x11cb0, Bidi::NSM),
+    (0x11cb1, 0x11cb1, Bidi::L), (0x11cb2, 0x11cb3, Bidi::NSM), (0x11cb4, 0x11cb4, Bidi::L),
+    (0x11cb5, 0x11cb6, Bidi::NSM), (0x11d00, 0x11d06, Bidi::L), (0x11d08, 0x11d09, Bidi::L),
+    (0x11d0b, 0x11d30, Bidi::L), (0x11d31, 0x11d36, Bidi::NSM), (0x11d3a, 0x11d3a, Bidi::NSM),
+    (0x11d3c, 0x11d3d, Bidi::NSM), (0x11d3f, 0x11d45, Bidi::NSM), (0x11d46, 0x11d46, Bidi::L),
+    (0x11d47, 0x11d47, Bidi::NSM), (0x11d50, 0x11d59, Bidi::L), (0x11d60, 0x11d65, Bidi::L),
+    (0x11d67, 0x11d68, Bidi::L), (0x11d6a, 0x11d8e, Bidi::L), (0x11d90, 0x11d91, Bidi::NSM),
+    (0x11d93, 0x11d94, Bidi::L), (0x11d95, 0x11d95, Bidi::NSM), (0x11d96, 0x11d96, Bidi::L),
+    (0x11d97, 0x11d97, Bidi::NSM), (0x11d98, 0x11d98, Bidi::L), (0x11da0, 0x11da9, Bidi::L),
+    (0x11ee0, 0x11ef2, Bidi::L), (0x11ef3, 0x11ef4, Bidi::NSM), (0x11ef5, 0x11ef8, Bidi::L),
+    (0x11f00, 0x11f01, Bidi::NSM), (0x11f02, 0x11f10, Bidi::L), (0x11f12, 0x11f35, Bidi::L),
+    (0x11f36, 0x11f3a, Bidi::NSM), (0x11f3e, 0x11f3f, Bidi::L), (0x11f40, 0x11f40, Bidi::NSM),
+    (0x11f41, 0x11f41, Bidi::L), (0x11f42, 0x11f42, Bidi::NSM), (0x11f43, 0x11f59, Bidi::L),
+    (0x11fb0, 0x11fb0, Bidi::L), (0x11fc0, 0x11fd4, Bidi::L), (0x11fd5, 0x11fdc, Bidi::ON),
+    (0x11fdd, 0x11fe0, Bidi::ET), (0x11fe1, 0x11ff1, Bidi::ON), (0x11fff, 0x12399, Bidi::L),
+    (0x12400, 0x1246e, Bidi::L), (0x12470, 0x12474, Bidi::L), (0x12480, 0x12543, Bidi::L),
+    (0x12f90, 0x12ff2, Bidi::L), (0x13000, 0x1343f, Bidi::L), (0x13440, 0x13440, Bidi::NSM),
+    (0x13441, 0x13446, Bidi::L), (0x13447, 0x13455, Bidi::NSM), (0x14400, 0x14646, Bidi::L),
+    (0x16800, 0x16a38, Bidi::L), (0x16a40, 0x16a5e, Bidi::L), (0x16a60, 0x16a69, Bidi::L),
+    (0x16a6e, 0x16abe, Bidi::L), (0x16ac0, 0x16ac9, Bidi::L), (0x16ad0, 0x16aed, Bidi::L),
+    (0x16af0, 0x16af4, Bidi::NSM), (0x16af5, 0x16af5, Bidi::L), (0x16b00, 0x16b2f, Bidi::L),
+    (0x16b30, 0x16b36, Bidi::NSM), (0x16b37, 0x16b45, Bidi::L), (0x16b50, 0x16b59, Bidi::L),
+    (0x16b5b, 0x16b61, Bidi::L), (0x16b63, 0x16b77, Bidi::L), (0x16b7d, 0x16b8f, Bidi::L),
+    (0x16e40, 0x16e9a, Bidi::L), (0x16f00, 0x16f4a, Bidi::L), (0x16f4f, 0x16f4f, Bidi::NSM),
+    (0x16f50, 0x16f87, Bidi::L), (0x16f8f, 0x16f92, Bidi::NSM), (0x16f93, 0x16f9f, Bidi::L),
+    (0x16fe0, 0x16fe1, Bidi::L), (0x16fe2, 0x16fe2, Bidi::ON), (0x16fe3, 0x16fe3, Bidi::L),
+    (0x16fe4, 0x16fe4, Bidi::NSM), (0x16ff0, 0x16ff1, Bidi::L), (0x17000, 0x187f7, Bidi::L),
+    (0x18800, 0x18cd5, Bidi::L), (0x18d00, 0x18d08, Bidi::L), (0x1aff0, 0x1aff3, Bidi::L),
+    (0x1aff5, 0x1affb, Bidi::L), (0x1affd, 0x1affe, Bidi::L), (0x1b000, 0x1b122, Bidi::L),
+    (0x1b132, 0x1b132, Bidi::L), (0x1b150, 0x1b152, Bidi::L), (0x1b155, 0x1b155, Bidi::L),
+    (0x1b164, 0x1b167, Bidi::L), (0x1b170, 0x1b2fb, Bidi::L), (0x1bc00, 0x1bc6a, Bidi::L),
+    (0x1bc70, 0x1bc7c, Bidi::L), (0x1bc80, 0x1bc88, Bidi::L), (0x1bc90, 0x1bc99, Bidi::L),
+    (0x1bc9c, 0x1bc9c, Bidi::L), (0x1bc9d, 0x1bc9e, Bidi::NSM), (0x1bc9f, 0x1bc9f, Bidi::L),
+    (0x1bca0, 0x1bca3, Bidi::BN), (0x1cf00, 0x1cf2d, Bidi::NSM), (0x1cf30, 0x1cf46, Bidi::NSM),
+    (0x1cf50, 0x1cfc3, Bidi::L), (0x1d000, 0x1d0f5, Bidi::L), (0x1d100, 0x1d126, Bidi::L),
+    (0x1d129, 0x1d166, Bidi::L), (0x1d167, 0x1d169, Bidi::NSM), (0x1d16a, 0x1d172, Bidi::L),
+    (0x1d173, 0x1d17a, Bidi::BN), (0x1d17b, 0x1d182, Bidi::NSM), (0x1d183, 0x1d184, Bidi::L),
+    (0x1d185, 0x1d18b, Bidi::NSM), (0x1d18c, 0x1d1a9, Bidi::L), (0x1d1aa, 0x1d1ad, Bidi::NSM),
+    (0x1d1ae, 0x1d1e8, Bidi::L), (0x1d1e9, 0x1d1ea, Bidi::ON), (0x1d200, 0x1d241, Bidi::ON),
+    (0x1d242, 0x1d244, Bidi::NSM), (0x1d245, 0x1d245, Bidi::ON), (0x1d2c0, 0x1d2d3, Bidi::L),
+    (0x1d2e0, 0x1d2f3, Bidi::L), (0x1d300, 0x1d356, Bidi::ON), (0x1d360, 0x1d378, Bidi::L),
+    (0x1d400, 0x1d454, Bidi::L), (0x1d456, 0x1d49c, Bidi::L), (0x1d49e, 0x1d49f, Bidi::L),
+    (0x1d4a2, 0x1d4a2, Bidi::L), (0x1d4a5, 0x1d4a6, Bidi::L), (0x1d4a9, 0x1d4ac, Bidi::L),
+    (0x1d4ae, 0x1d4b9, Bidi::L), (0x1d4bb, 0x1d4bb, Bidi::L), (0x1d4bd, 0x1d4c3, Bidi::L),
+    (0x1d4c5, 0x1d505, Bidi::L), (0x1d507, 0x1d50a, Bidi::L), (0x1d50d, 0x1d514, Bidi::L),
+    (0x1d516, 0x1d51c, Bidi::L), (0x1d51e, 0x1d539, Bidi::L), (0x1d53b, 0x1d53e, Bidi::L),
+    (0x1d540, 0x1d544, Bidi::L), (0x1d546, 0x1d546, Bidi::L), (0x1d54a, 0x1d550, Bidi::L),
+    (0x1d552, 0x1d6a5, Bidi::L), (0x1d6a8, 0x1d6da, Bidi::L), (0x1d6db, 0x1d6db, Bidi::ON),
+    (0x1d6dc, 0x1d714, Bidi::L), (0x1d715, 0x1d715, Bidi::ON), (0x1d716, 0x1d74e, Bidi::L),
+    (0x1d74f, 0x1d74f, Bidi::ON), (0x1d750, 0x1d788, Bidi::L), (0x1d789, 0x1d789, Bidi::ON),
+    (0x1d78a, 0x1d7c2, Bidi::L), (0x1d7c3, 0x1d7c3, Bidi::ON), (0x1d7c4, 0x1d7cb, Bidi::L),
+    (0x1d7ce, 0x1d7ff, Bidi::EN), (0x1d800, 0x1d9ff, Bidi::L), (0x1da00, 0x1da36, Bidi::NSM),
+    (0x1da37, 0x1da3a, Bidi::L), (0x1da3b, 0x1da6c, Bidi::NSM), (0x1da6d, 0x1da74, Bidi::L),
+    (0x1da75, 0x1da75, Bidi::NSM), (0x1da76, 0x1da83, Bidi::L), (0x1da84, 0x1da84, Bidi::NSM),
+    (0x1da85, 0x1da8b, Bidi::L), (0x1da9b, 0x1da9f, Bidi::NSM), (0x1daa1, 0x1daaf, Bidi::NSM),
+    (0x1df00, 0x1df1e, Bidi::L), (0x1df25, 0x1df2a, Bidi::L), (0x1e000, 0x1e006, Bidi::NSM),
+    (0x1e008, 0x1e018, Bidi::NSM), (0x1e01b, 0x1e021, Bidi::NSM), (0x1e023, 0x1e024, Bidi::NSM),
+    (0x1e026, 0x1e02a, Bidi::NSM), (0x1e030, 0x1e06d, Bidi::L), (0x1e08f, 0x1e08f, Bidi::NSM),
+    (0x1e100, 0x1e12c, Bidi::L), (0x1e130, 0x1e136, Bidi::NSM), (0x1e137, 0x1e13d, Bidi::L),
+    (0x1e140, 0x1e149, Bidi::L), (0x1e14e, 0x1e14f, Bidi::L), (0x1e290, 0x1e2ad, Bidi::L),
+    (0x1e2ae, 0x1e2ae, Bidi::NSM), (0x1e2c0, 0x1e2eb, Bidi::L), (0x1e2ec, 0x1e2ef, Bidi::NSM),
+    (0x1e2f0, 0x1e2f9, Bidi::L), (0x1e2ff, 0x1e2ff, Bidi::ET), (0x1e4d0, 0x1e4eb, Bidi::L),
+    (0x1e4ec, 0x1e4ef, Bidi::NSM), (0x1e4f0, 0x1e4f9, Bidi::L), (0x1e7e0, 0x1e7e6, Bidi::L),
+    (0x1e7e8, 0x1e7eb, Bidi::L), (0x1e7ed, 0x1e7ee, Bidi::L), (0x1e7f0, 0x1e7fe, Bidi::L),
+    (0x1e800, 0x1e8c4, Bidi::R), (0x1e8c7, 0x1e8cf, Bidi::R), (0x1e8d0, 0x1e8d6, Bidi::NSM),
+    (0x1e900, 0x1e943, Bidi::R), (0x1e944, 0x1e94a, Bidi::NSM), (0x1e94b, 0x1e94b, Bidi::R),
+    (0x1e950, 0x1e959, Bidi::R), (0x1e95e, 0x1e95f, Bidi::R), (0x1ec71, 0x1ecb4, Bidi::AL),
+    (0x1ed01, 0x1ed3d, Bidi::AL), (0x1ee00, 0x1ee03, Bidi::AL), (0x1ee05, 0x1ee1f, Bidi::AL),
+    (0x1ee21, 0x1ee22, Bidi::AL), (0x1ee24, 0x1ee24, Bidi::AL), (0x1ee27, 0x1ee27, Bidi::AL),
+    (0x1ee29, 0x1ee32, Bidi::AL), (0x1ee34, 0x1ee37, Bidi::AL), (0x1ee39, 0x1ee39, Bidi::AL),
+    (0x1ee3b, 0x1ee3b, Bidi::AL), (0x1ee42, 0x1ee42, Bidi::AL), (0x1ee47, 0x1ee47, Bidi::AL),
+    (0x1ee49, 0x1ee49, Bidi::AL), (0x1ee4b, 0x1ee4b, Bidi::AL), (0x1ee4d, 0x1ee4f, Bidi::AL),
+    (0x1ee51, 0x1ee52, Bidi::AL), (0x1ee54, 0x1ee54, Bidi::AL), (0x1ee57, 0x1ee57, Bidi::AL),
+    (0x1ee59, 0x1ee59, Bidi::AL), (0x1ee5b, 0x1ee5b, Bidi::AL), (0x1ee5d, 0x1ee5d, Bidi::AL),
+    (0x1ee5f, 0x1ee5f, Bidi::AL), (0x1ee61, 0x1ee62, Bidi::AL), (0x1ee64, 0x1ee64, Bidi::AL),
+    (0x1ee67, 0x1ee6a, Bidi::AL), (0x1ee6c, 0x1ee72, Bidi::AL), (0x1ee74, 0x1ee77, Bidi::AL),
+    (0x1ee79, 0x1ee7c, Bidi::AL), (0x1ee7e, 0x1ee7e, Bidi::AL), (0x1ee80, 0x1ee89, Bidi::AL),
+    (0x1ee8b, 0x1ee9b, Bidi::AL), (0x1eea1, 0x1eea3, Bidi::AL), (0x1eea5, 0x1eea9, Bidi::AL),
+    (0x1eeab, 0x1eebb, Bidi::AL), (0x1eef0, 0x1eef1, Bidi::ON), (0x1f000, 0x1f02b, Bidi::ON),
+    (0x1f030, 0x1f093, Bidi::ON), (0x1f0a0, 0x1f0ae, Bidi::ON), (0x1f0b1, 0x1f0bf, Bidi::ON),
+    (0x1f0c1, 0x1f0cf, Bidi::ON), (0x1f0d1, 0x1f0f5, Bidi::ON), (0x1f100, 0x1f10a, Bidi::EN),
+    (0x1f10b, 0x1f10f, Bidi::ON), (0x1f110, 0x1f12e, Bidi::L), (0x1f12f, 0x1f12f, Bidi::ON),
+    (0x1f130, 0x1f169, Bidi::L), (0x1f16a, 0x1f16f, Bidi::ON), (0x1f170, 0x1f1ac, Bidi::L),
+    (0x1f1ad, 0x1f1ad, Bidi::ON), (0x1f1e6, 0x1f202, Bidi::L), (0x1f210, 0x1f23b, Bidi::L),
+    (0x1f240, 0x1f248, Bidi::L), (0x1f250, 0x1f251, Bidi::L), (0x1f260, 0x1f265, Bidi::ON),
+    (0x1f300, 0x1f6d7, Bidi::ON), (0x1f6dc, 0x1f6ec, Bidi::ON), (0x1f6f0, 0x1f6fc, Bidi::ON),
+    (0x1f700, 0x1f776, Bidi::ON), (0x1f77b, 0x1f7d9, Bidi::ON), (0x1f7e0, 0x1f7eb, Bidi::ON),
+    (0x1f7f0, 0x1f7f0, Bidi::ON), (0x1f800, 0x1f80b, Bidi::ON), (0x1f810, 0x1f847, Bidi::ON),
+    (0x1f850, 0x1f859, Bidi::ON), (0x1f860, 0x1f887, Bidi::ON), (0x1f890, 0x1f8ad, Bidi::ON),
+    (0x1f8b0, 0x1f8b1, Bidi::ON), (0x1f900, 0x1fa53, Bidi::ON), (0x1fa60, 0x1fa6d, Bidi::ON),
+    (0x1fa70, 0x1fa7c, Bidi::ON), (0x1fa80, 0x1fa88, Bidi::ON), (0x1fa90, 0x1fabd, Bidi::ON),
+    (0x1fabf, 0x1fac5, Bidi::ON), (0x1face, 0x1fadb, Bidi::ON), (0x1fae0, 0x1fae8, Bidi::ON),
+    (0x1faf0, 0x1faf8, Bidi::ON), (0x1fb00, 0x1fb92, Bidi::ON), (0x1fb94, 0x1fbca, Bidi::ON),
+    (0x1fbf0, 0x1fbf9, Bidi::EN), (0x20000, 0x2a6df, Bidi::L), (0x2a700, 0x2b739, Bidi::L),
+    (0x2b740, 0x2b81d, Bidi::L), (0x2b820, 0x2cea1, Bidi::L), (0x2ceb0, 0x2ebe0, Bidi::L),
+    (0x2ebf0, 0x2ee5d, Bidi::L), (0x2f800, 0x2fa1d, Bidi::L), (0x30000, 0x3134a, Bidi::L),
+    (0x31350, 0x323af, Bidi::L), (0xe0001, 0xe0001, Bidi::BN), (0xe0020, 0xe007f, Bidi::BN),
+    (0xe0100, 0xe01ef, Bidi::NSM), (0xf0000, 0xffffd, Bidi::L), (0x100000, 0x10fffd, Bidi::L),
+];
+
+/// The canonical combining classes other than 0.
+pub static COMBINING_CLASSES: &'static [(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31a, 0x31a, 232),
+    (0x31b, 0x31b, 216), (0x31c, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33c, 220),
+    (0x33d, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34a, 0x34c, 230), (0x34d, 0x34e, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35a, 220), (0x35b, 0x35b, 230),
+    (0x35c, 0x35c, 233), (0x35d, 0x35e, 234), (0x35f, 0x35f, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36f, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59a, 0x59a, 222),
+    (0x59b, 0x59b, 220), (0x59c, 0x5a1, 230), (0x5a2, 0x5a7, 220), (0x5a8, 0x5a9, 230),
+    (0x5aa, 0x5aa, 220), (0x5ab, 0x5ac, 230), (0x5ad, 0x5ad, 222), (0x5ae, 0x5ae, 228),
+    (0x5af, 0x5af, 230), (0x5b0, 0x5b0, 10), (0x5b1, 0x5b1, 11), (0x5b2, 0x5b2, 12),
+    (0x5b3, 0x5b3, 13), (0x5b4, 0x5b4, 14), (0x5b5, 0x5b5, 15), (0x5b6, 0x5b6, 16),
+    (0x5b7, 0x5b7, 17), (0x5b8, 0x5b8, 18), (0x5b9, 0x5ba, 19), (0x5bb, 0x5bb, 20),
+    (0x5bc, 0x5bc, 21), (0x5bd, 0x5bd, 22), (0x5bf, 0x5bf, 23), (0x5c1, 0x5c1, 24),
+    (0x5c2, 0x5c2, 25), (0x5c4, 0x5c4, 230), (0x5c5, 0x5c5, 220), (0x5c7, 0x5c7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61a, 0x61a, 32),
+    (0x64b, 0x64b, 27), (0x64c, 0x64c, 28), (0x64d, 0x64d, 29), (0x64e, 0x64e, 30),
+    (0x64f, 0x64f, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65b, 230), (0x65c, 0x65c, 220),
+    (0x65d, 0x65e, 230), (0x65f, 0x65f, 220), (0x670, 0x670, 35), (0x6d6, 0x6dc, 230),
+    (0x6df, 0x6e2, 230), (0x6e3, 0x6e3, 220), (0x6e4, 0x6e4, 230), (0x6e7, 0x6e8, 230),
+    (0x6ea, 0x6ea, 220), (0x6eb, 0x6ec, 230), (0x6ed, 0x6ed, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73a, 0x73a, 230), (0x73b, 0x73c, 220),
+    (0x73d, 0x73d, 230), (0x73e, 0x73e, 220), (0x73f, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74a, 230), (0x7eb, 0x7f1, 230),
+    (0x7f2, 0x7f2, 220), (0x7f3, 0x7f3, 230), (0x7fd, 0x7fd, 220), (0x816, 0x819, 230),
+    (0x81b, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82d, 230), (0x859, 0x85b, 220),
+    (0x898, 0x898, 230), (0x899, 0x89b, 220), (0x89c, 0x89f, 230), (0x8ca, 0x8ce, 230),
+    (0x8cf, 0x8d3, 220), (0x8d4, 0x8e1, 230), (0x8e3, 0x8e3, 220), (0x8e4, 0x8e5, 230),
+    (0x8e6, 0x8e6, 220), (0x8e7, 0x8e8, 230), (0x8e9, 0x8e9, 220), (0x8ea, 0x8ec, 230),
+    (0x8ed, 0x8ef, 220), (0x8f0, 0x8f0, 27), (0x8f1, 0x8f1, 28), (0x8f2, 0x8f2, 29),
+    (0x8f3, 0x8f5, 230), (0x8f6, 0x8f6, 220), (0x8f7, 0x8f8, 230), (0x8f9, 0x8fa, 220),
+    (0x8fb, 0x8ff, 230), (0x93c, 0x93c, 7), (0x94d, 0x94d, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9bc, 0x9bc, 7), (0x9cd, 0x9cd, 9),
+    (0x9fe, 0x9fe, 230), (0xa3c, 0xa3c, 7), (0xa4d, 0xa4d, 9), (0xabc, 0xabc, 7), (0xacd, 0xacd, 9),
+    (0xb3c, 0xb3c, 7), (0xb4d, 0xb4d, 9), (0xbcd, 0xbcd, 9), (0xc3c, 0xc3c, 7), (0xc4d, 0xc4d, 9),
+    (0xc55, 0xc55, 84), (0xc56, 0xc56, 91), (0xcbc, 0xcbc, 7), (0xccd, 0xccd, 9), (0xd3b, 0xd3c, 9),
+    (0xd4d, 0xd4d, 9), (0xdca, 0xdca, 9), (0xe38, 0xe39, 103), (0xe3a, 0xe3a, 9),
+    (0xe48, 0xe4b, 107), (0xeb8, 0xeb9, 118), (0xeba, 0xeba, 9), (0xec8, 0xecb, 122),
+    (0xf18, 0xf19, 220), (0xf35, 0xf35, 220), (0xf37, 0xf37, 220), (0xf39, 0xf39, 216),
+    (0xf71, 0xf71, 129), (0xf72, 0xf72, 130), (0xf74, 0xf74, 132), (0xf7a, 0xf7d, 130),
+    (0xf80, 0xf80, 130), (0xf82, 0xf83, 230), (0xf84, 0xf84, 9), (0xf86, 0xf87, 230),
+    (0xfc6, 0xfc6, 220), (0x1037, 0x1037, 7), (0x1039, 0x103a, 9), (0x108d, 0x108d, 220),
+    (0x135d, 0x135f, 230), (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17d2, 0x17d2, 9),
+    (0x17dd, 0x17dd, 230), (0x18a9, 0x18a9, 228), (0x1939, 0x1939, 222), (0x193a, 0x193a, 230),
+    (0x193b, 0x193b, 220), (0x1a17, 0x1a17, 230), (0x1a18, 0x1a18, 220), (0x1a60, 0x1a60, 9),
+    (0x1a75, 0x1a7c, 230), (0x1a7f, 0x1a7f, 220), (0x1ab0, 0x1ab4, 230), (0x1ab5, 0x1aba, 220),
+    (0x1abb, 0x1abc, 230), (0x1abd, 0x1abd, 220), (0x1abf, 0x1ac0, 220), (0x1ac1, 0x1ac2, 230),
+    (0x1ac3, 0x1ac4, 220), (0x1ac5, 0x1ac9, 230), (0x1aca, 0x1aca, 220), (0x1acb, 0x1ace, 230),
+    (0x1b34, 0x1b34, 7), (0x1b44, 0x1b44, 9), (0x1b6b, 0x1b6b, 230), (0x1b6c, 0x1b6c, 220),
+    (0x1b6d, 0x1b73, 230), (0x1baa, 0x1bab, 9), (0x1be6, 0x1be6, 7), (0x1bf2, 0x1bf3, 9),
+    (0x1c37, 0x1c37, 7), (0x1cd0, 0x1cd2, 230), (0x1cd4, 0x1cd4, 1), (0x1cd5, 0x1cd9, 220),
+    (0x1cda, 0x1cdb, 230), (0x1cdc, 0x1cdf, 220), (0x1ce0, 0x1ce0, 230), (0x1ce2, 0x1ce8, 1),
+    (0x1ced, 0x1ced, 220), (0x1cf4, 0x1cf4, 230), (0x1cf8, 0x1cf9, 230), (0x1dc0, 0x1dc1, 230),
+    (0x1dc2, 0x1dc2, 220), (0x1dc3, 0x1dc9, 230), (0x1dca, 0x1dca, 220), (0x1dcb, 0x1dcc, 230),
+    (0x1dcd, 0x1dcd, 234), (0x1dce, 0x1dce, 214), (0x1dcf, 0x1dcf, 220), (0x1dd0, 0x1dd0, 202),
+    (0x1dd1, 0x1df5, 230), (0x1df6, 0x1df6, 232), (0x1df7, 0x1df8, 228), (0x1df9, 0x1df9, 220),
+    (0x1dfa, 0x1dfa, 218), (0x1dfb, 0x1dfb, 230), (0x1dfc, 0x1dfc, 233), (0x1dfd, 0x1dfd, 220),
+    (0x1dfe, 0x1dfe, 230), (0x1dff, 0x1dff, 220), (0x20d0, 0x20d1, 230), (0x20d2, 0x20d3, 1),
+    (0x20d4, 0x20d7, 230), (0x20d8, 0x20da, 1), (0x20db, 0x20dc, 230), (0x20e1, 0x20e1, 230),
+    (0x20e5, 0x20e6, 1), (0x20e7, 0x20e7, 230), (0x20e8, 0x20e8, 220), (0x20e9, 0x20e9, 230),
+    (0x20ea, 0x20eb, 1), (0x20ec, 0x20ef, 220), (0x20f0, 0x20f0, 230), (0x2cef, 0x2cf1, 230),
+    (0x2d7f, 0x2d7f, 9), (0x2de0, 0x2dff, 230), (0x302a, 0x302a, 218), (0x302b, 0x302b, 228),
+    (0x302c, 0x302c, 232), (0x302d, 0x302d, 222), (0x302e, 0x302f, 224), (0x3099, 0x309a, 8),
+    (0xa66f, 0xa66f, 230), (0xa674, 0xa67d, 230), (0xa69e, 0xa69f, 230), (0xa6f0, 0xa6f1, 230),
+    (0xa806, 0xa806, 9), (0xa82c, 0xa82c, 9), (0xa8c4, 0xa8c4, 9), (0xa8e0, 0xa8f1, 230),
+    (0xa92b, 0xa92d, 220), (0xa953, 0xa953, 9), (0xa9b3, 0xa9b3, 7), (0xa9c0, 0xa9c0, 9),
+    (0xaab0, 0xaab0, 230), (0xaab2, 0xaab3, 230), (0xaab4, 0xaab4, 220), (0xaab7, 0xaab8, 230),
+    (0xaabe, 0xaabf, 230), (0xaac1, 0xaac1, 230), (0xaaf6, 0xaaf6, 9), (0xabed, 0xabed, 9),
+    (0xfb1e, 0xfb1e, 26), (0xfe20, 0xfe26, 230), (0xfe27, 0xfe2d, 220), (0xfe2e, 0xfe2f, 230),
+    (0x101fd, 0x101fd, 220), (0x102e0, 0x102e0, 220), (0x10376, 0x1037a, 230),
+    (0x10a0d, 0x10a0d, 220), (0x10a0f, 0x10a0f, 230), (0x10a38, 0x10a38, 230),
+    (0x10a39, 0x10a39, 1), (0x10a3a, 0x10a3a, 220), (0x10a3f, 0x10a3f, 9), (0x10ae5, 0x10ae5, 230),
+    (0x10ae6, 0x10ae6, 220), (0x10d24, 0x10d27, 230), (0x10eab, 0x10eac, 230),
+    (0x10efd, 0x10eff, 220), (0x10f46, 0x10f47, 220), (0x10f48, 0x10f4a, 230),
+    (0x10f4b, 0x10f4b, 220), (0x10f4c, 0x10f4c, 230), (0x10f4d, 0x10f50, 220),
+    (0x10f82, 0x10f82, 230), (0x10f83, 0x10f83, 220), (0x10f84, 0x10f84, 230),
+    (0x10f85, 0x10f85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107f, 0x1107f, 9),
+    (0x110b9, 0x110b9, 9), (0x110ba, 0x110ba, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111c0, 0x111c0, 9), (0x111ca, 0x111ca, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112e9, 0x112e9, 7), (0x112ea, 0x112ea, 9), (0x1133b, 0x1133c, 7),
+    (0x1134d, 0x1134d, 9), (0x11366, 0x1136c, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7), (0x1145e, 0x1145e, 230), (0x114c2, 0x114c2, 9), (0x114c3, 0x114c3, 7),
+    (0x115bf, 0x115bf, 9), (0x115c0, 0x115c0, 7), (0x1163f, 0x1163f, 9), (0x116b6, 0x116b6, 9),
+    (0x116b7, 0x116b7, 7), (0x1172b, 0x1172b, 9), (0x11839, 0x11839, 9), (0x1183a, 0x1183a, 7),
+    (0x1193d, 0x1193e, 9), (0x11943, 0x11943, 7), (0x119e0, 0x119e0, 9), (0x11a34, 0x11a34, 9),
+    (0x11a47, 0x11a47, 9), (0x11a99, 0x11a99, 9), (0x11c3f, 0x11c3f, 9), (0x11d42, 0x11d42, 7),
+    (0x11d44, 0x11d45, 9), (0x11d97, 0x11d97, 9), (0x11f41, 0x11f42, 9), (0x16af0, 0x16af4, 1),
+    (0x16b30, 0x16b36, 230), (0x16ff0, 0x16ff1, 6), (0x1bc9e, 0x1bc9e, 1), (0x1d165, 0x1d166, 216),
+    (0x1d167, 0x1d169, 1), (0x1d16d, 0x1d16d, 226), (0x1d16e, 0x1d172, 216),
+    (0x1d17b, 0x1d182, 220), (0x1d185, 0x1d189, 230), (0x1d18a, 0x1d18b, 220),
+    (0x1d1aa, 0x1d1ad, 230), (0x1d242, 0x1d244, 230), (0x1e000, 0x1e006, 230),
+    (0x1e008, 0x1e018, 230), (0x1e01b, 0x1e021, 230), (0x1e023, 0x1e024, 230),
+    (0x1e026, 0x1e02a, 230), (0x1e08f, 0x1e08f, 230), (0x1e130, 0x1e136, 230),
+    (0x1e2ae, 0x1e2ae, 230), (0x1e2ec, 0x1e2ef, 230), (0x1e4ec, 0x1e4ed, 232),
+    (0x1e4ee, 0x1e4ee, 220), (0x1e4ef, 0x1e4ef, 230), (0x1e8d0, 0x1e8d6, 220),
+    (0x1e944, 0x1e949, 230), (0x1e94a, 0x1e94a, 7),
+];
+
+/// The full canonical decompositions, Hangul syllables left out.
+pub static DECOMPOSITIONS: &'static [(u32, &'static str)] = &[
+    (0xc0, "A\u{300}"), (0xc1, "A\u{301}"), (0xc2, "A\u{302}"), (0xc3, "A\u{303}"),
+    (0xc4, "A\u{308}"), (0xc5, "A\u{30a}"), (0xc7, "C\u{327}"), (0xc8, "E\u{300}"),
+    (0xc9, "E\u{301}"), (0xca, "E\u{302}"), (0xcb, "E\u{308}"), (0xcc, "I\u{300}"),
+    (0xcd, "I\u{301}"), (0xce, "I\u{302}"), (0xcf, "I\u{308}"), (0xd1, "N\u{303}"),
+    (0xd2, "O\u{300}"), (0xd3, "O\u{301}"), (0xd4, "O\u{302}"), (0xd5, "O\u{303}"),
+    (0xd6, "O\u{308}"), (0xd9, "U\u{300}"), (0xda, "U\u{301}"), (0xdb, "U\u{302}"),
+    (0xdc, "U\u{308}"), (0xdd, "Y\u{301}"), (0xe0, "a\u{300}"), (0xe1, "a\u{301}"),
+    (0xe2, "a\u{302}"), (0xe3, "a\u{303}"), (0xe4, "a\u{308}"), (0xe5, "a\u{30a}"),
+    (0xe7, "c\u{327}"), (0xe8, "e\u{300}"), (0xe9, "e\u{301}"), (0xea, "e\u{302}"),
+    (0xeb, "e\u{308}"), (0xec, "i\u{300}"), (0xed, "i\u{301}"), (0xee, "i\u{302}"),
+    (0xef, "i\u{308}"), (0xf1, "n\u{303}"), (0xf2, "o\u{300}"), (0xf3, "o\u{301}"),
+    (0xf4, "o\u{302}"), (0xf5, "o\u{303}"), (0xf6, "o\u{308}"), (0xf9, "u\u{300}"),
+    (0xfa, "u\u{301}"), (0xfb, "u\u{302}"), (0xfc, "u\u{308}"), (0xfd, "y\u{301}"),
+    (0xff, "y\u{308}"), (0x100, "A\u{304}"), (0x101, "a\u{304}"), (0x102, "A\u{306}"),
+    (0x103, "a\u{306}"), (0x104, "A\u{328}"), (0x105, "a\u{328}"), (0x106, "C\u{301}"),
+    (0x107, "c\u{301}"), (0x108, "C\u{302}"), (0x109, "c\u{302}"), (0x10a, "C\u{307}"),
+    (0x10b, "c\u{307}"), (0x10c, "C\u{30c}"), (0x10d, "c\u{30c}"), (0x10e, "D\u{30c}"),
+    (0x10f, "d\u{30c}"), (0x112, "E\u{304}"), (0x113, "e\u{304}"), (0x114, "E\u{306}"),
+    (0x115, "e\u{306}"), (0x116, "E\u{307}"), (0x117, "e\u{307}"), (0x118, "E\u{328}"),
+    (0x119, "e\u{328}"), (0x11a, "E\u{30c}"), (0x11b, "e\u{30c}"), (0x11c, "G\u{302}"),
+    (0x11d, "g\u{302}"), (0x11e, "G\u{306}"), (0x11f, "g\u{306}"), (0x120, "G\u{307}"),
+    (0x121, "g\u{307}"), (0x122, "G\u{327}"), (0x123, "g\u{327}"), (0x124, "H\u{302}"),
+    (0x125, "h\u{302}"), (0x128, "I\u{303}"), (0x129, "i\u{303}"), (0x12a, "I\u{304}"),
+    (0x12b, "i\u{304}"), (0x12c, "I\u{306}"), (0x12d, "i\u{306}"), (0x12e, "I\u{328}"),
+    (0x12f, "i\u{328}"), (0x130, "I\u{307}"), (0x134, "J\u{302}"), (0x135, "j\u{302}"),
+    (0x136, "K\u{327}"), (0x137, "k\u{327}"), (0x139, "L\u{301}"), (0x13a, "l\u{301}"),
+    (0x13b, "L\u{327}"), (0x13c, "l\u{327}"), (0x13d, "L\u{30c}"), (0x13e, "l\u{30c}"),
+    (0x143, "N\u{301}"), (0x144, "n\u{301}"), (0x145, "N\u{327}"), (0x146, "n\u{327}"),
+    (0x147, "N\u{30c}"), (0x148, "n\u{30c}"), (0x14c, "O\u{304}"), (0x14d, "o\u{304}"),
+    (0x14e, "O\u{306}"), (0x14f, "o\u{306}"), (0x150, "O\u{30b}"), (0x151, "o\u{30b}"),
+    (0x154, "R\u{301}"), (0x155, "r\u{301}"), (0x156, "R\u{327}"), (0x157, "r\u{327}"),
+    (0x158, "R\u{30c}"), (0x159, "r\u{30c}"), (0x15a, "S\u{301}"), (0x15b, "s\u{301}"),
+    (0x15c, "S\u{302}"), (0x15d, "s\u{302}"), (0x15e, "S\u{327}"), (0x15f, "s\u{327}"),
+    (0x160, "S\u{30c}"), (0x161, "s\u{30c}"), (0x162, "T\u{327}"), (0x163, "t\u{327}"),
+    (0x164, "T\u{30c}"), (0x165, "t\u{30c}"), (0x168, "U\u{303}"), (0x169, "u\u{303}"),
+    (0x16a, "U\u{304}"), (0x16b, "u\u{304}"), (0x16c, "U\u{306}"), (0x16d, "u\u{306}"),
+    (0x16e, "U\u{30a}"), (0x16f, "u\u{30a}"), (0x170, "U\u{30b}"), (0x171, "u\u{30b}"),
+    (0x172, "U\u{328}"), (0x173, "u\u{328}"), (0x174, "W\u{302}"), (0x175, "w\u{302}"),
+    (0x176, "Y\u{302}"), (0x177, "y\u{302}"), (0x178, "Y\u{308}"), (0x179, "Z\u{301}"),
+    (0x17a, "z\u{301}"), (0x17b, "Z\u{307}"), (0x17c, "z\u{307}"), (0x17d, "Z\u{30c}"),
+    (0x17e, "z\u{30c}"), (0x1a0, "O\u{31b}"), (0x1a1, "o\u{31b}"), (0x1af, "U\u{31b}"),
+    (0x1b0, "u\u{31b}"), (0x1cd, "A\u{30c}"), (0x1ce, "a\u{30c}"), (0x1cf, "I\u{30c}"),
+    (0x1d0, "i\u{30c}"), (0x1d1, "O\u{30c}"), (0x1d2, "o\u{30c}"), (0x1d3, "U\u{30c}"),
+    (0x1d4, "u\u{30c}"), (0x1d5, "U\u{308}\u{304}"), (0x1d6, "u\u{308}\u{304}"),
+    (0x1d7, "U\u{308}\u{301}"), (0x1d8, "u\u{308}\u{301}"), (0x1d9, "U\u{308}\u{30c}"),
+    (0x1da, "u\u{308}\u{30c}"), (0x1db, "U\u{308}\u{300}"), (0x1dc, "u\u{308}\u{300}"),
+    (0x1de, "A\u{308}\u{304}"), (0x1df, "a\u{308}\u{304}"), (0x1e0, "A\u{307}\u{304}"),
+    (0x1e1, "a\u{307}\u{304}"), (0x1e2, "\u{c6}\u{304}"), (0x1e3, "\u{e6}\u{304}"),
+    (0x1e6, "G\u{30c}"), (0x1e7, "g\u{30c}"), (0x1e8, "K\u{30c}"), (0x1e9, "k\u{30c}"),
+    (0x1ea, "O\u{328}"), (0x1eb, "o\u{328}"), (0x1ec, "O\u{328}\u{304}"),
+    (0x1ed, "o\u{328}\u{304}"), (0x1ee, "\u{1b7}\u{30c}"), (0x1ef, "\u{292}\u{30c}"),
+    (0x1f0, "j\u{30c}"), (0x1f4, "G\u{301}"), (0x1f5, "g\u{301}"), (0x1f8, "N\u{300}"),
+    (0x1f9, "n\u{300}"), (0x1fa, "A\u{30a}\u{301}"), (0x1fb, "a\u{30a}\u{301}"),
+    (0x1fc, "\u{c6}\u{301}"), (0x1fd, "\u{e6}\u{301}"), (0x1fe, "\u{d8}\u{301}"),
+    (0x1ff, "\u{f8}\u{301}"), (0x200, "A\u{30f}"), (0x201, "a\u{30f}"), (0x202, "A\u{311}"),
+    (0x203, "a\u{311}"), (0x204, "E\u{30f}"), (0x205, "e\u{30f}"), (0x206, "E\u{311}"),
+    (0x207, "e\u{311}"), (0x208, "I\u{30f}"), (0x209, "i\u{30f}"), (0x20a, "I\u{311}"),
+    (0x20b, "i\u{311}"), (0x20c, "O\u{30f}"), (0x20d, "o\u{30f}"), (0x20e, "O\u{311}"),
+    (0x20f, "o\u{311}"), (0x210, "R\u{30f}"), (0x211, "r\u{30f}"), (0x212, "R\u{311}"),
+    (0x213, "r\u{311}"), (0x214, "U\u{30f}"), (0x215, "u\u{30f}"), (0x216, "U\u{311}"),
+    (0x217, "u\u{311}"), (0x218, "S\u{326}"), (0x219, "s\u{326}"), (0x21a, "T\u{326}"),
+    (0x21b, "t\u{326}"), (0x21e, "H\u{30c}"), (0x21f, "h\u{30c}"), (0x226, "A\u{307}"),
+    (0x227, "a\u{307}"), (0x228, "E\u{327}"), (0x229, "e\u{327}"), (0x22a, "O\u{308}\u{304}"),
+    (0x22b, "o\u{308}\u{304}"), (0x22c, "O\u{303}\u{304}"), (0x22d, "o\u{303}\u{304}"),
+    (0x22e, "O\u{307}"), (0x22f, "o\u{307}"), (0x230, "O\u{307}\u{304}"),
+    (0x231, "o\u{307}\u{304}"), (0x232, "Y\u{304}"), (0x233, "y\u{304}"), (0x340, "\u{300}"),
+    (0x341, "\u{301}"), (0x343, "\u{313}"), (0x344, "\u{308}\u{301}"), (0x374, "\u{2b9}"),
+    (0x37e, ";"), (0x385, "\u{a8}\u{301}"), (0x386, "\u{391}\u{301}"), (0x387, "\u{b7}"),
+    (0x388, "\u{395}\u{301}"), (0x389, "\u{397}\u{301}"), (0x38a, "\u{399}\u{301}"),
+    (0x38c, "\u{39f}\u{301}"), (0x38e, "\u{3a5}\u{301}"), (0x38f, "\u{3a9}\u{301}"),
+    (0x390, "\u{3b9}\u{308}\u{301}"), (0x3aa, "\u{399}\u{308}"), (0x3ab, "\u{3a5}\u{308}"),
+    (0x3ac, "\u{3b1}\u{301}"), (0x3ad, "\u{3b5}\u{301}"), (0x3ae, "\u{3b7}\u{301}"),
+    (0x3af, "\u{3b9}\u{301}"), (0x3b0, "\u{3c5}\u{308}\u{301}"), (0x3ca, "\u{3b9}\u{308}"),
+    (0x3cb, "\u{3c5}\u{308}"), (0x3cc, "\u{3bf}\u{301}"), (0x3cd, "\u{3c5}\u{301}"),
+    (0x3ce, "\u{3c9}\u{301}"), (0x3d3, "\u{3d2}\u{301}"), (0x3d4, "\u{3d2}\u{308}"),
+    (0x400, "\u{415}\u{300}"), (0x401, "\u{415}\u{308}"), (0x403, "\u{413}\u{301}"),
+    (0x407, "\u{406}\u{308}"), (0x40c, "\u{41a}\u{301}"), (0x40d, "\u{418}\u{300}"),
+    (0x40e, "\u{423}\u{306}"), (0x419, "\u{418}\u{306}"), (0x439, "\u{438}\u{306}"),
+    (0x450, "\u{435}\u{300}"), (0x451, "\u{435}\u{308}"), (0x453, "\u{433}\u{301}"),
+    (0x457, "\u{456}\u{308}"), (0x45c, "\u{43a}\u{301}"), (0x45d, "\u{438}\u{300}"),
+    (0x45e, "\u{443}\u{306}"), (0x476, "\u{474}\u{30f}"), (0x477, "\u{475}\u{30f}"),
+    (0x4c1, "\u{416}\u{306}"), (0x4c2, "\u{436}\u{306}"), (0x4d0, "\u{410}\u{306}"),
+    (0x4d1, "\u{430}\u{306}"), (0x4d2, "\u{410}\u{308}"), (0x4d3, "\u{430}\u{308}"),
+    (0x4d6, "\u{415}\u{306}"), (0x4d7, "\u{435}\u{306}"), (0x4da, "\u{4d8}\u{308}"),
+    (0x4db, "\u{4d9}\u{308}"), (0x4dc, "\u{416}\u{308}"), (0x4dd, "\u{436}\u{308}"),
+    (0x4de, "\u{417}\u{308}"), (0x4df, "\u{437}\u{308}"), (0x4e2, "\u{418}\u{304}"),
+    (0x4e3, "\u{438}\u{304}"), (0x4e4, "\u{418}\u{308}"), (0x4e5, "\u{438}\u{308}"),
+    (0x4e6, "\u{41e}\u{308}"), (0x4e7, "\u{43e}\u{308}"), (0x4ea, "\u{4e8}\u{308}"),
+    (0x4eb, "\u{4e9}\u{308}"), (0x4ec, "\u{42d}\u{308}"), (0x4ed, "\u{44d}\u{308}"),
+    (0x4ee, "\u{423}\u{304}"), (0x4ef, "\u{443}\u{304}"), (0x4f0, "\u{423}\u{308}"),
+    (0x4f1, "\u{443}\u{308}"), (0x4f2, "\u{423}\u{30b}"), (0x4f3, "\u{443}\u{30b}"),
+    (0x4f4, "\u{427}\u{308}"), (0x4f5, "\u{447}\u{308}"), (0x4f8, "\u{42b}\u{308}"),
+    (0x4f9, "\u{44b}\u{308}"), (0x622, "\u{627}\u{653}"), (0x623, "\u{627}\u{654}"),
+    (0x624, "\u{648}\u{654}"), (0x625, "\u{627}\u{655}"), (0x626, "\u{64a}\u{654}"),
+    (0x6c0, "\u{6d5}\u{654}"), (0x6c2, "\u{6c1}\u{654}"), (0x6d3, "\u{6d2}\u{654}"),
+    (0x929, "\u{928}\u{93c}"), (0x931, "\u{930}\u{93c}"), (0x934, "\u{933}\u{93c}"),
+    (0x958, "\u{915}\u{93c}"), (0x959, "\u{916}\u{93c}"), (0x95a, "\u{917}\u{93c}"),
+    (0x95b, "\u{91c}\u{93c}"), (0x95c, "\u{921}\u{93c}"), (0x95d, "\u{922}\u{93c}"),
+    (0x95e, "\u{92b}\u{93c}"), (0x95f, "\u{92f}\u{93c}"), (0x9cb, "\u{9c7}\u{9be}"),
+    (0x9cc, "\u{9c7}\u{9d7}"), (0x9dc, "\u{9a1}\u{9bc}"), (0x9dd, "\u{9a2}\u{9bc}"),
+    (0x9df, "\u{9af}\u{9bc}"), (0xa33, "\u{a32}\u{a3c}"), (0xa36, "\u{a38}\u{a3c}"),
+    (0xa59, "\u{a16}\u{a3c}"), (0xa5a, "\u{a17}\u{a3c}"), (0xa5b, "\u{a1c}\u{a3c}"),
+    (0xa5e, "\u{a2b}\u{a3c}"), (0xb48, "\u{b47}\u{b56}"), (0xb4b, "\u{b47}\u{b3e}"),
+    (0xb4c, "\u{b47}\u{b57}"), (0xb5c, "\u{b21}\u{b3c}"), (0xb5d, "\u{b22}\u{b3c}"),
+    (0xb94, "\u{b92}\u{bd7}"), (0xbca, "\u{bc6}\u{bbe}"), (0xbcb, "\u{bc7}\u{bbe}"),
+    (0xbcc, "\u{bc6}\u{bd7}"), (0xc48, "\u{c46}\u{c56}"), (0xcc0, "\u{cbf}\u{cd5}"),
+    (0xcc7, "\u{cc6}\u{cd5}"), (0xcc8, "\u{cc6}\u{cd6}"), (0xcca, "\u{cc6}\u{cc2}"),
+    (0xccb, "\u{cc6}\u{cc2}\u{cd5}"), (0xd4a, "\u{d46}\u{d3e}"), (0xd4b, "\u{d47}\u{d3e}"),
+    (0xd4c, "\u{d46}\u{d57}"), (0xdda, "\u{dd9}\u{dca}"), (0xddc, "\u{dd9}\u{dcf}"),
+    (0xddd, "\u{dd9}\u{dcf}\u{dca}"), (0xdde, "\u{dd9}\u{ddf}"), (0xf43, "\u{f42}\u{fb7}"),
+    (0xf4d, "\u{f4c}\u{fb7}"), (0xf52, "\u{f51}\u{fb7}"), (0xf57, "\u{f56}\u{fb7}"),
+    (0xf5c, "\u{f5b}\u{fb7}"), (0xf69, "\u{f40}\u{fb5}"), (0xf73, "\u{f71}\u{f72}"),
+    (0xf75, "\u{f71}\u{f74}"), (0xf76, "\u{fb2}\u{f80}"), (0xf78, "\u{fb3}\u{f80}"),
+    (0xf81, "\u{f71}\u{f80}"), (0xf93, "\u{f92}\u{fb7}"), (0xf9d, "\u{f9c}\u{fb7}"),
+    (0xfa2, "\u{fa1}\u{fb7}"), (0xfa7, "\u{fa6}\u{fb7}"), (0xfac, "\u{fab}\u{fb7}"),
+    (0xfb9, "\u{f90}\u{fb5}"), (0x1026, "\u{1025}\u{102e}"), (0x1b06, "\u{1b05}\u{1b35}"),
+    (0x1b08, "\u{1b07}\u{1b35}"), (0x1b0a, "\u{1b09}\u{1b35}"), (0x1b0c, "\u{1b0b}\u{1b35}"),
+    (0x1b0e, "\u{1b0d}\u{1b35}"), (0x1b12, "\u{1b11}\u{1b35}"), (0x1b3b, "\u{1b3a}\u{1b35}"),
+    (0x1b3d, "\u{1b3c}\u{1b35}"), (0x1b40, "\u{1b3e}\u{1b35}"), (0x1b41, "\u{1b3f}\u{1b35}"),
+    (0x1b43, "\u{1b42}\u{1b35}"), (0x1e00, "A\u{325}"), (0x1e01, "a\u{325}"), (0x1e02, "B\u{307}"),
+    (0x1e03, "b\u{307}"), (0x1e04, "B\u{323}"), (0x1e05, "b\u{323}"), (0x1e06, "B\u{331}"),
+    (0x1e07, "b\u{331}"), (0x1e08, "C\u{327}\u{301}"), (0x1e09, "c\u{327}\u{301}"),
+    (0x1e0a, "D\u{307}"), (0x1e0b, "d\u{307}"), (0x1e0c, "D\u{323}"), (0x1e0d, "d\u{323}"),
+    (0x1e0e, "D\u{331}"), (0x1e0f, "d\u{331}"), (0x1e10, "D\u{327}"), (0x1e11, "d\u{327}"),
+    (0x1e12, "D\u{32d}"), (0x1e13, "d\u{32d}"), (0x1e14, "E\u{304}\u{300}"),
+    (0x1e15, "e\u{304}\u{300}"), (0x1e16, "E\u{304}\u{301}"), (0x1e17, "e\u{304}\u{301}"),
+    (0x1e18, "E\u{32d}"), (0x1e19, "e\u{32d}"), (0x1e1a, "E\u{330}"), (0x1e1b, "e\u{330}"),
+    (0x1e1c, "E\u{327}\u{306}"), (0x1e1d, "e\u{327}\u{306}"), (0x1e1e, "F\u{307}"),
+    (0x1e1f, "f\u{307}"), (0x1e20, "G\u{304}"), (0x1e21, "g\u{304}"), (0x1e22, "H\u{307}"),
+    (0x1e23, "h\u{307}"), (0x1e24, "H\u{323}"), (0x1e25, "h\u{323}"), (0x1e26, "H\u{308}"),
+    (0x1e27, "h\u{308}"), (0x1e28, "H\u{327}"), (0x1e29, "h\u{327}"), (0x1e2a, "H\u{32e}"),
+    (0x1e2b, "h\u{32e}"), (0x1e2c, "I\u{330}"), (0x1e2d, "i\u{330}"), (0x1e2e, "I\u{308}\u{301}"),
+    (0x1e2f, "i\u{308}\u{301}"), (0x1e30, "K\u{301}"), (0x1e31, "k\u{301}"), (0x1e32, "K\u{323}"),
+    (0x1e33, "k\u{323}"), (0x1e34, "K\u{331}"), (0x1e35, "k\u{331}"), (0x1e36, "L\u{323}"),
+    (0x1e37, "l\u{323}"), (0x1e38, "L\u{323}\u{304}"), (0x1e39, "l\u{323}\u{304}"),
+    (0x1e3a, "L\u{331}"), (0x1e3b, "l\u{331}"), (0x1e3c, "L\u{32d}"), (0x1e3d, "l\u{32d}"),
+    (0x1e3e, "M\u{301}"), (0x1e3f, "m\u{301}"), (0x1e40, "M\u{307}"), (0x1e41, "m\u{307}"),
+    (0x1e42, "M\u{323}"), (0x1e43, "m\u{323}"), (0x1e44, "N\u{307}"), (0x1e45, "n\u{307}"),
+    (0x1e46, "N\u{323}"), (0x1e47, "n\u{323}"), (0x1e48, "N\u{331}"), (0x1e49, "n\u{331}"),
+    (0x1e4a, "N\u{32d}"), (0x1e4b, "n\u{32d}"), (0x1e4c, "O\u{303}\u{301}"),
+    (0x1e4d, "o\u{303}\u{301}"), (0x1e4e, "O\u{303}\u{308}"), (0x1e4f, "o\u{303}\u{308}"),
+    (0x1e50, "O\u{304}\u{300}"), (0x1e51, "o\u{304}\u{300}"), (0x1e52, "O\u{304}\u{301}"),
+    (0x1e53, "o\u{304}\u{301}"), (0x1e54, "P\u{301}"), (0x1e55, "p\u{301}"), (0x1e56, "P\u{307}"),
+    (0x1e57, "p\u{307}"), (0x1e58, "R\u{307}"), (0x1e59, "r\u{307}"), (0x1e5a, "R\u{323}"),
+    (0x1e5b, "r\u{323}"), (0x1e5c, "R\u{323}\u{304}"), (0x1e5d, "r\u{323}\u{304}"),
+    (0x1e5e, "R\u{331}"), (0x1e5f, "r\u{331}"), (0x1e60, "S\u{307}"), (0x1e61, "s\u{307}"),
+    (0x1e62, "S\u{323}"), (0x1e63, "s\u{323}"), (0x1e64, "S\u{301}\u{307}"),
+    (0x1e65, "s\u{301}\u{307}"), (0x1e66, "S\u{30c}\u{307}"), (0x1e67, "s\u{30c}\u{307}"),
+    (0x1e68, "S\u{323}\u{307}"), (0x1e69, "s\u{323}\u{307}"), (0x1e6a, "T\u{307}"),
+    (0x1e6b, "t\u{307}"), (0x1e6c, "T\u{323}"), (0x1e6d, "t\u{323}"), (0x1e6e, "T\u{331}"),
+    (0x1e6f, "t\u{331}"), (0x1e70, "T\u{32d}"), (0x1e71, "t\u{32d}"), (0x1e72, "U\u{324}"),
+    (0x1e73, "u\u{324}"), (0x1e74, "U\u{330}"), (0x1e75, "u\u{330}"), (0x1e76, "U\u{32d}"),
+    (0x1e77, "u\u{32d}"), (0x1e78, "U\u{303}\u{301}"), (0x1e79, "u\u{303}\u{301}"),
+    (0x1e7a, "U\u{304}\u{308}"), (0x1e7b, "u\u{304}\u{308}"), (0x1e7c, "V\u{303}"),
+    (0x1e7d, "v\u{303}"), (0x1e7e, "V\u{323}"), (0x1e7f, "v\u{323}"), (0x1e80, "W\u{300}"),
+    (0x1e81, "w\u{300}"), (0x1e82, "W\u{301}"), (0x1e83, "w\u{301}"), (0x1e84, "W\u{308}"),
+    (0x1e85, "w\u{308}"), (0x1e86, "W\u{307}"), (0x1e87, "w\u{307}"), (0x1e88, "W\u{323}"),
+    (0x1e89, "w\u{323}"), (0x1e8a, "X\u{307}"), (0x1e8b, "x\u{307}"), (0x1e8c, "X\u{308}"),
+    (0x1e8d, "x\u{308}"), (0x1e8e, "Y\u{307}"), (0x1e8f, "y\u{307}"), (0x1e90, "Z\u{302}"),
+    (0x1e91, "z\u{302}"), (0x1e92, "Z\u{323}"), (0x1e93, "z\u{323}"), (0x1e94, "Z\u{331}"),
+    (0x1e95, "z\u{331}"), (0x1e96, "h\u{331}"), (0x1e97, "t\u{308}"), (0x1e98, "w\u{30a}"),
+    (0x1e99, "y\u{30a}"), (0x1e9b, "\u{17f}\u{307}"), (0x1ea0, "A\u{323}"), (0x1ea1, "a\u{323}"),
+    (0x1ea2, "A\u{309}"), (0x1ea3, "a\u{309}"), (0x1ea4, "A\u{302}\u{301}"),
+    (0x1ea5, "a\u{302}\u{301}"), (0x1ea6, "A\u{302}\u{300}"), (0x1ea7, "a\u{302}\u{300}"),
+    (0x1ea8, "A\u{302}\u{309}"), (0x1ea9, "a\u{302}\u{309}"), (0x1eaa, "A\u{302}\u{303}"),
+    (0x1eab, "a\u{302}\u{303}"), (0x1eac, "A\u{323}\u{302}"), (0x1ead, "a\u{323}\u{302}"),
+    (0x1eae, "A\u{306}\u{301}"), (0x1eaf, "a\u{306}\u{301}"), (0x1eb0, "A\u{306}\u{300}"),
+    (0x1eb1, "a\u{306}\u{300}"), (0x1eb2, "A\u{306}\u{309}"), (0x1eb3, "a\u{306}\u{309}"),
+    (0x1eb4, "A\u{306}\u{303}"), (0x1eb5, "a\u{306}\u{303}"), (0x1eb6, "A\u{323}\u{306}"),
+    (0x1eb7, "a\u{323}\u{306}"), (0x1eb8, "E\u{323}"), (0x1eb9, "e\u{323}"), (0x1eba, "E\u{309}"),
+    (0x1ebb, "e\u{309}"), (0x1ebc, "E\u{303}"), (0x1ebd, "e\u{303}"), (0x1ebe, "E\u{302}\u{301}"),
+    (0x1ebf, "e\u{302}\u{301}"), (0x1ec0, "E\u{302}\u{300}"), (0x1ec1, "e\u{302}\u{300}"),
+    (0x1ec2, "E\u{302}\u{309}"), (0x1ec3, "e\u{302}\u{309}"), (0x1ec4, "E\u{302}\u{303}"),
+    (0x1ec5, "e\u{302}\u{303}"), (0x1ec6, "E\u{323}\u{302}"), (0x1ec7, "e\u{323}\u{302}"),
+    (0x1ec8, "I\u{309}"), (0x1ec9, "i\u{309}"), (0x1eca, "I\u{323}"), (0x1ecb, "i\u{323}"),
+    (0x1ecc, "O\u{323}"), (0x1ecd, "o\u{323}"), (0x1ece, "O\u{309}"), (0x1ecf, "o\u{309}"),
+    (0x1ed0, "O\u{302}\u{301}"), (0x1ed1, "o\u{302}\u{301}"), (0x1ed2, "O\u{302}\u{300}"),
+    (0x1ed3, "o\u{302}\u{300}"), (0x1ed4, "O\u{302}\u{309}"), (0x1ed5, "o\u{302}\u{309}"),
+    (0x1ed6, "O\u{302}\u{303}"), (0x1ed7, "o\u{302}\u{303}"), (0x1ed8, "O\u{323}\u{302}"),
+    (0x1ed9, "o\u{323}\u{302}"), (0x1eda, "O\u{31b}\u{301}"), (0x1edb, "o\u{31b}\u{301}"),
+    (0x1edc, "O\u{31b}\u{300}"), (0x1edd, "o\u{31b}\u{300}"), (0x1ede, "O\u{31b}\u{309}"),
+    (0x1edf, "o\u{31b}\u{309}"), (0x1ee0, "O\u{31b}\u{303}"), (0x1ee1, "o\u{31b}\u{303}"),
+    (0x1ee2, "O\u{31b}\u{323}"), (0x1ee3, "o\u{31b}\u{323}"), (0x1ee4, "U\u{323}"),
+    (0x1ee5, "u\u{323}"), (0x1ee6, "U\u{309}"), (0x1ee7, "u\u{309}"), (0x1ee8, "U\u{31b}\u{301}"),
+    (0x1ee9, "u\u{31b}\u{301}"), (0x1eea, "U\u{31b}\u{300}"), (0x1eeb, "u\u{31b}\u{300}"),
+    (0x1eec, "U\u{31b}\u{309}"), (0x1eed, "u\u{31b}\u{309}"), (0x1eee, "U\u{31b}\u{303}"),
+    (0x1eef, "u\u{31b}\u{303}"), (0x1ef0, "U\u{31b}\u{323}"), (0x1ef1, "u\u{31b}\u{323}"),
+    (0x1ef2, "Y\u{300}"), (0x1ef3, "y\u{300}"), (0x1ef4, "Y\u{323}"), (0x1ef5, "y\u{323}"),
+    (0x1ef6, "Y\u{309}"), (0x1ef7, "y\u{309}"), (0x1ef8, "Y\u{303}"), (0x1ef9, "y\u{303}"),
+    (0x1f00, "\u{3b1}\u{313}"), (0x1f01, "\u{3b1}\u{314}"), (0x1f02, "\u{3b1}\u{313}\u{300}"),
+    (0x1f03, "\u{3b1}\u{314}\u{300}"), (0x1f04, "\u{3b1}\u{313}\u{301}"),
+    (0x1f05, "\u{3b1}\u{314}\u{301}"), (0x1f06, "\u{3b1}\u{313}\u{342}"),
+    (0x1f07, "\u{3b1}\u{314}\u{342}"), (0x1f08, "\u{391}\u{313}"), (0x1f09, "\u{391}\u{314}"),
+    (0x1f0a, "\u{391}\u{313}\u{300}"), (0x1f0b, "\u{391}\u{314}\u{300}"),
+    (0x1f0c, "\u{391}\u{313}\u{301}"), (0x1f0d, "\u{391}\u{314}\u{301}"),
+    (0x1f0e, "\u{391}\u{313}\u{342}"), (0x1f0f, "\u{391}\u{314}\u{342}"),
+    (0x1f10, "\u{3b5}\u{313}"), (0x1f11, "\u{3b5}\u{314}"), (0x1f12, "\u{3b5}\u{313}\u{300}"),
+    (0x1f13, "\u{3b5}\u{314}\u{300}"), (0x1f14, "\u{3b5}\u{313}\u{301}"),
+    (0x1f15, "\u{3b5}\u{314}\u{301}"), (0x1f18, "\u{395}\u{313}"), (0x1f19, "\u{395}\u{314}"),
+    (0x1f1a, "\u{395}\u{313}\u{300}"), (0x1f1b, "\u{395}\u{314}\u{300}"),
+    (0x1f1c, "\u{395}\u{313}\u{301}"), (0x1f1d, "\u{395}\u{314}\u{301}"),
+    (0x1f20, "\u{3b7}\u{313}"), (0x1f21, "\u{3b7}\u{314}"), (0x1f22, "\u{3b7}\u{313}\u{300}"),
+    (0x1f23, "\u{3b7}\u{314}\u{300}"), (0x1f24, "\u{3b7}\u{313}\u{301}"),
+    (0x1f25, "\u{3b7}\u{314}\u{301}"), (0x1f26, "\u{3b7}\u{313}\u{342}"),
+    (0x1f27, "\u{3b7}\u{314}\u{342}"), (0x1f28, "\u{397}\u{313}"), (0x1f29, "\u{397}\u{314}"),
+    (0x1f2a, "\u{397}\u{313}\u{300}"), (0x1f2b, "\u{397}\u{314}\u{300}"),
+    (0x1f2c, "\u{397}\u{313}\u{301}"), (0x1f2d, "\u{397}\u{314}\u{301}"),
+    (0x1f2e, "\u{397}\u{313}\u{342}"), (0x1f2f, "\u{397}\u{314}\u{342}"),
+    (0x1f30, "\u{3b9}\u{313}"), (0x1f31, "\u{3b9}\u{314}"), (0x1f32, "\u{3b9}\u{313}\u{300}"),
+    (0x1f33, "\u{3b9}\u{314}\u{300}"), (0x1f34, "\u{3b9}\u{313}\u{301}"),
+    (0x1f35, "\u{3b9}\u{314}\u{301}"), (0x1f36, "\u{3b9}\u{313}\u{342}"),
+    (0x1f37, "\u{3b9}\u{314}\u{342}"), (0x1f38, "\u{399}\u{313}"), (0x1f39, "\u{399}\u{314}"),
+    (0x1f3a, "\u{399}\u{313}\u{300}"), (0x1f3b, "\u{399}\u{314}\u{300}"),
+    (0x1f3c, "\u{399}\u{313}\u{301}"), (0x1f3d, "\u{399}\u{314}\u{301}"),
+    (0x1f3e, "\u{399}\u{313}\u{342}"), (0x1f3f, "\u{399}\u{314}\u{342}"),
+    (0x1f40, "\u{3bf}\u{313}"), (0x1f41, "\u{3bf}\u{314}"), (0x1f42, "\u{3bf}\u{313}\u{300}"),
+    (0x1f43, "\u{3bf}\u{314}\u{300}"), (0x1f44, "\u{3bf}\u{313}\u{301}"),
+    (0x1f45, "\u{3bf}\u{314}\u{301}"), (0x1f48, "\u{39f}\u{313}"), (0x1f49, "\u{39f}\u{314}"),
+    (0x1f4a, "\u{39f}\u{313}\u{300}"), (0x1f4b, "\u{39f}\u{314}\u{300}"),
+    (0x1f4c, "\u{39f}\u{313}\u{301}"), (0x1f4d, "\u{39f}\u{314}\u{301}"),
+    (0x1f50, "\u{3c5}\u{313}"), (0x1f51, "\u{3c5}\u{314}"), (0x1f52, "\u{3c5}\u{313}\u{300}"),
+    (0x1f53, "\u{3c5}\u{314}\u{300}"), (0x1f54, "\u{3c5}\u{313}\u{301}"),
+    (0x1f55, "\u{3c5}\u{314}\u{301}"), (0x1f56, "\u{3c5}\u{313}\u{342}"),
+    (0x1f57, "\u{3c5}\u{314}\u{342}"), (0x1f59, "\u{3a5}\u{314}"),
+    (0x1f5b, "\u{3a5}\u{314}\u{300}"), (0x1f5d, "\u{3a5}\u{314}\u{301}"),
+    (0x1f5f, "\u{3a5}\u{314}\u{342}"), (0x1f60, "\u{3c9}\u{313}"), (0x1f61, "\u{3c9}\u{314}"),
+    (0x1f62, "\u{3c9}\u{313}\u{300}"), (0x1f63, "\u{3c9}\u{314}\u{300}"),
+    (0x1f64, "\u{3c9}\u{313}\u{301}"), (0x1f65, "\u{3c9}\u{314}\u{301}"),
+    (0x1f66, "\u{3c9}\u{313}\u{342}"), (0x1f67, "\u{3c9}\u{314}\u{342}"),
+    (0x1f68, "\u{3a9}\u{313}"), (0x1f69, "\u{3a9}\u{314}"), (0x1f6a, "\u{3a9}\u{313}\u{300}"),
+    (0x1f6b, "\u{3a9}\u{314}\u{300}"), (0x1f6c, "\u{3a9}\u{313}\u{301}"),
+    (0x1f6d, "\u{3a9}\u{314}\u{301}"), (0x1f6e, "\u{3a9}\u{313}\u{342}"),
+    (0x1f6f, "\u{3a9}\u{314}\u{342}"), (0x1f70, "\u{3b1}\u{300}"), (0x1f71, "\u{3b1}\u{301}"),
+    (0x1f72, "\u{3b5}\u{300}"), (0x1f73, "\u{3b5}\u{301}"), (0x1f74, "\u{3b7}\u{300}"),
+    (0x1f75, "\u{3b7}\u{301}"), (0x1f76, "\u{3b9}\u{300}"), (0x1f77, "\u{3b9}\u{301}"),
+    (0x1f78, "\u{3bf}\u{300}"), (0x1f79, "\u{3bf}\u{301}"), (0x1f7a, "\u{3c5}\u{300}"),
+    (0x1f7b, "\u{3c5}\u{301}"), (0x1f7c, "\u{3c9}\u{300}"), (0x1f7d, "\u{3c9}\u{301}"),
+    (0x1f80, "\u{3b1}\u{313}\u{345}"), (0x1f81, "\u{3b1}\u{314}\u{345}"),
+    (0x1f82, "\u{3b1}\u{313}\u{300}\u{345}"), (0x1f83, "\u{3b1}\u{314}\u{300}\u{345}"),
+    (0x1f84, "\u{3b1}\u{313}\u{301}\u{345}"), (0x1f85, "\u{3b1}\u{314}\u{301}\u{345}"),
+    (0x1f86, "\u{3b1}\u{313}\u{342}\u{345}"), (0x1f87, "\u{3b1}\u{314}\u{342}\u{345}"),
+    (0x1f88, "\u{391}\u{313}\u{345}"), (0x1f89, "\u{391}\u{314}\u{345}"),
+    (0x1f8a, "\u{391}\u{313}\u{300}\u{345}"), (0x1f8b, "\u{391}\u{314}\u{300}\u{345}"),
+    (0x1f8c, "\u{391}\u{313}\u{301}\u{345}"), (0x1f8d, "\u{391}\u{314}\u{301}\u{345}"),
+    (0x1f8e, "\u{391}\u{313}\u{342}\u{345}"), (0x1f8f, "\u{391}\u{314}\u{342}\u{345}"),
+    (0x1f90, "\u{3b7}\u{313}\u{345}"), (0x1f91, "\u{3b7}\u{314}\u{345}"),
+    (0x1f92, "\u{3b7}\u{313}\u{300}\u{345}"), (0x1f93, "\u{3b7}\u{314}\u{300}\u{345}"),
+    (0x1f94, "\u{3b7}\u{313}\u{301}\u{345}"), (0x1f95, "\u{3b7}\u{314}\u{301}\u{345}"),
+    (0x1f96, "\u{3b7}\u{313}\u{342}\u{345}"), (0x1f97, "\u{3b7}\u{314}\u{342}\u{345}"),
+    (0x1f98, "\u{397}\u{313}\u{345}"), (0x1f99, "\u{397}\u{314}\u{345}"),
+    (0x1f9a, "\u{397}\u{313}\u{300}\u{345}"), (0x1f9b, "\u{397}\u{314}\u{300}\u{345}"),
+    (0x1f9c, "\u{397}\u{313}\u{301}\u{345}"), (0x1f9d, "\u{397}\u{314}\u{301}\u{345}"),
+    (0x1f9e, "\u{397}\u{313}\u{342}\u{345}"), (0x1f9f, "\u{397}\u{314}\u{342}\u{345}"),
+    (0x1fa0, "\u{3c9}\u{313}\u{345}"), (0x1fa1, "\u{3c9}\u{314}\u{345}"),
+    (0x1fa2, "\u{3c9}\u{313}\u{300}\u{345}"), (0x1fa3, "\u{3c9}\u{314}\u{300}\u{345}"),
+    (0x1fa4, "\u{3c9}\u{313}\u{301}\u{345}"), (0x1fa5, "\u{3c9}\u{314}\u{301}\u{345}"),
+    (0x1fa6, "\u{3c9}\u{313}\u{342}\u{345}"), (0x1fa7, "\u{3c9}\u{314}\u{342}\u{345}"),
+    (0x1fa8, "\u{3a9}\u{313}\u{345}"), (0x1fa9, "\u{3a9}\u{314}\u{345}"),
+    (0x1faa, "\u{3a9}\u{313}\u{300}\u{345}"), (0x1fab, "\u{3a9}\u{314}\u{300}\u{345}"),
+    (0x1fac, "\u{3a9}\u{313}\u{301}\u{345}"), (0x1fad, "\u{3a9}\u{314}\u{301}\u{345}"),
+    (0x1fae, "\u{3a9}\u{313}\u{342}\u{345}"), (0x1faf, "\u{3a9}\u{314}\u{342}\u{345}"),
+    (0x1fb0, "\u{3b1}\u{306}"), (0x1fb1, "\u{3b1}\u{304}"), (0x1fb2, "\u{3b1}\u{300}\u{345}"),
+    (0x1fb3, "\u{3b1}\u{345}"), (0x1fb4, "\u{3b1}\u{301}\u{345}"), (0x1fb6, "\u{3b1}\u{342}"),
+    (0x1fb7, "\u{3b1}\u{342}\u{345}"), (0x1fb8, "\u{391}\u{306}"), (0x1fb9, "\u{391}\u{304}"),
+    (0x1fba, "\u{391}\u{300}"), (0x1fbb, "\u{391}\u{301}"), (0x1fbc, "\u{391}\u{345}"),
+    (0x1fbe, "\u{3b9}"), (0x1fc1, "\u{a8}\u{342}"), (0x1fc2, "\u{3b7}\u{300}\u{345}"),
+    (0x1fc3, "\u{3b7}\u{345}"), (0x1fc4, "\u{3b7}\u{301}\u{345}"), (0x1fc6, "\u{3b7}\u{342}"),
+    (0x1fc7, "\u{3b7}\u{342}\u{345}"), (0x1fc8, "\u{395}\u{300}"), (0x1fc9, "\u{395}\u{301}"),
+    (0x1fca, "\u{397}\u{300}"), (0x1fcb, "\u{397}\u{301}"), (0x1fcc, "\u{397}\u{345}"),
+    (0x1fcd, "\u{1fbf}\u{300}"), (0x1fce, "\u{1fbf}\u{301}"), (0x1fcf, "\u{1fbf}\u{342}"),
+    (0x1fd0, "\u{3b9}\u{306}"), (0x1fd1, "\u{3b9}\u{304}"), (0x1fd2, "\u{3b9}\u{308}\u{300}"),
+    (0x1fd3, "\u{3b9}\u{308}\u{301}"), (0x1fd6, "\u{3b9}\u{342}"),
+    (0x1fd7, "\u{3b9}\u{308}\u{342}"), (0x1fd8, "\u{399}\u{306}"), (0x1fd9, "\u{399}\u{304}"),
+    (0x1fda, "\u{399}\u{300}"), (0x1fdb, "\u{399}\u{301}"), (0x1fdd, "\u{1ffe}\u{300}"),
+    (0x1fde, "\u{1ffe}\u{301}"), (0x1fdf, "\u{1ffe}\u{342}"), (0x1fe0, "\u{3c5}\u{306}"),
+    (0x1fe1, "\u{3c5}\u{304}"), (0x1fe2, "\u{3c5}\u{308}\u{300}"),
+    (0x1fe3, "\u{3c5}\u{308}\u{301}"), (0x1fe4, "\u{3c1}\u{313}"), (0x1fe5, "\u{3c1}\u{314}"),
+    (0x1fe6, "\u{3c5}\u{342}"), (0x1fe7, "\u{3c5}\u{308}\u{342}"), (0x1fe8, "\u{3a5}\u{306}"),
+    (0x1fe9, "\u{3a5}\u{304}"), (0x1fea, "\u{3a5}\u{300}"), (0x1feb, "\u{3a5}\u{301}"),
+    (0x1fec, "\u{3a1}\u{314}"), (0x1fed, "\u{a8}\u{300}"), (0x1fee, "\u{a8}\u{301}"), (0x1fef, "`"),
+    (0x1ff2, "\u{3c9}\u{300}\u{345}"), (0x1ff3, "\u{3c9}\u{345}"),
+    (0x1ff4, "\u{3c9}\u{301}\u{345}"), (0x1ff6, "\u{3c9}\u{342}"),
+    (0x1ff7, "\u{3c9}\u{342}\u{345}"), (0x1ff8, "\u{39f}\u{300}"), (0x1ff9, "\u{39f}\u{301}"),
+    (0x1ffa, "\u{3a9}\u{300}"), (0x1ffb, "\u{3a9}\u{301}"), (0x1ffc, "\u{3a9}\u{345}"),
+    (0x1ffd, "\u{b4}"), (0x2000, "\u{2002}"), (0x2001, "\u{2003}"), (0x2126, "\u{3a9}"),
+    (0x212a, "K"), (0x212b, "A\u{30a}"), (0x219a, "\u{2190}\u{338}"), (0x219b, "\u{2192}\u{338}"),
+    (0x21ae, "\u{2194}\u{338}"), (0x21cd, "\u{21d0}\u{338}"), (0x21ce, "\u{21d4}\u{338}"),
+    (0x21cf, "\u{21d2}\u{338}"), (0x2204, "\u{2203}\u{338}"), (0x2209, "\u{2208}\u{338}"),
+    (0x220c, "\u{220b}\u{338}"), (0x2224, "\u{2223}\u{338}"), (0x2226, "\u{2225}\u{338}"),
+    (0x2241, "\u{223c}\u{338}"), (0x2244, "\u{2243}\u{338}"), (0x2247, "\u{2245}\u{338}"),
+    (0x2249, "\u{2248}\u{338}"), (0x2260, "=\u{338}"), (0x2262, "\u{2261}\u{338}"),
+    (0x226d, "\u{224d}\u{338}"), (0x226e, "<\u{338}"), (0x226f, ">\u{338}"),
+    (0x2270, "\u{2264}\u{338}"), (0x2271, "\u{2265}\u{338}"), (0x2274, "\u{2272}\u{338}"),
+    (0x2275, "\u{2273}\u{338}"), (0x2278, "\u{2276}\u{338}"), (0x2279, "\u{2277}\u{338}"),
+    (0x2280, "\u{227a}\u{338}"), (0x2281, "\u{227b}\u{338}"), (0x2284, "\u{2282}\u{338}"),
+    (0x2285, "\u{2283}\u{338}"), (0x2288, "\u{2286}\u{338}"), (0x2289, "\u{2287}\u{338}"),
+    (0x22ac, "\u{22a2}\u{338}"), (0x22ad, "\u{22a8}\u{338}"), (0x22ae, "\u{22a9}\u{338}"),
+    (0x22af, "\u{22ab}\u{338}"), (0x22e0, "\u{227c}\u{338}"), (0x22e1, "\u{227d}\u{338}"),
+    (0x22e2, "\u{2291}\u{338}"), (0x22e3, "\u{2292}\u{338}"), (0x22ea, "\u{22b2}\u{338}"),
+    (0x22eb, "\u{22b3}\u{338}"), (0x22ec, "\u{22b4}\u{338}"), (0x22ed, "\u{22b5}\u{338}"),
+    (0x2329, "\u{3008}"), (0x232a, "\u{3009}"), (0x2adc, "\u{2add}\u{338}"),
+    (0x304c, "\u{304b}\u{3099}"), (0x304e, "\u{304d}\u{3099}"), (0x3050, "\u{304f}\u{3099}"),
+    (0x3052, "\u{3051}\u{3099}"), (0x3054, "\u{3053}\u{3099}"), (0x3056, "\u{3055}\u{3099}"),
+    (0x3058, "\u{3057}\u{3099}"), (0x305a, "\u{3059}\u{3099}"), (0x305c, "\u{305b}\u{3099}"),
+    (0x305e, "\u{305d}\u{3099}"), (0x3060, "\u{305f}\u{3099}"), (0x3062, "\u{3061}\u{3099}"),
+    (0x3065, "\u{3064}\u{3099}"), (0x3067, "\u{3066}\u{3099}"), (0x3069, "\u{3068}\u{3099}"),
+    (0x3070, "\u{306f}\u{3099}"), (0x3071, "\u{306f}\u{309a}"), (0x3073, "\u{3072}\u{3099}"),
+    (0x3074, "\u{3072}\u{309a}"), (0x3076, "\u{3075}\u{3099}"), (0x3077, "\u{3075}\u{309a}"),
+    (0x3079, "\u{3078}\u{3099}"), (0x307a, "\u{3078}\u{309a}"), (0x307c, "\u{307b}\u{3099}"),
+    (0x307d, "\u{307b}\u{309a}"), (0x3094, "\u{3046}\u{3099}"), (0x309e, "\u{309d}\u{3099}"),
+    (0x30ac, "\u{30ab}\u{3099}"), (0x30ae, "\u{30ad}\u{3099}"), (0x30b0, "\u{30af}\u{3099}"),
+    (0x30b2, "\u{30b1}\u{3099}"), (0x30b4, "\u{30b3}\u{3099}"), (0x30b6, "\u{30b5}\u{3099}"),
+    (0x30b8, "\u{30b7}\u{3099}"), (0x30ba, "\u{30b9}\u{3099}"), (0x30bc, "\u{30bb}\u{3099}"),
+    (0x30be, "\u{30bd}\u{3099}"), (0x30c0, "\u{30bf}\u{3099}"), (0x30c2, "\u{30c1}\u{3099}"),
+    (0x30c5, "\u{30c4}\u{3099}"), (0x30c7, "\u{30c6}\u{3099}"), (0x30c9, "\u{30c8}\u{3099}"),
+    (0x30d0, "\u{30cf}\u{3099}"), (0x30d1, "\u{30cf}\u{309a}"), (0x30d3, "\u{30d2}\u{3099}"),
+    (0x30d4, "\u{30d2}\u{309a}"), (0x30d6, "\u{30d5}\u{3099}"), (0x30d7, "\u{30d5}\u{309a}"),
+    (0x30d9, "\u{30d8}\u{3099}"), (0x30da, "\u{30d8}\u{309a}"), (0x30dc, "\u{30db}\u{3099}"),
+    (0x30dd, "\u{30db}\u{309a}"), (0x30f4, "\u{30a6}\u{3099}"), (0x30f7, "\u{30ef}\u{3099}"),
+    (0x30f8, "\u{30f0}\u{3099}"), (0x30f9, "\u{30f1}\u{3099}"), (0x30fa, "\u{30f2}\u{3099}"),
+    (0x30fe, "\u{30fd}\u{3099}"), (0xf900, "\u{8c48}"), (0xf901, "\u{66f4}"), (0xf902, "\u{8eca}"),
+    (0xf903, "\u{8cc8}"), (0xf904, "\u{6ed1}"), (0xf905, "\u{4e32}"), (0xf906, "\u{53e5}"),
+    (0xf907, "\u{9f9c}"), (0xf908, "\u{9f9c}"), (0xf909, "\u{5951}"), (0xf90a, "\u{91d1}"),
+    (0xf90b, "\u{5587}"), (0xf90c, "\u{5948}"), (0xf90d, "\u{61f6}"), (0xf90e, "\u{7669}"),
+    (0xf90f, "\u{7f85}"), (0xf910, "\u{863f}"), (0xf911, "\u{87ba}"), (0xf912, "\u{88f8}"),
+    (0xf913, "\u{908f}"), (0xf914, "\u{6a02}"), (0xf915, "\u{6d1b}"), (0xf916, "\u{70d9}"),
+    (0xf917, "\u{73de}"), (0xf918, "\u{843d}"), (0xf919, "\u{916a}"), (0xf91a, "\u{99f1}"),
+    (0xf91b, "\u{4e82}"), (0xf91c, "\u{5375}"), (0xf91d, "\u{6b04}"), (0xf91e, "\u{721b}"),
+    (0xf91f, "\u{862d}"), (0xf920, "\u{9e1e}"), (0xf921, "\u{5d50}"), (0xf922, "\u{6feb}"),
+    (0xf923, "\u{85cd}"), (0xf924, "\u{8964}"), (0xf925, "\u{62c9}"), (0xf926, "\u{81d8}"),
+    (0xf927, "\u{881f}"), (0xf928, "\u{5eca}"), (0xf929, "\u{6717}"), (0xf92a, "\u{6d6a}"),
+    (0xf92b, "\u{72fc}"), (0xf92c, "\u{90ce}"), (0xf92d, "\u{4f86}"), (0xf92e, "\u{51b7}"),
+    (0xf92f, "\u{52de}"), (0xf930, "\u{64c4}"), (0xf931, "\u{6ad3}"), (0xf932, "\u{7210}"),
+    (0xf933, "\u{76e7}"), (0xf934, "\u{8001}"), (0xf935, "\u{8606}"), (0xf936, "\u{865c}"),
+    (0xf937, "\u{8def}"), (0xf938, "\u{9732}"), (0xf939, "\u{9b6f}"), (0xf93a, "\u{9dfa}"),
+    (0xf93b, "\u{788c}"), (0xf93c, "\u{797f}"), (0xf93d, "\u{7da0}"), (0xf93e, "\u{83c9}"),
+    (0xf93f, "\u{9304}"), (0xf940, "\u{9e7f}"), (0xf941, "\u{8ad6}"), (0xf942, "\u{58df}"),
+    (0xf943, "\u{5f04}"), (0xf944, "\u{7c60}"), (0xf945, "\u{807e}"), (0xf946, "\u{7262}"),
+    (0xf947, "\u{78ca}"), (0xf948, "\u{8cc2}"), (0xf949, "\u{96f7}"), (0xf94a, "\u{58d8}"),
+    (0xf94b, "\u{5c62}"), (0xf94c, "\u{6a13}"), (0xf94d, "\u{6dda}"), (0xf94e, "\u{6f0f}"),
+    (0xf94f, "\u{7d2f}"), (0xf950, "\u{7e37}"), (0xf951, "\u{964b}"), (0xf952, "\u{52d2}"),
+    (0xf953, "\u{808b}"), (0xf954, "\u{51dc}"), (0xf955, "\u{51cc}"), (0xf956, "\u{7a1c}"),
+    (0xf957, "\u{7dbe}"), (0xf958, "\u{83f1}"), (0xf959, "\u{9675}"), (0xf95a, "\u{8b80}"),
+    (0xf95b, "\u{62cf}"), (0xf95c, "\u{6a02}"), (0xf95d, "\u{8afe}"), (0xf95e, "\u{4e39}"),
+    (0xf95f, "\u{5be7}"), (0xf960, "\u{6012}"), (0xf961, "\u{7387}"), (0xf962, "\u{7570}"),
+    (0xf963, "\u{5317}"), (0xf964, "\u{78fb}"), (0xf965, "\u{4fbf}"), (0xf966, "\u{5fa9}"),
+    (0xf967, "\u{4e0d}"), (0xf968, "\u{6ccc}"), (0xf969, "\u{6578}"), (0xf96a, "\u{7d22}"),
+    (0xf96b, "\u{53c3}"), (0xf96c, "\u{585e}"), (0xf96d, "\u{7701}"), (0xf96e, "\u{8449}"),
+    (0xf96f, "\u{8aaa}"), (0xf970, "\u{6bba}"), (0xf971, "\u{8fb0}"), (0xf972, "\u{6c88}"),
+    (0xf973, "\u{62fe}"), (0xf974, "\u{82e5}"), (0xf975, "\u{63a0}"), (0xf976, "\u{7565}"),
+    (0xf977, "\u{4eae}"), (0xf978, "\u{5169}"), (0xf979, "\u{51c9}"), (0xf97a, "\u{6881}"),
+    (0xf97b, "\u{7ce7}"), (0xf97c, "\u{826f}"), (0xf97d, "\u{8ad2}"), (0xf97e, "\u{91cf}"),
+    (0xf97f, "\u{52f5}"), (0xf980, "\u{5442}"), (0xf981, "\u{5973}"), (0xf982, "\u{5eec}"),
+    (0xf983, "\u{65c5}"), (0xf984, "\u{6ffe}"), (0xf985, "\u{792a}"), (0xf986, "\u{95ad}"),
+    (0xf987, "\u{9a6a}"), (0xf988, "\u{9e97}"), (0xf989, "\u{9ece}"), (0xf98a, "\u{529b}"),
+    (0xf98b, "\u{66c6}"), (0xf98c, "\u{6b77}"), (0xf98d, "\u{8f62}"), (0xf98e, "\u{5e74}"),
+    (0xf98f, "\u{6190}"), (0xf990, "\u{6200}"), (0xf991, "\u{649a}"), (0xf992, "\u{6f23}"),
+    (0xf993, "\u{7149}"), (0xf994, "\u{7489}"), (0xf995, "\u{79ca}"), (0xf996, "\u{7df4}"),
+    (0xf997, "\u{806f}"), (0xf998, "\u{8f26}"), (0xf999, "\u{84ee}"), (0xf99a, "\u{9023}"),
+    (0xf99b, "\u{934a}"), (0xf99c, "\u{5217}"), (0xf99d, "\u{52a3}"), (0xf99e, "\u{54bd}"),
+    (0xf99f, "\u{70c8}"), (0xf9a0, "\u{88c2}"), (0xf9a1, "\u{8aaa}"), (0xf9a2, "\u{5ec9}"),
+    (0xf9a3, "\u{5ff5}"), (0xf9a4, "\u{637b}"), (0xf9a5, "\u{6bae}"), (0xf9a6, "\u{7c3e}"),
+    (0xf9a7, "\u{7375}"), (0xf9a8, "\u{4ee4}"), (0xf9a9, "\u{56f9}"), (0xf9aa, "\u{5be7}"),
+    (0xf9ab, "\u{5dba}"), (0xf9ac, "\u{601c}"), (0xf9ad, "\u{73b2}"), (0xf9ae, "\u{7469}"),
+    (0xf9af, "\u{7f9a}"), (0xf9b0, "\u{8046}"), (0xf9b1, "\u{9234}"), (0xf9b2, "\u{96f6}"),
+    (0xf9b3, "\u{9748}"), (0xf9b4, "\u{9818}"), (0xf9b5, "\u{4f8b}"), (0xf9b6, "\u{79ae}"),
+    (0xf9b7, "\u{91b4}"), (0xf9b8, "\u{96b8}"), (0xf9b9, "\u{60e1}"), (0xf9ba, "\u{4e86}"),
+    (0xf9bb, "\u{50da}"), (0xf9bc, "\u{5bee}"), (0xf9bd, "\u{5c3f}"), (0xf9be, "\u{6599}"),
+    (0xf9bf, "\u{6a02}"), (0xf9c0, "\u{71ce}"), (0xf9c1, "\u{7642}"), (0xf9c2, "\u{84fc}"),
+    (0xf9c3, "\u{907c}"), (0xf9c4, "\u{9f8d}"), (0xf9c5, "\u{6688}"), (0xf9c6, "\u{962e}"),
+    (0xf9c7, "\u{5289}"), (0xf9c8, "\u{677b}"), (0xf9c9, "\u{67f3}"), (0xf9ca, "\u{6d41}"),
+    (0xf9cb, "\u{6e9c}"), (0xf9cc, "\u{7409}"), (0xf9cd, "\u{7559}"), (0xf9ce, "\u{786b}"),
+    (0xf9cf, "\u{7d10}"), (0xf9d0, "\u{985e}"), (0xf9d1, "\u{516d}"), (0xf9d2, "\u{622e}"),
+    (0xf9d3, "\u{9678}"), (0xf9d4, "\u{502b}"), (0xf9d5, "\u{5d19}"), (0xf9d6, "\u{6dea}"),
+    (0xf9d7, "\u{8f2a}"), (0xf9d8, "\u{5f8b}"), (0xf9d9, "\u{6144}"), (0xf9da, "\u{6817}"),
+    (0xf9db, "\u{7387}"), (0xf9dc, "\u{9686}"), (0xf9dd, "\u{5229}"), (0xf9de, "\u{540f}"),
+    (0xf9df, "\u{5c65}"), (0xf9e0, "\u{6613}"), (0xf9e1, "\u{674e}"), (0xf9e2, "\u{68a8}"),
+    (0xf9e3, "\u{6ce5}"), (0xf9e4, "\u{7406}"), (0xf9e5, "\u{75e2}"), (0xf9e6, "\u{7f79}"),
+    (0xf9e7, "\u{88cf}"), (0xf9e8, "\u{88e1}"), (0xf9e9, "\u{91cc}"), (0xf9ea, "\u{96e2}"),
+    (0xf9eb, "\u{533f}"), (0xf9ec, "\u{6eba}"), (0xf9ed, "\u{541d}"), (0xf9ee, "\u{71d0}"),
+    (0xf9ef, "\u{7498}"), (0xf9f0, "\u{85fa}"), (0xf9f1, "\u{96a3}"), (0xf9f2, "\u{9c57}"),
+    (0xf9f3, "\u{9e9f}"), (0xf9f4, "\u{6797}"), (0xf9f5, "\u{6dcb}"), (0xf9f6, "\u{81e8}"),
+    (0xf9f7, "\u{7acb}"), (0xf9f8, "\u{7b20}"), (0xf9f9, "\u{7c92}"), (0xf9fa, "\u{72c0}"),
+    (0xf9fb, "\u{7099}"), (0xf9fc, "\u{8b58}"), (0xf9fd, "\u{4ec0}"), (0xf9fe, "\u{8336}"),
+    (0xf9ff, "\u{523a}"), (0xfa00, "\u{5207}"), (0xfa01, "\u{5ea6}"), (0xfa02, "\u{62d3}"),
+    (0xfa03, "\u{7cd6}"), (0xfa04, "\u{5b85}"), (0xfa05, "\u{6d1e}"), (0xfa06, "\u{66b4}"),
+    (0xfa07, "\u{8f3b}"), (0xfa08, "\u{884c}"), (0xfa09, "\u{964d}"), (0xfa0a, "\u{898b}"),
+    (0xfa0b, "\u{5ed3}"), (0xfa0c, "\u{5140}"), (0xfa0d, "\u{55c0}"), (0xfa10, "\u{585a}"),
+    (0xfa12, "\u{6674}"), (0xfa15, "\u{51de}"), (0xfa16, "\u{732a}"), (0xfa17, "\u{76ca}"),
+    (0xfa18, "\u{793c}"), (0xfa19, "\u{795e}"), (0xfa1a, "\u{7965}"), (0xfa1b, "\u{798f}"),
+    (0xfa1c, "\u{9756}"), (0xfa1d, "\u{7cbe}"), (0xfa1e, "\u{7fbd}"), (0xfa20, "\u{8612}"),
+    (0xfa22, "\u{8af8}"), (0xfa25, "\u{9038}"), (0xfa26, "\u{90fd}"), (0xfa2a, "\u{98ef}"),
+    (0xfa2b, "\u{98fc}"), (0xfa2c, "\u{9928}"), (0xfa2d, "\u{9db4}"), (0xfa2e, "\u{90de}"),
+    (0xfa2f, "\u{96b7}"), (0xfa30, "\u{4fae}"), (0xfa31, "\u{50e7}"), (0xfa32, "\u{514d}"),
+    (0xfa33, "\u{52c9}"), (0xfa34, "\u{52e4}"), (0xfa35, "\u{5351}"), (0xfa36, "\u{559d}"),
+    (0xfa37, "\u{5606}"), (0xfa38, "\u{5668}"), (0xfa39, "\u{5840}"), (0xfa3a, "\u{58a8}"),
+    (0xfa3b, "\u{5c64}"), (0xfa3c, "\u{5c6e}"), (0xfa3d, "\u{6094}"), (0xfa3e, "\u{6168}"),
+    (0xfa3f, "\u{618e}"), (0xfa40, "\u{61f2}"), (0xfa41, "\u{654f}"), (0xfa42, "\u{65e2}"),
+    (0xfa43, "\u{6691}"), (0xfa44, "\u{6885}"), (0xfa45, "\u{6d77}"), (0xfa46, "\u{6e1a}"),
+    (0xfa47, "\u{6f22}"), (0xfa48, "\u{716e}"), (0xfa49, "\u{722b}"), (0xfa4a, "\u{7422}"),
+    (0xfa4b, "\u{7891}"), (0xfa4c, "\u{793e}"), (0xfa4d, "\u{7949}"), (0xfa4e, "\u{7948}"),
+    (0xfa4f, "\u{7950}"), (0xfa50, "\u{7956}"), (0xfa51, "\u{795d}"), (0xfa52, "\u{798d}"),
+    (0xfa53, "\u{798e}"), (0xfa54, "\u{7a40}"), (0xfa55, "\u{7a81}"), (0xfa56, "\u{7bc0}"),
+    (0xfa57, "\u{7df4}"), (0xfa58, "\u{7e09}"), (0xfa59, "\u{7e41}"), (0xfa5a, "\u{7f72}"),
+    (0xfa5b, "\u{8005}"), (0xfa5c, "\u{81ed}"), (0xfa5d, "\u{8279}"), (0xfa5e, "\u{8279}"),
+    (0xfa5f, "\u{8457}"), (0xfa60, "\u{8910}"), (0xfa61, "\u{8996}"), (0xfa62, "\u{8b01}"),
+    (0xfa63, "\u{8b39}"), (0xfa64, "\u{8cd3}"), (0xfa65, "\u{8d08}"), (0xfa66, "\u{8fb6}"),
+    (0xfa67, "\u{9038}"), (0xfa68, "\u{96e3}"), (0xfa69, "\u{97ff}"), (0xfa6a, "\u{983b}"),
+    (0xfa6b, "\u{6075}"), (0xfa6c, "\u{242ee}"), (0xfa6d, "\u{8218}"), (0xfa70, "\u{4e26}"),
+    (0xfa71, "\u{51b5}"), (0xfa72, "\u{5168}"), (0xfa73, "\u{4f80}"), (0xfa74, "\u{5145}"),
+    (0xfa75, "\u{5180}"), (0xfa76, "\u{52c7}"), (0xfa77, "\u{52fa}"), (0xfa78, "\u{559d}"),
+    (0xfa79, "\u{5555}"), (0xfa7a, "\u{5599}"), (0xfa7b, "\u{55e2}"), (0xfa7c, "\u{585a}"),
+    (0xfa7d, "\u{58b3}"), (0xfa7e, "\u{5944}"), (0xfa7f, "\u{5954}"), (0xfa80, "\u{5a62}"),
+    (0xfa81, "\u{5b28}"), (0xfa82, "\u{5ed2}"), (0xfa83, "\u{5ed9}"), (0xfa84, "\u{5f69}"),
+    (0xfa85, "\u{5fad}"), (0xfa86, "\u{60d8}"), (0xfa87, "\u{614e}"), (0xfa88, "\u{6108}"),
+    (0xfa89, "\u{618e}"), (0xfa8a, "\u{6160}"), (0xfa8b, "\u{61f2}"), (0xfa8c, "\u{6234}"),
+    (0xfa8d, "\u{63c4}"), (0xfa8e, "\u{641c}"), (0xfa8f, "\u{6452}"), (0xfa90, "\u{6556}"),
+    (0xfa91, "\u{6674}"), (0xfa92, "\u{6717}"), (0xfa93, "\u{671b}"), (0xfa94, "\u{6756}"),
+    (0xfa95, "\u{6b79}"), (0xfa96, "\u{6bba}"), (0xfa97, "\u{6d41}"), (0xfa98, "\u{6edb}"),
+    (0xfa99, "\u{6ecb}"), (0xfa9a, "\u{6f22}"), (0xfa9b, "\u{701e}"), (0xfa9c, "\u{716e}"),
+    (0xfa9d, "\u{77a7}"), (0xfa9e, "\u{7235}"), (0xfa9f, "\u{72af}"), (0xfaa0, "\u{732a}"),
+    (0xfaa1, "\u{7471}"), (0xfaa2, "\u{7506}"), (0xfaa3, "\u{753b}"), (0xfaa4, "\u{761d}"),
+    (0xfaa5, "\u{761f}"), (0xfaa6, "\u{76ca}"), (0xfaa7, "\u{76db}"), (0xfaa8, "\u{76f4}"),
+    (0xfaa9, "\u{774a}"), (0xfaaa, "\u{7740}"), (0xfaab, "\u{78cc}"), (0xfaac, "\u{7ab1}"),
+    (0xfaad, "\u{7bc0}"), (0xfaae, "\u{7c7b}"), (0xfaaf, "\u{7d5b}"), (0xfab0, "\u{7df4}"),
+    (0xfab1, "\u{7f3e}"), (0xfab2, "\u{8005}"), (0xfab3, "\u{8352}"), (0xfab4, "\u{83ef}"),
+    (0xfab5, "\u{8779}"), (0xfab6, "\u{8941}"), (0xfab7, "\u{8986}"), (0xfab8, "\u{8996}"),
+    (0xfab9, "\u{8abf}"), (0xfaba, "\u{8af8}"), (0xfabb, "\u{8acb}"), (0xfabc, "\u{8b01}"),
+    (0xfabd, "\u{8afe}"), (0xfabe, "\u{8aed}"), (0xfabf, "\u{8b39}"), (0xfac0, "\u{8b8a}"),
+    (0xfac1, "\u{8d08}"), (0xfac2, "\u{8f38}"), (0xfac3, "\u{9072}"), (0xfac4, "\u{9199}"),
+    (0xfac5, "\u{9276}"), (0xfac6, "\u{967c}"), (0xfac7, "\u{96e3}"), (0xfac8, "\u{9756}"),
+    (0xfac9, "\u{97db}"), (0xfaca, "\u{97ff}"), (0xfacb, "\u{980b}"), (0xfacc, "\u{983b}"),
+    (0xfacd, "\u{9b12}"), (0xface, "\u{9f9c}"), (0xfacf, "\u{2284a}"), (0xfad0, "\u{22844}"),
+    (0xfad1, "\u{233d5}"), (0xfad2, "\u{3b9d}"), (0xfad3, "\u{4018}"), (0xfad4, "\u{4039}"),
+    (0xfad5, "\u{25249}"), (0xfad6, "\u{25cd0}"), (0xfad7, "\u{27ed3}"), (0xfad8, "\u{9f43}"),
+    (0xfad9, "\u{9f8e}"), (0xfb1d, "\u{5d9}\u{5b4}"), (0xfb1f, "\u{5f2}\u{5b7}"),
+    (0xfb2a, "\u{5e9}\u{5c1}"), (0xfb2b, "\u{5e9}\u{5c2}"), (0xfb2c, "\u{5e9}\u{5bc}\u{5c1}"),
+    (0xfb2d, "\u{5e9}\u{5bc}\u{5c2}"), (0xfb2e, "\u{5d0}\u{5b7}"), (0xfb2f, "\u{5d0}\u{5b8}"),
+    (0xfb30, "\u{5d0}\u{5bc}"), (0xfb31, "\u{5d1}\u{5bc}"), (0xfb32, "\u{5d2}\u{5bc}"),
+    (0xfb33, "\u{5d3}\u{5bc}"), (0xfb34, "\u{5d4}\u{5bc}"), (0xfb35, "\u{5d5}\u{5bc}"),
+    (0xfb36, "\u{5d6}\u{5bc}"), (0xfb38, "\u{5d8}\u{5bc}"), (0xfb39, "\u{5d9}\u{5bc}"),
+    (0xfb3a, "\u{5da}\u{5bc}"), (0xfb3b, "\u{5db}\u{5bc}"), (0xfb3c, "\u{5dc}\u{5bc}"),
+    (0xfb3e, "\u{5de}\u{5bc}"), (0xfb40, "\u{5e0}\u{5bc}"), (0xfb41, "\u{5e1}\u{5bc}"),
+    (0xfb43, "\u{5e3}\u{5bc}"), (0xfb44, "\u{5e4}\u{5bc}"), (0xfb46, "\u{5e6}\u{5bc}"),
+    (0xfb47, "\u{5e7}\u{5bc}"), (0xfb48, "\u{5e8}\u{5bc}"), (0xfb49, "\u{5e9}\u{5bc}"),
+    (0xfb4a, "\u{5ea}\u{5bc}"), (0xfb4b, "\u{5d5}\u{5b9}"), (0xfb4c, "\u{5d1}\u{5bf}"),
+    (0xfb4d, "\u{5db}\u{5bf}"), (0xfb4e, "\u{5e4}\u{5bf}"), (0x1109a, "\u{11099}\u{110ba}"),
+    (0x1109c, "\u{1109b}\u{110ba}"), (0x110ab, "\u{110a5}\u{110ba}"),
+    (0x1112e, "\u{11131}\u{11127}"), (0x1112f, "\u{11132}\u{11127}"),
+    (0x1134b, "\u{11347}\u{1133e}"), (0x1134c, "\u{11347}\u{11357}"),
+    (0x114bb, "\u{114b9}\u{114ba}"), (0x114bc, "\u{114b9}\u{114b0}"),
+    (0x114be, "\u{114b9}\u{114bd}"), (0x115ba, "\u{115b8}\u{115af}"),
+    (0x115bb, "\u{115b9}\u{115af}"), (0x11938, "\u{11935}\u{11930}"),
+    (0x1d15e, "\u{1d157}\u{1d165}"), (0x1d15f, "\u{1d158}\u{1d165}"),
+    (0x1d160, "\u{1d158}\u{1d165}\u{1d16e}"), (0x1d161, "\u{1d158}\u{1d165}\u{1d16f}"),
+    (0x1d162, "\u{1d158}\u{1d165}\u{1d170}"), (0x1d163, "\u{1d158}\u{1d165}\u{1d171}"),
+    (0x1d164, "\u{1d158}\u{1d165}\u{1d172}"), (0x1d1bb, "\u{1d1b9}\u{1d165}"),
+    (0x1d1bc, "\u{1d1ba}\u{1d165}"), (0x1d1bd, "\u{1d1b9}\u{1d165}\u{1d16e}"),
+    (0x1d1be, "\u{1d1ba}\u{1d165}\u{1d16e}"), (0x1d1bf, "\u{1d1b9}\u{1d165}\u{1d16f}"),
+    (0x1d1c0, "\u{1d1ba}\u{1d165}\u{1d16f}"), (0x2f800, "\u{4e3d}"), (0x2f801, "\u{4e38}"),
+    (0x2f802, "\u{4e41}"), (0x2f803, "\u{20122}"), (0x2f804, "\u{4f60}"), (0x2f805, "\u{4fae}"),
+    (0x2f806, "\u{4fbb}"), (0x2f807, "\u{5002}"), (0x2f808, "\u{507a}"), (0x2f809, "\u{5099}"),
+    (0x2f80a, "\u{50e7}"), (0x2f80b, "\u{50cf}"), (0x2f80c, "\u{349e}"), (0x2f80d, "\u{2063a}"),
+    (0x2f80e, "\u{514d}"), (0x2f80f, "\u{5154}"), (0x2f810, "\u{5164}"), (0x2f811, "\u{5177}"),
+    (0x2f812, "\u{2051c}"), (0x2f813, "\u{34b9}"), (0x2f814, "\u{5167}"), (0x2f815, "\u{518d}"),
+    (0x2f816, "\u{2054b}"), (0x2f817, "\u{5197}"), (0x2f818, "\u{51a4}"), (0x2f819, "\u{4ecc}"),
+    (0x2f81a, "\u{51ac}"), (0x2f81b, "\u{51b5}"), (0x2f81c, "\u{291df}"), (0x2f81d, "\u{51f5}"),
+    (0x2f81e, "\u{5203}"), (0x2f81f, "\u{34df}"), (0x2f820, "\u{523b}"), (0x2f821, "\u{5246}"),
+    (0x2f822, "\u{5272}"), (0x2f823, "\u{5277}"), (0x2f824, "\u{3515}"), (0x2f825, "\u{52c7}"),
+    (0x2f826, "\u{52c9}"), (0x2f827, "\u{52e4}"), (0x2f828, "\u{52fa}"), (0x2f829, "\u{5305}"),
+    (0x2f82a, "\u{5306}"), (0x2f82b, "\u{5317}"), (0x2f82c, "\u{5349}"), (0x2f82d, "\u{5351}"),
+    (0x2f82e, "\u{535a}"), (0x2f82f, "\u{5373}"), (0x2f830, "\u{537d}"), (0x2f831, "\u{537f}"),
+    (0x2f832, "\u{537f}"), (0x2f833, "\u{537f}"), (0x2f834, "\u{20a2c}"), (0x2f835, "\u{7070}"),
+    (0x2f836, "\u{53ca}"), (0x2f837, "\u{53df}"), (0x2f838, "\u{20b63}"), (0x2f839, "\u{53eb}"),
+    (0x2f83a, "\u{53f1}"), (0x2f83b, "\u{5406}"), (0x2f83c, "\u{549e}"), (0x2f83d, "\u{5438}"),
+    (0x2f83e, "\u{5448}"), (0x2f83f, "\u{5468}"), (0x2f840, "\u{54a2}"), (0x2f841, "\u{54f6}"),
+    (0x2f842, "\u{5510}"), (0x2f843, "\u{5553}"), (0x2f844, "\u{5563}"), (0x2f845, "\u{5584}"),
+    (0x2f846, "\u{5584}"), (0x2f847, "\u{5599}"), (0x2f848, "\u{55ab}"), (0x2f849, "\u{55b3}"),
+    (0x2f84a, "\u{55c2}"), (0x2f84b, "\u{5716}"), (0x2f84c, "\u{5606}"), (0x2f84d, "\u{5717}"),
+    (0x2f84e, "\u{5651}"), (0x2f84f, "\u{5674}"), (0x2f850, "\u{5207}"), (0x2f851, "\u{58ee}"),
+    (0x2f852, "\u{57ce}"), (0x2f853, "\u{57f4}"), (0x2f854, "\u{580d}"), (0x2f855, "\u{578b}"),
+    (0x2f856, "\u{5832}"), (0x2f857, "\u{5831}"), (0x2f858, "\u{58ac}"), (0x2f859, "\u{214e4}"),
+    (0x2f85a, "\u{58f2}"), (0x2f85b, "\u{58f7}"), (0x2f85c, "\u{5906}"), (0x2f85d, "\u{591a}"),
+    (0x2f85e, "\u{5922}"), (0x2f85f, "\u{5962}"), (0x2f860, "\u{216a8}"), (0x2f861, "\u{216ea}"),
+    (0x2f862, "\u{59ec}"), (0x2f863, "\u{5a1b}"), (0x2f864, "\u{5a27}"), (0x2f865, "\u{59d8}"),
+    (0x2f866, "\u{5a66}"), (0x2f867, "\u{36ee}"), (0x2f868, "\u{36fc}"), (0x2f869, "\u{5b08}"),
+    (0x2f86a, "\u{5b3e}"), (0x2f86b, "\u{5b3e}"), (0x2f86c, "\u{219c8}"), (0x2f86d, "\u{5bc3}"),
+    (0x2f86e, "\u{5bd8}"), (0x2f86f, "\u{5be7}"), (0x2f870, "\u{5bf3}"), (0x2f871, "\u{21b18}"),
+    (0x2f872, "\u{5bff}"), (0x2f873, "\u{5c06}"), (0x2f874, "\u{5f53}"), (0x2f875, "\u{5c22}"),
+    (0x2f876, "\u{3781}"), (0x2f877, "\u{5c60}"), (0x2f878, "\u{5c6e}"), (0x2f879, "\u{5cc0}"),
+    (0x2f87a, "\u{5c8d}"), (0x2f87b, "\u{21de4}"), (0x2f87c, "\u{5d43}"), (0x2f87d, "\u{21de6}"),
+    (0x2f87e, "\u{5d6e}"), (0x2f87f, "\u{5d6b}"), (0x2f880, "\u{5d7c}"), (0x2f881, "\u{5de1}"),
+    (0x2f882, "\u{5de2}"), (0x2f883, "\u{382f}"), (0x2f884, "\u{5dfd}"), (0x2f885, "\u{5e28}"),
+    (0x2f886, "\u{5e3d}"), (0x2f887, "\u{5e69}"), (0x2f888, "\u{3862}"), (0x2f889, "\u{22183}"),
+    (0x2f88a, "\u{387c}"), (0x2f88b, "\u{5eb0}"), (0x2f88c, "\u{5eb3}"), (0x2f88d, "\u{5eb6}"),
+    (0x2f88e, "\u{5eca}"), (0x2f88f, "\u{2a392}"), (0x2f890, "\u{5efe}"), (0x2f891, "\u{22331}"),
+    (0x2f892, "\u{22331}"), (0x2f893, "\u{8201}"), (0x2f894, "\u{5f22}"), (0x2f895, "\u{5f22}"),
+    (0x2f896, "\u{38c7}"), (0x2f897, "\u{232b8}"), (0x2f898, "\u{261da}"), (0x2f899, "\u{5f62}"),
+    (0x2f89a, "\u{5f6b}"), (0x2f89b, "\u{38e3}"), (0x2f89c, "\u{5f9a}"), (0x2f89d, "\u{5fcd}"),
+    (0x2f89e, "\u{5fd7}"), (0x2f89f, "\u{5ff9}"), (0x2f8a0, "\u{6081}"), (0x2f8a1, "\u{393a}"),
+    (0x2f8a2, "\u{391c}"), (0x2f8a3, "\u{6094}"), (0x2f8a4, "\u{226d4}"), (0x2f8a5, "\u{60c7}"),
+    (0x2f8a6, "\u{6148}"), (0x2f8a7, "\u{614c}"), (0x2f8a8, "\u{614e}"), (0x2f8a9, "\u{614c}"),
+    (0x2f8aa, "\u{617a}"), (0x2f8ab, "\u{618e}"), (0x2f8ac, "\u{61b2}"), (0x2f8ad, "\u{61a4}"),
+    (0x2f8ae, "\u{61af}"), (0x2f8af, "\u{61de}"), (0x2f8b0, "\u{61f2}"), (0x2f8b1, "\u{61f6}"),
+    (0x2f8b2, "\u{6210}"), (0x2f8b3, "\u{621b}"), (0x2f8b4, "\u{625d}"), (0x2f8b5, "\u{62b1}"),
+    (0x2f8b6, "\u{62d4}"), (0x2f8b7, "\u{6350}"), (0x2f8b8, "\u{22b0c}"), (0x2f8b9, "\u{633d}"),
+    (0x2f8ba, "\u{62fc}"), (0x2f8bb, "\u{6368}"), (0x2f8bc, "\u{6383}"), (0x2f8bd, "\u{63e4}"),
+    (0x2f8be, "\u{22bf1}"), (0x2f8bf, "\u{6422}"), (0x2f8c0, "\u{63c5}"), (0x2f8c1, "\u{63a9}"),
+    (0x2f8c2, "\u{3a2e}"), (0x2f8c3, "\u{6469}"), (0x2f8c4, "\u{647e}"), (0x2f8c5, "\u{649d}"),
+    (0x2f8c6, "\u{6477}"), (0x2f8c7, "\u{3a6c}"), (0x2f8c8, "\u{654f}"), (0x2f8c9, "\u{656c}"),
+    (0x2f8ca, "\u{2300a}"), (0x2f8cb, "\u{65e3}"), (0x2f8cc, "\u{66f8}"), (0x2f8cd, "\u{6649}"),
+    (0x2f8ce, "\u{3b19}"), (0x2f8cf, "\u{6691}"), (0x2f8d0, "\u{3b08}"), (0x2f8d1, "\u{3ae4}"),
+    (0x2f8d2, "\u{5192}"), (0x2f8d3, "\u{5195}"), (0x2f8d4, "\u{6700}"), (0x2f8d5, "\u{669c}"),
+    (0x2f8d6, "\u{80ad}"), (0x2f8d7, "\u{43d9}"), (0x2f8d8, "\u{6717}"), (0x2f8d9, "\u{671b}"),
+    (0x2f8da, "\u{6721}"), (0x2f8db, "\u{675e}"), (0x2f8dc, "\u{6753}"), (0x2f8dd, "\u{233c3}"),
+    (0x2f8de, "\u{3b49}"), (0x2f8df, "\u{67fa}"), (0x2f8e0, "\u{6785}"), (0x2f8e1, "\u{6852}"),
+    (0x2f8e2, "\u{6885}"), (0x2f8e3, "\u{2346d}"), (0x2f8e4, "\u{688e}"), (0x2f8e5, "\u{681f}"),
+    (0x2f8e6, "\u{6914}"), (0x2f8e7, "\u{3b9d}"), (0x2f8e8, "\u{6942}"), (0x2f8e9, "\u{69a3}"),
+    (0x2f8ea, "\u{69ea}"), (0x2f8eb, "\u{6aa8}"), (0x2f8ec, "\u{236a3}"), (0x2f8ed, "\u{6adb}"),
+    (0x2f8ee, "\u{3c18}"), (0x2f8ef, "\u{6b21}"), (0x2f8f0, "\u{238a7}"), (0x2f8f1, "\u{6b54}"),
+    (0x2f8f2, "\u{3c4e}"), (0x2f8f3, "\u{6b72}"), (0x2f8f4, "\u{6b9f}"), (0x2f8f5, "\u{6bba}"),
+    (0x2f8f6, "\u{6bbb}"), (0x2f8f7, "\u{23a8d}"), (0x2f8f8, "\u{21d0b}"), (0x2f8f9, "\u{23afa}"),
+    (0x2f8fa, "\u{6c4e}"), (0x2f8fb, "\u{23cbc}"), (0x2f8fc, "\u{6cbf}"), (0x2f8fd, "\u{6ccd}"),
+    (0x2f8fe, "\u{6c67}"), (0x2f8ff, "\u{6d16}"), (0x2f900, "\u{6d3e}"), (0x2f901, "\u{6d77}"),
+    (0x2f902, "\u{6d41}"), (0x2f903, "\u{6d69}"), (0x2f904, "\u{6d78}"), (0x2f905, "\u{6d85}"),
+    (0x2f906, "\u{23d1e}"), (0x2f907, "\u{6d34}"), (0x2f908, "\u{6e2f}"), (0x2f909, "\u{6e6e}"),
+    (0x2f90a, "\u{3d33}"), (0x2f90b, "\u{6ecb}"), (0x2f90c, "\u{6ec7}"), (0x2f90d, "\u{23ed1}"),
+    (0x2f90e, "\u{6df9}"), (0x2f90f, "\u{6f6e}"), (0x2f910, "\u{23f5e}"), (0x2f911, "\u{23f8e}"),
+    (0x2f912, "\u{6fc6}"), (0x2f913, "\u{7039}"), (0x2f914, "\u{701e}"), (0x2f915, "\u{701b}"),
+    (0x2f916, "\u{3d96}"), (0x2f917, "\u{704a}"), (0x2f918, "\u{707d}"), (0x2f919, "\u{7077}"),
+    (0x2f91a, "\u{70ad}"), (0x2f91b, "\u{20525}"), (0x2f91c, "\u{7145}"), (0x2f91d, "\u{24263}"),
+    (0x2f91e, "\u{719c}"), (0x2f91f, "\u{243ab}"), (0x2f920, "\u{7228}"), (0x2f921, "\u{7235}"),
+    (0x2f922, "\u{7250}"), (0x2f923, "\u{24608}"), (0x2f924, "\u{7280}"), (0x2f925, "\u{7295}"),
+    (0x2f926, "\u{24735}"), (0x2f927, "\u{24814}"), (0x2f928, "\u{737a}"), (0x2f929, "\u{738b}"),
+    (0x2f92a, "\u{3eac}"), (0x2f92b, "\u{73a5}"), (0x2f92c, "\u{3eb8}"), (0x2f92d, "\u{3eb8}"),
+    (0x2f92e, "\u{7447}"), (0x2f92f, "\u{745c}"), (0x2f930, "\u{7471}"), (0x2f931, "\u{7485}"),
+    (0x2f932, "\u{74ca}"), (0x2f933, "\u{3f1b}"), (0x2f934, "\u{7524}"), (0x2f935, "\u{24c36}"),
+    (0x2f936, "\u{753e}"), (0x2f937, "\u{24c92}"), (0x2f938, "\u{7570}"), (0x2f939, "\u{2219f}"),
+    (0x2f93a, "\u{7610}"), (0x2f93b, "\u{24fa1}"), (0x2f93c, "\u{24fb8}"), (0x2f93d, "\u{25044}"),
+    (0x2f93e, "\u{3ffc}"), (0x2f93f, "\u{4008}"), (0x2f940, "\u{76f4}"), (0x2f941, "\u{250f3}"),
+    (0x2f942, "\u{250f2}"), (0x2f943, "\u{25119}"), (0x2f944, "\u{25133}"), (0x2f945, "\u{771e}"),
+    (0x2f946, "\u{771f}"), (0x2f947, "\u{771f}"), (0x2f948, "\u{774a}"), (0x2f949, "\u{4039}"),
+    (0x2f94a, "\u{778b}"), (0x2f94b, "\u{4046}"), (0x2f94c, "\u{4096}"), (0x2f94d, "\u{2541d}"),
+    (0x2f94e, "\u{784e}"), (0x2f94f, "\u{788c}"), (0x2f950, "\u{78cc}"), (0x2f951, "\u{40e3}"),
+    (0x2f952, "\u{25626}"), (0x2f953, "\u{7956}"), (0x2f954, "\u{2569a}"), (0x2f955, "\u{256c5}"),
+    (0x2f956, "\u{798f}"), (0x2f957, "\u{79eb}"), (0x2f958, "\u{412f}"), (0x2f959, "\u{7a40}"),
+    (0x2f95a, "\u{7a4a}"), (0x2f95b, "\u{7a4f}"), (0x2f95c, "\u{2597c}"), (0x2f95d, "\u{25aa7}"),
+    (0x2f95e, "\u{25aa7}"), (0x2f95f, "\u{7aee}"), (0x2f960, "\u{4202}"), (0x2f961, "\u{25bab}"),
+    (0x2f962, "\u{7bc6}"), (0x2f963, "\u{7bc9}"), (0x2f964, "\u{4227}"), (0x2f965, "\u{25c80}"),
+    (0x2f966, "\u{7cd2}"), (0x2f967, "\u{42a0}"), (0x2f968, "\u{7ce8}"), (0x2f969, "\u{7ce3}"),
+    (0x2f96a, "\u{7d00}"), (0x2f96b, "\u{25f86}"), (0x2f96c, "\u{7d63}"), (0x2f96d, "\u{4301}"),
+    (0x2f96e, "\u{7dc7}"), (0x2f96f, "\u{7e02}"), (0x2f970, "\u{7e45}"), (0x2f971, "\u{4334}"),
+    (0x2f972, "\u{26228}"), (0x2f973, "\u{26247}"), (0x2f974, "\u{4359}"), (0x2f975, "\u{262d9}"),
+    (0x2f976, "\u{7f7a}"), (0x2f977, "\u{2633e}"), (0x2f978, "\u{7f95}"), (0x2f979, "\u{7ffa}"),
+    (0x2f97a, "\u{8005}"), (0x2f97b, "\u{264da}"), (0x2f97c, "\u{26523}"), (0x2f97d, "\u{8060}"),
+    (0x2f97e, "\u{265a8}"), (0x2f97f, "\u{8070}"), (0x2f980, "\u{2335f}"), (0x2f981, "\u{43d5}"),
+    (0x2f982, "\u{80b2}"), (0x2f983, "\u{8103}"), (0x2f984, "\u{440b}"), (0x2f985, "\u{813e}"),
+    (0x2f986, "\u{5ab5}"), (0x2f987, "\u{267a7}"), (0x2f988, "\u{267b5}"), (0x2f989, "\u{23393}"),
+    (0x2f98a, "\u{2339c}"), (0x2f98b, "\u{8201}"), (0x2f98c, "\u{8204}"), (0x2f98d, "\u{8f9e}"),
+    (0x2f98e, "\u{446b}"), (0x2f98f, "\u{8291}"), (0x2f990, "\u{828b}"), (0x2f991, "\u{829d}"),
+    (0x2f992, "\u{52b3}"), (0x2f993, "\u{82b1}"), (0x2f994, "\u{82b3}"), (0x2f995, "\u{82bd}"),
+    (0x2f996, "\u{82e6}"), (0x2f997, "\u{26b3c}"), (0x2f998, "\u{82e5}"), (0x2f999, "\u{831d}"),
+    (0x2f99a, "\u{8363}"), (0x2f99b, "\u{83ad}"), (0x2f99c, "\u{8323}"), (0x2f99d, "\u{83bd}"),
+    (0x2f99e, "\u{83e7}"), (0x2f99f, "\u{8457}"), (0x2f9a0, "\u{8353}"), (0x2f9a1, "\u{83ca}"),
+    (0x2f9a2, "\u{83cc}"), (0x2f9a3, "\u{83dc}"), (0x2f9a4, "\u{26c36}"), (0x2f9a5, "\u{26d6b}"),
+    (0x2f9a6, "\u{26cd5}"), (0x2f9a7, "\u{452b}"), (0x2f9a8, "\u{84f1}"), (0x2f9a9, "\u{84f3}"),
+    (0x2f9aa, "\u{8516}"), (0x2f9ab, "\u{273ca}"), (0x2f9ac, "\u{8564}"), (0x2f9ad, "\u{26f2c}"),
+    (0x2f9ae, "\u{455d}"), (0x2f9af, "\u{4561}"), (0x2f9b0, "\u{26fb1}"), (0x2f9b1, "\u{270d2}"),
+    (0x2f9b2, "\u{456b}"), (0x2f9b3, "\u{8650}"), (0x2f9b4, "\u{865c}"), (0x2f9b5, "\u{8667}"),
+    (0x2f9b6, "\u{8669}"), (0x2f9b7, "\u{86a9}"), (0x2f9b8, "\u{8688}"), (0x2f9b9, "\u{870e}"),
+    (0x2f9ba, "\u{86e2}"), (0x2f9bb, "\u{8779}"), (0x2f9bc, "\u{8728}"), (0x2f9bd, "\u{876b}"),
+    (0x2f9be, "\u{8786}"), (0x2f9bf, "\u{45d7}"), (0x2f9c0, "\u{87e1}"), (0x2f9c1, "\u{8801}"),
+    (0x2f9c2, "\u{45f9}"), (0x2f9c3, "\u{8860}"), (0x2f9c4, "\u{8863}"), (0x2f9c5, "\u{27667}"),
+    (0x2f9c6, "\u{88d7}"), (0x2f9c7, "\u{88de}"), (0x2f9c8, "\u{4635}"), (0x2f9c9, "\u{88fa}"),
+    (0x2f9ca, "\u{34bb}"), (0x2f9cb, "\u{278ae}"), (0x2f9cc, "\u{27966}"), (0x2f9cd, "\u{46be}"),
+    (0x2f9ce, "\u{46c7}"), (0x2f9cf, "\u{8aa0}"), (0x2f9d0, "\u{8aed}"), (0x2f9d1, "\u{8b8a}"),
+    (0x2f9d2, "\u{8c55}"), (0x2f9d3, "\u{27ca8}"), (0x2f9d4, "\u{8cab}"), (0x2f9d5, "\u{8cc1}"),
+    (0x2f9d6, "\u{8d1b}"), (0x2f9d7, "\u{8d77}"), (0x2f9d8, "\u{27f2f}"), (0x2f9d9, "\u{20804}"),
+    (0x2f9da, "\u{8dcb}"), (0x2f9db, "\u{8dbc}"), (0x2f9dc, "\u{8df0}"), (0x2f9dd, "\u{208de}"),
+    (0x2f9de, "\u{8ed4}"), (0x2f9df, "\u{8f38}"), (0x2f9e0, "\u{285d2}"), (0x2f9e1, "\u{285ed}"),
+    (0x2f9e2, "\u{9094}"), (0x2f9e3, "\u{90f1}"), (0x2f9e4, "\u{9111}"), (0x2f9e5, "\u{2872e}"),
+    (0x2f9e6, "\u{911b}"), (0x2f9e7, "\u{9238}"), (0x2f9e8, "\u{92d7}"), (0x2f9e9, "\u{92d8}"),
+    (0x2f9ea, "\u{927c}"), (0x2f9eb, "\u{93f9}"), (0x2f9ec, "\u{9415}"), (0x2f9ed, "\u{28bfa}"),
+    (0x2f9ee, "\u{958b}"), (0x2f9ef, "\u{4995}"), (0x2f9f0, "\u{95b7}"), (0x2f9f1, "\u{28d77}"),
+    (0x2f9f2, "\u{49e6}"), (0x2f9f3, "\u{96c3}"), (0x2f9f4, "\u{5db2}"), (0x2f9f5, "\u{9723}"),
+    (0x2f9f6, "\u{29145}"), (0x2f9f7, "\u{2921a}"), (0x2f9f8, "\u{4a6e}"), (0x2f9f9, "\u{4a76}"),
+    (0x2f9fa, "\u{97e0}"), (0x2f9fb, "\u{2940a}"), (0x2f9fc, "\u{4ab2}"), (0x2f9fd, "\u{29496}"),
+    (0x2f9fe, "\u{980b}"), (0x2f9ff, "\u{980b}"), (0x2fa00, "\u{9829}"), (0x2fa01, "\u{295b6}"),
+    (0x2fa02, "\u{98e2}"), (0x2fa03, "\u{4b33}"), (0x2fa04, "\u{9929}"), (0x2fa05, "\u{99a7}"),
+    (0x2fa06, "\u{99c2}"), (0x2fa07, "\u{99fe}"), (0x2fa08, "\u{4bce}"), (0x2fa09, "\u{29b30}"),
+    (0x2fa0a, "\u{9b12}"), (0x2fa0b, "\u{9c40}"), (0x2fa0c, "\u{9cfd}"), (0x2fa0d, "\u{4cce}"),
+    (0x2fa0e, "\u{4ced}"), (0x2fa0f, "\u{9d67}"), (0x2fa10, "\u{2a0ce}"), (0x2fa11, "\u{4cf8}"),
+    (0x2fa12, "\u{2a105}"), (0x2fa13, "\u{2a20e}"), (0x2fa14, "\u{2a291}"), (0x2fa15, "\u{9ebb}"),
+    (0x2fa16, "\u{4d56}"), (0x2fa17, "\u{9ef9}"), (0x2fa18, "\u{9efe}"), (0x2fa19, "\u{9f05}"),
+    (0x2fa1a, "\u{9f0f}"), (0x2fa1b, "\u{9f16}"), (0x2fa1c, "\u{9f3b}"), (0x2fa1d, "\u{2a600}"),
+];
+
+/// The pairs that compose to a primary composite, Hangul left out.
+pub static COMPOSITIONS: &'static [(u32, u32, u32)] = &[
+    (0x3c, 0x338, 0x226e), (0x3d, 0x338, 0x2260), (0x3e, 0x338, 0x226f), (0x41, 0x300, 0xc0),
+    (0x41, 0x301, 0xc1), (0x41, 0x302, 0xc2), (0x41, 0x303, 0xc3), (0x41, 0x304, 0x100),
+    (0x41, 0x306, 0x102), (0x41, 0x307, 0x226), (0x41, 0x308, 0xc4), (0x41, 0x309, 0x1ea2),
+    (0x41, 0x30a, 0xc5), (0x41, 0x30c, 0x1cd), (0x41, 0x30f, 0x200), (0x41, 0x311, 0x202),
+    (0x41, 0x323, 0x1ea0), (0x41, 0x325, 0x1e00), (0x41, 0x328, 0x104), (0x42, 0x307, 0x1e02),
+    (0x42, 0x323, 0x1e04), (0x42, 0x331, 0x1e06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108),
+    (0x43, 0x307, 0x10a), (0x43, 0x30c, 0x10c), (0x43, 0x327, 0xc7), (0x44, 0x307, 0x1e0a),
+    (0x44, 0x30c, 0x10e), (0x44, 0x323, 0x1e0c), (0x44, 0x327, 0x1e10), (0x44, 0x32d, 0x1e12),
+    (0x44, 0x331, 0x1e0e), (0x45, 0x300, 0xc8), (0x45, 0x301, 0xc9), (0x45, 0x302, 0xca),
+    (0x45, 0x303, 0x1ebc), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114), (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xcb), (0x45, 0x309, 0x1eba), (0x45, 0x30c, 0x11a), (0x45, 0x30f, 0x204),
+    (0x45, 0x311, 0x206), (0x45, 0x323, 0x1eb8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118),
+    (0x45, 0x32d, 0x1e18), (0x45, 0x330, 0x1e1a), (0x46, 0x307, 0x1e1e), (0x47, 0x301, 0x1f4),
+    (0x47, 0x302, 0x11c), (0x47, 0x304, 0x1e20), (0x47, 0x306, 0x11e), (0x47, 0x307, 0x120),
+    (0x47, 0x30c, 0x1e6), (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1e22),
+    (0x48, 0x308, 0x1e26), (0x48, 0x30c, 0x21e), (0x48, 0x323, 0x1e24), (0x48, 0x327, 0x1e28),
+    (0x48, 0x32e, 0x1e2a), (0x49, 0x300, 0xcc), (0x49, 0x301, 0xcd), (0x49, 0x302, 0xce),
+    (0x49, 0x303, 0x128), (0x49, 0x304, 0x12a), (0x49, 0x306, 0x12c), (0x49, 0x307, 0x130),
+    (0x49, 0x308, 0xcf), (0x49, 0x309, 0x1ec8), (0x49, 0x30c, 0x1cf), (0x49, 0x30f, 0x208),
+    (0x49, 0x311, 0x20a), (0x49, 0x323, 0x1eca), (0x49, 0x328, 0x12e), (0x49, 0x330, 0x1e2c),
+    (0x4a, 0x302, 0x134), (0x4b, 0x301, 0x1e30), (0x4b, 0x30c, 0x1e8), (0x4b, 0x323, 0x1e32),
+    (0x4b, 0x327, 0x136), (0x4b, 0x331, 0x1e34), (0x4c, 0x301, 0x139), (0x4c, 0x30c, 0x13d),
+    (0x4c, 0x323, 0x1e36), (0x4c, 0x327, 0x13b), (0x4c, 0x32d, 0x1e3c), (0x4c, 0x331, 0x1e3a),
+    (0x4d, 0x301, 0x1e3e), (0x4d, 0x307, 0x1e40), (0x4d, 0x323, 0x1e42), (0x4e, 0x300, 0x1f8),
+    (0x4e, 0x301, 0x143), (0x4e, 0x303, 0xd1), (0x4e, 0x307, 0x1e44), (0x4e, 0x30c, 0x147),
+    (0x4e, 0x323, 0x1e46), (0x4e, 0x327, 0x145), (0x4e, 0x32d, 0x1e4a), (0x4e, 0x331, 0x1e48),
+    (0x4f, 0x300, 0xd2), (0x4f, 0x301, 0xd3), (0x4f, 0x302, 0xd4), (0x4f, 0x303, 0xd5),
+    (0x4f, 0x304, 0x14c), (0x4f, 0x306, 0x14e), (0x4f, 0x307, 0x22e), (0x4f, 0x308, 0xd6),
+    (0x4f, 0x309, 0x1ece), (0x4f, 0x30b, 0x150), (0x4f, 0x30c, 0x1d1), (0x4f, 0x30f, 0x20c),
+    (0x4f, 0x311, 0x20e), (0x4f, 0x31b, 0x1a0), (0x4f, 0x323, 0x1ecc), (0x4f, 0x328, 0x1ea),
+    (0x50, 0x301, 0x1e54), (0x50, 0x307, 0x1e56), (0x52, 0x301, 0x154), (0x52, 0x307, 0x1e58),
+    (0x52, 0x30c, 0x158), (0x52, 0x30f, 0x210), (0x52, 0x311, 0x212), (0x52, 0x323, 0x1e5a),
+    (0x52, 0x327, 0x156), (0x52, 0x331, 0x1e5e), (0x53, 0x301, 0x15a), (0x53, 0x302, 0x15c),
+    (0x53, 0x307, 0x1e60), (0x53, 0x30c, 0x160), (0x53, 0x323, 0x1e62), (0x53, 0x326, 0x218),
+    (0x53, 0x327, 0x15e), (0x54, 0x307, 0x1e6a), (0x54, 0x30c, 0x164), (0x54, 0x323, 0x1e6c),
+    (0x54, 0x326, 0x21a), (0x54, 0x327, 0x162), (0x54, 0x32d, 0x1e70), (0x54, 0x331, 0x1e6e),
+    (0x55, 0x300, 0xd9), (0x55, 0x301, 0xda), (0x55, 0x302, 0xdb), (0x55, 0x303, 0x168),
+    (0x55, 0x304, 0x16a), (0x55, 0x306, 0x16c), (0x55, 0x308, 0xdc), (0x55, 0x309, 0x1ee6),
+    (0x55, 0x30a, 0x16e), (0x55, 0x30b, 0x170), (0x55, 0x30c, 0x1d3), (0x55, 0x30f, 0x214),
+    (0x55, 0x311, 0x216), (0x55, 0x31b, 0x1af), (0x55, 0x323, 0x1ee4), (0x55, 0x324, 0x1e72),
+    (0x55, 0x328, 0x172), (0x55, 0x32d, 0x1e76), (0x55, 0x330, 0x1e74), (0x56, 0x303, 0x1e7c),
+    (0x56, 0x323, 0x1e7e), (0x57, 0x300, 0x1e80), (0x57, 0x301, 0x1e82), (0x57, 0x302, 0x174),
+    (0x57, 0x307, 0x1e86), (0x57, 0x308, 0x1e84), (0x57, 0x323, 0x1e88), (0x58, 0x307, 0x1e8a),
+    (0x58, 0x308, 0x1e8c), (0x59, 0x300, 0x1ef2), (0x59, 0x301, 0xdd), (0x59, 0x302, 0x176),
+    (0x59, 0x303, 0x1ef8), (0x59, 0x304, 0x232), (0x59, 0x307, 0x1e8e), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1ef6), (0x59, 0x323, 0x1ef4), (0x5a, 0x301, 0x179), (0x5a, 0x302, 0x1e90),
+    (0x5a, 0x307, 0x17b), (0x5a, 0x30c, 0x17d), (0x5a, 0x323, 0x1e92), (0x5a, 0x331, 0x1e94),
+    (0x61, 0x300, 0xe0), (0x61, 0x301, 0xe1), (0x61, 0x302, 0xe2), (0x61, 0x303, 0xe3),
+    (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227), (0x61, 0x308, 0xe4),
+    (0x61, 0x309, 0x1ea3), (0x61, 0x30a, 0xe5), (0x61, 0x30c, 0x1ce), (0x61, 0x30f, 0x201),
+    (0x61, 0x311, 0x203), (0x61, 0x323, 0x1ea1), (0x61, 0x325, 0x1e01), (0x61, 0x328, 0x105),
+    (0x62, 0x307, 0x1e03), (0x62, 0x323, 0x1e05), (0x62, 0x331, 0x1e07), (0x63, 0x301, 0x107),
+    (0x63, 0x302, 0x109), (0x63, 0x307, 0x10b), (0x63, 0x30c, 0x10d), (0x63, 0x327, 0xe7),
+    (0x64, 0x307, 0x1e0b), (0x64, 0x30c, 0x10f), (0x64, 0x323, 0x1e0d), (0x64, 0x327, 0x1e11),
+    (0x64, 0x32d, 0x1e13), (0x64, 0x331, 0x1e0f), (0x65, 0x300, 0xe8), (0x65, 0x301, 0xe9),
+    (0x65, 0x302, 0xea), (0x65, 0x303, 0x1ebd), (0x65, 0x304, 0x113), (0x65, 0x306, 0x115),
+    (0x65, 0x307, 0x117), (0x65, 0x308, 0xeb), (0x65, 0x309, 0x1ebb), (0x65, 0x30c, 0x11b),
+    (0x65, 0x30f, 0x205), (0x65, 0x311, 0x207), (0x65, 0x323, 0x1eb9), (0x65, 0x327, 0x229),
+    (0x65, 0x328, 0x119), (0x65, 0x32d, 0x1e19), (0x65, 0x330, 0x1e1b), (0x66, 0x307, 0x1e1f),
+    (0x67, 0x301, 0x1f5), (0x67, 0x302, 0x11d), (0x67, 0x304, 0x1e21), (0x67, 0x306, 0x11f),
+    (0x67, 0x307, 0x121), (0x67, 0x30c, 0x1e7), (0x67, 0x327, 0x123), (0x68, 0x302, 0x125),
+    (0x68, 0x307, 0x1e23), (0x68, 0x308, 0x1e27), (0x68, 0x30c, 0x21f), (0x68, 0x323, 0x1e25),
+    (0x68, 0x327, 0x1e29), (0x68, 0x32e, 0x1e2b), (0x68, 0x331, 0x1e96), (0x69, 0x300, 0xec),
+    (0x69, 0x301, 0xed), (0x69, 0x302, 0xee), (0x69, 0x303, 0x129), (0x69, 0x304, 0x12b),
+    (0x69, 0x306, 0x12d), (0x69, 0x308, 0xef), (0x69, 0x309, 0x1ec9), (0x69, 0x30c, 0x1d0),
+    (0x69, 0x30f, 0x209), (0x69, 0x311, 0x20b), (0x69, 0x323, 0x1ecb), (0x69, 0x328, 0x12f),
+    (0x69, 0x330, 0x1e2d), (0x6a, 0x302, 0x135), (0x6a, 0x30c, 0x1f0), (0x6b, 0x301, 0x1e31),
+    (0x6b, 0x30c, 0x1e9), (0x6b, 0x323, 0x1e33), (0x6b, 0x327, 0x137), (0x6b, 0x331, 0x1e35),
+    (0x6c, 0x301, 0x13a), (0x6c, 0x30c, 0x13e), (0x6c, 0x323, 0x1e37), (0x6c, 0x327, 0x13c),
+    (0x6c, 0x32d, 0x1e3d), (0x6c, 0x331, 0x1e3b), (0x6d, 0x301, 0x1e3f), (0x6d, 0x307, 0x1e41),
+    (0x6d, 0x323, 0x1e43), (0x6e, 0x300, 0x1f9), (0x6e, 0x301, 0x144), (0x6e, 0x303, 0xf1),
+    (0x6e, 0x307, 0x1e45), (0x6e, 0x30c, 0x148), (0x6e, 0x323, 0x1e47), (0x6e, 0x327, 0x146),
+    (0x6e, 0x32d, 0x1e4b), (0x6e, 0x331, 0x1e49), (0x6f, 0x300, 0xf2), (0x6f, 0x301, 0xf3),
+    (0x6f, 0x302, 0xf4), (0x6f, 0x303, 0xf5), (0x6f, 0x304, 0x14d), (0x6f, 0x306, 0x14f),
+    (0x6f, 0x307, 0x22f), (0x6f, 0x308, 0xf6), (0x6f, 0x309, 0x1ecf), (0x6f, 0x30b, 0x151),
+    (0x6f, 0x30c, 0x1d2), (0x6f, 0x30f, 0x20d), (0x6f, 0x311, 0x20f), (0x6f, 0x31b, 0x1a1),
+    (0x6f, 0x323, 0x1ecd), (0x6f, 0x328, 0x1eb), (0x70, 0x301, 0x1e55), (0x70, 0x307, 0x1e57),
+    (0x72, 0x301, 0x155), (0x72, 0x307, 0x1e59), (0x72, 0x30c, 0x159), (0x72, 0x30f, 0x211),
+    (0x72, 0x311, 0x213), (0x72, 0x323, 0x1e5b), (0x72, 0x327, 0x157), (0x72, 0x331, 0x1e5f),
+    (0x73, 0x301, 0x15b), (0x73, 0x302, 0x15d), (0x73, 0x307, 0x1e61), (0x73, 0x30c, 0x161),
+    (0x73, 0x323, 0x1e63), (0x73, 0x326, 0x219), (0x73, 0x327, 0x15f), (0x74, 0x307, 0x1e6b),
+    (0x74, 0x308, 0x1e97), (0x74, 0x30c, 0x165), (0x74, 0x323, 0x1e6d), (0x74, 0x326, 0x21b),
+    (0x74, 0x327, 0x163), (0x74, 0x32d, 0x1e71), (0x74, 0x331, 0x1e6f), (0x75, 0x300, 0xf9),
+    (0x75, 0x301, 0xfa), (0x75, 0x302, 0xfb), (0x75, 0x303, 0x169), (0x75, 0x304, 0x16b),
+    (0x75, 0x306, 0x16d), (0x75, 0x308, 0xfc), (0x75, 0x309, 0x1ee7), (0x75, 0x30a, 0x16f),
+    (0x75, 0x30b, 0x171), (0x75, 0x30c, 0x1d4), (0x75, 0x30f, 0x215), (0x75, 0x311, 0x217),
+    (0x75, 0x31b, 0x1b0), (0x75, 0x323, 0x1ee5), (0x75, 0x324, 0x1e73), (0x75, 0x328, 0x173),
+    (0x75, 0x32d, 0x1e77), (0x75, 0x330, 0x1e75), (0x76, 0x303, 0x1e7d), (0x76, 0x323, 0x1e7f),
+    (0x77, 0x300, 0x1e81), (0x77, 0x301, 0x1e83), (0x77, 0x302, 0x175), (0x77, 0x307, 0x1e87),
+    (0x77, 0x308, 0x1e85), (0x77, 0x30a, 0x1e98), (0x77, 0x323, 0x1e89), (0x78, 0x307, 0x1e8b),
+    (0x78, 0x308, 0x1e8d), (0x79, 0x300, 0x1ef3), (0x79, 0x301, 0xfd), (0x79, 0x302, 0x177),
+    (0x79, 0x303, 0x1ef9), (0x79, 0x304, 0x233), (0x79, 0x307, 0x1e8f), (0x79, 0x308, 0xff),
+    (0x79, 0x309, 0x1ef7), (0x79, 0x30a, 0x1e99), (0x79, 0x323, 0x1ef5), (0x7a, 0x301, 0x17a),
+    (0x7a, 0x302, 0x1e91), (0x7a, 0x307, 0x17c), (0x7a, 0x30c, 0x17e), (0x7a, 0x323, 0x1e93),
+    (0x7a, 0x331, 0x1e95), (0xa8, 0x300, 0x1fed), (0xa8, 0x301, 0x385), (0xa8, 0x342, 0x1fc1),
+    (0xc2, 0x300, 0x1ea6), (0xc2, 0x301, 0x1ea4), (0xc2, 0x303, 0x1eaa), (0xc2, 0x309, 0x1ea8),
+    (0xc4, 0x304, 0x1de), (0xc5, 0x301, 0x1fa), (0xc6, 0x301, 0x1fc), (0xc6, 0x304, 0x1e2),
+    (0xc7, 0x301, 0x1e08), (0xca, 0x300, 0x1ec0), (0xca, 0x301, 0x1ebe), (0xca, 0x303, 0x1ec4),
+    (0xca, 0x309, 0x1ec2), (0xcf, 0x301, 0x1e2e), (0xd4, 0x300, 0x1ed2), (0xd4, 0x301, 0x1ed0),
+    (0xd4, 0x303, 0x1ed6), (0xd4, 0x309, 0x1ed4), (0xd5, 0x301, 0x1e4c), (0xd5, 0x304, 0x22c),
+    (0xd5, 0x308, 0x1e4e), (0xd6, 0x304, 0x22a), (0xd8, 0x301, 0x1fe), (0xdc, 0x300, 0x1db),
+    (0xdc, 0x301, 0x1d7), (0xdc, 0x304, 0x1d5), (0xdc, 0x30c, 0x1d9), (0xe2, 0x300, 0x1ea7),
+    (0xe2, 0x301, 0x1ea5), (0xe2, 0x303, 0x1eab), (0xe2, 0x309, 0x1ea9), (0xe4, 0x304, 0x1df),
+    (0xe5, 0x301, 0x1fb), (0xe6, 0x301, 0x1fd), (0xe6, 0x304, 0x1e3), (0xe7, 0x301, 0x1e09),
+    (0xea, 0x300, 0x1ec1), (0xea, 0x301, 0x1ebf), (0xea, 0x303, 0x1ec5), (0xea, 0x309, 0x1ec3),
+    (0xef, 0x301, 0x1e2f), (0xf4, 0x300, 0x1ed3), (0xf4, 0x301, 0x1ed1), (0xf4, 0x303, 0x1ed7),
+    (0xf4, 0x309, 0x1ed5), (0xf5, 0x301, 0x1e4d), (0xf5, 0x304, 0x22d), (0xf5, 0x308, 0x1e4f),
+    (0xf6, 0x304, 0x22b), (0xf8, 0x301, 0x1ff), (0xfc, 0x300, 0x1dc), (0xfc, 0x301, 0x1d8),
+    (0xfc, 0x304, 0x1d6), (0xfc, 0x30c, 0x1da), (0x102, 0x300, 0x1eb0), (0x102, 0x301, 0x1eae),
+    (0x102, 0x303, 0x1eb4), (0x102, 0x309, 0x1eb2), (0x103, 0x300, 0x1eb1), (0x103, 0x301, 0x1eaf),
+    (0x103, 0x303, 0x1eb5), (0x103, 0x309, 0x1eb3), (0x112, 0x300, 0x1e14), (0x112, 0x301, 0x1e16),
+    (0x113, 0x300, 0x1e15), (0x113, 0x301, 0x1e17), (0x14c, 0x300, 0x1e50), (0x14c, 0x301, 0x1e52),
+    (0x14d, 0x300, 0x1e51), (0x14d, 0x301, 0x1e53), (0x15a, 0x307, 0x1e64), (0x15b, 0x307, 0x1e65),
+    (0x160, 0x307, 0x1e66), (0x161, 0x307, 0x1e67), (0x168, 0x301, 0x1e78), (0x169, 0x301, 0x1e79),
+    (0x16a, 0x308, 0x1e7a), (0x16b, 0x308, 0x1e7b), (0x17f, 0x307, 0x1e9b), (0x1a0, 0x300, 0x1edc),
+    (0x1a0, 0x301, 0x1eda), (0x1a0, 0x303, 0x1ee0), (0x1a0, 0x309, 0x1ede), (0x1a0, 0x323, 0x1ee2),
+    (0x1a1, 0x300, 0x1edd), (0x1a1, 0x301, 0x1edb), (0x1a1, 0x303, 0x1ee1), (0x1a1, 0x309, 0x1edf),
+    (0x1a1, 0x323, 0x1ee3), (0x1af, 0x300, 0x1eea), (0x1af, 0x301, 0x1ee8), (0x1af, 0x303, 0x1eee),
+    (0x1af, 0x309, 0x1eec), (0x1af, 0x323, 0x1ef0), (0x1b0, 0x300, 0x1eeb), (0x1b0, 0x301, 0x1ee9),
+    (0x1b0, 0x303, 0x1eef), (0x1b0, 0x309, 0x1eed), (0x1b0, 0x323, 0x1ef1), (0x1b7, 0x30c, 0x1ee),
+    (0x1ea, 0x304, 0x1ec), (0x1eb, 0x304, 0x1ed), (0x226, 0x304, 0x1e0), (0x227, 0x304, 0x1e1),
+    (0x228, 0x306, 0x1e1c), (0x229, 0x306, 0x1e1d), (0x22e, 0x304, 0x230), (0x22f, 0x304, 0x231),
+    (0x292, 0x30c, 0x1ef), (0x391, 0x300, 0x1fba), (0x391, 0x301, 0x386), (0x391, 0x304, 0x1fb9),
+    (0x391, 0x306, 0x1fb8), (0x391, 0x313, 0x1f08), (0x391, 0x314, 0x1f09), (0x391, 0x345, 0x1fbc),
+    (0x395, 0x300, 0x1fc8), (0x395, 0x301, 0x388), (0x395, 0x313, 0x1f18), (0x395, 0x314, 0x1f19),
+    (0x397, 0x300, 0x1fca), (0x397, 0x301, 0x389), (0x397, 0x313, 0x1f28), (0x397, 0x314, 0x1f29),
+    (0x397, 0x345, 0x1fcc), (0x399, 0x300, 0x1fda), (0x399, 0x301, 0x38a), (0x399, 0x304, 0x1fd9),
+    (0x399, 0x306, 0x1fd8), (0x399, 0x308, 0x3aa), (0x399, 0x313, 0x1f38), (0x399, 0x314, 0x1f39),
+    (0x39f, 0x300, 0x1ff8), (0x39f, 0x301, 0x38c), (0x39f, 0x313, 0x1f48), (0x39f, 0x314, 0x1f49),
+    (0x3a1, 0x314, 0x1fec), (0x3a5, 0x300, 0x1fea), (0x3a5, 0x301, 0x38e), (0x3a5, 0x304, 0x1fe9),
+    (0x3a5, 0x306, 0x1fe8), (0x3a5, 0x308, 0x3ab), (0x3a5, 0x314, 0x1f59), (0x3a9, 0x300, 0x1ffa),
+    (0x3a9, 0x301, 0x38f), (0x3a9, 0x313, 0x1f68), (0x3a9, 0x314, 0x1f69), (0x3a9, 0x345, 0x1ffc),
+    (0x3ac, 0x345, 0x1fb4), (0x3ae, 0x345, 0x1fc4), (0x3b1, 0x300, 0x1f70), (0x3b1, 0x301, 0x3ac),
+    (0x3b1, 0x304, 0x1fb1), (0x3b1, 0x306, 0x1fb0), (0x3b1, 0x313, 0x1f00), (0x3b1, 0x314, 0x1f01),
+    (0x3b1, 0x342, 0x1fb6), (0x3b1, 0x345, 0x1fb3), (0x3b5, 0x300, 0x1f72), (0x3b5, 0x301, 0x3ad),
+    (0x3b5, 0x313, 0x1f10), (0x3b5, 0x314, 0x1f11), (0x3b7, 0x300, 0x1f74), (0x3b7, 0x301, 0x3ae),
+    (0x3b7, 0x313, 0x1f20), (0x3b7, 0x314, 0x1f21), (0x3b7, 0x342, 0x1fc6), (0x3b7, 0x345, 0x1fc3),
+    (0x3b9, 0x300, 0x1f76), (0x3b9, 0x301, 0x3af), (0x3b9, 0x304, 0x1fd1), (0x3b9, 0x306, 0x1fd0),
+    (0x3b9, 0x308, 0x3ca), (0x3b9, 0x313, 0x1f30), (0x3b9, 0x314, 0x1f31), (0x3b9, 0x342, 0x1fd6),
+    (0x3bf, 0x300, 0x1f78), (0x3bf, 0x301, 0x3cc), (0x3bf, 0x313, 0x1f40), (0x3bf, 0x314, 0x1f41),
+    (0x3c1, 0x313, 0x1fe4), (0x3c1, 0x314, 0x1fe5), (0x3c5, 0x300, 0x1f7a), (0x3c5, 0x301, 0x3cd),
+    (0x3c5, 0x304, 0x1fe1), (0x3c5, 0x306, 0x1fe0), (0x3c5, 0x308, 0x3cb), (0x3c5, 0x313, 0x1f50),
+    (0x3c5, 0x314, 0x1f51), (0x3c5, 0x342, 0x1fe6), (0x3c9, 0x300, 0x1f7c), (0x3c9, 0x301, 0x3ce),
+    (0x3c9, 0x313, 0x1f60), (0x3c9, 0x314, 0x1f61), (0x3c9, 0x342, 0x1ff6), (0x3c9, 0x345, 0x1ff3),
+    (0x3ca, 0x300, 0x1fd2), (0x3ca, 0x301, 0x390), (0x3ca, 0x342, 0x1fd7), (0x3cb, 0x300, 0x1fe2),
+    (0x3cb, 0x301, 0x3b0), (0x3cb, 0x342, 0x1fe7), (0x3ce, 0x345, 0x1ff4), (0x3d2, 0x301, 0x3d3),
+    (0x3d2, 0x308, 0x3d4), (0x406, 0x308, 0x407), (0x410, 0x306, 0x4d0), (0x410, 0x308, 0x4d2),
+    (0x413, 0x301, 0x403), (0x415, 0x300, 0x400), (0x415, 0x306, 0x4d6), (0x415, 0x308, 0x401),
+    (0x416, 0x306, 0x4c1), (0x416, 0x308, 0x4dc), (0x417, 0x308, 0x4de), (0x418, 0x300, 0x40d),
+    (0x418, 0x304, 0x4e2), (0x418, 0x306, 0x419), (0x418, 0x308, 0x4e4), (0x41a, 0x301, 0x40c),
+    (0x41e, 0x308, 0x4e6), (0x423, 0x304, 0x4ee), (0x423, 0x306, 0x40e), (0x423, 0x308, 0x4f0),
+    (0x423, 0x30b, 0x4f2), (0x427, 0x308, 0x4f4), (0x42b, 0x308, 0x4f8), (0x42d, 0x308, 0x4ec),
+    (0x430, 0x306, 0x4d1), (0x430, 0x308, 0x4d3), (0x433, 0x301, 0x453), (0x435, 0x300, 0x450),
+    (0x435, 0x306, 0x4d7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4c2), (0x436, 0x308, 0x4dd),
+    (0x437, 0x308, 0x4df), (0x438, 0x300, 0x45d), (0x438, 0x304, 0x4e3), (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4e5), (0x43a, 0x301, 0x45c), (0x43e, 0x308, 0x4e7), (0x443, 0x304, 0x4ef),
+    (0x443, 0x306, 0x45e), (0x443, 0x308, 0x4f1), (0x443, 0x30b, 0x4f3), (0x447, 0x308, 0x4f5),
+    (0x44b, 0x308, 0x4f9), (0x44d, 0x308, 0x4ed), (0x456, 0x308, 0x457), (0x474, 0x30f, 0x476),
+    (0x475, 0x30f, 0x477), (0x4d8, 0x308, 0x4da), (0x4d9, 0x308, 0x4db), (0x4e8, 0x308, 0x4ea),
+    (0x4e9, 0x308, 0x4eb), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623), (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624), (0x64a, 0x654, 0x626), (0x6c1, 0x654, 0x6c2), (0x6d2, 0x654, 0x6d3),
+    (0x6d5, 0x654, 0x6c0), (0x928, 0x93c, 0x929), (0x930, 0x93c, 0x931), (0x933, 0x93c, 0x934),
+    (0x9c7, 0x9be, 0x9cb), (0x9c7, 0x9d7, 0x9cc), (0xb47, 0xb3e, 0xb4b), (0xb47, 0xb56, 0xb48),
+    (0xb47, 0xb57, 0xb4c), (0xb92, 0xbd7, 0xb94), (0xbc6, 0xbbe, 0xbca), (0xbc6, 0xbd7, 0xbcc),
+    (0xbc7, 0xbbe, 0xbcb), (0xc46, 0xc56, 0xc48), (0xcbf, 0xcd5, 0xcc0), (0xcc6, 0xcc2, 0xcca),
+    (0xcc6, 0xcd5, 0xcc7), (0xcc6, 0xcd6, 0xcc8), (0xcca, 0xcd5, 0xccb), (0xd46, 0xd3e, 0xd4a),
+    (0xd46, 0xd57, 0xd4c), (0xd47, 0xd3e, 0xd4b), (0xdd9, 0xdca, 0xdda), (0xdd9, 0xdcf, 0xddc),
+    (0xdd9, 0xddf, 0xdde), (0xddc, 0xdca, 0xddd), (0x1025, 0x102e, 0x1026),
+    (0x1b05, 0x1b35, 0x1b06), (0x1b07, 0x1b35, 0x1b08), (0x1b09, 0x1b35, 0x1b0a),
+    (0x1b0b, 0x1b35, 0x1b0c), (0x1b0d, 0x1b35, 0x1b0e), (0x1b11, 0x1b35, 0x1b12),
+    (0x1b3a, 0x1b35, 0x1b3b), (0x1b3c, 0x1b35, 0x1b3d), (0x1b3e, 0x1b35, 0x1b40),
+    (0x1b3f, 0x1b35, 0x1b41), (0x1b42, 0x1b35, 0x1b43), (0x1e36, 0x304, 0x1e38),
+    (0x1e37, 0x304, 0x1e39), (0x1e5a, 0x304, 0x1e5c), (0x1e5b, 0x304, 0x1e5d),
+    (0x1e62, 0x307, 0x1e68), (0x1e63, 0x307, 0x1e69), (0x1ea0, 0x302, 0x1eac),
+    (0x1ea0, 0x306, 0x1eb6), (0x1ea1, 0x302, 0x1ead), (0x1ea1, 0x306, 0x1eb7),
+    (0x1eb8, 0x302, 0x1ec6), (0x1eb9, 0x302, 0x1ec7), (0x1ecc, 0x302, 0x1ed8),
+    (0x1ecd, 0x302, 0x1ed9), (0x1f00, 0x300, 0x1f02), (0x1f00, 0x301, 0x1f04),
+    (0x1f00, 0x342, 0x1f06), (0x1f00, 0x345, 0x1f80), (0x1f01, 0x300, 0x1f03),
+    (0x1f01, 0x301, 0x1f05), (0x1f01, 0x342, 0x1f07), (0x1f01, 0x345, 0x1f81),
+    (0x1f02, 0x345, 0x1f82), (0x1f03, 0x345, 0x1f83), (0x1f04, 0x345, 0x1f84),
+    (0x1f05, 0x345, 0x1f85), (0x1f06, 0x345, 0x1f86), (0x1f07, 0x345, 0x1f87),
+    (0x1f08, 0x300, 0x1f0a), (0x1f08, 0x301, 0x1f0c), (0x1f08, 0x342, 0x1f0e),
+    (0x1f08, 0x345, 0x1f88), (0x1f09, 0x300, 0x1f0b), (0x1f09, 0x301, 0x1f0d),
+    (0x1f09, 0x342, 0x1f0f), (0x1f09, 0x345, 0x1f89), (0x1f0a, 0x345, 0x1f8a),
+    (0x1f0b, 0x345, 0x1f8b), (0x1f0c, 0x345, 0x1f8c), (0x1f0d, 0x345, 0x1f8d),
+    (0x1f0e, 0x345, 0x1f8e), (0x1f0f, 0x345, 0x1f8f), (0x1f10, 0x300, 0x1f12),
+    (0x1f10, 0x301, 0x1f14), (0x1f11, 0x300, 0x1f13), (0x1f11, 0x301, 0x1f15),
+    (0x1f18, 0x300, 0x1f1a), (0x1f18, 0x301, 0x1f1c), (0x1f19, 0x300, 0x1f1b),
+    (0x1f19, 0x301, 0x1f1d), (0x1f20, 0x300, 0x1f22), (0x1f20, 0x301, 0x1f24),
+    (0x1f20, 0x342, 0x1f26), (0x1f20, 0x345, 0x1f90), (0x1f21, 0x300, 0x1f23),
+    (0x1f21, 0x301, 0x1f25), (0x1f21, 0x342, 0x1f27), (0x1f21, 0x345, 0x1f91),
+    (0x1f22, 0x345, 0x1f92), (0x1f23, 0x345, 0x1f93), (0x1f24, 0x345, 0x1f94),
+    (0x1f25, 0x345, 0x1f95), (0x1f26, 0x345, 0x1f96), (0x1f27, 0x345, 0x1f97),
+    (0x1f28, 0x300, 0x1f2a), (0x1f28, 0x301, 0x1f2c), (0x1f28, 0x342, 0x1f2e),
+    (0x1f28, 0x345, 0x1f98), (0x1f29, 0x300, 0x1f2b), (0x1f29, 0x301, 0x1f2d),
+    (0x1f29, 0x342, 0x1f2f), (0x1f29, 0x345, 0x1f99), (0x1f2a, 0x345, 0x1f9a),
+    (0x1f2b, 0x345, 0x1f9b), (0x1f2c, 0x345, 0x1f9c), (0x1f2d, 0x345, 0x1f9d),
+    (0x1f2e, 0x345, 0x1f9e), (0x1f2f, 0x345, 0x1f9f), (0x1f30, 0x300, 0x1f32),
+    (0x1f30, 0x301, 0x1f34), (0x1f30, 0x342, 0x1f36), (0x1f31, 0x300, 0x1f33),
+    (0x1f31, 0x301, 0x1f35), (0x1f31, 0x342, 0x1f37), (0x1f38, 0x300, 0x1f3a),
+    (0x1f38, 0x301, 0x1f3c), (0x1f38, 0x342, 0x1f3e), (0x1f39, 0x300, 0x1f3b),
+    (0x1f39, 0x301, 0x1f3d), (0x1f39, 0x342, 0x1f3f), (0x1f40, 0x300, 0x1f42),
+    (0x1f40, 0x301, 0x1f44), (0x1f41, 0x300, 0x1f43), (0x1f41, 0x301, 0x1f45),
+    (0x1f48, 0x300, 0x1f4a), (0x1f48, 0x301, 0x1f4c), (0x1f49, 0x300, 0x1f4b),
+    (0x1f49, 0x301, 0x1f4d), (0x1f50, 0x300, 0x1f52), (0x1f50, 0x301, 0x1f54),
+    (0x1f50, 0x342, 0x1f56), (0x1f51, 0x300, 0x1f53), (0x1f51, 0x301, 0x1f55),
+    (0x1f51, 0x342, 0x1f57), (0x1f59, 0x300, 0x1f5b), (0x1f59, 0x301, 0x1f5d),
+    (0x1f59, 0x342, 0x1f5f), (0x1f60, 0x300, 0x1f62), (0x1f60, 0x301, 0x1f64),
+    (0x1f60, 0x342, 0x1f66), (0x1f60, 0x345, 0x1fa0), (0x1f61, 0x300, 0x1f63),
+    (0x1f61, 0x301, 0x1f65), (0x1f61, 0x342, 0x1f67), (0x1f61, 0x345, 0x1fa1),
+    (0x1f62, 0x345, 0x1fa2), (0x1f63, 0x345, 0x1fa3), (0x1f64, 0x345, 0x1fa4),
+    (0x1f65, 0x345, 0x1fa5), (0x1f66, 0x345, 0x1fa6), (0x1f67, 0x345, 0x1fa7),
+    (0x1f68, 0x300, 0x1f6a), (0x1f68, 0x301, 0x1f6c), (0x1f68, 0x342, 0x1f6e),
+    (0x1f68, 0x345, 0x1fa8), (0x1f69, 0x300, 0x1f6b), (0x1f69, 0x301, 0x1f6d),
+    (0x1f69, 0x342, 0x1f6f), (0x1f69, 0x345, 0x1fa9), (0x1f6a, 0x345, 0x1faa),
+    (0x1f6b, 0x345, 0x1fab), (0x1f6c, 0x345, 0x1fac), (0x1f6d, 0x345, 0x1fad),
+    (0x1f6e, 0x345, 0x1fae), (0x1f6f, 0x345, 0x1faf), (0x1f70, 0x345, 0x1fb2),
+    (0x1f74, 0x345, 0x1fc2), (0x1f7c, 0x345, 0x1ff2), (0x1fb6, 0x345, 0x1fb7),
+    (0x1fbf, 0x300, 0x1fcd), (0x1fbf, 0x301, 0x1fce), (0x1fbf, 0x342, 0x1fcf),
+    (0x1fc6, 0x345, 0x1fc7), (0x1ff6, 0x345, 0x1ff7), (0x1ffe, 0x300, 0x1fdd),
+    (0x1ffe, 0x301, 0x1fde), (0x1ffe, 0x342, 0x1fdf), (0x2190, 0x338, 0x219a),
+    (0x2192, 0x338, 0x219b), (0x2194, 0x338, 0x21ae), (0x21d0, 0x338, 0x21cd),
+    (0x21d2, 0x338, 0x21cf), (0x21d4, 0x338, 0x21ce), (0x2203, 0x338, 0x2204),
+    (0x2208, 0x338, 0x2209), (0x220b, 0x338, 0x220c), (0x2223, 0x338, 0x2224),
+    (0x2225, 0x338, 0x2226), (0x223c, 0x338, 0x2241), (0x2243, 0x338, 0x2244),
+    (0x2245, 0x338, 0x2247), (0x2248, 0x338, 0x2249), (0x224d, 0x338, 0x226d),
+    (0x2261, 0x338, 0x2262), (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271),
+    (0x2272, 0x338, 0x2274), (0x2273, 0x338, 0x2275), (0x2276, 0x338, 0x2278),
+    (0x2277, 0x338, 0x2279), (0x227a, 0x338, 0x2280), (0x227b, 0x338, 0x2281),
+    (0x227c, 0x338, 0x22e0), (0x227d, 0x338, 0x22e1), (0x2282, 0x338, 0x2284),
+    (0x2283, 0x338, 0x2285), (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22e2), (0x2292, 0x338, 0x22e3), (0x22a2, 0x338, 0x22ac),
+    (0x22a8, 0x338, 0x22ad), (0x22a9, 0x338, 0x22ae), (0x22ab, 0x338, 0x22af),
+    (0x22b2, 0x338, 0x22ea), (0x22b3, 0x338, 0x22eb), (0x22b4, 0x338, 0x22ec),
+    (0x22b5, 0x338, 0x22ed), (0x3046, 0x3099, 0x3094), (0x304b, 0x3099, 0x304c),
+    (0x304d, 0x3099, 0x304e), (0x304f, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052),
+    (0x3053, 0x3099, 0x3054), (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058),
+    (0x3059, 0x3099, 0x305a), (0x305b, 0x3099, 0x305c), (0x305d, 0x3099, 0x305e),
+    (0x305f, 0x3099, 0x3060), (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067), (0x3068, 0x3099, 0x3069), (0x306f, 0x3099, 0x3070),
+    (0x306f, 0x309a, 0x3071), (0x3072, 0x3099, 0x3073), (0x3072, 0x309a, 0x3074),
+    (0x3075, 0x3099, 0x3076), (0x3075, 0x309a, 0x3077), (0x3078, 0x3099, 0x3079),
+    (0x3078, 0x309a, 0x307a), (0x307b, 0x3099, 0x307c), (0x307b, 0x309a, 0x307d),
+    (0x309d, 0x3099, 0x309e), (0x30a6, 0x3099, 0x30f4), (0x30ab, 0x3099, 0x30ac),
+    (0x30ad, 0x3099, 0x30ae), (0x30af, 0x3099, 0x30b0), (0x30b1, 0x3099, 0x30b2),
+    (0x30b3, 0x3099, 0x30b4), (0x30b5, 0x3099, 0x30b6), (0x30b7, 0x3099, 0x30b8),
+    (0x30b9, 0x3099, 0x30ba), (0x30bb, 0x3099, 0x30bc), (0x30bd, 0x3099, 0x30be),
+    (0x30bf, 0x3099, 0x30c0), (0x30c1, 0x3099, 0x30c2), (0x30c4, 0x3099, 0x30c5),
+    (0x30c6, 0x3099, 0x30c7), (0x30c8, 0x3099, 0x30c9), (0x30cf, 0x3099, 0x30d0),
+    (0x30cf, 0x309a, 0x30d1), (0x30d2, 0x3099, 0x30d3), (0x30d2, 0x309a, 0x30d4),
+    (0x30d5, 0x3099, 0x30d6), (0x30d5, 0x309a, 0x30d7), (0x30d8, 0x3099, 0x30d9),
+    (0x30d8, 0x309a, 0x30da), (0x30db, 0x3099, 0x30dc), (0x30db, 0x309a, 0x30dd),
+    (0x30ef, 0x3099, 0x30f7), (0x30f0, 0x3099, 0x30f8), (0x30f1, 0x3099, 0x30f9),
+    (0x30f2, 0x3099, 0x30fa), (0x30fd, 0x3099, 0x30fe), (0x11099, 0x110ba, 0x1109a),
+    (0x1109b, 0x110ba, 0x1109c), (0x110a5, 0x110ba, 0x110ab), (0x11131, 0x11127, 0x1112e),
+    (0x11132, 0x11127, 0x1112f), (0x11347, 0x1133e, 0x1134b), (0x11347, 0x11357, 0x1134c),
+    (0x114b9, 0x114b0, 0x114bc), (0x114b9, 0x114ba, 0x114bb), (0x114b9, 0x114bd, 0x114be),
+    (0x115b8, 0x115af, 0x115ba), (0x115b9, 0x115af, 0x115bb), (0x11935, 0x11930, 0x11938),
+];
@@ -0,0 +1,582 @@
+use std::result;
+use std::str::FromStr;
+
+use dns::{Error, Result, Class, RType, RName, RData};
+use dns::message::Resource;
+
+/// One logical line of a master file with comments stripped and lines
+/// joined across parentheses.
+#[derive(Debug, PartialEq)]
+pub struct Line {
+    pub line: usize,
+    /// The line starts with whitespace so it continues the previous owner.
+    pub blank_owner: bool,
+    /// Raw tokens; quoted strings keep their quotes and escapes are left
+    /// for the consumer to interpret.
+    pub tokens: Vec<String>,
+}
+
+/// Splits RFC 1035 master file text into logical lines of tokens.
+pub struct Tokenizer<'a> {
+    input: &'a [u8],
+    off: usize,
+    line: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+
+    pub fn new(input: &'a str) -> Tokenizer<'a> {
+        Tokenizer{ input: input.as_bytes(), off: 0, line: 1 }
+    }
+
+    #[inline]
+    pub fn line(&self) -> usize { self.line }
+
+    fn token(&self, start: usize) -> String {
+        let end = if self.off > self.input.len() { self.input.len() } else { self.off };
+        String::from_utf8_lossy(&self.input[start..end]).into_owned()
+    }
+
+    fn fail(&mut self) -> Option<Result<Line>> {
+        self.off = self.input.len();
+        Some(Err(Error::BadSyntax))
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<Line>;
+
+    fn next(&mut self) -> Option<Result<Line>> {
+        let maxlen = self.input.len();
+        while self.off < maxlen {
+            let line = self.line;
+            let blank_owner = self.input[self.off] == b' ' || self.input[self.off] == b'\t';
+            let mut tokens = vec![];
+            let mut parens = 0;
+
+            while self.off < maxlen {
+                match self.input[self.off] {
+                    b' ' | b'\t' | b'\r' => self.off += 1,
+                    b'\n' => {
+                        self.off += 1;
+                        self.line += 1;
+                        if parens == 0 {
+                            break
+                        }
+                    }
+                    b';' => {
+                        while self.off < maxlen && self.input[self.off] != b'\n' {
+                            self.off += 1;
+                        }
+                    }
+                    b'(' => {
+                        parens += 1;
+                        self.off += 1;
+                    }
+                    b')' => {
+                        if parens == 0 {
+                            return self.fail()
+                        }
+                        parens -= 1;
+                        self.off += 1;
+                    }
+                    b'"' => {
+                        let start = self.off;
+                        self.off += 1;
+                        loop {
+                            if self.off >= maxlen {
+                                return self.fail()
+                            }
+                            match self.input[self.off] {
+                                b'\\' => self.off += 2,
+                                b'"' => {
+                                    self.off += 1;
+                                    break
+                                }
+                                c => {
+                                    if c == b'\n' {
+                                        self.line += 1;
+                                    }
+                                    self.off += 1;
+                                }
+                            }
+                        }
+                        tokens.push(self.token(start));
+                    }
+                    _ => {
+                        let start = self.off;
+                        while self.off < maxlen {
+                            match self.input[self.off] {
+                                b'\\' => self.off += 2,
+                                b' ' | b'\t' | b'\r' | b'\n' | b';' | b'(' | b')' | b'"' => break,
+                                _ => self.off += 1,
+                            }
+                        }
+                        tokens.push(self.token(start));
+                    }
+                }
+            }
+
+            if parens > 0 {
+                return self.fail()
+            }
+            if !tokens.is_empty() {
+                return Some(Ok(Line{ line: line, blank_owner: blank_owner, tokens: tokens }))
+            }
+        }
+        None
+    }
+}
+
+/// The rdata tokens of an entry, consumed a field at a time. A token that
+/// does not parse as the field it stands for fails with `BadToken`
+/// holding it, running out of tokens with `BadRdata`.
+pub struct Fields<'a> {
+    tokens: &'a [String],
+    next: usize,
+}
+
+impl<'a> Fields<'a> {
+
+    pub fn new(tokens: &'a [String]) -> Fields<'a> {
+        Fields{ tokens: tokens, next: 0 }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.next >= self.tokens.len() }
+
+    /// The next token, left to be read.
+    #[inline]
+    pub fn peek(&self) -> Option<&'a str> { self.tokens.get(self.next).map(|s| &s[..]) }
+
+    /// Reads the next token as it is.
+    pub fn token(&mut self) -> Result<&'a str> {
+        match self.tokens.get(self.next) {
+            Some(token) => {
+                self.next += 1;
+                Ok(token)
+            }
+            None => Err(Error::BadRdata),
+        }
+    }
+
+    /// Reads the next token with `parse`.
+    pub fn field<T, F>(&mut self, parse: F) -> Result<T> where F: FnOnce(&str) -> Result<T> {
+        let token = try!(self.token());
+        parse(token).map_err(|_| Error::BadToken(token.to_string()))
+    }
+
+    pub fn number<T: FromStr>(&mut self) -> Result<T> {
+        self.field(|s| s.parse().map_err(|_| Error::BadRdata))
+    }
+
+    pub fn ttl(&mut self) -> Result<u32> {
+        self.field(parse_ttl)
+    }
+
+    /// A possibly relative domain name.
+    pub fn name(&mut self, origin: &RName) -> Result<RName> {
+        self.field(|s| parse_name(s, origin))
+    }
+
+    /// A character-string, quoted or not, of at most 255 bytes.
+    pub fn string(&mut self) -> Result<Vec<u8>> {
+        self.field(|s| {
+            let s = try!(unescape(s));
+            if s.len() > 255 {
+                return Err(Error::BadRdata)
+            }
+            Ok(s)
+        })
+    }
+
+    /// Reads all remaining tokens joined with `parse`, for base64 and hex
+    /// that may be split by whitespace.
+    pub fn rest<T, F>(&mut self, parse: F) -> Result<T> where F: FnOnce(&str) -> Result<T> {
+        if self.is_empty() {
+            return Err(Error::BadRdata)
+        }
+        let joined = self.tokens[self.next..].concat();
+        self.next = self.tokens.len();
+        parse(&joined).map_err(|_| Error::BadToken(joined.clone()))
+    }
+
+    /// Fails with the first token left over.
+    pub fn finish(&self) -> Result<()> {
+        match self.tokens.get(self.next) {
+            Some(token) => Err(Error::BadToken(token.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A resource record read from a master file. The rdata is left as tokens
+/// since its interpretation depends on the type.
+#[derive(Debug, PartialEq)]
+pub struct Entry {
+    pub name: RName,
+    pub ttl: u32,
+    pub class: Class,
+    pub rtype: RType,
+    pub rdata: Vec<String>,
+    /// The `$ORIGIN` in effect, needed to resolve relative names in rdata.
+    pub origin: RName,
+}
+
+impl Entry {
+
+    /// Converts the rdata tokens into typed rdata.
+    pub fn data(&self) -> Result<RData> {
+        RData::parse(self.rtype, &self.rdata, &self.origin)
+    }
+
+    pub fn to_resource(&self) -> Result<Resource> {
+        Ok(Resource{
+            name: self.name.clone(),
+            rtype: self.rtype,
+            class: self.class,
+            ttl: self.ttl,
+            data: try!(self.data()),
+        })
+    }
+}
+
+/// Reads entries from master file text, handling `$ORIGIN`, `$TTL`,
+/// relative and inherited owner names and optional TTL and class fields.
+pub struct Reader<'a> {
+    tokens: Tokenizer<'a>,
+    origin: RName,
+    ttl: Option<u32>,
+    last_name: Option<RName>,
+    last_class: Class,
+}
+
+impl<'a> Reader<'a> {
+
+    pub fn new(input: &'a str, origin: RName) -> Reader<'a> {
+        Reader{
+            tokens: Tokenizer::new(input),
+            origin: origin,
+            ttl: None,
+            last_name: None,
+            last_class: Class::IN,
+        }
+    }
+
+    #[inline]
+    pub fn origin(&self) -> &RName { &self.origin }
+
+    /// The current line, for error reporting.
+    #[inline]
+    pub fn line(&self) -> usize { self.tokens.line() }
+
+    fn directive(&mut self, tokens: &[String]) -> Result<()> {
+        if tokens.len() < 2 {
+            return Err(Error::BadDirective)
+        }
+        match &tokens[0].to_uppercase()[..] {
+            "$ORIGIN" => self.origin = try!(parse_name(&tokens[1], &self.origin)),
+            "$TTL" => self.ttl = Some(try!(parse_ttl(&tokens[1]))),
+            _ => return Err(Error::BadDirective),
+        }
+        Ok(())
+    }
+
+    fn entry(&mut self, line: Line) -> Result<Entry> {
+        let tokens = line.tokens;
+        let mut i = 0;
+
+        let name = if line.blank_owner {
+            match self.last_name {
+                Some(ref name) => name.clone(),
+                None => return Err(Error::BadSyntax),
+            }
+        } else {
+            i += 1;
+            try!(parse_name(&tokens[0], &self.origin))
+        };
+
+        let mut ttl = None;
+        let mut class = None;
+        let rtype;
+        loop {
+            let token = match tokens.get(i) {
+                Some(token) => token,
+                None => return Err(Error::BadSyntax),
+            };
+            i += 1;
+            if ttl.is_none() && is_digit(token.as_bytes()[0]) {
+                ttl = Some(try!(parse_ttl(token)));
+            } else if class.is_none() && Class::from_str(token).is_ok() {
+                class = Class::from_str(token).ok();
+            } else {
+                rtype = try!(RType::from_str(token));
+                break
+            }
+        }
+        let rdata = tokens[i..].to_vec();
+
+        // without $TTL the SOA minimum becomes the default (RFC 2308)
+        if rtype == RType::SOA && self.ttl.is_none() && rdata.len() == 7 {
+            self.ttl = Some(try!(parse_ttl(&rdata[6])));
+        }
+        let ttl = match ttl.or(self.ttl) {
+            Some(ttl) => ttl,
+            None => return Err(Error::BadTtl),
+        };
+        let class = class.unwrap_or(self.last_class);
+
+        self.last_name = Some(name.clone());
+        self.last_class = class;
+
+        Ok(Entry{
+            name: name,
+            ttl: ttl,
+            class: class,
+            rtype: rtype,
+            rdata: rdata,
+            origin: self.origin.clone(),
+        })
+    }
+}
+
+impl<'a> Iterator for Reader<'a> {
+    type Item = Result<Entry>;
+
+    fn next(&mut self) -> Option<Result<Entry>> {
+        loop {
+            let line = match self.tokens.next() {
+                Some(Ok(line)) => line,
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            };
+            if !line.blank_owner && line.tokens[0].starts_with('$') {
+                if let Err(e) = self.directive(&line.tokens) {
+                    return Some(Err(e))
+                }
+                continue
+            }
+            return Some(self.entry(line))
+        }
+    }
+}
+
+/// Parses a possibly relative domain name; `@` stands for the origin.
+pub fn parse_name(s: &str, origin: &RName) -> Result<RName> {
+    if s == "@" {
+        Ok(origin.clone())
+    } else if is_absolute(s) {
+        s.parse()
+    } else {
+        s.parse::<RName>().and_then(|name| name.append(origin))
+    }
+}
+
+#[inline]
+fn is_digit(c: u8) -> bool {
+    c >= b'0' && c <= b'9'
+}
+
+fn is_absolute(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    if bytes.last() != Some(&b'.') {
+        return false
+    }
+    // the final dot must not itself be escaped
+    let escapes = bytes[..bytes.len()-1].iter().rev().take_while(|&&c| c == b'\\').count();
+    escapes % 2 == 0
+}
+
+/// Parses a TTL given in seconds or with BIND style units, e.g. `1h30m`.
+pub fn parse_ttl(s: &str) -> Result<u32> {
+    if s.is_empty() {
+        return Err(Error::BadTtl)
+    }
+    let mut total: u64 = 0;
+    let mut n: u64 = 0;
+    let mut digits = false;
+    for c in s.bytes() {
+        let unit = match c {
+            b'0' ... b'9' => {
+                n = n * 10 + (c - b'0') as u64;
+                digits = true;
+                if n > u32::max_value() as u64 {
+                    return Err(Error::BadTtl)
+                }
+                continue
+            }
+            b's' | b'S' => 1,
+            b'm' | b'M' => 60,
+            b'h' | b'H' => 3600,
+            b'd' | b'D' => 86400,
+            b'w' | b'W' => 604800,
+            _ => return Err(Error::BadTtl),
+        };
+        if !digits {
+            return Err(Error::BadTtl)
+        }
+        total += n * unit;
+        n = 0;
+        digits = false;
+    }
+    total += n;
+    if total > u32::max_value() as u64 {
+        return Err(Error::BadTtl)
+    }
+    Ok(total as u32)
+}
+
+impl FromStr for Entry {
+    type Err = Error;
+
+    /// Parses a single absolute entry such as `www.example.com. 300 IN A 192.0.2.1`.
+    fn from_str(s: &str) -> result::Result<Entry, Error> {
+        match Reader::new(s, RName::from_str(".").unwrap()).next() {
+            Some(entry) => entry,
+            None => Err(Error::BadSyntax),
+        }
+    }
+}
+
+/// Strips quotes and resolves `\X` and `\DDD` escapes in a character-string.
+pub fn unescape(s: &str) -> Result<Vec<u8>> {
+    let bytes = s.as_bytes();
+    let bytes = if bytes.len() >= 2 && bytes[0] == b'"' && bytes[bytes.len()-1] == b'"' {
+        &bytes[1..bytes.len()-1]
+    } else {
+        bytes
+    };
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' {
+            out.push(bytes[i]);
+            i += 1;
+            continue
+        }
+        i += 1;
+        if i >= bytes.len() {
+            return Err(Error::BadEscape)
+        }
+        if bytes[i] >= b'0' && bytes[i] <= b'9' {
+            if i + 3 > bytes.len() {
+                return Err(Error::BadEscape)
+            }
+            let mut n: u32 = 0;
+            for &c in bytes[i..i+3].iter() {
+                if c < b'0' || c > b'9' {
+                    return Err(Error::BadEscape)
+                }
+                n = n * 10 + (c - b'0') as u32;
+            }
+            if n > 255 {
+                return Err(Error::BadEscape)
+            }
+            out.push(n as u8);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    Ok(out)
+}
+
+
+#[cfg(test)]
+fn tokens(s: &str) -> Vec<Vec<String>> {
+    Tokenizer::new(s).map(|l| l.unwrap().tokens).collect()
+}
+
+#[test]
+fn tokenize_lines() {
+    assert_eq!(tokens("a b\tc\n\n  d ; comment\ne"),
+               vec![vec!["a", "b", "c"], vec!["d"], vec!["e"]]);
+
+    assert_eq!(tokens("@ IN SOA ns hostmaster ( 1 ; serial\n 2 3\n 4 5 )\nnext A 1.2.3.4"),
+               vec![vec!["@", "IN", "SOA", "ns", "hostmaster", "1", "2", "3", "4", "5"],
+                    vec!["next", "A", "1.2.3.4"]]);
+
+    assert_eq!(tokens("txt TXT \"hello world\" \"a \\\" b;c\" x\\ y"),
+               vec![vec!["txt", "TXT", "\"hello world\"", "\"a \\\" b;c\"", "x\\ y"]]);
+
+    let lines: Vec<Line> = Tokenizer::new("a\n b\n").map(|l| l.unwrap()).collect();
+    assert_eq!(lines[0].blank_owner, false);
+    assert_eq!(lines[1].blank_owner, true);
+    assert_eq!(lines[1].line, 2);
+
+    assert_eq!(Tokenizer::new("a ( b").next(), Some(Err(Error::BadSyntax)));
+    assert_eq!(Tokenizer::new("a ) b").next(), Some(Err(Error::BadSyntax)));
+    assert_eq!(Tokenizer::new("a \"b").next(), Some(Err(Error::BadSyntax)));
+}
+
+#[test]
+fn parse_ttls() {
+    assert_eq!(parse_ttl("300"), Ok(300));
+    assert_eq!(parse_ttl("1h30m"), Ok(5400));
+    assert_eq!(parse_ttl("1W2d"), Ok(777600));
+    assert_eq!(parse_ttl("h"), Err(Error::BadTtl));
+    assert_eq!(parse_ttl("5x"), Err(Error::BadTtl));
+    assert_eq!(parse_ttl("4294967296"), Err(Error::BadTtl));
+}
+
+#[test]
+fn read_entries() {
+    let zone = "$ORIGIN example.com.\n\
+                $TTL 1h\n\
+                @        IN SOA ns1 hostmaster 1 7200 900 1209600 300\n\
+                \tNS     ns1\n\
+                ns1  60  IN A   192.0.2.1\n\
+                www  IN  120 CNAME ns1.example.com.\n\
+                $ORIGIN sub\n\
+                host     AAAA   2001:db8::1\n";
+
+    let entries: Vec<Entry> = Reader::new(zone, RName::from_str(".").unwrap())
+        .map(|e| e.unwrap())
+        .collect();
+
+    let names: Vec<String> = entries.iter().map(|e| e.name.to_string()).collect();
+    assert_eq!(names, vec!["example.com.", "example.com.", "ns1.example.com.",
+                           "www.example.com.", "host.sub.example.com."]);
+
+    let types: Vec<RType> = entries.iter().map(|e| e.rtype).collect();
+    assert_eq!(types, vec![RType::SOA, RType::NS, RType::A, RType::CNAME, RType::AAAA]);
+
+    let ttls: Vec<u32> = entries.iter().map(|e| e.ttl).collect();
+    assert_eq!(ttls, vec![3600, 3600, 60, 120, 3600]);
+
+    assert_eq!(entries[1].rdata, vec!["ns1"]);
+    assert_eq!(entries[4].origin.to_string(), "sub.example.com.");
+}
+
+#[test]
+fn read_invalid_entries() {
+    fn first(s: &str) -> Result<Entry> {
+        Reader::new(s, RName::from_str("example.com").unwrap()).next().unwrap()
+    }
+    assert_eq!(first("www 300 IN BOGUS 1").err(), Some(Error::BadRType));
+    assert_eq!(first("www IN A 1.2.3.4").err(), Some(Error::BadTtl));
+    assert_eq!(first("www 300 IN").err(), Some(Error::BadSyntax));
+    assert_eq!(first(" A 1.2.3.4").err(), Some(Error::BadSyntax));
+    assert_eq!(first("$INCLUDE other.zone").err(), Some(Error::BadDirective));
+
+    // the SOA minimum is the default TTL without $TTL
+    let entries: Vec<Entry> = Reader::new("@ SOA ns host 1 2 3 4 600\nwww A 192.0.2.1",
+                                          RName::from_str("example.com").unwrap())
+        .map(|e| e.unwrap())
+        .collect();
+    assert_eq!(entries[1].ttl, 600);
+}
+
+#[test]
+fn parse_relative_names() {
+    let origin = RName::from_str("example.com").unwrap();
+    assert_eq!(parse_name("@", &origin).unwrap().to_string(), "example.com.");
+    assert_eq!(parse_name("www", &origin).unwrap().to_string(), "www.example.com.");
+    assert_eq!(parse_name("www.", &origin).unwrap().to_string(), "www.");
+    assert_eq!(parse_name("a\\.", &origin).unwrap().to_string(), "a\\..example.com.");
+    assert_eq!(parse_name("www", &RName::from_str(".").unwrap()).unwrap().to_string(), "www.");
+
+    assert_eq!("www.example.com. 300 IN A 192.0.2.1".parse::<Entry>().unwrap().rdata,
+               vec!["192.0.2.1"]);
+}
@@ -1,7 +1,13 @@
 use std::fmt;
+use std::result;
+use std::str::FromStr;
 
 use dns::{Error, Result, Class, OpCode, RCode, RType, RName, RData};
+use dns::rname::Compressor;
+use dns::master::Reader;
+use dns::dnssec::type_name;
 
+#[derive(Clone)]
 pub struct Message {
     pub id: u16,
     pub opcode: OpCode,
@@ -17,6 +23,7 @@ pub struct Message {
     pub answers:     Vec<Resource>,
     pub authority:   Vec<Resource>,
     pub additionals: Vec<Resource>,
+    pub edns: Option<Edns>,
 }
 
 /*
@@ -38,9 +45,10 @@ pub struct Message {
 impl Message {
 
     pub fn pack(&self, buf: &mut [u8], mut offset: usize) -> Result<usize> {
-        if buf.len() < 12 {
+        if offset + 12 > buf.len() {
             return Err(Error::SmallBuf)
         }
+        let mut names = Compressor::new(offset);
 
         // ID
         unsafe { write_be!(buf, offset, self.id, 2); }
@@ -62,15 +70,16 @@ impl Message {
             write_be!(buf, offset + 4,  self.questions.len() as u16, 2);
             write_be!(buf, offset + 6,  self.answers.len() as u16, 2);
             write_be!(buf, offset + 8,  self.authority.len() as u16, 2);
-            write_be!(buf, offset + 10, self.additionals.len() as u16, 2);
+            write_be!(buf, offset + 10, (self.additionals.len() + self.edns.is_some() as usize) as u16, 2);
             offset += 12;
         }
 
         // Results
-        for q in self.questions.iter()   { offset = try!(q.pack(buf, offset)); }
-        for a in self.answers.iter()     { offset = try!(a.pack(buf, offset)); }
-        for a in self.authority.iter()   { offset = try!(a.pack(buf, offset)); }
-        for a in self.additionals.iter() { offset = try!(a.pack(buf, offset)); }
+        for q in self.questions.iter()   { offset = try!(q.pack(buf, offset, &mut names)); }
+        for a in self.answers.iter()     { offset = try!(a.pack(buf, offset, &mut names)); }
+        for a in self.authority.iter()   { offset = try!(a.pack(buf, offset, &mut names)); }
+        for a in self.additionals.iter() { offset = try!(a.pack(buf, offset, &mut names)); }
+        if let Some(ref edns) = self.edns { offset = try!(edns.pack(buf, offset)); }
 
         Ok(offset)
     }
@@ -130,7 +139,17 @@ impl Message {
         }
 
         let mut additionals: Vec<Resource> = Vec::with_capacity(arcount);
+        let mut edns = None;
         for _ in 0..arcount {
+            if Edns::is_opt(msg, offset) {
+                if edns.is_some() {
+                    return Err(Error::BadRdata)
+                }
+                let (e, o) = try!(Edns::unpack(msg, offset));
+                edns = Some(e);
+                offset = o;
+                continue
+            }
             match Resource::unpack(msg, offset) {
                 Err(err) => return Err(err),
                 Ok((a, o)) => {
@@ -157,34 +176,63 @@ impl Message {
             answers: answers,
             authority: authority,
             additionals: additionals,
+            edns: edns,
         })
     }
 
     pub fn new_reply(req: &Message) -> Message {
-        let mut questions = Vec::with_capacity(1);
+        MessageBuilder::reply_to(req).opcode(OpCode::QUERY).build()
+    }
 
-        let mut answers = Vec::with_capacity(0); // TODO remove me
+    pub fn new_error(req: &Message, rcode: RCode) -> Message {
+        MessageBuilder::reply_to(req).rcode(rcode).build()
+    }
 
-        if req.questions.len() > 0 {
-            let ref q = req.questions[0];
+    /// The largest UDP response the sender of this request accepts.
+    pub fn max_payload(&self) -> usize {
+        match self.edns {
+            Some(ref edns) if edns.payload > 512 => edns.payload as usize,
+            _ => 512,
+        }
+    }
+}
 
-            questions.push(q.clone());
+/// Builds a message a section at a time, starting from a query or from the
+/// request a reply is for so the header matches it.
+pub struct MessageBuilder {
+    msg: Message,
+}
 
-            // TODO hardcoded hack
-            let resource = Resource{
-                name: q.name.clone(),
-                rtype: RType::A,
-                class: q.class,
-                ttl: 300,
-                data: RData::A(0xd8, 0x3a, 0xd0, 0x2e),
-            };
-            answers.push(resource);
-        }
+impl MessageBuilder {
 
-        Message{
-            id: req.id,
+    /// A recursive query for `name` in class IN.
+    pub fn query(name: RName, rtype: RType) -> MessageBuilder {
+        MessageBuilder{ msg: Message{
+            id: 0,
             opcode: OpCode::QUERY,
             rcode: RCode::NOERROR,
+            qr: false,
+            aa: false,
+            tc: false,
+            rd: true,
+            ra: false,
+            ad: false,
+            cd: false,
+            questions: vec![Question{ name: name, rtype: rtype, class: Class::IN }],
+            answers: vec![],
+            authority: vec![],
+            additionals: vec![],
+            edns: None,
+        }}
+    }
+
+    /// A reply to `req` with its id, opcode, RD bit and question, and with
+    /// EDNS when the request has it.
+    pub fn reply_to(req: &Message) -> MessageBuilder {
+        MessageBuilder{ msg: Message{
+            id: req.id,
+            opcode: req.opcode,
+            rcode: RCode::NOERROR,
             qr: true,
             aa: false,
             tc: false,
@@ -192,11 +240,76 @@ impl Message {
             ra: true,
             ad: false,
             cd: false,
-            questions: questions,
-            answers: answers,
+            questions: req.questions.iter().take(1).cloned().collect(),
+            answers: vec![],
             authority: vec![],
             additionals: vec![],
-        }
+            edns: req.edns.as_ref().map(|_| Edns::default()),
+        }}
+    }
+
+    pub fn id(mut self, id: u16) -> MessageBuilder {
+        self.msg.id = id;
+        self
+    }
+
+    pub fn opcode(mut self, opcode: OpCode) -> MessageBuilder {
+        self.msg.opcode = opcode;
+        self
+    }
+
+    pub fn rcode(mut self, rcode: RCode) -> MessageBuilder {
+        self.msg.rcode = rcode;
+        self
+    }
+
+    pub fn aa(mut self, aa: bool) -> MessageBuilder {
+        self.msg.aa = aa;
+        self
+    }
+
+    pub fn tc(mut self, tc: bool) -> MessageBuilder {
+        self.msg.tc = tc;
+        self
+    }
+
+    pub fn rd(mut self, rd: bool) -> MessageBuilder {
+        self.msg.rd = rd;
+        self
+    }
+
+    pub fn ad(mut self, ad: bool) -> MessageBuilder {
+        self.msg.ad = ad;
+        self
+    }
+
+    pub fn cd(mut self, cd: bool) -> MessageBuilder {
+        self.msg.cd = cd;
+        self
+    }
+
+    pub fn answer(mut self, rr: Resource) -> MessageBuilder {
+        self.msg.answers.push(rr);
+        self
+    }
+
+    pub fn authority(mut self, rr: Resource) -> MessageBuilder {
+        self.msg.authority.push(rr);
+        self
+    }
+
+    pub fn additional(mut self, rr: Resource) -> MessageBuilder {
+        self.msg.additionals.push(rr);
+        self
+    }
+
+    pub fn edns(mut self, edns: Edns) -> MessageBuilder {
+        self.msg.edns = Some(edns);
+        self
+    }
+
+    pub fn build(self) -> Message {
+        self.msg
     }
 }
 
@@ -217,7 +330,13 @@ impl fmt::Display for Message {
                     self.questions.len(),
                     self.answers.len(),
                     self.authority.len(),
-                    self.additionals.len()));
+                    self.additionals.len() + self.edns.is_some() as usize));
+
+        if let Some(ref edns) = self.edns {
+            try!(write!(f, "\n;; OPT PSEUDOSECTION:\n; EDNS: version: {}, flags:", edns.version));
+            if edns.dnssec_ok { try!(write!(f, " do")); }
+            try!(write!(f, "; udp: {}\n", edns.payload));
+        }
 
         if self.questions.len() > 0 {
             try!(write!(f, "\n;; QUESTION SECTION:\n"));
@@ -230,24 +349,21 @@ impl fmt::Display for Message {
         if self.answers.len() > 0 {
             try!(write!(f, "\n;; ANSWER SECTION:\n"));
             for a in self.answers.iter() {
-                try!(write!(f, ";"));
-                try!(a.fmt(f));
+                try!(write!(f, "{}\n", a));
             }
         }
 
         if self.authority.len() > 0 {
             try!(write!(f, "\n;; AUTHORITY SECTION:\n"));
             for a in self.authority.iter() {
-                try!(write!(f, ";"));
-                try!(a.fmt(f));
+                try!(write!(f, "{}\n", a));
             }
         }
 
         if self.additionals.len() > 0 {
             try!(write!(f, "\n;; ADDITIONAL SECTION:\n"));
             for a in self.additionals.iter() {
-                try!(write!(f, ";"));
-                try!(a.fmt(f));
+                try!(write!(f, "{}\n", a));
             }
         }
 
@@ -277,8 +393,8 @@ pub struct Question {
 */
 impl Question {
 
-    fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
-        let offset = try!(self.name.pack(buf, offset));
+    fn pack(&self, buf: &mut [u8], offset: usize, names: &mut Compressor) -> Result<usize> {
+        let offset = try!(self.name.pack_compressed(buf, offset, names));
         if offset + 4 > buf.len() {
             return Err(Error::SmallBuf)
         }
@@ -292,6 +408,7 @@ impl Question {
     fn unpack(msg: &[u8], offset: usize) -> Result<(Question, usize)> {
         match RName::unpack(msg, offset) {
             Err(e) => Err(e),
+            Ok((_, offset)) if offset + 4 > msg.len() => Err(Error::ShortRead),
             Ok((name, offset)) => {
                 Ok((Question{
                     name: name,
@@ -357,8 +474,15 @@ pub struct Resource {
 
 impl Resource {
 
-    fn pack(&self, buf: &mut [u8], mut offset: usize) -> Result<usize> {
-        offset = try!(self.name.pack(buf, offset));
+    /// The uncompressed length of the record on the wire.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.name.len() + 1 + 10 + self.data.len()
+    }
+
+    /// Writes the record at `offset`, returning the offset after it.
+    pub fn pack(&self, buf: &mut [u8], mut offset: usize, names: &mut Compressor) -> Result<usize> {
+        offset = try!(self.name.pack_compressed(buf, offset, names));
         if offset + 8 > buf.len() {
             return Err(Error::SmallBuf)
         }
@@ -367,22 +491,20 @@ impl Resource {
             write_be!(buf, offset + 2, self.class as u16, 2);
             write_be!(buf, offset + 4, self.ttl, 4);
         }
-        Ok(try!(self.data.pack(buf, offset+8)))
+        Ok(try!(self.data.pack_compressed(buf, offset+8, names)))
     }
 
-    fn unpack(msg: &[u8], offset: usize) -> Result<(Resource, usize)> {
+    /// Reads the record at `offset`, returning it with the offset after it.
+    pub fn unpack(msg: &[u8], offset: usize) -> Result<(Resource, usize)> {
         let (name, offset) = try!(RName::unpack(msg, offset));
+        if offset + 10 > msg.len() {
+            return Err(Error::ShortRead)
+        }
         let rtype = try!(RType::unpack(unsafe { read_be!(msg, offset, u16) }));
         let class = try!(Class::unpack(unsafe { read_be!(msg, offset + 2, u16) }));
         let ttl = unsafe { read_be!(msg, offset + 4, u32 ) };
         let rdlength = unsafe { read_be!(msg, offset + 8, u16) };
-
-        // if offset +  10 + rdlength >= msg.len() {
-        //     return Err(ShortRead)
-        // }
-
-        // todo parse data type
-        let data = RData::RawData(vec![]);
+        let data = try!(RData::unpack(rtype, msg, offset + 10, rdlength as usize));
 
         Ok((Resource{
             name: name,
@@ -405,9 +527,118 @@ impl Resource {
     }
 }
 
+/// Parses a record as a line of a master file, relative names being
+/// relative to the root, such as `www.example.com. 300 IN A 192.0.2.1`.
+impl FromStr for Resource {
+    type Err = Error;
+
+    fn from_str(s: &str) -> result::Result<Resource, Error> {
+        let mut entries = Reader::new(s, RName::from_str(".").unwrap());
+        match (entries.next(), entries.next()) {
+            (Some(entry), None) => try!(entry).to_resource(),
+            _ => Err(Error::BadSyntax),
+        }
+    }
+}
+
+/// A line of a master file.
 impl fmt::Display for Resource {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}\t{:?}\t{:?}\t{:?}\n", self.name, self.ttl, self.class, self.rtype)
+        write!(f, "{}\t{}\t{:?}\t{}\t{}", self.name, self.ttl, self.class, type_name(self.rtype as u16), self.data)
+    }
+}
+
+
+/// EDNS(0) parameters carried in the OPT pseudo-record (RFC 6891).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edns {
+    pub payload: u16,
+    pub ext_rcode: u8,
+    pub version: u8,
+    pub dnssec_ok: bool,
+    pub options: Vec<(u16, Vec<u8>)>,
+}
+
+impl Default for Edns {
+    fn default() -> Edns {
+        Edns{
+            payload: 1232,
+            ext_rcode: 0,
+            version: 0,
+            dnssec_ok: false,
+            options: vec![],
+        }
+    }
+}
+
+impl Edns {
+
+    #[inline]
+    fn is_opt(msg: &[u8], offset: usize) -> bool {
+        offset + 3 <= msg.len() && msg[offset] == 0 &&
+            unsafe { read_be!(msg, offset + 1, u16) } == RType::OPT as u16
+    }
+
+    fn pack(&self, buf: &mut [u8], mut offset: usize) -> Result<usize> {
+        let rdlength = self.options.iter().fold(0, |n, &(_, ref v)| n + 4 + v.len());
+        if offset + 11 + rdlength > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        buf[offset] = 0;
+        unsafe {
+            write_be!(buf, offset + 1, RType::OPT as u16, 2);
+            write_be!(buf, offset + 3, self.payload, 2);
+            write_be!(buf, offset + 9, rdlength as u16, 2);
+        }
+        buf[offset + 5] = self.ext_rcode;
+        buf[offset + 6] = self.version;
+        buf[offset + 7] = (self.dnssec_ok as u8) << 7;
+        buf[offset + 8] = 0;
+        offset += 11;
+        for &(code, ref data) in self.options.iter() {
+            unsafe {
+                write_be!(buf, offset, code, 2);
+                write_be!(buf, offset + 2, data.len() as u16, 2);
+            }
+            buf[offset + 4..offset + 4 + data.len()].copy_from_slice(data);
+            offset += 4 + data.len();
+        }
+        Ok(offset)
+    }
+
+    fn unpack(msg: &[u8], offset: usize) -> Result<(Edns, usize)> {
+        if offset + 11 > msg.len() {
+            return Err(Error::ShortRead)
+        }
+        let payload = unsafe { read_be!(msg, offset + 3, u16) };
+        let rdlength = unsafe { read_be!(msg, offset + 9, u16) } as usize;
+        let end = offset + 11 + rdlength;
+        if end > msg.len() {
+            return Err(Error::ShortRead)
+        }
+
+        let mut options = vec![];
+        let mut off = offset + 11;
+        while off < end {
+            if off + 4 > end {
+                return Err(Error::BadRdata)
+            }
+            let code = unsafe { read_be!(msg, off, u16) };
+            let len = unsafe { read_be!(msg, off + 2, u16) } as usize;
+            if off + 4 + len > end {
+                return Err(Error::BadRdata)
+            }
+            options.push((code, msg[off + 4..off + 4 + len].to_vec()));
+            off += 4 + len;
+        }
+
+        Ok((Edns{
+            payload: payload,
+            ext_rcode: msg[offset + 5],
+            version: msg[offset + 6],
+            dnssec_ok: msg[offset + 7] & 0x80 != 0,
+            options: options,
+        }, end))
     }
 }
 
@@ -419,14 +650,28 @@ impl fmt::Display for Resource {
 #[cfg(test)] use super::RType::*;
 #[cfg(test)] use super::Class::*;
 
+// dig www.bbc.co.uk.
+//
+// www.bbc.co.uk.		167	IN	CNAME	www.bbc.net.uk.
+// www.bbc.net.uk.		58	IN	A	212.58.244.70
+// www.bbc.net.uk.		58	IN	A	212.58.244.71
+#[cfg(test)]
+pub const BBC: &'static str = "0bd081800001000300000000037777770362626302636f02756b0000010001c00c00050001000000a7000e0377777703626263036e6574c017c02b000100010000003a0004d43af446c02b000100010000003a0004d43af447";
+
+// dig www.reddit.com, fifteen addresses
+#[cfg(test)]
+pub const REDDIT: &'static str = "f13a81800001000f00000000037777770672656464697403636f6d0000010001c00c000100010000012b0004c629d18fc00c000100010000012b0004c629d18dc00c000100010000012b0004c629d08ec00c000100010000012b0004c629d08bc00c000100010000012b0004c629d188c00c000100010000012b0004c629d08cc00c000100010000012b0004c629d08fc00c000100010000012b0004c629d18bc00c000100010000012b0004c629d18ac00c000100010000012b0004c629d189c00c000100010000012b0004c629d089c00c000100010000012b0004c629d08ac00c000100010000012b0004c629d18ec00c000100010000012b0004c629d18cc00c000100010000012b0004c629d08d";
+
 #[cfg(test)]
 fn q(name: &str, rtype: RType, class: Class) -> Question {
     Question::parse(name, rtype, class).unwrap()
 }
 
 #[cfg(test)]
-fn r(name: &str, rtype: RType, class: Class, ttl: u32) -> Resource {
-    Resource::parse(name, rtype, class, ttl).unwrap()
+fn r(name: &str, rtype: RType, class: Class, ttl: u32, data: RData) -> Resource {
+    let mut r = Resource::parse(name, rtype, class, ttl).unwrap();
+    r.data = data;
+    r
 }
 
 #[test]
@@ -503,42 +748,72 @@ fn unpack_message_responses() {
            11042,
            NOERROR,
            q("www.google.com", A, IN),
-           vec![r("www.google.com", A, IN, 188)]);
+           vec![r("www.google.com", A, IN, 188, RData::A(216, 58, 208, 68))]);
 
-    // dig www.bbc.co.uk.
-    //
-    // www.bbc.co.uk.		167	IN	CNAME	www.bbc.net.uk.
-    // www.bbc.net.uk.		58	IN	A	212.58.244.70
-    // www.bbc.net.uk.		58	IN	A	212.58.244.71
-    unpack("0bd081800001000300000000037777770362626302636f02756b0000010001c00c00050001000000a7000e0377777703626263036e6574c017c02b000100010000003a0004d43af446c02b000100010000003a0004d43af447",
+    unpack(BBC,
            3024,
            NOERROR,
            q("www.bbc.co.uk", A, IN),
-           vec![r("www.bbc.co.uk", CNAME, IN, 167),
-                r("www.bbc.net.uk", A, IN, 58),
-                r("www.bbc.net.uk", A, IN, 58)]);
+           vec![r("www.bbc.co.uk", CNAME, IN, 167, RData::CNAME("www.bbc.net.uk".parse().unwrap())),
+                r("www.bbc.net.uk", A, IN, 58, RData::A(212, 58, 244, 70)),
+                r("www.bbc.net.uk", A, IN, 58, RData::A(212, 58, 244, 71))]);
 
-    // dig www.reddit.com
-    unpack("f13a81800001000f00000000037777770672656464697403636f6d0000010001c00c000100010000012b0004c629d18fc00c000100010000012b0004c629d18dc00c000100010000012b0004c629d08ec00c000100010000012b0004c629d08bc00c000100010000012b0004c629d188c00c000100010000012b0004c629d08cc00c000100010000012b0004c629d08fc00c000100010000012b0004c629d18bc00c000100010000012b0004c629d18ac00c000100010000012b0004c629d189c00c000100010000012b0004c629d089c00c000100010000012b0004c629d08ac00c000100010000012b0004c629d18ec00c000100010000012b0004c629d18cc00c000100010000012b0004c629d08d",
+    unpack(REDDIT,
            61754,
            NOERROR,
            q("www.reddit.com", A, IN),
-           vec![r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299),
-                r("www.reddit.com", A, IN, 299)]);
+           vec![r("www.reddit.com", A, IN, 299, RData::A(198, 41, 209, 143)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 209, 141)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 208, 142)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 208, 139)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 209, 136)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 208, 140)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 208, 143)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 209, 139)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 209, 138)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 209, 137)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 208, 137)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 208, 138)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 209, 142)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 209, 140)),
+                r("www.reddit.com", A, IN, 299, RData::A(198, 41, 208, 141))]);
+
+}
 
+#[test]
+fn unpack_message_edns() {
+    // dig www.google.com with AD set
+    let buf = "33be012000010000000000010377777706676f6f676c6503636f6d00000100010000291000000000000000".from_hex().unwrap();
+    let msg = Message::unpack(&buf, 0).unwrap();
+    assert_eq!(msg.id, 13246);
+    assert_eq!(msg.ad, true);
+    assert_eq!(msg.questions, vec![q("www.google.com", A, IN)]);
+    assert_eq!(msg.additionals, vec![]);
+    assert_eq!(msg.edns, Some(Edns{ payload: 4096, ..Edns::default() }));
+    assert_eq!(msg.max_payload(), 4096);
+
+    let mut out = [0; 512];
+    let len = msg.pack(&mut out, 0).unwrap();
+    assert_eq!(&out[..len], &buf[..]);
+
+    let reply = Message::new_reply(&msg);
+    assert_eq!(reply.edns, Some(Edns::default()));
+
+    // options and the DO bit
+    let buf = "000100000001000000000001000001000100002904d0000080000008000a000401020304".from_hex().unwrap();
+    let msg = Message::unpack(&buf, 0).unwrap();
+    assert_eq!(msg.edns, Some(Edns{
+        payload: 1232,
+        dnssec_ok: true,
+        options: vec![(10, vec![1, 2, 3, 4])],
+        ..Edns::default()
+    }));
+    let len = msg.pack(&mut out, 0).unwrap();
+    assert_eq!(&out[..len], &buf[..]);
+
+    // a second OPT record is malformed
+    let buf = "000100000001000000000002000001000100002910000000000000000000291000000000000000".from_hex().unwrap();
+    assert_eq!(Message::unpack(&buf, 0).err(), Some(BadRdata));
 }
 
 #[test]
@@ -548,7 +823,108 @@ fn pack_message_requests() {
 }
 
 #[test]
-#[ignore]
 fn pack_message_responses() {
 
+    fn repack(buf: &'static str) {
+        let buf = buf.from_hex().unwrap();
+        let msg = Message::unpack(&buf, 0).unwrap();
+        let mut out = [0; 1024];
+        let len = msg.pack(&mut out, 0).unwrap();
+        assert_eq!(&out[..len], &buf[..]);
+        assert_eq!(Message::pack(&msg, &mut out[..len - 1], 0).err(), Some(SmallBuf));
+    }
+
+    // names are compressed the way the upstream servers did
+    repack("2b22818000010001000000000377777706676f6f676c6503636f6d0000010001c00c00010001000000bc0004d83ad044");
+    repack(BBC);
+
+    // pointers are relative to the start of the message
+    let buf = BBC.from_hex().unwrap();
+    let msg = Message::unpack(&buf, 0).unwrap();
+    let mut out = [0; 1024];
+    let len = msg.pack(&mut out, 2).unwrap();
+    assert_eq!(&out[2..len], &buf[..]);
+    assert_eq!(Message::unpack(&out[2..len], 0).unwrap().answers, msg.answers);
+}
+
+#[test]
+fn build_messages() {
+    let query = MessageBuilder::query("www.example.com".parse().unwrap(), A)
+        .id(7)
+        .edns(Edns{ dnssec_ok: true, ..Edns::default() })
+        .build();
+    assert_eq!((query.id, query.opcode, query.qr, query.rd), (7, QUERY, false, true));
+    assert_eq!(query.questions, vec![q("www.example.com", A, IN)]);
+
+    let reply = MessageBuilder::reply_to(&query)
+        .aa(true)
+        .answer("www.example.com. 300 IN CNAME web".parse().unwrap())
+        .answer("web.example.com. 300 IN A 192.0.2.1".parse().unwrap())
+        .authority("example.com. 300 IN NS ns.example.com.".parse().unwrap())
+        .additional("ns.example.com. 300 IN AAAA 2001:db8::53".parse().unwrap())
+        .build();
+    assert_eq!((reply.id, reply.qr, reply.aa, reply.rd, reply.ra), (7, true, true, true, true));
+    assert_eq!(reply.questions, query.questions);
+    assert_eq!(reply.answers[0].data, RData::CNAME("web".parse().unwrap()));
+    assert_eq!(reply.answers[1].data, RData::A(192, 0, 2, 1));
+    assert_eq!((reply.authority.len(), reply.additionals.len()), (1, 1));
+    assert_eq!(reply.edns, Some(Edns::default()));
+
+    let mut buf = [0; 512];
+    let len = reply.pack(&mut buf, 0).unwrap();
+    let unpacked = Message::unpack(&buf[..len], 0).unwrap();
+    assert_eq!(unpacked.answers, reply.answers);
+
+    let refused = MessageBuilder::reply_to(&query).rcode(REFUSED).build();
+    assert_eq!(refused.rcode, REFUSED);
+    assert_eq!(refused.answers, vec![]);
+}
+
+#[test]
+fn parse_resources_from_str() {
+    assert_eq!("www 300 IN A 1.2.3.4".parse::<Resource>().unwrap(),
+               r("www", A, IN, 300, RData::A(1, 2, 3, 4)));
+    assert_eq!("example.com. 1h MX 10 mail.example.com.".parse::<Resource>().unwrap(),
+               r("example.com", MX, IN, 3600, RData::MX(10, "mail.example.com".parse().unwrap())));
+    assert_eq!("www IN A 1.2.3.4".parse::<Resource>(), Err(BadTtl));
+    assert_eq!("www 300 IN A 1.2.3".parse::<Resource>(), Err(BadToken("1.2.3".to_string())));
+    assert_eq!("a 1 A 1.2.3.4\nb 1 A 1.2.3.4".parse::<Resource>(), Err(BadSyntax));
+}
+
+#[test]
+fn display_resources() {
+    let display = |s: &str| s.parse::<Resource>().unwrap().to_string();
+    assert_eq!(display("www.example.com. 300 IN A 192.0.2.1"), "www.example.com.\t300\tIN\tA\t192.0.2.1");
+    assert_eq!(display("example.com. 60 IN MX 10 mail.example.com."),
+               "example.com.\t60\tIN\tMX\t10 mail.example.com.");
+    assert_eq!(display("example.com. 60 IN SOA ns hostmaster 1 7200 900 1209600 300"),
+               "example.com.\t60\tIN\tSOA\tns. hostmaster. 1 7200 900 1209600 300");
+    assert_eq!(display("txt. 60 IN TXT \"v=spf1 -all\" \"say \\\"hi\\\"\\009\""),
+               "txt.\t60\tIN\tTXT\t\"v=spf1 -all\" \"say \\\"hi\\\"\\009\"");
+    assert_eq!(display("example. 60 IN HINFO \\# 2 abcd"), "example.\t60\tIN\tHINFO\t\\# 2 abcd");
+
+    let mut txt = r("txt", TXT, IN, 60, RData::RawData(vec![5, b'b', b'a', b'd']));
+    assert_eq!(txt.to_string(), "txt.\t60\tIN\tTXT\t\\# 4 05626164");
+    txt.data = RData::None;
+    assert_eq!(txt.to_string(), "txt.\t60\tIN\tTXT\t\\# 0");
+}
+
+#[test]
+fn display_messages() {
+    let query = MessageBuilder::query("example.com".parse().unwrap(), MX).id(7).build();
+    let reply = MessageBuilder::reply_to(&query)
+        .answer("example.com. 300 IN MX 10 mail.example.com.".parse().unwrap())
+        .additional("mail.example.com. 300 IN A 192.0.2.25".parse().unwrap())
+        .build();
+    let text = reply.to_string();
+    assert!(text.contains(";; ANSWER SECTION:\nexample.com.\t300\tIN\tMX\t10 mail.example.com.\n"));
+    assert!(text.contains(";; ADDITIONAL SECTION:\nmail.example.com.\t300\tIN\tA\t192.0.2.25\n"));
+}
+
+#[test]
+fn unpack_service_binding_queries() {
+    // www.example.com IN HTTPS, as browsers send it
+    let msg = Message::unpack(&"12340100000100000000000003777777076578616d706c6503636f6d0000410001".from_hex().unwrap(), 0).unwrap();
+    assert_eq!(msg.questions, vec![q("www.example.com", HTTPS, IN)]);
+    assert_eq!(RType::from_str("SVCB"), Ok(SVCB));
 }
@@ -12,8 +12,17 @@ pub mod types;
 pub mod rname;
 pub mod rdata;
 pub mod message;
+pub mod master;
+pub mod base64;
+pub mod base32;
+pub mod idna;
+mod idna_tables;
+pub mod view;
+pub mod dnssec;
+pub mod service;
+pub mod txt;
 
-const MAX_LABEL_LEN: usize = 63;
+pub const MAX_LABEL_LEN: usize = 63;
 const MAX_DOMAIN_LEN: usize = 255;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -31,4 +40,9 @@ pub enum Error {
     TooManyCompressionPointers,
     DomainOverflow,
     EmptyLabel,
+    BadTtl,
+    BadSyntax,
+    BadDirective,
+    BadIdna,
+    BadToken(String),
 }
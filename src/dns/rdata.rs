@@ -1,30 +1,84 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::ptr::copy_nonoverlapping;
+use std::str::FromStr;
 
-use dns::Result;
+use dns::{Error, Result, RName, RType};
+use dns::rname::Compressor;
+use dns::master::{Tokenizer, Fields};
+use dns::dnssec::{Dnskey, Ds, Rrsig, Nsec, Nsec3, Nsec3Param, hex};
+use dns::service::{Srv, Naptr, Uri, Caa, Sshfp, Tlsa, Svcb, parse_openpgpkey};
+use dns::txt::Txt;
+use dns::base64;
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum RData {
     None,
     A(u8, u8, u8, u8), // replace with u32 or u16, u16
     AAAA(u16, u16, u16, u16, u16, u16, u16, u16), // replace with u64, u64?
+    NS(RName),
+    CNAME(RName),
+    PTR(RName),
+    MX(u16, RName),
+    SOA {
+        mname: RName,
+        rname: RName,
+        serial: u32,
+        refresh: u32,
+        retry: u32,
+        expire: u32,
+        minimum: u32,
+    },
+    TXT(Txt),
+    SPF(Txt),
+    DS(Ds),
+    RRSIG(Rrsig),
+    NSEC(Nsec),
+    DNSKEY(Dnskey),
+    NSEC3(Nsec3),
+    NSEC3PARAM(Nsec3Param),
+    CDS(Ds),
+    CDNSKEY(Dnskey),
+    SRV(Srv),
+    NAPTR(Naptr),
+    URI(Uri),
+    CAA(Caa),
+    SSHFP(Sshfp),
+    TLSA(Tlsa),
+    OPENPGPKEY(Vec<u8>),
+    SVCB(Svcb),
+    HTTPS(Svcb),
     RawData(Vec<u8>),
 }
 
 impl RData {
 
-    #[allow(dead_code)]
+    /// The uncompressed length of the data.
     #[inline]
-    fn len(&self) -> usize {
+    pub fn len(&self) -> usize {
         match *self {
             RData::None => { 0 },
             RData::A(..) => { 4 },
             RData::AAAA(..) => { 16 },
-            RData::RawData(ref v) => { v.len() }
+            RData::NS(ref n) | RData::CNAME(ref n) | RData::PTR(ref n) => { n.len() + 1 },
+            RData::MX(_, ref n) => { 2 + n.len() + 1 },
+            RData::SOA{ ref mname, ref rname, .. } => { mname.len() + 1 + rname.len() + 1 + 20 },
+            RData::DS(ref d) | RData::CDS(ref d) => { d.len() },
+            RData::RRSIG(ref d) => { d.len() },
+            RData::NSEC(ref d) => { d.len() },
+            RData::DNSKEY(ref d) | RData::CDNSKEY(ref d) => { d.len() },
+            RData::NSEC3(ref d) => { d.len() },
+            RData::NSEC3PARAM(ref d) => { d.len() },
+            RData::TXT(ref d) | RData::SPF(ref d) => { d.len() },
+            RData::SRV(ref d) => { d.len() },
+            RData::NAPTR(ref d) => { d.len() },
+            RData::URI(ref d) => { d.len() },
+            RData::CAA(ref d) => { d.len() },
+            RData::SSHFP(ref d) => { d.len() },
+            RData::TLSA(ref d) => { d.len() },
+            RData::SVCB(ref d) | RData::HTTPS(ref d) => { d.len() },
+            RData::OPENPGPKEY(ref v) | RData::RawData(ref v) => { v.len() }
             // todo
-            // CNAME
-            // TXT
-
-
             // DNAME
             // INT8
             // INT16
@@ -34,7 +88,6 @@ impl RData {
             // B64
             // B64_EXT
             // HEX
-            // NSEC
             // TYPE
             // CLASS
             // TIME
@@ -47,52 +100,396 @@ impl RData {
     }
 
     pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
-        Ok(match *self {
-            RData::None => offset,
+        self.pack_compressed(buf, offset, &mut Compressor::none())
+    }
+
+    /// Packs RDLENGTH and the data, compressing the names of the types
+    /// defined in RFC 1035.
+    pub fn pack_compressed(&self, buf: &mut [u8], offset: usize, names: &mut Compressor) -> Result<usize> {
+        if offset + 2 + self.len() > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        let start = offset + 2;
+        let end = match *self {
+            RData::None => start,
             RData::A(a1, a2, a3, a4) => {
-                unsafe{
-                    write_be!(buf, offset, 4 as u16, 2);
-                }
-                buf[offset + 2] = a1;
-                buf[offset + 3] = a2;
-                buf[offset + 4] = a3;
-                buf[offset + 5] = a4;
-                offset + 6
+                buf[start] = a1;
+                buf[start + 1] = a2;
+                buf[start + 2] = a3;
+                buf[start + 3] = a4;
+                start + 4
             },
             RData::AAAA(a1, a2, a3, a4, a5, a6, a7, a8) => {
-                unsafe{
-                    write_be!(buf, offset, 16 as u16, 2);
+                for (i, a) in [a1, a2, a3, a4, a5, a6, a7, a8].iter().enumerate() {
+                    buf[start + 2*i] = (a >> 8) as u8;
+                    buf[start + 2*i + 1] = (a & 0xff) as u8;
                 }
-                buf[offset +  2] = (a1 >> 8) as u8;
-                buf[offset +  3] = (a1 & 0xff) as u8;
-                buf[offset +  4] = (a2 >> 8) as u8;
-                buf[offset +  5] = (a2 & 0xff) as u8;
-                buf[offset +  6] = (a3 >> 8) as u8;
-                buf[offset +  7] = (a3 & 0xff) as u8;
-                buf[offset +  8] = (a4 >> 8) as u8;
-                buf[offset +  9] = (a4 & 0xff) as u8;
-                buf[offset + 10] = (a5 >> 8) as u8;
-                buf[offset + 11] = (a5 & 0xff) as u8;
-                buf[offset + 12] = (a6 >> 8) as u8;
-                buf[offset + 13] = (a6 & 0xff) as u8;
-                buf[offset + 14] = (a7 >> 8) as u8;
-                buf[offset + 15] = (a7 & 0xff) as u8;
-                buf[offset + 16] = (a8 >> 8) as u8;
-                buf[offset + 17] = (a8 & 0xff) as u8;
-                offset + 18
+                start + 16
             },
-            RData::RawData(ref v) => {
-                let len = v.len();
+            RData::NS(ref n) | RData::CNAME(ref n) | RData::PTR(ref n) => {
+                try!(n.pack_compressed(buf, start, names))
+            },
+            RData::MX(pref, ref n) => {
                 unsafe {
-                    write_be!(buf, offset, len as u16, 2);
-                    copy_nonoverlapping(v.as_ptr(), buf.as_mut_ptr().offset((offset+2) as isize), len);
+                    write_be!(buf, start, pref, 2);
+                }
+                try!(n.pack_compressed(buf, start + 2, names))
+            },
+            RData::SOA{ ref mname, ref rname, serial, refresh, retry, expire, minimum } => {
+                let off = try!(mname.pack_compressed(buf, start, names));
+                let off = try!(rname.pack_compressed(buf, off, names));
+                unsafe {
+                    write_be!(buf, off, serial, 4);
+                    write_be!(buf, off + 4, refresh, 4);
+                    write_be!(buf, off + 8, retry, 4);
+                    write_be!(buf, off + 12, expire, 4);
+                    write_be!(buf, off + 16, minimum, 4);
+                }
+                off + 20
+            },
+            RData::DS(ref d) | RData::CDS(ref d) => try!(d.pack(buf, start)),
+            RData::RRSIG(ref d) => try!(d.pack(buf, start)),
+            RData::NSEC(ref d) => try!(d.pack(buf, start)),
+            RData::DNSKEY(ref d) | RData::CDNSKEY(ref d) => try!(d.pack(buf, start)),
+            RData::NSEC3(ref d) => try!(d.pack(buf, start)),
+            RData::NSEC3PARAM(ref d) => try!(d.pack(buf, start)),
+            RData::TXT(ref d) | RData::SPF(ref d) => try!(d.pack(buf, start)),
+            RData::SRV(ref d) => try!(d.pack(buf, start)),
+            RData::NAPTR(ref d) => try!(d.pack(buf, start)),
+            RData::URI(ref d) => try!(d.pack(buf, start)),
+            RData::CAA(ref d) => try!(d.pack(buf, start)),
+            RData::SSHFP(ref d) => try!(d.pack(buf, start)),
+            RData::TLSA(ref d) => try!(d.pack(buf, start)),
+            RData::SVCB(ref d) | RData::HTTPS(ref d) => try!(d.pack(buf, start)),
+            RData::OPENPGPKEY(ref v) | RData::RawData(ref v) => {
+                unsafe {
+                    copy_nonoverlapping(v.as_ptr(), buf.as_mut_ptr().offset(start as isize), v.len());
+                }
+                start + v.len()
+            }
+        };
+        unsafe {
+            write_be!(buf, offset, (end - start) as u16, 2);
+        }
+        Ok(end)
+    }
+
+    /// Reads `len` bytes of `rtype` data at `offset` in `msg`, following
+    /// compression pointers in the names of the RFC 1035 types. Types
+    /// without a representation of their own are kept as raw bytes, and
+    /// empty data, as in the prerequisites of an UPDATE, is `None`.
+    pub fn unpack(rtype: RType, msg: &[u8], offset: usize, len: usize) -> Result<RData> {
+        let end = offset + len;
+        if end > msg.len() {
+            return Err(Error::ShortRead)
+        }
+        if len == 0 {
+            return Ok(RData::None)
+        }
+        let rdata = &msg[offset..end];
+        let name = |off: usize| -> Result<(RName, usize)> {
+            let (name, off) = try!(RName::unpack(msg, off));
+            if off > end {
+                return Err(Error::BadRdata)
+            }
+            Ok((name, off))
+        };
+        let (data, off) = match rtype {
+            RType::A if len == 4 => {
+                (RData::A(rdata[0], rdata[1], rdata[2], rdata[3]), end)
+            },
+            RType::AAAA if len == 16 => {
+                let a = |i: usize| (rdata[2*i] as u16) << 8 | rdata[2*i + 1] as u16;
+                (RData::AAAA(a(0), a(1), a(2), a(3), a(4), a(5), a(6), a(7)), end)
+            },
+            RType::A | RType::AAAA => return Err(Error::BadRdata),
+            RType::NS => { let (n, off) = try!(name(offset)); (RData::NS(n), off) },
+            RType::CNAME => { let (n, off) = try!(name(offset)); (RData::CNAME(n), off) },
+            RType::PTR => { let (n, off) = try!(name(offset)); (RData::PTR(n), off) },
+            RType::MX => {
+                if len < 3 {
+                    return Err(Error::BadRdata)
+                }
+                let pref = (rdata[0] as u16) << 8 | rdata[1] as u16;
+                let (n, off) = try!(name(offset + 2));
+                (RData::MX(pref, n), off)
+            },
+            RType::SOA => {
+                let (mname, off) = try!(name(offset));
+                let (rname, off) = try!(name(off));
+                if off + 20 != end {
+                    return Err(Error::BadRdata)
+                }
+                let int = |i: usize| unsafe { read_be!(msg, off + 4*i, u32) };
+                (RData::SOA{
+                    mname: mname,
+                    rname: rname,
+                    serial: int(0),
+                    refresh: int(1),
+                    retry: int(2),
+                    expire: int(3),
+                    minimum: int(4),
+                }, end)
+            },
+            RType::TXT => (RData::TXT(try!(Txt::unpack(rdata))), end),
+            RType::SPF => (RData::SPF(try!(Txt::unpack(rdata))), end),
+            RType::DS => (RData::DS(try!(Ds::unpack(rdata))), end),
+            RType::CDS => (RData::CDS(try!(Ds::unpack(rdata))), end),
+            RType::RRSIG => (RData::RRSIG(try!(Rrsig::unpack(rdata))), end),
+            RType::NSEC => (RData::NSEC(try!(Nsec::unpack(rdata))), end),
+            RType::DNSKEY => (RData::DNSKEY(try!(Dnskey::unpack(rdata))), end),
+            RType::CDNSKEY => (RData::CDNSKEY(try!(Dnskey::unpack(rdata))), end),
+            RType::NSEC3 => (RData::NSEC3(try!(Nsec3::unpack(rdata))), end),
+            RType::NSEC3PARAM => (RData::NSEC3PARAM(try!(Nsec3Param::unpack(rdata))), end),
+            RType::SRV => (RData::SRV(try!(Srv::unpack(msg, offset, end))), end),
+            RType::NAPTR => (RData::NAPTR(try!(Naptr::unpack(msg, offset, end))), end),
+            RType::URI => (RData::URI(try!(Uri::unpack(rdata))), end),
+            RType::CAA => (RData::CAA(try!(Caa::unpack(rdata))), end),
+            RType::SSHFP => (RData::SSHFP(try!(Sshfp::unpack(rdata))), end),
+            RType::TLSA => (RData::TLSA(try!(Tlsa::unpack(rdata))), end),
+            RType::OPENPGPKEY => (RData::OPENPGPKEY(rdata.to_vec()), end),
+            RType::SVCB => (RData::SVCB(try!(Svcb::unpack(rdata))), end),
+            RType::HTTPS => (RData::HTTPS(try!(Svcb::unpack(rdata))), end),
+            _ => (RData::RawData(rdata.to_vec()), end),
+        };
+        if off != end {
+            return Err(Error::BadRdata)
+        }
+        Ok(data)
+    }
+
+    /// Parses `rtype` data in presentation format, such as `10 mail` for
+    /// MX, with relative names relative to `origin`. The text may hold
+    /// quoted strings, escapes, comments and parentheses as in a master
+    /// file but only a single record's data.
+    pub fn from_str(rtype: RType, s: &str, origin: &RName) -> Result<RData> {
+        let mut lines = Tokenizer::new(s);
+        let tokens = match (lines.next(), lines.next()) {
+            (Some(line), None) => try!(line).tokens,
+            (None, _) => vec![],
+            _ => return Err(Error::BadSyntax),
+        };
+        RData::parse(rtype, &tokens, origin)
+    }
+
+    /// Parses the data of a master file entry from its tokens. Types
+    /// without a typed representation are accepted in the RFC 3597 generic
+    /// `\# length hex` form only.
+    pub fn parse(rtype: RType, tokens: &[String], origin: &RName) -> Result<RData> {
+        if tokens.first().map(|s| &s[..]) == Some("\\#") {
+            return generic(&tokens[1..])
+        }
+        let mut t = Fields::new(tokens);
+        let data = match rtype {
+            RType::A => {
+                let o = try!(t.field(|s| Ipv4Addr::from_str(s).map_err(|_| Error::BadRdata))).octets();
+                RData::A(o[0], o[1], o[2], o[3])
+            }
+            RType::AAAA => {
+                let s = try!(t.field(|s| Ipv6Addr::from_str(s).map_err(|_| Error::BadRdata))).segments();
+                RData::AAAA(s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7])
+            }
+            RType::NS => RData::NS(try!(t.name(origin))),
+            RType::CNAME => RData::CNAME(try!(t.name(origin))),
+            RType::PTR => RData::PTR(try!(t.name(origin))),
+            RType::MX => RData::MX(try!(t.number()), try!(t.name(origin))),
+            RType::SOA => RData::SOA{
+                mname: try!(t.name(origin)),
+                rname: try!(t.name(origin)),
+                serial: try!(t.number()),
+                refresh: try!(t.ttl()),
+                retry: try!(t.ttl()),
+                expire: try!(t.ttl()),
+                minimum: try!(t.ttl()),
+            },
+            RType::TXT => return Txt::parse(tokens).map(RData::TXT),
+            RType::SPF => return Txt::parse(tokens).map(RData::SPF),
+            RType::DS => return Ds::parse(tokens).map(RData::DS),
+            RType::CDS => return Ds::parse(tokens).map(RData::CDS),
+            RType::RRSIG => return Rrsig::parse(tokens, origin).map(RData::RRSIG),
+            RType::NSEC => return Nsec::parse(tokens, origin).map(RData::NSEC),
+            RType::DNSKEY => return Dnskey::parse(tokens).map(RData::DNSKEY),
+            RType::CDNSKEY => return Dnskey::parse(tokens).map(RData::CDNSKEY),
+            RType::NSEC3 => return Nsec3::parse(tokens).map(RData::NSEC3),
+            RType::NSEC3PARAM => return Nsec3Param::parse(tokens).map(RData::NSEC3PARAM),
+            RType::SRV => return Srv::parse(tokens, origin).map(RData::SRV),
+            RType::NAPTR => return Naptr::parse(tokens, origin).map(RData::NAPTR),
+            RType::URI => return Uri::parse(tokens).map(RData::URI),
+            RType::CAA => return Caa::parse(tokens).map(RData::CAA),
+            RType::SSHFP => return Sshfp::parse(tokens).map(RData::SSHFP),
+            RType::TLSA => return Tlsa::parse(tokens).map(RData::TLSA),
+            RType::OPENPGPKEY => return parse_openpgpkey(tokens).map(RData::OPENPGPKEY),
+            RType::SVCB => return Svcb::parse(tokens, origin).map(RData::SVCB),
+            RType::HTTPS => return Svcb::parse(tokens, origin).map(RData::HTTPS),
+            _ => return Err(Error::BadRdata),
+        };
+        try!(t.finish());
+        Ok(data)
+    }
+}
+
+// `\# <length> <hex>...` (RFC 3597 §5)
+fn generic(tokens: &[String]) -> Result<RData> {
+    let mut t = Fields::new(tokens);
+    let len: usize = try!(t.number());
+    if len == 0 {
+        try!(t.finish());
+        return Ok(RData::RawData(vec![]))
+    }
+    let data = try!(t.rest(hex));
+    if data.len() != len {
+        return Err(Error::BadRdata)
+    }
+    Ok(RData::RawData(data))
+}
+
+/// The presentation format of RFC 1035 §5.1. Data without a typed
+/// representation is written in the generic form of RFC 3597 §5.
+impl fmt::Display for RData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RData::None => write!(f, "\\# 0"),
+            RData::A(a, b, c, d) => write!(f, "{}", Ipv4Addr::new(a, b, c, d)),
+            RData::AAAA(a, b, c, d, e, g, h, i) => write!(f, "{}", Ipv6Addr::new(a, b, c, d, e, g, h, i)),
+            RData::NS(ref n) | RData::CNAME(ref n) | RData::PTR(ref n) => write!(f, "{}", n),
+            RData::MX(pref, ref n) => write!(f, "{} {}", pref, n),
+            RData::SOA{ ref mname, ref rname, serial, refresh, retry, expire, minimum } => {
+                write!(f, "{} {} {} {} {} {} {}", mname, rname, serial, refresh, retry, expire, minimum)
+            }
+            RData::TXT(ref d) | RData::SPF(ref d) => write!(f, "{}", d),
+            RData::DS(ref d) | RData::CDS(ref d) => write!(f, "{}", d),
+            RData::RRSIG(ref d) => write!(f, "{}", d),
+            RData::NSEC(ref d) => write!(f, "{}", d),
+            RData::DNSKEY(ref d) | RData::CDNSKEY(ref d) => write!(f, "{}", d),
+            RData::NSEC3(ref d) => write!(f, "{}", d),
+            RData::NSEC3PARAM(ref d) => write!(f, "{}", d),
+            RData::SRV(ref d) => write!(f, "{}", d),
+            RData::NAPTR(ref d) => write!(f, "{}", d),
+            RData::URI(ref d) => write!(f, "{}", d),
+            RData::CAA(ref d) => write!(f, "{}", d),
+            RData::SSHFP(ref d) => write!(f, "{}", d),
+            RData::TLSA(ref d) => write!(f, "{}", d),
+            RData::SVCB(ref d) | RData::HTTPS(ref d) => write!(f, "{}", d),
+            RData::OPENPGPKEY(ref v) => write!(f, "{}", base64::encode(v)),
+            RData::RawData(ref v) => {
+                try!(write!(f, "\\# {}", v.len()));
+                if !v.is_empty() {
+                    try!(write!(f, " "));
+                }
+                for b in v.iter() {
+                    try!(write!(f, "{:02x}", b));
                 }
-                offset + 2 + len
+                Ok(())
             }
+        }
+    }
+}
+
+/// A character-string in quotes, with quotes and backslashes escaped and
+/// bytes other than printable ASCII written as `\DDD`.
+pub fn quote(s: &[u8]) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for &c in s.iter() {
+        match c {
+            b'"' | b'\\' => {
+                out.push('\\');
+                out.push(c as char);
+            }
+            0x20...0x7e => out.push(c as char),
+            _ => out.push_str(&format!("\\{:03}", c)),
+        }
+    }
+    out.push('"');
+    out
+}
+
+
+
+#[test]
+fn display_rdata() {
+    let name = |s: &str| RName::from_str(s).unwrap();
+    assert_eq!(RData::A(192, 0, 2, 1).to_string(), "192.0.2.1");
+    assert_eq!(RData::AAAA(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1).to_string(), "2001:db8::1");
+    assert_eq!(RData::CNAME(name("www.example.com")).to_string(), "www.example.com.");
+    assert_eq!(RData::MX(10, name("mail.example.com")).to_string(), "10 mail.example.com.");
+    assert_eq!(RData::SOA{
+        mname: name("ns.example.com"),
+        rname: name("hostmaster.example.com"),
+        serial: 2017010101,
+        refresh: 7200,
+        retry: 900,
+        expire: 1209600,
+        minimum: 300,
+    }.to_string(), "ns.example.com. hostmaster.example.com. 2017010101 7200 900 1209600 300");
+    assert_eq!(RData::DS(Ds{ key_tag: 60485, algorithm: 5, digest_type: 1, digest: vec![0x2b, 0xb1, 0x83] }).to_string(),
+               "60485 5 1 2BB183");
+    assert_eq!(RData::DNSKEY(Dnskey{ flags: 256, protocol: 3, algorithm: 15, public_key: b"key".to_vec() }).to_string(),
+               "256 3 15 a2V5");
+    assert_eq!(RData::RawData(vec![0xc0, 0, 2, 1]).to_string(), "\\# 4 c0000201");
+    assert_eq!(RData::None.to_string(), "\\# 0");
+}
+
+#[test]
+fn quote_character_strings() {
+    assert_eq!(quote(b"hello world"), "\"hello world\"");
+    assert_eq!(quote(b"a \"b\" \\c"), "\"a \\\"b\\\" \\\\c\"");
+    assert_eq!(quote(b"tab\tnul\x00\xff"), "\"tab\\009nul\\000\\255\"");
+}
+
+#[test]
+fn parse_rdata_from_str() {
+    let origin = RName::from_str("example.com").unwrap();
+    let parse = |rtype: RType, s: &str| RData::from_str(rtype, s, &origin);
+    let name = |s: &str| RName::from_str(s).unwrap();
+
+    assert_eq!(parse(RType::A, "192.0.2.1"), Ok(RData::A(192, 0, 2, 1)));
+    assert_eq!(parse(RType::AAAA, "2001:db8::1"), Ok(RData::AAAA(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)));
+    assert_eq!(parse(RType::MX, "10 mail"), Ok(RData::MX(10, name("mail.example.com"))));
+    assert_eq!(parse(RType::CNAME, "@"), Ok(RData::CNAME(origin.clone())));
+    assert_eq!(parse(RType::NS, "ns.example.net."), Ok(RData::NS(name("ns.example.net"))));
+    assert_eq!(parse(RType::SOA, "ns hostmaster ( 2017010101 ; serial\n 2h 15m 2w 5m )"), Ok(RData::SOA{
+        mname: name("ns.example.com"),
+        rname: name("hostmaster.example.com"),
+        serial: 2017010101,
+        refresh: 7200,
+        retry: 900,
+        expire: 1209600,
+        minimum: 300,
+    }));
+    assert_eq!(parse(RType::TXT, "\"v=spf1 -all\" \"a \\\"b\\\"\\009\" c\\ d"),
+               Ok(RData::TXT(Txt{ strings: vec![b"v=spf1 -all".to_vec(), b"a \"b\"\x09".to_vec(), b"c d".to_vec()] })));
+    assert_eq!(parse(RType::DS, "60485 5 1 ( 2BB183AF5F22588179A53B0A\n 98631FAD1A292118 )").map(|d| d.len()),
+               Ok(24));
+    assert_eq!(parse(RType::HINFO, "\\# 3 abcdef"), Ok(RData::RawData(vec![0xab, 0xcd, 0xef])));
+    assert_eq!(parse(RType::HINFO, "\\# 0"), Ok(RData::RawData(vec![])));
 
-        })
+    // printed data reads back
+    let samples = [
+        (RType::SOA, "ns.example.com. hostmaster.example.com. 1 7200 900 1209600 300"),
+        (RType::DNSKEY, "257 3 15 l02Woi0iS8Aa25FQkUd9RMzZHJpBoRQwAQEX1SxZJA4="),
+        (RType::RRSIG, "A 15 2 3600 20170201000000 20170101000000 2642 example.com. a2V5"),
+        (RType::NSEC, "host.example.com. A MX RRSIG NSEC TYPE1234"),
+        (RType::NSEC3, "1 1 12 aabbccdd 2t7b4g4vsa5smi47k61mv5bv1a22bojr NS SOA MX RRSIG DNSKEY NSEC3PARAM"),
+        (RType::NSEC3PARAM, "1 0 0 -"),
+    ];
+    for &(rtype, text) in samples.iter() {
+        assert_eq!(parse(rtype, text).unwrap().to_string(), text);
     }
 
-    // fn unpack(rtype: RType, msg: &[u8], mut off: usize) -> Result<(RName, usize)> {
-    // }
+    // errors name the token at fault
+    assert_eq!(parse(RType::A, "1.2.3"), Err(Error::BadToken("1.2.3".to_string())));
+    assert_eq!(parse(RType::MX, "ten mail"), Err(Error::BadToken("ten".to_string())));
+    assert_eq!(parse(RType::MX, "10 mail extra"), Err(Error::BadToken("extra".to_string())));
+    assert_eq!(parse(RType::SOA, "ns hm 1 2 3 4 5x"), Err(Error::BadToken("5x".to_string())));
+    assert_eq!(parse(RType::DS, "60485 5 1 2BB18"), Err(Error::BadToken("2BB18".to_string())));
+    assert_eq!(parse(RType::NSEC, "host A BOGUS"), Err(Error::BadToken("BOGUS".to_string())));
+    assert_eq!(parse(RType::NSEC3PARAM, "1 0 0 - 1"), Err(Error::BadToken("1".to_string())));
+    assert_eq!(parse(RType::TXT, "\"bad\\2\""), Err(Error::BadToken("\"bad\\2\"".to_string())));
+    assert_eq!(parse(RType::MX, "10"), Err(Error::BadRdata));
+    assert_eq!(parse(RType::TXT, ""), Err(Error::BadRdata));
+    assert_eq!(parse(RType::HINFO, "\\# 4 abcdef"), Err(Error::BadRdata));
+    assert_eq!(parse(RType::HINFO, "\"cpu\" \"os\""), Err(Error::BadRdata));
+    assert_eq!(parse(RType::A, "192.0.2.1\n192.0.2.2"), Err(Error::BadSyntax));
+    assert_eq!(parse(RType::A, "( 192.0.2.1"), Err(Error::BadSyntax));
 }
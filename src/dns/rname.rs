@@ -1,13 +1,20 @@
 use std::fmt;
 use std::result;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::collections::HashMap;
 use std::ptr::copy_nonoverlapping;
 use std::iter::FromIterator;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str;
 use std::str::FromStr;
 
 use dns::{Result, Error};
+use dns::idna;
 use dns::{MAX_LABEL_LEN, MAX_DOMAIN_LEN};
 
-#[derive(PartialEq)]
+/// A domain name in wire format without the root label. Names keep the
+/// case they were written in but compare, hash and sort ignoring it.
 pub struct RName {
     inner: Vec<u8>,
 }
@@ -22,9 +29,191 @@ impl RName {
         Vec::from_iter(self.into_iter())
     }
 
+    /// Parses a name that may hold Unicode, converting it to ASCII with
+    /// IDNA (RFC 5891). Fails with `BadIdna` for labels IDNA does not
+    /// allow.
+    pub fn from_unicode(s: &str) -> Result<RName> {
+        idna::to_ascii(s).and_then(|s| s.parse())
+    }
+
+    /// The name as text with its `xn--` labels shown in Unicode, for
+    /// people to read rather than to parse back.
+    pub fn to_unicode(&self) -> String {
+        if self.inner.is_empty() {
+            return ".".to_string()
+        }
+        let mut buf = Vec::with_capacity(self.len() + 1);
+        for label in self.labels() {
+            match idna::to_unicode(label) {
+                Some(unicode) => buf.extend_from_slice(unicode.as_bytes()),
+                None => { write_label(&mut buf, label, 0, label.len()); }
+            }
+            buf.push(b'.');
+        }
+        unsafe { String::from_utf8_unchecked(buf) }
+    }
+
+    /// The name looked up for the PTR records of an address, in
+    /// `in-addr.arpa` or, one label per nibble, in `ip6.arpa` (RFC 3596
+    /// §2.5).
+    pub fn from_ip(ip: IpAddr) -> RName {
+        let mut inner = Vec::with_capacity(72);
+        match ip {
+            IpAddr::V4(ip) => {
+                for b in ip.octets().iter().rev() {
+                    let label = b.to_string();
+                    inner.push(label.len() as u8);
+                    inner.extend_from_slice(label.as_bytes());
+                }
+                inner.extend_from_slice(b"\x07in-addr\x04arpa");
+            }
+            IpAddr::V6(ip) => {
+                for b in ip.octets().iter().rev() {
+                    inner.extend_from_slice(&[1, HEX[(b & 0xf) as usize], 1, HEX[(b >> 4) as usize]]);
+                }
+                inner.extend_from_slice(b"\x03ip6\x04arpa");
+            }
+        }
+        RName{inner: inner}
+    }
+
+    /// The address a reverse name stands for and how many of its leading
+    /// bits the name gives: all of them for the name of a host, fewer for
+    /// the apex of a reverse zone such as `2.0.192.in-addr.arpa` (/24).
+    /// `None` for any other name.
+    pub fn to_ip(&self) -> Option<(IpAddr, u8)> {
+        let mut labels = self.labels().rev();
+        if !labels.next().map_or(false, |l| l.eq_ignore_ascii_case(b"arpa")) {
+            return None
+        }
+        match labels.next() {
+            Some(l) if l.eq_ignore_ascii_case(b"in-addr") => {
+                let mut octets = [0; 4];
+                let mut len = 0;
+                for label in labels {
+                    if len == 4 || label.len() > 1 && label[0] == b'0' ||
+                        !label.iter().all(|c| c.is_ascii_digit()) {
+                        return None
+                    }
+                    octets[len] = match str::from_utf8(label).ok().and_then(|s| s.parse().ok()) {
+                        Some(octet) => octet,
+                        None => return None,
+                    };
+                    len += 1;
+                }
+                Some((IpAddr::V4(Ipv4Addr::from(octets)), len as u8 * 8))
+            }
+            Some(l) if l.eq_ignore_ascii_case(b"ip6") => {
+                let mut octets = [0; 16];
+                let mut len = 0;
+                for label in labels {
+                    let nibble = match (label.len(), (label[0] as char).to_digit(16)) {
+                        (1, Some(nibble)) if len < 32 => nibble as u8,
+                        _ => return None,
+                    };
+                    octets[len / 2] |= if len % 2 == 0 { nibble << 4 } else { nibble };
+                    len += 1;
+                }
+                Some((IpAddr::V6(Ipv6Addr::from(octets)), len as u8 * 4))
+            }
+            _ => None,
+        }
+    }
+
+    /// The labels as they are on the wire, without escaping.
+    pub fn labels<'a>(&'a self) -> Labels<'a> {
+        let mut starts = [0; MAX_LABELS];
+        let mut back = 0;
+        let mut i = 0;
+        while i < self.inner.len() {
+            starts[back] = i as u8;
+            back += 1;
+            i += 1 + self.inner[i] as usize;
+        }
+        Labels{ name: &self.inner, starts: starts, front: 0, back: back }
+    }
+
+    pub fn label_count(&self) -> usize {
+        self.labels().len()
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        self.inner.starts_with(&[1, b'*'])
+    }
+
+    /// The name with its leftmost label removed, `None` for the root.
+    pub fn parent(&self) -> Option<RName> {
+        match self.inner.first() {
+            Some(&len) => Some(RName{inner: self.inner[1 + len as usize..].to_vec()}),
+            None => None,
+        }
+    }
+
+    /// Whether the name is `other` or below it.
+    pub fn is_subdomain_of(&self, other: &RName) -> bool {
+        self.split(other).is_some()
+    }
+
+    /// The longest name both names are subdomains of.
+    pub fn common_ancestor(&self, other: &RName) -> RName {
+        let len: usize = self.labels().rev().zip(other.labels().rev())
+            .take_while(|&(a, b)| a.eq_ignore_ascii_case(b))
+            .map(|(a, _)| a.len() + 1)
+            .sum();
+        RName{inner: self.inner[self.len() - len..].to_vec()}
+    }
+
+    /// The name with `label` added in front of it, as a child.
+    pub fn prepend_label(&self, label: &[u8]) -> Result<RName> {
+        if label.is_empty() {
+            return Err(Error::EmptyLabel)
+        } else if label.len() > MAX_LABEL_LEN || self.len() + label.len() + 1 > MAX_DOMAIN_LEN - 1 {
+            return Err(Error::DomainOverflow)
+        }
+        let mut inner = Vec::with_capacity(self.len() + label.len() + 1);
+        inner.push(label.len() as u8);
+        inner.extend_from_slice(label);
+        inner.extend_from_slice(&self.inner);
+        Ok(RName{inner: inner})
+    }
+
+    /// Makes a name relative to `origin` absolute.
+    pub fn append(&self, origin: &RName) -> Result<RName> {
+        if self.len() + origin.len() > MAX_DOMAIN_LEN - 1 {
+            return Err(Error::DomainOverflow)
+        }
+        let mut inner = Vec::with_capacity(self.len() + origin.len());
+        inner.extend_from_slice(&self.inner);
+        inner.extend_from_slice(&origin.inner);
+        Ok(RName{inner: inner})
+    }
+
+    /// The part of the name relative to `zone`, `None` when the name is not
+    /// at or below it. The apex itself becomes the empty name.
+    pub fn strip_suffix(&self, zone: &RName) -> Option<RName> {
+        self.split(zone).map(|i| RName{inner: self.inner[..i].to_vec()})
+    }
+
+    // Offset of `suffix` within the name, when the name ends with its labels.
+    fn split(&self, suffix: &RName) -> Option<usize> {
+        let mut i = 0;
+        while self.len() - i > suffix.len() {
+            i += 1 + self.inner[i] as usize;
+        }
+        if self.len() - i == suffix.len() && self.inner[i..].eq_ignore_ascii_case(&suffix.inner) {
+            Some(i)
+        } else {
+            None
+        }
+    }
+
     pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
         let len = self.len();
-        if len + 1 > buf.len() {
+        if offset + len + 1 > buf.len() {
             return Err(Error::SmallBuf)
         }
         unsafe {
@@ -34,12 +223,43 @@ impl RName {
         Ok(offset + len + 1)
     }
 
+    /// Packs the name, pointing to an earlier occurrence of its longest
+    /// known suffix instead of repeating it (RFC 1035 §4.1.4).
+    pub fn pack_compressed(&self, buf: &mut [u8], offset: usize, names: &mut Compressor) -> Result<usize> {
+        let name = &self.inner;
+        let mut off = offset;
+        let mut i = 0;
+        while i < name.len() {
+            let suffix = name[i..].to_ascii_lowercase();
+            if let Some(ptr) = names.find(&suffix) {
+                if off + 2 > buf.len() {
+                    return Err(Error::SmallBuf)
+                }
+                buf[off] = 0xc0 | (ptr >> 8) as u8;
+                buf[off + 1] = ptr as u8;
+                return Ok(off + 2)
+            }
+            let len = name[i] as usize + 1;
+            if off + len + 1 > buf.len() {
+                return Err(Error::SmallBuf)
+            }
+            names.insert(suffix, off);
+            buf[off..off + len].copy_from_slice(&name[i..i + len]);
+            off += len;
+            i += len;
+        }
+        if off >= buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        buf[off] = 0;
+        Ok(off + 1)
+    }
+
     pub fn unpack(msg: &[u8], mut offset: usize) -> Result<(RName, usize)> {
         let maxlen = msg.len();
         let mut off1 = offset;
         let mut ptr = 0;
-        let mut len = 0;
-        let mut name = [0; MAX_DOMAIN_LEN-1]; // Ingore last byte \0
+        let mut name = Vec::with_capacity(MAX_DOMAIN_LEN-1); // Ingore last byte \0
         loop {
             if offset >= maxlen {
                 return Err(Error::SmallBuf)
@@ -50,17 +270,12 @@ impl RName {
                 0x00 => {
                     if c == 0 {
                         break
-                    } else if len + c > MAX_DOMAIN_LEN {
+                    } else if name.len() + c + 1 > MAX_DOMAIN_LEN - 1 {
                         return Err(Error::DomainOverflow)
                     } else if offset + c >= maxlen {
                         return Err(Error::SmallBuf)
                     }
-                    unsafe {
-                        copy_nonoverlapping(msg.as_ptr().offset((offset-1) as isize),
-                                            name.as_mut_ptr().offset(len as isize),
-                                            c+1);
-                    }
-                    len += c + 1;
+                    name.extend_from_slice(&msg[offset-1..offset+c]);
                     offset += c;
                 }
                 0xc0 => { // compressed response
@@ -84,7 +299,7 @@ impl RName {
         if ptr == 0 {
             off1 = offset
         }
-        Ok((RName{inner: name[..len].to_vec()}, off1))
+        Ok((RName{inner: name}, off1))
     }
 
     pub fn to_string(&self) -> String {
@@ -130,7 +345,8 @@ impl FromStr for RName {
         let mut l = 0;
 
         while l < maxlen {
-            if off > MAX_DOMAIN_LEN - 1 {
+            // only the dot after the last label goes past the buffer
+            if off > MAX_DOMAIN_LEN - 1 || off == MAX_DOMAIN_LEN - 1 && bytes[l] != b'.' {
                 return Err(Error::DomainOverflow)
             }
             match bytes[l] {
@@ -197,6 +413,86 @@ impl FromStr for RName {
     }
 }
 
+/// Offsets of the names already written to a message, for compression.
+pub struct Compressor {
+    base: usize,
+    enabled: bool,
+    names: HashMap<Vec<u8>, u16>,
+}
+
+impl Compressor {
+
+    /// Compresses names of a message starting at `base` in the buffer.
+    pub fn new(base: usize) -> Compressor {
+        Compressor{ base: base, enabled: true, names: HashMap::new() }
+    }
+
+    /// Writes every name in full.
+    pub fn none() -> Compressor {
+        Compressor{ base: 0, enabled: false, names: HashMap::new() }
+    }
+
+    fn find(&self, suffix: &[u8]) -> Option<u16> {
+        if self.enabled { self.names.get(suffix).cloned() } else { None }
+    }
+
+    fn insert(&mut self, suffix: Vec<u8>, offset: usize) {
+        // pointers only reach the first 16k of a message
+        if self.enabled && offset >= self.base && offset - self.base < 0x4000 {
+            self.names.entry(suffix).or_insert((offset - self.base) as u16);
+        }
+    }
+}
+
+const HEX: &'static [u8] = b"0123456789abcdef";
+
+// The most labels a name can have, each at least two bytes long.
+const MAX_LABELS: usize = MAX_DOMAIN_LEN / 2;
+
+/// Iterates over the labels of a name from the leftmost one, or from the
+/// root when reversed.
+pub struct Labels<'a> {
+    name: &'a [u8],
+    starts: [u8; MAX_LABELS],
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Labels<'a> {
+    fn label(&self, i: usize) -> &'a [u8] {
+        let start = self.starts[i] as usize;
+        &self.name[start + 1..start + 1 + self.name[start] as usize]
+    }
+}
+
+impl<'a> Iterator for Labels<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.front == self.back {
+            return None
+        }
+        self.front += 1;
+        Some(self.label(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Labels<'a> {
+    fn next_back(&mut self) -> Option<&'a [u8]> {
+        if self.front == self.back {
+            return None
+        }
+        self.back -= 1;
+        Some(self.label(self.back))
+    }
+}
+
+impl<'a> ExactSizeIterator for Labels<'a> {}
+
 pub struct RNameIter<'a> {
     name: &'a [u8],
     off: usize,
@@ -273,6 +569,47 @@ impl Clone for RName {
     }
 }
 
+/// Names are equal when their labels are, ignoring ASCII case (RFC 4343
+/// §3).
+impl PartialEq for RName {
+    fn eq(&self, other: &RName) -> bool {
+        self.inner.eq_ignore_ascii_case(&other.inner)
+    }
+}
+
+impl Eq for RName {}
+
+impl Hash for RName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.inner.iter() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+    }
+}
+
+/// The canonical order of DNSSEC (RFC 4034 §6.1): labels are compared from
+/// the root down as lowercase bytes, and a name sorts right before the
+/// names below it.
+impl Ord for RName {
+    fn cmp(&self, other: &RName) -> Ordering {
+        let (a, b) = (self.labels(), other.labels());
+        let (alen, blen) = (a.len(), b.len());
+        for (la, lb) in a.rev().zip(b.rev()) {
+            let lower = la.iter().map(|c| c.to_ascii_lowercase()).cmp(lb.iter().map(|c| c.to_ascii_lowercase()));
+            if lower != Ordering::Equal {
+                return lower
+            }
+        }
+        alen.cmp(&blen)
+    }
+}
+
+impl PartialOrd for RName {
+    fn partial_cmp(&self, other: &RName) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Debug for RName {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.to_string().fmt(f)
@@ -314,12 +651,69 @@ fn parse_valid_rnames_from_str() {
 
 #[test]
 fn parse_invalid_rnames_from_str() {
+    let long = |last: usize| format!("{0}.{0}.{0}.{1}", "a".repeat(63), "a".repeat(last));
+    assert_eq!(RName::from_str(&long(61)).unwrap().len(), 254);
+    assert_eq!(RName::from_str(&(long(61) + ".")).unwrap().len(), 254);
+    assert_eq!(RName::from_str(&long(62)), Err(DomainOverflow));
+    assert_eq!(RName::from_str(&(long(61) + ".a")), Err(DomainOverflow));
     assert_eq!(RName::from_str("example..com"), Err(EmptyLabel));
     assert_eq!(RName::from_str("example.com\\"), Err(BadEscape));
     assert_eq!(RName::from_str("thelongestinvaliddomainnameintheworldandthensomeandthensomemoreandmore.com"), Err(DomainOverflow));
 
 }
 
+#[test]
+fn pack_compressed_rnames() {
+    let mut buf = [0; 64];
+    let mut names = Compressor::new(2);
+    let off = RName::from_str("www.example.com").unwrap().pack_compressed(&mut buf, 2, &mut names).unwrap();
+    assert_eq!(off, 19);
+    let off = RName::from_str("mail.EXAMPLE.com").unwrap().pack_compressed(&mut buf, off, &mut names).unwrap();
+    assert_eq!(&buf[19..off], &[4, b'm', b'a', b'i', b'l', 0xc0, 4]);
+    let end = RName::from_str("www.example.com").unwrap().pack_compressed(&mut buf, off, &mut names).unwrap();
+    assert_eq!(&buf[off..end], &[0xc0, 0]);
+
+    let (name, next) = RName::unpack(&buf[2..], off - 2).unwrap();
+    assert_eq!(name, RName::from_str("www.example.com").unwrap());
+    assert_eq!(next, end - 2);
+
+    let mut names = Compressor::none();
+    let off = RName::from_str("example.com").unwrap().pack_compressed(&mut buf, 0, &mut names).unwrap();
+    let end = RName::from_str("example.com").unwrap().pack_compressed(&mut buf, off, &mut names).unwrap();
+    assert_eq!(end - off, 13);
+
+    assert_eq!(RName::from_str("example.com").unwrap().pack_compressed(&mut buf[..12], 0, &mut Compressor::none()),
+               Err(SmallBuf));
+}
+
+#[test]
+fn unpack_long_compressed_rnames() {
+    // three 63 byte labels, each pointing at the rest of the name, ending
+    // in a label of `last` bytes
+    let name = |last: usize| {
+        let mut msg = vec![last as u8];
+        msg.extend(vec![b'd'; last]);
+        msg.push(0);
+        let mut at = 0;
+        for &b in b"cba" {
+            let next = msg.len();
+            msg.push(63);
+            msg.extend(vec![b; 63]);
+            msg.extend_from_slice(&[0xc0, at as u8]);
+            at = next;
+        }
+        (msg, at)
+    };
+    // 255 bytes on the wire, root label included
+    let (msg, at) = name(61);
+    let (rname, end) = RName::unpack(&msg, at).unwrap();
+    assert_eq!(rname.len(), 254);
+    assert_eq!(end, msg.len());
+    assert_eq!(rname.labels().map(|l| l.len()).collect::<Vec<_>>(), vec![63, 63, 63, 61]);
+    let (msg, at) = name(62);
+    assert_eq!(RName::unpack(&msg, at), Err(DomainOverflow));
+}
+
 #[test]
 fn iterate_rnames() {
     assert_eq!(RName::from_str("www.google.com").unwrap().to_vec(),
@@ -327,3 +721,140 @@ fn iterate_rnames() {
                     "google".to_string(),
                     "com".to_string()])
 }
+
+#[test]
+fn compare_rnames_ignoring_case() {
+    use std::collections::HashSet;
+
+    let name = RName::from_str("WWW.Example.COM").unwrap();
+    assert_eq!(name, RName::from_str("www.example.com.").unwrap());
+    assert!(name != RName::from_str("www.example.net").unwrap());
+    assert!(name != RName::from_str("www.example").unwrap());
+    assert_eq!(name.to_string(), "WWW.Example.COM.");
+
+    let mut names = HashSet::new();
+    names.insert(name);
+    assert!(names.contains(&RName::from_str("www.EXAMPLE.com").unwrap()));
+    assert!(!names.contains(&RName::from_str("example.com").unwrap()));
+}
+
+#[test]
+fn order_rnames_canonically() {
+    // the example of RFC 4034 §6.1
+    let sorted = ["example", "a.example", "yljkjljk.a.example", "Z.a.example", "zABC.a.EXAMPLE",
+                  "z.example", "\\001.z.example", "*.z.example", "\\200.z.example"];
+    let mut names: Vec<RName> = sorted.iter().rev().map(|s| RName::from_str(s).unwrap()).collect();
+    names.sort();
+    let names: Vec<String> = names.iter().map(|n| n.to_string()).collect();
+    let expected: Vec<String> = sorted.iter().map(|s| format!("{}.", s)).collect();
+    assert_eq!(names, expected);
+
+    assert!(RName::from_str(".").unwrap() < RName::from_str("com").unwrap());
+    assert_eq!(RName::from_str("A.b").unwrap().cmp(&RName::from_str("a.B").unwrap()), Ordering::Equal);
+}
+
+#[test]
+fn iterate_rname_labels() {
+    let name = RName::from_str("www.Example.com").unwrap();
+    let labels: Vec<&[u8]> = name.labels().collect();
+    assert_eq!(labels, vec![&b"www"[..], b"Example", b"com"]);
+    let labels: Vec<&[u8]> = name.labels().rev().collect();
+    assert_eq!(labels, vec![&b"com"[..], b"Example", b"www"]);
+
+    let mut labels = name.labels();
+    assert_eq!(labels.len(), 3);
+    assert_eq!(labels.next(), Some(&b"www"[..]));
+    assert_eq!(labels.next_back(), Some(&b"com"[..]));
+    assert_eq!(labels.next(), Some(&b"Example"[..]));
+    assert_eq!(labels.next_back(), None);
+
+    assert_eq!(RName::from_str("a\\.b").unwrap().labels().next(), Some(&b"a.b"[..]));
+    assert_eq!(RName::from_str(".").unwrap().labels().next(), None);
+    assert_eq!(name.label_count(), 3);
+    assert_eq!(RName::from_str(".").unwrap().label_count(), 0);
+}
+
+#[test]
+fn relate_rnames() {
+    let name = |s: &str| RName::from_str(s).unwrap();
+    let www = name("www.Example.com");
+    assert_eq!(www.parent(), Some(name("example.com")));
+    assert_eq!(name("com").parent(), Some(name(".")));
+    assert_eq!(name(".").parent(), None);
+    assert!(name(".").is_root() && !www.is_root());
+
+    assert!(www.is_subdomain_of(&name("example.COM")));
+    assert!(www.is_subdomain_of(&www));
+    assert!(www.is_subdomain_of(&name(".")));
+    assert!(!www.is_subdomain_of(&name("ample.com")));
+    assert!(!name("example.com").is_subdomain_of(&www));
+
+    assert_eq!(www.common_ancestor(&name("mail.example.com")), name("example.com"));
+    assert_eq!(www.common_ancestor(&name("example.net")), name("."));
+    assert_eq!(www.common_ancestor(&name("a.b.www.example.com")), www);
+
+    assert_eq!(www.strip_suffix(&name("example.com")), Some(name("www")));
+    assert_eq!(www.strip_suffix(&www), Some(name(".")));
+    assert_eq!(www.strip_suffix(&name("ample.com")), None);
+}
+
+#[test]
+fn build_rnames() {
+    let name = |s: &str| RName::from_str(s).unwrap();
+    let origin = name("example.com");
+    assert_eq!(origin.prepend_label(b"*").unwrap().to_string(), "*.example.com.");
+    assert!(origin.prepend_label(b"*").unwrap().is_wildcard());
+    assert!(!origin.is_wildcard() && !name("a*.example.com").is_wildcard());
+    assert_eq!(origin.prepend_label(b"a.b").unwrap().to_string(), "a\\.b.example.com.");
+    assert_eq!(origin.prepend_label(b""), Err(EmptyLabel));
+    assert_eq!(origin.prepend_label(&[b'a'; 64]), Err(DomainOverflow));
+
+    assert_eq!(name("www.sub").append(&origin).unwrap(), name("www.sub.example.com"));
+    assert_eq!(name(".").append(&origin).unwrap(), origin);
+    let long = name(&vec!["a".repeat(63); 3].join("."));
+    assert_eq!(long.append(&name("abcdefghi")).unwrap().len(), 202);
+    assert_eq!(long.append(&long), Err(DomainOverflow));
+    assert_eq!(long.append(&name(&"b".repeat(61))).unwrap().len(), 254);
+    assert_eq!(long.append(&name(&"b".repeat(62))), Err(DomainOverflow));
+}
+
+#[test]
+fn reverse_rnames() {
+    let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+    let name = |s: &str| RName::from_str(s).unwrap();
+    assert_eq!(RName::from_ip(ip("192.0.2.10")).to_string(), "10.2.0.192.in-addr.arpa.");
+    assert_eq!(RName::from_ip(ip("2001:db8::567:89ab")).to_string(),
+               "b.a.9.8.7.6.5.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.arpa.");
+
+    for s in &["192.0.2.10", "0.0.0.0", "2001:db8::567:89ab", "::"] {
+        assert_eq!(RName::from_ip(ip(s)).to_ip(), Some((ip(s), if ip(s).is_ipv4() { 32 } else { 128 })));
+    }
+    assert_eq!(name("2.0.192.IN-ADDR.ARPA").to_ip(), Some((ip("192.0.2.0"), 24)));
+    assert_eq!(name("8.B.D.0.1.0.0.2.ip6.arpa").to_ip(), Some((ip("2001:db8::"), 32)));
+    assert_eq!(name("in-addr.arpa").to_ip(), Some((ip("0.0.0.0"), 0)));
+
+    assert_eq!(name("256.2.0.192.in-addr.arpa").to_ip(), None);
+    assert_eq!(name("010.2.0.192.in-addr.arpa").to_ip(), None);
+    assert_eq!(name("0/25.2.0.192.in-addr.arpa").to_ip(), None);
+    assert_eq!(name("1.10.2.0.192.in-addr.arpa").to_ip(), None);
+    assert_eq!(name("g.8.b.d.0.1.0.0.2.ip6.arpa").to_ip(), None);
+    assert_eq!(name("ab.8.b.d.0.1.0.0.2.ip6.arpa").to_ip(), None);
+    assert_eq!(name("example.arpa").to_ip(), None);
+    assert_eq!(name("www.example.com").to_ip(), None);
+}
+
+#[test]
+fn internationalized_rnames() {
+    let name = RName::from_unicode("Bücher.example").unwrap();
+    assert_eq!(name.to_string(), "xn--bcher-kva.example.");
+    assert_eq!(name.to_unicode(), "bücher.example.");
+    assert_eq!(RName::from_str("XN--BCHER-KVA.Example").unwrap().to_unicode(), "bücher.Example.");
+    assert_eq!(RName::from_unicode("www.example.com.").unwrap(), RName::from_str("www.example.com").unwrap());
+    assert_eq!(RName::from_unicode(".").unwrap().to_unicode(), ".");
+
+    // labels that are not valid punycode are shown as they are
+    assert_eq!(RName::from_str("xn--abc-.a\\.b").unwrap().to_unicode(), "xn--abc-.a\\.b.");
+
+    assert_eq!(RName::from_unicode("bü cher.example"), Err(BadIdna));
+    assert_eq!(RName::from_unicode(&format!("{}.example", "ü".repeat(60))), Err(DomainOverflow));
+}
@@ -0,0 +1,977 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use dns::{Error, Result, RName};
+use dns::base64;
+use dns::master::{Fields, unescape};
+use dns::rdata::quote;
+use dns::dnssec::hex;
+
+/// The location of a service (RFC 2782).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Srv {
+    pub priority: u16,
+    pub weight: u16,
+    pub port: u16,
+    pub target: RName,
+}
+
+impl Srv {
+
+    pub fn len(&self) -> usize { 6 + self.target.len() + 1 }
+
+    /// Packs the data; the target is never compressed.
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if offset + 6 > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        put_u16(buf, offset, self.priority);
+        put_u16(buf, offset + 2, self.weight);
+        put_u16(buf, offset + 4, self.port);
+        self.target.pack(buf, offset + 6)
+    }
+
+    /// Reads the data at `offset` in `msg`, where the target may be
+    /// compressed by older servers (RFC 3597 §4).
+    pub fn unpack(msg: &[u8], offset: usize, end: usize) -> Result<Srv> {
+        if offset + 7 > end {
+            return Err(Error::BadRdata)
+        }
+        let (target, off) = try!(RName::unpack(msg, offset + 6));
+        if off != end {
+            return Err(Error::BadRdata)
+        }
+        Ok(Srv{
+            priority: get_u16(msg, offset),
+            weight: get_u16(msg, offset + 2),
+            port: get_u16(msg, offset + 4),
+            target: target,
+        })
+    }
+
+    /// Reads `priority weight port target`.
+    pub fn parse(t: &[String], origin: &RName) -> Result<Srv> {
+        let mut t = Fields::new(t);
+        let srv = Srv{
+            priority: try!(t.number()),
+            weight: try!(t.number()),
+            port: try!(t.number()),
+            target: try!(t.name(origin)),
+        };
+        try!(t.finish());
+        Ok(srv)
+    }
+}
+
+impl fmt::Display for Srv {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.priority, self.weight, self.port, self.target)
+    }
+}
+
+/// Orders the targets of an SRV RRset the way clients try them: by
+/// priority, and within a priority at random in proportion to weight
+/// (RFC 2782). `random` yields uniformly distributed numbers.
+pub fn order<F>(srvs: &mut Vec<Srv>, mut random: F) where F: FnMut() -> u64 {
+    srvs.sort_by_key(|s| s.priority);
+    let mut ordered = Vec::with_capacity(srvs.len());
+    while !srvs.is_empty() {
+        let priority = srvs[0].priority;
+        let end = srvs.iter().take_while(|s| s.priority == priority).count();
+        let mut group: Vec<Srv> = srvs.drain(..end).collect();
+        // targets of weight zero go first so they keep a small chance
+        group.sort_by_key(|s| s.weight != 0);
+        while !group.is_empty() {
+            let total: u64 = group.iter().map(|s| s.weight as u64).sum();
+            let pick = random() % (total + 1);
+            let mut sum = 0;
+            let i = group.iter().position(|s| {
+                sum += s.weight as u64;
+                sum >= pick
+            }).unwrap();
+            ordered.push(group.remove(i));
+        }
+    }
+    *srvs = ordered;
+}
+
+/// A rule of the Dynamic Delegation Discovery System (RFC 3403 §4).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Naptr {
+    pub order: u16,
+    pub preference: u16,
+    pub flags: Vec<u8>,
+    pub services: Vec<u8>,
+    pub regexp: Vec<u8>,
+    pub replacement: RName,
+}
+
+impl Naptr {
+
+    pub fn len(&self) -> usize {
+        4 + 1 + self.flags.len() + 1 + self.services.len() + 1 + self.regexp.len() + self.replacement.len() + 1
+    }
+
+    /// Packs the data; the replacement is never compressed.
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if offset + 4 > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        put_u16(buf, offset, self.order);
+        put_u16(buf, offset + 2, self.preference);
+        let off = try!(put_string(buf, offset + 4, &self.flags));
+        let off = try!(put_string(buf, off, &self.services));
+        let off = try!(put_string(buf, off, &self.regexp));
+        self.replacement.pack(buf, off)
+    }
+
+    /// Reads the data at `offset` in `msg`, where the replacement may be
+    /// compressed by older servers (RFC 3597 §4).
+    pub fn unpack(msg: &[u8], offset: usize, end: usize) -> Result<Naptr> {
+        if offset + 4 > end {
+            return Err(Error::BadRdata)
+        }
+        let (flags, off) = try!(string(&msg[..end], offset + 4));
+        let (services, off) = try!(string(&msg[..end], off));
+        let (regexp, off) = try!(string(&msg[..end], off));
+        let (replacement, off) = try!(RName::unpack(msg, off));
+        if off != end {
+            return Err(Error::BadRdata)
+        }
+        Ok(Naptr{
+            order: get_u16(msg, offset),
+            preference: get_u16(msg, offset + 2),
+            flags: flags,
+            services: services,
+            regexp: regexp,
+            replacement: replacement,
+        })
+    }
+
+    /// Reads `order preference flags services regexp replacement`.
+    pub fn parse(t: &[String], origin: &RName) -> Result<Naptr> {
+        let mut t = Fields::new(t);
+        let naptr = Naptr{
+            order: try!(t.number()),
+            preference: try!(t.number()),
+            flags: try!(t.string()),
+            services: try!(t.string()),
+            regexp: try!(t.string()),
+            replacement: try!(t.name(origin)),
+        };
+        try!(t.finish());
+        Ok(naptr)
+    }
+}
+
+impl fmt::Display for Naptr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {} {} {}", self.order, self.preference, quote(&self.flags),
+               quote(&self.services), quote(&self.regexp), self.replacement)
+    }
+}
+
+/// A URI a service is reached at (RFC 7553).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Uri {
+    pub priority: u16,
+    pub weight: u16,
+    pub target: Vec<u8>,
+}
+
+impl Uri {
+
+    pub fn len(&self) -> usize { 4 + self.target.len() }
+
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if offset + 4 > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        put_u16(buf, offset, self.priority);
+        put_u16(buf, offset + 2, self.weight);
+        put_bytes(buf, offset + 4, &self.target)
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Uri> {
+        if rdata.len() < 5 {
+            return Err(Error::BadRdata)
+        }
+        Ok(Uri{
+            priority: get_u16(rdata, 0),
+            weight: get_u16(rdata, 2),
+            target: rdata[4..].to_vec(),
+        })
+    }
+
+    /// Reads `priority weight "target"`; the target is not limited to 255
+    /// bytes as character-strings are.
+    pub fn parse(t: &[String]) -> Result<Uri> {
+        let mut t = Fields::new(t);
+        let uri = Uri{
+            priority: try!(t.number()),
+            weight: try!(t.number()),
+            target: try!(t.field(|s| {
+                let target = try!(unescape(s));
+                if target.is_empty() {
+                    return Err(Error::BadRdata)
+                }
+                Ok(target)
+            })),
+        };
+        try!(t.finish());
+        Ok(uri)
+    }
+}
+
+impl fmt::Display for Uri {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.priority, self.weight, quote(&self.target))
+    }
+}
+
+/// The issuer critical flag of a CAA record (RFC 8659 §4.1).
+pub const CRITICAL: u8 = 0x80;
+
+/// A certification authority authorization (RFC 8659).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Caa {
+    pub flags: u8,
+    /// Letters and digits only, such as `issue` or `iodef`.
+    pub tag: String,
+    pub value: Vec<u8>,
+}
+
+impl Caa {
+
+    pub fn len(&self) -> usize { 2 + self.tag.len() + self.value.len() }
+
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if offset + 1 > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        buf[offset] = self.flags;
+        let off = try!(put_string(buf, offset + 1, self.tag.as_bytes()));
+        put_bytes(buf, off, &self.value)
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Caa> {
+        if rdata.is_empty() {
+            return Err(Error::BadRdata)
+        }
+        let (tag, off) = try!(string(rdata, 1));
+        Ok(Caa{
+            flags: rdata[0],
+            tag: try!(caa_tag(&tag)),
+            value: rdata[off..].to_vec(),
+        })
+    }
+
+    /// Reads `flags tag "value"`.
+    pub fn parse(t: &[String]) -> Result<Caa> {
+        let mut t = Fields::new(t);
+        let caa = Caa{
+            flags: try!(t.number()),
+            tag: try!(t.field(|s| caa_tag(s.as_bytes()))),
+            value: try!(t.field(unescape)),
+        };
+        try!(t.finish());
+        Ok(caa)
+    }
+
+    #[inline]
+    pub fn is_critical(&self) -> bool { self.flags & CRITICAL != 0 }
+}
+
+impl fmt::Display for Caa {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.flags, self.tag, quote(&self.value))
+    }
+}
+
+// Tags are 1 to 15 letters and digits (RFC 8659 §4.1).
+fn caa_tag(tag: &[u8]) -> Result<String> {
+    if tag.is_empty() || tag.len() > 15 || !tag.iter().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(Error::BadRdata)
+    }
+    Ok(String::from_utf8(tag.to_vec()).unwrap())
+}
+
+/// The fingerprint of an SSH host key (RFC 4255).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Sshfp {
+    pub algorithm: u8,
+    pub fp_type: u8,
+    pub fingerprint: Vec<u8>,
+}
+
+impl Sshfp {
+
+    pub fn len(&self) -> usize { 2 + self.fingerprint.len() }
+
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if offset + 2 > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        buf[offset] = self.algorithm;
+        buf[offset + 1] = self.fp_type;
+        put_bytes(buf, offset + 2, &self.fingerprint)
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Sshfp> {
+        if rdata.len() < 2 {
+            return Err(Error::BadRdata)
+        }
+        Ok(Sshfp{
+            algorithm: rdata[0],
+            fp_type: rdata[1],
+            fingerprint: rdata[2..].to_vec(),
+        })
+    }
+
+    /// Reads `algorithm fp-type hex...`.
+    pub fn parse(t: &[String]) -> Result<Sshfp> {
+        let mut t = Fields::new(t);
+        Ok(Sshfp{
+            algorithm: try!(t.number()),
+            fp_type: try!(t.number()),
+            fingerprint: try!(t.rest(hex)),
+        })
+    }
+}
+
+impl fmt::Display for Sshfp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.algorithm, self.fp_type, upper_hex(&self.fingerprint))
+    }
+}
+
+/// A certificate association for TLS (RFC 6698 §2).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Tlsa {
+    pub usage: u8,
+    pub selector: u8,
+    pub matching_type: u8,
+    pub data: Vec<u8>,
+}
+
+impl Tlsa {
+
+    pub fn len(&self) -> usize { 3 + self.data.len() }
+
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if offset + 3 > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        buf[offset] = self.usage;
+        buf[offset + 1] = self.selector;
+        buf[offset + 2] = self.matching_type;
+        put_bytes(buf, offset + 3, &self.data)
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Tlsa> {
+        if rdata.len() < 3 {
+            return Err(Error::BadRdata)
+        }
+        Ok(Tlsa{
+            usage: rdata[0],
+            selector: rdata[1],
+            matching_type: rdata[2],
+            data: rdata[3..].to_vec(),
+        })
+    }
+
+    /// Reads `usage selector matching-type hex...`.
+    pub fn parse(t: &[String]) -> Result<Tlsa> {
+        let mut t = Fields::new(t);
+        Ok(Tlsa{
+            usage: try!(t.number()),
+            selector: try!(t.number()),
+            matching_type: try!(t.number()),
+            data: try!(t.rest(hex)),
+        })
+    }
+}
+
+impl fmt::Display for Tlsa {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.usage, self.selector, self.matching_type, upper_hex(&self.data))
+    }
+}
+
+/// Reads the base64 of an OpenPGP transferable public key (RFC 7929 §2.3).
+pub fn parse_openpgpkey(t: &[String]) -> Result<Vec<u8>> {
+    Fields::new(t).rest(base64::decode)
+}
+
+/// The keys of the service parameters of RFC 9460 §14.3.2.
+pub const MANDATORY: u16 = 0;
+pub const ALPN: u16 = 1;
+pub const NO_DEFAULT_ALPN: u16 = 2;
+pub const PORT: u16 = 3;
+pub const IPV4HINT: u16 = 4;
+pub const ECH: u16 = 5;
+pub const IPV6HINT: u16 = 6;
+
+/// A service binding, the data of both SVCB and HTTPS (RFC 9460 §2).
+/// Priority 0 makes it an alias for the target.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Svcb {
+    pub priority: u16,
+    pub target: RName,
+    /// In increasing order of key, each key at most once.
+    pub params: Vec<SvcParam>,
+}
+
+/// A service parameter, in the form its key defines or as opaque bytes
+/// for keys without one.
+#[derive(Clone, PartialEq, Debug)]
+pub enum SvcParam {
+    Mandatory(Vec<u16>),
+    Alpn(Vec<Vec<u8>>),
+    NoDefaultAlpn,
+    Port(u16),
+    Ipv4Hint(Vec<Ipv4Addr>),
+    Ech(Vec<u8>),
+    Ipv6Hint(Vec<Ipv6Addr>),
+    Unknown(u16, Vec<u8>),
+}
+
+impl Svcb {
+
+    pub fn len(&self) -> usize {
+        2 + self.target.len() + 1 + self.params.iter().map(|p| 4 + p.value().len()).sum::<usize>()
+    }
+
+    /// Packs the data; the target is never compressed.
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        if offset + 2 > buf.len() {
+            return Err(Error::SmallBuf)
+        }
+        put_u16(buf, offset, self.priority);
+        let mut off = try!(self.target.pack(buf, offset + 2));
+        for param in self.params.iter() {
+            let value = param.value();
+            if value.len() > 0xffff {
+                return Err(Error::BadRdata)
+            }
+            if off + 4 > buf.len() {
+                return Err(Error::SmallBuf)
+            }
+            put_u16(buf, off, param.key());
+            put_u16(buf, off + 2, value.len() as u16);
+            off = try!(put_bytes(buf, off + 4, &value));
+        }
+        Ok(off)
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Svcb> {
+        if rdata.len() < 3 {
+            return Err(Error::BadRdata)
+        }
+        let (target, mut off) = try!(RName::unpack(rdata, 2));
+        let mut params = vec![];
+        while off < rdata.len() {
+            if off + 4 > rdata.len() {
+                return Err(Error::BadRdata)
+            }
+            let (key, len) = (get_u16(rdata, off), get_u16(rdata, off + 2) as usize);
+            if off + 4 + len > rdata.len() {
+                return Err(Error::BadRdata)
+            }
+            params.push(try!(SvcParam::unpack(key, &rdata[off + 4..off + 4 + len])));
+            off += 4 + len;
+        }
+        let svcb = Svcb{ priority: get_u16(rdata, 0), target: target, params: params };
+        try!(svcb.check());
+        Ok(svcb)
+    }
+
+    /// Reads `priority target key=value...`, where values may be quoted
+    /// and lists are separated by commas (RFC 9460 §2.1).
+    pub fn parse(t: &[String], origin: &RName) -> Result<Svcb> {
+        let mut t = Fields::new(t);
+        let priority = try!(t.number());
+        let target = try!(t.name(origin));
+        let mut params = vec![];
+        while !t.is_empty() {
+            let mut param = try!(t.token()).to_string();
+            // the tokenizer splits `key="value"` at the quote
+            if param.ends_with('=') && t.peek().map_or(false, |s| s.starts_with('"')) {
+                param.push_str(try!(t.token()));
+            }
+            params.push(try!(SvcParam::parse(&param).map_err(|_| Error::BadToken(param.clone()))));
+        }
+        params.sort_by_key(|p| p.key());
+        let svcb = Svcb{ priority: priority, target: target, params: params };
+        try!(svcb.check());
+        Ok(svcb)
+    }
+
+    // Keys must be unique and those listed as mandatory unique and present
+    // (RFC 9460 §2.2, §8).
+    fn check(&self) -> Result<()> {
+        if self.params.windows(2).any(|w| w[0].key() >= w[1].key()) {
+            return Err(Error::BadRdata)
+        }
+        for param in self.params.iter() {
+            if let SvcParam::Mandatory(ref keys) = *param {
+                if keys.windows(2).any(|w| w[0] >= w[1]) ||
+                    keys.iter().any(|&k| k == MANDATORY || self.param(k).is_none()) {
+                    return Err(Error::BadRdata)
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_alias(&self) -> bool { self.priority == 0 }
+
+    pub fn param(&self, key: u16) -> Option<&SvcParam> {
+        self.params.iter().find(|p| p.key() == key)
+    }
+
+    /// The name whose addresses serve the records at `owner`, which a
+    /// target of `.` stands for in service mode. An alias to `.` says
+    /// the service is not available.
+    pub fn target_of<'a>(&'a self, owner: &'a RName) -> Option<&'a RName> {
+        match (self.target.is_root(), self.is_alias()) {
+            (false, _) => Some(&self.target),
+            (true, false) => Some(owner),
+            (true, true) => None,
+        }
+    }
+}
+
+impl fmt::Display for Svcb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{} {}", self.priority, self.target));
+        for param in self.params.iter() {
+            try!(write!(f, " {}", param));
+        }
+        Ok(())
+    }
+}
+
+impl SvcParam {
+
+    pub fn key(&self) -> u16 {
+        match *self {
+            SvcParam::Mandatory(_) => MANDATORY,
+            SvcParam::Alpn(_) => ALPN,
+            SvcParam::NoDefaultAlpn => NO_DEFAULT_ALPN,
+            SvcParam::Port(_) => PORT,
+            SvcParam::Ipv4Hint(_) => IPV4HINT,
+            SvcParam::Ech(_) => ECH,
+            SvcParam::Ipv6Hint(_) => IPV6HINT,
+            SvcParam::Unknown(key, _) => key,
+        }
+    }
+
+    /// The value in wire format.
+    pub fn value(&self) -> Vec<u8> {
+        let mut out = vec![];
+        match *self {
+            SvcParam::Mandatory(ref keys) => for &k in keys.iter() {
+                out.push((k >> 8) as u8);
+                out.push(k as u8);
+            },
+            SvcParam::Alpn(ref ids) => for id in ids.iter() {
+                out.push(id.len() as u8);
+                out.extend_from_slice(id);
+            },
+            SvcParam::NoDefaultAlpn => {}
+            SvcParam::Port(port) => out.extend_from_slice(&[(port >> 8) as u8, port as u8]),
+            SvcParam::Ipv4Hint(ref addrs) => for a in addrs.iter() {
+                out.extend_from_slice(&a.octets());
+            },
+            SvcParam::Ech(ref v) | SvcParam::Unknown(_, ref v) => out.extend_from_slice(v),
+            SvcParam::Ipv6Hint(ref addrs) => for a in addrs.iter() {
+                out.extend_from_slice(&a.octets());
+            },
+        }
+        out
+    }
+
+    pub fn unpack(key: u16, value: &[u8]) -> Result<SvcParam> {
+        let len = value.len();
+        let list = |size: usize| len > 0 && len % size == 0;
+        Ok(match key {
+            MANDATORY if list(2) => {
+                SvcParam::Mandatory(value.chunks(2).map(|k| get_u16(k, 0)).collect())
+            }
+            ALPN if len > 0 => {
+                let mut ids = vec![];
+                let mut off = 0;
+                while off < len {
+                    let (id, end) = try!(string(value, off));
+                    if id.is_empty() {
+                        return Err(Error::BadRdata)
+                    }
+                    ids.push(id);
+                    off = end;
+                }
+                SvcParam::Alpn(ids)
+            }
+            NO_DEFAULT_ALPN if len == 0 => SvcParam::NoDefaultAlpn,
+            PORT if len == 2 => SvcParam::Port(get_u16(value, 0)),
+            IPV4HINT if list(4) => {
+                SvcParam::Ipv4Hint(value.chunks(4).map(|a| Ipv4Addr::new(a[0], a[1], a[2], a[3])).collect())
+            }
+            ECH => SvcParam::Ech(value.to_vec()),
+            IPV6HINT if list(16) => SvcParam::Ipv6Hint(value.chunks(16).map(|a| {
+                let s = |i: usize| get_u16(a, 2*i);
+                Ipv6Addr::new(s(0), s(1), s(2), s(3), s(4), s(5), s(6), s(7))
+            }).collect()),
+            MANDATORY...IPV6HINT | 0xffff => return Err(Error::BadRdata),
+            _ => SvcParam::Unknown(key, value.to_vec()),
+        })
+    }
+
+    /// Reads `key=value`, or a key alone for values that may be empty.
+    pub fn parse(s: &str) -> Result<SvcParam> {
+        let (key, value) = match s.find('=') {
+            Some(i) => (&s[..i], Some(try!(unescape(&s[i + 1..])))),
+            None => (s, None),
+        };
+        let key = try!(parse_key(key));
+        let text = |value: Option<Vec<u8>>| -> Result<String> {
+            match value {
+                Some(ref v) if !v.is_empty() => String::from_utf8(v.clone()).map_err(|_| Error::BadRdata),
+                _ => Err(Error::BadRdata),
+            }
+        };
+        Ok(match key {
+            MANDATORY => {
+                let mut keys = vec![];
+                for k in try!(text(value)).split(',') {
+                    keys.push(try!(parse_key(k)));
+                }
+                keys.sort();
+                SvcParam::Mandatory(keys)
+            }
+            ALPN => {
+                let ids = split_list(&try!(value.ok_or(Error::BadRdata)));
+                if ids.is_empty() || ids.iter().any(|id| id.is_empty() || id.len() > 255) {
+                    return Err(Error::BadRdata)
+                }
+                SvcParam::Alpn(ids)
+            }
+            NO_DEFAULT_ALPN if value.is_none() => SvcParam::NoDefaultAlpn,
+            PORT => SvcParam::Port(try!(try!(text(value)).parse().map_err(|_| Error::BadRdata))),
+            IPV4HINT => {
+                let mut addrs = vec![];
+                for a in try!(text(value)).split(',') {
+                    addrs.push(try!(a.parse().map_err(|_| Error::BadRdata)));
+                }
+                SvcParam::Ipv4Hint(addrs)
+            }
+            ECH => SvcParam::Ech(try!(base64::decode(&try!(text(value))))),
+            IPV6HINT => {
+                let mut addrs = vec![];
+                for a in try!(text(value)).split(',') {
+                    addrs.push(try!(a.parse().map_err(|_| Error::BadRdata)));
+                }
+                SvcParam::Ipv6Hint(addrs)
+            }
+            NO_DEFAULT_ALPN => return Err(Error::BadRdata),
+            _ => SvcParam::Unknown(key, value.unwrap_or(vec![])),
+        })
+    }
+}
+
+impl fmt::Display for SvcParam {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{}", key_name(self.key())));
+        match *self {
+            SvcParam::Mandatory(ref keys) => {
+                let names: Vec<String> = keys.iter().map(|&k| key_name(k)).collect();
+                write!(f, "={}", names.join(","))
+            }
+            SvcParam::Alpn(ref ids) => {
+                // commas and backslashes in ids are escaped for the list,
+                // then the list for the character-string
+                let mut list = vec![];
+                for (i, id) in ids.iter().enumerate() {
+                    if i > 0 {
+                        list.push(b',');
+                    }
+                    for &c in id.iter() {
+                        if c == b',' || c == b'\\' {
+                            list.push(b'\\');
+                        }
+                        list.push(c);
+                    }
+                }
+                write!(f, "={}", quote(&list))
+            }
+            SvcParam::NoDefaultAlpn => Ok(()),
+            SvcParam::Port(port) => write!(f, "={}", port),
+            SvcParam::Ipv4Hint(ref addrs) => {
+                let addrs: Vec<String> = addrs.iter().map(|a| a.to_string()).collect();
+                write!(f, "={}", addrs.join(","))
+            }
+            SvcParam::Ech(ref v) => write!(f, "={}", base64::encode(v)),
+            SvcParam::Ipv6Hint(ref addrs) => {
+                let addrs: Vec<String> = addrs.iter().map(|a| a.to_string()).collect();
+                write!(f, "={}", addrs.join(","))
+            }
+            SvcParam::Unknown(_, ref v) => write!(f, "={}", quote(v)),
+        }
+    }
+}
+
+/// The name of a service parameter key, or `keyNNNNN` for those without
+/// one.
+pub fn key_name(key: u16) -> String {
+    match key {
+        MANDATORY => "mandatory".to_string(),
+        ALPN => "alpn".to_string(),
+        NO_DEFAULT_ALPN => "no-default-alpn".to_string(),
+        PORT => "port".to_string(),
+        IPV4HINT => "ipv4hint".to_string(),
+        ECH => "ech".to_string(),
+        IPV6HINT => "ipv6hint".to_string(),
+        _ => format!("key{}", key),
+    }
+}
+
+/// Reads a service parameter key by name or as `keyNNNNN`.
+pub fn parse_key(s: &str) -> Result<u16> {
+    Ok(match s {
+        "mandatory" => MANDATORY,
+        "alpn" => ALPN,
+        "no-default-alpn" => NO_DEFAULT_ALPN,
+        "port" => PORT,
+        "ipv4hint" => IPV4HINT,
+        "ech" => ECH,
+        "ipv6hint" => IPV6HINT,
+        _ if s.starts_with("key") => match s[3..].parse() {
+            Ok(0xffff) | Err(_) => return Err(Error::BadRdata),
+            Ok(key) => key,
+        },
+        _ => return Err(Error::BadRdata),
+    })
+}
+
+// Splits a comma-separated list in which `\` escapes the next byte
+// (RFC 9460 Appendix A.1).
+fn split_list(value: &[u8]) -> Vec<Vec<u8>> {
+    let mut items = vec![vec![]];
+    let mut escaped = false;
+    for &c in value.iter() {
+        if escaped {
+            items.last_mut().unwrap().push(c);
+            escaped = false;
+        } else if c == b'\\' {
+            escaped = true;
+        } else if c == b',' {
+            items.push(vec![]);
+        } else {
+            items.last_mut().unwrap().push(c);
+        }
+    }
+    items
+}
+
+fn upper_hex(data: &[u8]) -> String {
+    let hex: Vec<String> = data.iter().map(|b| format!("{:02X}", b)).collect();
+    hex.concat()
+}
+
+// A length-prefixed run of bytes.
+fn string(data: &[u8], off: usize) -> Result<(Vec<u8>, usize)> {
+    if off >= data.len() || off + 1 + data[off] as usize > data.len() {
+        return Err(Error::BadRdata)
+    }
+    let end = off + 1 + data[off] as usize;
+    Ok((data[off + 1..end].to_vec(), end))
+}
+
+fn put_string(buf: &mut [u8], off: usize, s: &[u8]) -> Result<usize> {
+    if s.len() > 255 {
+        return Err(Error::BadRdata)
+    }
+    if off + 1 > buf.len() {
+        return Err(Error::SmallBuf)
+    }
+    buf[off] = s.len() as u8;
+    put_bytes(buf, off + 1, s)
+}
+
+#[inline]
+fn get_u16(buf: &[u8], off: usize) -> u16 {
+    (buf[off] as u16) << 8 | buf[off + 1] as u16
+}
+
+#[inline]
+fn put_u16(buf: &mut [u8], off: usize, v: u16) {
+    buf[off] = (v >> 8) as u8;
+    buf[off + 1] = v as u8;
+}
+
+fn put_bytes(buf: &mut [u8], off: usize, data: &[u8]) -> Result<usize> {
+    if off + data.len() > buf.len() {
+        return Err(Error::SmallBuf)
+    }
+    buf[off..off + data.len()].copy_from_slice(data);
+    Ok(off + data.len())
+}
+
+
+#[cfg(test)] use std::str::FromStr;
+#[cfg(test)] use dns::{RType, RData};
+#[cfg(test)] use rustc_serialize::hex::FromHex;
+
+#[cfg(test)]
+fn roundtrip(rtype: RType, text: &str) -> RData {
+    let data = RData::from_str(rtype, text, &RName::from_str("example.com").unwrap()).unwrap();
+    let mut buf = vec![0; 2 + data.len()];
+    assert_eq!(data.pack(&mut buf, 0), Ok(buf.len()));
+    assert_eq!(RData::unpack(rtype, &buf, 2, buf.len() - 2), Ok(data.clone()));
+    data
+}
+
+#[test]
+fn service_records() {
+    let srv = roundtrip(RType::SRV, "10 60 5060 sip");
+    assert_eq!(srv, RData::SRV(Srv{ priority: 10, weight: 60, port: 5060, target: RName::from_str("sip.example.com").unwrap() }));
+    assert_eq!(srv.to_string(), "10 60 5060 sip.example.com.");
+
+    let naptr = roundtrip(RType::NAPTR, "100 10 \"S\" \"SIP+D2U\" \"\" _sip._udp");
+    assert_eq!(naptr.to_string(), "100 10 \"S\" \"SIP+D2U\" \"\" _sip._udp.example.com.");
+    let naptr = roundtrip(RType::NAPTR, "100 50 \"u\" \"E2U+sip\" \"!^.*$!sip:info@example.com!\" .");
+    assert_eq!(naptr.to_string(), "100 50 \"u\" \"E2U+sip\" \"!^.*$!sip:info@example.com!\" .");
+
+    let uri = roundtrip(RType::URI, "10 1 \"ftp://ftp1.example.com/public\"");
+    assert_eq!(uri, RData::URI(Uri{ priority: 10, weight: 1, target: b"ftp://ftp1.example.com/public".to_vec() }));
+    assert_eq!(uri.to_string(), "10 1 \"ftp://ftp1.example.com/public\"");
+
+    assert_eq!(RData::from_str(RType::SRV, "10 60 sip", &RName::from_str(".").unwrap()), Err(Error::BadToken("sip".to_string())));
+    assert_eq!(RData::from_str(RType::URI, "10 1 \"\"", &RName::from_str(".").unwrap()), Err(Error::BadToken("\"\"".to_string())));
+}
+
+#[test]
+fn security_records() {
+    let caa = roundtrip(RType::CAA, "128 issue \"ca.example.net; account=230123\"");
+    match caa {
+        RData::CAA(ref c) => {
+            assert!(c.is_critical());
+            assert_eq!(c.tag, "issue");
+        }
+        _ => panic!("{:?}", caa),
+    }
+    assert_eq!(caa.to_string(), "128 issue \"ca.example.net; account=230123\"");
+    assert_eq!(roundtrip(RType::CAA, "0 iodef \"mailto:security@example.com\"").len(), 34);
+    assert_eq!(RData::from_str(RType::CAA, "0 is-sue \"ca\"", &RName::from_str(".").unwrap()), Err(Error::BadToken("is-sue".to_string())));
+    assert_eq!(Caa::unpack(&[0, 0]), Err(Error::BadRdata));
+
+    let sshfp = roundtrip(RType::SSHFP, "4 2 ( 123456789abcdef67890123456789abcdef67890\n 123456789abcdef67890123456789abc )");
+    assert_eq!(sshfp.to_string(), "4 2 123456789ABCDEF67890123456789ABCDEF67890123456789ABCDEF67890123456789ABC");
+
+    let tlsa = roundtrip(RType::TLSA, "3 1 1 d2abde240d7cd3ee6b4b28c54df034b97983a1d16e8a410e4561cb106618e971");
+    assert_eq!(tlsa, RData::TLSA(Tlsa{
+        usage: 3,
+        selector: 1,
+        matching_type: 1,
+        data: hex("d2abde240d7cd3ee6b4b28c54df034b97983a1d16e8a410e4561cb106618e971").unwrap(),
+    }));
+    assert_eq!(RData::from_str(RType::TLSA, "3 1 1 xyz", &RName::from_str(".").unwrap()), Err(Error::BadToken("xyz".to_string())));
+
+    let key = roundtrip(RType::OPENPGPKEY, "mQENBFV ( gcmg= )");
+    assert_eq!(key, RData::OPENPGPKEY(base64::decode("mQENBFVgcmg=").unwrap()));
+    assert_eq!(key.to_string(), "mQENBFVgcmg=");
+}
+
+#[test]
+fn order_srv_records() {
+    let srv = |priority: u16, weight: u16, port: u16| Srv{
+        priority: priority,
+        weight: weight,
+        port: port,
+        target: RName::from_str(".").unwrap(),
+    };
+    let ports = |srvs: &[Srv]| srvs.iter().map(|s| s.port).collect::<Vec<u16>>();
+
+    // the draws pick the running sum at or above them
+    let mut srvs = vec![srv(20, 0, 1), srv(10, 30, 2), srv(10, 10, 3), srv(10, 0, 4)];
+    let mut draws = vec![25, 0, 15].into_iter();
+    order(&mut srvs, || draws.next().unwrap_or(0));
+    assert_eq!(ports(&srvs), vec![2, 4, 3, 1]);
+
+    // heavier targets come first more often
+    let mut firsts = [0; 2];
+    let mut state = 0x9e3779b97f4a7c15u64;
+    for _ in 0..1000 {
+        let mut srvs = vec![srv(0, 1, 0), srv(0, 9, 1)];
+        order(&mut srvs, || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        });
+        firsts[srvs[0].port as usize] += 1;
+    }
+    assert!(firsts[1] > 800, "{:?}", firsts);
+}
+
+#[test]
+fn service_bindings() {
+    // RFC 9460 Appendix D
+    let wire = |rtype: RType, text: &str| {
+        let data = roundtrip(rtype, text);
+        let mut buf = vec![0; 2 + data.len()];
+        data.pack(&mut buf, 0).unwrap();
+        buf[2..].to_vec()
+    };
+    assert_eq!(wire(RType::HTTPS, "0 foo.example.com."), "000003666f6f076578616d706c6503636f6d00".from_hex().unwrap());
+    assert_eq!(wire(RType::SVCB, "1 ."), vec![0, 1, 0]);
+    assert_eq!(wire(RType::SVCB, "16 foo.example.com. port=53"),
+               "001003666f6f076578616d706c6503636f6d000003000200 35".replace(" ", "").from_hex().unwrap());
+    assert_eq!(wire(RType::SVCB, "1 foo.example.com. key667=\"hello\\210qoo\""),
+               "000103666f6f076578616d706c6503636f6d00029b000968656c6c6fd2716f6f".from_hex().unwrap());
+    assert_eq!(wire(RType::SVCB, "1 foo.example.com. ipv6hint=\"2001:db8::1,2001:db8::53:1\""),
+               "000103666f6f076578616d706c6503636f6d000006002020010db800000000000000000000000120010db8000000000000000000530001"
+               .from_hex().unwrap());
+    assert_eq!(wire(RType::SVCB, "16 foo.example.org. alpn=h2,h3-19 mandatory=ipv4hint,alpn ipv4hint=192.0.2.1"),
+               "001003666f6f076578616d706c65036f726700000000040001000400010009026832056833 2d3139 00040004c0000201"
+               .replace(" ", "").from_hex().unwrap());
+    assert_eq!(wire(RType::SVCB, "16 foo.example.org. alpn=\"f\\\\\\\\oo\\\\,bar,h2\""),
+               "001003666f6f076578616d706c65036f7267000001000c 08665c6f6f2c626172026832".replace(" ", "").from_hex().unwrap());
+
+    let svcb = match RData::from_str(RType::HTTPS, "1 . ( alpn=h3,h2 port=8443 no-default-alpn\n ipv4hint=192.0.2.1,192.0.2.2 ech=AEP+DQA= )",
+                                     &RName::from_str("example.com").unwrap()).unwrap() {
+        RData::HTTPS(svcb) => svcb,
+        other => panic!("{:?}", other),
+    };
+    assert_eq!(svcb.param(PORT), Some(&SvcParam::Port(8443)));
+    assert_eq!(svcb.param(ALPN), Some(&SvcParam::Alpn(vec![b"h3".to_vec(), b"h2".to_vec()])));
+    assert_eq!(svcb.to_string(),
+               "1 . alpn=\"h3,h2\" no-default-alpn port=8443 ipv4hint=192.0.2.1,192.0.2.2 ech=AEP+DQA=");
+    let owner = RName::from_str("example.com").unwrap();
+    assert_eq!(svcb.target_of(&owner), Some(&owner));
+    let alias = Svcb{ priority: 0, target: RName::from_str(".").unwrap(), params: vec![] };
+    assert_eq!(alias.target_of(&owner), None);
+
+    // RFC 9460 Appendix D.3
+    for text in &["1 foo.example.com. ( key123=abc key123=def )",
+                  "1 foo.example.com. mandatory",
+                  "1 foo.example.com. alpn",
+                  "1 foo.example.com. port",
+                  "1 foo.example.com. ipv4hint",
+                  "1 foo.example.com. ipv6hint",
+                  "1 foo.example.com. no-default-alpn=abc",
+                  "1 foo.example.com. mandatory=key123",
+                  "1 foo.example.com. mandatory=mandatory",
+                  "1 foo.example.com. ( mandatory=key123,key123 key123=abc )",
+                  "1 foo.example.com. key65535=abc"] {
+        assert!(RData::from_str(RType::SVCB, text, &RName::from_str(".").unwrap()).is_err(), "{}", text);
+    }
+    assert_eq!(RData::from_str(RType::SVCB, "1 . port=http", &RName::from_str(".").unwrap()),
+               Err(Error::BadToken("port=http".to_string())));
+
+    // keys out of order, a short port and a bad list on the wire
+    assert_eq!(Svcb::unpack(&[0, 1, 0, 0, 3, 0, 2, 0, 53, 0, 1, 0, 3, 2, b'h', b'2']), Err(Error::BadRdata));
+    assert_eq!(Svcb::unpack(&[0, 1, 0, 0, 3, 0, 1, 0]), Err(Error::BadRdata));
+    assert_eq!(Svcb::unpack(&[0, 1, 0, 0, 1, 0, 3, 5, b'h', b'2']), Err(Error::BadRdata));
+}
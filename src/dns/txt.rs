@@ -0,0 +1,370 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str;
+
+use dns::{Error, Result};
+use dns::base64;
+use dns::master::Fields;
+use dns::rdata::quote;
+
+/// The character-strings of TXT and SPF data (RFC 1035 §3.3.14).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Txt {
+    /// Each at most 255 bytes.
+    pub strings: Vec<Vec<u8>>,
+}
+
+impl Txt {
+
+    /// Splits a value of any length into strings of 255 bytes.
+    pub fn new(value: &[u8]) -> Txt {
+        if value.is_empty() {
+            return Txt{ strings: vec![vec![]] }
+        }
+        Txt{ strings: value.chunks(255).map(|s| s.to_vec()).collect() }
+    }
+
+    pub fn len(&self) -> usize { self.strings.iter().map(|s| 1 + s.len()).sum() }
+
+    pub fn pack(&self, buf: &mut [u8], offset: usize) -> Result<usize> {
+        let mut off = offset;
+        for s in self.strings.iter() {
+            if s.len() > 255 {
+                return Err(Error::BadRdata)
+            }
+            if off + 1 + s.len() > buf.len() {
+                return Err(Error::SmallBuf)
+            }
+            buf[off] = s.len() as u8;
+            buf[off + 1..off + 1 + s.len()].copy_from_slice(s);
+            off += 1 + s.len();
+        }
+        Ok(off)
+    }
+
+    pub fn unpack(rdata: &[u8]) -> Result<Txt> {
+        if rdata.is_empty() {
+            return Err(Error::BadRdata)
+        }
+        let mut strings = vec![];
+        let mut off = 0;
+        while off < rdata.len() {
+            let end = off + 1 + rdata[off] as usize;
+            if end > rdata.len() {
+                return Err(Error::BadRdata)
+            }
+            strings.push(rdata[off + 1..end].to_vec());
+            off = end;
+        }
+        Ok(Txt{ strings: strings })
+    }
+
+    /// Reads one or more character-strings, quoted or not.
+    pub fn parse(t: &[String]) -> Result<Txt> {
+        let mut t = Fields::new(t);
+        let mut strings = vec![try!(t.string())];
+        while !t.is_empty() {
+            strings.push(try!(t.string()));
+        }
+        Ok(Txt{ strings: strings })
+    }
+
+    /// The strings joined without separators, the way SPF (RFC 7208
+    /// §3.3) and DKIM (RFC 6376 §3.6.2.2) read them.
+    pub fn value(&self) -> Vec<u8> {
+        self.strings.concat()
+    }
+
+    /// The value as an SPF policy.
+    pub fn spf(&self) -> Result<Spf> {
+        Spf::parse(try!(str::from_utf8(&self.value()).map_err(|_| Error::BadSyntax)))
+    }
+
+    /// The value as a DKIM key record.
+    pub fn dkim(&self) -> Result<DkimKey> {
+        DkimKey::parse(try!(str::from_utf8(&self.value()).map_err(|_| Error::BadSyntax)))
+    }
+}
+
+impl fmt::Display for Txt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, s) in self.strings.iter().enumerate() {
+            if i > 0 {
+                try!(write!(f, " "));
+            }
+            try!(write!(f, "{}", quote(s)));
+        }
+        Ok(())
+    }
+}
+
+/// How a host matching an SPF mechanism is judged (RFC 7208 §4.6.2).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Qualifier {
+    Pass,
+    Fail,
+    SoftFail,
+    Neutral,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum SpfTerm {
+    /// A mechanism such as `ip4:192.0.2.0/24`, named in lowercase, with
+    /// what follows the name but for the colon as its argument.
+    Mechanism(Qualifier, String, Option<String>),
+    /// A modifier such as `redirect=_spf.example.com`.
+    Modifier(String, String),
+}
+
+/// A sender policy of RFC 7208, the terms following `v=spf1`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Spf {
+    pub terms: Vec<SpfTerm>,
+}
+
+impl Spf {
+
+    /// Reads a policy, failing with `BadSyntax` on text that is not one
+    /// or has a term RFC 7208 §4.6.1 does not allow.
+    pub fn parse(s: &str) -> Result<Spf> {
+        let mut words = s.split(' ').filter(|w| !w.is_empty());
+        if !words.next().map_or(false, |v| v.eq_ignore_ascii_case("v=spf1")) {
+            return Err(Error::BadSyntax)
+        }
+        let mut terms = vec![];
+        for word in words {
+            terms.push(try!(spf_term(word)));
+        }
+        Ok(Spf{ terms: terms })
+    }
+
+    /// The qualifier of the `all` mechanism ending the policy.
+    pub fn all(&self) -> Option<Qualifier> {
+        self.terms.iter().filter_map(|t| match *t {
+            SpfTerm::Mechanism(q, ref name, _) if name == "all" => Some(q),
+            _ => None,
+        }).next()
+    }
+
+    /// The domains of `include` mechanisms and of a `redirect`, whose
+    /// policies this one refers to.
+    pub fn references(&self) -> Vec<&str> {
+        self.terms.iter().filter_map(|t| match *t {
+            SpfTerm::Mechanism(_, ref name, Some(ref domain)) if name == "include" => Some(&domain[..]),
+            SpfTerm::Modifier(ref name, ref domain) if name == "redirect" => Some(&domain[..]),
+            _ => None,
+        }).collect()
+    }
+}
+
+impl fmt::Display for Spf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "v=spf1"));
+        for term in self.terms.iter() {
+            match *term {
+                SpfTerm::Mechanism(q, ref name, ref arg) => {
+                    let q = match q {
+                        Qualifier::Pass => "",
+                        Qualifier::Fail => "-",
+                        Qualifier::SoftFail => "~",
+                        Qualifier::Neutral => "?",
+                    };
+                    try!(write!(f, " {}{}", q, name));
+                    match *arg {
+                        Some(ref arg) if arg.starts_with('/') => try!(write!(f, "{}", arg)),
+                        Some(ref arg) => try!(write!(f, ":{}", arg)),
+                        None => {}
+                    }
+                }
+                SpfTerm::Modifier(ref name, ref value) => try!(write!(f, " {}={}", name, value)),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn spf_term(word: &str) -> Result<SpfTerm> {
+    let name_end = word.find(|c: char| c == ':' || c == '/' || c == '=').unwrap_or(word.len());
+    if word[name_end..].starts_with('=') {
+        let name = &word[..name_end];
+        let valid = name.chars().next().map_or(false, |c| c.is_ascii_alphabetic()) &&
+            name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+        if !valid {
+            return Err(Error::BadSyntax)
+        }
+        return Ok(SpfTerm::Modifier(name.to_ascii_lowercase(), word[name_end + 1..].to_string()))
+    }
+
+    let (qualifier, start) = match word.as_bytes()[0] {
+        b'+' => (Qualifier::Pass, 1),
+        b'-' => (Qualifier::Fail, 1),
+        b'~' => (Qualifier::SoftFail, 1),
+        b'?' => (Qualifier::Neutral, 1),
+        _ => (Qualifier::Pass, 0),
+    };
+    let name = word[start..name_end].to_ascii_lowercase();
+    let arg = match &word[name_end..] {
+        "" => None,
+        rest if rest.starts_with(':') => Some(rest[1..].to_string()),
+        rest => Some(rest.to_string()),
+    };
+    let addr = |arg: &Option<String>| arg.as_ref().map(|a| a.split('/').next().unwrap().to_string());
+    let valid = match (&name[..], &arg) {
+        ("all", &None) => true,
+        ("include", &Some(_)) | ("exists", &Some(_)) => true,
+        ("a", _) | ("mx", _) | ("ptr", _) => true,
+        ("ip4", &Some(_)) => addr(&arg).map_or(false, |a| a.parse::<Ipv4Addr>().is_ok()),
+        ("ip6", &Some(_)) => addr(&arg).map_or(false, |a| a.parse::<Ipv6Addr>().is_ok()),
+        _ => false,
+    };
+    if !valid || arg.as_ref().map_or(false, |a| a.is_empty()) {
+        return Err(Error::BadSyntax)
+    }
+    Ok(SpfTerm::Mechanism(qualifier, name, arg))
+}
+
+/// The public key of a DKIM selector (RFC 6376 §3.6.1).
+#[derive(Clone, PartialEq, Debug)]
+pub struct DkimKey {
+    /// `k=`, `rsa` when absent.
+    pub key_type: String,
+    /// `p=`, empty for a revoked key.
+    pub public_key: Vec<u8>,
+    /// `h=`, the hash algorithms allowed; all of them when empty.
+    pub hashes: Vec<String>,
+    /// `t=`, such as `y` for a domain testing DKIM.
+    pub flags: Vec<String>,
+    /// All tags as given, including those above.
+    pub tags: Vec<(String, String)>,
+}
+
+impl DkimKey {
+
+    /// Reads a key record, which must have a `p=` tag and may only start
+    /// with `v=DKIM1`.
+    pub fn parse(s: &str) -> Result<DkimKey> {
+        let tags = try!(parse_tags(s));
+        let tag = |name: &str| tags.iter().find(|t| t.0 == name).map(|t| &t.1[..]);
+        let list = |value: Option<&str>| -> Vec<String> {
+            value.map_or(vec![], |v| v.split(':').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+        };
+        let version_ok = match tags.iter().position(|t| t.0 == "v") {
+            None => true,
+            Some(0) => tags[0].1 == "DKIM1",
+            Some(_) => false,
+        };
+        let key = match tag("p") {
+            Some(p) if version_ok => {
+                let p: String = p.chars().filter(|c| !c.is_whitespace()).collect();
+                try!(base64::decode(&p).map_err(|_| Error::BadSyntax))
+            }
+            _ => return Err(Error::BadSyntax),
+        };
+        Ok(DkimKey{
+            key_type: tag("k").unwrap_or("rsa").to_string(),
+            public_key: key,
+            hashes: list(tag("h")),
+            flags: list(tag("t")),
+            tags: tags.clone(),
+        })
+    }
+
+    #[inline]
+    pub fn is_revoked(&self) -> bool { self.public_key.is_empty() }
+
+    pub fn is_testing(&self) -> bool { self.flags.iter().any(|f| f == "y") }
+}
+
+/// Reads a `tag=value; ...` list as DKIM (RFC 6376 §3.2) and DMARC use
+/// it. Whitespace around tags and values is dropped, and a tag may only
+/// appear once.
+pub fn parse_tags(s: &str) -> Result<Vec<(String, String)>> {
+    let mut tags: Vec<(String, String)> = vec![];
+    let specs: Vec<&str> = s.split(';').collect();
+    for (i, spec) in specs.iter().enumerate() {
+        if spec.trim().is_empty() && i == specs.len() - 1 {
+            break
+        }
+        let eq = try!(spec.find('=').ok_or(Error::BadSyntax));
+        let (name, value) = (spec[..eq].trim(), spec[eq + 1..].trim());
+        let valid = name.chars().next().map_or(false, |c| c.is_ascii_alphabetic()) &&
+            name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid || tags.iter().any(|t| t.0 == name) {
+            return Err(Error::BadSyntax)
+        }
+        tags.push((name.to_string(), value.to_string()));
+    }
+    Ok(tags)
+}
+
+
+#[test]
+fn split_and_join_strings() {
+    let long = vec![b'x'; 600];
+    let txt = Txt::new(&long);
+    assert_eq!(txt.strings.iter().map(|s| s.len()).collect::<Vec<usize>>(), vec![255, 255, 90]);
+    assert_eq!(txt.value(), long);
+    assert_eq!(Txt::new(b"").strings, vec![Vec::<u8>::new()]);
+
+    let mut buf = vec![0; txt.len()];
+    assert_eq!(txt.pack(&mut buf, 0), Ok(603));
+    assert_eq!(Txt::unpack(&buf), Ok(txt));
+
+    assert_eq!(Txt::unpack(b"\x05hello\x00\x01!").unwrap().strings, vec![b"hello".to_vec(), vec![], b"!".to_vec()]);
+    assert_eq!(Txt::unpack(b"\x05hell"), Err(Error::BadRdata));
+    assert_eq!(Txt{ strings: vec![vec![0; 256]] }.pack(&mut [0; 300], 0), Err(Error::BadRdata));
+
+    let txt = Txt{ strings: vec![b"say \"hi\"".to_vec(), b"tab\tback\\".to_vec()] };
+    assert_eq!(txt.to_string(), "\"say \\\"hi\\\"\" \"tab\\009back\\\\\"");
+}
+
+#[test]
+fn parse_spf_policies() {
+    let txt = Txt{ strings: vec![b"v=spf1 ip4:192.0.2.0/24 a mx:mail.example.com/28 include:_spf.exa".to_vec(),
+                                 b"mple.net ~all redirect=_spf.example.org".to_vec()] };
+    let spf = txt.spf().unwrap();
+    assert_eq!(spf.terms[0], SpfTerm::Mechanism(Qualifier::Pass, "ip4".to_string(), Some("192.0.2.0/24".to_string())));
+    assert_eq!(spf.terms[1], SpfTerm::Mechanism(Qualifier::Pass, "a".to_string(), None));
+    assert_eq!(spf.all(), Some(Qualifier::SoftFail));
+    assert_eq!(spf.references(), vec!["_spf.example.net", "_spf.example.org"]);
+    assert_eq!(spf.to_string(), "v=spf1 ip4:192.0.2.0/24 a mx:mail.example.com/28 include:_spf.example.net ~all redirect=_spf.example.org");
+
+    let spf = Spf::parse("V=SPF1 -A/24 ?ip6:2001:db8::/32 -ALL").unwrap();
+    assert_eq!(spf.terms[0], SpfTerm::Mechanism(Qualifier::Fail, "a".to_string(), Some("/24".to_string())));
+    assert_eq!(spf.all(), Some(Qualifier::Fail));
+    assert_eq!(spf.to_string(), "v=spf1 -a/24 ?ip6:2001:db8::/32 -all");
+
+    assert_eq!(Spf::parse("v=spf10 -all"), Err(Error::BadSyntax));
+    assert_eq!(Spf::parse("google-site-verification=abc"), Err(Error::BadSyntax));
+    assert_eq!(Spf::parse("v=spf1 ip4:example.com -all"), Err(Error::BadSyntax));
+    assert_eq!(Spf::parse("v=spf1 include -all"), Err(Error::BadSyntax));
+    assert_eq!(Spf::parse("v=spf1 bogus:x -all"), Err(Error::BadSyntax));
+}
+
+#[test]
+fn parse_dkim_keys() {
+    let txt = Txt{ strings: vec![b"v=DKIM1; k=rsa; t=y:s; h=sha256; p=MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQ".to_vec(),
+                                 b"KBgQC5 ".to_vec()] };
+    let key = txt.dkim().unwrap();
+    assert_eq!(key.key_type, "rsa");
+    assert_eq!(key.public_key, base64::decode("MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQC5").unwrap());
+    assert_eq!(key.hashes, vec!["sha256"]);
+    assert!(key.is_testing());
+    assert!(!key.is_revoked());
+
+    let key = DkimKey::parse("k=ed25519; p=").unwrap();
+    assert_eq!(key.key_type, "ed25519");
+    assert!(key.is_revoked());
+    assert_eq!(DkimKey::parse("p=AAAA").unwrap().key_type, "rsa");
+
+    assert_eq!(DkimKey::parse("v=DKIM1; k=rsa"), Err(Error::BadSyntax));
+    assert_eq!(DkimKey::parse("k=rsa; v=DKIM1; p=AAAA"), Err(Error::BadSyntax));
+    assert_eq!(DkimKey::parse("v=DKIM2; p=AAAA"), Err(Error::BadSyntax));
+    assert_eq!(DkimKey::parse("p=!!!!"), Err(Error::BadSyntax));
+
+    assert_eq!(parse_tags(" v = DMARC1 ; p=reject;").unwrap(),
+               vec![("v".to_string(), "DMARC1".to_string()), ("p".to_string(), "reject".to_string())]);
+    assert_eq!(parse_tags("a=1; a=2"), Err(Error::BadSyntax));
+    assert_eq!(parse_tags("a=1;; b=2"), Err(Error::BadSyntax));
+    assert_eq!(parse_tags("novalue"), Err(Error::BadSyntax));
+}
@@ -1,3 +1,6 @@
+use std::result;
+use std::str::FromStr;
+
 use dns::{Error, Result};
 
 #[repr(u8)]
@@ -101,6 +104,25 @@ impl Class {
     }
 }
 
+impl FromStr for Class {
+    type Err = Error;
+
+    fn from_str(s: &str) -> result::Result<Class, Error> {
+        Ok(match &s.to_uppercase()[..] {
+            "IN"              => Class::IN,
+            "CH" | "CHAOS"    => Class::CH,
+            "HS" | "HESIOD"   => Class::HS,
+            "NONE"            => Class::NONE,
+            "ANY"             => Class::ANY,
+            s if s.starts_with("CLASS") => match s[5..].parse::<u16>() {
+                Ok(v) => return Class::unpack(v),
+                Err(_) => return Err(Error::BadClass),
+            },
+            _ => return Err(Error::BadClass),
+        })
+    }
+}
+
 #[repr(u16)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RType {
@@ -142,27 +164,30 @@ pub enum RType {
     NAPTR      = 0x0023,
     KX         = 0x0024,
     CERT       = 0x0025,
-    DNAME      = 0x0026,
-    SINK       = 0x0027,
-    OPT        = 0x0028,
-    APL        = 0x0029,
-    DS         = 0x002A,
-    SSHFP      = 0x002B,
-    IPSECKEY   = 0x002C,
-    RRSIG      = 0x002D,
-    NSEC       = 0x002E,
-    DNSKEY     = 0x002F,
-    DHCID      = 0x0030,
-    NSEC3      = 0x0031,
-    NSEC3PARAM = 0x0032,
-    TLSA       = 0x0033,
-    HIP        = 0x0036,
-    NINFO      = 0x0037,
-    RKEY       = 0x0038,
-    TALINK     = 0x0039,
-    CDS        = 0x003A,
-    CDNSKEY    = 0x003B,
-    OPENPGPKEY = 0x003C,
+    A6         = 0x0026,
+    DNAME      = 0x0027,
+    SINK       = 0x0028,
+    OPT        = 0x0029,
+    APL        = 0x002A,
+    DS         = 0x002B,
+    SSHFP      = 0x002C,
+    IPSECKEY   = 0x002D,
+    RRSIG      = 0x002E,
+    NSEC       = 0x002F,
+    DNSKEY     = 0x0030,
+    DHCID      = 0x0031,
+    NSEC3      = 0x0032,
+    NSEC3PARAM = 0x0033,
+    TLSA       = 0x0034,
+    HIP        = 0x0037,
+    NINFO      = 0x0038,
+    RKEY       = 0x0039,
+    TALINK     = 0x003A,
+    CDS        = 0x003B,
+    CDNSKEY    = 0x003C,
+    OPENPGPKEY = 0x003D,
+    SVCB       = 0x0040,
+    HTTPS      = 0x0041,
     SPF        = 0x0063,
     UINFO      = 0x0064,
     UID        = 0x0065,
@@ -233,27 +258,30 @@ impl RType {
             0x0023 => RType::NAPTR,
             0x0024 => RType::KX,
             0x0025 => RType::CERT,
-            0x0026 => RType::DNAME,
-            0x0027 => RType::SINK,
-            0x0028 => RType::OPT,
-            0x0029 => RType::APL,
-            0x002A => RType::DS,
-            0x002B => RType::SSHFP,
-            0x002C => RType::IPSECKEY,
-            0x002D => RType::RRSIG,
-            0x002E => RType::NSEC,
-            0x002F => RType::DNSKEY,
-            0x0030 => RType::DHCID,
-            0x0031 => RType::NSEC3,
-            0x0032 => RType::NSEC3PARAM,
-            0x0033 => RType::TLSA,
-            0x0036 => RType::HIP,
-            0x0037 => RType::NINFO,
-            0x0038 => RType::RKEY,
-            0x0039 => RType::TALINK,
-            0x003A => RType::CDS,
-            0x003B => RType::CDNSKEY,
-            0x003C => RType::OPENPGPKEY,
+            0x0026 => RType::A6,
+            0x0027 => RType::DNAME,
+            0x0028 => RType::SINK,
+            0x0029 => RType::OPT,
+            0x002A => RType::APL,
+            0x002B => RType::DS,
+            0x002C => RType::SSHFP,
+            0x002D => RType::IPSECKEY,
+            0x002E => RType::RRSIG,
+            0x002F => RType::NSEC,
+            0x0030 => RType::DNSKEY,
+            0x0031 => RType::DHCID,
+            0x0032 => RType::NSEC3,
+            0x0033 => RType::NSEC3PARAM,
+            0x0034 => RType::TLSA,
+            0x0037 => RType::HIP,
+            0x0038 => RType::NINFO,
+            0x0039 => RType::RKEY,
+            0x003A => RType::TALINK,
+            0x003B => RType::CDS,
+            0x003C => RType::CDNSKEY,
+            0x003D => RType::OPENPGPKEY,
+            0x0040 => RType::SVCB,
+            0x0041 => RType::HTTPS,
             0x0063 => RType::SPF,
             0x0064 => RType::UINFO,
             0x0065 => RType::UID,
@@ -287,3 +315,100 @@ impl RType {
         })
     }
 }
+
+impl FromStr for RType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> result::Result<RType, Error> {
+        Ok(match &s.to_uppercase()[..] {
+            "A"          => RType::A,
+            "NS"         => RType::NS,
+            "MD"         => RType::MD,
+            "MF"         => RType::MF,
+            "CNAME"      => RType::CNAME,
+            "SOA"        => RType::SOA,
+            "MB"         => RType::MB,
+            "MG"         => RType::MG,
+            "MR"         => RType::MR,
+            "NULL"       => RType::NULL,
+            "WKS"        => RType::WKS,
+            "PTR"        => RType::PTR,
+            "HINFO"      => RType::HINFO,
+            "MINFO"      => RType::MINFO,
+            "MX"         => RType::MX,
+            "TXT"        => RType::TXT,
+            "RP"         => RType::RP,
+            "AFSDB"      => RType::AFSDB,
+            "X25"        => RType::X25,
+            "ISDN"       => RType::ISDN,
+            "RT"         => RType::RT,
+            "NSAP"       => RType::NSAP,
+            "NSAP-PTR"   => RType::NSAPPTR,
+            "SIG"        => RType::SIG,
+            "KEY"        => RType::KEY,
+            "PX"         => RType::PX,
+            "GPOS"       => RType::GPOS,
+            "AAAA"       => RType::AAAA,
+            "LOC"        => RType::LOC,
+            "NXT"        => RType::NXT,
+            "EID"        => RType::EID,
+            "NIMLOC"     => RType::NIMLOC,
+            "SRV"        => RType::SRV,
+            "ATMA"       => RType::ATMA,
+            "NAPTR"      => RType::NAPTR,
+            "KX"         => RType::KX,
+            "CERT"       => RType::CERT,
+            "A6"         => RType::A6,
+            "DNAME"      => RType::DNAME,
+            "SINK"       => RType::SINK,
+            "OPT"        => RType::OPT,
+            "APL"        => RType::APL,
+            "DS"         => RType::DS,
+            "SSHFP"      => RType::SSHFP,
+            "IPSECKEY"   => RType::IPSECKEY,
+            "RRSIG"      => RType::RRSIG,
+            "NSEC"       => RType::NSEC,
+            "DNSKEY"     => RType::DNSKEY,
+            "DHCID"      => RType::DHCID,
+            "NSEC3"      => RType::NSEC3,
+            "NSEC3PARAM" => RType::NSEC3PARAM,
+            "TLSA"       => RType::TLSA,
+            "HIP"        => RType::HIP,
+            "NINFO"      => RType::NINFO,
+            "RKEY"       => RType::RKEY,
+            "TALINK"     => RType::TALINK,
+            "CDS"        => RType::CDS,
+            "CDNSKEY"    => RType::CDNSKEY,
+            "OPENPGPKEY" => RType::OPENPGPKEY,
+            "SVCB"       => RType::SVCB,
+            "HTTPS"      => RType::HTTPS,
+            "SPF"        => RType::SPF,
+            "UINFO"      => RType::UINFO,
+            "UID"        => RType::UID,
+            "GID"        => RType::GID,
+            "UNSPEC"     => RType::UNSPEC,
+            "NID"        => RType::NID,
+            "L32"        => RType::L32,
+            "L64"        => RType::L64,
+            "LP"         => RType::LP,
+            "EUI48"      => RType::EUI48,
+            "EUI64"      => RType::EUI64,
+            "TKEY"       => RType::TKEY,
+            "TSIG"       => RType::TSIG,
+            "IXFR"       => RType::IXFR,
+            "AXFR"       => RType::AXFR,
+            "MAILB"      => RType::MAILB,
+            "MAILA"      => RType::MAILA,
+            "ANY"        => RType::ALL,
+            "URI"        => RType::URI,
+            "CAA"        => RType::CAA,
+            "TA"         => RType::TA,
+            "DLV"        => RType::DLV,
+            s if s.starts_with("TYPE") => match s[4..].parse::<u16>() {
+                Ok(v) => return RType::unpack(v),
+                Err(_) => return Err(Error::BadRType),
+            },
+            _ => return Err(Error::BadRType),
+        })
+    }
+}
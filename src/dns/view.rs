@@ -0,0 +1,479 @@
+use std::fmt;
+
+use dns::{Error, Result, OpCode, RCode, RType, Class, RName};
+use dns::message::{Message, Question, Resource};
+
+// Pointers followed within one name, as many as `RName::unpack` follows.
+const MAX_POINTERS: usize = 100;
+
+/// A message read in place. The header is checked and the sections are
+/// found when the view is made, while names and records are only decoded
+/// as they are asked for and without allocating.
+#[derive(Clone, Copy)]
+pub struct MessageView<'a> {
+    msg: &'a [u8],
+    // where the question, answer, authority and additional sections start
+    // and where the last of them ends
+    sections: [usize; 5],
+}
+
+impl<'a> MessageView<'a> {
+
+    /// Checks that the header and every record of the message are within
+    /// `msg`. Compression pointers are only followed once a name is read.
+    pub fn new(msg: &'a [u8]) -> Result<MessageView<'a>> {
+        if msg.len() < 12 {
+            return Err(Error::ShortRead)
+        }
+        let mut sections = [12; 5];
+        let mut off = 12;
+        for _ in 0..read_u16(msg, 4) {
+            off = try!(skip_name(msg, off)) + 4;
+            if off > msg.len() {
+                return Err(Error::ShortRead)
+            }
+        }
+        for i in 0..3 {
+            sections[i + 1] = off;
+            for _ in 0..read_u16(msg, 6 + 2 * i) {
+                off = try!(skip_name(msg, off));
+                if off + 10 > msg.len() {
+                    return Err(Error::ShortRead)
+                }
+                off += 10 + read_u16(msg, off + 8) as usize;
+                if off > msg.len() {
+                    return Err(Error::ShortRead)
+                }
+            }
+        }
+        sections[4] = off;
+        Ok(MessageView{ msg: msg, sections: sections })
+    }
+
+    /// The bytes the view reads from.
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] { self.msg }
+
+    #[inline]
+    pub fn id(&self) -> u16 { read_u16(self.msg, 0) }
+
+    pub fn opcode(&self) -> Result<OpCode> { OpCode::unpack(self.msg[2] & 0x78) }
+
+    pub fn rcode(&self) -> Result<RCode> { RCode::unpack(self.msg[3] & 0x0f) }
+
+    #[inline]
+    pub fn qr(&self) -> bool { self.msg[2] & 0x80 != 0 }
+
+    #[inline]
+    pub fn aa(&self) -> bool { self.msg[2] & 0x04 != 0 }
+
+    #[inline]
+    pub fn tc(&self) -> bool { self.msg[2] & 0x02 != 0 }
+
+    #[inline]
+    pub fn rd(&self) -> bool { self.msg[2] & 0x01 != 0 }
+
+    #[inline]
+    pub fn ra(&self) -> bool { self.msg[3] & 0x80 != 0 }
+
+    #[inline]
+    pub fn ad(&self) -> bool { self.msg[3] & 0x20 != 0 }
+
+    #[inline]
+    pub fn cd(&self) -> bool { self.msg[3] & 0x10 != 0 }
+
+    pub fn questions(&self) -> Questions<'a> {
+        Questions{ msg: self.msg, off: self.sections[0], left: read_u16(self.msg, 4) as usize }
+    }
+
+    pub fn answers(&self) -> Resources<'a> { self.section(1) }
+
+    pub fn authority(&self) -> Resources<'a> { self.section(2) }
+
+    /// The additional records, the OPT record included.
+    pub fn additionals(&self) -> Resources<'a> { self.section(3) }
+
+    /// Whether the sender asked for DNSSEC records with the DO bit of its
+    /// OPT record.
+    pub fn dnssec_ok(&self) -> bool {
+        self.additionals().find(|r| r.rtype == RType::OPT as u16).map_or(false, |r| r.ttl & 0x8000 != 0)
+    }
+
+    /// The largest UDP response the sender takes, as `Message::max_payload`.
+    pub fn max_payload(&self) -> usize {
+        match self.additionals().find(|r| r.rtype == RType::OPT as u16) {
+            Some(opt) if opt.class > 512 => opt.class as usize,
+            _ => 512,
+        }
+    }
+
+    /// Decodes the whole message.
+    pub fn to_message(&self) -> Result<Message> {
+        Message::unpack(self.msg, 0)
+    }
+
+    fn section(&self, i: usize) -> Resources<'a> {
+        Resources{ msg: self.msg, off: self.sections[i], end: self.sections[i + 1] }
+    }
+}
+
+/// A possibly compressed name within a message.
+#[derive(Clone, Copy)]
+pub struct NameView<'a> {
+    msg: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> NameView<'a> {
+
+    /// The labels of the name, following compression pointers. They end
+    /// early when the name turns out to be malformed.
+    pub fn labels(&self) -> NameLabels<'a> {
+        NameLabels{ msg: self.msg, off: self.offset, pointers: 0, done: false }
+    }
+
+    pub fn to_rname(&self) -> Result<RName> {
+        RName::unpack(self.msg, self.offset).map(|(name, _)| name)
+    }
+}
+
+/// Compares the labels ignoring case, as names are compared.
+impl<'a> PartialEq<RName> for NameView<'a> {
+    fn eq(&self, other: &RName) -> bool {
+        let mut labels = self.labels();
+        for label in other.labels() {
+            match labels.next() {
+                Some(l) if l.eq_ignore_ascii_case(label) => {}
+                _ => return false,
+            }
+        }
+        labels.next().is_none() && labels.done
+    }
+}
+
+impl<'a> fmt::Debug for NameView<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to_rname() {
+            Ok(name) => name.fmt(f),
+            Err(e) => write!(f, "<{:?}>", e),
+        }
+    }
+}
+
+pub struct NameLabels<'a> {
+    msg: &'a [u8],
+    off: usize,
+    pointers: usize,
+    // whether the root label was reached
+    done: bool,
+}
+
+impl<'a> Iterator for NameLabels<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        loop {
+            let len = match self.msg.get(self.off) {
+                Some(&len) => len as usize,
+                None => return None,
+            };
+            match len & 0xc0 {
+                0x00 if len == 0 => {
+                    self.done = true;
+                    return None
+                }
+                0x00 => {
+                    let start = self.off + 1;
+                    if start + len > self.msg.len() {
+                        return None
+                    }
+                    self.off = start + len;
+                    return Some(&self.msg[start..start + len])
+                }
+                0xc0 if self.pointers < MAX_POINTERS && self.off + 1 < self.msg.len() => {
+                    self.pointers += 1;
+                    self.off = (len & 0x3f) << 8 | self.msg[self.off + 1] as usize;
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+/// A question as on the wire; types and classes unknown to `RType` and
+/// `Class` are kept.
+#[derive(Clone, Copy, Debug)]
+pub struct QuestionView<'a> {
+    pub name: NameView<'a>,
+    pub rtype: u16,
+    pub class: u16,
+}
+
+impl<'a> QuestionView<'a> {
+    pub fn to_question(&self) -> Result<Question> {
+        Ok(Question{
+            name: try!(self.name.to_rname()),
+            rtype: try!(RType::unpack(self.rtype)),
+            class: try!(Class::unpack(self.class)),
+        })
+    }
+}
+
+/// A record with its data left in wire format.
+#[derive(Clone, Copy, Debug)]
+pub struct ResourceView<'a> {
+    pub name: NameView<'a>,
+    pub rtype: u16,
+    pub class: u16,
+    pub ttl: u32,
+    pub data: &'a [u8],
+    // where the type of the record is
+    offset: usize,
+}
+
+impl<'a> ResourceView<'a> {
+
+    /// Where the TTL of the record is in the message, for changing it in
+    /// a copy.
+    pub fn ttl_offset(&self) -> usize {
+        self.offset + 4
+    }
+
+    pub fn to_resource(&self) -> Result<Resource> {
+        Resource::unpack(self.name.msg, self.name.offset).map(|(rr, _)| rr)
+    }
+}
+
+pub struct Questions<'a> {
+    msg: &'a [u8],
+    off: usize,
+    left: usize,
+}
+
+impl<'a> Iterator for Questions<'a> {
+    type Item = QuestionView<'a>;
+
+    fn next(&mut self) -> Option<QuestionView<'a>> {
+        if self.left == 0 {
+            return None
+        }
+        // the view checked the question fits
+        let end = match skip_name(self.msg, self.off) {
+            Ok(end) => end,
+            Err(_) => return None,
+        };
+        let q = QuestionView{
+            name: NameView{ msg: self.msg, offset: self.off },
+            rtype: read_u16(self.msg, end),
+            class: read_u16(self.msg, end + 2),
+        };
+        self.off = end + 4;
+        self.left -= 1;
+        Some(q)
+    }
+}
+
+pub struct Resources<'a> {
+    msg: &'a [u8],
+    off: usize,
+    end: usize,
+}
+
+impl<'a> Iterator for Resources<'a> {
+    type Item = ResourceView<'a>;
+
+    fn next(&mut self) -> Option<ResourceView<'a>> {
+        if self.off >= self.end {
+            return None
+        }
+        // the view checked the record fits
+        let off = match skip_name(self.msg, self.off) {
+            Ok(off) => off,
+            Err(_) => return None,
+        };
+        let len = read_u16(self.msg, off + 8) as usize;
+        let rr = ResourceView{
+            name: NameView{ msg: self.msg, offset: self.off },
+            rtype: read_u16(self.msg, off),
+            class: read_u16(self.msg, off + 2),
+            ttl: (read_u16(self.msg, off + 4) as u32) << 16 | read_u16(self.msg, off + 6) as u32,
+            data: &self.msg[off + 10..off + 10 + len],
+            offset: off,
+        };
+        self.off = off + 10 + len;
+        Some(rr)
+    }
+}
+
+#[inline]
+fn read_u16(msg: &[u8], off: usize) -> u16 {
+    (msg[off] as u16) << 8 | msg[off + 1] as u16
+}
+
+// The offset after the name at `off`, without following pointers.
+fn skip_name(msg: &[u8], mut off: usize) -> Result<usize> {
+    loop {
+        match msg.get(off) {
+            Some(&0) => return Ok(off + 1),
+            Some(&len) if len & 0xc0 == 0xc0 => {
+                if off + 2 > msg.len() {
+                    return Err(Error::ShortRead)
+                }
+                return Ok(off + 2)
+            }
+            Some(&len) if len & 0xc0 == 0 => off += 1 + len as usize,
+            Some(_) => return Err(Error::BadRdata),
+            None => return Err(Error::ShortRead),
+        }
+    }
+}
+
+
+#[cfg(test)] use std::str::FromStr;
+#[cfg(test)] use rustc_serialize::hex::FromHex;
+#[cfg(test)] use dns::message::{Edns, BBC, REDDIT};
+#[cfg(all(test, feature = "bench"))] use test::{Bencher, black_box};
+#[cfg(test)] use std::alloc::{GlobalAlloc, Layout, System};
+#[cfg(test)] use std::cell::Cell;
+
+// Counts the allocations made on each thread, so a test can tell how many
+// one call makes while other tests allocate alongside.
+#[cfg(test)]
+struct Counting;
+
+#[cfg(test)]
+thread_local!(static ALLOCATIONS: Cell<usize> = Cell::new(0));
+
+#[cfg(test)]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[cfg(test)]
+#[global_allocator]
+static COUNTING: Counting = Counting;
+
+// The allocations one call of `f` makes.
+#[cfg(test)]
+fn allocations<T, F: FnOnce() -> T>(f: F) -> usize {
+    let before = ALLOCATIONS.with(|n| n.get());
+    let result = f();
+    let count = ALLOCATIONS.with(|n| n.get()) - before;
+    drop(result);
+    count
+}
+
+// the BBC answer with an OPT record asking for DNSSEC
+#[cfg(test)]
+fn bbc_with_edns() -> Vec<u8> {
+    let mut msg = Message::unpack(&BBC.from_hex().unwrap(), 0).unwrap();
+    msg.edns = Some(Edns{ dnssec_ok: true, ..Edns::default() });
+    let mut buf = vec![0; 512];
+    let len = msg.pack(&mut buf, 0).unwrap();
+    buf.truncate(len);
+    buf
+}
+
+#[test]
+fn view_messages() {
+    let buf = bbc_with_edns();
+    let view = MessageView::new(&buf).unwrap();
+    assert_eq!(view.id(), 3024);
+    assert_eq!(view.opcode(), Ok(OpCode::QUERY));
+    assert_eq!(view.rcode(), Ok(RCode::NOERROR));
+    assert!(view.qr() && view.rd() && view.ra() && !view.aa() && !view.tc() && !view.ad() && !view.cd());
+    assert!(view.dnssec_ok());
+
+    let q = view.questions().next().unwrap();
+    assert!(q.name == RName::from_str("WWW.bbc.co.uk").unwrap());
+    assert!(!(q.name == RName::from_str("bbc.co.uk").unwrap()));
+    assert!(!(q.name == RName::from_str("www.bbc.co.uk.example").unwrap()));
+    assert_eq!((q.rtype, q.class), (RType::A as u16, Class::IN as u16));
+
+    let answers: Vec<ResourceView> = view.answers().collect();
+    assert_eq!(answers.len(), 3);
+    // the names of the addresses point into the CNAME
+    assert!(answers[1].name == RName::from_str("www.bbc.net.uk").unwrap());
+    let labels: Vec<&[u8]> = answers[1].name.labels().collect();
+    assert_eq!(labels, vec![&b"www"[..], b"bbc", b"net", b"uk"]);
+    assert_eq!(answers[1].ttl, 58);
+    assert_eq!(answers[1].data, &[212, 58, 244, 70]);
+    assert_eq!(&buf[answers[0].ttl_offset()..answers[0].ttl_offset() + 4], &[0, 0, 0, 0xa7]);
+    assert_eq!(view.authority().count(), 0);
+    assert_eq!(view.additionals().next().unwrap().rtype, RType::OPT as u16);
+
+    let msg = view.to_message().unwrap();
+    assert_eq!(view.max_payload(), msg.max_payload());
+    assert_eq!(q.to_question().unwrap(), msg.questions[0]);
+    let resources: Vec<Resource> = answers.iter().map(|r| r.to_resource().unwrap()).collect();
+    assert_eq!(resources, msg.answers);
+}
+
+#[test]
+fn view_malformed_messages() {
+    let buf = REDDIT.from_hex().unwrap();
+    assert!(MessageView::new(&buf).is_ok());
+    assert_eq!(MessageView::new(&buf[..buf.len() - 1]).err(), Some(Error::ShortRead));
+    assert_eq!(MessageView::new(&buf[..11]).err(), Some(Error::ShortRead));
+
+    // a pointer to itself is only noticed once the name is read
+    let mut buf = bbc_with_edns();
+    buf[58] = 57;
+    let view = MessageView::new(&buf).unwrap();
+    let name = view.answers().nth(1).unwrap().name;
+    assert_eq!(name.labels().count(), 0);
+    assert_eq!(name.to_rname().err(), Some(Error::TooManyCompressionPointers));
+    assert!(!(name == RName::from_str(".").unwrap()));
+}
+
+#[test]
+fn count_allocations() {
+    let buf = REDDIT.from_hex().unwrap();
+    assert!(allocations(|| Message::unpack(&buf, 0).unwrap().questions[0].rtype) > 0);
+    assert_eq!(allocations(|| MessageView::new(&buf).unwrap().questions().next().unwrap().rtype), 0);
+    assert!(allocations(|| Message::unpack(&buf, 0).unwrap().answers.iter().map(|r| r.ttl).min()) > 0);
+    assert_eq!(allocations(|| MessageView::new(&buf).unwrap().answers().map(|r| r.ttl).min()), 0);
+}
+
+#[cfg(feature = "bench")]
+#[bench]
+fn bench_unpack_question(b: &mut Bencher) {
+    let buf = REDDIT.from_hex().unwrap();
+    b.bytes = buf.len() as u64;
+    let run = || Message::unpack(black_box(&buf), 0).unwrap().questions[0].rtype;
+    b.iter(run);
+}
+
+#[cfg(feature = "bench")]
+#[bench]
+fn bench_view_question(b: &mut Bencher) {
+    let buf = REDDIT.from_hex().unwrap();
+    b.bytes = buf.len() as u64;
+    let run = || MessageView::new(black_box(&buf)).unwrap().questions().next().unwrap().rtype;
+    b.iter(run);
+}
+
+#[cfg(feature = "bench")]
+#[bench]
+fn bench_unpack_ttls(b: &mut Bencher) {
+    let buf = REDDIT.from_hex().unwrap();
+    b.bytes = buf.len() as u64;
+    let run = || Message::unpack(black_box(&buf), 0).unwrap().answers.iter().map(|r| r.ttl).min();
+    b.iter(run);
+}
+
+#[cfg(feature = "bench")]
+#[bench]
+fn bench_view_ttls(b: &mut Bencher) {
+    let buf = REDDIT.from_hex().unwrap();
+    b.bytes = buf.len() as u64;
+    let run = || MessageView::new(black_box(&buf)).unwrap().answers().map(|r| r.ttl).min();
+    b.iter(run);
+}
@@ -0,0 +1,533 @@
+use digest::{Hash, Sha512};
+
+// Ed25519 (RFC 8032 §5.1) on fixed width arithmetic. Everything that
+// touches a secret scalar runs in time independent of its value: field
+// elements are five 51 bit limbs reduced without branches, points are
+// multiplied with a ladder that swaps by mask, and scalars are reduced
+// modulo L a bit at a time with a masked subtraction.
+
+// 2^51 - 1, the bits of one limb
+const MASK: u64 = (1 << 51) - 1;
+
+// Exponents as little endian bytes: p - 2 for inverses, (p - 5) / 8 and
+// (p - 1) / 4 for square roots (RFC 8032 §5.1.3).
+const P_MINUS_2: [u8; 32] = exponent(0xeb, 0x7f);
+const P_MINUS_5_DIV_8: [u8; 32] = exponent(0xfd, 0x0f);
+const P_MINUS_1_DIV_4: [u8; 32] = exponent(0xfb, 0x1f);
+
+// The group order L = 2^252 + 27742317777372353535851937790883648493 as
+// little endian 64 bit limbs.
+const L: [u64; 4] = [0x5812631a5cf5d3ed, 0x14def9dea2f79cd6, 0, 0x1000000000000000];
+
+const fn exponent(low: u8, high: u8) -> [u8; 32] {
+    let mut e = [0xff; 32];
+    e[0] = low;
+    e[31] = high;
+    e
+}
+
+// An element of the field of p = 2^255 - 19, with limbs of a little over
+// 51 bits at most between operations.
+#[derive(Clone, Copy)]
+struct Fe([u64; 5]);
+
+impl Fe {
+
+    fn zero() -> Fe { Fe([0; 5]) }
+
+    fn one() -> Fe { Fe([1, 0, 0, 0, 0]) }
+
+    fn small(n: u64) -> Fe { Fe([n, 0, 0, 0, 0]) }
+
+    // Little endian bytes, ignoring the top bit.
+    fn from_bytes(b: &[u8]) -> Fe {
+        let load = |i: usize| {
+            let mut v = 0u64;
+            for j in 0..8 {
+                v |= (b[i + j] as u64) << (8 * j);
+            }
+            v
+        };
+        Fe([load(0) & MASK,
+            load(6) >> 3 & MASK,
+            load(12) >> 6 & MASK,
+            load(19) >> 1 & MASK,
+            load(24) >> 12 & MASK])
+    }
+
+    // The canonical little endian encoding, below p.
+    fn to_bytes(&self) -> [u8; 32] {
+        let mut h = self.carry().carry().0;
+        // h < 2^255 + 2^13, so h >= p exactly when h + 19 reaches 2^255
+        let mut q = (h[0] + 19) >> 51;
+        for i in 1..5 {
+            q = (h[i] + q) >> 51;
+        }
+        h[0] += 19 * q;
+        for i in 0..4 {
+            h[i + 1] += h[i] >> 51;
+            h[i] &= MASK;
+        }
+        h[4] &= MASK;
+
+        let mut out = [0; 32];
+        let mut acc = 0u128;
+        let mut bits = 0;
+        let mut n = 0;
+        for &limb in h.iter() {
+            acc |= (limb as u128) << bits;
+            bits += 51;
+            while bits >= 8 {
+                out[n] = acc as u8;
+                acc >>= 8;
+                bits -= 8;
+                n += 1;
+            }
+        }
+        out[n] = acc as u8;
+        out
+    }
+
+    // Brings every limb back to 51 bits, folding the top carry by 19.
+    fn carry(&self) -> Fe {
+        let mut h = self.0;
+        let mut c;
+        for i in 0..4 {
+            c = h[i] >> 51;
+            h[i] &= MASK;
+            h[i + 1] += c;
+        }
+        c = h[4] >> 51;
+        h[4] &= MASK;
+        h[0] += c * 19;
+        c = h[0] >> 51;
+        h[0] &= MASK;
+        h[1] += c;
+        Fe(h)
+    }
+
+    fn add(&self, b: &Fe) -> Fe {
+        let mut h = self.0;
+        for i in 0..5 {
+            h[i] += b.0[i];
+        }
+        Fe(h).carry()
+    }
+
+    // Adds 2p first so no limb goes below zero.
+    fn sub(&self, b: &Fe) -> Fe {
+        let two_p = [0xfffffffffffda, 0xffffffffffffe, 0xffffffffffffe, 0xffffffffffffe, 0xffffffffffffe];
+        let mut h = self.0;
+        for i in 0..5 {
+            h[i] = h[i] + two_p[i] - b.0[i];
+        }
+        Fe(h).carry()
+    }
+
+    fn neg(&self) -> Fe {
+        Fe::zero().sub(self)
+    }
+
+    fn mul(&self, b: &Fe) -> Fe {
+        let (a, b) = (&self.0, &b.0);
+        let m = |x: u64, y: u64| x as u128 * y as u128;
+        let (b1, b2, b3, b4) = (b[1] * 19, b[2] * 19, b[3] * 19, b[4] * 19);
+        let mut r = [
+            m(a[0], b[0]) + m(a[1], b4) + m(a[2], b3) + m(a[3], b2) + m(a[4], b1),
+            m(a[0], b[1]) + m(a[1], b[0]) + m(a[2], b4) + m(a[3], b3) + m(a[4], b2),
+            m(a[0], b[2]) + m(a[1], b[1]) + m(a[2], b[0]) + m(a[3], b4) + m(a[4], b3),
+            m(a[0], b[3]) + m(a[1], b[2]) + m(a[2], b[1]) + m(a[3], b[0]) + m(a[4], b4),
+            m(a[0], b[4]) + m(a[1], b[3]) + m(a[2], b[2]) + m(a[3], b[1]) + m(a[4], b[0]),
+        ];
+        for i in 0..4 {
+            r[i + 1] += r[i] >> 51;
+            r[i] &= MASK as u128;
+        }
+        r[0] += (r[4] >> 51) * 19;
+        r[4] &= MASK as u128;
+        Fe([r[0] as u64, r[1] as u64, r[2] as u64, r[3] as u64, r[4] as u64]).carry()
+    }
+
+    fn square(&self) -> Fe {
+        self.mul(self)
+    }
+
+    // Square and multiply over a public exponent, so always the same steps.
+    fn pow(&self, exp: &[u8; 32]) -> Fe {
+        let mut r = Fe::one();
+        for i in (0..256).rev() {
+            r = r.square();
+            if exp[i / 8] >> (i % 8) & 1 == 1 {
+                r = r.mul(self);
+            }
+        }
+        r
+    }
+
+    fn invert(&self) -> Fe {
+        self.pow(&P_MINUS_2)
+    }
+
+    fn is_negative(&self) -> u8 {
+        self.to_bytes()[0] & 1
+    }
+
+    fn equals(&self, b: &Fe) -> bool {
+        self.to_bytes() == b.to_bytes()
+    }
+
+    // Swaps a and b when `swap` is 1, without branching on it.
+    fn cswap(a: &mut Fe, b: &mut Fe, swap: u64) {
+        let mask = swap.wrapping_neg();
+        for i in 0..5 {
+            let t = mask & (a.0[i] ^ b.0[i]);
+            a.0[i] ^= t;
+            b.0[i] ^= t;
+        }
+    }
+}
+
+// A point in extended twisted Edwards coordinates.
+#[derive(Clone, Copy)]
+struct Point {
+    x: Fe,
+    y: Fe,
+    z: Fe,
+    t: Fe,
+}
+
+struct Curve {
+    d: Fe,
+    d2: Fe,
+    sqrt_m1: Fe,
+    base: Point,
+}
+
+impl Curve {
+
+    fn new() -> Curve {
+        // d = -121665 / 121666
+        let d = Fe::small(121665).neg().mul(&Fe::small(121666).invert());
+        let sqrt_m1 = Fe::small(2).pow(&P_MINUS_1_DIV_4);
+        let mut curve = Curve{ d: d, d2: d.add(&d), sqrt_m1: sqrt_m1, base: identity() };
+        let mut b = [0x66; 32];
+        b[0] = 0x58;
+        curve.base = curve.decode(&b).unwrap();
+        curve
+    }
+
+    // RFC 8032 §5.1.3, for public keys and signatures.
+    fn decode(&self, bytes: &[u8]) -> Option<Point> {
+        if bytes.len() != 32 {
+            return None
+        }
+        let sign = bytes[31] >> 7;
+        let y = Fe::from_bytes(bytes);
+        let mut canonical = [0; 32];
+        canonical.copy_from_slice(bytes);
+        canonical[31] &= 0x7f;
+        if y.to_bytes() != canonical {
+            return None
+        }
+        let yy = y.square();
+        let u = yy.sub(&Fe::one());
+        let v = self.d.mul(&yy).add(&Fe::one());
+        let v3 = v.square().mul(&v);
+        let v7 = v3.square().mul(&v);
+        let mut x = u.mul(&v3).mul(&u.mul(&v7).pow(&P_MINUS_5_DIV_8));
+        let vxx = v.mul(&x.square());
+        if vxx.equals(&u.neg()) {
+            x = x.mul(&self.sqrt_m1);
+        } else if !vxx.equals(&u) {
+            return None
+        }
+        if x.equals(&Fe::zero()) && sign == 1 {
+            return None
+        }
+        if x.is_negative() != sign {
+            x = x.neg();
+        }
+        Some(Point{ x: x, y: y, z: Fe::one(), t: x.mul(&y) })
+    }
+
+    fn encode(&self, a: &Point) -> [u8; 32] {
+        let zinv = a.z.invert();
+        let mut out = a.y.mul(&zinv).to_bytes();
+        out[31] |= a.x.mul(&zinv).is_negative() << 7;
+        out
+    }
+
+    // add-2008-hwcd-3, complete so it also doubles
+    fn add(&self, a: &Point, b: &Point) -> Point {
+        let aa = a.y.sub(&a.x).mul(&b.y.sub(&b.x));
+        let bb = a.y.add(&a.x).mul(&b.y.add(&b.x));
+        let c = a.t.mul(&self.d2).mul(&b.t);
+        let zz = a.z.mul(&b.z);
+        let d = zz.add(&zz);
+        let (e, f, g, h) = (bb.sub(&aa), d.sub(&c), d.add(&c), bb.add(&aa));
+        Point{ x: e.mul(&f), y: g.mul(&h), t: e.mul(&h), z: f.mul(&g) }
+    }
+
+    fn neg(&self, a: &Point) -> Point {
+        Point{ x: a.x.neg(), y: a.y, z: a.z, t: a.t.neg() }
+    }
+
+    // k * a for a little endian 256 bit scalar: a ladder doing the same
+    // additions for every bit and swapping its two points by mask.
+    fn mul(&self, k: &[u8; 32], a: &Point) -> Point {
+        let (mut r0, mut r1) = (identity(), *a);
+        for i in (0..256).rev() {
+            let bit = (k[i / 8] >> (i % 8) & 1) as u64;
+            cswap(&mut r0, &mut r1, bit);
+            r1 = self.add(&r0, &r1);
+            r0 = self.add(&r0, &r0);
+            cswap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+}
+
+fn identity() -> Point {
+    Point{ x: Fe::zero(), y: Fe::one(), z: Fe::one(), t: Fe::zero() }
+}
+
+fn cswap(a: &mut Point, b: &mut Point, swap: u64) {
+    Fe::cswap(&mut a.x, &mut b.x, swap);
+    Fe::cswap(&mut a.y, &mut b.y, swap);
+    Fe::cswap(&mut a.z, &mut b.z, swap);
+    Fe::cswap(&mut a.t, &mut b.t, swap);
+}
+
+// A little endian number of any length modulo L. Each bit shifts in and is
+// followed by a subtraction of L that is kept or dropped by mask.
+fn reduce(le: &[u8]) -> [u8; 32] {
+    let mut acc = [0u64; 4];
+    for i in (0..le.len() * 8).rev() {
+        // acc < L, so 2 * acc + 1 still fits
+        let mut carry = (le[i / 8] >> (i % 8) & 1) as u64;
+        for limb in acc.iter_mut() {
+            let top = *limb >> 63;
+            *limb = *limb << 1 | carry;
+            carry = top;
+        }
+        let mut diff = [0u64; 4];
+        let mut borrow = 0u64;
+        for j in 0..4 {
+            let (d, b1) = acc[j].overflowing_sub(L[j]);
+            let (d, b2) = d.overflowing_sub(borrow);
+            diff[j] = d;
+            borrow = (b1 | b2) as u64;
+        }
+        // all ones when acc was below L and stays as it is
+        let keep = borrow.wrapping_neg();
+        for j in 0..4 {
+            acc[j] = acc[j] & keep | diff[j] & !keep;
+        }
+    }
+    let mut out = [0; 32];
+    for (i, limb) in acc.iter().enumerate() {
+        for j in 0..8 {
+            out[i * 8 + j] = (limb >> (8 * j)) as u8;
+        }
+    }
+    out
+}
+
+// (a * b + c) mod L for little endian 256 bit numbers.
+fn mul_add(a: &[u8; 32], b: &[u8; 32], c: &[u8; 32]) -> [u8; 32] {
+    let limbs = |x: &[u8; 32]| {
+        let mut l = [0u64; 4];
+        for i in 0..32 {
+            l[i / 8] |= (x[i] as u64) << (8 * (i % 8));
+        }
+        l
+    };
+    let (a, b, c) = (limbs(a), limbs(b), limbs(c));
+    let mut wide = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let t = wide[i + j] as u128 + a[i] as u128 * b[j] as u128 + carry;
+            wide[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        wide[i + 4] = carry as u64;
+    }
+    let mut carry = 0u128;
+    for i in 0..8 {
+        let t = wide[i] as u128 + if i < 4 { c[i] as u128 } else { 0 } + carry;
+        wide[i] = t as u64;
+        carry = t >> 64;
+    }
+    let mut bytes = [0; 64];
+    for (i, limb) in wide.iter().enumerate() {
+        for j in 0..8 {
+            bytes[i * 8 + j] = (limb >> (8 * j)) as u8;
+        }
+    }
+    reduce(&bytes)
+}
+
+// The clamped secret scalar and the nonce prefix of a seed (RFC 8032 §5.1.5).
+fn expand(seed: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let h = Sha512::digest(seed);
+    let (mut a, mut prefix) = ([0; 32], [0; 32]);
+    a.copy_from_slice(&h[..32]);
+    prefix.copy_from_slice(&h[32..]);
+    a[0] &= 0xf8;
+    a[31] &= 0x7f;
+    a[31] |= 0x40;
+    (a, prefix)
+}
+
+/// The public key of a 32 byte secret seed (RFC 8032 §5.1.5).
+pub fn public_key(seed: &[u8]) -> Vec<u8> {
+    let curve = Curve::new();
+    let (a, _) = expand(seed);
+    curve.encode(&curve.mul(&a, &curve.base)).to_vec()
+}
+
+/// Signs `data` with the key of a 32 byte secret seed (RFC 8032 §5.1.6).
+pub fn sign(seed: &[u8], data: &[u8]) -> Vec<u8> {
+    let curve = Curve::new();
+    let (a, prefix) = expand(seed);
+    let public_key = curve.encode(&curve.mul(&a, &curve.base));
+
+    let mut h = Sha512::new();
+    h.update(&prefix);
+    h.update(data);
+    let r = reduce(&h.finish());
+    let big_r = curve.encode(&curve.mul(&r, &curve.base));
+
+    let mut h = Sha512::new();
+    h.update(&big_r);
+    h.update(&public_key);
+    h.update(data);
+    let k = reduce(&h.finish());
+
+    let mut sig = big_r.to_vec();
+    sig.extend_from_slice(&mul_add(&k, &a, &r));
+    sig
+}
+
+/// Verifies an Ed25519 signature (RFC 8032 §5.1.7).
+pub fn verify(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+    if key.len() != 32 || signature.len() != 64 {
+        return false
+    }
+    let curve = Curve::new();
+    let a = match curve.decode(key) {
+        Some(a) => a,
+        None => return false,
+    };
+    let mut s = [0; 32];
+    s.copy_from_slice(&signature[32..]);
+    if reduce(&s) != s {
+        return false
+    }
+    let mut h = Sha512::new();
+    h.update(&signature[..32]);
+    h.update(key);
+    h.update(data);
+    let k = reduce(&h.finish());
+
+    let sb = curve.mul(&s, &curve.base);
+    let ka = curve.mul(&k, &curve.neg(&a));
+    &curve.encode(&curve.add(&sb, &ka))[..] == &signature[..32]
+}
+
+
+#[cfg(test)] use rustc_serialize::hex::{FromHex, ToHex};
+#[cfg(test)] use bignum::Modulus;
+
+#[test]
+fn field_arithmetic() {
+    let x = Fe::small(9);
+    assert!(x.mul(&x.invert()).equals(&Fe::one()));
+    assert!(Fe::small(2).sub(&Fe::small(5)).add(&Fe::small(3)).equals(&Fe::zero()));
+    // p and values above it encode canonically
+    let p = "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f".from_hex().unwrap();
+    assert_eq!(Fe::from_bytes(&p).to_bytes(), [0; 32]);
+    let mut p1 = p.clone();
+    p1[0] += 1;
+    assert_eq!(Fe::from_bytes(&p1).to_bytes(), Fe::one().to_bytes());
+    // sqrt(-1) squared
+    let curve = Curve::new();
+    assert!(curve.sqrt_m1.square().equals(&Fe::one().neg()));
+    assert_eq!(Fe::small(121665).neg().to_bytes()[..4].to_hex(), "ac24feff");
+}
+
+#[test]
+fn scalars_modulo_l() {
+    // checked against the general Montgomery arithmetic
+    let l = Modulus::new(&"1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed".from_hex().unwrap());
+    let be = |le: &[u8]| le.iter().rev().cloned().collect::<Vec<u8>>();
+    let wide: Vec<u8> = (0..64).map(|i| (i * 37 + 11) as u8).collect();
+    assert_eq!(be(&reduce(&wide)), l.bytes(&l.reduce(&be(&wide))));
+
+    let mut a = [0; 32];
+    let mut b = [0; 32];
+    let mut c = [0; 32];
+    for i in 0..32 {
+        a[i] = (i * 7 + 3) as u8;
+        b[i] = 0xff - i as u8;
+        c[i] = (i * 13) as u8;
+    }
+    a[31] &= 0x7f;
+    let expected = l.add(&l.mul(&l.reduce(&be(&a)), &l.reduce(&be(&b))), &l.reduce(&be(&c)));
+    assert_eq!(be(&mul_add(&a, &b, &c)), l.bytes(&expected));
+
+    let mut l_le = be(&"1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed".from_hex().unwrap());
+    assert_eq!(reduce(&l_le), [0; 32]);
+    l_le[0] -= 1;
+    assert_eq!(&reduce(&l_le)[..], &l_le[..]);
+}
+
+#[test]
+fn ladder_matches_addition() {
+    let curve = Curve::new();
+    let b = curve.base;
+    let mut k = [0; 32];
+    k[0] = 5;
+    let five = curve.add(&curve.add(&curve.add(&b, &b), &curve.add(&b, &b)), &b);
+    assert_eq!(curve.encode(&curve.mul(&k, &b)), curve.encode(&five));
+    // L * B is the identity
+    let l: Vec<u8> = "edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010".from_hex().unwrap();
+    let mut l32 = [0; 32];
+    l32.copy_from_slice(&l);
+    assert_eq!(curve.encode(&curve.mul(&l32, &b)), curve.encode(&identity()));
+    assert_eq!(curve.encode(&curve.add(&b, &curve.neg(&b))), curve.encode(&identity()));
+}
+
+#[test]
+fn rfc8032_vectors() {
+    // RFC 8032 §7.1 tests 1 to 3: secret key, public key, message, signature
+    let vectors = [
+        ("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+         "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+         "",
+         "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"),
+        ("4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+         "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+         "72",
+         "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00"),
+        ("c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+         "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+         "af82",
+         "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a"),
+    ];
+    for &(seed, key, msg, sig) in vectors.iter() {
+        let (seed, key, msg, sig) = (seed.from_hex().unwrap(), key.from_hex().unwrap(),
+                                     msg.from_hex().unwrap(), sig.from_hex().unwrap());
+        assert_eq!(public_key(&seed), key);
+        assert_eq!(sign(&seed, &msg), sig);
+        assert!(verify(&key, &msg, &sig));
+        let mut bad = sig.clone();
+        bad[0] ^= 1;
+        assert!(!verify(&key, &msg, &bad));
+        // S at or above L is rejected (RFC 8032 §5.1.7)
+        let mut high = sig.clone();
+        high[63] |= 0x10;
+        assert!(!verify(&key, &msg, &high));
+    }
+}
@@ -0,0 +1,360 @@
+use std::fs::File;
+use std::io::{self, Read};
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use std::collections::HashMap;
+
+use dns::{Message, RCode};
+
+/// Seconds an upstream server has to answer before the next is tried.
+pub const FORWARD_TIMEOUT: u64 = 2;
+
+// Bound on outstanding queries; beyond it new queries fail immediately.
+const MAX_PENDING: usize = 4096;
+
+// Bytes read from `/dev/urandom` at a time.
+const RANDOM_BLOCK: usize = 1024;
+
+/// Where a forwarded query came from and its answer goes back to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Client {
+    Udp(SocketAddr),
+    /// The token of a TCP connection.
+    Tcp(usize),
+    /// The slot of a query whose answer is held back for validation.
+    Validating(usize),
+    /// The slot of a signed request, whose answer is signed on its way back.
+    Signed(usize),
+    /// A lookup of keys the validator needs.
+    Fetch,
+}
+
+/// What to do with a query after an upstream failed to answer in time.
+pub enum Retry {
+    /// Send the rewritten query to the next upstream, from the socket
+    /// with this index.
+    Resend(Vec<u8>, SocketAddr, usize),
+    /// Every upstream failed; answer the client with this response. The
+    /// duration is the time since the query was first forwarded.
+    Fail(Message, Client, Duration),
+}
+
+/// A matched upstream response.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Answered {
+    pub client: Client,
+    pub upstream: SocketAddr,
+    /// The view the query was forwarded for.
+    pub view: usize,
+    /// Time since the query was sent to this upstream.
+    pub rtt: Duration,
+    /// Time since the query was first forwarded.
+    pub elapsed: Duration,
+}
+
+struct Pending {
+    id: u16,
+    client: Client,
+    view: usize,
+    query: Vec<u8>,
+    question: Vec<u8>,
+    upstreams: Vec<SocketAddr>,
+    next: usize,
+    socket: usize,
+    sent: u64,
+    started: Instant,
+    sent_at: Instant,
+}
+
+/// Relays queries to upstream servers, rewriting message ids so answers
+/// can be matched back to the client that asked. Queries leave from one of
+/// several sockets picked at random, so a spoofed answer has to guess the
+/// port as well as the id (RFC 5452 §9.2).
+pub struct Forwarder {
+    pending: HashMap<u16, Pending>,
+    sockets: usize,
+    random: Random,
+}
+
+impl Forwarder {
+
+    /// A forwarder sending from `sockets` sockets.
+    pub fn new(sockets: usize) -> io::Result<Forwarder> {
+        Ok(Forwarder{
+            pending: HashMap::new(),
+            sockets: sockets,
+            random: try!(Random::new()),
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize { self.pending.len() }
+
+    /// A socket picked at random to send from.
+    pub fn socket(&mut self) -> usize {
+        self.random.next() as usize % self.sockets
+    }
+
+    /// Whether queries sent from a socket are still waiting for answers.
+    pub fn in_use(&self, socket: usize) -> bool {
+        self.pending.values().any(|p| p.socket == socket)
+    }
+
+    /// Takes the raw `query` from `client` in `view` and returns it
+    /// rewritten with a fresh id along with the upstream to send it to and
+    /// the socket to send it from, or `None` if too many queries are
+    /// outstanding or there is nowhere to send it.
+    pub fn query(&mut self, query: &[u8], client: Client, view: usize, upstreams: &[SocketAddr], now: u64) -> Option<(Vec<u8>, SocketAddr, usize)> {
+        if upstreams.is_empty() || query.len() < 12 || self.pending.len() >= MAX_PENDING {
+            return None
+        }
+        let question = match question(query) {
+            Some(q) => q.to_vec(),
+            None => return None,
+        };
+        let id = self.id();
+        let socket = self.socket();
+        let mut query = query.to_vec();
+        let orig = (query[0] as u16) << 8 | query[1] as u16;
+        set_id(&mut query, id);
+
+        let upstream = upstreams[0];
+        self.pending.insert(id, Pending{
+            id: orig,
+            client: client,
+            view: view,
+            query: query.clone(),
+            question: question,
+            upstreams: upstreams.to_vec(),
+            next: 1,
+            socket: socket,
+            sent: now,
+            started: Instant::now(),
+            sent_at: Instant::now(),
+        });
+        Some((query, upstream, socket))
+    }
+
+    /// Matches a response received from `from` on `socket` against the
+    /// outstanding queries. On a match the id is restored in place and the
+    /// query it answers is returned; spoofed or late responses give `None`.
+    pub fn response(&mut self, resp: &mut [u8], from: &SocketAddr, socket: usize) -> Option<Answered> {
+        if resp.len() < 12 {
+            return None
+        }
+        let id = (resp[0] as u16) << 8 | resp[1] as u16;
+        let matches = match self.pending.get(&id) {
+            Some(p) => p.upstreams[p.next - 1] == *from && p.socket == socket &&
+                question(resp).map_or(false, |q| q.eq_ignore_ascii_case(&p.question)),
+            None => false,
+        };
+        if !matches {
+            return None
+        }
+        let p = self.pending.remove(&id).unwrap();
+        set_id(resp, p.id);
+        Some(Answered{
+            client: p.client,
+            upstream: *from,
+            view: p.view,
+            rtt: p.sent_at.elapsed(),
+            elapsed: p.started.elapsed(),
+        })
+    }
+
+    /// Forgets the queries of a client that went away, so a later client
+    /// reusing its TCP token is not sent their answers.
+    pub fn cancel(&mut self, client: Client) {
+        self.pending.retain(|_, p| p.client != client);
+    }
+
+    /// Retries queries not answered within `FORWARD_TIMEOUT` on the next
+    /// upstream, failing them with SERVFAIL when none are left. Each retry
+    /// comes with the upstream that timed out.
+    pub fn expire(&mut self, now: u64) -> Vec<(SocketAddr, Retry)> {
+        let expired: Vec<u16> = self.pending.iter()
+            .filter(|&(_, p)| now >= p.sent + FORWARD_TIMEOUT)
+            .map(|(&id, _)| id)
+            .collect();
+
+        let mut retries = vec![];
+        for id in expired {
+            let mut p = self.pending.remove(&id).unwrap();
+            let timed_out = p.upstreams[p.next - 1];
+            if p.next < p.upstreams.len() {
+                let upstream = p.upstreams[p.next];
+                p.next += 1;
+                p.socket = self.socket();
+                p.sent = now;
+                p.sent_at = Instant::now();
+                let new_id = self.id();
+                set_id(&mut p.query, new_id);
+                retries.push((timed_out, Retry::Resend(p.query.clone(), upstream, p.socket)));
+                self.pending.insert(new_id, p);
+            } else {
+                set_id(&mut p.query, p.id);
+                if let Ok(req) = Message::unpack(&p.query, 0) {
+                    let resp = Message::new_error(&req, RCode::SERVFAIL);
+                    retries.push((timed_out, Retry::Fail(resp, p.client, p.started.elapsed())));
+                }
+            }
+        }
+        retries
+    }
+
+    // A random id no outstanding query has.
+    fn id(&mut self) -> u16 {
+        let mut id = self.random.next();
+        while self.pending.contains_key(&id) {
+            id = self.random.next();
+        }
+        id
+    }
+}
+
+#[inline]
+fn set_id(msg: &mut [u8], id: u16) {
+    msg[0] = (id >> 8) as u8;
+    msg[1] = id as u8;
+}
+
+// The raw bytes of the single question of a message, without compression.
+fn question(msg: &[u8]) -> Option<&[u8]> {
+    if msg.len() < 12 || msg[4] != 0 || msg[5] != 1 {
+        return None
+    }
+    let mut off = 12;
+    loop {
+        match msg.get(off) {
+            Some(&0) => break,
+            Some(&len) if len & 0xc0 == 0 => off += 1 + len as usize,
+            _ => return None,
+        }
+    }
+    if off + 5 > msg.len() {
+        return None
+    }
+    Some(&msg[12..off + 5])
+}
+
+/// Random numbers off-path attackers can not predict, for message ids
+/// and the like, read from `/dev/urandom` a block at a time.
+pub struct Random {
+    file: File,
+    block: Vec<u8>,
+    used: usize,
+}
+
+impl Random {
+
+    pub fn new() -> io::Result<Random> {
+        let mut random = Random{
+            file: try!(File::open("/dev/urandom")),
+            block: vec![0; RANDOM_BLOCK],
+            used: 0,
+        };
+        try!(random.file.read_exact(&mut random.block));
+        Ok(random)
+    }
+
+    pub fn next(&mut self) -> u16 {
+        if self.used + 2 > self.block.len() {
+            // reading does not fail once the device is open
+            self.file.read_exact(&mut self.block).expect("failed to read /dev/urandom");
+            self.used = 0;
+        }
+        let v = (self.block[self.used] as u16) << 8 | self.block[self.used + 1] as u16;
+        self.used += 2;
+        v
+    }
+}
+
+
+#[cfg(test)] use rustc_serialize::hex::FromHex;
+
+#[cfg(test)]
+fn addr(s: &str) -> SocketAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn forward_and_match_response() {
+    let mut fwd = Forwarder::new(4).unwrap();
+    let client = Client::Udp(addr("192.0.2.1:5353"));
+    let upstreams = [addr("198.51.100.53:53"), addr("203.0.113.53:53")];
+
+    // www.google.com IN A
+    let query = "2b22010000010000000000000377777706676f6f676c6503636f6d0000010001".from_hex().unwrap();
+    let (sent, upstream, socket) = fwd.query(&query, client, 0, &upstreams, 0).unwrap();
+    assert_eq!(upstream, upstreams[0]);
+    assert_eq!(&sent[2..], &query[2..]);
+    assert!(socket < 4 && fwd.in_use(socket));
+
+    // the same answer with the rewritten id, case randomised by the upstream
+    let mut resp = sent.clone();
+    resp[2] |= 0x80;
+    resp[13] = b'W';
+
+    // answers from elsewhere, to another port or for another question are
+    // ignored
+    assert_eq!(fwd.response(&mut resp.clone(), &upstreams[1], socket), None);
+    assert_eq!(fwd.response(&mut resp.clone(), &upstreams[0], (socket + 1) % 4), None);
+    let mut other = resp.clone();
+    other[14] = b'x';
+    assert_eq!(fwd.response(&mut other, &upstreams[0], socket), None);
+
+    let answered = fwd.response(&mut resp, &upstreams[0], socket).unwrap();
+    assert_eq!((answered.client, answered.upstream, answered.view), (client, upstreams[0], 0));
+    assert_eq!(&resp[..2], &query[..2]);
+    assert_eq!(fwd.len(), 0);
+    assert!(!fwd.in_use(socket));
+
+    // a response is only accepted once
+    let mut resp = sent.clone();
+    assert_eq!(fwd.response(&mut resp, &upstreams[0], socket), None);
+}
+
+#[test]
+fn retry_next_upstream() {
+    let mut fwd = Forwarder::new(4).unwrap();
+    let client = Client::Tcp(1024);
+    let upstreams = [addr("198.51.100.53:53"), addr("203.0.113.53:53")];
+    let query = "2b22010000010000000000000377777706676f6f676c6503636f6d0000010001".from_hex().unwrap();
+
+    let (sent, _, socket) = fwd.query(&query, client, 3, &upstreams, 10).unwrap();
+    assert!(fwd.expire(11).is_empty());
+
+    let retried = match fwd.expire(12).pop() {
+        Some((timed_out, Retry::Resend(msg, upstream, _))) => {
+            assert_eq!(timed_out, upstreams[0]);
+            assert_eq!(upstream, upstreams[1]);
+            msg
+        }
+        _ => panic!("expected a retry"),
+    };
+    assert_eq!(&retried[2..], &query[2..]);
+
+    // the first upstream is too late now
+    assert_eq!(fwd.response(&mut sent.clone(), &upstreams[0], socket), None);
+
+    match fwd.expire(14).pop() {
+        Some((timed_out, Retry::Fail(resp, to, _))) => {
+            assert_eq!(timed_out, upstreams[1]);
+            assert_eq!(to, client);
+            assert_eq!(resp.id, 0x2b22);
+            assert_eq!(resp.rcode, RCode::SERVFAIL);
+        }
+        _ => panic!("expected a failure"),
+    }
+    assert_eq!(fwd.len(), 0);
+}
+
+#[test]
+fn random_ids() {
+    let mut random = Random::new().unwrap();
+    let ids: Vec<u16> = (0..RANDOM_BLOCK).map(|_| random.next()).collect();
+    let mut distinct = ids.clone();
+    distinct.sort();
+    distinct.dedup();
+    assert!(distinct.len() > RANDOM_BLOCK * 9 / 10);
+}
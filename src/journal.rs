@@ -0,0 +1,402 @@
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use {Result, Error};
+use dns::{RName, RType};
+use dns::message::Resource;
+use dns::rname::Compressor;
+use zone::{Diff, Zone, serial_lt};
+use signing::SigningConfig;
+
+/// Size in bytes past which a journal is folded into its zone file.
+pub const MAX_JOURNAL: u64 = 1 << 20;
+
+const MAGIC: &'static [u8] = b"RGJNL001";
+
+/// A zone loaded from a master file whose changes are kept in a journal
+/// next to it, in the same path with `.jnl` appended.
+#[derive(Clone, Debug)]
+pub struct ZoneFile {
+    pub origin: RName,
+    pub file: PathBuf,
+    /// Signs the zone at load, after every update and again before its
+    /// signatures expire, the signatures journaled as any other change.
+    pub signing: Option<SigningConfig>,
+    /// Zone files of the same view whose A and AAAA records get PTR
+    /// records in this reverse zone at load. They are not journaled, so
+    /// they follow the forward zones from one start to the next.
+    pub ptrs_from: Vec<RName>,
+}
+
+/// The changes made to a zone since its master file was written, appended
+/// one serial at a time.
+///
+/// Every change is written as a record of its length, the deleted and
+/// added records in wire format and a checksum, and synced before it is
+/// considered written. A record cut short by a crash or failing the
+/// checksum ends the journal and is cut off when it is opened again.
+pub struct Journal {
+    // the master file the journal belongs to
+    path: PathBuf,
+    file: File,
+    // the serial the zone file and the journal lead up to
+    serial: u32,
+    len: u64,
+}
+
+impl Journal {
+
+    /// Loads the zone at `path` and applies the changes journaled since it
+    /// was written. The changes replayed are the ones IXFR is served from.
+    pub fn load<P: AsRef<Path>>(origin: RName, path: P) -> Result<(Zone, Journal)> {
+        let path = path.as_ref();
+        let mut zone = try!(Zone::open(origin, path));
+        let (file, diffs, len) = try!(open(&journal_path(path)));
+        let mut journal = Journal{ path: path.to_path_buf(), file: file, serial: zone.serial(), len: len };
+
+        let mut replayed = 0;
+        for diff in diffs {
+            // left over from before the zone file was last written
+            if !serial_lt(zone.serial(), diff.to_serial()) {
+                continue
+            }
+            if let Err(e) = zone.apply(diff) {
+                println!("zone {} journal does not apply on serial {}: {:?}", zone.origin(), zone.serial(), e);
+                break
+            }
+            replayed += 1;
+        }
+        if replayed > 0 {
+            println!("zone {} replayed {} changes up to serial {}", zone.origin(), replayed, zone.serial());
+        }
+        journal.serial = zone.serial();
+        Ok((zone, journal))
+    }
+
+    /// Writes `zone` to `path` and starts an empty journal next to it.
+    pub fn create<P: AsRef<Path>>(zone: &Zone, path: P) -> Result<Journal> {
+        let path = path.as_ref();
+        try!(zone.save(path));
+        let (file, _, len) = try!(open(&journal_path(path)));
+        let mut journal = Journal{ path: path.to_path_buf(), file: file, serial: zone.serial(), len: len };
+        try!(journal.truncate());
+        Ok(journal)
+    }
+
+    /// Size of the journal in bytes.
+    #[inline]
+    pub fn len(&self) -> u64 { self.len }
+
+    /// The serial the zone file and the journal lead up to.
+    #[inline]
+    pub fn serial(&self) -> u32 { self.serial }
+
+    /// Brings the journal up to the current serial of `zone`, appending the
+    /// changes since the last one written. A zone that can not be reached
+    /// by its changes, as after a full transfer, or a journal grown too
+    /// large is compacted instead.
+    pub fn sync(&mut self, zone: &Zone) -> Result<()> {
+        if zone.serial() == self.serial {
+            return Ok(())
+        }
+        let diffs: Vec<Diff> = match zone.changes_since(self.serial) {
+            Some(diffs) => diffs.into_iter().cloned().collect(),
+            None => return self.compact(zone),
+        };
+        for diff in diffs.iter() {
+            try!(self.append(diff));
+        }
+        if self.len > MAX_JOURNAL {
+            return self.compact(zone)
+        }
+        Ok(())
+    }
+
+    /// Writes `zone` as a fresh zone file and empties the journal. The
+    /// changes left in the journal if this is interrupted in between are
+    /// those in the file already and skipped when loading.
+    pub fn compact(&mut self, zone: &Zone) -> Result<()> {
+        try!(zone.save(&self.path));
+        try!(self.truncate());
+        self.serial = zone.serial();
+        Ok(())
+    }
+
+    fn append(&mut self, diff: &Diff) -> Result<()> {
+        if diff.from_serial() != self.serial {
+            return Err(Error::BadJournal)
+        }
+        let record = try!(encode(diff));
+        try!(self.file.seek(SeekFrom::Start(self.len)));
+        try!(self.file.write_all(&record));
+        try!(self.file.sync_data());
+        self.len += record.len() as u64;
+        self.serial = diff.to_serial();
+        Ok(())
+    }
+
+    fn truncate(&mut self) -> Result<()> {
+        try!(self.file.set_len(MAGIC.len() as u64));
+        try!(self.file.sync_all());
+        self.len = MAGIC.len() as u64;
+        Ok(())
+    }
+}
+
+fn journal_path(path: &Path) -> PathBuf {
+    let mut jnl = path.as_os_str().to_owned();
+    jnl.push(".jnl");
+    PathBuf::from(jnl)
+}
+
+// Opens or creates the journal at `path`, returning the changes in it and
+// its length once anything after the last complete record is cut off.
+fn open(path: &Path) -> Result<(File, Vec<Diff>, u64)> {
+    let mut file = try!(OpenOptions::new().read(true).write(true).create(true).open(path));
+    let mut data = vec![];
+    try!(file.read_to_end(&mut data));
+
+    if data.len() < MAGIC.len() {
+        // created now, or cut short while being created
+        if !MAGIC.starts_with(&data) {
+            return Err(Error::BadJournal)
+        }
+        try!(file.set_len(0));
+        try!(file.seek(SeekFrom::Start(0)));
+        try!(file.write_all(MAGIC));
+        try!(file.sync_all());
+        return Ok((file, vec![], MAGIC.len() as u64))
+    }
+    if &data[..MAGIC.len()] != MAGIC {
+        return Err(Error::BadJournal)
+    }
+
+    let mut diffs = vec![];
+    let mut offset = MAGIC.len();
+    while let Some((diff, next)) = decode(&data, offset) {
+        diffs.push(diff);
+        offset = next;
+    }
+    if offset < data.len() {
+        println!("journal {} cut off after {} changes", path.display(), diffs.len());
+        try!(file.set_len(offset as u64));
+        try!(file.sync_all());
+    }
+    Ok((file, diffs, offset as u64))
+}
+
+// A change as a journal record: the length of what follows up to the
+// checksum, the number of records deleted and added, the records in the
+// order of an IXFR response and a checksum over all but the length.
+fn encode(diff: &Diff) -> Result<Vec<u8>> {
+    let rrs: Vec<&Resource> = Some(&diff.from).into_iter()
+        .chain(diff.deleted.iter())
+        .chain(Some(&diff.to))
+        .chain(diff.added.iter())
+        .collect();
+    let len = 4 + rrs.iter().fold(0, |len, rr| len + rr.len());
+    let mut buf = vec![0; 4 + len + 4];
+    put_u16(&mut buf[..], 4, diff.deleted.len() as u16);
+    put_u16(&mut buf[..], 6, diff.added.len() as u16);
+
+    let mut offset = 8;
+    for rr in rrs {
+        offset = try!(rr.pack(&mut buf, offset, &mut Compressor::none()));
+    }
+    let sum = checksum(&buf[4..offset]);
+    put_u32(&mut buf[..], 0, (offset - 4) as u32);
+    put_u32(&mut buf[..], offset, sum);
+    buf.truncate(offset + 4);
+    Ok(buf)
+}
+
+// The change of the record at `offset` with the offset after it, or `None`
+// if the record is incomplete or damaged.
+fn decode(data: &[u8], offset: usize) -> Option<(Diff, usize)> {
+    if offset + 4 > data.len() {
+        return None
+    }
+    let len = get_u32(data, offset) as usize;
+    let end = offset + 4 + len;
+    if len < 4 || end + 4 > data.len() || checksum(&data[offset + 4..end]) != get_u32(data, end) {
+        return None
+    }
+    let record = &data[..end];
+    let deleted = get_u16(record, offset + 4) as usize;
+    let added = get_u16(record, offset + 6) as usize;
+
+    let mut at = offset + 8;
+    let mut rrs = Vec::with_capacity(deleted + added + 2);
+    for _ in 0..deleted + added + 2 {
+        let (rr, next) = match Resource::unpack(record, at) {
+            Ok(rr) => rr,
+            Err(_) => return None,
+        };
+        rrs.push(rr);
+        at = next;
+    }
+    if at != end || rrs[0].rtype != RType::SOA || rrs[deleted + 1].rtype != RType::SOA {
+        return None
+    }
+
+    let added: Vec<Resource> = rrs.split_off(deleted + 2);
+    let to = rrs.pop().unwrap();
+    let deleted = rrs.split_off(1);
+    let from = rrs.pop().unwrap();
+    Some((Diff{ from: from, to: to, deleted: deleted, added: added }, end + 4))
+}
+
+// 32 bit FNV-1a, enough to tell a torn write from a complete one.
+fn checksum(data: &[u8]) -> u32 {
+    data.iter().fold(0x811c9dc5, |h, &b| (h ^ b as u32).wrapping_mul(0x01000193))
+}
+
+fn put_u16(buf: &mut [u8], offset: usize, v: u16) {
+    buf[offset] = (v >> 8) as u8;
+    buf[offset + 1] = v as u8;
+}
+
+fn put_u32(buf: &mut [u8], offset: usize, v: u32) {
+    put_u16(buf, offset, (v >> 16) as u16);
+    put_u16(buf, offset + 2, v as u16);
+}
+
+fn get_u16(buf: &[u8], offset: usize) -> u16 {
+    (buf[offset] as u16) << 8 | buf[offset + 1] as u16
+}
+
+fn get_u32(buf: &[u8], offset: usize) -> u32 {
+    (get_u16(buf, offset) as u32) << 16 | get_u16(buf, offset + 2) as u32
+}
+
+
+#[cfg(test)] use std::env;
+#[cfg(test)] use std::fs;
+#[cfg(test)] use dns::{RData, Class};
+#[cfg(test)] use zone::soa_serial;
+
+#[cfg(test)]
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("reagent-journal-{}-{}", name, ::std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[cfg(test)]
+fn example() -> Zone {
+    Zone::parse("example.com".parse().unwrap(), "@ 60 IN SOA ns hm 1 60 60 60 60\n\
+                                                 @ 60 IN NS ns\n\
+                                                 ns 60 IN A 192.0.2.53").unwrap()
+}
+
+// Adds www A 192.0.2.<octet>, bumping the serial.
+#[cfg(test)]
+fn change(zone: &mut Zone, octet: u8) {
+    let serial = zone.serial();
+    let added = Resource{
+        name: "www.example.com".parse().unwrap(),
+        rtype: RType::A,
+        class: Class::IN,
+        ttl: 60,
+        data: RData::A(192, 0, 2, octet),
+    };
+    let diff = Diff{ from: soa_serial(zone, serial), to: soa_serial(zone, serial + 1), deleted: vec![], added: vec![added] };
+    zone.apply(diff).unwrap();
+}
+
+#[test]
+fn replay_journal() {
+    let dir = temp_dir("replay");
+    let path = dir.join("example.com.zone");
+    let mut zone = example();
+    let mut journal = Journal::create(&zone, &path).unwrap();
+    assert_eq!(journal.len(), MAGIC.len() as u64);
+
+    change(&mut zone, 1);
+    change(&mut zone, 2);
+    journal.sync(&zone).unwrap();
+    assert_eq!(journal.serial(), 3);
+
+    let (loaded, journal) = Journal::load(zone.origin().clone(), &path).unwrap();
+    assert_eq!(loaded.serial(), 3);
+    assert_eq!(loaded.records(), zone.records());
+    assert_eq!(journal.serial(), 3);
+    // the replayed changes serve IXFR
+    assert_eq!(loaded.changes_since(1), zone.changes_since(1));
+
+    // compacting writes the zone file and leaves an empty journal
+    let mut journal = journal;
+    journal.compact(&loaded).unwrap();
+    assert_eq!(journal.len(), MAGIC.len() as u64);
+    let (reloaded, _) = Journal::load(zone.origin().clone(), &path).unwrap();
+    assert_eq!(reloaded.records(), zone.records());
+    assert_eq!(reloaded.changes_since(1), None);
+
+    // a zone not reached by changes, like after a full transfer
+    let mut other = example();
+    change(&mut other, 9);
+    change(&mut other, 10);
+    change(&mut other, 11);
+    let mut fresh = Zone::new(other.origin().clone());
+    for rr in other.records() {
+        fresh.insert(rr.clone()).unwrap();
+    }
+    journal.sync(&fresh).unwrap();
+    assert_eq!(journal.len(), MAGIC.len() as u64);
+    let (reloaded, _) = Journal::load(zone.origin().clone(), &path).unwrap();
+    assert_eq!(reloaded.serial(), 4);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn survive_torn_writes() {
+    let dir = temp_dir("torn");
+    let path = dir.join("example.com.zone");
+    let jnl = journal_path(&path);
+    let mut zone = example();
+    let mut journal = Journal::create(&zone, &path).unwrap();
+    change(&mut zone, 1);
+    journal.sync(&zone).unwrap();
+    let complete = journal.len();
+    change(&mut zone, 2);
+    journal.sync(&zone).unwrap();
+    let full = fs::read(&jnl).unwrap();
+    drop(journal);
+
+    // cut the last record at every byte: the first change survives
+    for cut in complete as usize..full.len() {
+        fs::write(&jnl, &full[..cut]).unwrap();
+        let (loaded, mut journal) = Journal::load(zone.origin().clone(), &path).unwrap();
+        assert_eq!(loaded.serial(), 2, "cut at {}", cut);
+        assert_eq!(journal.len(), complete);
+        assert_eq!(fs::metadata(&jnl).unwrap().len(), complete);
+
+        // and the journal is appended to where it was cut off
+        journal.sync(&zone).unwrap();
+        let (loaded, _) = Journal::load(zone.origin().clone(), &path).unwrap();
+        assert_eq!(loaded.serial(), 3);
+    }
+
+    // a damaged record ends the journal as well
+    let mut damaged = full.clone();
+    let last = damaged.len() - 6;
+    damaged[last] ^= 0xff;
+    fs::write(&jnl, &damaged).unwrap();
+    let (loaded, _) = Journal::load(zone.origin().clone(), &path).unwrap();
+    assert_eq!(loaded.serial(), 2);
+
+    // a journal cut while being created is started again
+    fs::write(&jnl, &MAGIC[..3]).unwrap();
+    let (loaded, journal) = Journal::load(zone.origin().clone(), &path).unwrap();
+    assert_eq!(loaded.serial(), 1);
+    assert_eq!(journal.len(), MAGIC.len() as u64);
+
+    // but anything else is not mistaken for a journal
+    fs::write(&jnl, b"zone data").unwrap();
+    assert!(Journal::load(zone.origin().clone(), &path).is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
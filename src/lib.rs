@@ -1,12 +1,36 @@
+#![cfg_attr(feature = "bench", feature(test))]
+
 extern crate mio;
 
 #[cfg(test)]
 extern crate rustc_serialize;
+#[cfg(all(test, feature = "bench"))]
+extern crate test;
 
 use std::{io, result};
-pub use server::Server;
+pub use server::{Server, ServerConfig};
 
 pub mod server;
+pub mod acl;
+pub mod rrl;
+pub mod blocklist;
+pub mod zone;
+pub mod xfr;
+pub mod secondary;
+pub mod notify;
+pub mod update;
+pub mod journal;
+pub mod digest;
+pub mod bignum;
+pub mod ed25519;
+pub mod signature;
+pub mod validator;
+pub mod signing;
+pub mod tsig;
+pub mod view;
+pub mod forward;
+pub mod cache;
+pub mod metrics;
 mod dns;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -15,6 +39,25 @@ pub type Result<T> = result::Result<T, Error>;
 pub enum Error {
     Io(io::Error),
     Dns(dns::Error),
+    BadPrefix,
+    UnknownAcl,
+    BadZone,
+    /// An incoming zone transfer was refused, malformed or incomplete.
+    BadTransfer,
+    /// A zone journal is not one written by this server, or does not
+    /// follow on from the zone.
+    BadJournal,
+    /// A TSIG or zone signing key with an unknown algorithm or a secret
+    /// that is not base64, or a key file not matching its key.
+    BadKey,
+    /// A trust anchor that is not a DS or DNSKEY record.
+    BadAnchor,
+    /// Rate limiting settings it can not work with, such as a table of
+    /// fewer than two responses.
+    BadRrl,
+    /// Views told apart by the address queries arrive on, when the server
+    /// is bound to a wildcard address and can not know it.
+    BadView,
 }
 
 impl From<io::Error> for Error {
@@ -0,0 +1,330 @@
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::time::Duration;
+use std::collections::HashMap;
+
+use cache::Cache;
+use rrl::RrlStats;
+use dns::{RCode, RType};
+
+/// Upper bounds in seconds of the latency histogram buckets.
+pub const LATENCY_BUCKETS: [f64; 12] = [
+    0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5,
+];
+
+// Largest HTTP request head accepted by the metrics listener.
+pub const MAX_HTTP_REQUEST: usize = 8192;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Transport {
+    Udp,
+    Tcp,
+}
+
+impl Transport {
+    fn name(&self) -> &'static str {
+        match *self {
+            Transport::Udp => "udp",
+            Transport::Tcp => "tcp",
+        }
+    }
+}
+
+/// Why a packet was dropped without an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DropReason {
+    /// The client is not allowed the access it asked for.
+    Acl,
+    /// A blocklist said to drop the query.
+    Policy,
+    /// Too many queries are being forwarded already.
+    Overload,
+    /// A response nobody asked for.
+    Unexpected,
+}
+
+impl DropReason {
+    fn name(&self) -> &'static str {
+        match *self {
+            DropReason::Acl => "acl",
+            DropReason::Policy => "policy",
+            DropReason::Overload => "overload",
+            DropReason::Unexpected => "unexpected",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    buckets: [u64; 12],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+
+    pub fn new() -> Histogram {
+        Histogram{ buckets: [0; 12], sum: 0.0, count: 0 }
+    }
+
+    pub fn observe(&mut self, d: Duration) {
+        let secs = d.as_secs() as f64 + d.subsec_nanos() as f64 / 1e9;
+        for (i, &le) in LATENCY_BUCKETS.iter().enumerate() {
+            if secs <= le {
+                self.buckets[i] += 1;
+            }
+        }
+        self.sum += secs;
+        self.count += 1;
+    }
+
+    #[inline]
+    pub fn count(&self) -> u64 { self.count }
+
+    fn write(&self, out: &mut String, name: &str, labels: &str) {
+        let sep = if labels.is_empty() { "" } else { "," };
+        for (i, le) in LATENCY_BUCKETS.iter().enumerate() {
+            let _ = write!(out, "{}_bucket{{{}{}le=\"{}\"}} {}\n", name, labels, sep, le, self.buckets[i]);
+        }
+        let _ = write!(out, "{}_bucket{{{}{}le=\"+Inf\"}} {}\n", name, labels, sep, self.count);
+        let _ = write!(out, "{}_sum{{{}}} {}\n", name, labels, self.sum);
+        let _ = write!(out, "{}_count{{{}}} {}\n", name, labels, self.count);
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Upstream {
+    requests: u64,
+    timeouts: u64,
+    rtt: Histogram,
+}
+
+/// Counters exported in the Prometheus text format.
+#[derive(Clone, Debug, Default)]
+pub struct Metrics {
+    queries: HashMap<(Transport, u16, u8), u64>,
+    latency: HashMap<Transport, Histogram>,
+    upstreams: HashMap<SocketAddr, Upstream>,
+    dropped: HashMap<DropReason, u64>,
+    malformed: u64,
+}
+
+impl Metrics {
+
+    pub fn new() -> Metrics {
+        Metrics::default()
+    }
+
+    /// Accounts a query answered after `elapsed`.
+    pub fn query(&mut self, transport: Transport, qtype: RType, rcode: RCode, elapsed: Duration) {
+        *self.queries.entry((transport, qtype as u16, rcode as u8)).or_insert(0) += 1;
+        self.latency.entry(transport).or_insert_with(Histogram::new).observe(elapsed);
+    }
+
+    pub fn queries(&self, transport: Transport, qtype: RType, rcode: RCode) -> u64 {
+        self.queries.get(&(transport, qtype as u16, rcode as u8)).cloned().unwrap_or(0)
+    }
+
+    pub fn malformed(&mut self) {
+        self.malformed += 1;
+    }
+
+    pub fn dropped(&mut self, reason: DropReason) {
+        *self.dropped.entry(reason).or_insert(0) += 1;
+    }
+
+    pub fn upstream_request(&mut self, upstream: &SocketAddr) {
+        self.upstream(upstream).requests += 1;
+    }
+
+    pub fn upstream_timeout(&mut self, upstream: &SocketAddr) {
+        self.upstream(upstream).timeouts += 1;
+    }
+
+    pub fn upstream_response(&mut self, upstream: &SocketAddr, rtt: Duration) {
+        self.upstream(upstream).rtt.observe(rtt);
+    }
+
+    fn upstream(&mut self, upstream: &SocketAddr) -> &mut Upstream {
+        self.upstreams.entry(*upstream).or_insert_with(|| Upstream{
+            requests: 0,
+            timeouts: 0,
+            rtt: Histogram::new(),
+        })
+    }
+
+    /// Renders all metrics in the Prometheus text exposition format.
+    pub fn render(&self, cache: Option<&Cache>, rrl: Option<&RrlStats>) -> String {
+        let mut out = String::new();
+
+        header(&mut out, "reagent_queries_total", "counter", "Queries answered by transport, query type and response code.");
+        let mut queries: Vec<_> = self.queries.iter().collect();
+        queries.sort();
+        for &(&(transport, qtype, rcode), n) in queries.iter() {
+            let qtype = RType::unpack(qtype).map(|t| format!("{:?}", t)).unwrap_or(qtype.to_string());
+            let rcode = RCode::unpack(rcode).map(|c| format!("{:?}", c)).unwrap_or(rcode.to_string());
+            let _ = write!(out, "reagent_queries_total{{transport=\"{}\",qtype=\"{}\",rcode=\"{}\"}} {}\n",
+                           transport.name(), qtype, rcode, n);
+        }
+
+        header(&mut out, "reagent_query_duration_seconds", "histogram", "Time from receiving a query to sending its answer.");
+        let mut latency: Vec<_> = self.latency.iter().collect();
+        latency.sort_by_key(|&(t, _)| *t);
+        for &(transport, h) in latency.iter() {
+            h.write(&mut out, "reagent_query_duration_seconds", &format!("transport=\"{}\"", transport.name()));
+        }
+
+        if let Some(cache) = cache {
+            let stats = cache.stats();
+            header(&mut out, "reagent_cache_entries", "gauge", "Responses held in the cache.");
+            let _ = write!(out, "reagent_cache_entries {}\n", cache.len());
+            header(&mut out, "reagent_cache_hits_total", "counter", "Queries answered from the cache.");
+            let _ = write!(out, "reagent_cache_hits_total {}\n", stats.hits);
+            header(&mut out, "reagent_cache_misses_total", "counter", "Cache lookups without a usable answer.");
+            let _ = write!(out, "reagent_cache_misses_total {}\n", stats.misses);
+            header(&mut out, "reagent_cache_evictions_total", "counter", "Responses evicted before they expired.");
+            let _ = write!(out, "reagent_cache_evictions_total {}\n", stats.evictions);
+        }
+
+        let mut upstreams: Vec<_> = self.upstreams.iter().map(|(a, u)| (a.to_string(), u)).collect();
+        upstreams.sort_by(|a, b| a.0.cmp(&b.0));
+        header(&mut out, "reagent_upstream_requests_total", "counter", "Queries sent to each upstream server.");
+        for &(ref addr, u) in upstreams.iter() {
+            let _ = write!(out, "reagent_upstream_requests_total{{upstream=\"{}\"}} {}\n", addr, u.requests);
+        }
+        header(&mut out, "reagent_upstream_timeouts_total", "counter", "Queries an upstream server did not answer in time.");
+        for &(ref addr, u) in upstreams.iter() {
+            let _ = write!(out, "reagent_upstream_timeouts_total{{upstream=\"{}\"}} {}\n", addr, u.timeouts);
+        }
+        header(&mut out, "reagent_upstream_rtt_seconds", "histogram", "Round trip time of answered upstream queries.");
+        for &(ref addr, u) in upstreams.iter() {
+            u.rtt.write(&mut out, "reagent_upstream_rtt_seconds", &format!("upstream=\"{}\"", addr));
+        }
+
+        header(&mut out, "reagent_malformed_packets_total", "counter", "Packets that could not be parsed.");
+        let _ = write!(out, "reagent_malformed_packets_total {}\n", self.malformed);
+
+        header(&mut out, "reagent_dropped_packets_total", "counter", "Packets dropped without an answer by reason.");
+        let mut dropped: Vec<_> = self.dropped.iter().collect();
+        dropped.sort();
+        for &(reason, n) in dropped.iter() {
+            let _ = write!(out, "reagent_dropped_packets_total{{reason=\"{}\"}} {}\n", reason.name(), n);
+        }
+
+        if let Some(rrl) = rrl {
+            header(&mut out, "reagent_rate_limited_responses_total", "counter", "Responses slipped or dropped by rate limiting.");
+            let _ = write!(out, "reagent_rate_limited_responses_total{{action=\"slip\"}} {}\n", rrl.slipped);
+            let _ = write!(out, "reagent_rate_limited_responses_total{{action=\"drop\"}} {}\n", rrl.dropped);
+        }
+
+        out
+    }
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = write!(out, "# HELP {} {}\n# TYPE {} {}\n", name, help, name, kind);
+}
+
+/// Answers an HTTP request for the metrics page once its head has been
+/// read completely, `None` until then. Only `GET` and `HEAD` of `/metrics`
+/// are served and the connection is closed after the response.
+pub fn http_response<F: FnOnce() -> String>(req: &[u8], render: F) -> Option<Vec<u8>> {
+    let end = match req.windows(4).position(|w| w == b"\r\n\r\n") {
+        Some(end) => end,
+        None if req.len() >= MAX_HTTP_REQUEST => return Some(http("400 Bad Request", "", true)),
+        None => return None,
+    };
+    let head = String::from_utf8_lossy(&req[..end]);
+    let mut line = head.lines().next().unwrap_or("").split(' ');
+    let (method, path, version) = (line.next(), line.next(), line.next());
+
+    Some(match (method, path.map(|p| p.split('?').next().unwrap_or(p)), version) {
+        (Some(m), Some("/metrics"), Some(v)) if v.starts_with("HTTP/1.") && (m == "GET" || m == "HEAD") => {
+            http("200 OK", &render(), m == "GET")
+        }
+        (Some(m), Some(_), Some(v)) if v.starts_with("HTTP/1.") && m != "GET" && m != "HEAD" => {
+            http("405 Method Not Allowed", "", true)
+        }
+        (Some(_), Some(_), Some(v)) if v.starts_with("HTTP/1.") => http("404 Not Found", "", true),
+        _ => http("400 Bad Request", "", true),
+    })
+}
+
+fn http(status: &str, body: &str, with_body: bool) -> Vec<u8> {
+    let mut resp = format!("HTTP/1.1 {}\r\n\
+                            Content-Type: text/plain; version=0.0.4; charset=utf-8\r\n\
+                            Content-Length: {}\r\n\
+                            Connection: close\r\n\r\n", status, body.len()).into_bytes();
+    if with_body {
+        resp.extend(body.as_bytes());
+    }
+    resp
+}
+
+
+#[cfg(test)] use cache::CacheConfig;
+
+#[test]
+fn render_metrics() {
+    let mut m = Metrics::new();
+    m.query(Transport::Udp, RType::A, RCode::NOERROR, Duration::from_millis(2));
+    m.query(Transport::Udp, RType::A, RCode::NOERROR, Duration::from_millis(30));
+    m.query(Transport::Udp, RType::MX, RCode::NXDOMAIN, Duration::from_secs(5));
+    m.malformed();
+    m.dropped(DropReason::Acl);
+    let upstream = "192.0.2.53:53".parse().unwrap();
+    m.upstream_request(&upstream);
+    m.upstream_request(&upstream);
+    m.upstream_timeout(&upstream);
+    m.upstream_response(&upstream, Duration::from_millis(20));
+
+    let cache = Cache::new(CacheConfig::default());
+    let out = m.render(Some(&cache), Some(&RrlStats{ responses: 10, slipped: 2, dropped: 3 }));
+
+    for line in [
+        "# TYPE reagent_queries_total counter",
+        "reagent_queries_total{transport=\"udp\",qtype=\"A\",rcode=\"NOERROR\"} 2",
+        "reagent_queries_total{transport=\"udp\",qtype=\"MX\",rcode=\"NXDOMAIN\"} 1",
+        "reagent_query_duration_seconds_bucket{transport=\"udp\",le=\"0.0025\"} 1",
+        "reagent_query_duration_seconds_bucket{transport=\"udp\",le=\"0.05\"} 2",
+        "reagent_query_duration_seconds_bucket{transport=\"udp\",le=\"2.5\"} 2",
+        "reagent_query_duration_seconds_bucket{transport=\"udp\",le=\"+Inf\"} 3",
+        "reagent_query_duration_seconds_count{transport=\"udp\"} 3",
+        "reagent_cache_entries 0",
+        "reagent_cache_misses_total 0",
+        "reagent_upstream_requests_total{upstream=\"192.0.2.53:53\"} 2",
+        "reagent_upstream_timeouts_total{upstream=\"192.0.2.53:53\"} 1",
+        "reagent_upstream_rtt_seconds_count{upstream=\"192.0.2.53:53\"} 1",
+        "reagent_malformed_packets_total 1",
+        "reagent_dropped_packets_total{reason=\"acl\"} 1",
+        "reagent_rate_limited_responses_total{action=\"slip\"} 2",
+        "reagent_rate_limited_responses_total{action=\"drop\"} 3",
+    ].iter() {
+        assert!(out.lines().any(|l| l == *line), "missing {}", line);
+    }
+}
+
+#[test]
+fn serve_http_requests() {
+    let body = || "reagent_malformed_packets_total 0\n".to_string();
+
+    assert_eq!(http_response(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n", &body), None);
+
+    let resp = String::from_utf8(http_response(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n", &body).unwrap()).unwrap();
+    assert!(resp.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(resp.contains("Content-Length: 34\r\n"));
+    assert!(resp.ends_with("\r\n\r\nreagent_malformed_packets_total 0\n"));
+
+    let resp = String::from_utf8(http_response(b"HEAD /metrics HTTP/1.0\r\n\r\n", &body).unwrap()).unwrap();
+    assert!(resp.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(resp.ends_with("\r\n\r\n"));
+
+    let status = |req: &[u8]| {
+        let resp = http_response(req, &body).unwrap();
+        String::from_utf8(resp).unwrap().lines().next().unwrap().to_string()
+    };
+    assert_eq!(status(b"GET / HTTP/1.1\r\n\r\n"), "HTTP/1.1 404 Not Found");
+    assert_eq!(status(b"POST /metrics HTTP/1.1\r\n\r\n"), "HTTP/1.1 405 Method Not Allowed");
+    assert_eq!(status(b"\x16\x03\x01\r\n\r\n"), "HTTP/1.1 400 Bad Request");
+    assert_eq!(status(&[b'a'; MAX_HTTP_REQUEST]), "HTTP/1.1 400 Bad Request");
+}
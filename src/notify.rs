@@ -0,0 +1,231 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::io;
+use std::collections::HashMap;
+
+use dns::{Message, OpCode, RType, RName, RData};
+use dns::message::MessageBuilder;
+use forward::Random;
+use zone::Zone;
+
+/// Seconds to wait for a secondary to acknowledge a NOTIFY before sending
+/// it again.
+pub const NOTIFY_INTERVAL: u64 = 15;
+
+/// Times a NOTIFY is sent before giving up on a secondary (RFC 1996 §3.6).
+pub const MAX_NOTIFY_TRIES: usize = 5;
+
+struct Pending {
+    msg: Vec<u8>,
+    target: SocketAddr,
+    origin: RName,
+    tries: usize,
+    sent: u64,
+}
+
+/// Tells secondaries about new serials of the zones served, resending each
+/// NOTIFY until it is acknowledged.
+pub struct Notifier {
+    // the serial last seen of every zone, by view and apex
+    serials: HashMap<(usize, RName), u32>,
+    pending: HashMap<u16, Pending>,
+    random: Random,
+}
+
+impl Notifier {
+
+    pub fn new() -> io::Result<Notifier> {
+        Ok(Notifier{
+            serials: HashMap::new(),
+            pending: HashMap::new(),
+            random: try!(Random::new()),
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize { self.pending.len() }
+
+    /// Compares the serial of `zone` in `view` with the one seen last and
+    /// returns the NOTIFY messages to send if it changed: to `also` and to
+    /// the addresses of the NS hosts held in the zone, except the primary
+    /// named in the SOA.
+    pub fn check(&mut self, view: usize, zone: &Zone, also: &[SocketAddr], now: u64) -> Vec<(Vec<u8>, SocketAddr)> {
+        let key = (view, zone.origin().clone());
+        let serial = zone.serial();
+        match self.serials.insert(key, serial) {
+            Some(last) if last != serial => {}
+            _ => return vec![],
+        }
+
+        // a newer serial replaces notifications still going out
+        self.pending.retain(|_, p| p.origin != *zone.origin());
+
+        let mut sent = vec![];
+        for target in targets(zone, also) {
+            let mut id = self.random.next();
+            while self.pending.contains_key(&id) {
+                id = self.random.next();
+            }
+            let msg = match notify(id, zone) {
+                Some(msg) => msg,
+                None => continue,
+            };
+            sent.push((msg.clone(), target));
+            self.pending.insert(id, Pending{
+                msg: msg,
+                target: target,
+                origin: zone.origin().clone(),
+                tries: 1,
+                sent: now,
+            });
+        }
+        sent
+    }
+
+    /// Matches a response from `from` against the notifications sent,
+    /// returning whether it acknowledged one.
+    pub fn response(&mut self, resp: &Message, from: &SocketAddr) -> bool {
+        let matches = match self.pending.get(&resp.id) {
+            Some(p) => resp.qr && resp.opcode == OpCode::NOTIFY && p.target == *from &&
+                resp.questions.first().map_or(false, |q| q.name == p.origin),
+            None => false,
+        };
+        if matches {
+            self.pending.remove(&resp.id);
+        }
+        matches
+    }
+
+    /// The notifications to send again, dropping those sent too often.
+    pub fn expire(&mut self, now: u64) -> Vec<(Vec<u8>, SocketAddr)> {
+        let mut resend = vec![];
+        let mut expired = vec![];
+        for (&id, p) in self.pending.iter_mut() {
+            if now < p.sent + NOTIFY_INTERVAL {
+                continue
+            }
+            if p.tries >= MAX_NOTIFY_TRIES {
+                println!("zone {} NOTIFY to {} not acknowledged", p.origin, p.target);
+                expired.push(id);
+                continue
+            }
+            p.tries += 1;
+            p.sent = now;
+            resend.push((p.msg.clone(), p.target));
+        }
+        for id in expired {
+            self.pending.remove(&id);
+        }
+        resend
+    }
+}
+
+/// A NOTIFY for the current SOA of `zone` (RFC 1996 §3.7).
+fn notify(id: u16, zone: &Zone) -> Option<Vec<u8>> {
+    let soa = match zone.soa() {
+        Some(soa) => soa.clone(),
+        None => return None,
+    };
+    let msg = MessageBuilder::query(zone.origin().clone(), RType::SOA)
+        .id(id)
+        .opcode(OpCode::NOTIFY)
+        .aa(true)
+        .rd(false)
+        .answer(soa)
+        .build();
+    let mut buf = [0; 512];
+    msg.pack(&mut buf, 0).ok().map(|len| buf[..len].to_vec())
+}
+
+// `also` followed by the addresses of the in-zone NS hosts but the primary.
+fn targets(zone: &Zone, also: &[SocketAddr]) -> Vec<SocketAddr> {
+    let primary = match zone.soa().map(|r| &r.data) {
+        Some(&RData::SOA{ ref mname, .. }) => Some(mname),
+        _ => None,
+    };
+    let records = zone.records();
+
+    let mut targets = also.to_vec();
+    let hosts = records.iter().filter_map(|r| match r.data {
+        RData::NS(ref host) if r.name == *zone.origin() && Some(host) != primary => Some(host),
+        _ => None,
+    });
+    for host in hosts.collect::<Vec<&RName>>() {
+        for rr in records.iter().filter(|r| r.name == *host) {
+            let ip = match rr.data {
+                RData::A(a, b, c, d) => IpAddr::V4(Ipv4Addr::new(a, b, c, d)),
+                RData::AAAA(a, b, c, d, e, f, g, h) => IpAddr::V6(Ipv6Addr::new(a, b, c, d, e, f, g, h)),
+                _ => continue,
+            };
+            let addr = SocketAddr::new(ip, 53);
+            if !targets.contains(&addr) {
+                targets.push(addr);
+            }
+        }
+    }
+    targets
+}
+
+
+#[cfg(test)] use zone::{Diff, soa_serial};
+#[cfg(test)] use dns::RCode;
+
+#[cfg(test)]
+fn example(serial: u32) -> Zone {
+    Zone::parse("example.com".parse().unwrap(), &format!("@ 60 IN SOA ns1 hm {} 60 60 60 60\n\
+                                                         @ 60 IN NS ns1\n\
+                                                         @ 60 IN NS ns2\n\
+                                                         @ 60 IN NS ns.example.net.\n\
+                                                         ns1 60 IN A 192.0.2.1\n\
+                                                         ns2 60 IN A 192.0.2.2\n\
+                                                         ns2 60 IN AAAA 2001:db8::2", serial)).unwrap()
+}
+
+#[cfg(test)]
+fn bump(zone: &mut Zone) {
+    let serial = zone.serial();
+    let diff = Diff{ from: soa_serial(zone, serial), to: soa_serial(zone, serial + 1), deleted: vec![], added: vec![] };
+    zone.apply(diff).unwrap();
+}
+
+#[test]
+fn notify_on_new_serial() {
+    let mut notifier = Notifier::new().unwrap();
+    let mut zone = example(1);
+    let also: SocketAddr = "198.51.100.1:5353".parse().unwrap();
+
+    // nothing to tell about the serial a zone is loaded with
+    assert!(notifier.check(0, &zone, &[also], 0).is_empty());
+    assert!(notifier.check(0, &zone, &[also], 1).is_empty());
+
+    bump(&mut zone);
+    let sent = notifier.check(0, &zone, &[also], 2);
+    let to: Vec<SocketAddr> = sent.iter().map(|&(_, t)| t).collect();
+    assert_eq!(to, vec![also, "192.0.2.2:53".parse().unwrap(), "[2001:db8::2]:53".parse().unwrap()]);
+    assert_eq!(notifier.len(), 3);
+
+    let msg = Message::unpack(&sent[0].0, 0).unwrap();
+    assert_eq!(msg.opcode, OpCode::NOTIFY);
+    assert!(msg.aa);
+    assert_eq!(msg.questions[0].rtype, RType::SOA);
+    assert_eq!(msg.answers, vec![zone.soa().unwrap().clone()]);
+
+    // acknowledged by the right server only
+    let ack = Message::new_error(&msg, RCode::NOERROR);
+    assert!(!notifier.response(&ack, &to[1]));
+    assert!(notifier.response(&ack, &also));
+    assert!(!notifier.response(&ack, &also));
+    assert_eq!(notifier.len(), 2);
+
+    // the rest is sent again until it runs out of tries
+    assert!(notifier.expire(2 + NOTIFY_INTERVAL - 1).is_empty());
+    let mut now = 2;
+    for _ in 1..MAX_NOTIFY_TRIES {
+        now += NOTIFY_INTERVAL;
+        assert_eq!(notifier.expire(now).len(), 2);
+    }
+    assert!(notifier.expire(now + NOTIFY_INTERVAL).is_empty());
+    assert_eq!(notifier.len(), 0);
+
+    // zones in other views are tracked apart
+    assert!(notifier.check(1, &zone, &[], now).is_empty());
+}
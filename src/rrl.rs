@@ -0,0 +1,275 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use {Result, Error};
+use acl::Prefix;
+use dns::{Message, RCode, RName};
+
+/// Response rate limiting settings. Responses are accounted per client
+/// prefix and per distinct response (qname, qtype and rcode).
+#[derive(Clone, Copy, Debug)]
+pub struct RrlConfig {
+    /// Identical responses allowed per second to one client prefix.
+    pub responses_per_second: u32,
+    /// Seconds over which the rate is averaged; a limited client has to
+    /// stay below the rate this long before it is answered again.
+    pub window: u32,
+    /// Every `slip`th limited response is sent truncated (TC=1) instead of
+    /// being dropped so legitimate clients can retry over TCP. 0 never slips,
+    /// 1 slips every response.
+    pub slip: u32,
+    pub ipv4_prefix_len: u8,
+    pub ipv6_prefix_len: u8,
+    /// Upper bound on tracked responses, at least 2; the oldest are
+    /// evicted first.
+    pub max_entries: usize,
+}
+
+impl Default for RrlConfig {
+    fn default() -> RrlConfig {
+        RrlConfig{
+            responses_per_second: 5,
+            window: 15,
+            slip: 2,
+            ipv4_prefix_len: 24,
+            ipv6_prefix_len: 56,
+            max_entries: 100_000,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    Send,
+    Slip,
+    Drop,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RrlStats {
+    pub responses: u64,
+    pub slipped: u64,
+    pub dropped: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Key {
+    prefix: Prefix,
+    // names hash and compare ignoring case
+    qname: Option<RName>,
+    qtype: u16,
+    rcode: u8,
+}
+
+struct Bucket {
+    balance: i64,
+    last: u64,
+    limited: u32,
+}
+
+pub struct RateLimiter {
+    config: RrlConfig,
+    buckets: HashMap<Key, Bucket>,
+    pruned: u64,
+    stats: RrlStats,
+}
+
+impl RateLimiter {
+
+    pub fn new(config: RrlConfig) -> Result<RateLimiter> {
+        if config.max_entries < 2 {
+            return Err(Error::BadRrl)
+        }
+        Ok(RateLimiter{
+            config: config,
+            buckets: HashMap::new(),
+            pruned: 0,
+            stats: RrlStats::default(),
+        })
+    }
+
+    #[inline]
+    pub fn stats(&self) -> &RrlStats { &self.stats }
+
+    #[inline]
+    pub fn len(&self) -> usize { self.buckets.len() }
+
+    /// Accounts `resp` sent to `client` at `now` (in seconds) and decides
+    /// whether it goes out, is slipped or is dropped.
+    pub fn check(&mut self, client: &IpAddr, resp: &Message, now: u64) -> Action {
+        if now >= self.pruned + self.config.window as u64 ||
+            self.buckets.len() >= self.config.max_entries {
+            self.prune(now);
+        }
+
+        let key = self.key(client, resp);
+        let rate = self.config.responses_per_second as i64;
+        let floor = -(rate * cmp::max(self.config.window, 1) as i64);
+
+        let bucket = self.buckets.entry(key).or_insert(Bucket{ balance: rate, last: now, limited: 0 });
+        let elapsed = now.saturating_sub(bucket.last) as i64;
+        bucket.balance = cmp::min(rate, bucket.balance.saturating_add(elapsed.saturating_mul(rate)));
+        bucket.balance = cmp::max(floor, bucket.balance - 1);
+        bucket.last = now;
+
+        self.stats.responses += 1;
+        if bucket.balance >= 0 {
+            return Action::Send
+        }
+
+        bucket.limited += 1;
+        if self.config.slip > 0 && bucket.limited % self.config.slip == 0 {
+            self.stats.slipped += 1;
+            Action::Slip
+        } else {
+            self.stats.dropped += 1;
+            Action::Drop
+        }
+    }
+
+    /// Forgets responses that have fully recovered and, if the table is still
+    /// over its limit, the least recently seen ones until it is half full.
+    pub fn prune(&mut self, now: u64) {
+        let window = self.config.window as u64;
+        self.buckets.retain(|_, b| now.saturating_sub(b.last) <= window);
+
+        let max = self.config.max_entries;
+        if self.buckets.len() >= max {
+            let mut seen: Vec<(u64, Key)> = self.buckets.iter().map(|(k, b)| (b.last, k.clone())).collect();
+            seen.sort_by_key(|&(last, _)| last);
+            let evicted = self.buckets.len() - max / 2;
+            for &(_, ref key) in seen[..evicted].iter() {
+                self.buckets.remove(key);
+            }
+        }
+        self.pruned = now;
+    }
+
+    fn key(&self, client: &IpAddr, resp: &Message) -> Key {
+        let len = match *client {
+            IpAddr::V4(_) => self.config.ipv4_prefix_len,
+            IpAddr::V6(_) => self.config.ipv6_prefix_len,
+        };
+        let prefix = Prefix::new(client, len).unwrap_or(Prefix::host(client));
+        // errors other than NXDOMAIN are accounted together per client
+        let (qname, qtype) = match (resp.rcode, resp.questions.first()) {
+            (RCode::NOERROR, Some(q)) | (RCode::NXDOMAIN, Some(q)) => (Some(q.name.clone()), q.rtype as u16),
+            _ => (None, 0),
+        };
+        Key{
+            prefix: prefix,
+            qname: qname,
+            qtype: qtype,
+            rcode: resp.rcode as u8,
+        }
+    }
+}
+
+
+#[cfg(test)] use std::net::Ipv4Addr;
+#[cfg(test)] use dns::RType;
+#[cfg(test)] use dns::message::MessageBuilder;
+
+#[cfg(test)]
+fn resp(name: &str, rcode: RCode) -> Message {
+    let req = MessageBuilder::query(name.parse().unwrap(), RType::A).id(1).build();
+    MessageBuilder::reply_to(&req).rcode(rcode).build()
+}
+
+#[cfg(test)]
+fn ip(a: u8, b: u8, c: u8, d: u8) -> IpAddr {
+    IpAddr::V4(Ipv4Addr::new(a, b, c, d))
+}
+
+#[test]
+fn limit_identical_responses() {
+    let mut rrl = RateLimiter::new(RrlConfig{ responses_per_second: 3, slip: 0, ..RrlConfig::default() }).unwrap();
+    let r = resp("www.example.com", RCode::NOERROR);
+
+    for _ in 0..3 {
+        assert_eq!(rrl.check(&ip(192, 0, 2, 1), &r, 100), Action::Send);
+    }
+    assert_eq!(rrl.check(&ip(192, 0, 2, 1), &r, 100), Action::Drop);
+
+    // other names, types and clients are accounted separately
+    assert_eq!(rrl.check(&ip(192, 0, 2, 1), &resp("mail.example.com", RCode::NOERROR), 100), Action::Send);
+    assert_eq!(rrl.check(&ip(192, 0, 2, 1), &resp("www.example.com", RCode::NXDOMAIN), 100), Action::Send);
+    assert_eq!(rrl.check(&ip(198, 51, 100, 1), &r, 100), Action::Send);
+
+    // qnames compare case-insensitively
+    assert_eq!(rrl.check(&ip(192, 0, 2, 1), &resp("WWW.Example.COM", RCode::NOERROR), 100), Action::Drop);
+
+    // one second later the bucket has been credited again
+    assert_eq!(rrl.check(&ip(192, 0, 2, 1), &r, 101), Action::Send);
+
+    assert_eq!(rrl.stats(), &RrlStats{ responses: 9, slipped: 0, dropped: 2 });
+}
+
+#[test]
+fn aggregate_by_prefix() {
+    let mut rrl = RateLimiter::new(RrlConfig{ responses_per_second: 2, slip: 0, ..RrlConfig::default() }).unwrap();
+    let r = resp("example.com", RCode::NOERROR);
+
+    assert_eq!(rrl.check(&ip(203, 0, 113, 1), &r, 0), Action::Send);
+    assert_eq!(rrl.check(&ip(203, 0, 113, 2), &r, 0), Action::Send);
+    assert_eq!(rrl.check(&ip(203, 0, 113, 3), &r, 0), Action::Drop);
+    assert_eq!(rrl.check(&ip(203, 0, 114, 3), &r, 0), Action::Send);
+
+    let v6 = |s: &str| IpAddr::V6(s.parse().unwrap());
+    assert_eq!(rrl.check(&v6("2001:db8:0:1::1"), &r, 0), Action::Send);
+    assert_eq!(rrl.check(&v6("2001:db8:0:2::1"), &r, 0), Action::Send);
+    assert_eq!(rrl.check(&v6("2001:db8:0:3::1"), &r, 0), Action::Drop);
+    assert_eq!(rrl.check(&v6("2001:db8:0:100::1"), &r, 0), Action::Send);
+}
+
+#[test]
+fn slip_limited_responses() {
+    let mut rrl = RateLimiter::new(RrlConfig{ responses_per_second: 1, slip: 3, ..RrlConfig::default() }).unwrap();
+    let r = resp("example.com", RCode::NOERROR);
+
+    let actions: Vec<Action> = (0..7).map(|_| rrl.check(&ip(192, 0, 2, 1), &r, 0)).collect();
+    assert_eq!(actions, vec![Action::Send,
+                             Action::Drop, Action::Drop, Action::Slip,
+                             Action::Drop, Action::Drop, Action::Slip]);
+    assert_eq!(rrl.stats(), &RrlStats{ responses: 7, slipped: 2, dropped: 4 });
+}
+
+#[test]
+fn recover_after_window() {
+    let mut rrl = RateLimiter::new(RrlConfig{ responses_per_second: 2, window: 5, slip: 0, ..RrlConfig::default() }).unwrap();
+    let r = resp("example.com", RCode::NOERROR);
+
+    // a flood drives the balance down to -window * rate
+    for _ in 0..100 {
+        rrl.check(&ip(192, 0, 2, 1), &r, 10);
+    }
+    assert_eq!(rrl.check(&ip(192, 0, 2, 1), &r, 12), Action::Drop);
+    assert_eq!(rrl.check(&ip(192, 0, 2, 1), &r, 18), Action::Send);
+
+    // idle entries are forgotten once the window has passed
+    assert_eq!(rrl.len(), 1);
+    rrl.prune(30);
+    assert_eq!(rrl.len(), 0);
+}
+
+#[test]
+fn bound_table_size() {
+    let mut rrl = RateLimiter::new(RrlConfig{ max_entries: 10, ..RrlConfig::default() }).unwrap();
+    for i in 0..100 {
+        rrl.check(&ip(10, 0, i, 1), &resp("example.com", RCode::NOERROR), i as u64 / 10);
+    }
+    assert!(rrl.len() <= 10);
+
+    // a flood within one second evicts the oldest half, not all of them
+    let mut rrl = RateLimiter::new(RrlConfig{ max_entries: 4, ..RrlConfig::default() }).unwrap();
+    for i in 0..4 {
+        rrl.check(&ip(10, 0, i, 1), &resp("example.com", RCode::NOERROR), 8);
+    }
+    assert_eq!(rrl.len(), 4);
+    rrl.check(&ip(10, 0, 4, 1), &resp("example.com", RCode::NOERROR), 8);
+    assert_eq!(rrl.len(), 3);
+
+    assert!(RateLimiter::new(RrlConfig{ max_entries: 1, ..RrlConfig::default() }).is_err());
+    assert!(RateLimiter::new(RrlConfig{ max_entries: 0, ..RrlConfig::default() }).is_err());
+}
@@ -0,0 +1,542 @@
+use std::fs::File;
+use std::io::{Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpStream, UdpSocket};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use {Result, Error};
+use dns::{Message, RCode, RType, RName, RData, Class};
+use dns::message::{Question, Resource};
+use zone::{Zone, serial_lt};
+use xfr::{self, Incoming, Received};
+use journal::Journal;
+use tsig::{self, Key, Signer};
+
+// Seconds a primary has to answer a query or send the next transfer message.
+const TIMEOUT: u64 = 5;
+
+// Seconds between attempts before the zone's own retry interval is known.
+const DEFAULT_RETRY: u32 = 60;
+
+/// A zone pulled from primary servers.
+#[derive(Clone, Debug)]
+pub struct SecondaryConfig {
+    pub origin: RName,
+    /// Servers the zone is transferred from, tried in order.
+    pub primaries: Vec<SocketAddr>,
+    /// Where the transferred zone is kept, so it can be served straight
+    /// after a restart. Incremental transfers go to its journal.
+    pub file: Option<PathBuf>,
+    /// Transfers are signed with this key and have to be answered signed.
+    pub key: Option<Key>,
+}
+
+/// The outcome of checking the primaries for a newer zone.
+pub enum Refresh {
+    UpToDate,
+    Updated(Zone),
+    Failed,
+}
+
+/// Tracks when a secondary zone has to be refreshed and until when it may
+/// be served without reaching a primary (RFC 1034 §4.3.5).
+pub struct Secondary {
+    pub config: SecondaryConfig,
+    /// The view the zone is served in.
+    pub view: usize,
+    serial: Option<u32>,
+    refresh: u32,
+    retry: u32,
+    expire: u32,
+    next: u64,
+    expires: u64,
+    running: bool,
+    // a NOTIFY arrived while refreshing
+    notified: bool,
+    journal: Option<Journal>,
+}
+
+impl Secondary {
+
+    pub fn new(view: usize, config: SecondaryConfig) -> Secondary {
+        Secondary{
+            config: config,
+            view: view,
+            serial: None,
+            refresh: 0,
+            retry: DEFAULT_RETRY,
+            expire: 0,
+            next: 0,
+            expires: 0,
+            running: false,
+            notified: false,
+            journal: None,
+        }
+    }
+
+    /// Reads the copy of the zone kept on disk, if there is one. It is
+    /// served until it expires and checked against the primaries first.
+    pub fn load(&mut self, now: u64) -> Option<Zone> {
+        let zone = match self.config.file {
+            Some(ref path) if path.exists() => match Journal::load(self.config.origin.clone(), path) {
+                Ok((zone, journal)) => {
+                    self.journal = Some(journal);
+                    zone
+                }
+                Err(e) => {
+                    println!("zone {} failed to load {}: {:?}", self.config.origin, path.display(), e);
+                    return None
+                }
+            },
+            _ => return None,
+        };
+        self.loaded(&zone, now);
+        self.next = now;
+        Some(zone)
+    }
+
+    #[inline]
+    pub fn serial(&self) -> Option<u32> { self.serial }
+
+    /// Whether a refresh should be started.
+    pub fn due(&self, now: u64) -> bool {
+        !self.running && now >= self.next
+    }
+
+    /// Whether there is zone data that has not expired yet.
+    pub fn serving(&self, now: u64) -> bool {
+        self.serial.is_some() && now < self.expires
+    }
+
+    /// Whether `ip` is one of the primaries of the zone.
+    pub fn is_primary(&self, ip: &IpAddr) -> bool {
+        self.config.primaries.iter().any(|p| p.ip() == *ip)
+    }
+
+    /// Moves the next refresh up to now after a NOTIFY, or to right after
+    /// the one running (RFC 1996 §3.11).
+    pub fn notify(&mut self, now: u64) {
+        if self.running {
+            self.notified = true;
+        } else {
+            self.next = now;
+        }
+    }
+
+    /// Marks a refresh as running until `finish` is called.
+    pub fn start(&mut self) {
+        self.running = true;
+    }
+
+    /// Schedules the next refresh after the outcome of the last, returning
+    /// the zone to serve if it changed. Changed zones are written to disk.
+    pub fn finish(&mut self, refresh: Refresh, now: u64) -> Option<Zone> {
+        self.running = false;
+        let zone = match refresh {
+            Refresh::UpToDate => {
+                self.next = now + self.refresh as u64;
+                self.expires = now + self.expire as u64;
+                None
+            }
+            Refresh::Updated(zone) => {
+                if let Some(path) = self.config.file.clone() {
+                    let saved = match self.journal {
+                        Some(ref mut journal) => journal.sync(&zone),
+                        None => Journal::create(&zone, &path).map(|j| self.journal = Some(j)),
+                    };
+                    if let Err(e) = saved {
+                        println!("zone {} failed to save {}: {:?}", self.config.origin, path.display(), e);
+                    }
+                }
+                self.loaded(&zone, now);
+                self.next = now + self.refresh as u64;
+                Some(zone)
+            }
+            Refresh::Failed => {
+                self.next = now + self.retry as u64;
+                None
+            }
+        };
+        if self.notified {
+            self.notified = false;
+            self.next = now;
+        }
+        zone
+    }
+
+    fn loaded(&mut self, zone: &Zone, now: u64) {
+        if let Some(&RData::SOA{ serial, refresh, retry, expire, .. }) = zone.soa().map(|r| &r.data) {
+            self.serial = Some(serial);
+            self.refresh = refresh;
+            self.retry = retry;
+            self.expire = expire;
+            self.expires = now + expire as u64;
+        }
+    }
+}
+
+/// Asks the primaries in turn for their serial and transfers the zone from
+/// the first that answers with a newer one, incrementally if `current` is
+/// known. This blocks, so it runs off the event loop.
+pub fn refresh(config: &SecondaryConfig, current: Option<&Zone>) -> Refresh {
+    for primary in config.primaries.iter() {
+        match check(primary, config, current) {
+            Ok(refresh) => return refresh,
+            Err(e) => println!("zone {} refresh from {} failed: {:?}", config.origin, primary, e),
+        }
+    }
+    Refresh::Failed
+}
+
+fn check(primary: &SocketAddr, config: &SecondaryConfig, current: Option<&Zone>) -> Result<Refresh> {
+    if let Some(zone) = current {
+        let serial = try!(query_soa(primary, &config.origin));
+        if !serial_lt(zone.serial(), serial) {
+            return Ok(Refresh::UpToDate)
+        }
+    }
+    transfer(primary, config, current)
+}
+
+fn query_soa(primary: &SocketAddr, origin: &RName) -> Result<u32> {
+    let any = match *primary {
+        SocketAddr::V4(_) => "0.0.0.0:0",
+        SocketAddr::V6(_) => "[::]:0",
+    };
+    let socket = try!(UdpSocket::bind(any));
+    try!(socket.set_read_timeout(Some(Duration::from_secs(TIMEOUT))));
+    try!(socket.connect(primary));
+
+    let id = random_id();
+    let mut req = xfr::request(id, origin, None);
+    req.questions[0] = Question{ name: origin.clone(), rtype: RType::SOA, class: Class::IN };
+    let mut buf = [0; 4096];
+    let len = try!(req.pack(&mut buf, 0));
+    try!(socket.send(&buf[..len]));
+
+    loop {
+        let len = try!(socket.recv(&mut buf));
+        let resp = match Message::unpack(&buf[..len], 0) {
+            Ok(ref resp) if resp.id != id || !resp.qr => continue,
+            Ok(resp) => resp,
+            Err(_) => continue,
+        };
+        if resp.rcode != RCode::NOERROR || !resp.aa {
+            return Err(Error::BadTransfer)
+        }
+        return match resp.answers.iter().find(|r| r.rtype == RType::SOA).map(|r| &r.data) {
+            Some(&RData::SOA{ serial, .. }) => Ok(serial),
+            _ => Err(Error::BadTransfer),
+        }
+    }
+}
+
+fn transfer(primary: &SocketAddr, config: &SecondaryConfig, current: Option<&Zone>) -> Result<Refresh> {
+    let origin = &config.origin;
+    let received = match try!(receive(primary, config, current.and_then(|z| z.soa()))) {
+        Some(received) => received,
+        // primaries without IXFR get asked for the whole zone (RFC 1995 section 4)
+        None if current.is_some() => {
+            println!("zone {} primary {} refused IXFR, asking for AXFR", origin, primary);
+            match try!(receive(primary, config, None)) {
+                Some(received) => received,
+                None => return Err(Error::BadTransfer),
+            }
+        }
+        None => return Err(Error::BadTransfer),
+    };
+
+    match received {
+        Received::UpToDate => Ok(Refresh::UpToDate),
+        Received::Zone(rrs) => {
+            let mut zone = Zone::new(origin.clone());
+            for rr in rrs {
+                try!(zone.insert(rr));
+            }
+            if zone.soa().is_none() {
+                return Err(Error::BadTransfer)
+            }
+            Ok(Refresh::Updated(zone))
+        }
+        Received::Changes(diffs) => {
+            let mut zone = match current {
+                Some(zone) => zone.clone(),
+                None => return Err(Error::BadTransfer),
+            };
+            for diff in diffs {
+                try!(zone.apply(diff));
+            }
+            Ok(Refresh::Updated(zone))
+        }
+    }
+}
+
+/// Receives one transfer of the zone, incremental from `soa` if given.
+/// `None` if the primary refuses that kind of transfer outright.
+fn receive(primary: &SocketAddr, config: &SecondaryConfig, soa: Option<&Resource>) -> Result<Option<Received>> {
+    let origin = &config.origin;
+    let mut stream = try!(TcpStream::connect_timeout(primary, Duration::from_secs(TIMEOUT)));
+    try!(stream.set_read_timeout(Some(Duration::from_secs(TIMEOUT))));
+    try!(stream.set_write_timeout(Some(Duration::from_secs(TIMEOUT))));
+
+    let id = random_id();
+    let req = xfr::request(id, origin, soa);
+    let mut signer = config.key.clone().map(Signer::new);
+    let mut buf = vec![0; 65535];
+    let len = try!(req.pack(&mut buf, 0));
+    buf.truncate(len);
+    if let Some(ref mut signer) = signer {
+        try!(signer.sign(&mut buf, tsig::now()));
+    }
+    try!(stream.write_all(&[(buf.len() >> 8) as u8, buf.len() as u8]));
+    try!(stream.write_all(&buf));
+    buf.resize(65535, 0);
+
+    let mut incoming = Incoming::new(id);
+    let mut first = true;
+    loop {
+        let mut prefix = [0; 2];
+        try!(stream.read_exact(&mut prefix));
+        let len = (prefix[0] as usize) << 8 | prefix[1] as usize;
+        try!(stream.read_exact(&mut buf[..len]));
+        if let Some(ref mut signer) = signer {
+            if let Err(rcode) = signer.verify(&buf[..len], tsig::now()) {
+                println!("zone {} transfer from {} failed TSIG: {:?}", origin, primary, rcode);
+                return Err(Error::BadTransfer)
+            }
+        }
+        let msg = try!(Message::unpack(&buf[..len], 0));
+        if first && msg.id == id {
+            match msg.rcode {
+                RCode::NOTIMPL | RCode::REFUSED | RCode::FORMERR => return Ok(None),
+                _ => (),
+            }
+        }
+        first = false;
+        if try!(incoming.feed(&msg)) {
+            break
+        }
+    }
+    if signer.map_or(false, |s| s.unverified()) {
+        println!("zone {} transfer from {} ended unsigned", origin, primary);
+        return Err(Error::BadTransfer)
+    }
+    incoming.finish().map(Some)
+}
+
+// An unpredictable message id, so off-path attackers can not inject data.
+fn random_id() -> u16 {
+    let mut id = [0; 2];
+    let _ = File::open("/dev/urandom").and_then(|mut f| f.read_exact(&mut id));
+    (id[0] as u16) << 8 | id[1] as u16
+}
+
+
+#[cfg(test)] use std::{fs, thread};
+#[cfg(test)] use std::net::TcpListener;
+#[cfg(test)] use std::sync::{Arc, Mutex};
+#[cfg(test)] use zone::{Diff, soa_serial};
+
+/// Serves a zone like a primary would: SOA queries over UDP and transfers
+/// over TCP, on the same port. Transfers signed with one of `keys` are
+/// answered signed. Without `ixfr`, IXFR requests are answered NOTIMP.
+#[cfg(test)]
+fn primary(zone: Arc<Mutex<Zone>>, keys: Vec<Key>, ixfr: bool) -> SocketAddr {
+    let udp = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let addr = udp.local_addr().unwrap();
+    let tcp = TcpListener::bind(addr).unwrap();
+
+    let z = zone.clone();
+    thread::spawn(move || {
+        let mut buf = [0; 4096];
+        while let Ok((len, src)) = udp.recv_from(&mut buf) {
+            let resp = z.lock().unwrap().answer(&Message::unpack(&buf[..len], 0).unwrap());
+            let len = resp.pack(&mut buf, 0).unwrap();
+            udp.send_to(&buf[..len], src).unwrap();
+        }
+    });
+    thread::spawn(move || {
+        for stream in tcp.incoming() {
+            let mut stream = stream.unwrap();
+            let mut prefix = [0; 2];
+            stream.read_exact(&mut prefix).unwrap();
+            let mut buf = vec![0; (prefix[0] as usize) << 8 | prefix[1] as usize];
+            stream.read_exact(&mut buf).unwrap();
+            let mut signer = match tsig::verify(&keys, &buf, tsig::now()) {
+                tsig::Verified::Valid(signer, _) => Some(signer),
+                _ => None,
+            };
+            let req = Message::unpack(&buf, 0).unwrap();
+            let msgs = match req.questions[0].rtype {
+                RType::IXFR if !ixfr => vec![Message::new_error(&req, RCode::NOTIMPL)],
+                _ => xfr::transfer(&zone.lock().unwrap(), &req),
+            };
+            for msg in msgs {
+                let mut buf = vec![0; 65535];
+                let len = msg.pack(&mut buf, 0).unwrap();
+                buf.truncate(len);
+                if let Some(ref mut signer) = signer {
+                    signer.sign(&mut buf, tsig::now()).unwrap();
+                }
+                stream.write_all(&[(buf.len() >> 8) as u8, buf.len() as u8]).unwrap();
+                stream.write_all(&buf).unwrap();
+            }
+        }
+    });
+    addr
+}
+
+#[test]
+fn refresh_from_primary() {
+    let origin: RName = "example.com".parse().unwrap();
+    let zone = Zone::parse(origin.clone(), "@ 60 IN SOA ns hm 1 3600 600 86400 300\n\
+                                            @ 60 IN NS ns\n\
+                                            ns 60 IN A 192.0.2.53").unwrap();
+    let served = Arc::new(Mutex::new(zone));
+    let path = ::std::env::temp_dir().join(format!("reagent-secondary-{}", ::std::process::id()));
+    let config = SecondaryConfig{
+        origin: origin.clone(),
+        primaries: vec![primary(served.clone(), vec![], true)],
+        file: Some(path.clone()),
+        key: None,
+    };
+
+    let mut secondary = Secondary::new(0, config.clone());
+    assert!(secondary.load(0).is_none());
+    assert!(secondary.due(0));
+    assert!(!secondary.serving(0));
+
+    // a full transfer to start with
+    secondary.start();
+    assert!(!secondary.due(0));
+    let zone = match refresh(&config, None) {
+        Refresh::Updated(zone) => zone,
+        _ => panic!("expected a transfer"),
+    };
+    assert_eq!(zone.len(), 3);
+    let zone = secondary.finish(Refresh::Updated(zone), 100).unwrap();
+    assert_eq!(secondary.serial(), Some(1));
+    assert!(secondary.serving(100));
+    assert!(!secondary.due(3699));
+    assert!(secondary.due(3700));
+
+    match refresh(&config, Some(&zone)) {
+        Refresh::UpToDate => {}
+        _ => panic!("expected no change"),
+    }
+
+    // changes on the primary come incrementally
+    {
+        let mut served = served.lock().unwrap();
+        let mut added = served.soa().unwrap().clone();
+        added.rtype = RType::A;
+        added.name = "www.example.com".parse().unwrap();
+        added.data = RData::A(192, 0, 2, 80);
+        let diff = Diff{ from: soa_serial(&served, 1), to: soa_serial(&served, 2), deleted: vec![], added: vec![added] };
+        served.apply(diff).unwrap();
+    }
+    let zone = match refresh(&config, Some(&zone)) {
+        Refresh::Updated(zone) => zone,
+        _ => panic!("expected a transfer"),
+    };
+    assert_eq!(zone.serial(), 2);
+    assert_eq!(zone.changes_since(1).map(|c| c.len()), Some(1));
+    let zone = secondary.finish(Refresh::Updated(zone), 200).unwrap();
+
+    // the saved copy is served after a restart until checked, the
+    // incremental change replayed from the journal
+    let mut restarted = Secondary::new(0, config.clone());
+    let loaded = restarted.load(300).unwrap();
+    assert_eq!(loaded.len(), zone.len());
+    assert_eq!(loaded.serial(), 2);
+    assert_eq!(loaded.changes_since(1).map(|c| c.len()), Some(1));
+    assert!(restarted.serving(300));
+    assert!(restarted.due(300));
+    fs::remove_file(&path).unwrap();
+    fs::remove_file(path.with_extension("jnl")).unwrap();
+
+    // without a primary the zone is retried and eventually expires
+    let gone = UdpSocket::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+    let unreachable = SecondaryConfig{ primaries: vec![gone], file: None, ..config };
+    secondary.start();
+    match refresh(&unreachable, Some(&zone)) {
+        Refresh::Failed => {}
+        _ => panic!("expected a failure"),
+    }
+    assert!(secondary.finish(Refresh::Failed, 300).is_none());
+    assert!(!secondary.due(899));
+    assert!(secondary.due(900));
+    assert!(secondary.serving(86599));
+    assert!(!secondary.serving(86600));
+
+    // a NOTIFY moves the next check up, after the running one if need be
+    secondary.notify(400);
+    assert!(secondary.due(400));
+    secondary.start();
+    secondary.notify(401);
+    assert!(!secondary.due(401));
+    secondary.finish(Refresh::UpToDate, 402);
+    assert!(secondary.due(402));
+    assert!(secondary.is_primary(&gone.ip()));
+    assert!(!secondary.is_primary(&"192.0.2.1".parse().unwrap()));
+}
+
+#[test]
+fn refresh_signed() {
+    let origin: RName = "example.com".parse().unwrap();
+    let zone = Zone::parse(origin.clone(), "@ 60 IN SOA ns hm 1 3600 600 86400 300\n\
+                                            @ 60 IN NS ns\n\
+                                            ns 60 IN A 192.0.2.53").unwrap();
+    let served = Arc::new(Mutex::new(zone));
+    let key = Key::parse("xfr-key", "hmac-sha256", "c2VjcmV0LWtleS1mb3ItdHNpZy10ZXN0cw==").unwrap();
+    let config = SecondaryConfig{
+        origin: origin.clone(),
+        primaries: vec![primary(served.clone(), vec![key.clone()], true)],
+        file: None,
+        key: Some(key.clone()),
+    };
+    match refresh(&config, None) {
+        Refresh::Updated(zone) => assert_eq!(zone.len(), 3),
+        _ => panic!("expected a transfer"),
+    }
+
+    // answers not signed with the key are not taken
+    let mut other = key.clone();
+    other.secret[0] ^= 1;
+    match refresh(&SecondaryConfig{ key: Some(other), ..config.clone() }, None) {
+        Refresh::Failed => {}
+        _ => panic!("expected a failure"),
+    }
+    let unsigned = SecondaryConfig{ primaries: vec![primary(served.clone(), vec![], true)], ..config };
+    match refresh(&unsigned, None) {
+        Refresh::Failed => {}
+        _ => panic!("expected a failure"),
+    }
+}
+
+#[test]
+fn fall_back_to_axfr() {
+    let origin: RName = "example.com".parse().unwrap();
+    let zone = Zone::parse(origin.clone(), "@ 60 IN SOA ns hm 1 3600 600 86400 300\n\
+                                            @ 60 IN NS ns\n\
+                                            ns 60 IN A 192.0.2.53").unwrap();
+    let mut changed = zone.clone();
+    let diff = Diff{ from: soa_serial(&zone, 1), to: soa_serial(&zone, 2), deleted: vec![], added: vec![] };
+    changed.apply(diff).unwrap();
+    let config = SecondaryConfig{
+        origin: origin.clone(),
+        primaries: vec![primary(Arc::new(Mutex::new(changed)), vec![], false)],
+        file: None,
+        key: None,
+    };
+
+    // the whole zone comes instead of the changes
+    match refresh(&config, Some(&zone)) {
+        Refresh::Updated(zone) => {
+            assert_eq!(zone.serial(), 2);
+            assert_eq!(zone.len(), 3);
+            assert_eq!(zone.changes_since(1), None);
+        }
+        _ => panic!("expected a transfer"),
+    }
+}
@@ -1,37 +1,273 @@
+use std::io;
+use std::result;
+use std::thread;
 use std::net::{SocketAddr};
-use mio;
+use std::sync::mpsc::{channel, Sender, Receiver};
+use std::collections::HashMap;
+use std::time::Instant;
+use mio::{self, TryRead, TryWrite};
 use mio::udp::UdpSocket;
-use mio::buf::{SliceBuf, MutSliceBuf};
+use mio::tcp::{TcpListener, TcpStream};
+use mio::buf::{MutBuf, SliceBuf, MutSliceBuf};
+use mio::util::Slab;
 //use rustc_serialize::hex::ToHex;
 
-use {Result};
-use dns::{Message};
+use {Result, Error};
+use acl::{Access, AccessControl, Denied};
+use rrl::{self, RateLimiter, RrlConfig};
+use blocklist::{Blocklists, Policy};
+use view::{View, Views};
+use forward::{Client, Forwarder, Retry};
+use cache::{Cache, CacheConfig};
+use metrics::{self, Metrics, Transport, DropReason};
+use xfr;
+use zone::Zone;
+use secondary::{self, Secondary, Refresh};
+use notify::Notifier;
+use update;
+use journal::Journal;
+use signing::ZoneSigner;
+use tsig::{self, Key, Signer, Verified};
+use validator::{self, Validator, Security};
+use dns::{self, Message, OpCode, RCode, RType, RName, Class};
+use dns::message::{Question, Resource};
+use dns::view::MessageView;
 
 const SERVER_UDP: mio::Token = mio::Token(0);
-//const TCP_SERVER: mio::Token = mio::Token(1);
-//const CLIENT: mio::Token = mio::Token(1);
+const METRICS_HTTP: mio::Token = mio::Token(2);
+const TCP_SERVER: mio::Token = mio::Token(3);
 
+// Tokens from here on are the sockets queries are sent upstream from.
+const UPSTREAM_UDP: usize = 4;
+const UPSTREAM_SOCKETS: usize = 8;
 
-#[derive(Copy, Clone, Debug)]
-pub struct ServerConfig<'a> {
-    pub addr: &'a str,
+// Tokens from here on are metrics HTTP connections.
+const HTTP_CONN: usize = 16;
+const MAX_HTTP_CONNS: usize = 64;
+
+// Tokens from here on are DNS TCP connections.
+const TCP_CONN: usize = 1024;
+const MAX_TCP_CONNS: usize = 256;
+
+// Seconds a TCP connection may stay idle before it is closed (RFC 7766 §6.2.3).
+const TCP_IDLE: u64 = 10;
+
+const TICK: usize = 0;
+const TICK_MS: u64 = 1_000;
+
+// Largest UDP message read or sent.
+const MAX_UDP_LEN: usize = 4096;
+
+// Lookups one answer may need before it is given up on as bogus.
+const MAX_FETCHES: usize = 24;
+
+// Seconds an answer may be held back for validation.
+const VALIDATION_TIMEOUT: u64 = 15;
+
+
+#[derive(Clone, Default)]
+pub struct ServerConfig {
+    pub access: AccessControl,
+    pub rrl: Option<RrlConfig>,
+    pub blocklists: Blocklists,
+    /// Views evaluated in order for every query. Without any a single view
+    /// named "default" matching all clients is used.
+    pub views: Views,
+    /// Caches forwarded responses when set.
+    pub cache: Option<CacheConfig>,
+    /// Serves Prometheus metrics over HTTP at `/metrics` on this address.
+    pub metrics: Option<SocketAddr>,
+    /// TSIG keys that may send UPDATEs from any address, next to the
+    /// clients the `Update` ACL allows.
+    pub update_keys: Vec<RName>,
+    /// Keys requests can be signed with (RFC 8945). Responses to signed
+    /// requests are signed too, forwarded ones included.
+    pub tsig_keys: Vec<Key>,
+    /// DS or DNSKEY records of the zones to validate forwarded answers
+    /// from (RFC 4035 §5). Queries with CD set are relayed unchecked.
+    pub trust_anchors: Vec<Resource>,
+}
+
+/// What became of a request.
+enum Reply {
+    Answer(Message),
+    /// A cached response, ready to send.
+    Cached(Vec<u8>),
+    /// A zone transfer, streamed as several messages over TCP.
+    Transfer(Vec<Message>),
+    Forwarded,
+    Dropped(DropReason),
+    /// A request that could not be read past its question.
+    Malformed,
+}
+
+struct TcpConn {
+    stream: TcpStream,
+    peer: SocketAddr,
+    req: Vec<u8>,
+    resp: Vec<u8>,
+    written: usize,
+    active: u64,
+    // the client closed its side, or writing failed
+    eof: bool,
+    failed: bool,
+}
+
+/// A forwarded query whose answer is held back until it is validated.
+struct Validating {
+    client: Client,
+    src: SocketAddr,
+    view: usize,
+    req: Message,
+    resp: Option<Message>,
+    // the lookup it waits for, by lowercase name and type
+    waiting: Option<(RName, u16)>,
+    fetches: usize,
+    started: Instant,
+}
+
+/// A signed request forwarded upstream, with the signer for its answer.
+struct Signed {
+    client: Client,
+    signer: Signer,
+    // the payload size the request allows
+    max: usize,
+}
+
+struct HttpConn {
+    stream: TcpStream,
+    req: Vec<u8>,
+    resp: Vec<u8>,
+    written: usize,
 }
 
 pub struct Server {
     udp_socket: UdpSocket,
-    // tcp_listener: TcpListener,
+    // rotated one at a time so the ports queries leave from keep changing
+    upstream: Vec<UdpSocket>,
+    rotated: usize,
+    local: SocketAddr,
+    tcp_listener: TcpListener,
+    tcp: Slab<TcpConn>,
+    metrics_listener: Option<TcpListener>,
+    http: Slab<HttpConn>,
+    config: ServerConfig,
+    rrl: Option<RateLimiter>,
+    forwarder: Forwarder,
+    validator: Option<Validator>,
+    validating: HashMap<usize, Validating>,
+    signed: HashMap<usize, Signed>,
+    next_slot: usize,
+    cache: Option<Cache>,
+    metrics: Metrics,
+    secondaries: Vec<Secondary>,
+    // journals of the zones loaded from files, by view and apex
+    journals: Vec<(usize, RName, Journal)>,
+    // signers of the zones signed here, by view and apex
+    signers: Vec<(usize, RName, ZoneSigner)>,
+    notifier: Notifier,
+    // outcomes of refreshes running on their own threads
+    refreshed: (Sender<(usize, Refresh)>, Receiver<(usize, Refresh)>),
+    started: Instant,
 }
 
 impl Server {
 
     pub fn new(addr: &SocketAddr) -> Result<Server> {
+        Server::configured(addr, ServerConfig::default())
+    }
+
+    pub fn configured(addr: &SocketAddr, mut config: ServerConfig) -> Result<Server> {
+        if config.views.len() == 0 {
+            config.views.push(View::new("default"));
+        }
+        let udp_socket = try!(UdpSocket::bound(&addr));
+        let mut upstream = vec![];
+        for _ in 0..UPSTREAM_SOCKETS {
+            upstream.push(try!(UdpSocket::bound(&unbound(addr))));
+        }
+        let metrics_listener = match config.metrics {
+            Some(ref addr) => Some(try!(TcpListener::bind(addr))),
+            None => None,
+        };
+        let local = try!(udp_socket.local_addr());
+        if local.ip().is_unspecified() && config.views.match_destinations() {
+            return Err(Error::BadView)
+        }
+
+        let mut secondaries = vec![];
+        let mut journals = vec![];
+        let mut signers = vec![];
+        for i in 0..config.views.len() {
+            let view = config.views.get_mut(i).unwrap();
+            let mut loaded = vec![];
+            for zf in view.zone_files.iter() {
+                loaded.push(try!(Journal::load(zf.origin.clone(), &zf.file)));
+            }
+            // reverse zones are completed before they are signed
+            for (j, zf) in view.zone_files.iter().enumerate() {
+                for origin in zf.ptrs_from.iter() {
+                    let forward = match loaded.iter().find(|&&(ref zone, _)| zone.origin() == origin) {
+                        Some(&(ref zone, _)) => zone.clone(),
+                        None => return Err(Error::BadZone),
+                    };
+                    let added = loaded[j].0.synthesize_ptrs(&forward);
+                    println!("zone {} synthesized {} PTR records from {}", zf.origin, added, origin);
+                }
+            }
+            for (zf, (mut zone, mut journal)) in view.zone_files.iter().zip(loaded) {
+                if let Some(ref signing) = zf.signing {
+                    let mut signer = try!(ZoneSigner::open(&zf.origin, signing.clone()));
+                    try!(resign(&mut zone, &mut signer, &mut journal));
+                    signers.push((i, zf.origin.clone(), signer));
+                }
+                view.zones.insert(zone);
+                journals.push((i, zf.origin.clone(), journal));
+            }
+            for sc in view.secondaries.iter() {
+                let mut secondary = Secondary::new(i, sc.clone());
+                if let Some(zone) = secondary.load(0) {
+                    view.zones.insert(zone);
+                }
+                secondaries.push(secondary);
+            }
+        }
+
         Ok(Server{
-            udp_socket: try!(UdpSocket::bound(&addr)),
+            local: local,
+            udp_socket: udp_socket,
+            tcp_listener: try!(TcpListener::bind(&local)),
+            tcp: Slab::new_starting_at(mio::Token(TCP_CONN), MAX_TCP_CONNS),
+            upstream: upstream,
+            rotated: 0,
+            metrics_listener: metrics_listener,
+            http: Slab::new_starting_at(mio::Token(HTTP_CONN), MAX_HTTP_CONNS),
+            rrl: match config.rrl {
+                Some(rrl) => Some(try!(RateLimiter::new(rrl))),
+                None => None,
+            },
+            cache: config.cache.map(Cache::new),
+            validator: match config.trust_anchors.len() {
+                0 => None,
+                _ => Some(Validator::new(config.trust_anchors.clone())),
+            },
+            config: config,
+            forwarder: try!(Forwarder::new(UPSTREAM_SOCKETS)),
+            validating: HashMap::new(),
+            signed: HashMap::new(),
+            next_slot: 0,
+            metrics: Metrics::new(),
+            secondaries: secondaries,
+            journals: journals,
+            signers: signers,
+            notifier: try!(Notifier::new()),
+            refreshed: channel(),
+            started: Instant::now(),
         })
     }
 
     pub fn run(&mut self) -> Result<()> {
-        println!("Listening on {}", self.udp_socket.local_addr().unwrap());
+        println!("Listening on {}", self.local);
 
         let mut config = mio::EventLoopConfig::default();
         config.io_poll_timeout_ms = 10_000;
@@ -40,55 +276,1223 @@ impl Server {
         let mut evloop = try!(mio::EventLoop::<Server>::configured(config));
         try!(evloop.register_opt(&self.udp_socket,
                                  SERVER_UDP,
-                                 mio::EventSet::all(),
+                                 mio::EventSet::readable(),
+                                 mio::PollOpt::edge()));
+        for (i, socket) in self.upstream.iter().enumerate() {
+            try!(evloop.register_opt(socket,
+                                     mio::Token(UPSTREAM_UDP + i),
+                                     mio::EventSet::readable(),
+                                     mio::PollOpt::edge()));
+        }
+        try!(evloop.register_opt(&self.tcp_listener,
+                                 TCP_SERVER,
+                                 mio::EventSet::readable(),
                                  mio::PollOpt::edge()));
+        if let Some(ref listener) = self.metrics_listener {
+            println!("Serving metrics on {}", try!(listener.local_addr()));
+            try!(evloop.register_opt(listener,
+                                     METRICS_HTTP,
+                                     mio::EventSet::readable(),
+                                     mio::PollOpt::edge()));
+        }
+        evloop.timeout_ms(TICK, TICK_MS).unwrap();
         try!(evloop.run(self));
 
         Ok(())
     }
-}
 
+    /// The address DNS queries are served on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local
+    }
 
-impl mio::Handler for Server {
-    type Timeout = usize;
-    type Message = ();
+    /// The address metrics are served on, if enabled.
+    pub fn metrics_addr(&self) -> Option<SocketAddr> {
+        self.metrics_listener.as_ref().and_then(|l| l.local_addr().ok())
+    }
 
-    fn ready(&mut self, _event_loop: &mut mio::EventLoop<Server>, token: mio::Token, _events: mio::EventSet) {
-        match token {
-            SERVER_UDP => {
-                let mut buf = [0; 512];
-                match self.udp_socket.recv_from(&mut MutSliceBuf::wrap(&mut buf)) {
-                    Ok(Some(ref src)) => {
-                        match Message::unpack(&buf, 0) {
-                            Ok(msg) => if !msg.qr {
-                                println!("{}", msg);
+    /// Decides what to do with a request from `src`. `raw` is the request
+    /// without its signature, `signer` the one it was verified with; it is
+    /// taken along with a forwarded request to sign the answer.
+    fn handle(&mut self, req: &MessageView, raw: &[u8], src: &SocketAddr, client: Client,
+              signer: &mut Option<Signer>) -> Reply {
+        let key = signer.as_ref().and_then(|s| s.key()).cloned();
+        let key = key.as_ref();
+        let access = access(req);
+        // secondaries also take NOTIFY from their primaries
+        let keyed = access == Access::Update && key.map_or(false, |key| {
+            self.config.update_keys.iter().any(|k| k == key)
+        });
+        let allowed = access == Access::Notify || keyed || self.config.access.allows(access, &src.ip());
+        let view = self.config.views.select(&src.ip(), &self.local.ip(), key);
 
-                                let msg = Message::new_reply(&msg);
+        // most queries are answered from the cache, before the rest of them is read
+        if let (true, Some(view)) = (allowed, view) {
+            if access == Access::Recursion || access == Access::Cache {
+                if let Some(reply) = self.lookup(req, src, view) {
+                    return reply
+                }
+            }
+        }
+        let req = &match req.to_message() {
+            Ok(req) => req,
+            Err(_) => return Reply::Malformed,
+        };
 
-                                // todo move response
+        if !allowed {
+            return match self.config.access.denied {
+                Denied::Refuse => Reply::Answer(Message::new_error(req, RCode::REFUSED)),
+                Denied::Drop => Reply::Dropped(DropReason::Acl),
+            }
+        }
 
-                                let len = msg.pack(&mut buf, 0).unwrap();
+        let view = match view {
+            Some(i) => i,
+            None => {
+                log_query(src, "-", req, "REFUSED");
+                return Reply::Answer(Message::new_error(req, RCode::REFUSED))
+            }
+        };
 
-                                match self.udp_socket.send_to(&mut SliceBuf::wrap(&mut buf[..len]), src) {
-                                    Err(e) => {
-                                        println!("failed to write response {}", e);
-                                    }
-                                    _ => {}
-                                };
+        if access == Access::Notify {
+            return Reply::Answer(self.notified(req, src, view))
+        }
+        if access == Access::Update {
+            return Reply::Answer(self.updated(req, src, view))
+        }
+
+        let now = self.started.elapsed().as_secs();
+        let index = view;
+        let view = &self.config.views.views()[view];
+        let ra = view.recursion && !view.forwarders.is_empty();
+
+        let expired = req.questions.first().map_or(false, |q| self.secondaries.iter().any(|s| {
+            s.view == index && !s.serving(now) && q.name.is_subdomain_of(&s.config.origin)
+        }));
+
+        let mut resp = match access {
+            _ if expired => Message::new_error(req, RCode::SERVFAIL),
+            Access::Recursion | Access::Cache => {
+                match req.questions.first().and_then(|q| view.zones.find(&q.name)) {
+                    Some(zone) => zone.answer(req),
+                    None => {
+                        if access == Access::Recursion && ra {
+                            // answers to signed requests come back through a slot
+                            // holding the signer
+                            let sign_slot = self.next_slot;
+                            let relayed = match *signer {
+                                Some(_) => {
+                                    self.next_slot = self.next_slot.wrapping_add(1);
+                                    Client::Signed(sign_slot)
+                                }
+                                None => client,
+                            };
+                            let forwarded = if self.validator.is_some() && !req.cd && !req.questions.is_empty() {
+                                // answers are checked here, not upstream
+                                let slot = self.next_slot;
+                                self.next_slot = self.next_slot.wrapping_add(1);
+                                let query = packed(&validator::query(req.id, &req.questions[0]));
+                                let forwarded = self.forwarder.query(&query, Client::Validating(slot), index, &view.forwarders, now);
+                                if forwarded.is_some() {
+                                    self.validating.insert(slot, Validating{
+                                        client: relayed,
+                                        src: *src,
+                                        view: index,
+                                        req: req.clone(),
+                                        resp: None,
+                                        waiting: None,
+                                        fetches: 0,
+                                        started: Instant::now(),
+                                    });
+                                }
+                                forwarded
                             } else {
-                                // TODO return error
-                                println!("Error got response {}", msg.qr);
-                            },
+                                self.forwarder.query(raw, relayed, index, &view.forwarders, now)
+                            };
+                            match forwarded {
+                                Some((query, upstream, socket)) => {
+                                    if let Some(signer) = signer.take() {
+                                        self.signed.insert(sign_slot, Signed{
+                                            client: client,
+                                            signer: signer,
+                                            max: req.max_payload(),
+                                        });
+                                    }
+                                    self.metrics.upstream_request(&upstream);
+                                    if let Err(e) = self.upstream[socket].send_to(&mut SliceBuf::wrap(&query[..]), &upstream) {
+                                        println!("failed to forward query to {}: {}", upstream, e);
+                                    }
+                                    log_query(src, &view.name, req, "forwarded");
+                                    return Reply::Forwarded
+                                }
+                                None => {
+                                    self.metrics.dropped(DropReason::Overload);
+                                    Message::new_error(req, RCode::SERVFAIL)
+                                }
+                            }
+                        } else {
+                            Message::new_error(req, RCode::REFUSED)
+                        }
+                    }
+                }
+            }
+            Access::Transfer => {
+                let qtype = req.questions.first().map(|q| q.rtype);
+                match (req.questions.first().and_then(|q| view.zones.get(&q.name)), client) {
+                    (None, _) => Message::new_error(req, RCode::NOTAUTH),
+                    (Some(zone), Client::Tcp(_)) => {
+                        let mut msgs = xfr::transfer(zone, req);
+                        for msg in msgs.iter_mut() {
+                            msg.ra = ra;
+                        }
+                        log_query(src, &view.name, req, &format!("{:?} in {} messages", msgs[0].rcode, msgs.len()));
+                        return Reply::Transfer(msgs)
+                    }
+                    // only IXFR has a meaning over UDP (RFC 5936 §4.2)
+                    (Some(zone), Client::Udp(_)) if qtype == Some(RType::IXFR) => xfr::udp(zone, req),
+                    (Some(_), _) => Message::new_error(req, RCode::NOTIMPL),
+                }
+            }
+            Access::Update | Access::Notify => unreachable!(),
+        };
+        resp.ra = ra;
+
+        let mut result = format!("{:?}", resp.rcode);
+        for rr in resp.answers.iter().filter(|rr| rr.rtype != RType::RRSIG) {
+            result.push_str(&format!(" {}", rr.data));
+        }
+        log_query(src, &view.name, req, &result);
+        Reply::Answer(resp)
+    }
+
+    /// Acknowledges a NOTIFY for a secondary zone in `view` from one of its
+    /// primaries, or a client allowed to, and checks the zone right away.
+    fn notified(&mut self, req: &Message, src: &SocketAddr, view: usize) -> Message {
+        let now = self.started.elapsed().as_secs();
+        let view_name = &self.config.views.views()[view].name;
+        let origin = match req.questions.first() {
+            Some(q) if q.rtype == RType::SOA => q.name.clone(),
+            _ => return Message::new_error(req, RCode::FORMERR),
+        };
+        let secondary = self.secondaries.iter_mut()
+            .find(|s| s.view == view && s.config.origin == origin);
+        let mut resp = match secondary {
+            None => Message::new_error(req, RCode::NOTAUTH),
+            Some(ref s) if !s.is_primary(&src.ip()) && !self.config.access.allows(Access::Notify, &src.ip()) => {
+                Message::new_error(req, RCode::REFUSED)
+            }
+            Some(s) => {
+                s.notify(now);
+                Message::new_error(req, RCode::NOERROR)
+            }
+        };
+        resp.aa = resp.rcode == RCode::NOERROR;
+        log_query(src, view_name, req, &format!("{:?}", resp.rcode));
+        resp
+    }
+
+    /// Applies an UPDATE to a zone served in `view`. Secondary zones only
+    /// change through transfers from their primaries.
+    fn updated(&mut self, req: &Message, src: &SocketAddr, view: usize) -> Message {
+        let origin = match req.questions.first() {
+            Some(q) if req.questions.len() == 1 && q.rtype == RType::SOA => q.name.clone(),
+            _ => return Message::new_error(req, RCode::FORMERR),
+        };
+        let secondary = self.secondaries.iter().any(|s| {
+            s.view == view && s.config.origin == origin
+        });
+        let index = view;
+        let view = self.config.views.get_mut(view).unwrap();
+        let journal = self.journals.iter_mut()
+            .find(|&&mut (v, ref o, _)| v == index && *o == origin)
+            .map(|&mut (_, _, ref mut journal)| journal);
+        let signer = self.signers.iter_mut()
+            .find(|&&mut (v, ref o, _)| v == index && *o == origin)
+            .map(|&mut (_, _, ref mut signer)| signer);
+        let rcode = match view.zones.get_mut(&origin) {
+            None => RCode::NOTAUTH,
+            Some(_) if secondary => RCode::REFUSED,
+            Some(zone) => match update::update(zone, req) {
+                Ok(Some(diff)) => {
+                    // signed zones stay signed
+                    let diff = match signer {
+                        Some(signer) => signer.sign(zone, diff, tsig::now() as u32),
+                        None => Ok(diff),
+                    };
+                    match diff.and_then(|diff| zone.apply(diff)) {
+                        // answered once the change is on disk
+                        Ok(()) => match journal.map_or(Ok(()), |j| j.sync(zone)) {
+                            Ok(()) => RCode::NOERROR,
                             Err(e) => {
-                                println!("failed to parse {:?}", e);
+                                println!("zone {} failed to journal update: {:?}", origin, e);
+                                RCode::SERVFAIL
                             }
+                        },
+                        Err(e) => {
+                            println!("zone {} update failed: {:?}", origin, e);
+                            RCode::SERVFAIL
                         }
                     }
-                    Ok(None) => {}
-                    Err(e) => {
-                        println!("listener.accept() failed: {}", e);
+                }
+                Ok(None) => RCode::NOERROR,
+                Err(rcode) => rcode,
+            },
+        };
+        let mut resp = Message::new_error(req, rcode);
+        resp.aa = rcode == RCode::NOERROR;
+        log_query(src, &view.name, req, &format!("{:?}", rcode));
+        resp
+    }
+
+    /// Looks a query for recursion or the cache up in the blocklists and
+    /// then in the cache of `view`, reading no more of it than its question.
+    /// One for a name served here, or missing from the cache, is left to
+    /// `handle`.
+    fn lookup(&mut self, req: &MessageView, src: &SocketAddr, view: usize) -> Option<Reply> {
+        let q = match req.questions().next().map(|q| q.to_question()) {
+            Some(Ok(q)) => q,
+            Some(Err(_)) => return Some(Reply::Malformed),
+            None => return None,
+        };
+        let name = &self.config.views.views()[view].name;
+        match self.config.blocklists.check(&q.name) {
+            None | Some(Policy::Pass) => {}
+            Some(Policy::Drop) => {
+                log_question(src, name, &q, "blocked");
+                return Some(Reply::Dropped(DropReason::Policy))
+            }
+            Some(policy) => {
+                log_question(src, name, &q, "blocked");
+                return Some(match req.to_message() {
+                    Ok(req) => Reply::Answer(policy.respond(&req)),
+                    Err(_) => Reply::Malformed,
+                })
+            }
+        }
+
+        let now = self.started.elapsed().as_secs();
+        let expired = self.secondaries.iter().any(|s| {
+            s.view == view && !s.serving(now) && q.name.is_subdomain_of(&s.config.origin)
+        });
+        if expired || self.config.views.views()[view].zones.find(&q.name).is_some() {
+            return None
+        }
+        self.cache.as_mut().and_then(|c| c.get(view, req, now)).map(|msg| {
+            log_question(src, name, &q, "cached");
+            Reply::Cached(msg)
+        })
+    }
+
+    /// Checks the TSIG of a request, giving the signer for the responses
+    /// and the request without its signature if it has one. A request that
+    /// fails gets the response to send instead (RFC 8945 §5.2).
+    fn authenticate(&self, req: &MessageView, src: &SocketAddr)
+                    -> result::Result<Option<(Signer, Vec<u8>)>, (Reply, Option<Signer>)> {
+        if !req.additionals().any(|r| r.rtype == RType::TSIG as u16) {
+            return Ok(None)
+        }
+        let (rcode, result, signer) = match tsig::verify(&self.config.tsig_keys, req.as_bytes(), tsig::now()) {
+            Verified::Unsigned => return Ok(None),
+            Verified::Valid(signer, unsigned) => return Ok(Some((signer, unsigned))),
+            Verified::Invalid(signer) => {
+                let result = format!("TSIG {:?}", signer.error().unwrap_or(RCode::BADSIG));
+                (RCode::NOTAUTH, result, Some(signer))
+            }
+            Verified::Malformed => (RCode::FORMERR, "TSIG malformed".to_string(), None),
+        };
+        match req.to_message() {
+            Ok(req) => {
+                log_query(src, "-", &req, &result);
+                Err((Reply::Answer(Message::new_error(&req, rcode)), signer))
+            }
+            Err(_) => Err((Reply::Malformed, signer)),
+        }
+    }
+
+    /// Applies response rate limiting to a UDP response.
+    fn limit(&mut self, resp: Message, src: &SocketAddr) -> Option<Message> {
+        match self.rate(&resp, src) {
+            rrl::Action::Send => Some(resp),
+            rrl::Action::Slip => Some(slipped(&resp)),
+            rrl::Action::Drop => None,
+        }
+    }
+
+    /// Applies response rate limiting to a UDP response packed elsewhere,
+    /// as cached or relayed from upstream. One that can not be read can
+    /// not be accounted and is dropped.
+    fn limit_packed(&mut self, resp: Vec<u8>, src: &SocketAddr) -> Option<Vec<u8>> {
+        if self.rrl.is_none() {
+            return Some(resp)
+        }
+        let msg = match Message::unpack(&resp, 0) {
+            Ok(msg) => msg,
+            Err(_) => return None,
+        };
+        match self.rate(&msg, src) {
+            rrl::Action::Send => Some(resp),
+            rrl::Action::Slip => Some(packed(&slipped(&msg))),
+            rrl::Action::Drop => None,
+        }
+    }
+
+    fn rate(&mut self, resp: &Message, src: &SocketAddr) -> rrl::Action {
+        let now = self.started.elapsed().as_secs();
+        match self.rrl {
+            Some(ref mut rrl) => rrl.check(&src.ip(), resp, now),
+            None => rrl::Action::Send,
+        }
+    }
+
+    /// Sends a response over UDP, truncating it if it does not fit into
+    /// `max` bytes with its signature.
+    fn send(&self, msg: &Message, max: usize, dst: &SocketAddr, signer: Option<&mut Signer>) {
+        let mut buf = [0; MAX_UDP_LEN];
+        let max = if max < buf.len() { max } else { buf.len() };
+        let max = max - signer.as_ref().map_or(0, |s| s.len());
+        let len = match msg.pack(&mut buf[..max], 0) {
+            Ok(len) => len,
+            Err(dns::Error::SmallBuf) => {
+                let mut tc = Message::new_error(msg, msg.rcode);
+                tc.aa = msg.aa;
+                tc.ra = msg.ra;
+                tc.tc = true;
+                match tc.pack(&mut buf[..max], 0) {
+                    Ok(len) => len,
+                    Err(e) => return println!("failed to pack response {:?}", e),
+                }
+            }
+            Err(e) => return println!("failed to pack response {:?}", e),
+        };
+        match signer {
+            Some(signer) => {
+                let mut msg = buf[..len].to_vec();
+                match signer.sign(&mut msg, tsig::now()) {
+                    Ok(()) => self.send_raw(&msg, dst),
+                    Err(e) => println!("failed to sign response {:?}", e),
+                }
+            }
+            None => self.send_raw(&buf[..len], dst),
+        }
+    }
+
+    fn send_raw(&self, msg: &[u8], dst: &SocketAddr) {
+        if let Err(e) = self.udp_socket.send_to(&mut SliceBuf::wrap(msg), dst) {
+            println!("failed to write response {}", e);
+        }
+    }
+
+    fn ready_server(&mut self) {
+        let mut buf = [0; MAX_UDP_LEN];
+        loop {
+            let (len, src) = match recv(&self.udp_socket, &mut buf) {
+                Ok(Some(r)) => r,
+                Ok(None) => return,
+                Err(e) => return println!("failed to read request {}", e),
+            };
+            let received = Instant::now();
+            let req = match MessageView::new(&buf[..len]) {
+                Ok(ref msg) if msg.qr() => {
+                    self.metrics.dropped(DropReason::Unexpected);
+                    continue
+                }
+                Ok(msg) => msg,
+                Err(e) => {
+                    println!("failed to parse {:?}", e);
+                    self.metrics.malformed();
+                    continue
+                }
+            };
+            let qtype = qtype(&req);
+            let (mut signer, unsigned) = match self.authenticate(&req, &src) {
+                Ok(Some((signer, unsigned))) => (Some(signer), Some(unsigned)),
+                Ok(None) => (None, None),
+                Err((Reply::Answer(resp), mut signer)) => {
+                    if let Some(resp) = self.limit(resp, &src) {
+                        self.send(&resp, req.max_payload(), &src, signer.as_mut());
+                        self.metrics.query(Transport::Udp, qtype, resp.rcode, received.elapsed());
+                    }
+                    continue
+                }
+                Err(_) => {
+                    self.metrics.malformed();
+                    continue
+                }
+            };
+            let raw = unsigned.as_ref().map_or(&buf[..len], |u| &u[..]);
+            match self.handle(&req, raw, &src, Client::Udp(src), &mut signer) {
+                Reply::Answer(resp) => {
+                    if let Some(resp) = self.limit(resp, &src) {
+                        self.send(&resp, req.max_payload(), &src, signer.as_mut());
+                        self.metrics.query(Transport::Udp, qtype, resp.rcode, received.elapsed());
+                    }
+                }
+                Reply::Cached(msg) => {
+                    let mut msg = match self.limit_packed(msg, &src) {
+                        Some(msg) => msg,
+                        None => continue,
+                    };
+                    let reserved = signer.as_ref().map_or(0, |s| s.len());
+                    if msg.len() + reserved > req.max_payload() {
+                        if let Ok(req) = req.to_message() {
+                            let mut tc = Message::new_error(&req, RCode::NOERROR);
+                            tc.tc = true;
+                            self.send(&tc, req.max_payload(), &src, signer.as_mut());
+                        }
+                    } else if let Some(ref mut signer) = signer {
+                        match signer.sign(&mut msg, tsig::now()) {
+                            Ok(()) => self.send_raw(&msg, &src),
+                            Err(e) => println!("failed to sign response {:?}", e),
+                        }
+                    } else {
+                        self.send_raw(&msg, &src);
+                    }
+                    let rcode = RCode::unpack(msg[3] & 0x0f).unwrap_or(RCode::NOERROR);
+                    self.metrics.query(Transport::Udp, qtype, rcode, received.elapsed());
+                }
+                Reply::Transfer(_) => unreachable!(),
+                Reply::Forwarded => {}
+                Reply::Dropped(reason) => self.metrics.dropped(reason),
+                Reply::Malformed => self.metrics.malformed(),
+            }
+        }
+    }
+
+    /// Sends a query upstream from one of the upstream sockets.
+    fn send_upstream(&mut self, query: &[u8], upstream: &SocketAddr, socket: usize) {
+        self.metrics.upstream_request(upstream);
+        if let Err(e) = self.upstream[socket].send_to(&mut SliceBuf::wrap(query), upstream) {
+            println!("failed to forward query to {}: {}", upstream, e);
+        }
+    }
+
+    fn ready_upstream(&mut self, socket: usize) {
+        let mut buf = [0; MAX_UDP_LEN];
+        loop {
+            let (len, from) = match recv(&self.upstream[socket], &mut buf) {
+                Ok(Some(r)) => r,
+                Ok(None) => return,
+                Err(e) => return println!("failed to read upstream response {}", e),
+            };
+            // responses are relayed as received, only the id is restored
+            let answered = match self.forwarder.response(&mut buf[..len], &from, socket) {
+                Some(answered) => answered,
+                None => {
+                    let acked = Message::unpack(&buf[..len], 0).ok()
+                        .map_or(false, |resp| self.notifier.response(&resp, &from));
+                    if !acked {
+                        self.metrics.dropped(DropReason::Unexpected);
+                    }
+                    continue
+                }
+            };
+            self.metrics.upstream_response(&answered.upstream, answered.rtt);
+            let transport = match answered.client {
+                Client::Udp(ref addr) => {
+                    if let Some(resp) = self.limit_packed(buf[..len].to_vec(), addr) {
+                        self.send_raw(&resp, addr);
+                    }
+                    Transport::Udp
+                }
+                Client::Tcp(token) => { self.send_tcp(token, &buf[..len]); Transport::Tcp }
+                // signed answers are repacked with the signature
+                Client::Signed(slot) => match Message::unpack(&buf[..len], 0) {
+                    Ok(resp) => match self.answer_signed(slot, resp) {
+                        Some(transport) => transport,
+                        None => continue,
+                    },
+                    Err(_) => {
+                        self.signed.remove(&slot);
+                        self.metrics.malformed();
+                        continue
                     }
+                },
+                Client::Validating(slot) => {
+                    match Message::unpack(&buf[..len], 0) {
+                        Ok(resp) => {
+                            if let Some(v) = self.validating.get_mut(&slot) {
+                                v.resp = Some(resp);
+                            }
+                            self.advance(slot);
+                        }
+                        Err(_) => {
+                            self.metrics.malformed();
+                            self.finish(slot, Security::Bogus("malformed response"));
+                        }
+                    }
+                    continue
                 }
+                Client::Fetch => {
+                    match Message::unpack(&buf[..len], 0) {
+                        Ok(resp) => self.fetched(&resp),
+                        Err(_) => self.metrics.malformed(),
+                    }
+                    continue
+                }
+            };
+
+            match MessageView::new(&buf[..len]).and_then(|resp| resp.rcode().map(|rcode| (resp, rcode))) {
+                Ok((resp, rcode)) => {
+                    let qtype = resp.questions().next().and_then(|q| RType::unpack(q.rtype).ok());
+                    self.metrics.query(transport, qtype.unwrap_or(RType::ZERO), rcode, answered.elapsed);
+                }
+                Err(_) => self.metrics.malformed(),
+            }
+            // when validating only checked answers are cached, and these
+            // answered queries with CD set
+            match self.cache {
+                Some(ref mut cache) if self.validator.is_none() => {
+                    cache.insert(answered.view, &buf[..len], self.started.elapsed().as_secs());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Validates a held back answer as far as the lookups made so far
+    /// allow, and sends the next lookup it needs.
+    fn advance(&mut self, slot: usize) {
+        let result = match (self.validator.as_mut(), self.validating.get(&slot)) {
+            (Some(validator), Some(&Validating{ resp: Some(ref resp), .. })) => validator.validate(resp, tsig::now()),
+            _ => return,
+        };
+        let (name, rtype) = match result {
+            Ok(security) => return self.finish(slot, security),
+            Err(fetch) => fetch,
+        };
+        let key = (name.clone(), rtype as u16);
+        let in_flight = self.validating.iter().any(|(&s, v)| s != slot && v.waiting.as_ref() == Some(&key));
+        let (view, fetches) = match self.validating.get_mut(&slot) {
+            Some(v) => {
+                v.fetches += 1;
+                v.waiting = Some(key);
+                (v.view, v.fetches)
+            }
+            None => return,
+        };
+        if fetches > MAX_FETCHES {
+            return self.finish(slot, Security::Bogus("too many lookups"))
+        }
+        if in_flight {
+            return
+        }
+        let query = packed(&validator::query(0, &Question{ name: name, rtype: rtype, class: Class::IN }));
+        let now = self.started.elapsed().as_secs();
+        match self.forwarder.query(&query, Client::Fetch, view, &self.config.views.views()[view].forwarders, now) {
+            Some((query, upstream, socket)) => self.send_upstream(&query, &upstream, socket),
+            None => {
+                self.metrics.dropped(DropReason::Overload);
+                self.finish(slot, Security::Bogus("lookup failed"));
+            }
+        }
+    }
+
+    /// Passes the answer to a lookup to the validator and resumes the
+    /// validations waiting for it.
+    fn fetched(&mut self, resp: &Message) {
+        let key = match resp.questions.first() {
+            Some(q) => (q.name.clone(), q.rtype as u16),
+            None => return,
+        };
+        if let Some(ref mut validator) = self.validator {
+            validator.learn(resp, tsig::now());
+        }
+        let waiting: Vec<usize> = self.validating.iter_mut()
+            .filter(|&(_, ref v)| v.waiting.as_ref() == Some(&key))
+            .map(|(&slot, v)| { v.waiting = None; slot })
+            .collect();
+        for slot in waiting {
+            self.advance(slot);
+        }
+    }
+
+    /// Answers the client of a held back query, with SERVFAIL if the
+    /// answer is bogus or never came.
+    fn finish(&mut self, slot: usize, security: Security) {
+        let v = match self.validating.remove(&slot) {
+            Some(v) => v,
+            None => return,
+        };
+        let resp = match v.resp {
+            Some(ref resp) => validator::answer(&v.req, resp, &security),
+            None => Message::new_error(&v.req, RCode::SERVFAIL),
+        };
+        if let Security::Bogus(reason) = security {
+            log_query(&v.src, &self.config.views.views()[v.view].name, &v.req, &format!("SERVFAIL, {}", reason));
+        }
+        let transport = match v.client {
+            Client::Udp(ref addr) => {
+                if let Some(resp) = self.limit(resp.clone(), addr) {
+                    self.send(&resp, v.req.max_payload(), addr, None);
+                }
+                Transport::Udp
+            }
+            Client::Tcp(token) => { self.send_tcp_msg(token, &resp, None); Transport::Tcp }
+            Client::Signed(slot) => match self.answer_signed(slot, resp.clone()) {
+                Some(transport) => transport,
+                None => return,
+            },
+            Client::Validating(_) | Client::Fetch => return,
+        };
+        let qtype = resp.questions.first().map_or(RType::ZERO, |q| q.rtype);
+        self.metrics.query(transport, qtype, resp.rcode, v.started.elapsed());
+        if let Some(ref mut cache) = self.cache {
+            cache.insert(v.view, &packed(&resp), self.started.elapsed().as_secs());
+        }
+    }
+
+    /// Answers a signed request that was forwarded, signing the response
+    /// with the signer the request came with. `None` if the client is gone.
+    fn answer_signed(&mut self, slot: usize, resp: Message) -> Option<Transport> {
+        let mut signed = match self.signed.remove(&slot) {
+            Some(signed) => signed,
+            None => return None,
+        };
+        match signed.client {
+            Client::Udp(ref addr) => {
+                if let Some(resp) = self.limit(resp, addr) {
+                    self.send(&resp, signed.max, addr, Some(&mut signed.signer));
+                }
+                Some(Transport::Udp)
+            }
+            Client::Tcp(token) => {
+                self.send_tcp_msg(token, &resp, Some(&mut signed.signer));
+                Some(Transport::Tcp)
+            }
+            _ => None,
+        }
+    }
+
+    fn accept_tcp(&mut self, event_loop: &mut mio::EventLoop<Server>) {
+        let now = self.started.elapsed().as_secs();
+        loop {
+            let stream = match self.tcp_listener.accept() {
+                Ok(Some(stream)) => stream,
+                Ok(None) => return,
+                Err(e) => return println!("failed to accept connection {}", e),
+            };
+            let peer = match stream.peer_addr() {
+                Ok(peer) => peer,
+                Err(_) => continue,
+            };
+            let conn = TcpConn{
+                stream: stream,
+                peer: peer,
+                req: vec![],
+                resp: vec![],
+                written: 0,
+                active: now,
+                eof: false,
+                failed: false,
+            };
+            let token = match self.tcp.insert(conn) {
+                Ok(token) => token,
+                Err(_) => {
+                    self.metrics.dropped(DropReason::Overload);
+                    continue
+                }
+            };
+            let registered = event_loop.register_opt(&self.tcp[token].stream,
+                                                     token,
+                                                     mio::EventSet::readable() | mio::EventSet::writable(),
+                                                     mio::PollOpt::edge());
+            if registered.is_err() {
+                self.tcp.remove(token);
+            }
+        }
+    }
+
+    fn ready_tcp(&mut self, event_loop: &mut mio::EventLoop<Server>, token: mio::Token, events: mio::EventSet) {
+        let now = self.started.elapsed().as_secs();
+        let mut reqs = vec![];
+        let peer = match self.tcp.get_mut(token) {
+            Some(conn) => {
+                conn.eof |= events.is_hup();
+                conn.failed |= events.is_error();
+                let mut chunk = [0; 4096];
+                while !conn.eof && !conn.failed {
+                    match conn.stream.try_read(&mut chunk) {
+                        Ok(Some(0)) => conn.eof = true,
+                        Ok(Some(n)) => conn.req.extend(&chunk[..n]),
+                        Ok(None) => break,
+                        Err(_) => conn.failed = true,
+                    }
+                }
+                // messages are prefixed with their length (RFC 1035 §4.2.2)
+                let mut off = 0;
+                while conn.req.len() >= off + 2 {
+                    let len = (conn.req[off] as usize) << 8 | conn.req[off + 1] as usize;
+                    if conn.req.len() < off + 2 + len {
+                        break
+                    }
+                    reqs.push(conn.req[off + 2..off + 2 + len].to_vec());
+                    off += 2 + len;
+                }
+                conn.req.drain(..off);
+                if !reqs.is_empty() {
+                    conn.active = now;
+                }
+                flush(conn);
+                conn.peer
+            }
+            None => return,
+        };
+
+        for raw in reqs {
+            self.query_tcp(token.0, &peer, &raw);
+        }
+        self.close_tcp(event_loop, token, now);
+    }
+
+    fn query_tcp(&mut self, token: usize, peer: &SocketAddr, raw: &[u8]) {
+        let received = Instant::now();
+        let req = match MessageView::new(raw) {
+            Ok(ref msg) if msg.qr() => return self.metrics.dropped(DropReason::Unexpected),
+            Ok(msg) => msg,
+            Err(e) => {
+                println!("failed to parse {:?}", e);
+                return self.metrics.malformed()
+            }
+        };
+        let qtype = qtype(&req);
+        let (mut signer, unsigned) = match self.authenticate(&req, peer) {
+            Ok(Some((signer, unsigned))) => (Some(signer), Some(unsigned)),
+            Ok(None) => (None, None),
+            Err((Reply::Answer(resp), mut signer)) => {
+                self.send_tcp_msg(token, &resp, signer.as_mut());
+                return self.metrics.query(Transport::Tcp, qtype, resp.rcode, received.elapsed())
+            }
+            Err(_) => return self.metrics.malformed(),
+        };
+        let raw = unsigned.as_ref().map_or(raw, |u| &u[..]);
+        match self.handle(&req, raw, peer, Client::Tcp(token), &mut signer) {
+            Reply::Answer(resp) => {
+                self.send_tcp_msg(token, &resp, signer.as_mut());
+                self.metrics.query(Transport::Tcp, qtype, resp.rcode, received.elapsed());
+            }
+            Reply::Transfer(msgs) => {
+                // every message of the stream is signed, chained to the last
+                for msg in msgs.iter() {
+                    self.send_tcp_msg(token, msg, signer.as_mut());
+                }
+                self.metrics.query(Transport::Tcp, qtype, msgs[0].rcode, received.elapsed());
+            }
+            Reply::Cached(mut msg) => {
+                if let Some(ref mut signer) = signer {
+                    if let Err(e) = signer.sign(&mut msg, tsig::now()) {
+                        return println!("failed to sign response {:?}", e)
+                    }
+                }
+                self.send_tcp(token, &msg);
+                let rcode = RCode::unpack(msg[3] & 0x0f).unwrap_or(RCode::NOERROR);
+                self.metrics.query(Transport::Tcp, qtype, rcode, received.elapsed());
+            }
+            Reply::Forwarded => {}
+            Reply::Dropped(reason) => self.metrics.dropped(reason),
+            Reply::Malformed => self.metrics.malformed(),
+        }
+    }
+
+    fn send_tcp_msg(&mut self, token: usize, msg: &Message, signer: Option<&mut Signer>) {
+        let mut buf = vec![0; 65535 - signer.as_ref().map_or(0, |s| s.len())];
+        let len = match msg.pack(&mut buf, 0) {
+            Ok(len) => len,
+            Err(e) => return println!("failed to pack response {:?}", e),
+        };
+        buf.truncate(len);
+        if let Some(signer) = signer {
+            if let Err(e) = signer.sign(&mut buf, tsig::now()) {
+                return println!("failed to sign response {:?}", e)
+            }
+        }
+        self.send_tcp(token, &buf);
+    }
+
+    /// Queues a message on a TCP connection and writes as much as the
+    /// socket takes; the rest goes out when it becomes writable.
+    fn send_tcp(&mut self, token: usize, msg: &[u8]) {
+        if let Some(conn) = self.tcp.get_mut(mio::Token(token)) {
+            conn.resp.push((msg.len() >> 8) as u8);
+            conn.resp.push(msg.len() as u8);
+            conn.resp.extend(msg);
+            flush(conn);
+        }
+    }
+
+    /// Closes a TCP connection that failed, was closed by the client and
+    /// has nothing left to write, or stayed idle for too long.
+    fn close_tcp(&mut self, event_loop: &mut mio::EventLoop<Server>, token: mio::Token, now: u64) {
+        let close = match self.tcp.get(token) {
+            Some(conn) => {
+                let idle = conn.written == conn.resp.len();
+                conn.failed || (idle && (conn.eof || now >= conn.active + TCP_IDLE))
+            }
+            None => false,
+        };
+        if close {
+            if let Some(conn) = self.tcp.remove(token) {
+                let _ = event_loop.deregister(&conn.stream);
+            }
+            let signed: Vec<usize> = self.signed.iter()
+                .filter(|&(_, s)| s.client == Client::Tcp(token.0))
+                .map(|(&slot, _)| slot)
+                .collect();
+            for slot in signed {
+                self.signed.remove(&slot);
+                self.forwarder.cancel(Client::Signed(slot));
+                self.validating.retain(|_, v| v.client != Client::Signed(slot));
+            }
+            self.forwarder.cancel(Client::Tcp(token.0));
+            self.validating.retain(|_, v| v.client != Client::Tcp(token.0));
+        }
+    }
+
+    fn accept_http(&mut self, event_loop: &mut mio::EventLoop<Server>) {
+        loop {
+            let stream = match self.metrics_listener.as_ref().map(|l| l.accept()) {
+                Some(Ok(Some(stream))) => stream,
+                Some(Err(e)) => return println!("failed to accept metrics connection {}", e),
+                _ => return,
+            };
+            let conn = HttpConn{ stream: stream, req: vec![], resp: vec![], written: 0 };
+            let token = match self.http.insert(conn) {
+                Ok(token) => token,
+                Err(_) => continue, // too many connections, close it
+            };
+            let registered = event_loop.register_opt(&self.http[token].stream,
+                                                     token,
+                                                     mio::EventSet::readable() | mio::EventSet::writable(),
+                                                     mio::PollOpt::edge());
+            if registered.is_err() {
+                self.http.remove(token);
+            }
+        }
+    }
+
+    fn ready_http(&mut self, event_loop: &mut mio::EventLoop<Server>, token: mio::Token, events: mio::EventSet) {
+        let metrics = &self.metrics;
+        let cache = self.cache.as_ref();
+        let rrl = self.rrl.as_ref().map(|rrl| rrl.stats());
+
+        let done = match self.http.get_mut(token) {
+            Some(conn) => {
+                let mut done = events.is_error() || events.is_hup();
+                if conn.resp.is_empty() && !done {
+                    let mut chunk = [0; 1024];
+                    loop {
+                        match conn.stream.try_read(&mut chunk) {
+                            Ok(Some(0)) => { done = true; break }
+                            Ok(Some(n)) => {
+                                conn.req.extend(&chunk[..n]);
+                                if conn.req.len() >= metrics::MAX_HTTP_REQUEST {
+                                    break
+                                }
+                            }
+                            Ok(None) => break,
+                            Err(_) => { done = true; break }
+                        }
+                    }
+                    if let Some(resp) = metrics::http_response(&conn.req, || metrics.render(cache, rrl)) {
+                        conn.resp = resp;
+                        done = false;
+                    }
+                }
+                while !done && !conn.resp.is_empty() && conn.written < conn.resp.len() {
+                    match conn.stream.try_write(&conn.resp[conn.written..]) {
+                        Ok(Some(n)) => conn.written += n,
+                        Ok(None) => break,
+                        Err(_) => done = true,
+                    }
+                }
+                done || (!conn.resp.is_empty() && conn.written == conn.resp.len())
+            }
+            None => false,
+        };
+
+        if done {
+            if let Some(conn) = self.http.remove(token) {
+                let _ = event_loop.deregister(&conn.stream);
+            }
+        }
+    }
+
+    /// Periodic housekeeping driven by the event loop timer.
+    fn tick(&mut self, event_loop: &mut mio::EventLoop<Server>) {
+        self.config.blocklists.reload();
+
+        let now = self.started.elapsed().as_secs();
+        for t in TCP_CONN..TCP_CONN + MAX_TCP_CONNS {
+            self.close_tcp(event_loop, mio::Token(t), now);
+        }
+        self.refresh(now);
+        self.resign();
+        self.notify(now);
+        self.rotate(event_loop);
+        if let Some(ref mut cache) = self.cache {
+            cache.prune(now);
+        }
+        if let Some(ref mut validator) = self.validator {
+            validator.prune(tsig::now());
+        }
+        let stale: Vec<usize> = self.validating.iter()
+            .filter(|&(_, v)| v.started.elapsed().as_secs() >= VALIDATION_TIMEOUT)
+            .map(|(&slot, _)| slot)
+            .collect();
+        for slot in stale {
+            self.finish(slot, Security::Bogus("validation timed out"));
+        }
+        for (timed_out, retry) in self.forwarder.expire(now) {
+            self.metrics.upstream_timeout(&timed_out);
+            match retry {
+                Retry::Resend(query, upstream, socket) => self.send_upstream(&query, &upstream, socket),
+                Retry::Fail(resp, client, elapsed) => {
+                    let qtype = resp.questions.first().map_or(RType::ZERO, |q| q.rtype);
+                    let rcode = resp.rcode;
+                    let transport = match client {
+                        Client::Udp(ref addr) => {
+                            if let Some(resp) = self.limit(resp, addr) {
+                                self.send(&resp, 512, addr, None);
+                            }
+                            Transport::Udp
+                        }
+                        Client::Tcp(token) => { self.send_tcp_msg(token, &resp, None); Transport::Tcp }
+                        Client::Signed(slot) => match self.answer_signed(slot, resp) {
+                            Some(transport) => transport,
+                            None => continue,
+                        },
+                        Client::Validating(slot) => {
+                            self.finish(slot, Security::Bogus("no answer from upstream"));
+                            continue
+                        }
+                        // lookups that failed are bogus for a while
+                        Client::Fetch => {
+                            self.fetched(&resp);
+                            continue
+                        }
+                    };
+                    self.metrics.query(transport, qtype, rcode, elapsed);
+                }
+            }
+        }
+    }
+
+    /// Installs the zones refreshed since the last tick, drops expired ones
+    /// and starts the refreshes that are due.
+    fn refresh(&mut self, now: u64) {
+        while let Ok((i, refresh)) = self.refreshed.1.try_recv() {
+            let secondary = &mut self.secondaries[i];
+            if let Some(zone) = secondary.finish(refresh, now) {
+                println!("zone {} transferred serial {}", zone.origin(), zone.serial());
+                self.config.views.get_mut(secondary.view).unwrap().zones.insert(zone);
+            }
+        }
+
+        for (i, secondary) in self.secondaries.iter_mut().enumerate() {
+            let view = self.config.views.get_mut(secondary.view).unwrap();
+            if !secondary.serving(now) && view.zones.remove(&secondary.config.origin).is_some() {
+                println!("zone {} expired", secondary.config.origin);
+            }
+            if !secondary.due(now) {
+                continue
+            }
+            secondary.start();
+            let config = secondary.config.clone();
+            let current = view.zones.get(&config.origin).cloned();
+            let done = self.refreshed.0.clone();
+            thread::spawn(move || {
+                let _ = done.send((i, secondary::refresh(&config, current.as_ref())));
+            });
+        }
+    }
+
+    /// Renews the signatures of the zones signed here once they are due.
+    fn resign(&mut self) {
+        let now = tsig::now() as u32;
+        for &mut (view, ref origin, ref mut signer) in self.signers.iter_mut() {
+            if !signer.due(now) {
+                continue
+            }
+            let zone = self.config.views.get_mut(view).and_then(|v| v.zones.get_mut(origin));
+            let journal = self.journals.iter_mut()
+                .find(|&&mut (v, ref o, _)| v == view && o == origin)
+                .map(|&mut (_, _, ref mut journal)| journal);
+            if let (Some(zone), Some(journal)) = (zone, journal) {
+                if let Err(e) = resign(zone, signer, journal) {
+                    println!("zone {} failed to sign: {:?}", origin, e);
+                }
+            }
+        }
+    }
+
+    /// Sends NOTIFY for zones with a new serial and resends those not
+    /// acknowledged yet.
+    fn notify(&mut self, now: u64) {
+        let mut out = vec![];
+        for (i, view) in self.config.views.views().iter().enumerate() {
+            for zone in view.zones.iter() {
+                out.extend(self.notifier.check(i, zone, &view.notify, now));
+            }
+        }
+        out.extend(self.notifier.expire(now));
+        for (msg, target) in out {
+            let socket = self.forwarder.socket();
+            if let Err(e) = self.upstream[socket].send_to(&mut SliceBuf::wrap(&msg[..]), &target) {
+                println!("failed to send NOTIFY to {}: {}", target, e);
+            }
+        }
+    }
+
+    /// Replaces the next upstream socket with one on a fresh port, unless
+    /// answers to queries sent from it are still due.
+    fn rotate(&mut self, event_loop: &mut mio::EventLoop<Server>) {
+        let i = self.rotated % UPSTREAM_SOCKETS;
+        self.rotated += 1;
+        if self.forwarder.in_use(i) {
+            return
+        }
+        let socket = match UdpSocket::bound(&unbound(&self.local)) {
+            Ok(socket) => socket,
+            Err(e) => return println!("failed to open upstream socket {}", e),
+        };
+        let registered = event_loop.register_opt(&socket,
+                                                 mio::Token(UPSTREAM_UDP + i),
+                                                 mio::EventSet::readable(),
+                                                 mio::PollOpt::edge());
+        if registered.is_ok() {
+            let _ = event_loop.deregister(&self.upstream[i]);
+            self.upstream[i] = socket;
+        }
+    }
+
+    pub fn rrl_stats(&self) -> Option<&rrl::RrlStats> {
+        self.rrl.as_ref().map(|rrl| rrl.stats())
+    }
+
+    pub fn blocklists(&self) -> &Blocklists {
+        &self.config.blocklists
+    }
+
+    pub fn views(&self) -> &Views {
+        &self.config.views
+    }
+
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+}
+
+fn access(req: &MessageView) -> Access {
+    match req.opcode() {
+        Ok(OpCode::UPDATE) => return Access::Update,
+        Ok(OpCode::NOTIFY) => return Access::Notify,
+        _ => {}
+    }
+    match req.questions().next().map(|q| q.rtype) {
+        Some(t) if t == RType::AXFR as u16 || t == RType::IXFR as u16 => Access::Transfer,
+        _ if req.rd() => Access::Recursion,
+        _ => Access::Cache,
+    }
+}
+
+// The type asked for, for the metrics.
+fn qtype(req: &MessageView) -> RType {
+    req.questions().next().and_then(|q| RType::unpack(q.rtype).ok()).unwrap_or(RType::ZERO)
+}
+
+fn log_query(src: &SocketAddr, view: &str, req: &Message, result: &str) {
+    match req.questions.first() {
+        Some(q) => log_question(src, view, q, result),
+        None => println!("{} view {}: {:?} {}", src, view, req.opcode, result),
+    }
+}
+
+fn log_question(src: &SocketAddr, view: &str, q: &Question, result: &str) {
+    println!("{} view {}: {} {:?} {:?} {}", src, view, q.name.to_unicode(), q.class, q.rtype, result)
+}
+
+/// The truncated response sent in place of one slipped by rate limiting,
+/// for the client to retry over TCP.
+fn slipped(resp: &Message) -> Message {
+    let mut tc = Message::new_error(resp, resp.rcode);
+    tc.tc = true;
+    tc
+}
+
+// Signs a zone anew if it is not fully signed or any of its signatures is
+// due, journaling the change.
+fn resign(zone: &mut Zone, signer: &mut ZoneSigner, journal: &mut Journal) -> Result<()> {
+    if let Some(diff) = try!(signer.resign(zone, tsig::now() as u32)) {
+        try!(zone.apply(diff));
+        try!(journal.sync(zone));
+        println!("zone {} signed serial {}", zone.origin(), zone.serial());
+    }
+    Ok(())
+}
+
+/// Packs a message of our own making, which always fits.
+fn packed(msg: &Message) -> Vec<u8> {
+    let mut buf = vec![0; 65535];
+    let len = msg.pack(&mut buf, 0).unwrap_or(0);
+    buf.truncate(len);
+    buf
+}
+
+/// Writes out what is queued on a TCP connection.
+fn flush(conn: &mut TcpConn) {
+    while !conn.failed && conn.written < conn.resp.len() {
+        match conn.stream.try_write(&conn.resp[conn.written..]) {
+            Ok(Some(n)) => conn.written += n,
+            Ok(None) => break,
+            Err(_) => conn.failed = true,
+        }
+    }
+    if conn.written == conn.resp.len() {
+        conn.resp.clear();
+        conn.written = 0;
+    }
+}
+
+/// The wildcard address of the family of `addr`, with a port picked by the
+/// system, which draws it at random.
+fn unbound(addr: &SocketAddr) -> SocketAddr {
+    match *addr {
+        SocketAddr::V4(_) => "0.0.0.0:0".parse().unwrap(),
+        SocketAddr::V6(_) => "[::]:0".parse().unwrap(),
+    }
+}
+
+/// Reads one datagram, returning its length and sender.
+fn recv(socket: &UdpSocket, buf: &mut [u8]) -> io::Result<Option<(usize, SocketAddr)>> {
+    let cap = buf.len();
+    let mut slice = MutSliceBuf::wrap(buf);
+    match try!(socket.recv_from(&mut slice)) {
+        Some(addr) => Ok(Some((cap - slice.remaining(), addr))),
+        None => Ok(None),
+    }
+}
+
+
+impl mio::Handler for Server {
+    type Timeout = usize;
+    type Message = ();
+
+    fn ready(&mut self, event_loop: &mut mio::EventLoop<Server>, token: mio::Token, events: mio::EventSet) {
+        match token {
+            SERVER_UDP => self.ready_server(),
+            mio::Token(t) if t >= UPSTREAM_UDP && t < UPSTREAM_UDP + UPSTREAM_SOCKETS => self.ready_upstream(t - UPSTREAM_UDP),
+            METRICS_HTTP => self.accept_http(event_loop),
+            TCP_SERVER => self.accept_tcp(event_loop),
+            mio::Token(t) if t >= TCP_CONN => self.ready_tcp(event_loop, token, events),
+            mio::Token(t) if t >= HTTP_CONN => self.ready_http(event_loop, token, events),
+            _ => (),
+        }
+    }
+
+    fn timeout(&mut self, event_loop: &mut mio::EventLoop<Server>, timeout: usize) {
+        match timeout {
+            TICK => {
+                self.tick(event_loop);
+                event_loop.timeout_ms(TICK, TICK_MS).unwrap();
             }
             _ => (),
         }
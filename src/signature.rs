@@ -0,0 +1,294 @@
+use bignum::{Modulus, Residue};
+use digest::{Hash, Sha1, Sha256, Sha512};
+use ed25519;
+
+/// DNSSEC algorithm numbers (RFC 8624 §3.1) of the supported algorithms.
+pub const RSASHA1: u8 = 5;
+pub const RSASHA1_NSEC3_SHA1: u8 = 7;
+pub const RSASHA256: u8 = 8;
+pub const RSASHA512: u8 = 10;
+pub const ECDSAP256SHA256: u8 = 13;
+pub const ED25519: u8 = 15;
+
+/// Whether signatures of `algorithm` can be checked. RRsets signed only
+/// with other algorithms are treated as unsigned (RFC 4035 §5.2).
+pub fn supported(algorithm: u8) -> bool {
+    match algorithm {
+        RSASHA1 | RSASHA1_NSEC3_SHA1 | RSASHA256 | RSASHA512 | ECDSAP256SHA256 | ED25519 => true,
+        _ => false,
+    }
+}
+
+/// Checks `signature` over `data` against a public key in the DNSKEY
+/// format of `algorithm`.
+pub fn verify(algorithm: u8, public_key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+    match algorithm {
+        RSASHA1 | RSASHA1_NSEC3_SHA1 => rsa(public_key, &SHA1_PREFIX, &Sha1::digest(data), signature),
+        RSASHA256 => rsa(public_key, &SHA256_PREFIX, &Sha256::digest(data), signature),
+        RSASHA512 => rsa(public_key, &SHA512_PREFIX, &Sha512::digest(data), signature),
+        ECDSAP256SHA256 => ecdsa_p256(public_key, &Sha256::digest(data), signature),
+        ED25519 => ed25519(public_key, data, signature),
+        _ => false,
+    }
+}
+
+// DER encoded DigestInfo headers of EMSA-PKCS1-v1_5 (RFC 8017 §9.2).
+const SHA1_PREFIX: [u8; 15] = [0x30, 0x21, 0x30, 0x09, 0x06, 0x05, 0x2b, 0x0e, 0x03, 0x02, 0x1a, 0x05, 0x00, 0x04, 0x14];
+const SHA256_PREFIX: [u8; 19] = [0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+                                 0x05, 0x00, 0x04, 0x20];
+const SHA512_PREFIX: [u8; 19] = [0x30, 0x51, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03,
+                                 0x05, 0x00, 0x04, 0x40];
+
+// Bounds on the RSA keys checked: moduli of up to 4096 bits (RFC 3110 §2)
+// and short exponents, as longer ones only make verification slow.
+const MAX_RSA_MODULUS: usize = 512;
+const MAX_RSA_EXPONENT: usize = 4;
+
+// RSASSA-PKCS1-v1_5 with the key as exponent length, exponent and modulus
+// (RFC 3110 §2).
+fn rsa(key: &[u8], prefix: &[u8], hash: &[u8], signature: &[u8]) -> bool {
+    let (exp, modulus) = match key.first() {
+        Some(&0) if key.len() > 3 => {
+            let len = (key[1] as usize) << 8 | key[2] as usize;
+            if key.len() <= 3 + len { return false }
+            (&key[3..3 + len], &key[3 + len..])
+        }
+        Some(&len) if key.len() > 1 + len as usize => (&key[1..1 + len as usize], &key[1 + len as usize..]),
+        _ => return false,
+    };
+    if exp.is_empty() || exp.len() > MAX_RSA_EXPONENT || modulus.len() > MAX_RSA_MODULUS {
+        return false
+    }
+    if modulus.len() < prefix.len() + hash.len() + 11 || modulus[0] == 0 || modulus[modulus.len() - 1] & 1 == 0 {
+        return false
+    }
+    let n = Modulus::new(modulus);
+    if signature.len() != n.len() {
+        return false
+    }
+    let s = match n.residue(signature) {
+        Some(s) => s,
+        None => return false,
+    };
+    let em = n.bytes(&n.pow(&s, exp));
+
+    let mut expected = vec![0x00, 0x01];
+    expected.resize(n.len() - prefix.len() - hash.len() - 1, 0xff);
+    expected.push(0x00);
+    expected.extend_from_slice(prefix);
+    expected.extend_from_slice(hash);
+    em == expected
+}
+
+// A point in Jacobian coordinates, the point at infinity having Z = 0.
+#[derive(Clone)]
+struct Jacobian {
+    x: Residue,
+    y: Residue,
+    z: Residue,
+}
+
+struct P256 {
+    p: Modulus,
+    n: Modulus,
+    b: Residue,
+    g: Jacobian,
+}
+
+impl P256 {
+
+    fn new() -> P256 {
+        let p = Modulus::new(&hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"));
+        let n = Modulus::new(&hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"));
+        let g = Jacobian{
+            x: p.reduce(&hex("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296")),
+            y: p.reduce(&hex("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5")),
+            z: p.one(),
+        };
+        P256{
+            b: p.reduce(&hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b")),
+            p: p,
+            n: n,
+            g: g,
+        }
+    }
+
+    // y^2 = x^3 - 3x + b
+    fn on_curve(&self, x: &Residue, y: &Residue) -> bool {
+        let p = &self.p;
+        let x3 = p.mul(&p.square(x), x);
+        let three_x = p.add(&p.add(x, x), x);
+        p.square(y) == p.add(&p.sub(&x3, &three_x), &self.b)
+    }
+
+    // dbl-2001-b, for a = -3
+    fn double(&self, a: &Jacobian) -> Jacobian {
+        let p = &self.p;
+        if p.is_zero(&a.z) {
+            return a.clone()
+        }
+        let delta = p.square(&a.z);
+        let gamma = p.square(&a.y);
+        let beta = p.mul(&a.x, &gamma);
+        let t = p.mul(&p.sub(&a.x, &delta), &p.add(&a.x, &delta));
+        let alpha = p.add(&p.add(&t, &t), &t);
+        let beta4 = p.add(&p.add(&beta, &beta), &p.add(&beta, &beta));
+        let x = p.sub(&p.square(&alpha), &p.add(&beta4, &beta4));
+        let z = p.sub(&p.sub(&p.square(&p.add(&a.y, &a.z)), &gamma), &delta);
+        let gamma2 = p.square(&gamma);
+        let gamma8 = p.add(&p.add(&gamma2, &gamma2), &p.add(&gamma2, &gamma2));
+        let y = p.sub(&p.mul(&alpha, &p.sub(&beta4, &x)), &p.add(&gamma8, &gamma8));
+        Jacobian{ x: x, y: y, z: z }
+    }
+
+    // add-2007-bl
+    fn add(&self, a: &Jacobian, b: &Jacobian) -> Jacobian {
+        let p = &self.p;
+        if p.is_zero(&a.z) {
+            return b.clone()
+        }
+        if p.is_zero(&b.z) {
+            return a.clone()
+        }
+        let z1z1 = p.square(&a.z);
+        let z2z2 = p.square(&b.z);
+        let u1 = p.mul(&a.x, &z2z2);
+        let u2 = p.mul(&b.x, &z1z1);
+        let s1 = p.mul(&p.mul(&a.y, &b.z), &z2z2);
+        let s2 = p.mul(&p.mul(&b.y, &a.z), &z1z1);
+        let h = p.sub(&u2, &u1);
+        let r = p.sub(&s2, &s1);
+        if p.is_zero(&h) {
+            return if p.is_zero(&r) { self.double(a) } else { Jacobian{ x: p.one(), y: p.one(), z: p.zero() } }
+        }
+        let r = p.add(&r, &r);
+        let i = p.square(&p.add(&h, &h));
+        let j = p.mul(&h, &i);
+        let v = p.mul(&u1, &i);
+        let x = p.sub(&p.sub(&p.square(&r), &j), &p.add(&v, &v));
+        let s1j = p.mul(&s1, &j);
+        let y = p.sub(&p.mul(&r, &p.sub(&v, &x)), &p.add(&s1j, &s1j));
+        let z = p.mul(&p.sub(&p.sub(&p.square(&p.add(&a.z, &b.z)), &z1z1), &z2z2), &h);
+        Jacobian{ x: x, y: y, z: z }
+    }
+
+    // u1 * G + u2 * Q with both scalars as big endian bytes.
+    fn combine(&self, u1: &[u8], u2: &[u8], q: &Jacobian) -> Jacobian {
+        let both = self.add(&self.g, q);
+        let mut r = Jacobian{ x: self.p.one(), y: self.p.one(), z: self.p.zero() };
+        for (&a, &b) in u1.iter().zip(u2.iter()) {
+            for bit in (0..8).rev() {
+                r = self.double(&r);
+                match (a >> bit & 1, b >> bit & 1) {
+                    (1, 1) => r = self.add(&r, &both),
+                    (1, 0) => r = self.add(&r, &self.g),
+                    (0, 1) => r = self.add(&r, q),
+                    _ => {}
+                }
+            }
+        }
+        r
+    }
+}
+
+// ECDSA over P-256 with the key and signature as concatenated coordinates
+// (RFC 6605 §4).
+fn ecdsa_p256(key: &[u8], hash: &[u8], signature: &[u8]) -> bool {
+    if key.len() != 64 || signature.len() != 64 {
+        return false
+    }
+    let curve = P256::new();
+    let (p, n) = (&curve.p, &curve.n);
+    let q = match (p.residue(&key[..32]), p.residue(&key[32..])) {
+        (Some(x), Some(y)) => Jacobian{ x: x, y: y, z: p.one() },
+        _ => return false,
+    };
+    if !curve.on_curve(&q.x, &q.y) {
+        return false
+    }
+    let (r, s) = match (n.residue(&signature[..32]), n.residue(&signature[32..])) {
+        (Some(r), Some(s)) => (r, s),
+        _ => return false,
+    };
+    if n.is_zero(&r) || n.is_zero(&s) {
+        return false
+    }
+    let w = n.inv(&s);
+    let u1 = n.bytes(&n.mul(&n.reduce(hash), &w));
+    let u2 = n.bytes(&n.mul(&r, &w));
+    let point = curve.combine(&u1, &u2, &q);
+    if p.is_zero(&point.z) {
+        return false
+    }
+    let x = p.bytes(&p.mul(&point.x, &p.inv(&p.square(&point.z))));
+    n.reduce(&x) == r
+}
+
+// Ed25519 (RFC 8032 §5.1.7) with the key and signature in their usual
+// encodings (RFC 8080 §3).
+fn ed25519(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+    ed25519::verify(key, data, signature)
+}
+
+/// The Ed25519 public key of a 32 byte secret seed (RFC 8032 §5.1.5).
+pub fn ed25519_public_key(seed: &[u8]) -> Vec<u8> {
+    ed25519::public_key(seed)
+}
+
+/// Signs `data` with the Ed25519 key of a 32 byte secret seed (RFC 8032
+/// §5.1.6), in constant time.
+pub fn sign_ed25519(seed: &[u8], data: &[u8]) -> Vec<u8> {
+    ed25519::sign(seed, data)
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len() / 2).map(|i| u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap()).collect()
+}
+
+
+#[cfg(test)] use dns::base64;
+
+#[test]
+fn verify_signatures() {
+    // an RSA and a P-256 key made and used by OpenSSL 3.5:
+    //   openssl genpkey -algorithm RSA -pkeyopt rsa_keygen_bits:1024 -out rsa.pem
+    //   openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:P-256 -out ec.pem
+    //   openssl dgst -sha256 -sign rsa.pem (or ec.pem) data
+    // with the keys in DNSKEY form (RFC 3110 §2, RFC 6605 §4) and the DER
+    // ECDSA signature taken apart into r and s
+    let data = b"signed by a real implementation";
+    let key = base64::decode("AwEAAdCQZVoQN6h/Rcbg7Heau/KIVdDz4g28hMGmVtvkVh9xGNJjPnwoaIJj+uuwZy/44PXE45BEzuUUzxe9\
+                              lOV4Uj1aGJ/rzBHO5TAYiB8LKhp/ooXZPa+HdMAF9aP11Lh/COB/djGQQys6xS77OIQm+iUrTwFZWstZwHFP\
+                              ay5WYWQ7").unwrap();
+    let sig = base64::decode("NtxIIi/8RTK4/iTXz8B4qhUiceX5b8SL6elp3zQVzPDl2W/xnGxO4vUgc7P3xeM98GDVZVCyzSIQ+GtH4dCp\
+                              eJMO3rtbKgpeW0SYY3Pj64ur3c4QeJ41cM6dsD+QEHFMKBmlFxj8JnBrjwiahcR9t9r7cd5InjRNDkr1Jh3y\
+                              zHE=").unwrap();
+    assert!(verify(RSASHA256, &key, data, &sig));
+    assert!(!verify(RSASHA512, &key, data, &sig));
+    assert!(!verify(RSASHA256, &key, b"signed by someone else", &sig));
+    // keys too large to check in reasonable time
+    let mut long_exponent = vec![0, 0, 5, 0, 0];
+    long_exponent.extend_from_slice(&key[1..]);
+    assert!(!verify(RSASHA256, &long_exponent, data, &sig));
+    let mut huge = vec![3, 1, 0, 1, 0xff];
+    huge.extend(vec![0xff; 512]);
+    assert!(!verify(RSASHA256, &huge, data, &vec![1; 513]));
+
+    let key = base64::decode("G2IAqvZAAbs4Q+x4XamtFG/zLx+Ej44a8sv554dZcepTQvP1qfGFdr55QfA0Q8d6SY7JgXOnGwkOQzJYyhjnxg==").unwrap();
+    let mut sig = base64::decode("0ZwqzGCybMCqzVJVl9B8cr7eTF9tA4PJwt4QeougPu2jDJrvxKl7rlUuqEu+sJSAuK+/OL4FziKORsthqgsweg==").unwrap();
+    assert!(verify(ECDSAP256SHA256, &key, data, &sig));
+    sig[40] ^= 1;
+    assert!(!verify(ECDSAP256SHA256, &key, data, &sig));
+
+    // RFC 8032 §7.1 test 1
+    let key = hex("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+    let sig = hex("e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b");
+    assert!(verify(ED25519, &key, b"", &sig));
+    assert!(!verify(ED25519, &key, b"x", &sig));
+    let seed = hex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60");
+    assert_eq!(ed25519_public_key(&seed), key);
+    assert_eq!(sign_ed25519(&seed, b""), sig);
+
+    assert!(!supported(14));
+    assert!(!verify(14, &key, b"", &sig));
+}
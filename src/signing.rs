@@ -0,0 +1,712 @@
+use std::cmp;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
+
+use {Result, Error};
+use dns::{RType, RName, RData};
+use dns::message::Resource;
+use dns::master::Reader;
+use dns::{base32, base64};
+use dns::dnssec::{Dnskey, Rrsig, Nsec, Nsec3, Nsec3Param, ZONE_KEY, SEP, PROTOCOL};
+use digest::{Hash, Sha256};
+use signature::{self, ed25519_public_key, sign_ed25519, ED25519};
+use validator::{signed_data, nsec3_hash, ds};
+use zone::{Diff, Zone, serial_lt};
+
+/// Seconds signatures are valid for by default.
+pub const VALIDITY: u32 = 14 * 86400;
+
+/// Seconds before they expire that signatures are renewed by default.
+pub const REFRESH: u32 = 4 * 86400;
+
+// Signatures are valid from an hour back, for validators with clocks behind.
+const SKEW: u32 = 3600;
+
+/// How a zone loaded from a file is signed by this server.
+#[derive(Clone, Debug)]
+pub struct SigningConfig {
+    /// Directory the keys of the zone are kept in, in the files BIND uses.
+    /// A key signing and a zone signing key are generated there when it
+    /// has none for the zone.
+    pub key_dir: PathBuf,
+    /// Hashing parameters to deny existence with NSEC3 instead of NSEC.
+    pub nsec3: Option<Nsec3Param>,
+    /// Seconds the signatures made are valid for.
+    pub validity: u32,
+    /// Seconds before they expire that signatures are made again.
+    pub refresh: u32,
+}
+
+impl SigningConfig {
+
+    /// Signing with NSEC and the default validity, with keys in `key_dir`.
+    pub fn new<P: AsRef<Path>>(key_dir: P) -> SigningConfig {
+        SigningConfig{
+            key_dir: key_dir.as_ref().to_path_buf(),
+            nsec3: None,
+            validity: VALIDITY,
+            refresh: REFRESH,
+        }
+    }
+}
+
+/// An Ed25519 key a zone is signed with.
+#[derive(Clone)]
+pub struct ZoneKey {
+    /// The DNSKEY flags, with SEP set on key signing keys.
+    pub flags: u16,
+    seed: Vec<u8>,
+}
+
+impl ZoneKey {
+
+    /// The key of a 32 byte secret seed.
+    pub fn new(flags: u16, seed: &[u8]) -> ZoneKey {
+        ZoneKey{ flags: flags, seed: seed.to_vec() }
+    }
+
+    /// A new key from `/dev/urandom`.
+    pub fn generate(flags: u16) -> Result<ZoneKey> {
+        let mut seed = vec![0; 32];
+        try!(File::open("/dev/urandom").and_then(|mut f| f.read_exact(&mut seed)));
+        Ok(ZoneKey::new(flags, &seed))
+    }
+
+    pub fn dnskey(&self) -> Dnskey {
+        Dnskey{
+            flags: self.flags,
+            protocol: PROTOCOL,
+            algorithm: ED25519,
+            public_key: ed25519_public_key(&self.seed),
+        }
+    }
+
+    /// Reads the keys of `origin` kept in `dir` as `K<origin>+015+<tag>`
+    /// `.key` and `.private` files. Keys of other algorithms are ignored.
+    pub fn load<P: AsRef<Path>>(origin: &RName, dir: P) -> Result<Vec<ZoneKey>> {
+        let prefix = format!("k{}+{:03}+", origin.to_string().to_lowercase(), ED25519);
+        let mut paths = vec![];
+        for entry in try!(fs::read_dir(dir)) {
+            let path = try!(entry).path();
+            let matches = path.file_name().and_then(|n| n.to_str()).map_or(false, |n| {
+                let n = n.to_lowercase();
+                n.starts_with(&prefix) && n.ends_with(".private")
+            });
+            if matches {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut keys = vec![];
+        for path in paths {
+            let private = try!(read(&path));
+            let seed = match private.lines().find(|l| l.starts_with("PrivateKey:")) {
+                Some(line) => try!(base64::decode(line[11..].trim()).map_err(|_| Error::BadKey)),
+                None => return Err(Error::BadKey),
+            };
+            // BIND leaves the TTL out of the public key file
+            let stem = path.to_string_lossy().trim_right_matches(".private").to_string();
+            let public = format!("$TTL 3600\n{}", try!(read(Path::new(&format!("{}.key", stem)))));
+            let mut dnskey = None;
+            for entry in Reader::new(&public, origin.clone()) {
+                if let RData::DNSKEY(key) = try!(try!(entry).data()) {
+                    dnskey = Some(key);
+                }
+            }
+            match dnskey {
+                Some(ref key) if seed.len() == 32 && key.algorithm == ED25519 &&
+                    key.public_key == ed25519_public_key(&seed) => keys.push(ZoneKey::new(key.flags, &seed)),
+                _ => return Err(Error::BadKey),
+            }
+        }
+        Ok(keys)
+    }
+
+    /// Writes the key to `dir` in the files `load` reads.
+    pub fn save<P: AsRef<Path>>(&self, origin: &RName, dir: P) -> Result<()> {
+        let dnskey = self.dnskey();
+        let base = format!("K{}+{:03}+{:05}", origin, ED25519, dnskey.key_tag());
+        let kind = if self.flags & SEP != 0 { "key-signing" } else { "zone-signing" };
+
+        let mut public = try!(File::create(dir.as_ref().join(format!("{}.key", base))));
+        try!(write!(public, "; This is a {} key, keyid {}, for {}\n{} IN DNSKEY {}\n",
+                    kind, dnskey.key_tag(), origin, origin, dnskey));
+        try!(public.sync_all());
+
+        let mut private = try!(private_file(&dir.as_ref().join(format!("{}.private", base))));
+        try!(write!(private, "Private-key-format: v1.3\nAlgorithm: {} (ED25519)\nPrivateKey: {}\n",
+                    ED25519, base64::encode(&self.seed)));
+        try!(private.sync_all());
+        Ok(())
+    }
+}
+
+/// Keeps a zone signed: every authoritative RRset, an NSEC or NSEC3 chain
+/// over its names and the DNSKEY, CDS and CDNSKEY records of its keys at
+/// the apex (RFC 4035 §2, RFC 7344 §4). The DNSKEY set is signed with the
+/// key signing keys, everything else with the zone signing keys, or with
+/// any key when there is only one kind.
+pub struct ZoneSigner {
+    pub config: SigningConfig,
+    keys: Vec<ZoneKey>,
+    // digests of the signatures known to be good, with the data they sign
+    verified: HashSet<Vec<u8>>,
+    // when the first signature is to be renewed
+    due: Option<u32>,
+}
+
+// What a zone needs to be signed: the records of the chain and the apex
+// keys, the signatures to keep and the RRsets to sign with each key.
+struct Plan {
+    records: Vec<Resource>,
+    keep: Vec<Resource>,
+    sign: Vec<(Vec<Resource>, usize)>,
+}
+
+impl ZoneSigner {
+
+    pub fn new(config: SigningConfig, keys: Vec<ZoneKey>) -> ZoneSigner {
+        ZoneSigner{
+            config: config,
+            keys: keys,
+            verified: HashSet::new(),
+            due: None,
+        }
+    }
+
+    /// Loads the keys of `origin` from the key directory, generating and
+    /// storing a key signing and a zone signing key if there are none.
+    pub fn open(origin: &RName, config: SigningConfig) -> Result<ZoneSigner> {
+        try!(fs::create_dir_all(&config.key_dir));
+        let mut keys = try!(ZoneKey::load(origin, &config.key_dir));
+        if keys.is_empty() {
+            for &flags in [ZONE_KEY | SEP, ZONE_KEY].iter() {
+                let key = try!(ZoneKey::generate(flags));
+                try!(key.save(origin, &config.key_dir));
+                println!("zone {} generated key {}", origin, key.dnskey().key_tag());
+                keys.push(key);
+            }
+        }
+        Ok(ZoneSigner::new(config, keys))
+    }
+
+    pub fn keys(&self) -> &[ZoneKey] {
+        &self.keys
+    }
+
+    /// Whether signatures are to be renewed at `now`, seconds since the
+    /// epoch. Zones not signed yet always are.
+    pub fn due(&self, now: u32) -> bool {
+        self.due.map_or(true, |due| !serial_lt(now, due))
+    }
+
+    /// Extends `diff`, a change to `zone`, with the signatures and chain
+    /// records the zone needs once it is made.
+    pub fn sign(&mut self, zone: &Zone, diff: Diff, now: u32) -> Result<Diff> {
+        let mut next = zone.clone();
+        try!(next.apply(diff.clone()));
+        let plan = self.plan(&next, now);
+        let (deleted, added) = self.changes(&next, plan, now);
+
+        let mut diff = diff;
+        for rr in deleted {
+            match diff.added.iter().position(|r| *r == rr) {
+                Some(i) => { diff.added.remove(i); }
+                None => diff.deleted.push(rr),
+            }
+        }
+        for rr in added {
+            match diff.deleted.iter().position(|r| *r == rr) {
+                Some(i) => { diff.deleted.remove(i); }
+                None => diff.added.push(rr),
+            }
+        }
+        Ok(diff)
+    }
+
+    /// The change signing `zone` under the next serial, or `None` if it is
+    /// signed already and none of its signatures is to be renewed.
+    pub fn resign(&mut self, zone: &Zone, now: u32) -> Result<Option<Diff>> {
+        let from = match zone.soa() {
+            Some(soa) => soa.clone(),
+            None => return Err(Error::BadZone),
+        };
+        let plan = self.plan(zone, now);
+        if plan.sign.is_empty() {
+            let (deleted, added) = self.changes(zone, plan, now);
+            if deleted.is_empty() && added.is_empty() {
+                return Ok(None)
+            }
+        }
+
+        let mut to = from.clone();
+        if let RData::SOA{ ref mut serial, .. } = to.data {
+            *serial = serial.wrapping_add(1);
+        }
+        let diff = Diff{ from: from, to: to, deleted: vec![], added: vec![] };
+        self.sign(zone, diff, now).map(Some)
+    }
+
+    fn plan(&mut self, zone: &Zone, now: u32) -> Plan {
+        let origin = zone.origin();
+        let (ttl, negative) = match zone.soa() {
+            Some(&Resource{ ttl, data: RData::SOA{ minimum, .. }, .. }) => (ttl, cmp::min(ttl, minimum)),
+            _ => (3600, 3600),
+        };
+
+        // the data to sign by owner in canonical order, leaving out the
+        // records kept here
+        let mut names: BTreeMap<RName, Vec<Resource>> = BTreeMap::new();
+        for rr in zone.records() {
+            if !managed(rr, origin) {
+                names.entry(rr.name.clone()).or_insert_with(Vec::new).push(rr.clone());
+            }
+        }
+        let cuts: HashSet<RName> = names.iter()
+            .filter(|&(name, rrs)| name != origin && rrs.iter().any(|r| r.rtype == RType::NS))
+            .map(|(name, _)| name.clone())
+            .collect();
+        // glue and anything else below a delegation is not authoritative
+        let names: BTreeMap<RName, Vec<Resource>> = names.into_iter()
+            .filter(|&(ref name, _)| !cuts.iter().any(|cut| cut != name && name.is_subdomain_of(cut)))
+            .collect();
+        let is_cut = |name: &RName| cuts.contains(name);
+
+        let mut records = vec![];
+        let mut apex_types = vec![RType::DNSKEY as u16];
+        for key in self.keys.iter() {
+            let dnskey = key.dnskey();
+            if key.flags & SEP != 0 {
+                records.push(record(origin, RType::CDS, ttl, RData::CDS(ds(origin, &dnskey, 2).unwrap())));
+                records.push(record(origin, RType::CDNSKEY, ttl, RData::CDNSKEY(dnskey.clone())));
+                apex_types.push(RType::CDS as u16);
+                apex_types.push(RType::CDNSKEY as u16);
+            }
+            records.push(record(origin, RType::DNSKEY, ttl, RData::DNSKEY(dnskey)));
+        }
+        if let Some(ref param) = self.config.nsec3 {
+            records.push(record(origin, RType::NSEC3PARAM, 0, RData::NSEC3PARAM(Nsec3Param{ flags: 0, ..param.clone() })));
+            apex_types.push(RType::NSEC3PARAM as u16);
+        }
+        let types = |name: &RName, rrs: &[Resource]| -> Vec<u16> {
+            let mut types: Vec<u16> = rrs.iter()
+                .filter(|r| !is_cut(name) || r.rtype == RType::NS || r.rtype == RType::DS)
+                .map(|r| r.rtype as u16)
+                .collect();
+            if name == origin {
+                types.extend(apex_types.iter().cloned());
+            }
+            types.sort();
+            types
+        };
+
+        let owners: Vec<(&RName, &Vec<Resource>)> = names.iter().collect();
+        match self.config.nsec3 {
+            None => for (i, &(name, rrs)) in owners.iter().enumerate() {
+                let mut types = types(name, rrs);
+                types.push(RType::NSEC as u16);
+                types.push(RType::RRSIG as u16);
+                types.sort();
+                let next = owners[(i + 1) % owners.len()].0.clone();
+                records.push(record(name, RType::NSEC, negative, RData::NSEC(Nsec{ next: next, types: types })));
+            },
+            Some(ref param) => {
+                // every name, the empty non-terminals above them included
+                let mut hashed: HashMap<Vec<u8>, Vec<u16>> = HashMap::new();
+                for &(name, rrs) in owners.iter() {
+                    let mut types = types(name, rrs);
+                    if !is_cut(name) || types.contains(&(RType::DS as u16)) {
+                        types.push(RType::RRSIG as u16);
+                        types.sort();
+                    }
+                    hashed.insert(nsec3_hash(name, &param.salt, param.iterations), types);
+                    let mut ent = name.parent();
+                    while let Some(parent) = ent {
+                        if parent.label_count() <= origin.label_count() {
+                            break
+                        }
+                        hashed.entry(nsec3_hash(&parent, &param.salt, param.iterations)).or_insert_with(Vec::new);
+                        ent = parent.parent();
+                    }
+                }
+                let mut hashes: Vec<(Vec<u8>, Vec<u16>)> = hashed.into_iter().collect();
+                hashes.sort();
+                for i in 0..hashes.len() {
+                    let owner = RName::from_str(&format!("{}.{}", base32::encode(&hashes[i].0), origin)).unwrap();
+                    records.push(record(&owner, RType::NSEC3, negative, RData::NSEC3(Nsec3{
+                        algorithm: param.algorithm,
+                        flags: 0,
+                        iterations: param.iterations,
+                        salt: param.salt.clone(),
+                        next: hashes[(i + 1) % hashes.len()].0.clone(),
+                        types: hashes[i].1.clone(),
+                    })));
+                }
+            }
+        }
+
+        // the RRsets to sign, keeping the signatures still good for them
+        let mut sets: HashMap<(RName, u16), Vec<Resource>> = HashMap::new();
+        for (name, rrs) in names.iter() {
+            for rr in rrs.iter().filter(|r| !is_cut(name) || r.rtype == RType::DS) {
+                sets.entry((name.clone(), rr.rtype as u16)).or_insert_with(Vec::new).push(rr.clone());
+            }
+        }
+        for rr in records.iter() {
+            sets.entry((rr.name.clone(), rr.rtype as u16)).or_insert_with(Vec::new).push(rr.clone());
+        }
+
+        let mut plan = Plan{ records: records, keep: vec![], sign: vec![] };
+        for (_, set) in sets {
+            let current = zone.get(&set[0].name);
+            for k in self.signing_keys(set[0].rtype) {
+                match current.iter().find(|r| self.good(r, &set, k, origin, now)) {
+                    Some(sig) => plan.keep.push(sig.clone()),
+                    None => plan.sign.push((set.clone(), k)),
+                }
+            }
+        }
+        plan
+    }
+
+    // The records to delete from and add to `zone` to carry out `plan`.
+    fn changes(&mut self, zone: &Zone, plan: Plan, now: u32) -> (Vec<Resource>, Vec<Resource>) {
+        let mut wanted = plan.records;
+        wanted.extend(plan.keep);
+        for (set, k) in plan.sign {
+            let sig = self.signature(zone.origin(), &set, k, now);
+            wanted.push(sig);
+        }
+
+        self.due = None;
+        for rr in wanted.iter() {
+            if let RData::RRSIG(ref sig) = rr.data {
+                let due = sig.expiration.wrapping_sub(self.config.refresh);
+                if self.due.map_or(true, |d| serial_lt(due, d)) {
+                    self.due = Some(due);
+                }
+            }
+        }
+
+        let current: Vec<&Resource> = zone.records().into_iter().filter(|rr| managed(rr, zone.origin())).collect();
+        (missing(&current, &wanted.iter().collect::<Vec<&Resource>>()), missing(&wanted.iter().collect::<Vec<&Resource>>(), &current))
+    }
+
+    // Indexes of the keys signing RRsets of `rtype`.
+    fn signing_keys(&self, rtype: RType) -> Vec<usize> {
+        let ksk = rtype == RType::DNSKEY || rtype == RType::CDS || rtype == RType::CDNSKEY;
+        let chosen: Vec<usize> = (0..self.keys.len())
+            .filter(|&i| (self.keys[i].flags & SEP != 0) == ksk)
+            .collect();
+        if chosen.is_empty() {
+            (0..self.keys.len()).collect()
+        } else {
+            chosen
+        }
+    }
+
+    // Whether `rr` is a signature by key `k` over `set` that can be kept.
+    fn good(&mut self, rr: &Resource, set: &[Resource], k: usize, origin: &RName, now: u32) -> bool {
+        let sig = match rr.data {
+            RData::RRSIG(ref sig) => sig,
+            _ => return false,
+        };
+        let dnskey = self.keys[k].dnskey();
+        if sig.type_covered != set[0].rtype as u16 || sig.algorithm != ED25519 ||
+            sig.key_tag != dnskey.key_tag() || sig.signer != *origin ||
+            sig.labels != labels(&set[0].name) || sig.original_ttl != set[0].ttl || rr.ttl != set[0].ttl ||
+            serial_lt(now, sig.inception) || !serial_lt(now.wrapping_add(self.config.refresh), sig.expiration) {
+            return false
+        }
+        let set: Vec<&Resource> = set.iter().collect();
+        let data = signed_data(sig, &set);
+        let digest = verified(&data, &sig.signature);
+        if self.verified.contains(&digest) {
+            return true
+        }
+        let good = signature::verify(ED25519, &dnskey.public_key, &data, &sig.signature);
+        if good {
+            self.verified.insert(digest);
+        }
+        good
+    }
+
+    fn signature(&mut self, origin: &RName, set: &[Resource], k: usize, now: u32) -> Resource {
+        let key = &self.keys[k];
+        let mut sig = Rrsig{
+            type_covered: set[0].rtype as u16,
+            algorithm: ED25519,
+            labels: labels(&set[0].name),
+            original_ttl: set[0].ttl,
+            expiration: now.wrapping_add(self.config.validity),
+            inception: now.wrapping_sub(SKEW),
+            key_tag: key.dnskey().key_tag(),
+            signer: origin.clone(),
+            signature: vec![],
+        };
+        let rrs: Vec<&Resource> = set.iter().collect();
+        let data = signed_data(&sig, &rrs);
+        sig.signature = sign_ed25519(&key.seed, &data);
+        self.verified.insert(verified(&data, &sig.signature));
+        Resource{
+            name: set[0].name.clone(),
+            rtype: RType::RRSIG,
+            class: set[0].class,
+            ttl: set[0].ttl,
+            data: RData::RRSIG(sig),
+        }
+    }
+}
+
+// The records of `from` that are not in `to`.
+fn missing(from: &[&Resource], to: &[&Resource]) -> Vec<Resource> {
+    let mut by_owner: HashMap<&RName, Vec<&Resource>> = HashMap::new();
+    for &rr in to.iter() {
+        by_owner.entry(&rr.name).or_insert_with(Vec::new).push(rr);
+    }
+    from.iter()
+        .filter(|rr| !by_owner.get(&rr.name).map_or(false, |rrs| rrs.contains(rr)))
+        .map(|&rr| rr.clone())
+        .collect()
+}
+
+// Records the signer makes rather than takes from the zone data.
+fn managed(rr: &Resource, origin: &RName) -> bool {
+    match rr.rtype {
+        RType::RRSIG | RType::NSEC | RType::NSEC3 | RType::NSEC3PARAM => true,
+        RType::DNSKEY | RType::CDS | RType::CDNSKEY => rr.name == *origin,
+        _ => false,
+    }
+}
+
+// The labels of a signature, not counting a leading wildcard.
+fn labels(name: &RName) -> u8 {
+    (name.label_count() - name.is_wildcard() as usize) as u8
+}
+
+fn record(name: &RName, rtype: RType, ttl: u32, data: RData) -> Resource {
+    Resource{
+        name: name.clone(),
+        rtype: rtype,
+        class: ::dns::Class::IN,
+        ttl: ttl,
+        data: data,
+    }
+}
+
+fn verified(data: &[u8], signature: &[u8]) -> Vec<u8> {
+    let mut h = Sha256::new();
+    h.update(data);
+    h.update(signature);
+    h.finish()
+}
+
+fn read(path: &Path) -> Result<String> {
+    let mut text = String::new();
+    try!(File::open(path).and_then(|mut f| f.read_to_string(&mut text)));
+    Ok(text)
+}
+
+// Private keys are readable by the owner only.
+#[cfg(unix)]
+fn private_file(path: &Path) -> Result<File> {
+    use std::os::unix::fs::OpenOptionsExt;
+    Ok(try!(OpenOptions::new().write(true).create(true).truncate(true).mode(0o600).open(path)))
+}
+
+#[cfg(not(unix))]
+fn private_file(path: &Path) -> Result<File> {
+    Ok(try!(OpenOptions::new().write(true).create(true).truncate(true).open(path)))
+}
+
+
+#[cfg(test)] use std::process::Command;
+#[cfg(test)] use dns::{Message, RCode, Class};
+#[cfg(test)] use dns::message::Question;
+#[cfg(test)] use validator::{self, Validator, Security};
+#[cfg(test)] use tsig;
+
+#[cfg(test)]
+const NOW: u32 = 1_700_000_000;
+
+#[cfg(test)]
+const ZONE: &'static str = "
+$ORIGIN example.com.
+$TTL 1h
+@       IN SOA  ns1 hostmaster 1 1d 2h 4w 300
+        IN NS   ns1
+ns1     IN A    192.0.2.53
+www     IN CNAME web
+web     IN A    192.0.2.80
+*.wild  IN A    192.0.2.99
+a.b.c   IN TXT  \"deep\"
+sub     IN NS   ns.sub
+ns.sub  IN A    192.0.2.153
+";
+
+#[cfg(test)]
+fn signer(nsec3: bool) -> ZoneSigner {
+    let mut config = SigningConfig::new("unused");
+    if nsec3 {
+        config.nsec3 = Some(Nsec3Param{ algorithm: 1, flags: 0, iterations: 5, salt: vec![0xbe, 0xef] });
+    }
+    ZoneSigner::new(config, vec![ZoneKey::new(ZONE_KEY | SEP, &[1; 32]), ZoneKey::new(ZONE_KEY, &[2; 32])])
+}
+
+#[cfg(test)]
+fn signed(signer: &mut ZoneSigner) -> Zone {
+    let mut zone = Zone::parse("example.com".parse().unwrap(), ZONE).unwrap();
+    let diff = signer.resign(&zone, NOW).unwrap().unwrap();
+    zone.apply(diff).unwrap();
+    zone
+}
+
+#[cfg(test)]
+fn rtypes(zone: &Zone, name: &str) -> Vec<RType> {
+    let mut types: Vec<RType> = zone.get(&name.parse().unwrap()).iter().map(|r| r.rtype).collect();
+    types.sort_by_key(|&t| t as u16);
+    types.dedup();
+    types
+}
+
+// Validates the answer of `zone` to a DO query, anchored at its key
+// signing key.
+#[cfg(test)]
+fn validate(zone: &Zone, qname: &str, qtype: RType) -> (Message, Security) {
+    let ksk = ZoneKey::new(ZONE_KEY | SEP, &[1; 32]).dnskey();
+    let anchor = record(zone.origin(), RType::DS, 3600, RData::DS(ds(zone.origin(), &ksk, 2).unwrap()));
+    let mut v = Validator::new(vec![anchor]);
+    let ask = |name: &RName, rtype: RType| zone.answer(&validator::query(1, &Question{ name: name.clone(), rtype: rtype, class: Class::IN }));
+
+    let resp = ask(&qname.parse().unwrap(), qtype);
+    for _ in 0..8 {
+        match v.validate(&resp, NOW as u64) {
+            Ok(security) => return (resp, security),
+            Err((name, rtype)) => v.learn(&ask(&name, rtype), NOW as u64),
+        }
+    }
+    panic!("validation did not finish")
+}
+
+#[test]
+fn sign_zone() {
+    let mut signer = signer(false);
+    let zone = signed(&mut signer);
+    assert_eq!(zone.serial(), 2);
+
+    use dns::RType::*;
+    assert_eq!(rtypes(&zone, "example.com"), vec![NS, SOA, RRSIG, NSEC, DNSKEY, CDS, CDNSKEY]);
+    assert_eq!(zone.get(&"example.com".parse().unwrap()).iter().filter(|r| r.rtype == DNSKEY).count(), 2);
+    assert_eq!(rtypes(&zone, "web.example.com"), vec![A, RRSIG, NSEC]);
+    assert_eq!(rtypes(&zone, "*.wild.example.com"), vec![A, RRSIG, NSEC]);
+    // delegations only have their NSEC signed, glue nothing at all
+    assert_eq!(rtypes(&zone, "sub.example.com"), vec![NS, RRSIG, NSEC]);
+    assert_eq!(rtypes(&zone, "ns.sub.example.com"), vec![A]);
+
+    // nothing to do until the signatures are about to expire
+    assert!(!signer.due(NOW + VALIDITY - REFRESH - 1));
+    assert!(signer.resign(&zone, NOW + 60).unwrap().is_none());
+    assert!(signer.due(NOW + VALIDITY - REFRESH));
+    let diff = signer.resign(&zone, NOW + VALIDITY - REFRESH).unwrap().unwrap();
+    assert_eq!(diff.deleted.len(), diff.added.len());
+    assert!(diff.added.iter().all(|r| r.rtype == RRSIG));
+
+    // a fresh signer checks the signatures it did not make once
+    let mut fresh = self::signer(false);
+    assert!(fresh.resign(&zone, NOW + 60).unwrap().is_none());
+}
+
+#[test]
+fn validate_signed_answers() {
+    for &nsec3 in [false, true].iter() {
+        let zone = signed(&mut signer(nsec3));
+        let check = |qname: &str, qtype: RType, rcode: RCode, security: Security| {
+            let (resp, result) = validate(&zone, qname, qtype);
+            assert_eq!((qname, resp.rcode, result), (qname, rcode, security));
+            resp
+        };
+        check("example.com", RType::SOA, RCode::NOERROR, Security::Secure);
+        check("www.example.com", RType::A, RCode::NOERROR, Security::Secure);
+        check("host.wild.example.com", RType::A, RCode::NOERROR, Security::Secure);
+        check("nope.example.com", RType::A, RCode::NXDOMAIN, Security::Secure);
+        check("web.example.com", RType::AAAA, RCode::NOERROR, Security::Secure);
+        check("b.c.example.com", RType::A, RCode::NOERROR, Security::Secure);
+        check("host.wild.example.com", RType::MX, RCode::NOERROR, Security::Secure);
+        // the delegation is proven unsigned, in referrals too
+        check("sub.example.com", RType::DS, RCode::NOERROR, Security::Secure);
+        let referral = validate(&zone, "www.sub.example.com", RType::A).0;
+        let denial = if nsec3 { RType::NSEC3 } else { RType::NSEC };
+        assert_eq!(referral.authority.iter().map(|r| r.rtype).collect::<Vec<RType>>(), vec![RType::NS, denial, RType::RRSIG]);
+
+        // without DO the records are left out
+        let plain = zone.answer(&Message{ edns: None, ..validator::query(1, &Question::parse("web.example.com", RType::A, Class::IN).unwrap()) });
+        assert_eq!(plain.answers.len(), 1);
+        assert_eq!(plain.edns, None);
+    }
+}
+
+#[test]
+fn sign_updates() {
+    let mut signer = signer(true);
+    let mut zone = signed(&mut signer);
+    let soa = zone.soa().unwrap().clone();
+    let mut to = soa.clone();
+    if let RData::SOA{ ref mut serial, .. } = to.data {
+        *serial += 1;
+    }
+    let new = record(&"new.example.com".parse().unwrap(), RType::A, 3600, RData::A(192, 0, 2, 7));
+    let diff = Diff{ from: soa, to: to, deleted: vec![], added: vec![new.clone()] };
+    let diff = signer.sign(&zone, diff, NOW + 60).unwrap();
+    assert!(diff.added.contains(&new));
+    assert!(diff.added.iter().any(|r| r.rtype == RType::NSEC3));
+    zone.apply(diff).unwrap();
+
+    assert_eq!(validate(&zone, "new.example.com", RType::A).1, Security::Secure);
+    assert_eq!(validate(&zone, "newer.example.com", RType::A).1, Security::Secure);
+}
+
+#[test]
+fn store_keys() {
+    let dir = ::std::env::temp_dir().join(format!("reagent-keys-{}", ::std::process::id()));
+    let origin: RName = "example.com".parse().unwrap();
+    let generated = ZoneSigner::open(&origin, SigningConfig::new(&dir)).unwrap();
+    let loaded = ZoneSigner::open(&origin, SigningConfig::new(&dir)).unwrap();
+    let other = ZoneKey::load(&"example.net".parse().unwrap(), &dir).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let dnskeys = |s: &ZoneSigner| {
+        let mut keys: Vec<(u16, u16)> = s.keys().iter().map(|k| (k.flags, k.dnskey().key_tag())).collect();
+        keys.sort();
+        keys
+    };
+    assert_eq!(dnskeys(&generated).len(), 2);
+    assert_eq!(dnskeys(&generated), dnskeys(&loaded));
+    assert_eq!(generated.keys().iter().filter(|k| k.flags & SEP != 0).count(), 1);
+    assert!(other.is_empty());
+}
+
+// Checks the zone as saved, signed now, with ldns-verify-zone from ldns or
+// else dnssec-verify from BIND: cargo test -- --ignored
+#[test]
+#[ignore]
+fn verify_saved_zone() {
+    for &nsec3 in [false, true].iter() {
+        let mut zone = Zone::parse("example.com".parse().unwrap(), ZONE).unwrap();
+        let diff = signer(nsec3).resign(&zone, tsig::now() as u32).unwrap().unwrap();
+        zone.apply(diff).unwrap();
+        let path = ::std::env::temp_dir().join(format!("reagent-verify-{}-{}.zone", ::std::process::id(), nsec3));
+        zone.save(&path).unwrap();
+
+        let verified = match Command::new("ldns-verify-zone").arg(&path).status() {
+            Ok(status) => status.success(),
+            Err(_) => Command::new("dnssec-verify").arg("-o").arg("example.com").arg(&path).status()
+                .expect("neither ldns-verify-zone nor dnssec-verify found").success(),
+        };
+        fs::remove_file(&path).unwrap();
+        assert!(verified, "nsec3: {}", nsec3);
+    }
+}
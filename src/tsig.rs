@@ -0,0 +1,675 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use {Result, Error};
+use dns::{self, RCode, RType, RName, RData, Class};
+use dns::message::Resource;
+use dns::rname::Compressor;
+use dns::base64;
+use digest::{self, Sha256, Sha512};
+
+/// Seconds the clocks of signer and verifier may differ by (RFC 8945 §10).
+pub const FUDGE: u16 = 300;
+
+/// Unsigned messages a response stream may have between two signed ones
+/// (RFC 8945 §5.3.1).
+pub const MAX_UNSIGNED: usize = 99;
+
+/// MAC algorithms keys can use.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Algorithm {
+    HmacSha256,
+    HmacSha512,
+}
+
+impl Algorithm {
+
+    /// The name of the algorithm in TSIG records (RFC 8945 §6).
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Algorithm::HmacSha256 => "hmac-sha256",
+            Algorithm::HmacSha512 => "hmac-sha512",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Algorithm> {
+        match &name.trim_right_matches('.').to_lowercase()[..] {
+            "hmac-sha256" => Some(Algorithm::HmacSha256),
+            "hmac-sha512" => Some(Algorithm::HmacSha512),
+            _ => None,
+        }
+    }
+
+    /// Length of an untruncated MAC.
+    pub fn len(&self) -> usize {
+        match *self {
+            Algorithm::HmacSha256 => 32,
+            Algorithm::HmacSha512 => 64,
+        }
+    }
+
+    fn mac(&self, secret: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+        match *self {
+            Algorithm::HmacSha256 => digest::hmac::<Sha256>(secret, parts),
+            Algorithm::HmacSha512 => digest::hmac::<Sha512>(secret, parts),
+        }
+    }
+}
+
+/// A shared secret messages are signed with, known to both ends by name.
+#[derive(Clone, Debug)]
+pub struct Key {
+    pub name: RName,
+    pub algorithm: Algorithm,
+    pub secret: Vec<u8>,
+}
+
+impl Key {
+
+    /// A key as given to other servers and `nsupdate -y`: its name, the
+    /// name of its algorithm and its secret in base64.
+    pub fn parse(name: &str, algorithm: &str, secret: &str) -> Result<Key> {
+        let algorithm = match Algorithm::from_name(algorithm) {
+            Some(algorithm) => algorithm,
+            None => return Err(Error::BadKey),
+        };
+        let secret = try!(base64::decode(secret));
+        if secret.is_empty() {
+            return Err(Error::BadKey)
+        }
+        Ok(Key{ name: try!(name.parse()), algorithm: algorithm, secret: secret })
+    }
+}
+
+/// The fields of a TSIG record (RFC 8945 §4.2).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tsig {
+    pub key: RName,
+    pub algorithm: RName,
+    /// Seconds since the epoch, 48 bits on the wire.
+    pub time: u64,
+    pub fudge: u16,
+    pub mac: Vec<u8>,
+    pub original_id: u16,
+    pub error: u16,
+    pub other: Vec<u8>,
+}
+
+impl Tsig {
+
+    pub fn from_resource(rr: &Resource) -> Option<Tsig> {
+        let data = match rr.data {
+            RData::RawData(ref data) if rr.rtype == RType::TSIG => data,
+            _ => return None,
+        };
+        let (algorithm, off) = match RName::unpack(data, 0) {
+            Ok(r) => r,
+            Err(_) => return None,
+        };
+        if off + 10 > data.len() {
+            return None
+        }
+        let time = (get_u16(data, off) as u64) << 32 | get_u32(data, off + 2) as u64;
+        let fudge = get_u16(data, off + 6);
+        let mac_end = off + 10 + get_u16(data, off + 8) as usize;
+        if mac_end + 6 > data.len() {
+            return None
+        }
+        let other_end = mac_end + 6 + get_u16(data, mac_end + 4) as usize;
+        if other_end != data.len() {
+            return None
+        }
+        Some(Tsig{
+            key: rr.name.clone(),
+            algorithm: algorithm,
+            time: time,
+            fudge: fudge,
+            mac: data[off + 10..mac_end].to_vec(),
+            original_id: get_u16(data, mac_end),
+            error: get_u16(data, mac_end + 2),
+            other: data[mac_end + 6..].to_vec(),
+        })
+    }
+
+    pub fn to_resource(&self) -> Resource {
+        let mut data = wire(&self.algorithm);
+        put_u16(&mut data, (self.time >> 32) as u16);
+        put_u32(&mut data, self.time as u32);
+        put_u16(&mut data, self.fudge);
+        put_u16(&mut data, self.mac.len() as u16);
+        data.extend(&self.mac);
+        put_u16(&mut data, self.original_id);
+        put_u16(&mut data, self.error);
+        put_u16(&mut data, self.other.len() as u16);
+        data.extend(&self.other);
+        Resource{ name: self.key.clone(), rtype: RType::TSIG, class: Class::ANY, ttl: 0, data: RData::RawData(data) }
+    }
+
+    // What is signed next to the message: all fields but the MAC and the
+    // original id with the names in canonical form, or only the timers on
+    // later messages of a stream (RFC 8945 §4.3.3, §5.3.1).
+    fn variables(&self, timers_only: bool) -> Vec<u8> {
+        let mut vars = vec![];
+        if !timers_only {
+            vars.extend(wire(&self.key).to_ascii_lowercase());
+            put_u16(&mut vars, Class::ANY as u16);
+            put_u32(&mut vars, 0);
+            vars.extend(wire(&self.algorithm).to_ascii_lowercase());
+        }
+        put_u16(&mut vars, (self.time >> 32) as u16);
+        put_u32(&mut vars, self.time as u32);
+        put_u16(&mut vars, self.fudge);
+        if !timers_only {
+            put_u16(&mut vars, self.error);
+            put_u16(&mut vars, self.other.len() as u16);
+            vars.extend(&self.other);
+        }
+        vars
+    }
+}
+
+/// What a request carries in terms of TSIG.
+pub enum Verified {
+    Unsigned,
+    /// Signed with a known key, with the signer for the responses and the
+    /// request without its TSIG record.
+    Valid(Signer, Vec<u8>),
+    /// Signed with an unknown key, a wrong MAC or out of time; answered
+    /// with NOTAUTH and the TSIG error through the signer (RFC 8945 §5.2).
+    Invalid(Signer),
+    /// A TSIG record that can not be read.
+    Malformed,
+}
+
+/// Verifies the TSIG record of the request `raw` against `keys`.
+pub fn verify(keys: &[Key], raw: &[u8], now: u64) -> Verified {
+    let (offset, tsig) = match split(raw) {
+        Split::Unsigned => return Verified::Unsigned,
+        Split::Signed(offset, tsig) => (offset, tsig),
+        Split::Malformed => return Verified::Malformed,
+    };
+    let mut signer = Signer{
+        key: None,
+        key_name: tsig.key.clone(),
+        algorithm: tsig.algorithm.clone(),
+        prior: vec![],
+        count: 0,
+        unsigned: vec![],
+        unsigned_count: 0,
+        error: 0,
+    };
+
+    let key = keys.iter().find(|k| {
+        k.name == tsig.key &&
+            Algorithm::from_name(&tsig.algorithm.to_string()) == Some(k.algorithm)
+    });
+    let key = match key {
+        Some(key) => key,
+        None => {
+            signer.error = RCode::BADKEY as u16;
+            return Verified::Invalid(signer)
+        }
+    };
+    if !truncation_allowed(key.algorithm, tsig.mac.len()) {
+        return Verified::Malformed
+    }
+    let unsigned = unsigned(raw, offset, tsig.original_id);
+    let mac = key.algorithm.mac(&key.secret, &[&unsigned, &tsig.variables(false)]);
+    if !digest::verify(&mac[..tsig.mac.len()], &tsig.mac) {
+        signer.error = RCode::BADSIG as u16;
+        return Verified::Invalid(signer)
+    }
+
+    // signed from here on, chained to the request
+    signer.key = Some(key.clone());
+    signer.prior = tsig.mac.clone();
+    if !in_time(&tsig, now) {
+        signer.error = RCode::BADTIME as u16;
+        return Verified::Invalid(signer)
+    }
+    Verified::Valid(signer, unsigned)
+}
+
+/// Signs the messages of one exchange in turn, each chained to the MAC of
+/// the one before: a request and the responses to it, or the responses to
+/// a signed request. Also verifies the responses to a signed request.
+pub struct Signer {
+    // unsigned error responses have no key
+    key: Option<Key>,
+    key_name: RName,
+    algorithm: RName,
+    // the MAC the next message is chained to
+    prior: Vec<u8>,
+    // messages signed or verified so far
+    count: usize,
+    // unsigned responses since the last signed one, digested with the next
+    unsigned: Vec<u8>,
+    unsigned_count: usize,
+    error: u16,
+}
+
+impl Signer {
+
+    /// A signer for a request to be sent with `key`.
+    pub fn new(key: Key) -> Signer {
+        Signer{
+            key_name: key.name.clone(),
+            algorithm: key.algorithm.name().parse().unwrap(),
+            key: Some(key),
+            prior: vec![],
+            count: 0,
+            unsigned: vec![],
+            unsigned_count: 0,
+            error: 0,
+        }
+    }
+
+    /// The name of the key messages are signed with; `None` for a request
+    /// that failed verification.
+    pub fn key(&self) -> Option<&RName> {
+        match self.key {
+            Some(ref key) if self.error == 0 => Some(&key.name),
+            _ => None,
+        }
+    }
+
+    /// The TSIG error a rejected request is answered with.
+    pub fn error(&self) -> Option<RCode> {
+        match self.error {
+            0 => None,
+            e => RCode::unpack(e as u8).ok(),
+        }
+    }
+
+    /// Bytes the TSIG record adds to a message.
+    pub fn len(&self) -> usize {
+        let mac = self.key.as_ref().map_or(0, |k| k.algorithm.len());
+        let other = if self.error == RCode::BADTIME as u16 { 6 } else { 0 };
+        self.key_name.len() + 1 + 10 + self.algorithm.len() + 1 + 16 + mac + other
+    }
+
+    /// Appends a TSIG record to the packed message `msg`. The first
+    /// message covers all TSIG fields, later ones only the timers.
+    pub fn sign(&mut self, msg: &mut Vec<u8>, now: u64) -> Result<()> {
+        if msg.len() < 12 {
+            return Err(Error::Dns(dns::Error::ShortRead))
+        }
+        let mut tsig = Tsig{
+            key: self.key_name.clone(),
+            algorithm: self.algorithm.clone(),
+            time: now,
+            fudge: FUDGE,
+            mac: vec![],
+            original_id: get_u16(msg, 0),
+            error: self.error,
+            other: vec![],
+        };
+        if self.error == RCode::BADTIME as u16 {
+            // the time of the server, for the client to see how far off it is
+            put_u16(&mut tsig.other, (now >> 32) as u16);
+            put_u32(&mut tsig.other, now as u32);
+        }
+        if let Some(ref key) = self.key {
+            let prior = self.chained();
+            tsig.mac = key.algorithm.mac(&key.secret, &[&prior, msg, &tsig.variables(self.count > 0)]);
+            self.prior = tsig.mac.clone();
+        }
+        self.count += 1;
+
+        let rr = tsig.to_resource();
+        let start = msg.len();
+        msg.resize(start + rr.len(), 0);
+        let end = try!(rr.pack(msg, start, &mut Compressor::none()));
+        msg.truncate(end);
+        let arcount = get_u16(msg, 10) + 1;
+        put_at(msg, 10, &[(arcount >> 8) as u8, arcount as u8]);
+        Ok(())
+    }
+
+    /// Verifies the next response to a request signed by this signer. An
+    /// error is the TSIG error the response carries or the one it fails on.
+    /// After the first response, up to `MAX_UNSIGNED` in a row may come
+    /// unsigned; they are covered by the next signed one, and `unverified`
+    /// tells whether the stream ended on such a message.
+    pub fn verify(&mut self, raw: &[u8], now: u64) -> ::std::result::Result<(), RCode> {
+        let key = match self.key {
+            Some(ref key) => key,
+            None => return Err(RCode::BADKEY),
+        };
+        let (offset, tsig) = match split(raw) {
+            Split::Signed(offset, tsig) => (offset, tsig),
+            // the request counts, so two are the request and a first response
+            Split::Unsigned if self.count >= 2 && self.unsigned_count < MAX_UNSIGNED => {
+                self.unsigned.extend(raw);
+                self.unsigned_count += 1;
+                return Ok(())
+            }
+            _ => return Err(RCode::FORMERR),
+        };
+        if tsig.key != key.name ||
+            Algorithm::from_name(&tsig.algorithm.to_string()) != Some(key.algorithm) {
+            return Err(RCode::BADKEY)
+        }
+        if tsig.error != 0 {
+            return Err(RCode::unpack(tsig.error as u8).unwrap_or(RCode::BADSIG))
+        }
+        if !truncation_allowed(key.algorithm, tsig.mac.len()) {
+            return Err(RCode::FORMERR)
+        }
+        let unsigned = unsigned(raw, offset, tsig.original_id);
+        let mac = key.algorithm.mac(&key.secret, &[&self.chained(), &self.unsigned, &unsigned,
+                                                    &tsig.variables(self.count > 1)]);
+        if !digest::verify(&mac[..tsig.mac.len()], &tsig.mac) {
+            return Err(RCode::BADSIG)
+        }
+        if !in_time(&tsig, now) {
+            return Err(RCode::BADTIME)
+        }
+        self.prior = tsig.mac;
+        self.count += 1;
+        self.unsigned.clear();
+        self.unsigned_count = 0;
+        Ok(())
+    }
+
+    /// Whether responses were accepted unsigned since the last signed one.
+    /// A stream has to end on a signed message.
+    pub fn unverified(&self) -> bool {
+        self.unsigned_count > 0
+    }
+
+    // the prior MAC with its length, if there is one
+    fn chained(&self) -> Vec<u8> {
+        let mut prior = vec![];
+        if !self.prior.is_empty() {
+            put_u16(&mut prior, self.prior.len() as u16);
+            prior.extend(&self.prior);
+        }
+        prior
+    }
+}
+
+/// Seconds since the epoch, the time TSIG records are signed at.
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+enum Split {
+    Unsigned,
+    Signed(usize, Tsig),
+    Malformed,
+}
+
+// Finds the TSIG record, which has to be the last one of the message, and
+// where it starts. One anywhere else makes the message malformed (RFC 8945 §5.1).
+fn split(raw: &[u8]) -> Split {
+    if raw.len() < 12 {
+        return Split::Malformed
+    }
+    let questions = get_u16(raw, 4) as usize;
+    let records = get_u16(raw, 6) as usize + get_u16(raw, 8) as usize + get_u16(raw, 10) as usize;
+    if get_u16(raw, 10) == 0 {
+        return Split::Unsigned
+    }
+    let mut offset = 12;
+    for _ in 0..questions {
+        match RName::unpack(raw, offset) {
+            Ok((_, next)) => offset = next + 4,
+            Err(_) => return Split::Malformed,
+        }
+    }
+    let mut last = None;
+    for i in 0..records {
+        match Resource::unpack(raw, offset) {
+            Ok((ref rr, _)) if rr.rtype == RType::TSIG && i + 1 < records => return Split::Malformed,
+            Ok((rr, next)) => {
+                last = Some((offset, rr));
+                offset = next;
+            }
+            Err(_) => return Split::Malformed,
+        }
+    }
+    match last {
+        Some((start, ref rr)) if rr.rtype == RType::TSIG => match Tsig::from_resource(rr) {
+            Some(tsig) => Split::Signed(start, tsig),
+            None => Split::Malformed,
+        },
+        _ => Split::Unsigned,
+    }
+}
+
+// The message as it was before the TSIG record at `offset` was added.
+fn unsigned(raw: &[u8], offset: usize, original_id: u16) -> Vec<u8> {
+    let mut msg = raw[..offset].to_vec();
+    let arcount = get_u16(&msg, 10) - 1;
+    put_at(&mut msg, 0, &[(original_id >> 8) as u8, original_id as u8]);
+    put_at(&mut msg, 10, &[(arcount >> 8) as u8, arcount as u8]);
+    msg
+}
+
+// MACs may be cut to half their length, but not below 10 bytes (RFC 8945 §5.2.2.1).
+fn truncation_allowed(algorithm: Algorithm, len: usize) -> bool {
+    len <= algorithm.len() && len >= 10 && len >= algorithm.len() / 2
+}
+
+fn in_time(tsig: &Tsig, now: u64) -> bool {
+    let diff = if now > tsig.time { now - tsig.time } else { tsig.time - now };
+    diff <= tsig.fudge as u64
+}
+
+fn wire(name: &RName) -> Vec<u8> {
+    let mut buf = vec![0; name.len() + 1];
+    name.pack(&mut buf, 0).unwrap();
+    buf
+}
+
+fn get_u16(buf: &[u8], offset: usize) -> u16 {
+    (buf[offset] as u16) << 8 | buf[offset + 1] as u16
+}
+
+fn get_u32(buf: &[u8], offset: usize) -> u32 {
+    (get_u16(buf, offset) as u32) << 16 | get_u16(buf, offset + 2) as u32
+}
+
+fn put_u16(buf: &mut Vec<u8>, v: u16) {
+    buf.push((v >> 8) as u8);
+    buf.push(v as u8);
+}
+
+fn put_u32(buf: &mut Vec<u8>, v: u32) {
+    put_u16(buf, (v >> 16) as u16);
+    put_u16(buf, v as u16);
+}
+
+fn put_at(buf: &mut [u8], offset: usize, data: &[u8]) {
+    buf[offset..offset + data.len()].copy_from_slice(data);
+}
+
+
+#[cfg(test)] use rustc_serialize::hex::FromHex;
+
+// Packets signed with HMAC-SHA256 and HMAC-SHA512 by dnspython 1.16.0, at
+// 1700000000 with key "test-key" (spelled "Test-Key" in the SHA512 one),
+// as printed by tests/tsig_fixtures.py. The ignored `sign_like_bind`
+// integration test checks against BIND's tools.
+#[cfg(test)] const SECRET: &'static str = "c2VjcmV0LWtleS1mb3ItdHNpZy10ZXN0cw==";
+#[cfg(test)] const REQUEST_SHA256: &'static str = "123400000001000000000001076578616d706c6503636f6d000006000108746573742d6b65790000fa00ff00000000003d0b686d61632d7368613235360000006553f100012c002022571358dbe0427eabd74ef809be5a68acd555d5277a9c4cef890b47aaa43c73123400000000";
+#[cfg(test)] const REQUEST_SHA512: &'static str = "123400000001000000000001076578616d706c6503636f6d000006000108546573742d4b65790000fa00ff00000000005d0b686d61632d7368613531320000006553f100012c004058103651019ab1d9ae0714fa1be816c1aa66fae6d3da8bc3aed66d808d210fed6f719afa21b4025644fac38dbc1d66f2672c859a393e1c8a584f26a30ee08962123400000000";
+// Two responses to REQUEST_SHA256 signed as a stream, a second later each.
+// The second may instead come unsigned, then a third covers both.
+#[cfg(test)] const RESPONSE_1: &'static str = "123484000001000100000001076578616d706c6503636f6d0000060001c00c000100010000003c0004c000020108746573742d6b65790000fa00ff00000000003d0b686d61632d7368613235360000006553f101012c0020bab2810d0c9432a718d4a1e5f2ace3ca0e4dd85e9175d782d73ae87ae7c79c79123400000000";
+#[cfg(test)] const RESPONSE_2: &'static str = "123484000000000100000001076578616d706c6503636f6d00000100010000003c0004c000020208746573742d6b65790000fa00ff00000000003d0b686d61632d7368613235360000006553f102012c0020cb49e6806ef3a4e64d68ee3e8868b697c5949e99c27abf33f07b90d51487201c123400000000";
+#[cfg(test)] const UNSIGNED_2: &'static str = "123484000000000100000000076578616d706c6503636f6d00000100010000003c0004c0000202";
+#[cfg(test)] const RESPONSE_3: &'static str = "123484000000000100000001076578616d706c6503636f6d00000100010000003c0004c000020308746573742d6b65790000fa00ff00000000003d0b686d61632d7368613235360000006553f103012c00203dac18f847bbd7514d9e9d00fbb9dd7b5ac506c3d6eed800b15054d6362125d5123400000000";
+
+#[cfg(test)]
+fn keys() -> Vec<Key> {
+    vec![Key::parse("test-key", "hmac-sha256", SECRET).unwrap(),
+         Key::parse("test-key", "hmac-sha512.", SECRET).unwrap()]
+}
+
+#[test]
+fn verify_requests() {
+    let keys = keys();
+    let now = 1700000000;
+    for packet in [REQUEST_SHA256, REQUEST_SHA512].iter() {
+        let raw = packet.from_hex().unwrap();
+        match verify(&keys, &raw, now + 10) {
+            Verified::Valid(_, unsigned) => {
+                assert_eq!(&unsigned[..10], &raw[..10]);
+                assert_eq!(&unsigned[10..12], &[0, 0]);
+                assert_eq!(&unsigned[12..], &raw[12..29]);
+            }
+            _ => panic!("expected a valid signature"),
+        }
+    }
+
+    let raw = REQUEST_SHA256.from_hex().unwrap();
+    let error = |keys: &[Key], raw: &[u8], now| match verify(keys, raw, now) {
+        Verified::Invalid(signer) => signer.error(),
+        _ => None,
+    };
+    assert_eq!(error(&keys, &raw, now + FUDGE as u64 + 1), Some(RCode::BADTIME));
+    assert_eq!(error(&keys, &raw, now - FUDGE as u64 - 1), Some(RCode::BADTIME));
+    assert_eq!(error(&keys[1..], &raw, now), Some(RCode::BADKEY));
+    let mut other = keys.clone();
+    other[0].secret[0] ^= 1;
+    assert_eq!(error(&other, &raw, now), Some(RCode::BADSIG));
+    let mut tampered = raw.clone();
+    tampered[2] = 0x01;
+    assert_eq!(error(&keys, &tampered, now), Some(RCode::BADSIG));
+
+    // unsigned and broken requests
+    let mut cut = raw[..29].to_vec();
+    cut[11] = 0;
+    assert!(match verify(&keys, &cut, now) { Verified::Unsigned => true, _ => false });
+    cut = raw[..raw.len() - 2].to_vec();
+    assert!(match verify(&keys, &cut, now) { Verified::Malformed => true, _ => false });
+    // a MAC size running past the record
+    cut = raw.clone();
+    let mac_size = 29 + 10 + 10 + 13 + 8;
+    cut[mac_size + 1] = 0x40;
+    assert!(match verify(&keys, &cut, now) { Verified::Malformed => true, _ => false });
+    // a TSIG record followed by another one
+    let mut trailing = raw.clone();
+    trailing[11] = 2;
+    trailing.extend(&[0, 0, 1, 0, 1, 0, 0, 0, 60, 0, 4, 192, 0, 2, 1]);
+    assert!(match verify(&keys, &trailing, now) { Verified::Malformed => true, _ => false });
+}
+
+#[test]
+fn sign_like_others() {
+    let keys = keys();
+    let mut query = REQUEST_SHA256.from_hex().unwrap()[..29].to_vec();
+    query[11] = 0;
+
+    // the same request as signed elsewhere, and the responses to it
+    let mut signer = Signer::new(keys[0].clone());
+    let mut req = query.clone();
+    signer.sign(&mut req, 1700000000).unwrap();
+    assert_eq!(req, REQUEST_SHA256.from_hex().unwrap());
+    assert_eq!(signer.verify(&RESPONSE_1.from_hex().unwrap(), 1700000001), Ok(()));
+    assert_eq!(signer.verify(&RESPONSE_2.from_hex().unwrap(), 1700000002), Ok(()));
+
+    // out of order
+    let mut signer = Signer::new(keys[0].clone());
+    signer.sign(&mut query.clone(), 1700000000).unwrap();
+    assert_eq!(signer.verify(&RESPONSE_2.from_hex().unwrap(), 1700000002), Err(RCode::BADSIG));
+
+    // signing a response stream as the server
+    let mut server = match verify(&keys, &REQUEST_SHA256.from_hex().unwrap(), 1700000000) {
+        Verified::Valid(signer, _) => signer,
+        _ => panic!("expected a valid signature"),
+    };
+    assert_eq!(server.key(), Some(&keys[0].name));
+    for &(packet, now) in [(RESPONSE_1, 1700000001), (RESPONSE_2, 1700000002)].iter() {
+        let mut resp = packet.from_hex().unwrap();
+        let unsigned = resp.len() - server.len();
+        resp.truncate(unsigned);
+        resp[11] = 0;
+        server.sign(&mut resp, now).unwrap();
+        assert_eq!(resp, packet.from_hex().unwrap());
+    }
+}
+
+#[test]
+fn verify_unsigned_intermediate_responses() {
+    let keys = keys();
+    let mut query = REQUEST_SHA256.from_hex().unwrap()[..29].to_vec();
+    query[11] = 0;
+    let stream = |signer: &mut Signer, packets: &[&str]| -> ::std::result::Result<(), RCode> {
+        signer.sign(&mut query.clone(), 1700000000).unwrap();
+        for (i, packet) in packets.iter().enumerate() {
+            try!(signer.verify(&packet.from_hex().unwrap(), 1700000001 + i as u64));
+        }
+        Ok(())
+    };
+
+    // the unsigned message is covered by the one after it
+    let mut signer = Signer::new(keys[0].clone());
+    assert_eq!(stream(&mut signer, &[RESPONSE_1, UNSIGNED_2]), Ok(()));
+    assert!(signer.unverified());
+    assert_eq!(signer.verify(&RESPONSE_3.from_hex().unwrap(), 1700000003), Ok(()));
+    assert!(!signer.unverified());
+
+    // and counts towards its MAC
+    let mut signer = Signer::new(keys[0].clone());
+    assert_eq!(stream(&mut signer, &[RESPONSE_1, RESPONSE_3]), Err(RCode::BADSIG));
+    let mut signer = Signer::new(keys[0].clone());
+    let mut altered = UNSIGNED_2.from_hex().unwrap();
+    let last = altered.len() - 1;
+    altered[last] = 3;
+    stream(&mut signer, &[RESPONSE_1]).unwrap();
+    signer.verify(&altered, 1700000002).unwrap();
+    assert_eq!(signer.verify(&RESPONSE_3.from_hex().unwrap(), 1700000003), Err(RCode::BADSIG));
+
+    // the first response has to be signed
+    let mut signer = Signer::new(keys[0].clone());
+    assert_eq!(stream(&mut signer, &[UNSIGNED_2]), Err(RCode::FORMERR));
+
+    // and no more than 99 in a row may be unsigned
+    let mut signer = Signer::new(keys[0].clone());
+    stream(&mut signer, &[RESPONSE_1]).unwrap();
+    let unsigned = UNSIGNED_2.from_hex().unwrap();
+    for _ in 0..MAX_UNSIGNED {
+        assert_eq!(signer.verify(&unsigned, 1700000002), Ok(()));
+    }
+    assert_eq!(signer.verify(&unsigned, 1700000002), Err(RCode::FORMERR));
+}
+
+#[test]
+fn answer_rejected_requests() {
+    let keys = keys();
+    let raw = REQUEST_SHA256.from_hex().unwrap();
+    let mut resp = raw[..29].to_vec();
+    resp[2] = 0x80;
+    resp[3] = RCode::NOTAUTH as u8;
+    resp[11] = 0;
+
+    // a wrong key gets an unsigned answer
+    let mut signer = match verify(&keys[1..], &raw, 1700000000) {
+        Verified::Invalid(signer) => signer,
+        _ => panic!("expected an invalid signature"),
+    };
+    let mut bad_key = resp.clone();
+    signer.sign(&mut bad_key, 1700000000).unwrap();
+    assert_eq!(bad_key.len(), resp.len() + signer.len());
+    let mut client = Signer::new(keys[0].clone());
+    client.sign(&mut raw[..29].to_vec(), 1700000000).unwrap();
+    assert_eq!(client.verify(&bad_key, 1700000000), Err(RCode::BADKEY));
+
+    // a wrong time a signed one, with the time of the server
+    let mut signer = match verify(&keys, &raw, 1700001000) {
+        Verified::Invalid(signer) => signer,
+        _ => panic!("expected an invalid signature"),
+    };
+    assert_eq!(signer.key(), None);
+    let mut bad_time = resp.clone();
+    signer.sign(&mut bad_time, 1700001000).unwrap();
+    assert_eq!(bad_time.len(), resp.len() + signer.len());
+    let rr = Resource::unpack(&bad_time, 29).unwrap().0;
+    let tsig = Tsig::from_resource(&rr).unwrap();
+    assert_eq!(tsig.error, RCode::BADTIME as u16);
+    assert_eq!(tsig.other, vec![0, 0, 0x65, 0x53, 0xf4, 0xe8]);
+    assert_eq!(tsig.mac.len(), 32);
+    assert_eq!(client.verify(&bad_time, 1700001000), Err(RCode::BADTIME));
+}
@@ -0,0 +1,288 @@
+use std::result;
+use std::collections::HashMap;
+
+use dns::{Message, RCode, RType, RName, RData, Class};
+use dns::message::Resource;
+use zone::{Diff, Zone, serial_lt};
+
+/// Evaluates an UPDATE request against `zone` (RFC 2136 §3). On success
+/// it gives the change to apply, with the SOA serial incremented, or
+/// `None` if the update leaves the zone as it is. Nothing is changed when
+/// a prerequisite or update fails; the error is the RCODE to answer with.
+pub fn update(zone: &Zone, req: &Message) -> result::Result<Option<Diff>, RCode> {
+    let soa = match zone.soa() {
+        Some(soa) => soa.clone(),
+        None => return Err(RCode::SERVFAIL),
+    };
+    try!(prerequisites(zone, &req.answers));
+    try!(prescan(zone, &req.authority));
+
+    // the records of every name updated, as they are after each update
+    let mut names: HashMap<RName, Vec<Resource>> = HashMap::new();
+    for rr in req.authority.iter() {
+        let apex = is_apex(zone, &rr.name);
+        let rrs = names.entry(rr.name.clone()).or_insert_with(|| zone.get(&rr.name).to_vec());
+        match rr.class {
+            Class::ANY if rr.rtype == RType::ALL => {
+                rrs.retain(|r| apex && (r.rtype == RType::SOA || r.rtype == RType::NS));
+            }
+            Class::ANY => {
+                if !apex || (rr.rtype != RType::SOA && rr.rtype != RType::NS) {
+                    rrs.retain(|r| r.rtype != rr.rtype);
+                }
+            }
+            Class::NONE => {
+                let last_ns = apex && rr.rtype == RType::NS &&
+                    rrs.iter().filter(|r| r.rtype == RType::NS).count() <= 1;
+                if rr.rtype != RType::SOA && !last_ns {
+                    rrs.retain(|r| r.rtype != rr.rtype || r.data != rr.data);
+                }
+            }
+            _ => add(rrs, rr, apex),
+        }
+    }
+
+    let mut diff = Diff{ from: soa.clone(), to: soa, deleted: vec![], added: vec![] };
+    for (name, rrs) in names.iter() {
+        let before = zone.get(name);
+        for rr in rrs.iter() {
+            if rr.rtype == RType::SOA {
+                diff.to = rr.clone();
+            } else if !before.contains(rr) {
+                diff.added.push(rr.clone());
+            }
+        }
+        diff.deleted.extend(before.iter().filter(|r| r.rtype != RType::SOA && !rrs.contains(r)).cloned());
+    }
+
+    if diff.deleted.is_empty() && diff.added.is_empty() && diff.to == diff.from {
+        return Ok(None)
+    }
+    if diff.to == diff.from {
+        if let RData::SOA{ ref mut serial, .. } = diff.to.data {
+            *serial = serial.wrapping_add(1);
+        }
+    }
+    Ok(Some(diff))
+}
+
+/// Checks the prerequisite section (RFC 2136 §3.2).
+fn prerequisites(zone: &Zone, prereqs: &[Resource]) -> result::Result<(), RCode> {
+    // RRsets that have to exist with exactly these records
+    let mut rrsets: HashMap<(RName, u16), Vec<&Resource>> = HashMap::new();
+
+    for rr in prereqs.iter() {
+        if rr.ttl != 0 {
+            return Err(RCode::FORMERR)
+        }
+        if !zone.contains(&rr.name) {
+            return Err(RCode::NOTZONE)
+        }
+        let rrs = zone.get(&rr.name);
+        match rr.class {
+            Class::ANY | Class::NONE if rr.data != RData::None => return Err(RCode::FORMERR),
+            Class::ANY if rr.rtype == RType::ALL => {
+                if rrs.is_empty() {
+                    return Err(RCode::NXDOMAIN)
+                }
+            }
+            Class::ANY => {
+                if !rrs.iter().any(|r| r.rtype == rr.rtype) {
+                    return Err(RCode::NXRRSET)
+                }
+            }
+            Class::NONE if rr.rtype == RType::ALL => {
+                if !rrs.is_empty() {
+                    return Err(RCode::YXDOMAIN)
+                }
+            }
+            Class::NONE => {
+                if rrs.iter().any(|r| r.rtype == rr.rtype) {
+                    return Err(RCode::YXRRSET)
+                }
+            }
+            Class::IN => rrsets.entry((rr.name.clone(), rr.rtype as u16)).or_insert_with(Vec::new).push(rr),
+            _ => return Err(RCode::FORMERR),
+        }
+    }
+
+    for (_, expected) in rrsets.iter() {
+        let name = &expected[0].name;
+        let rtype = expected[0].rtype;
+        let rrs: Vec<&Resource> = zone.get(name).iter().filter(|r| r.rtype == rtype).collect();
+        let same = rrs.len() == expected.len() &&
+            rrs.iter().all(|r| expected.iter().any(|e| e.data == r.data)) &&
+            expected.iter().all(|e| rrs.iter().any(|r| e.data == r.data));
+        if !same {
+            return Err(RCode::NXRRSET)
+        }
+    }
+    Ok(())
+}
+
+/// Checks the update section before anything is changed (RFC 2136 §3.4.1).
+fn prescan(zone: &Zone, updates: &[Resource]) -> result::Result<(), RCode> {
+    for rr in updates.iter() {
+        if !zone.contains(&rr.name) {
+            return Err(RCode::NOTZONE)
+        }
+        let valid = match rr.class {
+            Class::IN => !is_meta(rr.rtype) && rr.rtype != RType::ALL,
+            Class::ANY => rr.ttl == 0 && rr.data == RData::None && !is_meta(rr.rtype),
+            Class::NONE => rr.ttl == 0 && !is_meta(rr.rtype) && rr.rtype != RType::ALL,
+            _ => false,
+        };
+        if !valid {
+            return Err(RCode::FORMERR)
+        }
+    }
+    Ok(())
+}
+
+// Adds a record, replacing one with the same data. CNAMEs do not mix with
+// other data and the SOA is only replaced by one with a newer serial.
+fn add(rrs: &mut Vec<Resource>, rr: &Resource, apex: bool) {
+    if rr.rtype == RType::CNAME && rrs.iter().any(|r| r.rtype != RType::CNAME) {
+        return
+    }
+    if rr.rtype != RType::CNAME && rrs.iter().any(|r| r.rtype == RType::CNAME) {
+        return
+    }
+    if rr.rtype == RType::SOA {
+        let newer = match (rrs.iter().find(|r| r.rtype == RType::SOA).map(|r| &r.data), &rr.data) {
+            (Some(&RData::SOA{ serial: old, .. }), &RData::SOA{ serial: new, .. }) => serial_lt(old, new),
+            _ => false,
+        };
+        if !apex || !newer {
+            return
+        }
+        rrs.retain(|r| r.rtype != RType::SOA);
+    } else if rr.rtype == RType::CNAME {
+        rrs.retain(|r| r.rtype != RType::CNAME);
+    } else {
+        rrs.retain(|r| r.rtype != rr.rtype || r.data != rr.data);
+    }
+    rrs.push(rr.clone());
+}
+
+fn is_meta(rtype: RType) -> bool {
+    match rtype {
+        RType::AXFR | RType::IXFR | RType::MAILA | RType::MAILB | RType::OPT | RType::TSIG => true,
+        _ => false,
+    }
+}
+
+fn is_apex(zone: &Zone, name: &RName) -> bool {
+    name == zone.origin()
+}
+
+
+#[cfg(test)] use dns::OpCode;
+#[cfg(test)] use dns::message::MessageBuilder;
+
+#[cfg(test)]
+fn example() -> Zone {
+    Zone::parse("example.com".parse().unwrap(), "@ 60 IN SOA ns hm 10 60 60 60 60\n\
+                                                 @ 60 IN NS ns\n\
+                                                 ns 60 IN A 192.0.2.53\n\
+                                                 www 60 IN A 192.0.2.80\n\
+                                                 www 60 IN A 192.0.2.81\n\
+                                                 alias 60 IN CNAME www").unwrap()
+}
+
+#[cfg(test)]
+fn rr(name: &str, class: Class, rtype: RType, ttl: u32, data: RData) -> Resource {
+    Resource{ name: name.parse().unwrap(), rtype: rtype, class: class, ttl: ttl, data: data }
+}
+
+#[cfg(test)]
+fn request(prereqs: Vec<Resource>, updates: Vec<Resource>) -> Message {
+    let req = MessageBuilder::query("example.com".parse().unwrap(), RType::SOA).id(1).opcode(OpCode::UPDATE).rd(false);
+    let req = prereqs.into_iter().fold(req, |req, rr| req.answer(rr));
+    updates.into_iter().fold(req, |req, rr| req.authority(rr)).build()
+}
+
+#[cfg(test)]
+fn apply(zone: &mut Zone, prereqs: Vec<Resource>, updates: Vec<Resource>) -> result::Result<bool, RCode> {
+    match try!(update(zone, &request(prereqs, updates))) {
+        Some(diff) => { zone.apply(diff).unwrap(); Ok(true) }
+        None => Ok(false),
+    }
+}
+
+#[test]
+fn check_prerequisites() {
+    use dns::RCode::*;
+    let mut zone = example();
+    let www = |class, rtype| rr("www.example.com", class, rtype, 0, RData::None);
+    let nope = |class, rtype| rr("nope.example.com", class, rtype, 0, RData::None);
+
+    for &(ref prereq, rcode) in [(www(Class::ANY, RType::ALL), None),
+                                 (nope(Class::ANY, RType::ALL), Some(NXDOMAIN)),
+                                 (www(Class::ANY, RType::A), None),
+                                 (www(Class::ANY, RType::AAAA), Some(NXRRSET)),
+                                 (nope(Class::NONE, RType::ALL), None),
+                                 (www(Class::NONE, RType::ALL), Some(YXDOMAIN)),
+                                 (www(Class::NONE, RType::AAAA), None),
+                                 (www(Class::NONE, RType::A), Some(YXRRSET)),
+                                 (rr("www.example.net", Class::ANY, RType::ALL, 0, RData::None), Some(NOTZONE)),
+                                 (rr("www.example.com", Class::ANY, RType::A, 60, RData::None), Some(FORMERR))].iter() {
+        assert_eq!(apply(&mut zone, vec![prereq.clone()], vec![]).err(), rcode);
+    }
+
+    // value dependent RRsets have to match exactly
+    let a = |o| rr("www.example.com", Class::IN, RType::A, 0, RData::A(192, 0, 2, o));
+    assert_eq!(apply(&mut zone, vec![a(81), a(80)], vec![]), Ok(false));
+    assert_eq!(apply(&mut zone, vec![a(80)], vec![]), Err(NXRRSET));
+    assert_eq!(apply(&mut zone, vec![a(80), a(81), a(82)], vec![]), Err(NXRRSET));
+    assert_eq!(zone.serial(), 10);
+}
+
+#[test]
+fn apply_updates() {
+    let mut zone = example();
+    let a = |name: &str, o| rr(name, Class::IN, RType::A, 60, RData::A(192, 0, 2, o));
+
+    // add and delete single records
+    let updates = vec![a("new.example.com", 1),
+                       rr("www.example.com", Class::NONE, RType::A, 0, RData::A(192, 0, 2, 80))];
+    assert_eq!(apply(&mut zone, vec![], updates), Ok(true));
+    assert_eq!(zone.serial(), 11);
+    assert_eq!(zone.get(&"new.example.com".parse().unwrap()), &[a("new.example.com", 1)]);
+    assert_eq!(zone.get(&"www.example.com".parse().unwrap()), &[a("www.example.com", 81)]);
+    assert_eq!(zone.changes_since(10).map(|c| c.len()), Some(1));
+
+    // adding what is there already changes nothing
+    assert_eq!(apply(&mut zone, vec![], vec![a("new.example.com", 1)]), Ok(false));
+    assert_eq!(zone.serial(), 11);
+
+    // delete an RRset and a whole name
+    let updates = vec![rr("www.example.com", Class::ANY, RType::A, 0, RData::None),
+                       rr("new.example.com", Class::ANY, RType::ALL, 0, RData::None)];
+    assert_eq!(apply(&mut zone, vec![], updates), Ok(true));
+    assert!(zone.get(&"www.example.com".parse().unwrap()).is_empty());
+    assert!(zone.get(&"new.example.com".parse().unwrap()).is_empty());
+
+    // no other data next to a CNAME
+    assert_eq!(apply(&mut zone, vec![], vec![a("alias.example.com", 1)]), Ok(false));
+
+    // the SOA and the last NS stay, and the update fails as a whole
+    let updates = vec![rr("example.com", Class::ANY, RType::ALL, 0, RData::None),
+                       rr("example.com", Class::NONE, RType::NS, 0, RData::NS("ns.example.com".parse().unwrap()))];
+    assert_eq!(apply(&mut zone, vec![], updates), Ok(false));
+    assert_eq!(zone.len(), 4);
+
+    let updates = vec![a("ok.example.com", 1), a("www.example.net", 1)];
+    assert_eq!(apply(&mut zone, vec![], updates), Err(RCode::NOTZONE));
+    assert!(zone.get(&"ok.example.com".parse().unwrap()).is_empty());
+    let updates = vec![rr("ok.example.com", Class::IN, RType::ALL, 60, RData::None)];
+    assert_eq!(apply(&mut zone, vec![], updates), Err(RCode::FORMERR));
+
+    // a newer SOA is taken as it is
+    let mut soa = zone.soa().unwrap().clone();
+    if let RData::SOA{ ref mut serial, .. } = soa.data {
+        *serial = 100;
+    }
+    assert_eq!(apply(&mut zone, vec![], vec![soa]), Ok(true));
+    assert_eq!(zone.serial(), 100);
+}
@@ -0,0 +1,1250 @@
+use std::cmp::{self, Ordering};
+use std::result;
+use std::str::FromStr;
+use std::collections::HashMap;
+
+use {Result, Error};
+use dns::{Message, RCode, RType, RName, RData};
+use dns::message::{Edns, MessageBuilder, Question, Resource};
+use dns::master::Reader;
+use dns::base32;
+use dns::dnssec::{Dnskey, Ds, Rrsig, Nsec, Nsec3, ZONE_KEY, PROTOCOL};
+use dns::service::{Srv, Naptr};
+use digest::{Hash, Sha1, Sha256, Sha384};
+use signature;
+use zone::serial_lt;
+
+// Bounds in seconds on how long fetched keys and delegations are trusted.
+const MIN_TTL: u32 = 5;
+const MAX_TTL: u32 = 3600;
+// Failed lookups and bogus zones are retried after this many seconds.
+const BOGUS_TTL: u32 = 30;
+
+// Longest chain of CNAMEs followed in an answer.
+const MAX_CHAIN: usize = 16;
+
+// NSEC3 chains hashed more often are treated as unsigned (RFC 9276 §3.2).
+const MAX_ITERATIONS: u16 = 150;
+
+// Signature checks one validation may make. Responses needing more pile up
+// signatures or keys with colliding tags to keep resolvers busy (KeyTrap,
+// CVE-2023-50387).
+const MAX_VERIFICATIONS: usize = 16;
+
+/// The outcome of validating a response (RFC 4035 §4.3).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Security {
+    /// Every record chains up to a trust anchor.
+    Secure,
+    /// Some records are in zones proven to be unsigned, or under no
+    /// trust anchor at all.
+    Insecure,
+    /// Signatures or proofs are missing or wrong, for the given reason.
+    Bogus(&'static str),
+}
+
+/// A lookup validation has to wait for: the DNSKEY or DS records of a
+/// zone, to be passed to `Validator::learn` once answered.
+pub type Fetch = (RName, RType);
+
+type Step<T> = result::Result<T, Fetch>;
+
+// What is known about the keys of a name.
+#[derive(Clone)]
+enum Keys {
+    /// A zone apex with its zone keys, authenticated from the parent.
+    Secure(Vec<Dnskey>),
+    /// An unsigned delegation, or one with algorithms not supported here.
+    Insecure,
+    Bogus(&'static str),
+    /// Not a zone cut, the keys of the enclosing zone apply.
+    NotCut,
+}
+
+// What the DS lookup for a name showed.
+enum Delegation {
+    /// A signed zone with these DS records.
+    Signed(Vec<RData>),
+    Settled(Keys),
+}
+
+/// Builds chains of trust from configured trust anchors down to the
+/// answers of forwarded queries and checks their signatures and denials
+/// of existence (RFC 4035 §5).
+///
+/// Validation runs without I/O: when keys are missing `validate` returns
+/// the lookup to make, and is run again once its answer was passed to
+/// `learn`. Times are seconds since the epoch.
+pub struct Validator {
+    // owners in lowercase wire form, without the root label
+    anchors: Vec<(Vec<u8>, RData)>,
+    fetched: HashMap<(Vec<u8>, u16), (Message, u64)>,
+    zones: HashMap<Vec<u8>, (Keys, u64)>,
+    // signature checks left to the running validation
+    verifications: usize,
+}
+
+impl Validator {
+
+    /// Takes the trust anchors, DS or DNSKEY records of the zones to
+    /// start chains of trust at.
+    pub fn new(anchors: Vec<Resource>) -> Validator {
+        Validator{
+            anchors: anchors.into_iter().map(|rr| (wire(&rr.name), rr.data)).collect(),
+            fetched: HashMap::new(),
+            zones: HashMap::new(),
+            verifications: 0,
+        }
+    }
+
+    /// Validates the answer, NODATA or NXDOMAIN response to a query sent
+    /// with `query`. Other responses, and truncated ones, cannot be
+    /// checked and are insecure.
+    pub fn validate(&mut self, resp: &Message, now: u64) -> Step<Security> {
+        self.verifications = MAX_VERIFICATIONS;
+        let q = match resp.questions.first() {
+            Some(q) => q,
+            None => return Ok(Security::Insecure),
+        };
+        let nxdomain = resp.rcode == RCode::NXDOMAIN;
+        if resp.tc || (!nxdomain && resp.rcode != RCode::NOERROR) {
+            return Ok(Security::Insecure)
+        }
+        let answers = rrsets(&resp.answers);
+        let authority = rrsets(&resp.authority);
+
+        let mut security = Security::Secure;
+        for set in answers.iter() {
+            if set.rtype == RType::CNAME && set.sigs.is_empty() && synthesized(set, &answers) {
+                continue
+            }
+            let checked = try!(self.check(set, now));
+            // an expanded wildcard needs proof the name itself does not exist
+            let labels = set.sigs.iter().map(|s| s.labels as usize).min().unwrap_or(0);
+            if checked == Security::Secure && labels < label_count(&set.name) {
+                security = worst(security, expansion(&set.name, labels, &authority));
+            }
+            security = worst(security, checked);
+        }
+        let mut denial = Security::Secure;
+        for set in authority.iter() {
+            if set.rtype == RType::SOA || set.rtype == RType::NSEC || set.rtype == RType::NSEC3 {
+                denial = worst(denial, try!(self.check(set, now)));
+            }
+        }
+        security = worst(security, denial.clone());
+
+        // follow the CNAMEs to the name the answer is about
+        let mut target = wire(&q.name);
+        for _ in 0..MAX_CHAIN {
+            if answers.iter().any(|s| s.name == target && (s.rtype == q.rtype || q.rtype == RType::ALL)) {
+                return Ok(security)
+            }
+            let next = answers.iter()
+                .find(|s| s.name == target && s.rtype == RType::CNAME)
+                .and_then(|s| match s.records[0].data {
+                    RData::CNAME(ref name) => Some(wire(name)),
+                    _ => None,
+                });
+            match next {
+                Some(next) => target = next,
+                None => break,
+            }
+        }
+
+        let proof = if denial == Security::Insecure {
+            Security::Insecure
+        } else {
+            try!(self.deny(&target, q.rtype, nxdomain, &authority, now))
+        };
+        Ok(worst(security, proof))
+    }
+
+    /// Stores the answer to a lookup `validate` asked for.
+    pub fn learn(&mut self, resp: &Message, now: u64) {
+        let q = match resp.questions.first() {
+            Some(q) => q,
+            None => return,
+        };
+        let ttl = match resp.rcode {
+            RCode::NOERROR | RCode::NXDOMAIN => {
+                resp.answers.iter().chain(resp.authority.iter()).map(|r| r.ttl).min().unwrap_or(BOGUS_TTL)
+            }
+            _ => BOGUS_TTL,
+        };
+        let ttl = cmp::max(MIN_TTL, cmp::min(ttl, MAX_TTL));
+        self.fetched.insert((wire(&q.name), q.rtype as u16), (resp.clone(), now + ttl as u64));
+    }
+
+    /// Forgets lookups and keys that expired.
+    pub fn prune(&mut self, now: u64) {
+        self.fetched.retain(|_, &mut (_, expires)| expires > now);
+        self.zones.retain(|_, &mut (_, expires)| expires > now);
+    }
+
+    // Checks the signatures of an RRset against the keys of its signer.
+    fn check(&mut self, set: &RRset, now: u64) -> Step<Security> {
+        let signer = match set.sigs.iter().find(|s| signature::supported(s.algorithm)) {
+            Some(sig) => wire(&sig.signer),
+            None => return Ok(match try!(self.zone_keys(&set.name, now)).1 {
+                Keys::Secure(_) => Security::Bogus("missing signature"),
+                Keys::Bogus(reason) => Security::Bogus(reason),
+                _ => Security::Insecure,
+            }),
+        };
+        if !is_below(&set.name, &signer) {
+            return Ok(Security::Bogus("signer outside the zone"))
+        }
+        let (zone, keys) = try!(self.zone_keys(&signer, now));
+        Ok(match keys {
+            Keys::Secure(keys) => match verify(set, &keys, &zone, now, &mut self.verifications) {
+                Ok(()) => Security::Secure,
+                Err(reason) => Security::Bogus(reason),
+            },
+            Keys::Bogus(reason) => Security::Bogus(reason),
+            _ => Security::Insecure,
+        })
+    }
+
+    // Checks the proof that `name` has no `qtype` records, or does not
+    // exist at all.
+    fn deny(&mut self, name: &[u8], qtype: RType, nxdomain: bool, authority: &[RRset], now: u64) -> Step<Security> {
+        let nsecs = nsecs(authority);
+        if !nsecs.is_empty() {
+            return Ok(if nsec_denies(name, qtype, nxdomain, &nsecs) {
+                Security::Secure
+            } else {
+                Security::Bogus("NSEC does not prove the denial")
+            })
+        }
+        match Chain::new(authority) {
+            Some(ref chain) if !chain.supported => Ok(Security::Insecure),
+            Some(chain) => Ok(if nsec3_denies(name, qtype, nxdomain, &chain) {
+                Security::Secure
+            } else {
+                Security::Bogus("NSEC3 does not prove the denial")
+            }),
+            // without any proof the denial only stands in unsigned zones
+            None => Ok(match try!(self.zone_keys(name, now)).1 {
+                Keys::Secure(_) => Security::Bogus("missing denial of existence"),
+                Keys::Bogus(reason) => Security::Bogus(reason),
+                _ => Security::Insecure,
+            }),
+        }
+    }
+
+    // The apex and keys of the zone `name` is in, walking down from the
+    // closest trust anchor one label at a time.
+    fn zone_keys(&mut self, name: &[u8], now: u64) -> Step<(Vec<u8>, Keys)> {
+        let anchor = match self.anchor(name) {
+            Some(anchor) => anchor,
+            None => return Ok((vec![], Keys::Insecure)),
+        };
+        let mut zone = anchor.clone();
+        let mut keys = try!(self.keys(&anchor, now));
+        for n in label_count(&anchor) + 1..label_count(name) + 1 {
+            if let Keys::Secure(_) = keys {} else {
+                break
+            }
+            let cut = suffix(name, n);
+            match try!(self.keys(cut, now)) {
+                Keys::NotCut => {}
+                found => {
+                    zone = cut.to_vec();
+                    keys = found;
+                }
+            }
+        }
+        Ok((zone, keys))
+    }
+
+    // The closest trust anchor at or above `name`.
+    fn anchor(&self, name: &[u8]) -> Option<Vec<u8>> {
+        self.anchors.iter()
+            .map(|&(ref owner, _)| owner)
+            .filter(|owner| is_below(name, owner))
+            .max_by_key(|owner| owner.len())
+            .cloned()
+    }
+
+    fn keys(&mut self, name: &[u8], now: u64) -> Step<Keys> {
+        if let Some(&(ref keys, expires)) = self.zones.get(name) {
+            if expires > now {
+                return Ok(keys.clone())
+            }
+        }
+        let (keys, expires) = try!(self.establish(name, now));
+        let expires = match keys {
+            Keys::Bogus(_) => cmp::min(expires, now + BOGUS_TTL as u64),
+            _ => expires,
+        };
+        self.zones.insert(name.to_vec(), (keys.clone(), expires));
+        Ok(keys)
+    }
+
+    // Authenticates the keys of `name` through its trust anchors, or the
+    // DS records in its parent zone.
+    fn establish(&mut self, name: &[u8], now: u64) -> Step<(Keys, u64)> {
+        let anchored: Vec<RData> = self.anchors.iter()
+            .filter(|&&(ref owner, _)| owner == name)
+            .map(|&(_, ref data)| data.clone())
+            .collect();
+        if !anchored.is_empty() {
+            let (msg, expires) = try!(self.fetch(name, RType::DNSKEY, now));
+            return Ok((keyset(name, &msg, &anchored, now, &mut self.verifications), expires))
+        }
+
+        let (zone, keys) = try!(self.zone_keys(parent(name), now));
+        let keys = match keys {
+            Keys::Secure(keys) => keys,
+            other => return Ok((other, now + MAX_TTL as u64)),
+        };
+        let (msg, expires) = try!(self.fetch(name, RType::DS, now));
+        match delegation(name, &zone, &keys, &msg, now, &mut self.verifications) {
+            Delegation::Signed(ds) => {
+                let (msg, until) = try!(self.fetch(name, RType::DNSKEY, now));
+                Ok((keyset(name, &msg, &ds, now, &mut self.verifications), cmp::min(expires, until)))
+            }
+            Delegation::Settled(keys) => Ok((keys, expires)),
+        }
+    }
+
+    fn fetch(&self, name: &[u8], rtype: RType, now: u64) -> Step<(Message, u64)> {
+        match self.fetched.get(&(name.to_vec(), rtype as u16)) {
+            Some(&(ref msg, expires)) if expires > now => Ok((msg.clone(), expires)),
+            _ => Err((from_wire(name), rtype)),
+        }
+    }
+}
+
+/// A recursive query for `q` asking for DNSSEC records, with checking
+/// disabled upstream so bogus answers reach the validator.
+pub fn query(id: u16, q: &Question) -> Message {
+    let mut msg = MessageBuilder::query(q.name.clone(), q.rtype)
+        .id(id)
+        .cd(true)
+        .edns(Edns{ dnssec_ok: true, ..Edns::default() })
+        .build();
+    msg.questions[0].class = q.class;
+    msg
+}
+
+/// The response to the client's `req` from a validated upstream response.
+/// Bogus answers become SERVFAIL, and AD is only set on secure answers
+/// for clients that understand it (RFC 6840 §5.8). DNSSEC records the
+/// client did not ask for are left out.
+pub fn answer(req: &Message, resp: &Message, security: &Security) -> Message {
+    if let Security::Bogus(_) = *security {
+        return Message::new_error(req, RCode::SERVFAIL)
+    }
+    let dnssec_ok = req.edns.as_ref().map_or(false, |e| e.dnssec_ok);
+    let qtype = req.questions.first().map(|q| q.rtype);
+    let keep = |rr: &Resource| dnssec_ok || Some(rr.rtype) == qtype || match rr.rtype {
+        RType::RRSIG | RType::NSEC | RType::NSEC3 => false,
+        _ => true,
+    };
+    Message{
+        id: req.id,
+        opcode: req.opcode,
+        rcode: resp.rcode,
+        qr: true,
+        aa: false,
+        tc: resp.tc,
+        rd: req.rd,
+        ra: true,
+        ad: *security == Security::Secure && (dnssec_ok || req.ad),
+        cd: req.cd,
+        questions: req.questions.iter().take(1).cloned().collect(),
+        answers: resp.answers.iter().filter(|rr| keep(*rr)).cloned().collect(),
+        authority: resp.authority.iter().filter(|rr| keep(*rr)).cloned().collect(),
+        additionals: resp.additionals.iter().filter(|rr| keep(*rr)).cloned().collect(),
+        edns: req.edns.as_ref().map(|_| Edns{ dnssec_ok: dnssec_ok, ..Edns::default() }),
+    }
+}
+
+/// The data an RRSIG signs over an RRset, in canonical form (RFC 4034
+/// §3.1.8.1 and §6).
+pub fn signed_data(sig: &Rrsig, rrs: &[&Resource]) -> Vec<u8> {
+    let mut fields = sig.clone();
+    fields.signer = lower(&sig.signer);
+    fields.signature = vec![];
+    let mut out = vec![0; fields.len()];
+    fields.pack_fields(&mut out, 0).unwrap();
+
+    let mut rdata: Vec<(u16, Vec<u8>)> = rrs.iter().map(|rr| {
+        let data = canonical(&rr.data);
+        let mut buf = vec![0; 2 + data.len()];
+        data.pack(&mut buf, 0).unwrap();
+        (rr.class as u16, buf)
+    }).collect();
+    rdata.sort_by(|a, b| a.1[2..].cmp(&b.1[2..]));
+    rdata.dedup_by(|a, b| a.1 == b.1);
+
+    // the owner of an expanded wildcard is the wildcard
+    let name = match rrs.first() {
+        Some(rr) => wire(&rr.name),
+        None => return out,
+    };
+    let mut owner = if (sig.labels as usize) < label_count(&name) {
+        wildcard(suffix(&name, sig.labels as usize))
+    } else {
+        name
+    };
+    owner.push(0);
+    for (class, data) in rdata {
+        out.extend(&owner);
+        out.extend(&[(sig.type_covered >> 8) as u8, sig.type_covered as u8, (class >> 8) as u8, class as u8]);
+        let ttl = sig.original_ttl;
+        out.extend(&[(ttl >> 24) as u8, (ttl >> 16) as u8, (ttl >> 8) as u8, ttl as u8]);
+        out.extend(&data);
+    }
+    out
+}
+
+/// The DS record for `key` of the zone at `owner`, if `digest_type` is
+/// SHA-1, SHA-256 or SHA-384.
+pub fn ds(owner: &RName, key: &Dnskey, digest_type: u8) -> Option<Ds> {
+    digest(&wire(owner), key, digest_type).map(|digest| Ds{
+        key_tag: key.key_tag(),
+        algorithm: key.algorithm,
+        digest_type: digest_type,
+        digest: digest,
+    })
+}
+
+/// The NSEC3 hash of `name` with SHA-1 (RFC 5155 §5).
+pub fn nsec3_hash(name: &RName, salt: &[u8], iterations: u16) -> Vec<u8> {
+    hash(&wire(name), salt, iterations)
+}
+
+/// Reads trust anchors, DS or DNSKEY records in master file format such as
+/// the root zone anchors IANA publishes.
+pub fn anchors(input: &str) -> Result<Vec<Resource>> {
+    let mut anchors = vec![];
+    for entry in Reader::new(input, RName::from_str(".").unwrap()) {
+        let rr = try!(try!(entry).to_resource());
+        match rr.data {
+            RData::DS(_) | RData::DNSKEY(_) => {}
+            _ => return Err(Error::BadAnchor),
+        }
+        anchors.push(rr);
+    }
+    Ok(anchors)
+}
+
+// An RRset of a response section with the signatures covering it.
+struct RRset<'a> {
+    name: Vec<u8>,
+    rtype: RType,
+    records: Vec<&'a Resource>,
+    sigs: Vec<&'a Rrsig>,
+}
+
+fn rrsets<'a>(section: &'a [Resource]) -> Vec<RRset<'a>> {
+    let mut sets: Vec<RRset> = vec![];
+    for rr in section.iter().filter(|rr| rr.rtype != RType::RRSIG) {
+        let name = wire(&rr.name);
+        match sets.iter().position(|s| s.name == name && s.rtype == rr.rtype) {
+            Some(i) => sets[i].records.push(rr),
+            None => sets.push(RRset{ name: name, rtype: rr.rtype, records: vec![rr], sigs: vec![] }),
+        }
+    }
+    for rr in section.iter() {
+        if let RData::RRSIG(ref sig) = rr.data {
+            let name = wire(&rr.name);
+            if let Some(set) = sets.iter_mut().find(|s| s.name == name && s.rtype as u16 == sig.type_covered) {
+                set.sigs.push(sig);
+            }
+        }
+    }
+    sets
+}
+
+// Whether a CNAME was synthesized from a DNAME above it, and so comes
+// without signatures (RFC 6672 §5.3.1).
+fn synthesized(set: &RRset, answers: &[RRset]) -> bool {
+    answers.iter().any(|d| d.rtype == RType::DNAME && d.name != set.name && is_below(&set.name, &d.name))
+}
+
+fn worst(a: Security, b: Security) -> Security {
+    match (a, b) {
+        (Security::Bogus(reason), _) | (_, Security::Bogus(reason)) => Security::Bogus(reason),
+        (Security::Insecure, _) | (_, Security::Insecure) => Security::Insecure,
+        _ => Security::Secure,
+    }
+}
+
+// Checks that some signature of `set` verifies with one of the keys of
+// `zone`, within its validity period, taking each check from `budget`.
+fn verify(set: &RRset, keys: &[Dnskey], zone: &[u8], now: u64, budget: &mut usize) -> result::Result<(), &'static str> {
+    if !is_below(&set.name, zone) {
+        return Err("signer outside the zone")
+    }
+    let mut reason = "no signature by a zone key";
+    for sig in set.sigs.iter() {
+        if wire(&sig.signer) != zone || sig.labels as usize > label_count(&set.name) {
+            continue
+        }
+        let t = now as u32;
+        if serial_lt(t, sig.inception) || serial_lt(sig.expiration, t) {
+            reason = "signature expired or not yet valid";
+            continue
+        }
+        let data = signed_data(sig, &set.records);
+        for key in keys.iter().filter(|k| k.algorithm == sig.algorithm && k.key_tag() == sig.key_tag) {
+            if *budget == 0 {
+                return Err("too many signatures to verify")
+            }
+            *budget -= 1;
+            if signature::verify(sig.algorithm, &key.public_key, &data, &sig.signature) {
+                return Ok(())
+            }
+            reason = "bad signature";
+        }
+    }
+    Err(reason)
+}
+
+// The keys of `name` if its DNSKEY RRset in `msg` is signed by a key
+// matching one of the trusted DS or DNSKEY records.
+fn keyset(name: &[u8], msg: &Message, trusted: &[RData], now: u64, budget: &mut usize) -> Keys {
+    let usable: Vec<&RData> = trusted.iter().filter(|t| match **t {
+        RData::DS(ref ds) => signature::supported(ds.algorithm) && digest_supported(ds.digest_type),
+        RData::DNSKEY(ref key) => signature::supported(key.algorithm),
+        _ => false,
+    }).collect();
+    // a zone signed only with algorithms not supported here is unsigned
+    if usable.is_empty() {
+        return Keys::Insecure
+    }
+    if msg.rcode != RCode::NOERROR {
+        return Keys::Bogus("DNSKEY lookup failed")
+    }
+    let sets = rrsets(&msg.answers);
+    let set = match sets.iter().find(|s| s.name == name && s.rtype == RType::DNSKEY) {
+        Some(set) => set,
+        None => return Keys::Bogus("missing DNSKEY"),
+    };
+    let keys: Vec<Dnskey> = set.records.iter().filter_map(|rr| match rr.data {
+        RData::DNSKEY(ref key) if key.flags & ZONE_KEY != 0 && key.protocol == PROTOCOL => Some(key.clone()),
+        _ => None,
+    }).collect();
+    let entry: Vec<Dnskey> = keys.iter().filter(|key| usable.iter().any(|t| match **t {
+        RData::DS(ref ds) => {
+            ds.key_tag == key.key_tag() && ds.algorithm == key.algorithm &&
+                digest(name, key, ds.digest_type).map_or(false, |d| d == ds.digest)
+        }
+        RData::DNSKEY(ref anchor) => anchor == *key,
+        _ => false,
+    })).cloned().collect();
+    if entry.is_empty() {
+        return Keys::Bogus("no trusted DNSKEY")
+    }
+    match verify(set, &entry, name, now, budget) {
+        Ok(()) => Keys::Secure(keys),
+        Err(reason) => Keys::Bogus(reason),
+    }
+}
+
+// Reads the answer to the DS lookup for `name`, made in the zone above
+// it with the given keys.
+fn delegation(name: &[u8], zone: &[u8], keys: &[Dnskey], msg: &Message, now: u64, budget: &mut usize) -> Delegation {
+    if msg.rcode != RCode::NOERROR && msg.rcode != RCode::NXDOMAIN {
+        return Delegation::Settled(Keys::Bogus("DS lookup failed"))
+    }
+    let answers = rrsets(&msg.answers);
+    if let Some(set) = answers.iter().find(|s| s.name == name && s.rtype == RType::DS) {
+        return match verify(set, keys, zone, now, budget) {
+            Ok(()) => Delegation::Signed(set.records.iter().map(|rr| rr.data.clone()).collect()),
+            Err(reason) => Delegation::Settled(Keys::Bogus(reason)),
+        }
+    }
+    // an alias cannot be a zone cut
+    if answers.iter().any(|s| s.name == name && s.rtype == RType::CNAME) {
+        return Delegation::Settled(Keys::NotCut)
+    }
+
+    let authority = rrsets(&msg.authority);
+    let mut proven = false;
+    for set in authority.iter().filter(|s| s.rtype == RType::NSEC || s.rtype == RType::NSEC3) {
+        if let Err(reason) = verify(set, keys, zone, now, budget) {
+            return Delegation::Settled(Keys::Bogus(reason))
+        }
+        proven = true;
+    }
+    if !proven {
+        return Delegation::Settled(Keys::Bogus("missing DS denial"))
+    }
+    let at_cut = |types: &[u16]| {
+        let has = |t: RType| types.contains(&(t as u16));
+        if has(RType::DS) {
+            Keys::Bogus("DS denial lists DS")
+        } else if has(RType::NS) && !has(RType::SOA) {
+            Keys::Insecure
+        } else {
+            Keys::NotCut
+        }
+    };
+
+    let nsecs = nsecs(&authority);
+    if !nsecs.is_empty() {
+        return Delegation::Settled(match nsecs.iter().find(|n| n.0 == name) {
+            Some(n) => at_cut(&n.2.types),
+            // nothing at the name, so no delegation either
+            None if nsecs.iter().any(|n| covers(n.0, &n.1, name)) => Keys::NotCut,
+            None => Keys::Bogus("NSEC does not prove the DS denial"),
+        })
+    }
+    let chain = match Chain::new(&authority) {
+        Some(chain) => chain,
+        None => return Delegation::Settled(Keys::Bogus("missing DS denial")),
+    };
+    if !chain.supported {
+        return Delegation::Settled(Keys::Insecure)
+    }
+    Delegation::Settled(match chain.matching(name) {
+        Some(n) => at_cut(&n.types),
+        // an opt-out span may hide unsigned delegations (RFC 5155 §8.6)
+        None => match chain.closest_encloser(name) {
+            Some((_, true)) => Keys::Insecure,
+            Some((_, false)) => Keys::NotCut,
+            None => Keys::Bogus("NSEC3 does not prove the DS denial"),
+        },
+    })
+}
+
+// The NSEC records of a section with their owners and next names.
+fn nsecs<'a>(sets: &'a [RRset]) -> Vec<(&'a [u8], Vec<u8>, &'a Nsec)> {
+    let mut out = vec![];
+    for set in sets.iter().filter(|s| s.rtype == RType::NSEC) {
+        for rr in set.records.iter() {
+            if let RData::NSEC(ref nsec) = rr.data {
+                out.push((&set.name[..], wire(&nsec.next), nsec));
+            }
+        }
+    }
+    out
+}
+
+// RFC 4035 §5.4
+fn nsec_denies(name: &[u8], qtype: RType, nxdomain: bool, nsecs: &[(&[u8], Vec<u8>, &Nsec)]) -> bool {
+    let lacks = |nsec: &Nsec| !nsec.types.contains(&(qtype as u16)) && !nsec.types.contains(&(RType::CNAME as u16));
+    if let Some(n) = nsecs.iter().find(|n| n.0 == name) {
+        return !nxdomain && lacks(n.2)
+    }
+    let covering = match nsecs.iter().find(|n| covers(n.0, &n.1, name)) {
+        Some(n) => n,
+        None => return false,
+    };
+    // names below an empty non-terminal follow it in the chain
+    if is_below(&covering.1, name) {
+        return !nxdomain
+    }
+    let ancestors = (common(name, covering.0), common(name, &covering.1));
+    let encloser = if ancestors.0.len() > ancestors.1.len() { ancestors.0 } else { ancestors.1 };
+    let wildcard = wildcard(encloser);
+    if nxdomain {
+        nsecs.iter().any(|n| covers(n.0, &n.1, &wildcard))
+    } else {
+        nsecs.iter().find(|n| n.0 == &wildcard[..]).map_or(false, |n| lacks(n.2))
+    }
+}
+
+// RFC 5155 §8.4 to §8.7
+fn nsec3_denies(name: &[u8], qtype: RType, nxdomain: bool, chain: &Chain) -> bool {
+    let lacks = |nsec3: &Nsec3| !nsec3.types.contains(&(qtype as u16)) && !nsec3.types.contains(&(RType::CNAME as u16));
+    if let Some(n) = chain.matching(name) {
+        return !nxdomain && lacks(n)
+    }
+    let (encloser, opt_out) = match chain.closest_encloser(name) {
+        Some(found) => found,
+        None => return false,
+    };
+    if !nxdomain && qtype == RType::DS && opt_out {
+        return true
+    }
+    let wildcard = wildcard(&encloser);
+    if nxdomain {
+        chain.covering(&wildcard).is_some()
+    } else {
+        chain.matching(&wildcard).map_or(false, |n| lacks(n))
+    }
+}
+
+// Whether the authority section proves no closer name than the wildcard
+// matched an answer expanded from it (RFC 4035 §5.3.4).
+fn expansion(name: &[u8], labels: usize, authority: &[RRset]) -> Security {
+    let nsecs = nsecs(authority);
+    let proven = if !nsecs.is_empty() {
+        nsecs.iter().any(|n| covers(n.0, &n.1, name))
+    } else {
+        match Chain::new(authority) {
+            Some(ref chain) if !chain.supported => return Security::Insecure,
+            Some(chain) => chain.covering(suffix(name, labels + 1)).is_some(),
+            None => false,
+        }
+    };
+    if proven {
+        Security::Secure
+    } else {
+        Security::Bogus("wildcard expansion not proven")
+    }
+}
+
+// The NSEC3 records of a section sharing the parameters of the first.
+struct Chain<'a> {
+    zone: Vec<u8>,
+    salt: Vec<u8>,
+    iterations: u16,
+    // with SHA-1 and not too many iterations
+    supported: bool,
+    hashes: Vec<(Vec<u8>, &'a Nsec3)>,
+}
+
+impl<'a> Chain<'a> {
+
+    fn new(sets: &'a [RRset]) -> Option<Chain<'a>> {
+        let mut chain: Option<Chain> = None;
+        for set in sets.iter().filter(|s| s.rtype == RType::NSEC3 && !s.name.is_empty()) {
+            let label = &set.name[1..1 + set.name[0] as usize];
+            let hash = match base32::decode(&String::from_utf8_lossy(label)) {
+                Ok(hash) => hash,
+                Err(_) => continue,
+            };
+            for rr in set.records.iter() {
+                let nsec3 = match rr.data {
+                    RData::NSEC3(ref nsec3) => nsec3,
+                    _ => continue,
+                };
+                let chain = chain.get_or_insert_with(|| Chain{
+                    zone: parent(&set.name).to_vec(),
+                    salt: nsec3.salt.clone(),
+                    iterations: nsec3.iterations,
+                    supported: nsec3.algorithm == 1 && nsec3.iterations <= MAX_ITERATIONS,
+                    hashes: vec![],
+                });
+                if parent(&set.name) == &chain.zone[..] && nsec3.salt == chain.salt &&
+                    nsec3.iterations == chain.iterations {
+                    chain.hashes.push((hash.clone(), nsec3));
+                }
+            }
+        }
+        chain
+    }
+
+    fn matching(&self, name: &[u8]) -> Option<&'a Nsec3> {
+        let h = hash(name, &self.salt, self.iterations);
+        self.hashes.iter().find(|&&(ref owner, _)| *owner == h).map(|&(_, n)| n)
+    }
+
+    fn covering(&self, name: &[u8]) -> Option<&'a Nsec3> {
+        let h = hash(name, &self.salt, self.iterations);
+        self.hashes.iter().find(|&&(ref owner, n)| {
+            if owner < &n.next {
+                *owner < h && h < n.next
+            } else {
+                // the last hash wraps around to the first
+                *owner < h || h < n.next
+            }
+        }).map(|&(_, n)| n)
+    }
+
+    // The closest encloser of a name that does not exist, proven by a
+    // matching NSEC3 and one covering the next closer name, and whether
+    // that one has the opt-out flag (RFC 5155 §8.3).
+    fn closest_encloser(&self, name: &[u8]) -> Option<(Vec<u8>, bool)> {
+        let mut next_closer = name;
+        while next_closer != &self.zone[..] && is_below(next_closer, &self.zone) {
+            let encloser = parent(next_closer);
+            if self.matching(encloser).is_some() {
+                return self.covering(next_closer).map(|n| (encloser.to_vec(), n.flags & 1 == 1))
+            }
+            next_closer = encloser;
+        }
+        None
+    }
+}
+
+/// The NSEC3 hash of a lowercase wire name (RFC 5155 §5).
+fn hash(name: &[u8], salt: &[u8], iterations: u16) -> Vec<u8> {
+    let mut data = name.to_vec();
+    data.push(0);
+    data.extend(salt);
+    let mut h = Sha1::digest(&data);
+    for _ in 0..iterations {
+        h.extend(salt);
+        h = Sha1::digest(&h);
+    }
+    h
+}
+
+fn digest(owner: &[u8], key: &Dnskey, digest_type: u8) -> Option<Vec<u8>> {
+    let mut data = owner.to_vec();
+    data.push(0);
+    let start = data.len();
+    data.resize(start + key.len(), 0);
+    key.pack(&mut data, start).unwrap();
+    match digest_type {
+        1 => Some(Sha1::digest(&data)),
+        2 => Some(Sha256::digest(&data)),
+        4 => Some(Sha384::digest(&data)),
+        _ => None,
+    }
+}
+
+fn digest_supported(digest_type: u8) -> bool {
+    match digest_type {
+        1 | 2 | 4 => true,
+        _ => false,
+    }
+}
+
+// Names in rdata are lowercased for the types of RFC 4034 §6.2 this
+// server has a representation of.
+fn canonical(data: &RData) -> RData {
+    match *data {
+        RData::NS(ref n) => RData::NS(lower(n)),
+        RData::CNAME(ref n) => RData::CNAME(lower(n)),
+        RData::PTR(ref n) => RData::PTR(lower(n)),
+        RData::MX(pref, ref n) => RData::MX(pref, lower(n)),
+        RData::SOA{ ref mname, ref rname, serial, refresh, retry, expire, minimum } => RData::SOA{
+            mname: lower(mname),
+            rname: lower(rname),
+            serial: serial,
+            refresh: refresh,
+            retry: retry,
+            expire: expire,
+            minimum: minimum,
+        },
+        RData::SRV(ref srv) => RData::SRV(Srv{ target: lower(&srv.target), ..srv.clone() }),
+        RData::NAPTR(ref naptr) => RData::NAPTR(Naptr{ replacement: lower(&naptr.replacement), ..naptr.clone() }),
+        ref other => other.clone(),
+    }
+}
+
+// Names are compared as lowercase wire labels, without the root label.
+fn wire(name: &RName) -> Vec<u8> {
+    let mut buf = vec![0; name.len() + 1];
+    name.pack(&mut buf, 0).unwrap();
+    buf.pop();
+    buf.make_ascii_lowercase();
+    buf
+}
+
+fn from_wire(name: &[u8]) -> RName {
+    let mut buf = name.to_vec();
+    buf.push(0);
+    RName::unpack(&buf, 0).unwrap().0
+}
+
+fn lower(name: &RName) -> RName {
+    from_wire(&wire(name))
+}
+
+// Offsets of the labels of a name.
+fn starts(name: &[u8]) -> Vec<usize> {
+    let mut out = vec![];
+    let mut i = 0;
+    while i < name.len() {
+        out.push(i);
+        i += 1 + name[i] as usize;
+    }
+    out
+}
+
+fn label_count(name: &[u8]) -> usize {
+    starts(name).len()
+}
+
+fn parent(name: &[u8]) -> &[u8] {
+    match name.first() {
+        Some(&len) => &name[1 + len as usize..],
+        None => name,
+    }
+}
+
+// The name made of the last `n` labels.
+fn suffix(name: &[u8], n: usize) -> &[u8] {
+    let starts = starts(name);
+    if n >= starts.len() {
+        name
+    } else {
+        &name[starts[starts.len() - n]..]
+    }
+}
+
+fn wildcard(name: &[u8]) -> Vec<u8> {
+    let mut out = vec![1, b'*'];
+    out.extend(name);
+    out
+}
+
+// Whether `name` is `zone` or below it.
+fn is_below(name: &[u8], zone: &[u8]) -> bool {
+    zone.is_empty() || starts(name).into_iter().any(|i| &name[i..] == zone)
+}
+
+// The closest common ancestor of two names.
+fn common<'a>(a: &'a [u8], b: &[u8]) -> &'a [u8] {
+    let mut n = cmp::min(label_count(a), label_count(b));
+    while suffix(a, n) != suffix(b, n) {
+        n -= 1;
+    }
+    suffix(a, n)
+}
+
+// Canonical name order (RFC 4034 §6.1), comparing labels from the right.
+fn compare(a: &[u8], b: &[u8]) -> Ordering {
+    let (sa, sb) = (starts(a), starts(b));
+    let (mut i, mut j) = (sa.len(), sb.len());
+    loop {
+        match (i, j) {
+            (0, 0) => return Ordering::Equal,
+            (0, _) => return Ordering::Less,
+            (_, 0) => return Ordering::Greater,
+            _ => {}
+        }
+        i -= 1;
+        j -= 1;
+        let la = &a[sa[i] + 1..sa[i] + 1 + a[sa[i]] as usize];
+        let lb = &b[sb[j] + 1..sb[j] + 1 + b[sb[j]] as usize];
+        match la.cmp(lb) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+}
+
+// Whether an NSEC from `owner` to `next` proves `name` does not exist.
+fn covers(owner: &[u8], next: &[u8], name: &[u8]) -> bool {
+    let after = compare(owner, name) == Ordering::Less;
+    let before = compare(name, next) == Ordering::Less;
+    if compare(owner, next) == Ordering::Less {
+        after && before
+    } else {
+        // the last NSEC of a zone points back to the apex
+        after || before
+    }
+}
+
+
+#[cfg(test)] use dns::Class;
+#[cfg(test)] use dns::dnssec::SEP;
+#[cfg(test)] use signature::{ed25519_public_key, sign_ed25519, ED25519};
+
+#[cfg(test)]
+const NOW: u64 = 1_700_000_000;
+
+#[cfg(test)]
+fn name(s: &str) -> RName {
+    s.parse().unwrap()
+}
+
+#[cfg(test)]
+fn dnskey(seed: u8) -> Dnskey {
+    Dnskey{ flags: ZONE_KEY | SEP, protocol: PROTOCOL, algorithm: ED25519, public_key: ed25519_public_key(&[seed; 32]) }
+}
+
+#[cfg(test)]
+fn records(origin: &str, text: &str) -> Vec<Resource> {
+    Reader::new(text, name(origin)).map(|entry| entry.unwrap().to_resource().unwrap()).collect()
+}
+
+// Signs every RRset with the key made from `seed`, valid for a day
+// either side of NOW.
+#[cfg(test)]
+fn sign(records: &mut Vec<Resource>, origin: &str, seed: u8) {
+    let mut sigs = vec![];
+    for set in rrsets(records) {
+        let labels = label_count(&set.name) - if set.name.starts_with(b"\x01*") { 1 } else { 0 };
+        let mut sig = Rrsig{
+            type_covered: set.rtype as u16,
+            algorithm: ED25519,
+            labels: labels as u8,
+            original_ttl: set.records[0].ttl,
+            expiration: (NOW + 86400) as u32,
+            inception: (NOW - 86400) as u32,
+            key_tag: dnskey(seed).key_tag(),
+            signer: name(origin),
+            signature: vec![],
+        };
+        sig.signature = sign_ed25519(&[seed; 32], &signed_data(&sig, &set.records));
+        sigs.push(Resource{ name: set.records[0].name.clone(), rtype: RType::RRSIG, class: Class::IN, ttl: sig.original_ttl, data: RData::RRSIG(sig) });
+    }
+    records.extend(sigs);
+}
+
+// An NSEC3 chain over the given names and their types.
+#[cfg(test)]
+fn nsec3_chain(origin: &str, names: &[(&str, &[RType])]) -> Vec<Resource> {
+    let salt = vec![0xab, 0xcd];
+    let mut hashed: Vec<(Vec<u8>, Vec<u16>)> = names.iter().map(|&(n, types)| {
+        (hash(&wire(&name(n)), &salt, 10), types.iter().map(|&t| t as u16).collect())
+    }).collect();
+    hashed.sort();
+    (0..hashed.len()).map(|i| Resource{
+        name: name(&format!("{}.{}", base32::encode(&hashed[i].0), origin)),
+        rtype: RType::NSEC3,
+        class: Class::IN,
+        ttl: 300,
+        data: RData::NSEC3(Nsec3{
+            algorithm: 1,
+            flags: 0,
+            iterations: 10,
+            salt: salt.clone(),
+            next: hashed[(i + 1) % hashed.len()].0.clone(),
+            types: hashed[i].1.clone(),
+        }),
+    }).collect()
+}
+
+// The root, com. and three zones below it: example.com. signed with NSEC,
+// nsec3.com. signed with NSEC3 and insecure.com. not signed at all.
+#[cfg(test)]
+fn internet() -> Vec<Resource> {
+    let ds = |owner: &str, seed: u8| format!("{} 3600 IN DS {}", owner, ds(&name(owner), &dnskey(seed), 2).unwrap());
+    let key = |seed: u8| format!("@ 3600 IN DNSKEY {}", dnskey(seed));
+
+    let mut root = records(".", &format!("{}\n{}", key(1), ds("com.", 2)));
+    sign(&mut root, ".", 1);
+
+    let mut com = records("com.", &format!("{}\n{}\n{}\ninsecure 300 IN NSEC nsec3.com. NS RRSIG NSEC",
+                                           key(2), ds("example.com.", 3), ds("nsec3.com.", 4)));
+    sign(&mut com, "com.", 2);
+
+    let mut example = records("example.com.", &format!("{}\n{}", key(3), "\
+@ 3600 IN SOA ns hostmaster 1 3600 600 86400 300
+@ 300 IN NSEC alias.example.com. SOA RRSIG NSEC DNSKEY
+alias 3600 IN CNAME www
+alias 300 IN NSEC *.wild.example.com. CNAME RRSIG NSEC
+*.wild 3600 IN A 192.0.2.2
+*.wild 300 IN NSEC www.example.com. A RRSIG NSEC
+www 3600 IN A 192.0.2.1
+www 300 IN NSEC example.com. A RRSIG NSEC"));
+    sign(&mut example, "example.com.", 3);
+
+    let mut nsec3 = records("nsec3.com.", &format!("{}\n{}", key(4), "\
+@ 3600 IN SOA ns hostmaster 1 3600 600 86400 300
+www 3600 IN A 192.0.2.4"));
+    nsec3.extend(nsec3_chain("nsec3.com.", &[
+        ("nsec3.com.", &[RType::SOA, RType::RRSIG, RType::DNSKEY, RType::NSEC3PARAM][..]),
+        ("www.nsec3.com.", &[RType::A, RType::RRSIG][..]),
+    ]));
+    sign(&mut nsec3, "nsec3.com.", 4);
+
+    root.into_iter().chain(com).chain(example).chain(nsec3).collect()
+}
+
+// The records of `rtype` at `owner` with their signatures.
+#[cfg(test)]
+fn lookup(data: &[Resource], owner: &str, rtype: RType) -> Vec<Resource> {
+    let owner = wire(&name(owner));
+    data.iter().filter(|rr| wire(&rr.name) == owner && match rr.data {
+        RData::RRSIG(ref sig) => sig.type_covered == rtype as u16,
+        _ => rr.rtype == rtype,
+    }).cloned().collect()
+}
+
+// The NSEC records of the closest zone matching or covering `owner`,
+// with their signatures.
+#[cfg(test)]
+fn denial(data: &[Resource], owner: &str) -> Vec<Resource> {
+    let target = wire(&name(owner));
+    let signer = |rr: &Resource| match rr.data {
+        RData::RRSIG(ref sig) => Some(wire(&sig.signer)),
+        _ => None,
+    };
+    let zone = data.iter().filter_map(|rr| signer(rr)).filter(|z| is_below(&target, z)).max_by_key(|z| z.len());
+    let mut out = vec![];
+    for rr in data.iter() {
+        if let RData::NSEC(ref nsec) = rr.data {
+            let at = wire(&rr.name);
+            let found = lookup(data, &rr.name.to_string(), RType::NSEC);
+            let in_zone = found.iter().any(|rr| signer(rr) == zone);
+            if in_zone && (at == target || covers(&at, &wire(&nsec.next), &target)) {
+                out.extend(found);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+fn response(qname: &str, qtype: RType, rcode: RCode, answers: Vec<Resource>, authority: Vec<Resource>) -> Message {
+    let mut msg = query(0, &Question{ name: name(qname), rtype: qtype, class: Class::IN });
+    msg.qr = true;
+    msg.rcode = rcode;
+    msg.answers = answers;
+    msg.authority = authority;
+    msg
+}
+
+// Validates `resp`, answering the lookups it needs from `data`.
+#[cfg(test)]
+fn resolve(v: &mut Validator, data: &[Resource], resp: &Message, now: u64) -> Security {
+    for _ in 0..32 {
+        match v.validate(resp, now) {
+            Ok(security) => return security,
+            Err((owner, rtype)) => {
+                let owner = owner.to_string();
+                let answers = lookup(data, &owner, rtype);
+                let authority = if answers.is_empty() { denial(data, &owner) } else { vec![] };
+                v.learn(&response(&owner, rtype, RCode::NOERROR, answers, authority), now);
+            }
+        }
+    }
+    panic!("validation did not finish")
+}
+
+#[cfg(test)]
+fn root_anchor() -> Validator {
+    let anchor = format!(". 3600 IN DS {}", ds(&name("."), &dnskey(1), 2).unwrap());
+    Validator::new(anchors(&anchor).unwrap())
+}
+
+#[test]
+fn secure_answers() {
+    let data = internet();
+    let mut v = root_anchor();
+
+    let resp = response("www.example.com.", RType::A, RCode::NOERROR, lookup(&data, "www.example.com.", RType::A), vec![]);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Secure);
+
+    // the chain through a CNAME
+    let mut answers = lookup(&data, "alias.example.com.", RType::CNAME);
+    answers.extend(lookup(&data, "www.example.com.", RType::A));
+    let alias = response("alias.example.com.", RType::A, RCode::NOERROR, answers, vec![]);
+    assert_eq!(resolve(&mut v, &data, &alias, NOW), Security::Secure);
+
+    // a wildcard expansion with the proof that the name does not exist
+    let mut answers = lookup(&data, "*.wild.example.com.", RType::A);
+    for rr in answers.iter_mut() {
+        rr.name = name("a.wild.example.com.");
+    }
+    let proof = lookup(&data, "*.wild.example.com.", RType::NSEC);
+    let wild = response("a.wild.example.com.", RType::A, RCode::NOERROR, answers.clone(), proof);
+    assert_eq!(resolve(&mut v, &data, &wild, NOW), Security::Secure);
+    let wild = response("a.wild.example.com.", RType::A, RCode::NOERROR, answers, vec![]);
+    assert_eq!(resolve(&mut v, &data, &wild, NOW), Security::Bogus("wildcard expansion not proven"));
+
+    // AD and signatures only for clients that understand them
+    let mut req = query(7, &resp.questions[0]);
+    let shaped = answer(&req, &resp, &Security::Secure);
+    assert!(shaped.ad && shaped.id == 7 && shaped.answers.len() == 2);
+    assert!(!answer(&req, &resp, &Security::Insecure).ad);
+    req.edns = None;
+    let shaped = answer(&req, &resp, &Security::Secure);
+    assert!(!shaped.ad && shaped.answers.len() == 1 && shaped.edns.is_none());
+    assert_eq!(answer(&req, &resp, &Security::Bogus("test")).rcode, RCode::SERVFAIL);
+}
+
+#[test]
+fn insecure_answers() {
+    let data = internet();
+    let mut v = root_anchor();
+
+    // insecure.com. has no DS in com., proven by its NSEC
+    let answers = records("insecure.com.", "www 3600 IN A 192.0.2.3");
+    let resp = response("www.insecure.com.", RType::A, RCode::NOERROR, answers, vec![]);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Insecure);
+
+    // nothing to validate outside the anchored zones
+    let mut v = Validator::new(vec![Resource{
+        name: name("example.com."),
+        rtype: RType::DNSKEY,
+        class: Class::IN,
+        ttl: 3600,
+        data: RData::DNSKEY(dnskey(3)),
+    }]);
+    assert_eq!(v.validate(&resp, NOW), Ok(Security::Insecure));
+    let resp = response("www.example.com.", RType::A, RCode::NOERROR, lookup(&data, "www.example.com.", RType::A), vec![]);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Secure);
+
+    assert!(anchors("example.com. 3600 IN A 192.0.2.1").is_err());
+}
+
+#[test]
+fn bogus_answers() {
+    let data = internet();
+    let mut v = root_anchor();
+
+    let mut answers = lookup(&data, "www.example.com.", RType::A);
+    answers[0].data = RData::A(192, 0, 2, 66);
+    let resp = response("www.example.com.", RType::A, RCode::NOERROR, answers, vec![]);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Bogus("bad signature"));
+
+    // checking a pile of broken signatures before the good one is given up
+    let mut answers = lookup(&data, "www.example.com.", RType::A);
+    let mut broken = answers[1].clone();
+    if let RData::RRSIG(ref mut sig) = broken.data {
+        sig.signature[0] ^= 1;
+    }
+    for _ in 0..MAX_VERIFICATIONS {
+        answers.insert(1, broken.clone());
+    }
+    let resp = response("www.example.com.", RType::A, RCode::NOERROR, answers, vec![]);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Bogus("too many signatures to verify"));
+
+    // signatures cannot be left out of a signed zone
+    let answers = records("example.com.", "www 3600 IN A 192.0.2.1");
+    let resp = response("www.example.com.", RType::A, RCode::NOERROR, answers, vec![]);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Bogus("missing signature"));
+
+    // nor used outside their validity period
+    let resp = response("www.example.com.", RType::A, RCode::NOERROR, lookup(&data, "www.example.com.", RType::A), vec![]);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW + 2 * 86400), Security::Bogus("signature expired or not yet valid"));
+
+    // a root key that is not the anchor
+    let mut v = Validator::new(vec![Resource{
+        name: name("."),
+        rtype: RType::DNSKEY,
+        class: Class::IN,
+        ttl: 3600,
+        data: RData::DNSKEY(dnskey(9)),
+    }]);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Bogus("no trusted DNSKEY"));
+}
+
+#[test]
+fn denial_of_existence() {
+    let data = internet();
+    let mut v = root_anchor();
+    let soa = lookup(&data, "example.com.", RType::SOA);
+
+    // the name and the wildcard that could have matched are covered
+    let mut authority = soa.clone();
+    authority.extend(lookup(&data, "alias.example.com.", RType::NSEC));
+    authority.extend(lookup(&data, "example.com.", RType::NSEC));
+    let resp = response("nosuch.example.com.", RType::A, RCode::NXDOMAIN, vec![], authority);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Secure);
+
+    let mut authority = soa.clone();
+    authority.extend(lookup(&data, "alias.example.com.", RType::NSEC));
+    let resp = response("nosuch.example.com.", RType::A, RCode::NXDOMAIN, vec![], authority);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Bogus("NSEC does not prove the denial"));
+
+    let resp = response("nosuch.example.com.", RType::A, RCode::NXDOMAIN, vec![], soa.clone());
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Bogus("missing denial of existence"));
+
+    // NODATA, and a false one
+    let mut authority = soa.clone();
+    authority.extend(lookup(&data, "www.example.com.", RType::NSEC));
+    let resp = response("www.example.com.", RType::MX, RCode::NOERROR, vec![], authority.clone());
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Secure);
+    let resp = response("www.example.com.", RType::A, RCode::NOERROR, vec![], authority);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Bogus("NSEC does not prove the denial"));
+
+    // the same with NSEC3, from a closest encloser proof
+    let mut authority = lookup(&data, "nsec3.com.", RType::SOA);
+    authority.extend(data.iter().filter(|rr| match rr.data {
+        RData::NSEC3(_) => true,
+        RData::RRSIG(ref sig) => sig.type_covered == RType::NSEC3 as u16,
+        _ => false,
+    }).cloned());
+    let resp = response("nosuch.nsec3.com.", RType::A, RCode::NXDOMAIN, vec![], authority.clone());
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Secure);
+    let resp = response("www.nsec3.com.", RType::MX, RCode::NOERROR, vec![], authority.clone());
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Secure);
+    let resp = response("www.nsec3.com.", RType::A, RCode::NOERROR, vec![], authority);
+    assert_eq!(resolve(&mut v, &data, &resp, NOW), Security::Bogus("NSEC3 does not prove the denial"));
+}
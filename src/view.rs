@@ -0,0 +1,151 @@
+use std::net::{IpAddr, SocketAddr};
+
+use acl::Acl;
+use dns::RName;
+use zone::Zones;
+use secondary::SecondaryConfig;
+use journal::ZoneFile;
+
+/// A named set of zones and forwarding settings served to the clients it
+/// matches, so one name can have different answers inside and outside.
+#[derive(Clone)]
+pub struct View {
+    pub name: String,
+    /// Source addresses of the clients served by this view.
+    pub match_clients: Acl,
+    /// Local addresses the query has to arrive on, which only a server
+    /// bound to a specific address can tell.
+    pub match_destinations: Acl,
+    /// TSIG key names of which the request has to be signed with one; empty
+    /// matches any request, signed or not.
+    pub match_keys: Vec<RName>,
+    pub zones: Zones,
+    /// Zones loaded from master files at start, with the changes made to
+    /// them by updates journaled next to the files.
+    pub zone_files: Vec<ZoneFile>,
+    /// Zones transferred from primary servers and kept up to date.
+    pub secondaries: Vec<SecondaryConfig>,
+    /// Servers sent NOTIFY when a zone of this view changes, besides the
+    /// NS hosts of the zone.
+    pub notify: Vec<SocketAddr>,
+    /// Upstream servers recursive queries not answered from the zones are
+    /// forwarded to, tried in order.
+    pub forwarders: Vec<SocketAddr>,
+    pub recursion: bool,
+}
+
+impl View {
+
+    /// A view matching every client with no zones, forwarding nothing.
+    pub fn new(name: &str) -> View {
+        View{
+            name: name.to_string(),
+            match_clients: Acl::any(),
+            match_destinations: Acl::any(),
+            match_keys: vec![],
+            zones: Zones::new(),
+            zone_files: vec![],
+            secondaries: vec![],
+            notify: vec![],
+            forwarders: vec![],
+            recursion: true,
+        }
+    }
+
+    pub fn matches(&self, client: &IpAddr, dest: &IpAddr, key: Option<&RName>) -> bool {
+        self.match_clients.allows(client) &&
+            self.match_destinations.allows(dest) &&
+            (self.match_keys.is_empty() ||
+             key.map_or(false, |key| self.match_keys.contains(key)))
+    }
+}
+
+/// Views evaluated in order; the first matching view serves the query.
+#[derive(Clone, Default)]
+pub struct Views {
+    views: Vec<View>,
+}
+
+impl Views {
+
+    pub fn new() -> Views {
+        Views{ views: vec![] }
+    }
+
+    pub fn push(&mut self, view: View) {
+        self.views.push(view);
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize { self.views.len() }
+
+    pub fn views(&self) -> &[View] {
+        &self.views
+    }
+
+    pub fn get(&self, i: usize) -> Option<&View> {
+        self.views.get(i)
+    }
+
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut View> {
+        self.views.get_mut(i)
+    }
+
+    /// Whether any view matches on the address queries arrive on.
+    pub fn match_destinations(&self) -> bool {
+        self.views.iter().any(|v| !v.match_destinations.is_any())
+    }
+
+    /// Index of the first view matching a query from `client` received on
+    /// `dest`, signed with `key` if any.
+    pub fn select(&self, client: &IpAddr, dest: &IpAddr, key: Option<&RName>) -> Option<usize> {
+        self.views.iter().position(|v| v.matches(client, dest, key))
+    }
+}
+
+
+#[cfg(test)] use zone::Zone;
+
+#[cfg(test)]
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn select_view() {
+    let mut internal = View::new("internal");
+    internal.match_clients = "10.0.0.0/8, 192.168.0.0/16, !192.168.99.0/24".parse().unwrap();
+    internal.zones.insert(Zone::parse("example.com".parse().unwrap(),
+                                      "@ 60 IN SOA ns hm 1 1 1 1 1\nwww 60 IN A 10.0.0.80").unwrap());
+
+    let mut signed = View::new("signed");
+    signed.match_keys = vec!["transfer-key".parse().unwrap()];
+
+    let mut lan = View::new("lan");
+    lan.match_destinations = "192.0.2.53".parse().unwrap();
+
+    let mut views = Views::new();
+    views.push(internal);
+    views.push(signed);
+    views.push(lan);
+    views.push(View::new("external"));
+
+    let name = |client: &str, dest: &str, key: Option<&str>| {
+        let key: Option<RName> = key.map(|k| k.parse().unwrap());
+        views.select(&ip(client), &ip(dest), key.as_ref()).map(|i| views.views()[i].name.clone())
+    };
+    assert_eq!(name("10.1.2.3", "192.0.2.1", None), Some("internal".to_string()));
+    assert_eq!(name("192.168.99.1", "192.0.2.1", None), Some("external".to_string()));
+    assert_eq!(name("198.51.100.1", "192.0.2.1", Some("Transfer-Key.")), Some("signed".to_string()));
+    assert_eq!(name("198.51.100.1", "192.0.2.1", Some("other-key")), Some("external".to_string()));
+    assert_eq!(name("198.51.100.1", "192.0.2.53", None), Some("lan".to_string()));
+    assert!(views.match_destinations());
+
+    let mut views = Views::new();
+    let mut only = View::new("only");
+    only.match_clients = "::1".parse().unwrap();
+    views.push(only);
+    assert!(!views.match_destinations());
+    assert_eq!(views.select(&ip("127.0.0.1"), &ip("127.0.0.1"), None), None);
+    assert_eq!(views.select(&ip("::1"), &ip("::1"), None), Some(0));
+}
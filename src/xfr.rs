@@ -0,0 +1,384 @@
+use {Result, Error};
+use dns::{Message, RCode, RType, RName, RData};
+use dns::message::{MessageBuilder, Resource};
+use zone::{Diff, Zone, serial_lt};
+
+/// Records are packed into messages of at most this many bytes before
+/// compression, leaving room below the 64k limit of TCP messages.
+pub const MAX_MESSAGE: usize = 16384;
+
+/// Answers an AXFR or IXFR request over TCP with the messages to stream
+/// to the client.
+pub fn transfer(zone: &Zone, req: &Message) -> Vec<Message> {
+    match req.questions.first().map(|q| q.rtype) {
+        Some(RType::AXFR) => axfr(zone, req),
+        Some(RType::IXFR) => ixfr(zone, req),
+        _ => vec![Message::new_error(req, RCode::FORMERR)],
+    }
+}
+
+/// Answers an IXFR request received over UDP. The changes are sent if
+/// they fit into a single datagram, otherwise only the current SOA is, to
+/// make the client retry over TCP (RFC 1995 §2).
+pub fn udp(zone: &Zone, req: &Message) -> Message {
+    let known = match client_serial(req) {
+        Some(serial) => zone.changes_since(serial).is_some(),
+        None => return Message::new_error(req, RCode::FORMERR),
+    };
+    if known {
+        let mut msgs = ixfr(zone, req);
+        if msgs.len() == 1 && size(&msgs[0]) <= req.max_payload() {
+            return msgs.pop().unwrap()
+        }
+    }
+    match zone.soa() {
+        Some(soa) => stream(req, vec![soa.clone()]).pop().unwrap(),
+        None => Message::new_error(req, RCode::SERVFAIL),
+    }
+}
+
+/// The whole zone with its SOA as first and last record (RFC 5936 §2.2).
+pub fn axfr(zone: &Zone, req: &Message) -> Vec<Message> {
+    let soa = match zone.soa() {
+        Some(soa) => soa.clone(),
+        None => return vec![Message::new_error(req, RCode::SERVFAIL)],
+    };
+    let mut rrs = Vec::with_capacity(zone.len() + 1);
+    rrs.push(soa.clone());
+    rrs.extend(zone.records().into_iter().filter(|r| r.rtype != RType::SOA).cloned());
+    rrs.push(soa);
+    stream(req, rrs)
+}
+
+/// The changes since the serial of the SOA in the authority section of
+/// `req` (RFC 1995 §4). A client with the current serial gets just the
+/// SOA; one too far behind gets the whole zone.
+pub fn ixfr(zone: &Zone, req: &Message) -> Vec<Message> {
+    let client = match client_serial(req) {
+        Some(serial) => serial,
+        None => return vec![Message::new_error(req, RCode::FORMERR)],
+    };
+    let soa = match zone.soa() {
+        Some(soa) => soa.clone(),
+        None => return vec![Message::new_error(req, RCode::SERVFAIL)],
+    };
+    if !serial_lt(client, zone.serial()) {
+        return stream(req, vec![soa])
+    }
+    let changes = match zone.changes_since(client) {
+        Some(changes) => changes,
+        None => return axfr(zone, req),
+    };
+
+    let mut rrs = vec![soa.clone()];
+    for diff in changes {
+        rrs.push(diff.from.clone());
+        rrs.extend(diff.deleted.iter().cloned());
+        rrs.push(diff.to.clone());
+        rrs.extend(diff.added.iter().cloned());
+    }
+    rrs.push(soa);
+    stream(req, rrs)
+}
+
+fn client_serial(req: &Message) -> Option<u32> {
+    match req.authority.first().map(|r| &r.data) {
+        Some(&RData::SOA{ serial, .. }) => Some(serial),
+        _ => None,
+    }
+}
+
+/// A transfer request for `origin`, asking for the changes since the
+/// serial of `current` or the whole zone without it.
+pub fn request(id: u16, origin: &RName, current: Option<&Resource>) -> Message {
+    let rtype = if current.is_some() { RType::IXFR } else { RType::AXFR };
+    let req = MessageBuilder::query(origin.clone(), rtype).id(id).rd(false);
+    match current {
+        Some(soa) => req.authority(soa.clone()).build(),
+        None => req.build(),
+    }
+}
+
+/// What a completed incoming transfer holds.
+#[derive(Debug, PartialEq)]
+pub enum Received {
+    /// The primary has nothing newer than the serial asked for.
+    UpToDate,
+    /// Every record of the zone, SOA included.
+    Zone(Vec<Resource>),
+    /// The changes since the serial asked for.
+    Changes(Vec<Diff>),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Phase { Start, Axfr, Deleted, Added, Done }
+
+/// Collects the records of an incoming AXFR or IXFR stream, telling the
+/// two apart by the second record (RFC 1995 §4).
+pub struct Incoming {
+    id: u16,
+    soa: Option<Resource>,
+    phase: Phase,
+    count: usize,
+    records: Vec<Resource>,
+    diffs: Vec<Diff>,
+}
+
+impl Incoming {
+
+    /// Expects the answers to the request with message id `id`.
+    pub fn new(id: u16) -> Incoming {
+        Incoming{ id: id, soa: None, phase: Phase::Start, count: 0, records: vec![], diffs: vec![] }
+    }
+
+    /// Takes the next message of the stream, returning whether the
+    /// transfer is complete.
+    pub fn feed(&mut self, msg: &Message) -> Result<bool> {
+        if msg.id != self.id || !msg.qr || msg.rcode != RCode::NOERROR || self.phase == Phase::Done {
+            return Err(Error::BadTransfer)
+        }
+        for rr in msg.answers.iter() {
+            try!(self.record(rr));
+        }
+        // a lone SOA answers an IXFR for the current serial
+        if self.phase == Phase::Start && self.soa.is_some() && msg.answers.len() == 1 {
+            self.phase = Phase::Done;
+        }
+        Ok(self.phase == Phase::Done)
+    }
+
+    fn record(&mut self, rr: &Resource) -> Result<()> {
+        self.count += 1;
+        let serial = match rr.data {
+            RData::SOA{ serial, .. } => Some(serial),
+            _ => None,
+        };
+        let last = match self.soa {
+            Some(ref soa) => match soa.data {
+                RData::SOA{ serial, .. } => serial,
+                _ => unreachable!(),
+            },
+            None => {
+                if serial.is_none() {
+                    return Err(Error::BadTransfer)
+                }
+                self.soa = Some(rr.clone());
+                return Ok(())
+            }
+        };
+        match (self.phase, serial) {
+            (Phase::Start, Some(s)) if s != last => {
+                self.diffs.push(Diff{ from: rr.clone(), to: rr.clone(), deleted: vec![], added: vec![] });
+                self.phase = Phase::Deleted;
+            }
+            (Phase::Start, Some(_)) | (Phase::Axfr, Some(_)) => self.phase = Phase::Done,
+            (Phase::Start, None) | (Phase::Axfr, None) => {
+                self.records.push(rr.clone());
+                self.phase = Phase::Axfr;
+            }
+            (Phase::Deleted, Some(_)) => {
+                self.diffs.last_mut().unwrap().to = rr.clone();
+                self.phase = Phase::Added;
+            }
+            (Phase::Deleted, None) => self.diffs.last_mut().unwrap().deleted.push(rr.clone()),
+            (Phase::Added, Some(s)) if s == last => self.phase = Phase::Done,
+            (Phase::Added, Some(_)) => {
+                self.diffs.push(Diff{ from: rr.clone(), to: rr.clone(), deleted: vec![], added: vec![] });
+                self.phase = Phase::Deleted;
+            }
+            (Phase::Added, None) => self.diffs.last_mut().unwrap().added.push(rr.clone()),
+            (Phase::Done, _) => return Err(Error::BadTransfer),
+        }
+        Ok(())
+    }
+
+    /// The transfer once complete.
+    pub fn finish(self) -> Result<Received> {
+        if self.phase != Phase::Done {
+            return Err(Error::BadTransfer)
+        }
+        Ok(if !self.diffs.is_empty() {
+            Received::Changes(self.diffs)
+        } else if self.count == 1 {
+            Received::UpToDate
+        } else {
+            let mut records = self.records;
+            records.extend(self.soa);
+            Received::Zone(records)
+        })
+    }
+}
+
+// Splits records over as many messages as needed. Only the first carries
+// the question.
+fn stream(req: &Message, rrs: Vec<Resource>) -> Vec<Message> {
+    let mut msgs = vec![];
+    let mut msg = reply(req);
+    let mut len = size(&msg);
+    for rr in rrs {
+        if len + rr.len() > MAX_MESSAGE && !msg.answers.is_empty() {
+            msgs.push(msg);
+            msg = reply(req);
+            msg.questions.clear();
+            msg.edns = None;
+            len = size(&msg);
+        }
+        len += rr.len();
+        msg.answers.push(rr);
+    }
+    msgs.push(msg);
+    msgs
+}
+
+fn reply(req: &Message) -> Message {
+    let mut msg = Message::new_reply(req);
+    msg.aa = true;
+    msg
+}
+
+// Upper bound of the packed size of a message.
+fn size(msg: &Message) -> usize {
+    12 + msg.questions.iter().fold(0, |n, q| n + q.name.len() + 5) +
+        msg.answers.iter().chain(msg.authority.iter()).chain(msg.additionals.iter()).fold(0, |n, r| n + r.len()) +
+        msg.edns.as_ref().map_or(0, |_| 11)
+}
+
+#[cfg(test)] use zone::soa_serial;
+
+#[cfg(test)]
+fn query(rtype: RType, serial: Option<u32>) -> Message {
+    let zone = example(1);
+    let req = MessageBuilder::query(zone.origin().clone(), rtype).id(7).rd(false);
+    match serial {
+        Some(s) => req.authority(soa_serial(&zone, s)).build(),
+        None => req.build(),
+    }
+}
+
+#[cfg(test)]
+fn example(hosts: usize) -> Zone {
+    let mut text = "@ 3600 IN SOA ns hostmaster 1 3600 600 86400 300\n@ 3600 IN NS ns\nns 3600 IN A 192.0.2.53\n".to_string();
+    for i in 0..hosts {
+        text.push_str(&format!("host{} 3600 IN A 192.0.2.{}\n", i, i % 250));
+    }
+    Zone::parse("example.com".parse().unwrap(), &text).unwrap()
+}
+
+#[cfg(test)]
+fn answers(msgs: &[Message]) -> Vec<(String, RType)> {
+    msgs.iter().flat_map(|m| m.answers.iter()).map(|r| (r.name.to_string(), r.rtype)).collect()
+}
+
+#[test]
+fn transfer_whole_zone() {
+    let zone = example(1);
+    let msgs = transfer(&zone, &query(RType::AXFR, None));
+    assert_eq!(msgs.len(), 1);
+    assert!(msgs[0].aa);
+    assert_eq!(answers(&msgs), vec![("example.com.".to_string(), RType::SOA),
+                                    ("example.com.".to_string(), RType::NS),
+                                    ("host0.example.com.".to_string(), RType::A),
+                                    ("ns.example.com.".to_string(), RType::A),
+                                    ("example.com.".to_string(), RType::SOA)]);
+
+    // large zones span several messages, each fitting a TCP frame
+    let zone = example(3000);
+    let msgs = transfer(&zone, &query(RType::AXFR, None));
+    assert!(msgs.len() > 1);
+    assert_eq!(msgs[0].questions.len(), 1);
+    assert_eq!(msgs[1].questions.len(), 0);
+    let all = answers(&msgs);
+    assert_eq!(all.len(), zone.len() + 1);
+    assert_eq!(all[0].1, RType::SOA);
+    assert_eq!(all[all.len() - 1].1, RType::SOA);
+
+    let mut buf = vec![0; 65535];
+    for msg in msgs.iter() {
+        let len = msg.pack(&mut buf, 0).unwrap();
+        assert!(len <= MAX_MESSAGE);
+        assert_eq!(Message::unpack(&buf[..len], 0).unwrap().answers, msg.answers);
+    }
+}
+
+#[test]
+fn transfer_changes() {
+    let mut zone = example(2);
+    let host = |zone: &Zone, i: usize| zone.records().into_iter()
+        .find(|r| r.name.to_string() == format!("host{}.example.com.", i)).unwrap().clone();
+
+    let deleted = host(&zone, 0);
+    let mut added = host(&zone, 1);
+    added.data = RData::A(192, 0, 2, 201);
+    let diff = Diff{ from: soa_serial(&zone, 1), to: soa_serial(&zone, 2), deleted: vec![deleted], added: vec![added] };
+    zone.apply(diff).unwrap();
+
+    let msgs = transfer(&zone, &query(RType::IXFR, Some(1)));
+    assert_eq!(answers(&msgs), vec![("example.com.".to_string(), RType::SOA),
+                                    ("example.com.".to_string(), RType::SOA),
+                                    ("host0.example.com.".to_string(), RType::A),
+                                    ("example.com.".to_string(), RType::SOA),
+                                    ("host1.example.com.".to_string(), RType::A),
+                                    ("example.com.".to_string(), RType::SOA)]);
+    let serials: Vec<u32> = msgs[0].answers.iter().filter_map(|r| match r.data {
+        RData::SOA{ serial, .. } => Some(serial),
+        _ => None,
+    }).collect();
+    assert_eq!(serials, vec![2, 1, 2, 2]);
+
+    // up to date, or ahead of us
+    for &serial in [2, 3].iter() {
+        let msgs = transfer(&zone, &query(RType::IXFR, Some(serial)));
+        assert_eq!(answers(&msgs), vec![("example.com.".to_string(), RType::SOA)]);
+    }
+
+    // unknown history falls back to a full transfer
+    let msgs = transfer(&zone, &query(RType::IXFR, Some(0xffff_0000)));
+    assert_eq!(answers(&msgs).len(), zone.len() + 1);
+
+    let resp = udp(&zone, &query(RType::IXFR, Some(1)));
+    assert_eq!(resp.answers.len(), 6);
+    let resp = udp(&zone, &query(RType::IXFR, Some(0xffff_0000)));
+    assert_eq!(answers(&[resp]), vec![("example.com.".to_string(), RType::SOA)]);
+
+    // IXFR needs the client's SOA
+    let msgs = transfer(&zone, &query(RType::IXFR, None));
+    assert_eq!(msgs[0].rcode, RCode::FORMERR);
+}
+
+#[test]
+fn receive_transfers() {
+    fn receive(msgs: &[Message]) -> Result<Received> {
+        let mut incoming = Incoming::new(7);
+        for (i, msg) in msgs.iter().enumerate() {
+            assert_eq!(try!(incoming.feed(msg)), i == msgs.len() - 1);
+        }
+        incoming.finish()
+    }
+
+    let mut zone = example(3000);
+    let msgs = transfer(&zone, &query(RType::AXFR, None));
+    match receive(&msgs).unwrap() {
+        Received::Zone(rrs) => {
+            assert_eq!(rrs.len(), zone.len());
+            assert_eq!(rrs[rrs.len() - 1].rtype, RType::SOA);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let diff = Diff{ from: soa_serial(&zone, 1), to: soa_serial(&zone, 2), deleted: vec![], added: vec![] };
+    zone.apply(diff.clone()).unwrap();
+    let resp = transfer(&zone, &query(RType::IXFR, Some(1)));
+    assert_eq!(receive(&resp).unwrap(), Received::Changes(vec![diff]));
+    let resp = transfer(&zone, &query(RType::IXFR, Some(2)));
+    assert_eq!(receive(&resp).unwrap(), Received::UpToDate);
+
+    // answers to another query, errors and streams cut short
+    let mut msgs = msgs;
+    let mut incoming = Incoming::new(8);
+    assert!(incoming.feed(&msgs[0]).is_err());
+    let mut incoming = Incoming::new(7);
+    assert_eq!(incoming.feed(&msgs[0]).unwrap(), false);
+    assert!(incoming.finish().is_err());
+    msgs[0].rcode = RCode::REFUSED;
+    assert!(receive(&msgs[..1]).is_err());
+}
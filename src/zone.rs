@@ -0,0 +1,977 @@
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::Path;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::collections::{hash_map, BTreeMap, HashMap, HashSet, VecDeque};
+use std::str;
+
+use {Result, Error};
+use dns::{Message, RCode, RType, RName, RData};
+use dns::message::{Edns, Resource};
+use dns::base32;
+use dns::master::Reader;
+use dns::dnssec::Nsec3Param;
+use validator::nsec3_hash;
+
+// Longest CNAME chain followed within a zone.
+const MAX_CNAME_CHAIN: usize = 8;
+
+/// Number of changes kept per zone for serving IXFR.
+pub const MAX_HISTORY: usize = 64;
+
+/// The records deleted and added to go from one serial of a zone to the
+/// next, in the order of an IXFR response (RFC 1995 §4).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diff {
+    /// The SOA before the change.
+    pub from: Resource,
+    /// The SOA after the change.
+    pub to: Resource,
+    pub deleted: Vec<Resource>,
+    pub added: Vec<Resource>,
+}
+
+impl Diff {
+
+    pub fn from_serial(&self) -> u32 { serial(&self.from) }
+
+    pub fn to_serial(&self) -> u32 { serial(&self.to) }
+}
+
+/// Authoritative data for one zone, loaded from a master file.
+#[derive(Clone, Debug)]
+pub struct Zone {
+    origin: RName,
+    // in canonical order, so the names below a name follow right after it
+    records: BTreeMap<RName, Vec<Resource>>,
+    len: usize,
+    history: VecDeque<Diff>,
+    // owners and targets of the PTR records synthesized from forward
+    // zones, which are served but not saved
+    synthesized: HashSet<(RName, RName)>,
+}
+
+impl Zone {
+
+    pub fn new(origin: RName) -> Zone {
+        Zone{
+            origin: origin,
+            records: BTreeMap::new(),
+            len: 0,
+            history: VecDeque::new(),
+            synthesized: HashSet::new(),
+        }
+    }
+
+    /// Parses a zone from master file text. The zone must have an SOA at
+    /// its apex and every record must be at or below the apex.
+    pub fn parse(origin: RName, input: &str) -> Result<Zone> {
+        let mut zone = Zone::new(origin.clone());
+        let mut reader = Reader::new(input, origin);
+
+        while let Some(entry) = reader.next() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    println!("zone {} line {}: {:?}", zone.origin, reader.line(), e);
+                    return Err(e.into())
+                }
+            };
+            let rr = match entry.to_resource() {
+                Ok(rr) => rr,
+                Err(e) => {
+                    println!("zone {} line {}: {:?}", zone.origin, reader.line(), e);
+                    return Err(e.into())
+                }
+            };
+            try!(zone.insert(rr));
+        }
+
+        if zone.soa().is_none() {
+            return Err(Error::BadZone)
+        }
+        Ok(zone)
+    }
+
+    pub fn open<P: AsRef<Path>>(origin: RName, path: P) -> Result<Zone> {
+        let mut input = String::new();
+        try!(File::open(path).and_then(|mut f| f.read_to_string(&mut input)));
+        Zone::parse(origin, &input)
+    }
+
+    /// Writes the zone as a master file, replacing `path` only once the
+    /// new file is complete. Synthesized PTR records are left out.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        {
+            let mut file = try!(File::create(&tmp));
+            try!(write!(file, "; {} serial {}\n", self.origin, self.serial()));
+            for rr in self.records().into_iter().filter(|rr| !self.is_synthesized(rr)) {
+                try!(write!(file, "{}\n", rr));
+            }
+            try!(file.sync_all());
+        }
+        try!(fs::rename(&tmp, path));
+        Ok(())
+    }
+
+    #[inline]
+    pub fn origin(&self) -> &RName { &self.origin }
+
+    /// Number of records in the zone.
+    #[inline]
+    pub fn len(&self) -> usize { self.len }
+
+    pub fn soa(&self) -> Option<&Resource> {
+        self.get(&self.origin).iter().find(|r| r.rtype == RType::SOA)
+    }
+
+    /// The serial of the SOA, 0 for a zone without one.
+    pub fn serial(&self) -> u32 {
+        self.soa().map_or(0, serial)
+    }
+
+    /// Every record of the zone, ordered by owner name in canonical order
+    /// (RFC 4034 §6.1), the apex first.
+    pub fn records(&self) -> Vec<&Resource> {
+        self.records.values().flat_map(|rrs| rrs.iter()).collect()
+    }
+
+    /// The records owned by `name` itself.
+    pub fn get(&self, name: &RName) -> &[Resource] {
+        match self.records.get(name) {
+            Some(rrs) => &rrs[..],
+            None => &[],
+        }
+    }
+
+    /// Adds a record, failing if its owner is outside the zone.
+    pub fn insert(&mut self, rr: Resource) -> Result<()> {
+        if !self.contains(&rr.name) {
+            return Err(Error::BadZone)
+        }
+        // a record added like one synthesized is there for good
+        if let RData::PTR(ref target) = rr.data {
+            self.synthesized.remove(&(rr.name.clone(), target.clone()));
+        }
+        let rrs = self.records.entry(rr.name.clone()).or_insert_with(Vec::new);
+        if !rrs.contains(&rr) {
+            rrs.push(rr);
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    /// Removes a record regardless of its TTL, returning whether it was
+    /// present.
+    pub fn remove(&mut self, rr: &Resource) -> bool {
+        let (found, empty) = match self.records.get_mut(&rr.name) {
+            Some(rrs) => {
+                let before = rrs.len();
+                rrs.retain(|r| r.rtype != rr.rtype || r.class != rr.class || r.data != rr.data);
+                (before != rrs.len(), rrs.is_empty())
+            }
+            None => (false, false),
+        };
+        if found {
+            self.len -= 1;
+        }
+        if empty {
+            self.records.remove(&rr.name);
+        }
+        found
+    }
+
+    /// Applies a change on top of the current serial, replacing the SOA
+    /// and remembering the change for IXFR.
+    pub fn apply(&mut self, diff: Diff) -> Result<()> {
+        if diff.from_serial() != self.serial() || diff.to.rtype != RType::SOA {
+            return Err(Error::BadZone)
+        }
+        for rr in diff.added.iter() {
+            if !self.contains(&rr.name) {
+                return Err(Error::BadZone)
+            }
+        }
+        if let Some(soa) = self.soa().cloned() {
+            self.remove(&soa);
+        }
+        for rr in diff.deleted.iter() {
+            self.remove(rr);
+        }
+        for rr in diff.added.iter() {
+            try!(self.insert(rr.clone()));
+        }
+        try!(self.insert(diff.to.clone()));
+
+        self.history.push_back(diff);
+        while self.history.len() > MAX_HISTORY {
+            self.history.pop_front();
+        }
+        Ok(())
+    }
+
+    /// The changes leading from `serial` to the current serial, or `None`
+    /// if they are no longer known.
+    pub fn changes_since(&self, serial: u32) -> Option<Vec<&Diff>> {
+        if serial == self.serial() {
+            return Some(vec![])
+        }
+        let start = match self.history.iter().position(|d| d.from_serial() == serial) {
+            Some(start) => start,
+            None => return None,
+        };
+        let changes: Vec<&Diff> = self.history.iter().skip(start).collect();
+        for pair in changes.windows(2) {
+            if pair[0].to_serial() != pair[1].from_serial() {
+                return None
+            }
+        }
+        Some(changes)
+    }
+
+    /// Whether `name` is at or below the apex of this zone.
+    pub fn contains(&self, name: &RName) -> bool {
+        name.is_subdomain_of(&self.origin)
+    }
+
+    // Whether `name` holds records or is an empty non-terminal above some,
+    // the apex always existing.
+    fn exists(&self, name: &RName) -> bool {
+        *name == self.origin || self.records.range(name.clone()..).next()
+            .map_or(false, |(owner, _)| owner.is_subdomain_of(name))
+    }
+
+    /// Adds to this reverse zone a PTR record for every address of
+    /// `forward` it covers, except at names already having PTR records.
+    /// Returns the number of records added.
+    pub fn synthesize_ptrs(&mut self, forward: &Zone) -> usize {
+        let mut ptrs = vec![];
+        for rr in forward.records() {
+            let ip = match rr.data {
+                RData::A(a, b, c, d) => IpAddr::V4(Ipv4Addr::new(a, b, c, d)),
+                RData::AAAA(a, b, c, d, e, f, g, h) => IpAddr::V6(Ipv6Addr::new(a, b, c, d, e, f, g, h)),
+                _ => continue,
+            };
+            let name = RName::from_ip(ip);
+            if rr.name.is_wildcard() || !self.contains(&name) ||
+                self.get(&name).iter().any(|r| r.rtype == RType::PTR) {
+                continue
+            }
+            ptrs.push(Resource{
+                name: name,
+                rtype: RType::PTR,
+                class: rr.class,
+                ttl: rr.ttl,
+                data: RData::PTR(rr.name.clone()),
+            });
+        }
+        let len = self.len;
+        for rr in ptrs {
+            if let (Ok(()), RData::PTR(ref target)) = (self.insert(rr.clone()), &rr.data) {
+                self.synthesized.insert((rr.name.clone(), target.clone()));
+            }
+        }
+        self.len - len
+    }
+
+    fn is_synthesized(&self, rr: &Resource) -> bool {
+        match rr.data {
+            RData::PTR(ref target) => self.synthesized.contains(&(rr.name.clone(), target.clone())),
+            _ => false,
+        }
+    }
+
+    /// Answers the first question of `req` from the zone data, following
+    /// CNAMEs within the zone, returning referrals for delegated names and
+    /// expanding wildcards (RFC 1034 §4.3.2). With the DO bit set the
+    /// signatures and NSEC or NSEC3 records of a signed zone are included
+    /// (RFC 4035 §3.1).
+    pub fn answer(&self, req: &Message) -> Message {
+        let mut resp = Message::new_reply(req);
+        resp.aa = true;
+        let dnssec_ok = req.edns.as_ref().map_or(false, |e| e.dnssec_ok);
+        if dnssec_ok {
+            resp.edns = Some(Edns{ dnssec_ok: true, ..Edns::default() });
+        }
+        let dnssec = dnssec_ok && self.get(&self.origin).iter().any(|r| r.rtype == RType::DNSKEY);
+        if let Some(q) = req.questions.first() {
+            self.resolve(&mut resp, &q.name, q.rtype, 0, dnssec);
+        }
+        resp
+    }
+
+    fn resolve(&self, resp: &mut Message, qname: &RName, qtype: RType, depth: usize, dnssec: bool) {
+        if !self.contains(qname) {
+            return
+        }
+
+        // a zone cut above or at the name turns the answer into a referral
+        let mut names = vec![];
+        let mut name = qname.clone();
+        while name != self.origin {
+            let parent = name.parent();
+            names.push(name);
+            name = match parent {
+                Some(parent) => parent,
+                None => return,
+            };
+        }
+        for name in names.iter().rev() {
+            let rrs = match self.records.get(name) {
+                Some(rrs) => rrs,
+                None => continue,
+            };
+            if name == qname && qtype == RType::DS {
+                break
+            }
+            let ns: Vec<Resource> = rrs.iter().filter(|r| r.rtype == RType::NS).cloned().collect();
+            if !ns.is_empty() {
+                if resp.answers.is_empty() {
+                    resp.aa = false;
+                }
+                let owner = ns[0].name.clone();
+                self.additional(resp, &ns);
+                resp.authority.extend(ns);
+                if dnssec {
+                    // the delegation is signed, or proven not to be
+                    let ds: Vec<Resource> = rrs.iter().filter(|r| r.rtype == RType::DS).cloned().collect();
+                    if ds.is_empty() {
+                        self.deny(resp, name);
+                    } else {
+                        resp.authority.extend(ds);
+                        signatures(&mut resp.authority, &owner, rrs, RType::DS);
+                    }
+                }
+                return
+            }
+        }
+
+        if let Some(rrs) = self.records.get(qname) {
+            return self.answer_rrs(resp, qname, rrs, qtype, depth, dnssec, None)
+        }
+        if self.exists(qname) {
+            self.negative(resp, RCode::NOERROR, dnssec);
+            if dnssec {
+                self.deny(resp, qname);
+            }
+            return
+        }
+
+        // the closest encloser always exists since the apex does
+        let mut encloser = qname.clone();
+        while let Some(parent) = encloser.parent() {
+            encloser = parent;
+            if self.exists(&encloser) {
+                break
+            }
+        }
+        let wildcard = encloser.prepend_label(b"*").ok();
+        match wildcard.as_ref().and_then(|w| self.records.get(w)) {
+            Some(rrs) => self.answer_rrs(resp, qname, rrs, qtype, depth, dnssec, Some(&encloser)),
+            None => {
+                self.negative(resp, RCode::NXDOMAIN, dnssec);
+                if dnssec {
+                    self.deny_name(resp, qname, &encloser);
+                    if let Some(ref wildcard) = wildcard {
+                        self.deny(resp, wildcard);
+                    }
+                }
+            }
+        }
+    }
+
+    // Answers from the records at `qname`, or from those of the wildcard
+    // below its closest encloser when `expanded` is that encloser.
+    fn answer_rrs(&self, resp: &mut Message, owner: &RName, rrs: &[Resource],
+                  qtype: RType, depth: usize, dnssec: bool, expanded: Option<&RName>) {
+        let found: Vec<Resource> = rrs.iter()
+            .filter(|r| qtype == RType::ALL || r.rtype == qtype)
+            .map(|r| Resource{ name: owner.clone(), ..r.clone() })
+            .collect();
+        if !found.is_empty() {
+            self.additional(resp, &found);
+            resp.answers.extend(found);
+            // signatures are part of the records of ANY
+            if dnssec && qtype != RType::ALL && qtype != RType::RRSIG {
+                signatures(&mut resp.answers, owner, rrs, qtype);
+            }
+            if let (true, Some(encloser)) = (dnssec, expanded) {
+                self.deny_name(resp, owner, encloser);
+            }
+            return
+        }
+
+        if let Some(cname) = rrs.iter().find(|r| r.rtype == RType::CNAME) {
+            resp.answers.push(Resource{ name: owner.clone(), ..cname.clone() });
+            if dnssec {
+                signatures(&mut resp.answers, owner, rrs, RType::CNAME);
+                if let Some(encloser) = expanded {
+                    self.deny_name(resp, owner, encloser);
+                }
+            }
+            if let RData::CNAME(ref target) = cname.data {
+                if depth < MAX_CNAME_CHAIN {
+                    self.resolve(resp, target, qtype, depth + 1, dnssec);
+                }
+            }
+            return
+        }
+
+        self.negative(resp, RCode::NOERROR, dnssec);
+        if dnssec {
+            match expanded {
+                Some(encloser) => {
+                    self.deny_name(resp, owner, encloser);
+                    if let Ok(wildcard) = encloser.prepend_label(b"*") {
+                        self.deny(resp, &wildcard);
+                    }
+                }
+                None => self.deny(resp, owner),
+            }
+        }
+    }
+
+    /// NXDOMAIN or NODATA with the SOA for negative caching (RFC 2308).
+    fn negative(&self, resp: &mut Message, rcode: RCode, dnssec: bool) {
+        resp.rcode = rcode;
+        if let Some(soa) = self.soa() {
+            let mut soa = soa.clone();
+            if let RData::SOA{ minimum, .. } = soa.data {
+                if minimum < soa.ttl {
+                    soa.ttl = minimum;
+                }
+            }
+            resp.authority.push(soa);
+            if dnssec {
+                signatures(&mut resp.authority, &self.origin, self.get(&self.origin), RType::SOA);
+            }
+        }
+    }
+
+    // Adds the NSEC or NSEC3 record matching or covering the name, with its
+    // signatures. The chain is found by a scan of the zone.
+    fn deny(&self, resp: &mut Message, name: &RName) {
+        let found = match self.nsec3_param() {
+            Some(param) => {
+                let hash = nsec3_hash(name, &param.salt, param.iterations);
+                self.records.values().flat_map(|rrs| rrs.iter()).find(|r| match r.data {
+                    RData::NSEC3(ref nsec3) if nsec3.salt == param.salt && nsec3.iterations == param.iterations => {
+                        let owner = r.name.labels().next()
+                            .and_then(|l| str::from_utf8(l).ok())
+                            .and_then(|l| base32::decode(l).ok())
+                            .unwrap_or_default();
+                        covers(&owner, &nsec3.next, &hash)
+                    }
+                    _ => false,
+                })
+            }
+            None => self.records.values().flat_map(|rrs| rrs.iter()).find(|r| match r.data {
+                RData::NSEC(ref nsec) => covers(&r.name, &nsec.next, name),
+                _ => false,
+            }),
+        };
+        if let Some(rr) = found {
+            if !resp.authority.contains(rr) {
+                resp.authority.push(rr.clone());
+                signatures(&mut resp.authority, &rr.name, self.get(&rr.name), rr.rtype);
+            }
+        }
+    }
+
+    // Proves `name` does not exist below its closest encloser: with
+    // NSEC3, that the encloser exists and the next closer name does not
+    // (RFC 5155 §7.2.1).
+    fn deny_name(&self, resp: &mut Message, name: &RName, encloser: &RName) {
+        if self.nsec3_param().is_some() {
+            self.deny(resp, encloser);
+            let mut next_closer = name.clone();
+            while next_closer.label_count() > encloser.label_count() + 1 {
+                next_closer = match next_closer.parent() {
+                    Some(parent) => parent,
+                    None => return,
+                };
+            }
+            self.deny(resp, &next_closer);
+        } else {
+            self.deny(resp, name);
+        }
+    }
+
+    fn nsec3_param(&self) -> Option<&Nsec3Param> {
+        self.get(&self.origin).iter().filter_map(|r| match r.data {
+            RData::NSEC3PARAM(ref param) => Some(param),
+            _ => None,
+        }).next()
+    }
+
+    /// Adds addresses held in the zone for the targets of NS, MX, SRV,
+    /// SVCB and HTTPS records (RFC 9460 §4.2).
+    fn additional(&self, resp: &mut Message, rrs: &[Resource]) {
+        for rr in rrs.iter() {
+            let target = match rr.data {
+                RData::NS(ref name) | RData::MX(_, ref name) => name,
+                RData::SRV(ref srv) => &srv.target,
+                RData::SVCB(ref svcb) | RData::HTTPS(ref svcb) => match svcb.target_of(&rr.name) {
+                    Some(target) => target,
+                    None => continue,
+                },
+                _ => continue,
+            };
+            if !self.contains(target) {
+                continue
+            }
+            if let Some(addrs) = self.records.get(target) {
+                for addr in addrs.iter().filter(|r| r.rtype == RType::A || r.rtype == RType::AAAA) {
+                    if !resp.additionals.contains(addr) {
+                        resp.additionals.push(addr.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A set of zones answered from, keyed by their apex.
+#[derive(Clone, Debug, Default)]
+pub struct Zones {
+    zones: HashMap<RName, Zone>,
+}
+
+impl Zones {
+
+    pub fn new() -> Zones {
+        Zones{ zones: HashMap::new() }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize { self.zones.len() }
+
+    /// Adds a zone, replacing any zone with the same apex.
+    pub fn insert(&mut self, zone: Zone) {
+        self.zones.insert(zone.origin.clone(), zone);
+    }
+
+    pub fn get(&self, origin: &RName) -> Option<&Zone> {
+        self.zones.get(origin)
+    }
+
+    pub fn remove(&mut self, origin: &RName) -> Option<Zone> {
+        self.zones.remove(origin)
+    }
+
+    pub fn get_mut(&mut self, origin: &RName) -> Option<&mut Zone> {
+        self.zones.get_mut(origin)
+    }
+
+    /// The most specific zone containing `name`.
+    pub fn find(&self, name: &RName) -> Option<&Zone> {
+        let mut name = name.clone();
+        loop {
+            if let Some(zone) = self.zones.get(&name) {
+                return Some(zone)
+            }
+            name = match name.parent() {
+                Some(parent) => parent,
+                None => return None,
+            };
+        }
+    }
+
+    pub fn iter<'a>(&'a self) -> hash_map::Values<'a, RName, Zone> {
+        self.zones.values()
+    }
+}
+
+
+/// Whether serial `a` precedes `b` in sequence space arithmetic
+/// (RFC 1982 §3.2).
+pub fn serial_lt(a: u32, b: u32) -> bool {
+    a != b && b.wrapping_sub(a) < 0x8000_0000
+}
+
+// The serial of an SOA record, 0 for any other.
+fn serial(soa: &Resource) -> u32 {
+    match soa.data {
+        RData::SOA{ serial, .. } => serial,
+        _ => 0,
+    }
+}
+
+// Whether the link of an NSEC or NSEC3 chain from `owner` to `next`
+// matches or covers `name`, the last link wrapping around to the first.
+fn covers<T: Ord + ?Sized>(owner: &T, next: &T, name: &T) -> bool {
+    if owner == name {
+        true
+    } else if owner < next {
+        owner < name && name < next
+    } else {
+        owner < name || name < next
+    }
+}
+
+// Adds the signatures over the `rtype` records among `rrs`, with the owner
+// an expanded wildcard is answered for.
+fn signatures(section: &mut Vec<Resource>, owner: &RName, rrs: &[Resource], rtype: RType) {
+    for rr in rrs.iter() {
+        if let RData::RRSIG(ref sig) = rr.data {
+            if sig.type_covered == rtype as u16 {
+                section.push(Resource{ name: owner.clone(), ..rr.clone() });
+            }
+        }
+    }
+}
+
+
+#[cfg(test)] use dns::Class;
+#[cfg(test)] use dns::message::MessageBuilder;
+#[cfg(test)] use dns::txt::Txt;
+
+#[cfg(test)]
+const EXAMPLE: &'static str = "
+$ORIGIN example.com.
+$TTL 1h
+@       IN SOA  ns1 hostmaster 2016010101 1d 2h 4w 300
+        IN NS   ns1
+        IN MX   10 mail
+ns1     IN A    192.0.2.53
+mail    IN A    192.0.2.25
+        IN AAAA 2001:db8::25
+www     IN CNAME web
+web     IN A    192.0.2.80
+ext     IN CNAME www.example.net.
+*.wild  IN A    192.0.2.99
+a.b.c   IN TXT  \"v=spf1 -all\" \"second\"
+sub     IN NS   ns.sub
+ns.sub  IN A    192.0.2.153
+";
+
+#[cfg(test)]
+fn query(name: &str, rtype: RType) -> Message {
+    MessageBuilder::query(name.parse().unwrap(), rtype).id(7).rd(false).build()
+}
+
+#[cfg(test)]
+fn example() -> Zone {
+    Zone::parse("example.com".parse().unwrap(), EXAMPLE).unwrap()
+}
+
+#[cfg(test)]
+fn types(rrs: &[Resource]) -> Vec<(String, RType)> {
+    rrs.iter().map(|r| (r.name.to_string(), r.rtype)).collect()
+}
+
+#[test]
+fn load_zone() {
+    let zone = example();
+    assert_eq!(zone.len(), 13);
+    assert_eq!(zone.soa().unwrap().data, RData::SOA{
+        mname: "ns1.example.com".parse().unwrap(),
+        rname: "hostmaster.example.com".parse().unwrap(),
+        serial: 2016010101,
+        refresh: 86400,
+        retry: 7200,
+        expire: 2419200,
+        minimum: 300,
+    });
+
+    let resp = zone.answer(&query("a.b.c.example.com", RType::TXT));
+    assert_eq!(resp.answers[0].data, RData::TXT(Txt{ strings: vec![b"v=spf1 -all".to_vec(), b"second".to_vec()] }));
+
+    // no SOA, out of zone data and unsupported presentation formats
+    assert!(Zone::parse("example.com".parse().unwrap(), "www 60 IN A 192.0.2.1").is_err());
+    assert!(Zone::parse("example.com".parse().unwrap(),
+                        "@ 60 IN SOA ns hm 1 1 1 1 1\nwww.example.net. 60 IN A 192.0.2.1").is_err());
+    assert!(Zone::parse("example.com".parse().unwrap(),
+                        "@ 60 IN SOA ns hm 1 1 1 1 1\nwww 60 IN A 192.0.2").is_err());
+
+    let zone = Zone::parse("example.com".parse().unwrap(),
+                           "@ 60 IN SOA ns hm 1 1 1 1 1\n@ 60 IN TYPE13 \\# 3 abcd ef").unwrap();
+    assert_eq!(zone.len(), 2);
+}
+
+#[test]
+fn answer_from_zone() {
+    let zone = example();
+
+    let resp = zone.answer(&query("MAIL.example.com", RType::A));
+    assert!(resp.aa);
+    assert_eq!(resp.rcode, RCode::NOERROR);
+    assert_eq!(types(&resp.answers), vec![("MAIL.example.com.".to_string(), RType::A)]);
+
+    let resp = zone.answer(&query("example.com", RType::MX));
+    assert_eq!(types(&resp.answers), vec![("example.com.".to_string(), RType::MX)]);
+    assert_eq!(types(&resp.additionals), vec![("mail.example.com.".to_string(), RType::A),
+                                              ("mail.example.com.".to_string(), RType::AAAA)]);
+
+    // CNAMEs are followed within the zone but not outside it
+    let resp = zone.answer(&query("www.example.com", RType::A));
+    assert_eq!(types(&resp.answers), vec![("www.example.com.".to_string(), RType::CNAME),
+                                          ("web.example.com.".to_string(), RType::A)]);
+    let resp = zone.answer(&query("ext.example.com", RType::A));
+    assert_eq!(types(&resp.answers), vec![("ext.example.com.".to_string(), RType::CNAME)]);
+    assert_eq!(resp.authority, vec![]);
+
+    let resp = zone.answer(&query("host.wild.example.com", RType::A));
+    assert_eq!(types(&resp.answers), vec![("host.wild.example.com.".to_string(), RType::A)]);
+}
+
+#[test]
+fn answer_srv_with_targets() {
+    let zone = Zone::parse("example.com".parse().unwrap(), "
+@               60 IN SOA  ns hm 1 1 1 1 1
+_sip._udp       60 IN SRV  10 60 5060 sip1
+                60 IN SRV  10 40 5060 sip2.example.net.
+sip1            60 IN A    192.0.2.60
+").unwrap();
+    let resp = zone.answer(&query("_sip._udp.example.com", RType::SRV));
+    assert_eq!(resp.answers.len(), 2);
+    assert_eq!(types(&resp.additionals), vec![("sip1.example.com.".to_string(), RType::A)]);
+}
+
+#[test]
+fn answer_https_with_targets() {
+    let zone = Zone::parse("example.com".parse().unwrap(), "
+@       60 IN SOA   ns hm 1 1 1 1 1
+@       60 IN HTTPS 1 . alpn=h2
+        60 IN A     192.0.2.1
+www     60 IN HTTPS 0 cdn
+cdn     60 IN AAAA  2001:db8::1
+gone    60 IN HTTPS 0 .
+").unwrap();
+    let resp = zone.answer(&query("example.com", RType::HTTPS));
+    assert_eq!(types(&resp.answers), vec![("example.com.".to_string(), RType::HTTPS)]);
+    assert_eq!(types(&resp.additionals), vec![("example.com.".to_string(), RType::A)]);
+    let resp = zone.answer(&query("www.example.com", RType::HTTPS));
+    assert_eq!(types(&resp.additionals), vec![("cdn.example.com.".to_string(), RType::AAAA)]);
+    let resp = zone.answer(&query("gone.example.com", RType::HTTPS));
+    assert_eq!(resp.additionals, vec![]);
+}
+
+#[test]
+fn answer_negative() {
+    let zone = example();
+
+    let resp = zone.answer(&query("nope.example.com", RType::A));
+    assert_eq!(resp.rcode, RCode::NXDOMAIN);
+    assert_eq!(types(&resp.authority), vec![("example.com.".to_string(), RType::SOA)]);
+    assert_eq!(resp.authority[0].ttl, 300);
+
+    // no data for the type, and an empty non-terminal
+    for &(name, rtype) in [("web.example.com", RType::AAAA), ("b.c.example.com", RType::A)].iter() {
+        let resp = zone.answer(&query(name, rtype));
+        assert_eq!(resp.rcode, RCode::NOERROR);
+        assert_eq!(resp.answers, vec![]);
+        assert_eq!(types(&resp.authority), vec![("example.com.".to_string(), RType::SOA)]);
+    }
+
+    // the wildcard does not cover its own parent
+    let resp = zone.answer(&query("wild.example.com", RType::A));
+    assert_eq!(resp.rcode, RCode::NOERROR);
+    assert_eq!(resp.answers, vec![]);
+}
+
+#[test]
+fn answer_referral() {
+    let zone = example();
+    for name in ["sub.example.com", "www.sub.example.com"].iter() {
+        let resp = zone.answer(&query(name, RType::A));
+        assert!(!resp.aa);
+        assert_eq!(resp.rcode, RCode::NOERROR);
+        assert_eq!(resp.answers, vec![]);
+        assert_eq!(types(&resp.authority), vec![("sub.example.com.".to_string(), RType::NS)]);
+        assert_eq!(types(&resp.additionals), vec![("ns.sub.example.com.".to_string(), RType::A)]);
+    }
+}
+
+#[test]
+fn find_closest_zone() {
+    let mut zones = Zones::new();
+    zones.insert(example());
+    zones.insert(Zone::parse("sub.example.com".parse().unwrap(), "@ 60 IN SOA ns hm 1 1 1 1 1").unwrap());
+
+    let origin = |name: &str| zones.find(&name.parse().unwrap()).map(|z| z.origin().to_string());
+    assert_eq!(origin("www.Example.com"), Some("example.com.".to_string()));
+    assert_eq!(origin("a.sub.example.com"), Some("sub.example.com.".to_string()));
+    assert_eq!(origin("example.net"), None);
+}
+
+// The zone's SOA record carrying another serial, for building diffs in tests.
+#[cfg(test)]
+pub fn soa_serial(zone: &Zone, serial: u32) -> Resource {
+    let mut soa = zone.soa().unwrap().clone();
+    if let RData::SOA{ serial: ref mut s, .. } = soa.data {
+        *s = serial;
+    }
+    soa
+}
+
+#[cfg(test)]
+fn a(name: &str, o: u8) -> Resource {
+    Resource{
+        name: name.parse().unwrap(),
+        rtype: RType::A,
+        class: Class::IN,
+        ttl: 3600,
+        data: RData::A(192, 0, 2, o),
+    }
+}
+
+#[test]
+fn apply_changes() {
+    let mut zone = example();
+    assert_eq!(zone.serial(), 2016010101);
+    assert_eq!(zone.changes_since(2016010101), Some(vec![]));
+    assert_eq!(zone.changes_since(2016010100), None);
+
+    let first = Diff{
+        from: soa_serial(&zone, 2016010101),
+        to: soa_serial(&zone, 2016010102),
+        deleted: vec![a("web.example.com", 80)],
+        added: vec![a("web.example.com", 81), a("new.deep.example.com", 1)],
+    };
+    zone.apply(first.clone()).unwrap();
+    assert_eq!(zone.serial(), 2016010102);
+    assert_eq!(zone.len(), 14);
+    assert_eq!(zone.answer(&query("web.example.com", RType::A)).answers, vec![a("web.example.com", 81)]);
+    assert_eq!(zone.answer(&query("deep.example.com", RType::A)).rcode, RCode::NOERROR);
+
+    // changes must follow on from the current serial
+    assert!(zone.apply(first.clone()).is_err());
+    let outside = Diff{ from: soa_serial(&zone, 2016010102), to: soa_serial(&zone, 2016010103), deleted: vec![], added: vec![a("example.net", 1)] };
+    assert!(zone.apply(outside).is_err());
+
+    let second = Diff{
+        from: soa_serial(&zone, 2016010102),
+        to: soa_serial(&zone, 2016010103),
+        deleted: vec![a("new.deep.example.com", 1)],
+        added: vec![],
+    };
+    zone.apply(second.clone()).unwrap();
+    assert_eq!(zone.answer(&query("deep.example.com", RType::A)).rcode, RCode::NXDOMAIN);
+    assert_eq!(zone.changes_since(2016010101), Some(vec![&first, &second]));
+    assert_eq!(zone.changes_since(2016010102), Some(vec![&second]));
+    assert_eq!(zone.changes_since(2016010099), None);
+
+    let records = zone.records();
+    assert_eq!(records.len(), zone.len());
+    assert_eq!(records[0].name.to_string(), "example.com.");
+}
+
+#[test]
+fn canonical_order() {
+    // the example of RFC 4034 §6.1
+    let zone = Zone::parse("example".parse().unwrap(), "
+z.example.      60 IN A   192.0.2.1
+*.z.example.    60 IN A   192.0.2.2
+zABC.a.EXAMPLE. 60 IN A   192.0.2.3
+Z.a.example.    60 IN A   192.0.2.4
+yljkjljk.a.example. 60 IN A 192.0.2.5
+a.example.      60 IN A   192.0.2.6
+example.        60 IN SOA ns hm 1 1 1 1 1
+").unwrap();
+    let owners: Vec<String> = zone.records().iter().map(|r| r.name.to_string()).collect();
+    assert_eq!(owners, vec!["example.", "a.example.", "yljkjljk.a.example.", "Z.a.example.",
+                            "zABC.a.EXAMPLE.", "z.example.", "*.z.example."]);
+}
+
+#[test]
+fn escaped_dots() {
+    let mut zone = Zone::parse("example.com".parse().unwrap(), "
+@       60 IN SOA ns hm 1 1 1 1 1
+a\\.b   60 IN A   192.0.2.1
+a\\.b   60 IN TXT \"dot\"
+c       60 IN A   192.0.2.3
+").unwrap();
+    // a.b is not a name below b
+    assert_eq!(zone.answer(&query("b.example.com", RType::A)).rcode, RCode::NXDOMAIN);
+    assert_eq!(zone.answer(&query("a\\.b.example.com", RType::A)).answers.len(), 1);
+
+    // removing records elsewhere leaves the names standing
+    zone.remove(&a("c.example.com", 3));
+    assert_eq!(zone.answer(&query("b.example.com", RType::A)).rcode, RCode::NXDOMAIN);
+    assert_eq!(zone.answer(&query("a\\.b.example.com", RType::TXT)).answers.len(), 1);
+    assert_eq!(zone.answer(&query("c.example.com", RType::A)).rcode, RCode::NXDOMAIN);
+}
+
+#[test]
+fn compare_serials() {
+    assert!(serial_lt(1, 2));
+    assert!(!serial_lt(2, 1));
+    assert!(!serial_lt(5, 5));
+    assert!(serial_lt(0xffff_fff0, 3));
+    assert!(!serial_lt(3, 0xffff_fff0));
+}
+
+#[test]
+fn save_zone() {
+    let zone = example();
+    let path = ::std::env::temp_dir().join(format!("reagent-save-zone-{}", ::std::process::id()));
+    zone.save(&path).unwrap();
+    let loaded = Zone::open("example.com".parse().unwrap(), &path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.len(), zone.len());
+    assert_eq!(loaded.records(), zone.records());
+}
+
+#[test]
+fn load_signed_records() {
+    let zone = Zone::parse("example.com".parse().unwrap(), "
+@ 60 IN SOA ns hm 1 1 1 1 1
+@ 60 IN DNSKEY 256 3 5 ( AQOeiiR0GOMYkDshWoSKz9XzfwJr1AYtsmx3TGkJaNXVbfi/2pHm822aJ5iI9BMzNXxeYCmZDRD99WYwYqUSdjMmmAphXdvxegXd/M5+X7OrzKBaMbCVdFLUUh6DhweJBjEVv5f2wwjM9XzcnOf+EPbtG9DMBmADjFDc2w/rljwvFw== )
+@ 60 IN NSEC host A MX RRSIG NSEC TYPE1234
+@ 60 IN NSEC3PARAM 1 0 12 aabbccdd
+sub 60 IN DS 60485 5 1 2BB183AF5F22588179A53B0A98631FAD1A292118
+host 60 IN RRSIG A 5 3 86400 20030322173103 20030220173103 2642 @ oJB1W6WNGv+ldvQ3WDG0MQkg5IEhjRip8WTr
+").unwrap();
+    match zone.get(&"example.com".parse().unwrap()).iter().find(|r| r.rtype == RType::DNSKEY) {
+        Some(&Resource{ data: RData::DNSKEY(ref key), .. }) => assert_eq!(key.key_tag(), 60485),
+        _ => panic!("no DNSKEY"),
+    }
+
+    let path = ::std::env::temp_dir().join(format!("reagent-signed-zone-{}", ::std::process::id()));
+    zone.save(&path).unwrap();
+    let loaded = Zone::open("example.com".parse().unwrap(), &path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.records(), zone.records());
+}
+
+#[test]
+fn synthesize_reverse_records() {
+    let mut reverse = Zone::parse("2.0.192.in-addr.arpa".parse().unwrap(), "
+@ 60 IN SOA ns.example.com. hm.example.com. 1 1 1 1 1
+@ 60 IN NS ns.example.com.
+25 60 IN PTR smtp.example.com.
+").unwrap();
+    assert_eq!(reverse.synthesize_ptrs(&example()), 3);
+
+    let ptrs = |zone: &Zone, name: &str| -> Vec<String> {
+        zone.get(&name.parse().unwrap()).iter().map(|r| match r.data {
+            RData::PTR(ref target) => target.to_string(),
+            _ => panic!("not a PTR"),
+        }).collect()
+    };
+    assert_eq!(ptrs(&reverse, "53.2.0.192.in-addr.arpa"), vec!["ns1.example.com."]);
+    assert_eq!(ptrs(&reverse, "80.2.0.192.in-addr.arpa"), vec!["web.example.com."]);
+    assert_eq!(ptrs(&reverse, "153.2.0.192.in-addr.arpa"), vec!["ns.sub.example.com."]);
+    assert_eq!(ptrs(&reverse, "25.2.0.192.in-addr.arpa"), vec!["smtp.example.com."]);
+    assert!(ptrs(&reverse, "99.2.0.192.in-addr.arpa").is_empty());
+
+    // only the records of the zone itself are saved
+    let path = ::std::env::temp_dir().join(format!("reagent-reverse-zone-{}", ::std::process::id()));
+    reverse.save(&path).unwrap();
+    let loaded = Zone::open("2.0.192.in-addr.arpa".parse().unwrap(), &path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.len(), 3);
+    assert_eq!(ptrs(&loaded, "25.2.0.192.in-addr.arpa"), vec!["smtp.example.com."]);
+    assert!(ptrs(&loaded, "80.2.0.192.in-addr.arpa").is_empty());
+
+    let mut reverse6 = Zone::parse("8.b.d.0.1.0.0.2.ip6.arpa".parse().unwrap(),
+                                   "@ 60 IN SOA ns.example.com. hm.example.com. 1 1 1 1 1").unwrap();
+    assert_eq!(reverse6.synthesize_ptrs(&example()), 1);
+    let name = RName::from_ip("2001:db8::25".parse().unwrap()).to_string();
+    assert_eq!(ptrs(&reverse6, &name), vec!["mail.example.com."]);
+}
@@ -1,7 +1,420 @@
+extern crate reagent;
 
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream, UdpSocket};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
 
+use reagent::{Server, ServerConfig};
+use reagent::acl::Access;
+use reagent::view::View;
+use reagent::secondary::SecondaryConfig;
+use reagent::zone::Zone;
+use reagent::tsig::{self, Key, Signer};
+use reagent::validator;
+
+// www.google.com IN A with RD set
+const QUERY: [u8; 32] = [
+    0x2b, 0x22, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, b'w', b'w', b'w', 0x06, b'g', b'o', b'o', b'g', b'l', b'e', 0x03, b'c', b'o', b'm', 0x00,
+    0x00, 0x01, 0x00, 0x01,
+];
+
+fn start(mut config: ServerConfig) -> (SocketAddr, Option<SocketAddr>) {
+    config.metrics = config.metrics.or(Some("127.0.0.1:0".parse().unwrap()));
+    let mut server = Server::configured(&"127.0.0.1:0".parse().unwrap(), config).unwrap();
+    let addrs = (server.local_addr(), server.metrics_addr());
+    thread::spawn(move || server.run().unwrap());
+    addrs
+}
+
+fn scrape(addr: SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut resp = String::new();
+    stream.read_to_string(&mut resp).unwrap();
+    resp
+}
+
+// A stand-in recursive server answering A queries with 192.0.2.1 and any
+// other with no records, none of them signed.
+fn upstream() -> SocketAddr {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let addr = socket.local_addr().unwrap();
+    thread::spawn(move || {
+        let mut buf = [0; 512];
+        while let Ok((_, src)) = socket.recv_from(&mut buf) {
+            let mut end = 12;
+            while buf[end] != 0 {
+                end += 1 + buf[end] as usize;
+            }
+            end += 5;
+            // the question only, without the OPT record
+            let mut resp = buf[..end].to_vec();
+            resp[2] |= 0x80;
+            resp[3] = 0x80;
+            resp[11] = 0;
+            if &buf[end - 4..end - 2] == &[0, 1] {
+                resp[7] = 1;
+                resp.extend(&[0xc0, 0x0c, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x04, 192, 0, 2, 1]);
+            }
+            socket.send_to(&resp, src).unwrap();
+        }
+    });
+    addr
+}
 
 #[test]
 fn test_placeholder() {
     assert_eq!(true, true);
 }
+
+#[test]
+fn scrape_metrics() {
+    let (dns, metrics) = start(ServerConfig::default());
+    let metrics = metrics.unwrap();
+
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    client.send_to(&QUERY, dns).unwrap();
+    let mut buf = [0; 512];
+    let (len, _) = client.recv_from(&mut buf).unwrap();
+    assert!(len >= 12);
+    assert_eq!(&buf[..2], &QUERY[..2]);
+    client.send_to(&[0xde, 0xad], dns).unwrap();
+
+    // the malformed packet is accounted asynchronously
+    let mut body = String::new();
+    for _ in 0..50 {
+        body = scrape(metrics, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n");
+        if body.contains("reagent_malformed_packets_total 1") {
+            break
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    assert!(body.starts_with("HTTP/1.1 200 OK\r\n"), "{}", body);
+    assert!(body.contains("Content-Type: text/plain; version=0.0.4"));
+    // nothing to forward to without upstreams
+    assert!(body.contains("reagent_queries_total{transport=\"udp\",qtype=\"A\",rcode=\"REFUSED\"} 1\n"), "{}", body);
+    assert!(body.contains("reagent_query_duration_seconds_count{transport=\"udp\"} 1\n"));
+    assert!(body.contains("reagent_malformed_packets_total 1\n"));
+
+    let resp = scrape(metrics, "GET /other HTTP/1.0\r\n\r\n");
+    assert!(resp.starts_with("HTTP/1.1 404 Not Found\r\n"));
+}
+
+#[test]
+fn transfer_zone_over_tcp() {
+    let mut config = ServerConfig::default();
+    config.access.attach(Access::Transfer, "any").unwrap();
+    let mut view = View::new("default");
+    view.zones.insert(Zone::parse("example.com".parse().unwrap(),
+                                  "@ 60 IN SOA ns hm 1 60 60 60 60\n@ 60 IN NS ns\nns 60 IN A 192.0.2.53").unwrap());
+    config.views.push(view);
+    let (dns, _) = start(config);
+
+    // example.com IN AXFR
+    let query = [0x00, 0x1d, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                 0x07, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x03, b'c', b'o', b'm', 0x00,
+                 0x00, 0xfc, 0x00, 0x01];
+    let mut stream = TcpStream::connect(dns).unwrap();
+    stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    stream.write_all(&query).unwrap();
+
+    let mut len = [0; 2];
+    stream.read_exact(&mut len).unwrap();
+    let mut resp = vec![0; (len[0] as usize) << 8 | len[1] as usize];
+    stream.read_exact(&mut resp).unwrap();
+    assert_eq!(&resp[..2], &query[2..4]);
+    // authoritative, NOERROR, SOA NS A SOA
+    assert_eq!(resp[2] & 0x04, 0x04);
+    assert_eq!(resp[3] & 0x0f, 0);
+    assert_eq!(&resp[6..8], &[0, 4]);
+
+    // the same question over UDP is not served
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    client.send_to(&query[2..], dns).unwrap();
+    let mut buf = [0; 512];
+    client.recv_from(&mut buf).unwrap();
+    assert_eq!(buf[3] & 0x0f, 4);
+}
+
+#[test]
+fn accept_notify_from_primary() {
+    let mut config = ServerConfig::default();
+    let mut view = View::new("default");
+    view.secondaries.push(SecondaryConfig{
+        origin: "example.com".parse().unwrap(),
+        primaries: vec!["127.0.0.1:9".parse().unwrap()],
+        file: None,
+        key: None,
+    });
+    config.views.push(view);
+    let (dns, _) = start(config);
+
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut buf = [0; 512];
+
+    // example.com IN SOA with opcode NOTIFY and AA set
+    let mut notify = vec![0x12, 0x34, 0x24, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                          0x07, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x03, b'c', b'o', b'm', 0x00,
+                          0x00, 0x06, 0x00, 0x01];
+    client.send_to(&notify, dns).unwrap();
+    let (len, _) = client.recv_from(&mut buf).unwrap();
+    assert!(len >= 12);
+    assert_eq!(&buf[..2], &[0x12, 0x34]);
+    // a NOTIFY response, authoritative, NOERROR
+    assert_eq!(buf[2] & 0xfc, 0xa4);
+    assert_eq!(buf[3] & 0x0f, 0);
+
+    // not a zone this server is secondary for
+    notify[19] = b'x';
+    client.send_to(&notify, dns).unwrap();
+    client.recv_from(&mut buf).unwrap();
+    assert_eq!(buf[3] & 0x0f, 9);
+}
+
+#[test]
+fn update_zone() {
+    let mut config = ServerConfig::default();
+    config.access.attach(Access::Update, "any").unwrap();
+    let mut view = View::new("default");
+    view.zones.insert(Zone::parse("example.com".parse().unwrap(),
+                                  "@ 60 IN SOA ns hm 1 60 60 60 60\n@ 60 IN NS ns\nns 60 IN A 192.0.2.53").unwrap());
+    config.views.push(view);
+    let (dns, _) = start(config);
+
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut buf = [0; 512];
+
+    // zone example.com, add www.example.com 60 IN A 192.0.2.80
+    let update = [0x43, 0x21, 0x28, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+                  0x07, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x03, b'c', b'o', b'm', 0x00,
+                  0x00, 0x06, 0x00, 0x01,
+                  0x03, b'w', b'w', b'w', 0xc0, 0x0c, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3c,
+                  0x00, 0x04, 192, 0, 2, 80];
+    client.send_to(&update, dns).unwrap();
+    client.recv_from(&mut buf).unwrap();
+    assert_eq!(&buf[..2], &update[..2]);
+    assert_eq!(buf[2] & 0xfc, 0xac);
+    assert_eq!(buf[3] & 0x0f, 0);
+
+    // www.example.com IN A
+    let query = [0x43, 0x22, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                 0x03, b'w', b'w', b'w', 0x07, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x03, b'c', b'o', b'm', 0x00,
+                 0x00, 0x01, 0x00, 0x01];
+    client.send_to(&query, dns).unwrap();
+    let (len, _) = client.recv_from(&mut buf).unwrap();
+    assert_eq!(buf[3] & 0x0f, 0);
+    assert_eq!(&buf[6..8], &[0, 1]);
+    assert_eq!(&buf[len - 4..len], &[192, 0, 2, 80]);
+}
+
+#[test]
+fn update_zone_with_key() {
+    let key = Key::parse("update-key", "hmac-sha256", "c2VjcmV0LWtleS1mb3ItdHNpZy10ZXN0cw==").unwrap();
+    let mut config = ServerConfig::default();
+    config.access.attach(Access::Update, "none").unwrap();
+    config.tsig_keys.push(key.clone());
+    config.update_keys.push(key.name.clone());
+    let mut view = View::new("default");
+    view.zones.insert(Zone::parse("example.com".parse().unwrap(),
+                                  "@ 60 IN SOA ns hm 1 60 60 60 60\n@ 60 IN NS ns\nns 60 IN A 192.0.2.53").unwrap());
+    config.views.push(view);
+    let (dns, _) = start(config);
+
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut buf = [0; 512];
+
+    // zone example.com, add www.example.com 60 IN A 192.0.2.80
+    let update = [0x43, 0x23, 0x28, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+                  0x07, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x03, b'c', b'o', b'm', 0x00,
+                  0x00, 0x06, 0x00, 0x01,
+                  0x03, b'w', b'w', b'w', 0xc0, 0x0c, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3c,
+                  0x00, 0x04, 192, 0, 2, 80];
+
+    // a key of that name with another secret is not taken for it
+    let mut forged = key.clone();
+    forged.secret[0] ^= 1;
+    let mut req = update.to_vec();
+    Signer::new(forged).sign(&mut req, tsig::now()).unwrap();
+    client.send_to(&req, dns).unwrap();
+    client.recv_from(&mut buf).unwrap();
+    assert_eq!(buf[3] & 0x0f, 9);
+
+    let mut signer = Signer::new(key);
+    let mut req = update.to_vec();
+    signer.sign(&mut req, tsig::now()).unwrap();
+    client.send_to(&req, dns).unwrap();
+    let (len, _) = client.recv_from(&mut buf).unwrap();
+    assert_eq!(buf[3] & 0x0f, 0);
+    assert!(signer.verify(&buf[..len], tsig::now()).is_ok());
+
+    // unsigned it is refused
+    client.send_to(&update, dns).unwrap();
+    client.recv_from(&mut buf).unwrap();
+    assert_eq!(buf[3] & 0x0f, 5);
+}
+
+#[test]
+fn sign_responses() {
+    let key = Key::parse("test-key", "hmac-sha256", "c2VjcmV0LWtleS1mb3ItdHNpZy10ZXN0cw==").unwrap();
+    let mut config = ServerConfig::default();
+    config.tsig_keys.push(key.clone());
+    let mut view = View::new("default");
+    view.zones.insert(Zone::parse("example.com".parse().unwrap(),
+                                  "@ 60 IN SOA ns hm 1 60 60 60 60\n@ 60 IN NS ns\nns 60 IN A 192.0.2.53").unwrap());
+    config.views.push(view);
+    let (dns, _) = start(config);
+
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut buf = [0; 512];
+
+    // example.com IN SOA
+    let query = [0x51, 0x6e, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                 0x07, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x03, b'c', b'o', b'm', 0x00,
+                 0x00, 0x06, 0x00, 0x01];
+    let mut signer = Signer::new(key.clone());
+    let mut req = query.to_vec();
+    signer.sign(&mut req, tsig::now()).unwrap();
+    client.send_to(&req, dns).unwrap();
+    let (len, _) = client.recv_from(&mut buf).unwrap();
+    assert_eq!(buf[3] & 0x0f, 0);
+    assert_eq!(&buf[6..8], &[0, 1]);
+    assert!(signer.verify(&buf[..len], tsig::now()).is_ok());
+
+    // an unknown key gets NOTAUTH
+    let other = Key::parse("other-key", "hmac-sha256", "c2VjcmV0LWtleS1mb3ItdHNpZy10ZXN0cw==").unwrap();
+    let mut signer = Signer::new(other);
+    let mut req = query.to_vec();
+    signer.sign(&mut req, tsig::now()).unwrap();
+    client.send_to(&req, dns).unwrap();
+    let (len, _) = client.recv_from(&mut buf).unwrap();
+    assert_eq!(buf[3] & 0x0f, 9);
+    assert!(signer.verify(&buf[..len], tsig::now()).is_err());
+}
+
+#[test]
+fn validate_forwarded_answers() {
+    let mut config = ServerConfig::default();
+    config.trust_anchors = validator::anchors(
+        ". 3600 IN DS 20326 8 2 E06D44B80B8F1D39A95C0B0D7C65D08458E880409BBC683457104237C7F8EC8D").unwrap();
+    let mut view = View::new("default");
+    view.forwarders.push(upstream());
+    config.views.push(view);
+    let (dns, _) = start(config);
+
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut buf = [0; 512];
+
+    // the root has no keys upstream, so nothing below it can be trusted
+    client.send_to(&QUERY, dns).unwrap();
+    client.recv_from(&mut buf).unwrap();
+    assert_eq!(&buf[..2], &QUERY[..2]);
+    assert_eq!(buf[3] & 0x0f, 2);
+
+    // unless the client asks for the answer unchecked
+    let mut query = QUERY;
+    query[3] |= 0x10;
+    client.send_to(&query, dns).unwrap();
+    let (len, _) = client.recv_from(&mut buf).unwrap();
+    assert_eq!(&buf[..2], &QUERY[..2]);
+    assert_eq!(buf[3] & 0x0f, 0);
+    assert_eq!(&buf[6..8], &[0, 1]);
+    assert_eq!(&buf[len - 4..len], &[192, 0, 2, 1]);
+}
+
+#[test]
+fn sign_forwarded_answers() {
+    let key = Key::parse("test-key", "hmac-sha256", "c2VjcmV0LWtleS1mb3ItdHNpZy10ZXN0cw==").unwrap();
+    let mut config = ServerConfig::default();
+    config.tsig_keys.push(key.clone());
+    let mut view = View::new("default");
+    view.forwarders.push(upstream());
+    config.views.push(view);
+    let (dns, _) = start(config);
+
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let mut buf = [0; 512];
+
+    let mut signer = Signer::new(key.clone());
+    let mut req = QUERY.to_vec();
+    signer.sign(&mut req, tsig::now()).unwrap();
+    client.send_to(&req, dns).unwrap();
+    let (len, _) = client.recv_from(&mut buf).unwrap();
+    assert_eq!(&buf[..2], &QUERY[..2]);
+    assert_eq!(buf[3] & 0x0f, 0);
+    assert_eq!(&buf[6..8], &[0, 1]);
+    assert!(signer.verify(&buf[..len], tsig::now()).is_ok());
+
+    // validated answers are signed as well
+    let mut config = ServerConfig::default();
+    config.tsig_keys.push(key.clone());
+    config.trust_anchors = validator::anchors(
+        ". 3600 IN DS 20326 8 2 E06D44B80B8F1D39A95C0B0D7C65D08458E880409BBC683457104237C7F8EC8D").unwrap();
+    let mut view = View::new("default");
+    view.forwarders.push(upstream());
+    config.views.push(view);
+    let (dns, _) = start(config);
+
+    let mut signer = Signer::new(key);
+    let mut req = QUERY.to_vec();
+    signer.sign(&mut req, tsig::now()).unwrap();
+    client.send_to(&req, dns).unwrap();
+    let (len, _) = client.recv_from(&mut buf).unwrap();
+    assert_eq!(buf[3] & 0x0f, 2);
+    assert!(signer.verify(&buf[..len], tsig::now()).is_ok());
+}
+
+// Signs requests with dig and nsupdate from BIND, which have to be on the
+// path: cargo test -- --ignored
+#[test]
+#[ignore]
+fn sign_like_bind() {
+    let secret = "c2VjcmV0LWtleS1mb3ItdHNpZy10ZXN0cw==";
+    let key = Key::parse("test-key", "hmac-sha256", secret).unwrap();
+    let mut config = ServerConfig::default();
+    config.access.attach(Access::Transfer, "any").unwrap();
+    config.access.attach(Access::Update, "none").unwrap();
+    config.tsig_keys.push(key.clone());
+    config.update_keys.push(key.name.clone());
+    let mut view = View::new("default");
+    view.zones.insert(Zone::parse("example.com".parse().unwrap(),
+                                  "@ 60 IN SOA ns hm 1 60 60 60 60\n@ 60 IN NS ns\nns 60 IN A 192.0.2.53").unwrap());
+    config.views.push(view);
+    let (dns, _) = start(config);
+    let port = dns.port().to_string();
+    let tsig = format!("hmac-sha256:test-key:{}", secret);
+
+    let dig = |args: &[&str]| {
+        let out = Command::new("dig").args(&["@127.0.0.1", "-p", &port, "-y", &tsig, "+norec"]).args(args)
+            .output().unwrap();
+        assert!(out.status.success());
+        String::from_utf8(out.stdout).unwrap()
+    };
+    // dig complains about responses it can not verify
+    let soa = dig(&["example.com", "SOA"]);
+    assert!(soa.contains("status: NOERROR"), "{}", soa);
+    assert!(soa.contains("TSIG") && !soa.contains("verify"), "{}", soa);
+    // every message of a transfer is verified
+    let axfr = dig(&["example.com", "AXFR"]);
+    assert!(axfr.contains("XFR size: 4 records"), "{}", axfr);
+    assert!(!axfr.contains("verify") && !axfr.contains("failed"), "{}", axfr);
+
+    let mut nsupdate = Command::new("nsupdate").args(&["-y", &tsig]).stdin(Stdio::piped()).spawn().unwrap();
+    write!(nsupdate.stdin.take().unwrap(),
+           "server 127.0.0.1 {}\nzone example.com\nupdate add www.example.com 60 IN A 192.0.2.80\nsend\n",
+           port).unwrap();
+    assert!(nsupdate.wait().unwrap().success());
+    let www = dig(&["www.example.com", "A"]);
+    assert!(www.contains("192.0.2.80"), "{}", www);
+}
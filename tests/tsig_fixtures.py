@@ -0,0 +1,79 @@
+#!/usr/bin/env python3
+# Prints the TSIG packets the tests in src/tsig.rs check against, signed
+# by dnspython (tested with 1.16.0) with its clock held at fixed times:
+#
+#     PYTHONPATH=/path/to/dnspython python3 tests/tsig_fixtures.py
+#
+# The response streams are checked with dnspython's own verifier before
+# they are printed, the one with an unsigned middle message included.
+
+import base64
+import time
+
+import dns.message
+import dns.name
+import dns.rdatatype
+import dns.renderer
+import dns.rrset
+import dns.tsig
+
+NOW = 1700000000
+SECRET = 'c2VjcmV0LWtleS1mb3ItdHNpZy10ZXN0cw=='
+KEY = dns.name.from_text('test-key.')
+KEYRING = {KEY: base64.b64decode(SECRET)}
+
+
+def at(now):
+    time.time = lambda: now
+
+
+def request(keyname, algorithm):
+    at(NOW)
+    query = dns.message.make_query('example.com.', 'SOA')
+    query.id = 0x1234
+    query.flags = 0
+    query.use_tsig(KEYRING, dns.name.from_text(keyname), fudge=300, algorithm=algorithm)
+    return query.to_wire(), query.mac
+
+
+def response(ctx, request_mac, now, address, question=False, signed=True):
+    at(now)
+    r = dns.renderer.Renderer(0x1234, 0x8400, 65535)
+    if question:
+        r.add_question(dns.name.from_text('example.com.'), dns.rdatatype.SOA)
+    r.add_rrset(dns.renderer.ANSWER,
+                dns.rrset.from_text('example.com.', 60, 'IN', 'A', address))
+    r.write_header()
+    if signed:
+        ctx = r.add_multi_tsig(ctx, KEY, KEYRING[KEY], 300, 0x1234, 0, b'',
+                               request_mac, dns.tsig.HMAC_SHA256)
+    else:
+        # an unsigned message goes into the digest of the next signed one
+        ctx.update(r.get_wire())
+    return r.get_wire(), ctx
+
+
+def check(stream, request_mac):
+    # as dns.query.xfr reads a transfer
+    ctx = None
+    for i, wire in enumerate(stream):
+        at(NOW + i + 1)
+        msg = dns.message.from_wire(wire, keyring=KEYRING, request_mac=request_mac,
+                                    tsig_ctx=ctx, multi=True, first=i == 0)
+        ctx = msg.tsig_ctx
+    assert msg.had_tsig
+
+
+sha256, mac = request('test-key.', dns.tsig.HMAC_SHA256)
+sha512, _ = request('Test-Key.', dns.tsig.HMAC_SHA512)
+r1, ctx = response(None, mac, NOW + 1, '192.0.2.1', question=True)
+r2, _ = response(ctx.copy(), mac, NOW + 2, '192.0.2.2')
+u2, ctx = response(ctx, mac, NOW + 2, '192.0.2.2', signed=False)
+r3, _ = response(ctx, mac, NOW + 3, '192.0.2.3')
+check([r1, r2], mac)
+check([r1, u2, r3], mac)
+
+for name, wire in [('REQUEST_SHA256', sha256), ('REQUEST_SHA512', sha512),
+                   ('RESPONSE_1', r1), ('RESPONSE_2', r2),
+                   ('UNSIGNED_2', u2), ('RESPONSE_3', r3)]:
+    print('%s = "%s"' % (name, wire.hex()))
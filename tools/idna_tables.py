@@ -0,0 +1,148 @@
+#!/usr/bin/env python3
+# Writes src/dns/idna_tables.rs, the data of src/dns/idna.rs: the UTS #46
+# mapping and the IDNA 2008 code point classes, joining types and scripts
+# (RFC 5892) from the Python idna package, and the bidi classes, general
+# categories and normalization data from Python's unicodedata. Both have to
+# be of the same version of Unicode:
+#
+#     PYTHONPATH=/path/to/idna-3.10 python3.13 tools/idna_tables.py > src/dns/idna_tables.rs
+#
+# The idna package builds its data from IdnaMappingTable.txt and the UCD
+# with its tools/idna-data.
+
+import sys
+import unicodedata
+
+import idna
+from idna import idnadata, uts46data
+
+if idnadata.__version__ != unicodedata.unidata_version:
+    sys.exit('idna has Unicode %s, unicodedata %s' % (idnadata.__version__, unicodedata.unidata_version))
+
+CHARS = [chr(c) for c in range(0x110000)]
+BIDI = ['L', 'R', 'AL', 'EN', 'ES', 'ET', 'AN', 'CS', 'NSM', 'BN', 'B', 'S', 'WS', 'ON',
+        'LRE', 'LRO', 'RLE', 'RLO', 'PDF', 'LRI', 'RLI', 'FSI', 'PDI']
+
+
+def string(s):
+    out = ''
+    for c in s:
+        if c == '"' or c == '\\':
+            out += '\\' + c
+        elif ' ' <= c <= '~':
+            out += c
+        else:
+            out += '\\u{%x}' % ord(c)
+    return '"%s"' % out
+
+
+def ranges(values):
+    # (first, last, value) for the runs of code points with the same value,
+    # leaving out those without one
+    out = []
+    for c, value in enumerate(values):
+        if value is None:
+            continue
+        if out and out[-1][1] == c - 1 and out[-1][2] == value:
+            out[-1][1] = c
+        else:
+            out.append([c, c, value])
+    return out
+
+
+def intranges(encoded):
+    return [(r >> 32, (r & 0xffffffff) - 1) for r in encoded]
+
+
+def table(name, kind, rows, doc):
+    print()
+    for line in doc.split('\n'):
+        print('/// ' + line if line else '///')
+    print('pub static %s: &\'static [%s] = &[' % (name, kind))
+    line = '   '
+    for row in rows:
+        item = ' (%s),' % ', '.join(row)
+        if len(line) + len(item) > 100:
+            print(line)
+            line = '   '
+        line += item
+    if line.strip():
+        print(line)
+    print('];')
+
+
+def hexes(*values):
+    return ['0x%x' % v for v in values]
+
+
+print('// Generated by tools/idna_tables.py from the data of the idna package %s' % idna.__version__)
+print('// and Python %d.%d, both of Unicode %s. Do not edit.' % (sys.version_info[0], sys.version_info[1],
+                                                          unicodedata.unidata_version))
+print()
+print('/// The bidirectional class of a code point (UAX #9).')
+print('#[derive(Clone, Copy, Debug, PartialEq)]')
+print('pub enum Bidi {')
+print('    %s,' % ', '.join(BIDI))
+print('}')
+
+table('MAPPING', '(u32, u8, &\'static str)',
+      [('0x%x' % row[0], "b'%s'" % row[1], string(row[2] if len(row) > 2 else '')) for row in uts46data.uts46data],
+      'The UTS #46 status of the code points from the first one up to the\n'
+      'next row: `V` valid, `M` mapped, `D` deviation, `I` ignored, `X`\n'
+      'disallowed and `3` disallowed by STD 3, with what they are mapped to.')
+
+for name in ['PVALID', 'CONTEXTJ', 'CONTEXTO']:
+    table(name, '(u32, u32)', [hexes(*r) for r in intranges(idnadata.codepoint_classes[name])],
+          'The code points in %s under IDNA 2008 (RFC 5892 §2).' % name)
+
+table('JOINING_TYPES', '(u32, u32, u8)',
+      [hexes(first, last) + ["b'%s'" % chr(value)] for first, last, value in
+       ranges([idnadata.joining_types.get(c) for c in range(0x110000)])],
+      'The joining types of the code points that have one, for CONTEXTJ.')
+
+for script in ['Greek', 'Hebrew', 'Hiragana', 'Katakana', 'Han']:
+    table(script.upper(), '(u32, u32)', [hexes(*r) for r in intranges(idnadata.scripts[script])],
+          'The code points of the %s script, for CONTEXTO.' % script)
+
+table('MARKS', '(u32, u32)',
+      [hexes(first, last) for first, last, _ in
+       ranges([True if unicodedata.category(c)[0] == 'M' else None for c in CHARS])],
+      'The combining marks, general category M, no label may start with.')
+
+table('BIDI', '(u32, u32, Bidi)',
+      [hexes(first, last) + ['Bidi::' + value] for first, last, value in
+       ranges([unicodedata.bidirectional(c) or None for c in CHARS])],
+      'The bidirectional classes of the assigned code points.')
+
+table('COMBINING_CLASSES', '(u32, u32, u8)',
+      [hexes(first, last) + [str(value)] for first, last, value in
+       ranges([unicodedata.combining(c) or None for c in CHARS])],
+      'The canonical combining classes other than 0.')
+
+
+def canonical(c):
+    d = unicodedata.decomposition(c)
+    if not d or d.startswith('<'):
+        return None
+    return ''.join(canonical(chr(int(x, 16))) or chr(int(x, 16)) for x in d.split())
+
+
+# Hangul syllables are composed and decomposed by arithmetic instead
+decompositions = [(ord(c), canonical(c)) for c in CHARS
+                  if canonical(c) and not 0xac00 <= ord(c) <= 0xd7a3]
+table('DECOMPOSITIONS', '(u32, &\'static str)',
+      [('0x%x' % c, string(d)) for c, d in decompositions],
+      'The full canonical decompositions, Hangul syllables left out.')
+
+# the primary composites: those that decompose to a pair and come back from
+# it, which leaves out the composition exclusions and singletons
+compositions = []
+for c in CHARS:
+    d = unicodedata.decomposition(c)
+    if not d or d.startswith('<') or 0xac00 <= ord(c) <= 0xd7a3:
+        continue
+    pair = [chr(int(x, 16)) for x in d.split()]
+    if len(pair) == 2 and unicodedata.normalize('NFC', ''.join(pair)) == c:
+        compositions.append((ord(pair[0]), ord(pair[1]), ord(c)))
+table('COMPOSITIONS', '(u32, u32, u32)', [hexes(*r) for r in sorted(compositions)],
+      'The pairs that compose to a primary composite, Hangul left out.')